@@ -32,6 +32,7 @@ fn generate_parse_results(num_files: usize, funcs_per_file: usize, calls_per_fil
                     doc_comment: None,
                     body_hash: 0,
                     signature_hash: 0,
+                    complexity: Default::default(),
                 })
                 .collect();
             let call_sites: Vec<CallSite> = (0..calls_per_file)
@@ -5,12 +5,14 @@
 
 use drift_core::errors::BoundaryError;
 
+use crate::engine::embedded_sql::SqlStatement;
 use crate::parsers::types::ParseResult;
 
 use super::extractors::{self, FieldExtractor};
 use super::sensitive::SensitiveFieldDetector;
 use super::types::{
-    BoundaryScanResult, FrameworkSignature, OrmFramework,
+    BoundaryScanResult, ExtractedField, ExtractedModel, FrameworkSignature, OrmFramework,
+    SensitiveSqlAccess,
 };
 
 /// The boundary detector orchestrates framework detection, model extraction,
@@ -64,6 +66,76 @@ impl BoundaryDetector {
         Ok(result)
     }
 
+    /// Link raw SQL statements to the sensitive columns they touch.
+    ///
+    /// Columns of tables mapped by an extracted model take the sensitivity of
+    /// the model's field; other columns are classified by name, against the
+    /// first table no model maps.
+    pub fn link_sql_statements(
+        &self,
+        statements: &[SqlStatement],
+        scan: &BoundaryScanResult,
+    ) -> Vec<SensitiveSqlAccess> {
+        let mut accesses = Vec::new();
+        for stmt in statements {
+            let access = |table: &str, column: String, model_name: Option<String>, sensitivity, confidence| {
+                SensitiveSqlAccess {
+                    file: stmt.file.clone(),
+                    line: stmt.line,
+                    table: table.to_string(),
+                    column,
+                    operation: stmt.operation.name().to_string(),
+                    model_name,
+                    sensitivity,
+                    confidence,
+                }
+            };
+            let models: Vec<(&String, &ExtractedModel)> = stmt.tables.iter()
+                .filter_map(|t| scan.models.iter().find(|m| maps_table(m, t)).map(|m| (t, m)))
+                .collect();
+            let mut unmapped = Vec::new();
+            for column in &stmt.columns {
+                let owner = models.iter()
+                    .find(|(_, m)| m.fields.iter().any(|f| same_identifier(&f.name, column)));
+                let Some((table, model)) = owner else {
+                    unmapped.push(column);
+                    continue;
+                };
+                let sensitive = scan.sensitive_fields.iter().find(|f| {
+                    f.model_name == model.name && f.file == model.file && same_identifier(&f.field_name, column)
+                });
+                if let Some(field) = sensitive {
+                    accesses.push(access(table, column.clone(), Some(model.name.clone()), field.sensitivity, field.confidence));
+                }
+            }
+
+            let unmapped_table = stmt.tables.iter().find(|t| !models.iter().any(|(mapped, _)| mapped == t));
+            let Some(table) = unmapped_table.filter(|_| !unmapped.is_empty()) else { continue };
+            let synthetic = ExtractedModel {
+                name: table.clone(),
+                table_name: Some(table.clone()),
+                file: stmt.file.clone(),
+                line: stmt.line,
+                framework: OrmFramework::Unknown,
+                fields: unmapped.iter().map(|column| ExtractedField {
+                    name: column.to_string(),
+                    field_type: None,
+                    is_primary_key: false,
+                    is_nullable: true,
+                    is_unique: false,
+                    default_value: None,
+                    line: stmt.line,
+                }).collect(),
+                relationships: Vec::new(),
+                confidence: 0.5,
+            };
+            for field in self.sensitive_detector.detect_sensitive_fields(&synthetic) {
+                accesses.push(access(table, field.field_name, None, field.sensitivity, field.confidence));
+            }
+        }
+        accesses
+    }
+
     /// Detect which ORM frameworks are used in the codebase.
     fn detect_frameworks(&self, parse_results: &[ParseResult]) -> Vec<OrmFramework> {
         let mut detected = Vec::new();
@@ -90,6 +162,21 @@ impl Default for BoundaryDetector {
     }
 }
 
+/// Whether `model` maps `table`, by declared table name or model name.
+fn maps_table(model: &ExtractedModel, table: &str) -> bool {
+    let table = table.rsplit('.').next().unwrap_or(table);
+    model.table_name.as_deref().is_some_and(|t| t.eq_ignore_ascii_case(table))
+        || same_identifier(&model.name, table)
+}
+
+/// `passwordHash` and `password_hash` name the same column.
+fn same_identifier(a: &str, b: &str) -> bool {
+    let normalize = |s: &str| -> String {
+        s.chars().filter(|c| *c != '_').flat_map(char::to_lowercase).collect()
+    };
+    normalize(a) == normalize(b)
+}
+
 /// Build framework detection signatures.
 fn build_framework_signatures() -> Vec<FrameworkSignature> {
     vec![
@@ -153,5 +240,65 @@ fn build_framework_signatures() -> Vec<FrameworkSignature> {
             decorator_patterns: vec![],
             schema_file_patterns: vec!["*.php".into()],
         },
+        FrameworkSignature {
+            framework: OrmFramework::Drizzle,
+            import_patterns: vec!["drizzle-orm".into()],
+            decorator_patterns: vec![],
+            schema_file_patterns: vec!["schema.ts".into(), "*.schema.ts".into()],
+        },
+        FrameworkSignature {
+            framework: OrmFramework::Knex,
+            import_patterns: vec!["knex".into()],
+            decorator_patterns: vec![],
+            schema_file_patterns: vec!["migrations/*.js".into(), "migrations/*.ts".into()],
+        },
+        FrameworkSignature {
+            framework: OrmFramework::Gorm,
+            import_patterns: vec!["gorm.io/gorm".into(), "github.com/jinzhu/gorm".into()],
+            decorator_patterns: vec![],
+            schema_file_patterns: vec!["*.go".into()],
+        },
+        FrameworkSignature {
+            framework: OrmFramework::Ent,
+            import_patterns: vec!["entgo.io/ent".into()],
+            decorator_patterns: vec![],
+            schema_file_patterns: vec!["ent/schema/*.go".into()],
+        },
+        FrameworkSignature {
+            framework: OrmFramework::Diesel,
+            import_patterns: vec!["diesel".into()],
+            decorator_patterns: vec!["diesel".into()],
+            schema_file_patterns: vec!["models.rs".into(), "schema.rs".into()],
+        },
+        FrameworkSignature {
+            framework: OrmFramework::SeaOrm,
+            import_patterns: vec!["sea_orm".into()],
+            decorator_patterns: vec!["sea_orm".into()],
+            schema_file_patterns: vec!["entity/*.rs".into()],
+        },
+        FrameworkSignature {
+            framework: OrmFramework::Dapper,
+            import_patterns: vec!["Dapper".into()],
+            decorator_patterns: vec!["Table".into(), "Key".into()],
+            schema_file_patterns: vec!["*.cs".into()],
+        },
+        FrameworkSignature {
+            framework: OrmFramework::Doctrine,
+            import_patterns: vec!["Doctrine\\ORM".into()],
+            decorator_patterns: vec!["Entity".into(), "Column".into()],
+            schema_file_patterns: vec!["src/Entity/*.php".into()],
+        },
+        FrameworkSignature {
+            framework: OrmFramework::MyBatis,
+            import_patterns: vec!["org.apache.ibatis".into(), "com.baomidou.mybatisplus".into()],
+            decorator_patterns: vec!["Mapper".into(), "TableName".into()],
+            schema_file_patterns: vec!["*Mapper.java".into(), "*Mapper.xml".into()],
+        },
+        FrameworkSignature {
+            framework: OrmFramework::Jooq,
+            import_patterns: vec!["org.jooq".into()],
+            decorator_patterns: vec![],
+            schema_file_patterns: vec!["*.java".into()],
+        },
     ]
 }
@@ -0,0 +1,105 @@
+//! Dapper field extractor (C#).
+
+use crate::parsers::types::{ClassInfo, ClassKind, ParseResult};
+use super::{FieldExtractor, ExtractedModel, OrmFramework};
+use super::support::{annotation_args, annotation_name, first_positional, plural};
+use crate::boundaries::types::{ExtractedField, Relationship, RelationshipKind};
+
+pub struct DapperExtractor;
+
+/// Dapper.Contrib mapping attributes.
+const CONTRIB_ATTRIBUTES: &[&str] = &["Table", "Key", "ExplicitKey", "Computed", "Write"];
+
+const QUERY_METHODS: &[&str] = &[
+    "Query", "QueryAsync", "QueryFirst", "QueryFirstAsync", "QueryFirstOrDefault",
+    "QueryFirstOrDefaultAsync", "QuerySingle", "QuerySingleAsync", "QuerySingleOrDefault",
+    "QuerySingleOrDefaultAsync", "Get", "GetAsync", "GetAll", "GetAllAsync",
+];
+
+impl FieldExtractor for DapperExtractor {
+    fn framework(&self) -> OrmFramework { OrmFramework::Dapper }
+    fn schema_file_patterns(&self) -> &[&str] { &["*.cs"] }
+
+    fn extract_models(&self, pr: &ParseResult) -> Vec<ExtractedModel> {
+        // Dapper maps plain classes: those carrying Dapper.Contrib attributes,
+        // and those this file queries as `connection.Query<User>(...)`.
+        let queried: Vec<&str> = pr.call_sites.iter()
+            .filter_map(|c| generic_arguments(&c.callee_name))
+            .flat_map(|args| args.split(',').map(str::trim))
+            .collect();
+        let models: Vec<&ClassInfo> = pr.classes.iter()
+            .filter(|c| matches!(c.class_kind, ClassKind::Class | ClassKind::Record | ClassKind::Struct))
+            .filter(|c| {
+                queried.contains(&c.name.as_str())
+                    || c.decorators.iter().any(|d| annotation_name(d) == "Table")
+                    || c.properties.iter().flat_map(|p| &p.decorators).any(|d| CONTRIB_ATTRIBUTES.contains(&annotation_name(d)))
+            })
+            .collect();
+        let names: Vec<&str> = models.iter().map(|c| c.name.as_str()).collect();
+
+        models.iter().map(|class| {
+            let mut fields = Vec::new();
+            let mut relationships = Vec::new();
+            for p in &class.properties {
+                let ty = p.type_annotation.as_deref().unwrap_or("");
+                if let Some(rel) = navigation(ty, &names, class) {
+                    relationships.push(rel);
+                    continue;
+                }
+                let is_key = p.decorators.iter().any(|d| matches!(annotation_name(d), "Key" | "ExplicitKey"));
+                fields.push(ExtractedField {
+                    name: p.name.clone(),
+                    field_type: p.type_annotation.clone(),
+                    is_primary_key: is_key || p.name.eq_ignore_ascii_case("id"),
+                    is_nullable: ty.ends_with('?'),
+                    is_unique: false,
+                    default_value: None,
+                    line: p.line,
+                });
+            }
+            let table_name = class.decorators.iter()
+                .find(|d| annotation_name(d) == "Table")
+                .and_then(|d| first_positional(annotation_args(d)))
+                .map(str::to_string)
+                .unwrap_or_else(|| plural(&class.name));
+
+            ExtractedModel {
+                name: class.name.clone(),
+                table_name: Some(table_name),
+                file: pr.file.clone(),
+                line: class.range.start.line,
+                framework: OrmFramework::Dapper,
+                fields,
+                relationships,
+                confidence: 0.80,
+            }
+        }).collect()
+    }
+}
+
+/// `User` from `Query<User>`, `Post, User, Post` from a multi-mapping query.
+fn generic_arguments(callee: &str) -> Option<&str> {
+    let (method, rest) = callee.split_once('<')?;
+    QUERY_METHODS.contains(&method).then(|| rest.trim_end_matches('>'))
+}
+
+/// Properties typed as another model, filled by multi-mapping queries.
+fn navigation(ty: &str, models: &[&str], class: &ClassInfo) -> Option<Relationship> {
+    let ty = ty.trim_end_matches('?');
+    let element = ["List<", "IList<", "IEnumerable<", "ICollection<", "IReadOnlyList<"].iter()
+        .find_map(|prefix| ty.strip_prefix(prefix)?.strip_suffix('>'))
+        .or_else(|| ty.strip_suffix("[]"));
+    if let Some(element) = element.filter(|e| models.contains(e)) {
+        return Some(Relationship { kind: RelationshipKind::HasMany, target_model: element.to_string(), foreign_key: None });
+    }
+    if !models.contains(&ty) {
+        return None;
+    }
+    let key = format!("{ty}Id");
+    let holds_key = class.properties.iter().any(|p| p.name == key);
+    Some(Relationship {
+        kind: if holds_key { RelationshipKind::BelongsTo } else { RelationshipKind::HasOne },
+        target_model: ty.to_string(),
+        foreign_key: holds_key.then_some(key),
+    })
+}
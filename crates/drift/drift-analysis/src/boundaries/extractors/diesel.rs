@@ -0,0 +1,91 @@
+//! Diesel field extractor (Rust).
+
+use crate::parsers::types::{ClassKind, DecoratorInfo, ParseResult};
+use super::{FieldExtractor, ExtractedModel, OrmFramework};
+use super::support::{annotation_args, annotation_name, arg_value, first_positional, nested_args, plural, snake_case};
+use crate::boundaries::types::{ExtractedField, Relationship, RelationshipKind};
+
+pub struct DieselExtractor;
+
+const DERIVES: &[&str] = &[
+    "Queryable", "QueryableByName", "Selectable", "Insertable", "Identifiable", "AsChangeset",
+    "Associations",
+];
+
+impl FieldExtractor for DieselExtractor {
+    fn framework(&self) -> OrmFramework { OrmFramework::Diesel }
+    fn schema_file_patterns(&self) -> &[&str] { &["schema.rs", "models.rs", "models/*.rs"] }
+
+    fn extract_models(&self, pr: &ParseResult) -> Vec<ExtractedModel> {
+        let mut models = Vec::new();
+        for class in pr.classes.iter().filter(|c| c.class_kind == ClassKind::Struct) {
+            let derives_diesel = class.decorators.iter()
+                .filter(|d| annotation_name(d) == "derive")
+                .any(|d| annotation_args(d).split(',').any(|t| DERIVES.contains(&t.trim().rsplit("::").next().unwrap_or(""))));
+            // `#[diesel(...)]`, or the pre-2.0 `#[table_name = "users"]` forms.
+            let options: Vec<&str> = class.decorators.iter()
+                .filter(|d| annotation_name(d) == "diesel")
+                .map(annotation_args)
+                .collect();
+            let legacy: Vec<&DecoratorInfo> = class.decorators.iter()
+                .filter(|d| matches!(annotation_name(d), "table_name" | "primary_key" | "belongs_to"))
+                .collect();
+            if !derives_diesel && options.is_empty() {
+                continue;
+            }
+
+            let table_name = options.iter().find_map(|o| arg_value(o, "table_name"))
+                .or_else(|| legacy.iter().find(|d| annotation_name(d) == "table_name").and_then(|d| arg_value(&d.raw_text, "table_name")))
+                .map(|t| t.rsplit("::").next().unwrap_or(t).to_string())
+                .unwrap_or_else(|| plural(&snake_case(&class.name)));
+            let primary_key: Vec<String> = options.iter().find_map(|o| nested_args(o, "primary_key"))
+                .or_else(|| legacy.iter().find(|d| annotation_name(d) == "primary_key").map(|d| annotation_args(d)))
+                .map(|keys| keys.split(',').map(|k| k.trim().to_string()).collect())
+                .unwrap_or_else(|| vec!["id".to_string()]);
+
+            let fields = class.properties.iter().map(|p| {
+                let column = p.decorators.iter()
+                    .filter(|d| annotation_name(d) == "diesel")
+                    .find_map(|d| arg_value(annotation_args(d), "column_name"))
+                    .unwrap_or(&p.name);
+                ExtractedField {
+                    name: p.name.clone(),
+                    field_type: p.type_annotation.clone(),
+                    is_primary_key: primary_key.iter().any(|k| k == column),
+                    is_nullable: p.type_annotation.as_deref().is_some_and(|t| t.starts_with("Option<")),
+                    is_unique: false,
+                    default_value: None,
+                    line: p.line,
+                }
+            }).collect();
+
+            // `belongs_to(User)` or `belongs_to(User, foreign_key = author_id)`.
+            let relationships = options.iter().filter_map(|o| nested_args(o, "belongs_to"))
+                .chain(legacy.iter().filter(|d| annotation_name(d) == "belongs_to").map(|d| annotation_args(d)))
+                .filter_map(|args| {
+                    let target = first_positional(args)?;
+                    let target = target.rsplit("::").next().unwrap_or(target);
+                    Some(Relationship {
+                        kind: RelationshipKind::BelongsTo,
+                        target_model: target.to_string(),
+                        foreign_key: Some(arg_value(args, "foreign_key")
+                            .map(str::to_string)
+                            .unwrap_or_else(|| format!("{}_id", snake_case(target)))),
+                    })
+                })
+                .collect();
+
+            models.push(ExtractedModel {
+                name: class.name.clone(),
+                table_name: Some(table_name),
+                file: pr.file.clone(),
+                line: class.range.start.line,
+                framework: OrmFramework::Diesel,
+                fields,
+                relationships,
+                confidence: if derives_diesel { 0.90 } else { 0.80 },
+            });
+        }
+        models
+    }
+}
@@ -0,0 +1,140 @@
+//! Doctrine ORM field extractor (PHP).
+
+use crate::parsers::types::ParseResult;
+use super::{FieldExtractor, ExtractedModel, OrmFramework};
+use super::support::{annotation_args, annotation_name, arg_value, first_positional, is_set, snake_case, unquote};
+use crate::boundaries::types::{ExtractedField, Relationship, RelationshipKind};
+
+pub struct DoctrineExtractor;
+
+impl FieldExtractor for DoctrineExtractor {
+    fn framework(&self) -> OrmFramework { OrmFramework::Doctrine }
+    fn schema_file_patterns(&self) -> &[&str] { &["src/Entity/*.php", "*.php"] }
+
+    fn extract_models(&self, pr: &ParseResult) -> Vec<ExtractedModel> {
+        let mut models = Vec::new();
+        for class in &pr.classes {
+            let class_mapping = mapping(pr, &class.decorators, class.range.start.line);
+            if !class_mapping.iter().any(|(name, _)| name == "Entity") {
+                continue;
+            }
+            let table_name = class_mapping.iter()
+                .find(|(name, _)| name == "Table")
+                .and_then(|(_, args)| arg_value(args, "name").or_else(|| first_positional(args)))
+                .map(str::to_string)
+                .unwrap_or_else(|| snake_case(&class.name));
+
+            let members: Vec<_> = class.properties.iter()
+                .map(|p| (p, mapping(pr, &p.decorators, p.line)))
+                .collect();
+            // Unmapped properties are not columns, unless nothing is mapped
+            // (e.g. XML or YAML mapping files).
+            let any_mapped = members.iter().any(|(_, m)| m.iter().any(|(name, _)| name == "Column" || name == "Id"));
+
+            let mut fields = Vec::new();
+            let mut relationships = Vec::new();
+            for (p, member) in &members {
+                let find = |wanted: &str| member.iter().find(|(name, _)| name == wanted).map(|(_, args)| args.as_str());
+                if let Some(rel) = relationship(member, p.type_annotation.as_deref()) {
+                    relationships.push(rel);
+                    continue;
+                }
+                let column = find("Column");
+                let is_id = find("Id").is_some();
+                if any_mapped && column.is_none() && !is_id {
+                    continue;
+                }
+                let column = column.unwrap_or("");
+                fields.push(ExtractedField {
+                    name: p.name.clone(),
+                    field_type: arg_value(column, "type").map(str::to_string).or_else(|| p.type_annotation.clone()),
+                    is_primary_key: is_id || (!any_mapped && p.name == "id"),
+                    is_nullable: is_set(column, "nullable"),
+                    is_unique: is_set(column, "unique"),
+                    default_value: None,
+                    line: p.line,
+                });
+            }
+
+            models.push(ExtractedModel {
+                name: class.name.clone(),
+                table_name: Some(table_name),
+                file: pr.file.clone(),
+                line: class.range.start.line,
+                framework: OrmFramework::Doctrine,
+                fields,
+                relationships,
+                confidence: 0.90,
+            });
+        }
+        models
+    }
+}
+
+/// Mapping annotations as (name, arguments): PHP 8 attributes, or
+/// `@ORM\...` annotations in the docblock ending just above `line`.
+fn mapping(
+    pr: &ParseResult,
+    attributes: &[crate::parsers::types::DecoratorInfo],
+    line: u32,
+) -> Vec<(String, String)> {
+    let mut found: Vec<(String, String)> = attributes.iter()
+        .map(|d| (annotation_name(d).to_string(), annotation_args(d).to_string()))
+        .collect();
+    if let Some(doc) = pr.doc_comments.iter().find(|d| d.range.end.line + 1 == line) {
+        found.extend(docblock_annotations(&doc.text));
+    }
+    found
+}
+
+/// `@ORM\Column(type="string", unique=true)` → ("Column", `type="string", unique=true`).
+fn docblock_annotations(text: &str) -> Vec<(String, String)> {
+    let mut found = Vec::new();
+    let mut rest = text;
+    while let Some(at) = rest.find('@') {
+        rest = &rest[at + 1..];
+        let path_len = rest.find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '\\')).unwrap_or(rest.len());
+        let path = &rest[..path_len];
+        let name = path.rsplit('\\').next().unwrap_or(path);
+        rest = &rest[path_len..];
+        let mut args = String::new();
+        if let Some(inner) = rest.strip_prefix('(') {
+            let mut depth = 1;
+            let close = inner.char_indices().find(|&(_, c)| {
+                depth += match c { '(' => 1, ')' => -1, _ => 0 };
+                depth == 0
+            }).map_or(inner.len(), |(i, _)| i);
+            args = inner[..close].split('*').map(str::trim).collect::<Vec<_>>().join(" ");
+            rest = &inner[close..];
+        }
+        if !name.is_empty() {
+            found.push((name.to_string(), args));
+        }
+    }
+    found
+}
+
+fn relationship(member: &[(String, String)], property_type: Option<&str>) -> Option<Relationship> {
+    let has_join_column = member.iter().any(|(name, _)| name == "JoinColumn");
+    let (kind, args) = member.iter().find_map(|(name, args)| {
+        let kind = match name.as_str() {
+            "ManyToOne" => RelationshipKind::BelongsTo,
+            "OneToMany" => RelationshipKind::HasMany,
+            // The owning side of a one-to-one holds the join column.
+            "OneToOne" if has_join_column => RelationshipKind::BelongsTo,
+            "OneToOne" => RelationshipKind::HasOne,
+            "ManyToMany" => RelationshipKind::ManyToMany,
+            _ => return None,
+        };
+        Some((kind, args))
+    })?;
+    let target = arg_value(args, "targetEntity")
+        .map(|t| t.trim_end_matches("::class"))
+        .or(property_type.map(|t| t.trim_start_matches('?')))
+        .map(|t| unquote(t).rsplit('\\').next().unwrap_or(t).to_string())?;
+    let foreign_key = member.iter()
+        .find(|(name, _)| name == "JoinColumn")
+        .and_then(|(_, args)| arg_value(args, "name"))
+        .map(str::to_string);
+    Some(Relationship { kind, target_model: target, foreign_key })
+}
@@ -0,0 +1,107 @@
+//! Drizzle field extractor (TypeScript).
+
+use crate::parsers::types::ParseResult;
+use super::{FieldExtractor, ExtractedModel, OrmFramework};
+use super::support::{call_chains, plural, singular, CallChain};
+use crate::boundaries::types::{ExtractedField, Relationship, RelationshipKind};
+
+pub struct DrizzleExtractor;
+
+const TABLE_FUNCTIONS: &[&str] = &["pgTable", "mysqlTable", "sqliteTable", "singlestoreTable"];
+
+const COLUMN_TYPES: &[&str] = &[
+    "serial", "bigserial", "smallserial", "integer", "int", "smallint", "bigint", "tinyint",
+    "mediumint", "real", "doublePrecision", "double", "float", "numeric", "decimal", "boolean",
+    "text", "varchar", "char", "uuid", "json", "jsonb", "timestamp", "date", "time", "datetime",
+    "interval", "year", "blob", "binary", "varbinary", "inet", "cidr", "macaddr", "mysqlEnum",
+];
+
+impl FieldExtractor for DrizzleExtractor {
+    fn framework(&self) -> OrmFramework { OrmFramework::Drizzle }
+    fn schema_file_patterns(&self) -> &[&str] { &["schema.ts", "*.schema.ts", "db/schema/*.ts"] }
+
+    fn extract_models(&self, pr: &ParseResult) -> Vec<ExtractedModel> {
+        // `export const users = pgTable("users", { email: text("email").notNull() })`:
+        // columns are the builder chains after a table call and before the next.
+        let chains = call_chains(pr);
+        let tables: Vec<(&CallChain, String, String)> = chains.iter()
+            .filter(|c| c.base.receiver.is_none() && TABLE_FUNCTIONS.contains(&c.base.callee_name.as_str()))
+            .filter_map(|c| {
+                let table = c.strings.first()?.to_string();
+                let name = pr.exports.iter()
+                    .find(|e| e.line == c.line())
+                    .and_then(|e| e.name.clone())
+                    .unwrap_or_else(|| table.clone());
+                Some((c, name, table))
+            })
+            .collect();
+
+        tables.iter().enumerate().map(|(i, (table, name, table_name))| {
+            let start = (table.line(), table.base.column);
+            let end = tables.get(i + 1).map_or((u32::MAX, 0), |(next, _, _)| (next.line(), next.base.column));
+            let columns: Vec<&CallChain> = chains.iter()
+                .filter(|c| (c.line(), c.base.column) > start && (c.line(), c.base.column) < end)
+                .filter(|c| c.base.receiver.is_none() && COLUMN_TYPES.contains(&c.base.callee_name.as_str()))
+                .collect();
+
+            let mut fields = Vec::new();
+            let mut relationships = Vec::new();
+            for column in columns {
+                let Some(column_name) = column.strings.first() else { continue };
+                let is_primary_key = column.has("primaryKey");
+                fields.push(ExtractedField {
+                    name: column_name.to_string(),
+                    field_type: Some(column.base.callee_name.clone()),
+                    is_primary_key,
+                    is_nullable: !column.has("notNull") && !is_primary_key,
+                    is_unique: column.has("unique"),
+                    default_value: None,
+                    line: column.line(),
+                });
+                if column.has("references") {
+                    relationships.push(Relationship {
+                        kind: RelationshipKind::BelongsTo,
+                        target_model: reference_target(column.text())
+                            .unwrap_or_else(|| target_from_key(column_name, &tables)),
+                        foreign_key: Some(column_name.to_string()),
+                    });
+                }
+            }
+
+            ExtractedModel {
+                name: name.clone(),
+                table_name: Some(table_name.clone()),
+                file: pr.file.clone(),
+                line: table.line(),
+                framework: OrmFramework::Drizzle,
+                fields,
+                relationships,
+                confidence: 0.90,
+            }
+        }).collect()
+    }
+}
+
+/// `users` from `.references(() => users.id)`, visible when another call
+/// follows it in the chain.
+fn reference_target(text: &str) -> Option<String> {
+    let start = text.find("references(")?;
+    let after = text[start..].find("=>")? + start + 2;
+    let target: String = text[after..].trim_start()
+        .chars()
+        .take_while(|c| c.is_alphanumeric() || *c == '_' || *c == '$')
+        .collect();
+    (!target.is_empty()).then_some(target)
+}
+
+/// Otherwise `author_id` / `authorId` names the table: the one in this file
+/// whose singular matches, or the plural of the key's stem.
+fn target_from_key(column: &str, tables: &[(&CallChain, String, String)]) -> String {
+    let stem = column.strip_suffix("_id").or_else(|| column.strip_suffix("Id")).unwrap_or(column);
+    tables.iter()
+        .find(|(_, name, table)| {
+            singular(name).eq_ignore_ascii_case(stem) || singular(table).eq_ignore_ascii_case(stem)
+        })
+        .map(|(_, name, _)| name.clone())
+        .unwrap_or_else(|| plural(stem))
+}
@@ -0,0 +1,114 @@
+//! Ent field extractor (Go).
+
+use crate::parsers::types::{ClassKind, FunctionInfo, ParseResult};
+use super::{FieldExtractor, ExtractedModel, OrmFramework};
+use super::support::{call_chains, pascal_case, plural, singular, snake_case, CallChain};
+use crate::boundaries::types::{ExtractedField, Relationship, RelationshipKind};
+
+pub struct EntExtractor;
+
+impl FieldExtractor for EntExtractor {
+    fn framework(&self) -> OrmFramework { OrmFramework::Ent }
+    fn schema_file_patterns(&self) -> &[&str] { &["ent/schema/*.go"] }
+
+    fn extract_models(&self, pr: &ParseResult) -> Vec<ExtractedModel> {
+        // Schemas are structs embedding ent.Schema; their Fields() and
+        // Edges() methods follow the struct, so each method belongs to the
+        // nearest schema above it.
+        let schemas: Vec<_> = pr.classes.iter()
+            .filter(|c| c.class_kind == ClassKind::Struct)
+            .filter(|c| c.properties.iter().any(|p| p.name == "ent.Schema"))
+            .collect();
+        if schemas.is_empty() {
+            return Vec::new();
+        }
+        let chains = call_chains(pr);
+
+        schemas.iter().enumerate().map(|(i, schema)| {
+            let start = schema.range.start.line;
+            let end = schemas.get(i + 1).map_or(u32::MAX, |next| next.range.start.line);
+            let owned = |name: &str| -> Vec<&FunctionInfo> {
+                pr.functions.iter()
+                    .filter(|f| f.name == name && f.line > start && f.line < end)
+                    .collect()
+            };
+            let within = |funcs: &[&FunctionInfo], chain: &CallChain| {
+                funcs.iter().any(|f| chain.line() >= f.line && chain.line() <= f.end_line)
+            };
+
+            let field_fns = owned("Fields");
+            let mut fields: Vec<ExtractedField> = chains.iter()
+                .filter(|c| c.base.receiver.as_deref() == Some("field") && within(&field_fns, c))
+                .filter_map(|c| {
+                    let name = c.strings.first()?;
+                    Some(ExtractedField {
+                        name: name.to_string(),
+                        field_type: Some(c.base.callee_name.clone()),
+                        is_primary_key: *name == "id",
+                        is_nullable: c.has("Optional") || c.has("Nillable"),
+                        is_unique: c.has("Unique"),
+                        default_value: None,
+                        line: c.line(),
+                    })
+                })
+                .collect();
+            // Ent adds an integer `id` unless the schema declares one.
+            if !fields.iter().any(|f| f.name == "id") {
+                fields.insert(0, ExtractedField {
+                    name: "id".to_string(),
+                    field_type: Some("Int".to_string()),
+                    is_primary_key: true,
+                    is_nullable: false,
+                    is_unique: true,
+                    default_value: None,
+                    line: start,
+                });
+            }
+
+            let edge_fns = owned("Edges");
+            let relationships = chains.iter()
+                .filter(|c| c.base.receiver.as_deref() == Some("edge") && within(&edge_fns, c))
+                .filter_map(edge_relationship)
+                .collect();
+
+            ExtractedModel {
+                name: schema.name.clone(),
+                table_name: Some(plural(&snake_case(&schema.name))),
+                file: pr.file.clone(),
+                line: start,
+                framework: OrmFramework::Ent,
+                fields,
+                relationships,
+                confidence: 0.90,
+            }
+        }).collect()
+    }
+}
+
+/// `edge.To("posts", Post.Type)` is has-many, or has-one with `.Unique()`;
+/// `edge.From(...).Ref(...)` is its inverse: belongs-to when unique,
+/// otherwise the other side of a many-to-many.
+fn edge_relationship(chain: &CallChain) -> Option<Relationship> {
+    let name = chain.strings.first()?;
+    let unique = chain.has("Unique");
+    let kind = match (chain.base.callee_name.as_str(), unique) {
+        ("To", false) => RelationshipKind::HasMany,
+        ("To", true) => RelationshipKind::HasOne,
+        ("From", true) => RelationshipKind::BelongsTo,
+        ("From", false) => RelationshipKind::ManyToMany,
+        _ => return None,
+    };
+    // The target type is only in the source text when another call follows;
+    // otherwise it is inferred from the edge name.
+    let target = type_argument(chain.text()).unwrap_or_else(|| pascal_case(&singular(name)));
+    let foreign_key = chain.strings.last().filter(|_| chain.has("Field")).map(|s| s.to_string());
+    Some(Relationship { kind, target_model: target, foreign_key })
+}
+
+/// `Post` from `edge.To("posts", Post.Type)`.
+fn type_argument(text: &str) -> Option<String> {
+    let end = text.find(".Type")?;
+    let start = text[..end].rfind(|c: char| !(c.is_alphanumeric() || c == '_')).map_or(0, |i| i + 1);
+    let name = &text[start..end];
+    (!name.is_empty()).then(|| name.to_string())
+}
@@ -0,0 +1,151 @@
+//! GORM field extractor (Go).
+
+use crate::parsers::types::{ClassKind, ParseResult, PropertyInfo};
+use super::{FieldExtractor, ExtractedModel, OrmFramework};
+use super::support::{plural, snake_case};
+use crate::boundaries::types::{ExtractedField, Relationship, RelationshipKind};
+
+pub struct GormExtractor;
+
+impl FieldExtractor for GormExtractor {
+    fn framework(&self) -> OrmFramework { OrmFramework::Gorm }
+    fn schema_file_patterns(&self) -> &[&str] { &["*.go", "models/*.go"] }
+
+    fn extract_models(&self, pr: &ParseResult) -> Vec<ExtractedModel> {
+        let mut models = Vec::new();
+        let structs: Vec<&str> = pr.classes.iter()
+            .filter(|c| c.class_kind == ClassKind::Struct)
+            .map(|c| c.name.as_str())
+            .collect();
+
+        // A GORM model embeds gorm.Model or carries `gorm:"..."` tags.
+        for class in pr.classes.iter().filter(|c| c.class_kind == ClassKind::Struct) {
+            let embeds_model = class.properties.iter().any(is_gorm_model);
+            let tagged = class.properties.iter().any(|p| gorm_tag(p).is_some());
+            if !embeds_model && !tagged {
+                continue;
+            }
+
+            let mut fields = Vec::new();
+            let mut relationships = Vec::new();
+            for p in &class.properties {
+                if is_gorm_model(p) {
+                    fields.extend(embedded_model_fields(p.line));
+                    continue;
+                }
+                let tag = gorm_tag(p).unwrap_or("");
+                if tag.trim() == "-" {
+                    continue;
+                }
+                let ty = p.type_annotation.as_deref().unwrap_or("");
+                if let Some(rel) = relationship(p, ty, tag, &class.properties, &structs) {
+                    relationships.push(rel);
+                    continue;
+                }
+                if is_embedded(p) {
+                    continue;
+                }
+                fields.push(ExtractedField {
+                    name: p.name.clone(),
+                    field_type: p.type_annotation.clone(),
+                    is_primary_key: setting(tag, "primarykey").is_some()
+                        || setting(tag, "primary_key").is_some()
+                        || p.name == "ID",
+                    is_nullable: setting(tag, "not null").is_none()
+                        && (ty.starts_with('*') || ty.starts_with("sql.Null") || ty == "gorm.DeletedAt"),
+                    is_unique: setting(tag, "unique").is_some() || setting(tag, "uniqueindex").is_some(),
+                    default_value: setting(tag, "default").filter(|v| !v.is_empty()).map(str::to_string),
+                    line: p.line,
+                });
+            }
+
+            models.push(ExtractedModel {
+                name: class.name.clone(),
+                table_name: Some(plural(&snake_case(&class.name))),
+                file: pr.file.clone(),
+                line: class.range.start.line,
+                framework: OrmFramework::Gorm,
+                fields,
+                relationships,
+                confidence: if embeds_model { 0.90 } else { 0.80 },
+            });
+        }
+        models
+    }
+}
+
+fn is_gorm_model(p: &PropertyInfo) -> bool {
+    is_embedded(p) && p.type_annotation.as_deref().map(|t| t.trim_start_matches('*')) == Some("gorm.Model")
+}
+
+/// Embedded fields are recorded under their type's name, which `User User`
+/// also matches; such a field is only taken as an association when this
+/// struct holds its key.
+fn is_embedded(p: &PropertyInfo) -> bool {
+    p.type_annotation.as_deref().is_some_and(|t| t.trim_start_matches('*') == p.name)
+}
+
+/// The `gorm:"..."` part of a struct tag.
+fn gorm_tag(p: &PropertyInfo) -> Option<&str> {
+    let tag = p.tag.as_deref()?;
+    let start = tag.find("gorm:\"")? + "gorm:\"".len();
+    let len = tag[start..].find('"')?;
+    Some(&tag[start..start + len])
+}
+
+/// A `key` or `key:value` setting from a GORM tag, matched case-insensitively.
+fn setting<'a>(tag: &'a str, key: &str) -> Option<&'a str> {
+    tag.split(';').find_map(|part| {
+        let (k, v) = part.split_once(':').unwrap_or((part, ""));
+        k.trim().eq_ignore_ascii_case(key).then(|| v.trim())
+    })
+}
+
+/// Associations: slices of models are has-many (or many-to-many with a join
+/// table); a single model is belongs-to when this struct holds its key.
+fn relationship(
+    p: &PropertyInfo,
+    ty: &str,
+    tag: &str,
+    siblings: &[PropertyInfo],
+    structs: &[&str],
+) -> Option<Relationship> {
+    let is_slice = ty.starts_with("[]");
+    let target = ty.trim_start_matches("[]").trim_start_matches('*');
+    let is_type_name = target.chars().next().is_some_and(char::is_uppercase)
+        && target.chars().all(|c| c.is_alphanumeric() || c == '_');
+    if !is_type_name {
+        return None;
+    }
+    let foreign_key = setting(tag, "foreignkey").map(str::to_string);
+    if is_slice {
+        let kind = if setting(tag, "many2many").is_some() { RelationshipKind::ManyToMany } else { RelationshipKind::HasMany };
+        return Some(Relationship { kind, target_model: target.to_string(), foreign_key });
+    }
+    let own_key = foreign_key.clone().unwrap_or_else(|| format!("{}ID", p.name));
+    let holds_key = siblings.iter().any(|s| s.name == own_key);
+    if holds_key {
+        return Some(Relationship { kind: RelationshipKind::BelongsTo, target_model: target.to_string(), foreign_key: Some(own_key) });
+    }
+    if is_embedded(p) || setting(tag, "embedded").is_some() {
+        return None;
+    }
+    let known = structs.contains(&target) || foreign_key.is_some() || setting(tag, "references").is_some();
+    known.then(|| Relationship { kind: RelationshipKind::HasOne, target_model: target.to_string(), foreign_key })
+}
+
+/// Columns contributed by an embedded `gorm.Model`.
+fn embedded_model_fields(line: u32) -> Vec<ExtractedField> {
+    [("ID", "uint"), ("CreatedAt", "time.Time"), ("UpdatedAt", "time.Time"), ("DeletedAt", "gorm.DeletedAt")]
+        .into_iter()
+        .map(|(name, ty)| ExtractedField {
+            name: name.to_string(),
+            field_type: Some(ty.to_string()),
+            is_primary_key: name == "ID",
+            is_nullable: name == "DeletedAt",
+            is_unique: false,
+            default_value: None,
+            line,
+        })
+        .collect()
+}
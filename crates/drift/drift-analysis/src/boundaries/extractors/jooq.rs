@@ -0,0 +1,82 @@
+//! jOOQ field extractor (Java generated table classes).
+
+use crate::parsers::types::ParseResult;
+use super::{FieldExtractor, ExtractedModel, OrmFramework};
+use super::support::snake_case;
+use crate::boundaries::types::{ExtractedField, Relationship, RelationshipKind};
+
+pub struct JooqExtractor;
+
+impl FieldExtractor for JooqExtractor {
+    fn framework(&self) -> OrmFramework { OrmFramework::Jooq }
+    fn schema_file_patterns(&self) -> &[&str] { &["**/tables/*.java"] }
+
+    fn extract_models(&self, pr: &ParseResult) -> Vec<ExtractedModel> {
+        let mut models = Vec::new();
+        // The code generator emits one `TableImpl` subclass per table:
+        // public final TableField<UsersRecord, String> EMAIL =
+        //     createField(DSL.name("email"), SQLDataType.VARCHAR(255).nullable(false), this, "");
+        for class in pr.classes.iter().filter(|c| c.extends.as_deref().is_some_and(|e| e.contains("TableImpl"))) {
+            let fields = class.properties.iter()
+                .filter_map(|p| {
+                    let ty = p.type_annotation.as_deref()?;
+                    let value_type = ty.strip_prefix("TableField<")?.trim_end_matches('>');
+                    let value_type = value_type.split_once(',').map_or(value_type, |(_, v)| v).trim();
+                    let init = p.initializer.as_deref().unwrap_or("");
+                    let column = pr.string_literals.iter()
+                        .filter(|s| s.line == p.line && !s.value.is_empty())
+                        .min_by_key(|s| s.column)
+                        .map_or_else(|| p.name.to_lowercase(), |s| s.value.clone());
+                    Some(ExtractedField {
+                        name: column,
+                        field_type: Some(value_type.to_string()),
+                        is_primary_key: flag_argument(init, "identity") == Some(true),
+                        // Columns are nullable unless generated with `.nullable(false)`.
+                        is_nullable: flag_argument(init, "nullable") != Some(false),
+                        is_unique: false,
+                        default_value: None,
+                        line: p.line,
+                    })
+                })
+                .collect();
+
+            // Implicit join paths: `public Users users()` on the child table.
+            let relationships = class.methods.iter()
+                .filter(|m| m.parameters.is_empty())
+                .filter_map(|m| {
+                    let target = m.return_type.as_deref()?;
+                    (lower_camel(target) == m.name && target != class.name).then(|| Relationship {
+                        kind: RelationshipKind::BelongsTo,
+                        target_model: target.to_string(),
+                        foreign_key: None,
+                    })
+                })
+                .collect();
+
+            models.push(ExtractedModel {
+                name: class.name.clone(),
+                table_name: Some(snake_case(&class.name)),
+                file: pr.file.clone(),
+                line: class.range.start.line,
+                framework: OrmFramework::Jooq,
+                fields,
+                relationships,
+                confidence: 0.85,
+            });
+        }
+        models
+    }
+}
+
+/// The boolean argument of the last `.method(...)` call in `expr`, if it is a literal.
+fn flag_argument(expr: &str, method: &str) -> Option<bool> {
+    let call = format!(".{method}(");
+    let start = expr.rfind(&call)? + call.len();
+    let end = start + expr[start..].find(')')?;
+    expr[start..end].trim().parse().ok()
+}
+
+fn lower_camel(name: &str) -> String {
+    let mut chars = name.chars();
+    chars.next().map(|first| first.to_lowercase().chain(chars).collect()).unwrap_or_default()
+}
@@ -0,0 +1,104 @@
+//! Knex field extractor (JavaScript/TypeScript migrations).
+
+use crate::parsers::types::ParseResult;
+use super::{FieldExtractor, ExtractedModel, OrmFramework};
+use super::support::{call_chains, pascal_case, singular, CallChain};
+use crate::boundaries::types::{ExtractedField, Relationship, RelationshipKind};
+
+pub struct KnexExtractor;
+
+const TABLE_CALLS: &[&str] = &["createTable", "createTableIfNotExists", "alterTable", "table"];
+
+const COLUMN_TYPES: &[&str] = &[
+    "increments", "bigIncrements", "integer", "bigInteger", "tinyint", "smallint", "mediumint",
+    "bigint", "text", "string", "float", "double", "decimal", "boolean", "date", "datetime",
+    "time", "timestamp", "binary", "enu", "enum", "json", "jsonb", "uuid", "specificType",
+];
+
+impl FieldExtractor for KnexExtractor {
+    fn framework(&self) -> OrmFramework { OrmFramework::Knex }
+    fn schema_file_patterns(&self) -> &[&str] { &["migrations/*.js", "migrations/*.ts"] }
+
+    fn extract_models(&self, pr: &ParseResult) -> Vec<ExtractedModel> {
+        // knex.schema.createTable('users', (table) => { table.string('email').notNullable() })
+        let chains = call_chains(pr);
+        let tables: Vec<(&CallChain, &str)> = chains.iter()
+            .filter(|c| TABLE_CALLS.contains(&c.base.callee_name.as_str()))
+            .filter(|c| c.base.receiver.as_deref().is_some_and(|r| r.ends_with("schema")))
+            .filter_map(|c| Some((c, *c.strings.first()?)))
+            .collect();
+
+        tables.iter().enumerate().map(|(i, (table, table_name))| {
+            let start = (table.line(), table.base.column);
+            let end = tables.get(i + 1).map_or((u32::MAX, 0), |(next, _)| (next.line(), next.base.column));
+            let in_table = |c: &&CallChain| (c.line(), c.base.column) > start && (c.line(), c.base.column) < end;
+            let is_builder = |c: &&CallChain| c.base.receiver.as_deref().is_some_and(is_identifier);
+
+            let mut fields = Vec::new();
+            let mut relationships = Vec::new();
+            for chain in chains.iter().filter(in_table).filter(is_builder) {
+                let callee = chain.base.callee_name.as_str();
+                if callee == "timestamps" {
+                    fields.extend(["created_at", "updated_at"].map(|name| ExtractedField {
+                        name: name.to_string(),
+                        field_type: Some("timestamp".to_string()),
+                        is_primary_key: false,
+                        is_nullable: true,
+                        is_unique: false,
+                        default_value: None,
+                        line: chain.line(),
+                    }));
+                    continue;
+                }
+                let Some(column) = chain.strings.first() else { continue };
+                if callee == "foreign" || chain.has("references") {
+                    if let Some(target) = reference_target(chain) {
+                        relationships.push(Relationship {
+                            kind: RelationshipKind::BelongsTo,
+                            target_model: pascal_case(&singular(&target)),
+                            foreign_key: Some(column.to_string()),
+                        });
+                    }
+                }
+                if !COLUMN_TYPES.contains(&callee) {
+                    continue;
+                }
+                let is_primary_key = matches!(callee, "increments" | "bigIncrements") || chain.has("primary");
+                fields.push(ExtractedField {
+                    name: column.to_string(),
+                    field_type: Some(callee.to_string()),
+                    is_primary_key,
+                    is_nullable: !chain.has("notNullable") && !is_primary_key,
+                    is_unique: chain.has("unique"),
+                    default_value: None,
+                    line: chain.line(),
+                });
+            }
+
+            ExtractedModel {
+                name: pascal_case(&singular(table_name)),
+                table_name: Some(table_name.to_string()),
+                file: pr.file.clone(),
+                line: table.line(),
+                framework: OrmFramework::Knex,
+                fields,
+                relationships,
+                confidence: 0.85,
+            }
+        }).collect()
+    }
+}
+
+fn is_identifier(text: &str) -> bool {
+    !text.is_empty() && text.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+}
+
+/// Target table of `.references('users.id')` or `.references('id').inTable('users')`.
+fn reference_target(chain: &CallChain) -> Option<String> {
+    if chain.has("inTable") {
+        return chain.strings.last().map(|s| s.to_string());
+    }
+    chain.strings.iter().skip(1)
+        .find_map(|s| s.split_once('.'))
+        .map(|(table, _)| table.to_string())
+}
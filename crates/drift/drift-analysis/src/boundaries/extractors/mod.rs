@@ -1,4 +1,4 @@
-//! Field extractors for 10 ORM frameworks.
+//! Field extractors for 20 ORM frameworks.
 
 pub mod sequelize;
 pub mod typeorm;
@@ -10,6 +10,18 @@ pub mod mongoose;
 pub mod ef_core;
 pub mod hibernate;
 pub mod eloquent;
+pub mod drizzle;
+pub mod knex;
+pub mod gorm;
+pub mod ent;
+pub mod diesel;
+pub mod sea_orm;
+pub mod dapper;
+pub mod doctrine;
+pub mod mybatis;
+pub mod jooq;
+
+mod support;
 
 use crate::parsers::types::ParseResult;
 use super::types::{ExtractedModel, OrmFramework};
@@ -39,5 +51,15 @@ pub fn create_all_extractors() -> Vec<Box<dyn FieldExtractor>> {
         Box::new(ef_core::EfCoreExtractor),
         Box::new(hibernate::HibernateExtractor),
         Box::new(eloquent::EloquentExtractor),
+        Box::new(drizzle::DrizzleExtractor),
+        Box::new(knex::KnexExtractor),
+        Box::new(gorm::GormExtractor),
+        Box::new(ent::EntExtractor),
+        Box::new(diesel::DieselExtractor),
+        Box::new(sea_orm::SeaOrmExtractor),
+        Box::new(dapper::DapperExtractor),
+        Box::new(doctrine::DoctrineExtractor),
+        Box::new(mybatis::MyBatisExtractor),
+        Box::new(jooq::JooqExtractor),
     ]
 }
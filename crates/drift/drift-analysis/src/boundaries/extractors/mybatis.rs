@@ -0,0 +1,152 @@
+//! MyBatis field extractor (Java): MyBatis-Plus entities and annotated mappers.
+
+use crate::parsers::types::{ClassInfo, FunctionInfo, ParseResult};
+use super::{FieldExtractor, ExtractedModel, OrmFramework};
+use super::support::{annotation_args, annotation_name, arg_value, first_positional, is_set, pascal_case, singular, snake_case};
+use crate::boundaries::types::{ExtractedField, Relationship, RelationshipKind};
+
+pub struct MyBatisExtractor;
+
+impl FieldExtractor for MyBatisExtractor {
+    fn framework(&self) -> OrmFramework { OrmFramework::MyBatis }
+    fn schema_file_patterns(&self) -> &[&str] { &["*Mapper.java", "*.java"] }
+
+    fn extract_models(&self, pr: &ParseResult) -> Vec<ExtractedModel> {
+        let mut models: Vec<ExtractedModel> = pr.classes.iter()
+            .filter(|c| c.decorators.iter().any(|d| annotation_name(d) == "TableName"))
+            .map(|class| plus_entity(pr, class))
+            .collect();
+        // Mapper interface methods surface as top-level functions.
+        let methods = pr.functions.iter().chain(pr.classes.iter().flat_map(|c| &c.methods));
+        for method in methods {
+            let Some(model) = result_map(pr, method) else { continue };
+            // Several methods usually map the same type; keep the first.
+            if !models.iter().any(|m| m.name == model.name) {
+                models.push(model);
+            }
+        }
+        models
+    }
+}
+
+/// `@TableName("users") class User { @TableId Long id; ... }`.
+fn plus_entity(pr: &ParseResult, class: &ClassInfo) -> ExtractedModel {
+    let table_name = class.decorators.iter()
+        .find(|d| annotation_name(d) == "TableName")
+        .map(annotation_args)
+        .and_then(|args| arg_value(args, "value").or_else(|| first_positional(args)))
+        .map(str::to_string)
+        .unwrap_or_else(|| snake_case(&class.name));
+    let fields = class.properties.iter()
+        .filter(|p| !p.is_static)
+        .filter(|p| {
+            // `@TableField(exist = false)` marks a property with no column.
+            !p.decorators.iter()
+                .any(|d| annotation_name(d) == "TableField" && arg_value(annotation_args(d), "exist") == Some("false"))
+        })
+        .map(|p| ExtractedField {
+            name: p.name.clone(),
+            field_type: p.type_annotation.clone(),
+            is_primary_key: p.decorators.iter().any(|d| annotation_name(d) == "TableId") || p.name == "id",
+            is_nullable: false,
+            is_unique: false,
+            default_value: None,
+            line: p.line,
+        })
+        .collect();
+
+    ExtractedModel {
+        name: class.name.clone(),
+        table_name: Some(table_name),
+        file: pr.file.clone(),
+        line: class.range.start.line,
+        framework: OrmFramework::MyBatis,
+        fields,
+        relationships: Vec::new(),
+        confidence: 0.90,
+    }
+}
+
+/// A mapper method's `@Results({@Result(property = "id", column = "id", id = true), ...})`,
+/// describing how rows map onto its return type.
+fn result_map(pr: &ParseResult, method: &FunctionInfo) -> Option<ExtractedModel> {
+    let in_method = |line: u32| line >= method.range.start.line && line <= method.range.end.line;
+    let results: Vec<&str> = pr.decorators.iter()
+        .filter(|d| in_method(d.range.start.line) && annotation_name(d) == "Result")
+        .map(annotation_args)
+        .collect();
+    if results.is_empty() {
+        return None;
+    }
+    let model = element_type(method.return_type.as_deref()?)?;
+    let table_name = pr.decorators.iter()
+        .filter(|d| in_method(d.range.start.line) && annotation_name(d) == "Select")
+        .find_map(|d| from_table(annotation_args(d)));
+
+    let mut fields = Vec::new();
+    let mut relationships = Vec::new();
+    for args in results {
+        let Some(property) = arg_value(args, "property") else { continue };
+        let java_type = arg_value(args, "javaType").map(|t| t.trim_end_matches(".class"));
+        let kind = if arg_value(args, "many").is_some() {
+            Some(RelationshipKind::HasMany)
+        } else if arg_value(args, "one").is_some() {
+            Some(RelationshipKind::HasOne)
+        } else {
+            None
+        };
+        if let Some(kind) = kind {
+            // Collections declare `javaType = List.class`, so the element
+            // type is inferred from the property name.
+            let target = java_type
+                .filter(|t| kind == RelationshipKind::HasOne && !t.is_empty())
+                .map(str::to_string)
+                .unwrap_or_else(|| pascal_case(&singular(property)));
+            relationships.push(Relationship {
+                kind,
+                target_model: target,
+                foreign_key: arg_value(args, "column").map(str::to_string),
+            });
+            continue;
+        }
+        fields.push(ExtractedField {
+            name: property.to_string(),
+            field_type: java_type.map(str::to_string),
+            is_primary_key: is_set(args, "id"),
+            is_nullable: false,
+            is_unique: false,
+            default_value: None,
+            line: method.range.start.line,
+        });
+    }
+
+    Some(ExtractedModel {
+        table_name: table_name.or_else(|| Some(snake_case(&model))),
+        name: model,
+        file: pr.file.clone(),
+        line: method.range.start.line,
+        framework: OrmFramework::MyBatis,
+        fields,
+        relationships,
+        confidence: 0.75,
+    })
+}
+
+/// `User` from `User`, `List<User>` or `Optional<User>`.
+fn element_type(return_type: &str) -> Option<String> {
+    let ty = return_type.trim();
+    let ty = ty.split_once('<').map_or(ty, |(_, inner)| inner.trim_end_matches('>'));
+    let ty = ty.trim();
+    (!ty.is_empty() && ty != "void" && ty.starts_with(char::is_uppercase)).then(|| ty.to_string())
+}
+
+/// Table named in `@Select("SELECT * FROM users WHERE id = #{id}")`.
+fn from_table(sql: &str) -> Option<String> {
+    let upper = sql.to_ascii_uppercase();
+    let at = upper.find(" FROM ")? + " FROM ".len();
+    let table: String = sql[at..].trim_start()
+        .chars()
+        .take_while(|c| c.is_alphanumeric() || *c == '_' || *c == '.')
+        .collect();
+    (!table.is_empty()).then_some(table)
+}
@@ -0,0 +1,86 @@
+//! SeaORM field extractor (Rust).
+
+use crate::parsers::types::{ClassKind, ParseResult};
+use super::{FieldExtractor, ExtractedModel, OrmFramework};
+use super::support::{annotation_args, annotation_name, arg_value, is_set, pascal_case, singular, snake_case};
+use crate::boundaries::types::{ExtractedField, Relationship, RelationshipKind};
+
+pub struct SeaOrmExtractor;
+
+impl FieldExtractor for SeaOrmExtractor {
+    fn framework(&self) -> OrmFramework { OrmFramework::SeaOrm }
+    fn schema_file_patterns(&self) -> &[&str] { &["entity/*.rs", "entities/*.rs"] }
+
+    fn extract_models(&self, pr: &ParseResult) -> Vec<ExtractedModel> {
+        let mut models = Vec::new();
+        for class in pr.classes.iter().filter(|c| c.class_kind == ClassKind::Struct) {
+            let is_entity = class.decorators.iter()
+                .any(|d| annotation_name(d) == "derive" && annotation_args(d).contains("DeriveEntityModel"));
+            if !is_entity {
+                continue;
+            }
+            let table_name = class.decorators.iter()
+                .filter(|d| annotation_name(d) == "sea_orm")
+                .find_map(|d| arg_value(annotation_args(d), "table_name"))
+                .map(str::to_string);
+            // Entities are conventionally a `Model` struct in a module named
+            // after the entity, so the table names the model.
+            let name = match (&table_name, class.name.as_str()) {
+                (Some(table), "Model") => pascal_case(&singular(table)),
+                _ => class.name.clone(),
+            };
+
+            let fields = class.properties.iter().map(|p| {
+                let options: Vec<&str> = p.decorators.iter()
+                    .filter(|d| annotation_name(d) == "sea_orm")
+                    .map(annotation_args)
+                    .collect();
+                let set = |key: &str| options.iter().any(|o| is_set(o, key));
+                ExtractedField {
+                    name: p.name.clone(),
+                    field_type: p.type_annotation.clone(),
+                    is_primary_key: set("primary_key"),
+                    is_nullable: set("nullable") || p.type_annotation.as_deref().is_some_and(|t| t.starts_with("Option<")),
+                    is_unique: set("unique"),
+                    default_value: options.iter().find_map(|o| arg_value(o, "default_value")).map(str::to_string),
+                    line: p.line,
+                }
+            }).collect();
+
+            models.push(ExtractedModel {
+                name,
+                table_name: table_name.or_else(|| Some(snake_case(&class.name))),
+                file: pr.file.clone(),
+                line: class.range.start.line,
+                framework: OrmFramework::SeaOrm,
+                fields,
+                relationships: relations(pr),
+                confidence: 0.90,
+            });
+        }
+        models
+    }
+}
+
+/// Variants of the file's `Relation` enum:
+/// `#[sea_orm(belongs_to = "super::user::Entity", from = "Column::UserId", ...)]`.
+fn relations(pr: &ParseResult) -> Vec<Relationship> {
+    pr.decorators.iter()
+        // Attribute items, not the attributes nested inside them.
+        .filter(|d| d.raw_text.starts_with("#[") && annotation_name(d) == "sea_orm")
+        .filter_map(|d| {
+            let args = annotation_args(d);
+            let (kind, entity) = [
+                ("has_many", RelationshipKind::HasMany),
+                ("has_one", RelationshipKind::HasOne),
+                ("belongs_to", RelationshipKind::BelongsTo),
+            ].into_iter().find_map(|(key, kind)| Some((kind, arg_value(args, key)?)))?;
+            let module = entity.strip_suffix("::Entity").unwrap_or(entity);
+            let target = pascal_case(module.rsplit("::").next().unwrap_or(module));
+            let foreign_key = arg_value(args, "from")
+                .filter(|_| kind == RelationshipKind::BelongsTo)
+                .map(|from| snake_case(from.rsplit("::").next().unwrap_or(from)));
+            Some(Relationship { kind, target_model: target, foreign_key })
+        })
+        .collect()
+}
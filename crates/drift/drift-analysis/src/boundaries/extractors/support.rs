@@ -0,0 +1,218 @@
+//! Helpers shared by the extractors: annotation text, naming conventions
+//! and chained builder calls such as `field.String("email").Unique()`.
+
+use std::collections::BTreeMap;
+
+use crate::parsers::types::{CallSite, DecoratorInfo, ParseResult};
+
+/// Simple name of an annotation, attribute or decorator:
+/// `@ORM\Column(type: 'string')` → `Column`, `#[diesel(table_name = users)]` → `diesel`.
+pub(crate) fn annotation_name(decorator: &DecoratorInfo) -> &str {
+    let raw = decorator.raw_text.trim();
+    let raw = raw.strip_prefix("#[").unwrap_or(raw);
+    let raw = raw.trim_start_matches(['@', '[']).trim_start();
+    let end = raw
+        .find(|c: char| matches!(c, '(' | ')' | ']' | ',') || c.is_whitespace())
+        .unwrap_or(raw.len());
+    let path = &raw[..end];
+    path.rsplit(['\\', '.', ':']).next().unwrap_or(path)
+}
+
+/// Text between an annotation's outermost parentheses.
+pub(crate) fn annotation_args(decorator: &DecoratorInfo) -> &str {
+    let raw = decorator.raw_text.as_str();
+    match (raw.find('('), raw.rfind(')')) {
+        (Some(open), Some(close)) if close > open => &raw[open + 1..close],
+        _ => "",
+    }
+}
+
+/// Value of a `key = value` or `key: value` argument, unquoted.
+pub(crate) fn arg_value<'a>(args: &'a str, key: &str) -> Option<&'a str> {
+    let mut from = 0;
+    while let Some(pos) = args[from..].find(key) {
+        let start = from + pos;
+        from = start + key.len();
+        if args[..start].chars().next_back().is_some_and(is_ident) {
+            continue;
+        }
+        let rest = args[from..].trim_start();
+        let value = match rest.strip_prefix('=') {
+            Some(v) if !v.starts_with('=') => v,
+            _ => match rest.strip_prefix(':') {
+                Some(v) if !v.starts_with(':') => v,
+                _ => continue,
+            },
+        };
+        let value = value.trim_start();
+        return Some(unquote(value[..top_level_end(value)].trim()));
+    }
+    None
+}
+
+/// Arguments of a nested `name(...)` call inside annotation arguments,
+/// e.g. `belongs_to(User, foreign_key = author_id)`.
+pub(crate) fn nested_args<'a>(args: &'a str, name: &str) -> Option<&'a str> {
+    let mut from = 0;
+    while let Some(pos) = args[from..].find(name) {
+        let start = from + pos;
+        from = start + name.len();
+        if args[..start].chars().next_back().is_some_and(is_ident) {
+            continue;
+        }
+        if let Some(inner) = args[from..].trim_start().strip_prefix('(') {
+            return Some(&inner[..top_level_end(inner)]);
+        }
+    }
+    None
+}
+
+/// Whether a flag is set: `unique`, `unique = true` or `unique: true`.
+pub(crate) fn is_set(args: &str, key: &str) -> bool {
+    match arg_value(args, key) {
+        Some(value) => value == "true",
+        None => args.split(|c: char| !is_ident(c)).any(|word| word == key),
+    }
+}
+
+/// First positional (unkeyed) argument, unquoted.
+pub(crate) fn first_positional(args: &str) -> Option<&str> {
+    let first = args[..top_level_end(args)].trim();
+    let keyed = first.find(['=', ':']).is_some_and(|i| {
+        first[..i].chars().all(is_ident) && !first[i + 1..].starts_with(':')
+    });
+    (!first.is_empty() && !keyed).then(|| unquote(first))
+}
+
+fn top_level_end(text: &str) -> usize {
+    let mut depth = 0u32;
+    let mut quote: Option<char> = None;
+    for (i, c) in text.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '(' | '[' | '{') => depth += 1,
+            (None, ')' | ']' | '}') if depth > 0 => depth -= 1,
+            (None, ',' | ')' | ']' | '}') => return i,
+            _ => {}
+        }
+    }
+    text.len()
+}
+
+pub(crate) fn unquote(text: &str) -> &str {
+    text.trim_matches(['"', '\'', '`'])
+}
+
+fn is_ident(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// `UserProfile` → `user_profile`, `authorID` → `author_id`.
+pub(crate) fn snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut out = String::with_capacity(name.len() + 4);
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() {
+            let prev = i.checked_sub(1).map(|p| chars[p]);
+            let next = chars.get(i + 1);
+            let boundary = prev.is_some_and(|p| p.is_lowercase() || p.is_ascii_digit())
+                || (prev.is_some_and(char::is_uppercase) && next.is_some_and(|n| n.is_lowercase()));
+            if boundary && !out.ends_with('_') {
+                out.push('_');
+            }
+            out.extend(c.to_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// `user_profiles` → `UserProfiles`.
+pub(crate) fn pascal_case(name: &str) -> String {
+    name.split(['_', '-', ' '])
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            chars.next().map(|first| first.to_uppercase().chain(chars).collect::<String>()).unwrap_or_default()
+        })
+        .collect()
+}
+
+/// English plural, good enough for table-name conventions.
+pub(crate) fn plural(word: &str) -> String {
+    let lower = word.to_lowercase();
+    if lower.ends_with('y') && !lower.ends_with("ay") && !lower.ends_with("ey") && !lower.ends_with("oy") {
+        format!("{}ies", &word[..word.len() - 1])
+    } else if ["s", "x", "z", "ch", "sh"].iter().any(|s| lower.ends_with(s)) {
+        format!("{word}es")
+    } else {
+        format!("{word}s")
+    }
+}
+
+/// Inverse of [`plural`].
+pub(crate) fn singular(word: &str) -> String {
+    let lower = word.to_lowercase();
+    if lower.ends_with("ies") && word.len() > 3 {
+        format!("{}y", &word[..word.len() - 3])
+    } else if ["ses", "xes", "zes", "ches", "shes"].iter().any(|s| lower.ends_with(s)) {
+        word[..word.len() - 2].to_string()
+    } else if lower.ends_with('s') && !lower.ends_with("ss") && word.len() > 1 {
+        word[..word.len() - 1].to_string()
+    } else {
+        word.to_string()
+    }
+}
+
+/// A chain of calls sharing a start position, such as
+/// `table.string('email').notNullable().unique()`. Tree-sitter nests chained
+/// calls, so each one starts where the innermost (`base`) call does.
+pub(crate) struct CallChain<'a> {
+    pub base: &'a CallSite,
+    pub calls: Vec<&'a CallSite>,
+    /// String literals on the chain's first line, before the next chain.
+    pub strings: Vec<&'a str>,
+}
+
+impl<'a> CallChain<'a> {
+    pub fn line(&self) -> u32 {
+        self.base.line
+    }
+
+    pub fn has(&self, callee: &str) -> bool {
+        self.calls.iter().any(|c| c.callee_name == callee)
+    }
+
+    /// Source text of the chain up to its outermost call, which is the
+    /// longest receiver; the outermost call's own arguments are not included.
+    pub fn text(&self) -> &'a str {
+        self.calls.iter().filter_map(|c| c.receiver.as_deref()).max_by_key(|r| r.len()).unwrap_or("")
+    }
+}
+
+/// Group a file's call sites into chains, in source order.
+pub(crate) fn call_chains(pr: &ParseResult) -> Vec<CallChain<'_>> {
+    let mut by_start: BTreeMap<(u32, u32), Vec<&CallSite>> = BTreeMap::new();
+    for call in &pr.call_sites {
+        by_start.entry((call.line, call.column)).or_default().push(call);
+    }
+    let starts: Vec<(u32, u32)> = by_start.keys().copied().collect();
+    by_start
+        .into_iter()
+        .enumerate()
+        .filter_map(|(i, ((line, column), calls))| {
+            let base = *calls.iter().min_by_key(|c| c.receiver.as_ref().map_or(0, |r| r.len()))?;
+            let end = starts.get(i + 1).filter(|next| next.0 == line).map(|next| next.1);
+            let mut strings: Vec<_> = pr
+                .string_literals
+                .iter()
+                .filter(|s| s.line == line && s.column > column && end.map_or(true, |e| s.column < e))
+                .collect();
+            strings.sort_by_key(|s| s.column);
+            Some(CallChain { base, calls, strings: strings.into_iter().map(|s| s.value.as_str()).collect() })
+        })
+        .collect()
+}
@@ -9,6 +9,6 @@ pub mod detector;
 pub mod sensitive;
 pub mod extractors;
 
-pub use types::{BoundaryScanResult, SensitivityType, OrmFramework, ExtractedModel, ExtractedField};
+pub use types::{BoundaryScanResult, SensitivityType, OrmFramework, ExtractedModel, ExtractedField, SensitiveSqlAccess};
 pub use detector::BoundaryDetector;
 pub use sensitive::SensitiveFieldDetector;
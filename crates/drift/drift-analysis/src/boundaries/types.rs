@@ -66,6 +66,21 @@ pub struct SensitiveField {
     pub matched_pattern: String,
 }
 
+/// A raw SQL statement reading or writing a sensitive column.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SensitiveSqlAccess {
+    pub file: String,
+    pub line: u32,
+    pub table: String,
+    pub column: String,
+    /// `read`, `write` or `ddl`.
+    pub operation: String,
+    /// Model mapped to the table, when an ORM declares one.
+    pub model_name: Option<String>,
+    pub sensitivity: SensitivityType,
+    pub confidence: f32,
+}
+
 /// Sensitivity categories for detected fields.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum SensitivityType {
@@ -9,13 +9,17 @@ use rayon::prelude::*;
 use crate::parsers::types::{CallSite, ParseResult};
 
 use super::di_support;
-use super::resolution::{resolve_call, ResolutionDiagnostics};
-use super::types::{CallEdge, CallGraph, CallGraphStats, FunctionNode};
+use super::module_resolver::{ModuleResolver, ResolvedModule};
+use super::resolution::{resolve_call, resolve_manifest_import, ResolutionDiagnostics};
+use super::type_inference::TypeIndex;
+use super::types::{CallEdge, CallGraph, CallGraphStats, FunctionNode, Resolution};
 
 /// Builder for constructing a call graph from parse results.
 pub struct CallGraphBuilder {
     /// Maximum number of functions before switching to CTE fallback.
     pub in_memory_threshold: usize,
+    /// Resolves import sources through project manifests, when available.
+    module_resolver: Option<ModuleResolver>,
 }
 
 impl CallGraphBuilder {
@@ -23,6 +27,7 @@ impl CallGraphBuilder {
     pub fn new() -> Self {
         Self {
             in_memory_threshold: 500_000,
+            module_resolver: None,
         }
     }
 
@@ -30,9 +35,16 @@ impl CallGraphBuilder {
     pub fn with_threshold(threshold: usize) -> Self {
         Self {
             in_memory_threshold: threshold,
+            module_resolver: None,
         }
     }
 
+    /// Resolve imports through project manifests (`ManifestImport`).
+    pub fn with_module_resolver(mut self, resolver: ModuleResolver) -> Self {
+        self.module_resolver = Some(resolver);
+        self
+    }
+
     /// Build a call graph from a set of parse results.
     ///
     /// Phase 1: Extract all functions into nodes (parallel via rayon).
@@ -63,6 +75,7 @@ impl CallGraphBuilder {
                         is_exported: f.is_exported,
                         signature_hash: f.signature_hash,
                         body_hash: f.body_hash,
+                        complexity: f.complexity,
                     }
                 });
                 top_level
@@ -89,6 +102,7 @@ impl CallGraphBuilder {
                             is_exported: m.is_exported || class.is_exported,
                             signature_hash: m.signature_hash,
                             body_hash: m.body_hash,
+                            complexity: m.complexity,
                         }
                     })
                 })
@@ -146,6 +160,9 @@ impl CallGraphBuilder {
         // CG-RES-05: Detect DI frameworks for DI resolution
         let detected_frameworks = di_support::detect_di_frameworks(parse_results);
 
+        // Class fields, method tables and return types for receiver inference.
+        let type_index = TypeIndex::build(parse_results);
+
         // Phase 2: Resolve call sites into edges
         // Collect all (caller_key, call_site, file) tuples
         let call_entries: Vec<(String, &CallSite, &ParseResult)> = parse_results
@@ -163,6 +180,18 @@ impl CallGraphBuilder {
             })
             .collect();
 
+        // Resolve each file's import sources once, with its governing manifest.
+        let resolved_imports: FxHashMap<&str, (Vec<Option<ResolvedModule>>, Option<&str>)> = match &self.module_resolver {
+            Some(resolver) => parse_results
+                .par_iter()
+                .map(|pr| {
+                    let modules = pr.imports.iter().map(|i| resolver.resolve(&pr.file, &i.source)).collect();
+                    (pr.file.as_str(), (modules, resolver.owning_manifest(&pr.file)))
+                })
+                .collect(),
+            None => FxHashMap::default(),
+        };
+
         let mut resolution_counts: FxHashMap<String, usize> = FxHashMap::default();
         let mut diagnostics = ResolutionDiagnostics::new();
         let mut resolved = 0usize;
@@ -170,6 +199,7 @@ impl CallGraphBuilder {
         for (caller_key, call_site, pr) in &call_entries {
             let caller_language = pr.language.name();
             if let Some(caller_idx) = graph.get_node(caller_key) {
+                let file_imports = resolved_imports.get(pr.file.as_str());
                 // Try standard resolution chain first
                 let resolution_result = resolve_call(
                     call_site,
@@ -182,6 +212,29 @@ impl CallGraphBuilder {
                     &language_index,
                 );
 
+                // A receiver with an inferred type names the exact class, which
+                // beats every name-based match, SameFile included.
+                let resolution_result = match call_site.receiver {
+                    Some(_) => type_index
+                        .resolve(call_site, pr)
+                        .map(|key| (key, Resolution::TypedReceiver))
+                        .or(resolution_result),
+                    None => resolution_result,
+                };
+
+                // Manifest-resolved imports outrank everything below MethodCall.
+                let outranked = resolution_result.as_ref().map_or(true, |(_, r)| {
+                    r.default_confidence() < Resolution::ManifestImport.default_confidence()
+                });
+                let resolution_result = match file_imports {
+                    Some((modules, _)) if outranked => {
+                        resolve_manifest_import(call_site, &pr.imports, modules, &name_index)
+                            .map(|key| (key, Resolution::ManifestImport))
+                            .or(resolution_result)
+                    }
+                    _ => resolution_result,
+                };
+
                 // CG-RES-05: If standard resolution fails, try DI resolution
                 let resolution_result = resolution_result.or_else(|| {
                     if !detected_frameworks.is_empty() {
@@ -200,6 +253,9 @@ impl CallGraphBuilder {
                     resolution_result.as_ref().map(|(_, r)| r),
                     caller_language,
                 );
+                if let Some((_, Some(manifest))) = file_imports {
+                    diagnostics.record_manifest(manifest, resolution_result.is_some());
+                }
 
                 if let Some((callee_key, resolution)) = resolution_result {
                     if let Some(callee_idx) = graph.get_node(&callee_key) {
@@ -259,7 +315,7 @@ fn module_name_from_file(file: &str) -> String {
     let normalized = file.replace('\\', "/");
     let filename = normalized.rsplit('/').next().unwrap_or(&normalized);
     // Strip extension
-    for ext in &[".ts", ".tsx", ".js", ".jsx", ".py", ".java", ".go", ".rs", ".rb", ".php", ".cs", ".kt"] {
+    for ext in &[".ts", ".tsx", ".js", ".jsx", ".py", ".java", ".go", ".rs", ".rb", ".php", ".cs", ".kt", ".cpp", ".cc", ".cxx", ".hpp", ".hxx", ".hh", ".c", ".h", ".swift", ".scala"] {
         if let Some(stripped) = filename.strip_suffix(ext) {
             return stripped.to_string();
         }
@@ -102,3 +102,16 @@ pub fn resolve_di_injection(
     }
     None
 }
+
+/// The provider named by a DI parameter default: FastAPI's
+/// `Depends(get_repo)`, NestJS/ASP.NET-style `Inject(UserRepo)`.
+pub fn injected_provider(default_value: &str) -> Option<&str> {
+    let (call, args) = default_value.trim().split_once('(')?;
+    let call = call.rsplit('.').next().unwrap_or(call);
+    if !DI_FRAMEWORKS.iter().any(|fw| fw.injection_decorators.contains(&call.trim())) {
+        return None;
+    }
+    let provider = args.trim_end_matches(')').split(',').next()?.trim();
+    let provider = provider.strip_prefix("dependency=").unwrap_or(provider).trim();
+    (!provider.is_empty()).then_some(provider)
+}
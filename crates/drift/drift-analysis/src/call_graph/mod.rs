@@ -1,4 +1,4 @@
-//! Call Graph Builder — petgraph StableGraph, 6 resolution strategies, SQLite CTE fallback.
+//! Call Graph Builder — petgraph StableGraph, 8 resolution strategies, SQLite CTE fallback.
 //!
 //! Performance targets: Build <5s for 10K files, BFS <5ms, SQLite CTE <50ms.
 
@@ -9,9 +9,13 @@ pub mod traversal;
 pub mod cte_fallback;
 pub mod incremental;
 pub mod di_support;
+pub mod module_resolver;
+pub mod type_inference;
 
 pub use types::{CallGraph, FunctionNode, CallEdge, Resolution, CallGraphStats};
 pub use builder::CallGraphBuilder;
-pub use resolution::{ResolutionDiagnostics, is_fuzzy_blocked, resolve_call, resolve_constructor};
+pub use module_resolver::{ManifestKind, ModuleResolver, ModuleTarget, ResolvedModule};
+pub use resolution::{ResolutionDiagnostics, is_fuzzy_blocked, resolve_call, resolve_constructor, resolve_manifest_import};
+pub use type_inference::TypeIndex;
 pub use traversal::{bfs_forward, bfs_inverse, detect_entry_points};
 pub use incremental::IncrementalCallGraph;
@@ -0,0 +1,971 @@
+//! Manifest-aware module resolution.
+//!
+//! Maps an import source to a project file, a project package directory or
+//! an external package, using the project's manifests: tsconfig/jsconfig
+//! `paths` and `baseUrl`, package.json `exports` and workspace packages,
+//! Python source roots from pyproject.toml/setup.cfg, Go module paths from
+//! go.mod and Cargo crate names. Relative imports resolve against the
+//! importing file.
+
+use std::path::Path;
+
+use drift_core::types::collections::{FxHashMap, FxHashSet};
+use serde::{Deserialize, Serialize};
+
+/// Manifests larger than this are skipped.
+const MAX_MANIFEST_BYTES: u64 = 1024 * 1024;
+
+/// Directories never searched for manifests.
+const SKIPPED_DIRS: &[&str] = &["node_modules", "target", "vendor", ".git", "dist", "build", "__pycache__", ".venv", "venv"];
+
+const JS_EXTENSIONS: &[&str] = &[".ts", ".tsx", ".d.ts", ".js", ".jsx", ".mjs", ".cjs", ".mts", ".cts"];
+
+const NODE_BUILTINS: &[&str] = &[
+    "assert", "buffer", "child_process", "cluster", "crypto", "dns", "events", "fs", "fs/promises",
+    "http", "http2", "https", "net", "os", "path", "process", "querystring", "readline", "stream",
+    "timers", "tls", "url", "util", "vm", "worker_threads", "zlib",
+];
+
+/// Kind of project manifest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ManifestKind {
+    /// tsconfig.json / jsconfig.json.
+    TsConfig,
+    /// package.json.
+    PackageJson,
+    /// pyproject.toml, setup.cfg or setup.py.
+    PythonProject,
+    /// go.mod.
+    GoMod,
+    /// Cargo.toml.
+    CargoToml,
+}
+
+impl ManifestKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::TsConfig => "tsconfig",
+            Self::PackageJson => "package_json",
+            Self::PythonProject => "python_project",
+            Self::GoMod => "go_mod",
+            Self::CargoToml => "cargo_toml",
+        }
+    }
+}
+
+impl std::fmt::Display for ManifestKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// What an import source resolves to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ModuleTarget {
+    /// A file in the project.
+    File(String),
+    /// A package directory in the project (Go packages).
+    Directory(String),
+    /// A dependency or standard-library package outside the project.
+    Package(String),
+}
+
+/// A resolved import source.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResolvedModule {
+    pub target: ModuleTarget,
+    /// Manifest that governs the importing file, if any.
+    pub manifest: Option<String>,
+}
+
+/// tsconfig `paths`: pattern → target patterns.
+type PathMappings = Vec<(String, Vec<String>)>;
+
+#[derive(Clone)]
+struct TsConfig {
+    dir: String,
+    path: String,
+    base_url: Option<String>,
+    /// Directory `paths` targets are relative to.
+    paths_base: String,
+    paths: PathMappings,
+}
+
+#[derive(Clone)]
+struct NodePackage {
+    dir: String,
+    path: String,
+    name: Option<String>,
+    exports: Option<serde_json::Value>,
+    main: Option<String>,
+    dependencies: FxHashSet<String>,
+}
+
+#[derive(Clone)]
+struct PythonProject {
+    dir: String,
+    path: String,
+    roots: Vec<String>,
+    dependencies: FxHashSet<String>,
+}
+
+#[derive(Clone)]
+struct GoModule {
+    dir: String,
+    path: String,
+    module: String,
+    requires: Vec<String>,
+}
+
+#[derive(Clone)]
+struct Crate {
+    dir: String,
+    path: String,
+    name: Option<String>,
+    root_file: Option<String>,
+    dependencies: FxHashSet<String>,
+}
+
+/// Resolves import sources using the project's manifests.
+#[derive(Clone, Default)]
+pub struct ModuleResolver {
+    /// Absolute project root, when built with [`ModuleResolver::from_root`].
+    root: Option<String>,
+    files: FxHashSet<String>,
+    dirs: FxHashSet<String>,
+    tsconfigs: Vec<TsConfig>,
+    packages: Vec<NodePackage>,
+    python: Vec<PythonProject>,
+    go: Vec<GoModule>,
+    crates: Vec<Crate>,
+}
+
+impl ModuleResolver {
+    /// Discover files and manifests under `root`, honouring .gitignore and
+    /// skipping dependency and build directories.
+    pub fn from_root(root: &Path) -> Self {
+        let mut paths = Vec::new();
+        let mut contents = FxHashMap::default();
+        let walker = ignore::WalkBuilder::new(root)
+            .hidden(true)
+            .filter_entry(|e| !SKIPPED_DIRS.iter().any(|d| e.file_name() == *d))
+            .build();
+        for entry in walker.flatten() {
+            if !entry.file_type().is_some_and(|t| t.is_file()) {
+                continue;
+            }
+            let Ok(relative) = entry.path().strip_prefix(root) else { continue };
+            let relative = relative.to_string_lossy().replace('\\', "/");
+            if is_manifest(&relative) && entry.metadata().is_ok_and(|m| m.len() <= MAX_MANIFEST_BYTES) {
+                if let Ok(content) = std::fs::read_to_string(entry.path()) {
+                    contents.insert(relative.clone(), content);
+                }
+            }
+            paths.push(relative);
+        }
+        let mut resolver =
+            Self::from_files(paths.iter().map(|p| (p.as_str(), contents.get(p).map(String::as_str).unwrap_or(""))));
+        resolver.root = Some(root.to_string_lossy().replace('\\', "/").trim_end_matches('/').to_string());
+        resolver
+    }
+
+    /// Build from `(path, content)` pairs. Paths are project-relative with
+    /// `/` separators; content is only read for manifests.
+    pub fn from_files<'a>(files: impl IntoIterator<Item = (&'a str, &'a str)>) -> Self {
+        let mut resolver = Self::default();
+        let mut manifests = Vec::new();
+        for (path, content) in files {
+            let path = path.trim_start_matches("./");
+            resolver.files.insert(path.to_string());
+            let mut dir = parent_dir(path);
+            while !dir.is_empty() && resolver.dirs.insert(dir.to_string()) {
+                dir = parent_dir(dir);
+            }
+            if is_manifest(path) {
+                manifests.push((path.to_string(), content.to_string()));
+            }
+        }
+        manifests.sort();
+
+        let tsconfig_sources: FxHashMap<&str, &str> = manifests
+            .iter()
+            .filter(|(p, _)| file_name(p).starts_with("tsconfig") || file_name(p) == "jsconfig.json")
+            .map(|(p, c)| (p.as_str(), c.as_str()))
+            .collect();
+        for (path, content) in &manifests {
+            let dir = parent_dir(path).to_string();
+            match file_name(path) {
+                "tsconfig.json" | "jsconfig.json" => {
+                    if let Some(config) = load_tsconfig(path, &tsconfig_sources) {
+                        resolver.tsconfigs.push(config);
+                    }
+                }
+                "package.json" => {
+                    if let Ok(json) = serde_json::from_str::<serde_json::Value>(content) {
+                        resolver.packages.push(NodePackage {
+                            dir,
+                            path: path.clone(),
+                            name: json.get("name").and_then(|n| n.as_str()).map(str::to_string),
+                            exports: json.get("exports").cloned(),
+                            main: ["source", "module", "main"]
+                                .iter()
+                                .find_map(|k| json.get(*k).and_then(|v| v.as_str()))
+                                .map(str::to_string),
+                            dependencies: ["dependencies", "devDependencies", "peerDependencies"]
+                                .iter()
+                                .filter_map(|k| json.get(*k).and_then(|d| d.as_object()))
+                                .flat_map(|d| d.keys().cloned())
+                                .collect(),
+                        });
+                    }
+                }
+                "pyproject.toml" | "setup.cfg" | "setup.py" => {
+                    let project = load_python_project(path, content, &resolver.dirs);
+                    resolver.python.push(project);
+                }
+                "go.mod" => {
+                    if let Some(module) = load_go_mod(path, content) {
+                        resolver.go.push(module);
+                    }
+                }
+                "Cargo.toml" => {
+                    if let Ok(toml) = content.parse::<toml::Table>() {
+                        resolver.crates.push(load_crate(path, &toml, &resolver.files));
+                    }
+                }
+                _ => {}
+            }
+        }
+        resolver
+    }
+
+    /// All manifests, by path.
+    pub fn manifests(&self) -> Vec<(&str, ManifestKind)> {
+        let mut manifests: Vec<(&str, ManifestKind)> = self
+            .tsconfigs
+            .iter()
+            .map(|m| (m.path.as_str(), ManifestKind::TsConfig))
+            .chain(self.packages.iter().map(|m| (m.path.as_str(), ManifestKind::PackageJson)))
+            .chain(self.python.iter().map(|m| (m.path.as_str(), ManifestKind::PythonProject)))
+            .chain(self.go.iter().map(|m| (m.path.as_str(), ManifestKind::GoMod)))
+            .chain(self.crates.iter().map(|m| (m.path.as_str(), ManifestKind::CargoToml)))
+            .collect();
+        manifests.sort_by_key(|(path, _)| *path);
+        manifests
+    }
+
+    /// The nearest manifest governing `file` for its language.
+    pub fn owning_manifest(&self, file: &str) -> Option<&str> {
+        let (file, _) = self.relativize(file);
+        let candidates: Vec<(&str, &str)> = match family(file)? {
+            Family::JavaScript => self
+                .tsconfigs
+                .iter()
+                .map(|m| (m.dir.as_str(), m.path.as_str()))
+                .chain(self.packages.iter().map(|m| (m.dir.as_str(), m.path.as_str())))
+                .collect(),
+            Family::Python => self.python.iter().map(|m| (m.dir.as_str(), m.path.as_str())).collect(),
+            Family::Go => self.go.iter().map(|m| (m.dir.as_str(), m.path.as_str())).collect(),
+            Family::Rust => self.crates.iter().map(|m| (m.dir.as_str(), m.path.as_str())).collect(),
+        };
+        // Deepest directory wins; tsconfig is listed before package.json.
+        candidates
+            .into_iter()
+            .filter(|(dir, _)| is_under(dir, file))
+            .fold(None, |best: Option<(&str, &str)>, c| match best {
+                Some(b) if b.0.len() >= c.0.len() => Some(b),
+                _ => Some(c),
+            })
+            .map(|(_, path)| path)
+    }
+
+    /// Resolve an import source as written in `importer`.
+    ///
+    /// Importers under the project root may be given as absolute paths; the
+    /// resolved file or directory is then absolute too.
+    pub fn resolve(&self, importer: &str, source: &str) -> Option<ResolvedModule> {
+        let (relative, rooted) = self.relativize(importer);
+        let family = family(relative)?;
+        let source = normalize_source(source, family);
+        if source.is_empty() {
+            return None;
+        }
+        let target = match family {
+            Family::JavaScript => self.resolve_js(relative, &source),
+            Family::Python => self.resolve_python(relative, &source),
+            Family::Go => self.resolve_go(&source),
+            Family::Rust => self.resolve_rust(relative, &source),
+        }?;
+        let target = match (target, self.root.as_deref()) {
+            (ModuleTarget::File(file), Some(root)) if rooted => ModuleTarget::File(format!("{root}/{file}")),
+            (ModuleTarget::Directory(dir), Some(root)) if rooted => ModuleTarget::Directory(format!("{root}/{dir}")),
+            (target, _) => target,
+        };
+        Some(ResolvedModule {
+            target,
+            manifest: self.owning_manifest(importer).map(str::to_string),
+        })
+    }
+
+    /// Strip the project root from an absolute path.
+    fn relativize<'a>(&self, path: &'a str) -> (&'a str, bool) {
+        match self.root.as_deref().and_then(|root| path.strip_prefix(root)) {
+            Some(rest) if rest.starts_with('/') => (rest.trim_start_matches('/'), true),
+            _ => (path.trim_start_matches("./"), false),
+        }
+    }
+
+    // ---- JavaScript / TypeScript ----
+
+    fn resolve_js(&self, importer: &str, source: &str) -> Option<ModuleTarget> {
+        if source.starts_with('.') || source.starts_with('/') {
+            let base = join(parent_dir(importer), source.trim_start_matches('/'))?;
+            return self.probe_js(&base).map(ModuleTarget::File);
+        }
+
+        for config in self.tsconfigs_for(importer) {
+            for (pattern, targets) in &config.paths {
+                let Some(capture) = match_wildcard(pattern, source) else { continue };
+                for target in targets {
+                    let Some(base) = join(&config.paths_base, &target.replacen('*', capture, 1)) else { continue };
+                    if let Some(file) = self.probe_js(&base) {
+                        return Some(ModuleTarget::File(file));
+                    }
+                }
+            }
+            if let Some(file) = config.base_url.as_deref().and_then(|b| join(b, source)).and_then(|b| self.probe_js(&b)) {
+                return Some(ModuleTarget::File(file));
+            }
+        }
+
+        let (name, subpath) = split_package_specifier(source);
+        if let Some(package) = self.packages.iter().find(|p| p.name.as_deref() == Some(name)) {
+            if let Some(file) = self.resolve_workspace_package(package, subpath) {
+                return Some(ModuleTarget::File(file));
+            }
+        }
+        let builtin = source.strip_prefix("node:").unwrap_or(source);
+        if source.starts_with("node:") || NODE_BUILTINS.contains(&builtin) {
+            return Some(ModuleTarget::Package(format!("node:{builtin}")));
+        }
+        self.packages
+            .iter()
+            .any(|p| is_under(&p.dir, importer) && p.dependencies.contains(name))
+            .then(|| ModuleTarget::Package(name.to_string()))
+    }
+
+    /// tsconfigs whose directory contains `importer`, nearest first.
+    fn tsconfigs_for(&self, importer: &str) -> Vec<&TsConfig> {
+        let mut configs: Vec<&TsConfig> = self.tsconfigs.iter().filter(|c| is_under(&c.dir, importer)).collect();
+        configs.sort_by_key(|c| std::cmp::Reverse(c.dir.len()));
+        configs
+    }
+
+    fn resolve_workspace_package(&self, package: &NodePackage, subpath: &str) -> Option<String> {
+        let key = if subpath.is_empty() { ".".to_string() } else { format!("./{subpath}") };
+        let exported = package.exports.as_ref().and_then(|e| resolve_exports(e, &key));
+        let entry = match (exported, subpath.is_empty()) {
+            (Some(target), _) => target,
+            (None, true) => package.main.clone().unwrap_or_else(|| "index".to_string()),
+            (None, false) => subpath.to_string(),
+        };
+        let base = join(&package.dir, entry.trim_start_matches("./"))?;
+        self.probe_js(&base).or_else(|| {
+            // Exports usually name build output; map it back to the source tree.
+            let relative = base.strip_prefix(&package.dir).unwrap_or(&base).trim_start_matches('/');
+            ["dist/", "lib/", "build/", "out/"]
+                .iter()
+                .find_map(|out| relative.strip_prefix(out))
+                .and_then(|rest| join(&package.dir, &format!("src/{rest}")))
+                .and_then(|src| self.probe_js(&src))
+        })
+    }
+
+    fn probe_js(&self, base: &str) -> Option<String> {
+        if self.files.contains(base) && JS_EXTENSIONS.iter().any(|e| base.ends_with(e)) {
+            return Some(base.to_string());
+        }
+        // ESM imports name the emitted `.js` file for a `.ts` source.
+        let stem = [".js", ".mjs", ".cjs", ".jsx"]
+            .iter()
+            .find_map(|e| base.strip_suffix(e))
+            .unwrap_or(base);
+        JS_EXTENSIONS
+            .iter()
+            .map(|e| format!("{stem}{e}"))
+            .chain(JS_EXTENSIONS.iter().map(|e| format!("{base}/index{e}")))
+            .find(|candidate| self.files.contains(candidate))
+    }
+
+    // ---- Python ----
+
+    fn resolve_python(&self, importer: &str, source: &str) -> Option<ModuleTarget> {
+        let dots = source.chars().take_while(|c| *c == '.').count();
+        let segments: Vec<&str> = source[dots..].split('.').filter(|s| !s.is_empty()).collect();
+        if dots > 0 {
+            let mut base = parent_dir(importer);
+            for _ in 1..dots {
+                base = parent_dir(base);
+            }
+            return self.probe_python(base, &segments).map(ModuleTarget::File);
+        }
+
+        let mut roots: Vec<&str> = self
+            .python
+            .iter()
+            .filter(|p| is_under(&p.dir, importer))
+            .flat_map(|p| p.roots.iter().map(String::as_str))
+            .collect();
+        roots.extend(self.python.iter().flat_map(|p| p.roots.iter().map(String::as_str)));
+        roots.push("");
+        // `from pkg.mod import name` may name an attribute; try the longest module first.
+        for len in (1..=segments.len()).rev() {
+            for root in &roots {
+                if let Some(file) = self.probe_python(root, &segments[..len]) {
+                    return Some(ModuleTarget::File(file));
+                }
+            }
+        }
+        let top = segments.first()?.to_lowercase().replace('-', "_");
+        self.python
+            .iter()
+            .any(|p| p.dependencies.contains(&top))
+            .then_some(ModuleTarget::Package(top))
+    }
+
+    fn probe_python(&self, root: &str, segments: &[&str]) -> Option<String> {
+        let module = segments.join("/");
+        if module.is_empty() {
+            let init = join(root, "__init__.py")?;
+            return self.files.contains(&init).then_some(init);
+        }
+        let base = join(root, &module)?;
+        [format!("{base}.py"), format!("{base}/__init__.py"), format!("{base}.pyi")]
+            .into_iter()
+            .find(|candidate| self.files.contains(candidate))
+    }
+
+    // ---- Go ----
+
+    fn resolve_go(&self, source: &str) -> Option<ModuleTarget> {
+        for module in &self.go {
+            let rest = if source == module.module {
+                Some("")
+            } else {
+                source.strip_prefix(&module.module).and_then(|r| r.strip_prefix('/'))
+            };
+            if let Some(rest) = rest {
+                let dir = join(&module.dir, rest)?;
+                let has_go_files = self
+                    .files
+                    .iter()
+                    .any(|f| parent_dir(f) == dir && f.ends_with(".go"));
+                return has_go_files.then_some(ModuleTarget::Directory(dir));
+            }
+        }
+        // Standard library paths have no dot in their first element.
+        if !source.split('/').next().unwrap_or_default().contains('.') {
+            return Some(ModuleTarget::Package(source.to_string()));
+        }
+        self.go
+            .iter()
+            .flat_map(|m| m.requires.iter())
+            .filter(|r| source == r.as_str() || source.starts_with(&format!("{r}/")))
+            .max_by_key(|r| r.len())
+            .map(|r| ModuleTarget::Package(r.clone()))
+    }
+
+    // ---- Rust ----
+
+    fn resolve_rust(&self, importer: &str, source: &str) -> Option<ModuleTarget> {
+        let segments: Vec<&str> = source.split("::").filter(|s| !s.is_empty()).collect();
+        let first = *segments.first()?;
+        let owner = self
+            .crates
+            .iter()
+            .filter(|c| c.root_file.is_some() && is_under(&c.dir, importer))
+            .max_by_key(|c| c.dir.len());
+
+        let (krate, path): (&Crate, Vec<&str>) = match first {
+            "crate" => (owner?, segments[1..].to_vec()),
+            "self" | "super" => {
+                let krate = owner?;
+                let mut module = module_path(krate, importer)?;
+                let mut rest = &segments[..];
+                if rest.first() == Some(&"self") {
+                    rest = &rest[1..];
+                }
+                while rest.first() == Some(&"super") {
+                    module.pop()?;
+                    rest = &rest[1..];
+                }
+                module.extend_from_slice(rest);
+                (krate, module)
+            }
+            name => match self.crates.iter().find(|c| c.root_file.is_some() && c.name.as_deref() == Some(name)) {
+                Some(krate) => (krate, segments[1..].to_vec()),
+                None if matches!(name, "std" | "core" | "alloc" | "proc_macro") => {
+                    return Some(ModuleTarget::Package(name.to_string()));
+                }
+                None => {
+                    return self
+                        .crates
+                        .iter()
+                        .any(|c| c.dependencies.contains(name))
+                        .then(|| ModuleTarget::Package(name.to_string()));
+                }
+            },
+        };
+
+        // Descend while segments name module files; the rest are items.
+        let mut file = krate.root_file.clone()?;
+        let mut dir = parent_dir(&file).to_string();
+        for segment in path {
+            let flat = join(&dir, &format!("{segment}.rs"))?;
+            let nested = join(&dir, &format!("{segment}/mod.rs"))?;
+            if self.files.contains(&flat) {
+                file = flat;
+            } else if self.files.contains(&nested) {
+                file = nested;
+            } else {
+                break;
+            }
+            dir = join(&dir, segment)?;
+        }
+        Some(ModuleTarget::File(file))
+    }
+}
+
+/// Module path of a Rust file within its crate (`src/a/b.rs` → `[a, b]`).
+fn module_path<'a>(krate: &Crate, file: &'a str) -> Option<Vec<&'a str>> {
+    let root_dir = parent_dir(krate.root_file.as_deref()?);
+    let relative = file.strip_prefix(root_dir)?.trim_start_matches('/').strip_suffix(".rs")?;
+    let mut segments: Vec<&str> = relative.split('/').collect();
+    if matches!(segments.last(), Some(&"mod")) || (segments.len() == 1 && matches!(segments[0], "lib" | "main")) {
+        segments.pop();
+    }
+    Some(segments)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Family {
+    JavaScript,
+    Python,
+    Go,
+    Rust,
+}
+
+fn family(file: &str) -> Option<Family> {
+    let extension = file.rsplit_once('.').map(|(_, e)| e)?;
+    match extension {
+        "ts" | "tsx" | "js" | "jsx" | "mjs" | "cjs" | "mts" | "cts" => Some(Family::JavaScript),
+        "py" | "pyi" => Some(Family::Python),
+        "go" => Some(Family::Go),
+        "rs" => Some(Family::Rust),
+        _ => None,
+    }
+}
+
+/// Strip statement text that some parsers leave in the source
+/// (`import a.b`, `use a::b as c;`, quotes).
+fn normalize_source(source: &str, family: Family) -> String {
+    let mut s = source.trim().trim_end_matches(';').trim();
+    for keyword in ["pub use ", "use ", "import ", "from "] {
+        if let Some(rest) = s.strip_prefix(keyword) {
+            s = rest.trim();
+        }
+    }
+    if family == Family::Python {
+        s = s.split(" import ").next().unwrap_or(s);
+        s = s.split(',').next().unwrap_or(s);
+    }
+    if let Some((path, _)) = s.split_once(" as ") {
+        s = path;
+    }
+    if family == Family::Rust {
+        s = s.split("::{").next().unwrap_or(s);
+    }
+    s.trim().trim_matches(['"', '\'', '`']).trim_start_matches("::").to_string()
+}
+
+fn is_manifest(path: &str) -> bool {
+    let name = file_name(path);
+    matches!(
+        name,
+        "package.json" | "jsconfig.json" | "pyproject.toml" | "setup.cfg" | "setup.py" | "go.mod" | "Cargo.toml"
+    ) || (name.starts_with("tsconfig") && name.ends_with(".json"))
+}
+
+fn file_name(path: &str) -> &str {
+    path.rsplit('/').next().unwrap_or(path)
+}
+
+fn parent_dir(path: &str) -> &str {
+    path.rsplit_once('/').map_or("", |(dir, _)| dir)
+}
+
+fn is_under(dir: &str, file: &str) -> bool {
+    dir.is_empty() || file.strip_prefix(dir).is_some_and(|rest| rest.starts_with('/'))
+}
+
+/// Join a relative path onto a directory, resolving `.` and `..`.
+/// `None` if it escapes the project root.
+fn join(dir: &str, relative: &str) -> Option<String> {
+    let mut parts: Vec<&str> = dir.split('/').filter(|p| !p.is_empty()).collect();
+    for part in relative.split('/') {
+        match part {
+            "" | "." => {}
+            ".." => {
+                parts.pop()?;
+            }
+            _ => parts.push(part),
+        }
+    }
+    Some(parts.join("/"))
+}
+
+/// Match a tsconfig `paths` pattern with at most one `*`, returning the capture.
+fn match_wildcard<'a>(pattern: &str, source: &'a str) -> Option<&'a str> {
+    match pattern.split_once('*') {
+        None => (pattern == source).then_some(""),
+        Some((prefix, suffix)) => source
+            .strip_prefix(prefix)
+            .and_then(|rest| rest.strip_suffix(suffix))
+            .filter(|_| source.len() >= prefix.len() + suffix.len()),
+    }
+}
+
+/// `@scope/pkg/sub/path` → (`@scope/pkg`, `sub/path`).
+fn split_package_specifier(source: &str) -> (&str, &str) {
+    let segments = if source.starts_with('@') { 2 } else { 1 };
+    let mut end = 0;
+    for (count, (i, _)) in source.match_indices('/').enumerate() {
+        if count + 1 == segments {
+            end = i;
+            break;
+        }
+    }
+    if end == 0 {
+        (source, "")
+    } else {
+        (&source[..end], &source[end + 1..])
+    }
+}
+
+/// Resolve a package.json `exports` entry for a subpath such as `.` or `./utils`.
+fn resolve_exports(exports: &serde_json::Value, subpath: &str) -> Option<String> {
+    match exports {
+        serde_json::Value::Object(map) if map.keys().any(|k| k.starts_with('.')) => {
+            if let Some(target) = map.get(subpath) {
+                return condition_target(target);
+            }
+            map.iter().find_map(|(key, target)| {
+                let capture = match_wildcard(key, subpath).filter(|_| key.contains('*'))?;
+                condition_target(target).map(|t| t.replacen('*', capture, 1))
+            })
+        }
+        _ if subpath == "." => condition_target(exports),
+        _ => None,
+    }
+}
+
+/// Pick a target from a conditional export, preferring source-like conditions.
+fn condition_target(target: &serde_json::Value) -> Option<String> {
+    match target {
+        serde_json::Value::String(path) => Some(path.clone()),
+        serde_json::Value::Array(options) => options.iter().find_map(condition_target),
+        serde_json::Value::Object(conditions) => ["source", "development", "import", "module", "default", "require", "node", "types"]
+            .iter()
+            .find_map(|c| conditions.get(*c))
+            .and_then(condition_target),
+        _ => None,
+    }
+}
+
+/// Load a tsconfig, following relative `extends` chains.
+fn load_tsconfig(path: &str, sources: &FxHashMap<&str, &str>) -> Option<TsConfig> {
+    let dir = parent_dir(path).to_string();
+    let mut base_url: Option<String> = None;
+    let mut paths: Option<(String, PathMappings)> = None;
+
+    let mut current = path.to_string();
+    for _ in 0..5 {
+        let json: serde_json::Value = serde_json::from_str(&strip_jsonc(sources.get(current.as_str())?)).ok()?;
+        let config_dir = parent_dir(&current).to_string();
+        let options = json.get("compilerOptions");
+        if base_url.is_none() {
+            base_url = options
+                .and_then(|o| o.get("baseUrl"))
+                .and_then(|b| b.as_str())
+                .and_then(|b| join(&config_dir, b));
+        }
+        if paths.is_none() {
+            if let Some(map) = options.and_then(|o| o.get("paths")).and_then(|p| p.as_object()) {
+                let entries = map
+                    .iter()
+                    .map(|(pattern, targets)| {
+                        let targets = targets
+                            .as_array()
+                            .map(|t| t.iter().filter_map(|v| v.as_str()).map(str::to_string).collect())
+                            .unwrap_or_default();
+                        (pattern.clone(), targets)
+                    })
+                    .collect();
+                paths = Some((config_dir.clone(), entries));
+            }
+        }
+        match json.get("extends").and_then(|e| e.as_str()) {
+            Some(parent) if parent.starts_with('.') => {
+                let parent = if parent.ends_with(".json") { parent.to_string() } else { format!("{parent}.json") };
+                current = join(&config_dir, &parent)?;
+            }
+            _ => break,
+        }
+    }
+
+    // Without baseUrl, paths resolve relative to the config that declares them.
+    let (paths_dir, paths) = paths.unwrap_or_default();
+    Some(TsConfig {
+        paths_base: base_url.clone().unwrap_or(paths_dir),
+        dir,
+        path: path.to_string(),
+        base_url,
+        paths,
+    })
+}
+
+/// Strip comments and trailing commas from JSONC.
+fn strip_jsonc(content: &str) -> String {
+    let mut out = String::with_capacity(content.len());
+    let mut chars = content.chars().peekable();
+    let mut in_string = false;
+    while let Some(ch) = chars.next() {
+        if in_string {
+            out.push(ch);
+            if ch == '\\' {
+                if let Some(next) = chars.next() {
+                    out.push(next);
+                }
+            } else if ch == '"' {
+                in_string = false;
+            }
+            continue;
+        }
+        match ch {
+            '"' => {
+                in_string = true;
+                out.push(ch);
+            }
+            '/' if chars.peek() == Some(&'/') => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        out.push('\n');
+                        break;
+                    }
+                }
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut prev = ' ';
+                for c in chars.by_ref() {
+                    if prev == '*' && c == '/' {
+                        break;
+                    }
+                    prev = c;
+                }
+            }
+            _ => out.push(ch),
+        }
+    }
+    // Trailing commas before a closing bracket.
+    let mut cleaned = String::with_capacity(out.len());
+    let mut in_string = false;
+    let bytes: Vec<char> = out.chars().collect();
+    for (i, &ch) in bytes.iter().enumerate() {
+        if ch == '"' && (i == 0 || bytes[i - 1] != '\\') {
+            in_string = !in_string;
+        }
+        if ch == ',' && !in_string {
+            let next = bytes[i + 1..].iter().find(|c| !c.is_whitespace());
+            if matches!(next, Some('}') | Some(']')) {
+                continue;
+            }
+        }
+        cleaned.push(ch);
+    }
+    cleaned
+}
+
+fn load_python_project(path: &str, content: &str, dirs: &FxHashSet<String>) -> PythonProject {
+    let dir = parent_dir(path).to_string();
+    let mut roots: Vec<String> = Vec::new();
+    let mut dependencies = FxHashSet::default();
+    let add_root = |root: &str, roots: &mut Vec<String>| {
+        if let Some(root) = join(&dir, root.trim_matches('/')) {
+            if !roots.contains(&root) {
+                roots.push(root);
+            }
+        }
+    };
+
+    if file_name(path) == "pyproject.toml" {
+        if let Ok(toml) = content.parse::<toml::Table>() {
+            let tool = toml.get("tool");
+            let setuptools = tool.and_then(|t| t.get("setuptools"));
+            if let Some(root) = setuptools
+                .and_then(|s| s.get("package-dir"))
+                .and_then(|d| d.get(""))
+                .and_then(|r| r.as_str())
+            {
+                add_root(root, &mut roots);
+            }
+            for root in setuptools
+                .and_then(|s| s.get("packages"))
+                .and_then(|p| p.get("find"))
+                .and_then(|f| f.get("where"))
+                .and_then(|w| w.as_array())
+                .into_iter()
+                .flatten()
+                .filter_map(|w| w.as_str())
+            {
+                add_root(root, &mut roots);
+            }
+            let poetry = tool.and_then(|t| t.get("poetry"));
+            for package in poetry.and_then(|p| p.get("packages")).and_then(|p| p.as_array()).into_iter().flatten() {
+                if let Some(from) = package.get("from").and_then(|f| f.as_str()) {
+                    add_root(from, &mut roots);
+                }
+            }
+            for package in tool
+                .and_then(|t| t.get("hatch"))
+                .and_then(|h| h.get("build"))
+                .and_then(|b| b.get("targets"))
+                .and_then(|t| t.get("wheel"))
+                .and_then(|w| w.get("packages"))
+                .and_then(|p| p.as_array())
+                .into_iter()
+                .flatten()
+                .filter_map(|p| p.as_str())
+            {
+                add_root(parent_dir(package), &mut roots);
+            }
+
+            let requirements = toml
+                .get("project")
+                .and_then(|p| p.get("dependencies"))
+                .and_then(|d| d.as_array())
+                .into_iter()
+                .flatten()
+                .filter_map(|d| d.as_str())
+                .map(requirement_name);
+            dependencies.extend(requirements);
+            if let Some(deps) = poetry.and_then(|p| p.get("dependencies")).and_then(|d| d.as_table()) {
+                dependencies.extend(deps.keys().map(|k| requirement_name(k)));
+            }
+        }
+    } else if file_name(path) == "setup.cfg" {
+        // [options] package_dir = \n    =src
+        let mut in_package_dir = false;
+        for line in content.lines() {
+            let trimmed = line.trim();
+            if let Some(value) = trimmed.strip_prefix("package_dir") {
+                in_package_dir = true;
+                let value = value.trim_start_matches([' ', '=']).trim();
+                if !value.is_empty() {
+                    add_root(value, &mut roots);
+                }
+                continue;
+            }
+            if in_package_dir && line.starts_with([' ', '\t']) && !trimmed.is_empty() {
+                if let Some(root) = trimmed.strip_prefix('=') {
+                    add_root(root.trim(), &mut roots);
+                }
+                continue;
+            }
+            in_package_dir = false;
+        }
+    }
+
+    // src-layout by convention.
+    if let Some(src) = join(&dir, "src") {
+        if dirs.contains(&src) {
+            add_root("src", &mut roots);
+        }
+    }
+    add_root("", &mut roots);
+
+    PythonProject {
+        dir,
+        path: path.to_string(),
+        roots,
+        dependencies,
+    }
+}
+
+/// Distribution name of a requirement (`requests>=2; python_version<"3"` → `requests`),
+/// normalised to its import spelling.
+fn requirement_name(requirement: &str) -> String {
+    let end = requirement
+        .find(|c: char| !(c.is_alphanumeric() || c == '-' || c == '_' || c == '.'))
+        .unwrap_or(requirement.len());
+    requirement[..end].to_lowercase().replace('-', "_")
+}
+
+fn load_go_mod(path: &str, content: &str) -> Option<GoModule> {
+    let mut module = None;
+    let mut requires = Vec::new();
+    let mut in_require = false;
+    for line in content.lines() {
+        let line = line.split("//").next().unwrap_or_default().trim();
+        if let Some(name) = line.strip_prefix("module ") {
+            module = Some(name.trim().trim_matches('"').to_string());
+        } else if line.starts_with("require (") || line == "require(" {
+            in_require = true;
+        } else if in_require && line == ")" {
+            in_require = false;
+        } else if let Some(spec) = line.strip_prefix("require ") {
+            requires.extend(spec.split_whitespace().next().map(str::to_string));
+        } else if in_require {
+            requires.extend(line.split_whitespace().next().map(str::to_string));
+        }
+    }
+    Some(GoModule {
+        dir: parent_dir(path).to_string(),
+        path: path.to_string(),
+        module: module?,
+        requires,
+    })
+}
+
+fn load_crate(path: &str, toml: &toml::Table, files: &FxHashSet<String>) -> Crate {
+    let dir = parent_dir(path).to_string();
+    let package = toml.get("package");
+    let lib = toml.get("lib");
+    let name = lib
+        .and_then(|l| l.get("name"))
+        .or_else(|| package.and_then(|p| p.get("name")))
+        .and_then(|n| n.as_str())
+        .map(|n| n.replace('-', "_"));
+    let root_file = if package.is_some() {
+        let explicit = lib.and_then(|l| l.get("path")).and_then(|p| p.as_str()).and_then(|p| join(&dir, p));
+        explicit
+            .into_iter()
+            .chain(["src/lib.rs", "src/main.rs"].iter().filter_map(|f| join(&dir, f)))
+            .find(|f| files.contains(f))
+    } else {
+        None
+    };
+    let dependencies = ["dependencies", "dev-dependencies", "build-dependencies"]
+        .iter()
+        .filter_map(|k| toml.get(*k).and_then(|d| d.as_table()))
+        .chain(toml.get("workspace").and_then(|w| w.get("dependencies")).and_then(|d| d.as_table()))
+        .flat_map(|d| d.keys().map(|k| k.replace('-', "_")))
+        .collect();
+    Crate {
+        dir,
+        path: path.to_string(),
+        name,
+        root_file,
+        dependencies,
+    }
+}
@@ -1,10 +1,13 @@
-//! 6 resolution strategies: SameFile, MethodCall, DiInjection, ImportBased, ExportBased, Fuzzy.
+//! 8 resolution strategies: SameFile, TypedReceiver, MethodCall, ManifestImport, DiInjection,
+//! ImportBased, ExportBased, Fuzzy. TypedReceiver is applied by the builder from a
+//! [`TypeIndex`](super::type_inference::TypeIndex).
 //! First match wins — strategies are tried in order of decreasing confidence.
 
 use drift_core::types::collections::FxHashMap;
 
 use crate::parsers::types::{CallSite, ImportInfo};
 
+use super::module_resolver::{ModuleTarget, ResolvedModule};
 use super::types::Resolution;
 
 /// Names too common for fuzzy resolution — matching these produces false positives.
@@ -30,7 +33,8 @@ fn language_family(lang: &str) -> u8 {
         1
     } else if l == "python" {
         2
-    } else if l == "java" || l == "kotlin" {
+    } else if l == "java" || l == "kotlin" || l == "scala" {
+        // JVM languages call each other freely.
         3
     } else if l == "go" {
         4
@@ -42,6 +46,10 @@ fn language_family(lang: &str) -> u8 {
         7
     } else if l == "php" {
         8
+    } else if l == "c" || l == "c++" || l == "cpp" {
+        9
+    } else if l == "swift" {
+        10
     } else {
         0
     }
@@ -55,6 +63,8 @@ pub struct ResolutionDiagnostics {
     pub unresolved: usize,
     pub by_strategy: FxHashMap<String, usize>,
     pub by_language: FxHashMap<String, (usize, usize)>, // (resolved, total)
+    /// Call sites per governing manifest (tsconfig.json, go.mod, ...).
+    pub by_manifest: FxHashMap<String, (usize, usize)>, // (resolved, total)
 }
 
 impl ResolutionDiagnostics {
@@ -76,6 +86,23 @@ impl ResolutionDiagnostics {
         }
     }
 
+    /// Record a call site in a file governed by `manifest`.
+    pub fn record_manifest(&mut self, manifest: &str, resolved: bool) {
+        let entry = self.by_manifest.entry(manifest.to_string()).or_insert((0, 0));
+        entry.1 += 1;
+        if resolved {
+            entry.0 += 1;
+        }
+    }
+
+    /// Resolution rate for call sites governed by `manifest`.
+    pub fn manifest_resolution_rate(&self, manifest: &str) -> Option<f64> {
+        self.by_manifest
+            .get(manifest)
+            .filter(|(_, total)| *total > 0)
+            .map(|(resolved, total)| *resolved as f64 / *total as f64)
+    }
+
     pub fn resolution_rate(&self) -> f64 {
         if self.total_call_sites == 0 {
             0.0
@@ -97,6 +124,17 @@ impl ResolutionDiagnostics {
                 }
             }
         }
+        for (manifest, (resolved, total)) in &self.by_manifest {
+            if *total > 0 {
+                let rate = *resolved as f64 / *total as f64;
+                if rate < 0.30 {
+                    warnings.push(format!(
+                        "Low resolution rate for {}: {:.1}% ({}/{})",
+                        manifest, rate * 100.0, resolved, total
+                    ));
+                }
+            }
+        }
         warnings
     }
 }
@@ -144,6 +182,84 @@ pub fn resolve_call(
     None
 }
 
+/// Manifest import resolution: the callee is bound by an import whose source
+/// the module resolver mapped to a project file or Go package directory.
+///
+/// `resolved` is parallel to `imports`. Package targets never resolve, since
+/// dependencies have no nodes in the graph.
+pub fn resolve_manifest_import(
+    call_site: &CallSite,
+    imports: &[ImportInfo],
+    resolved: &[Option<ResolvedModule>],
+    name_index: &FxHashMap<String, Vec<String>>,
+) -> Option<String> {
+    let callee_name = &call_site.callee_name;
+    for (import, module) in imports.iter().zip(resolved) {
+        let Some(module) = module else { continue };
+        if matches!(module.target, ModuleTarget::Package(_)) {
+            continue;
+        }
+
+        // The name the callee has in the target module, if this import binds it.
+        let exported_name = match call_site.receiver.as_deref() {
+            // utils.foo() via `import * as utils`, `import utils`, Go `util.Do()`
+            Some(receiver) => {
+                let binds_receiver = import
+                    .specifiers
+                    .iter()
+                    .any(|s| s.alias.as_deref().unwrap_or(&s.name) == receiver)
+                    || (import.specifiers.is_empty() && source_binding(&import.source) == receiver);
+                binds_receiver.then_some(callee_name.as_str())
+            }
+            // foo() via `import { foo }` / `from m import foo as f` / `use m::foo`
+            None => import
+                .specifiers
+                .iter()
+                .find(|s| s.alias.as_deref().unwrap_or(&s.name) == callee_name)
+                .map(|s| if s.name == "default" { callee_name.as_str() } else { s.name.as_str() })
+                .or_else(|| {
+                    (import.specifiers.is_empty() && source_binding(&import.source) == callee_name)
+                        .then_some(callee_name.as_str())
+                }),
+        };
+        let Some(exported_name) = exported_name else { continue };
+
+        let Some(keys) = name_index.get(exported_name) else { continue };
+        let found = match &module.target {
+            ModuleTarget::File(file) => {
+                let key = format!("{}::{}", file, exported_name);
+                keys.contains(&key).then_some(key)
+            }
+            ModuleTarget::Directory(dir) => keys
+                .iter()
+                .find(|k| {
+                    k.split("::").next().and_then(|f| f.rsplit_once('/')).map(|(d, _)| d) == Some(dir.as_str())
+                })
+                .cloned(),
+            ModuleTarget::Package(_) => None,
+        };
+        if found.is_some() {
+            return found;
+        }
+    }
+    None
+}
+
+/// The local name an import without specifiers binds: its alias, or the last
+/// path segment (`./utils` → `utils`, `github.com/x/util` → `util`,
+/// `crate::a::helper` → `helper`).
+fn source_binding(source: &str) -> &str {
+    let source = source.trim().trim_end_matches(';').trim();
+    if let Some((_, alias)) = source.rsplit_once(" as ") {
+        return alias.trim();
+    }
+    source
+        .trim_matches(['"', '\'', '`'])
+        .rsplit(['/', '.', ':', ' '])
+        .find(|s| !s.is_empty())
+        .unwrap_or(source)
+}
+
 /// Same-file resolution: callee is in the same file as caller.
 fn resolve_same_file(
     call_site: &CallSite,
@@ -341,7 +457,7 @@ fn normalize_module_path(source: &str) -> String {
         s = s[3..].to_string();
     }
     // Strip common extensions
-    for ext in &[".ts", ".tsx", ".js", ".jsx", ".py", ".java", ".go", ".rs", ".rb", ".php", ".cs", ".kt"] {
+    for ext in &[".ts", ".tsx", ".js", ".jsx", ".py", ".java", ".go", ".rs", ".rb", ".php", ".cs", ".kt", ".cpp", ".cc", ".cxx", ".hpp", ".hxx", ".hh", ".c", ".h", ".swift", ".scala"] {
         if s.ends_with(ext) {
             s = s[..s.len() - ext.len()].to_string();
             break;
@@ -0,0 +1,352 @@
+//! Receiver type inference for method call resolution.
+//!
+//! Infers the class of a call's receiver from what the parsers record about
+//! declarations: local type bindings (`const repo = new UserRepo()`,
+//! `UserRepo repo`, `r := &UserRepo{}`), parameter annotations, DI-injected
+//! parameters, class fields and `this`/`self`. `repo.save()` then resolves to
+//! `UserRepo.save` rather than to any function named `save`.
+
+use drift_core::types::collections::{FxHashMap, FxHashSet};
+
+use crate::parsers::types::{CallSite, ClassInfo, FunctionInfo, ParseResult, TypeBinding, TypeBindingKind};
+
+use super::di_support;
+
+/// Supertype chains deeper than this are not followed.
+const MAX_HIERARCHY_DEPTH: usize = 8;
+
+/// Aliases (`self.repo = repo`) are followed at most this many times.
+const MAX_ALIAS_DEPTH: usize = 3;
+
+/// Project-wide type facts used to infer receiver types.
+#[derive(Debug, Default)]
+pub struct TypeIndex {
+    /// Known class, struct and interface names.
+    types: FxHashSet<String>,
+    /// Class → field → field type.
+    fields: FxHashMap<String, FxHashMap<String, String>>,
+    /// Class → extended and implemented types.
+    supertypes: FxHashMap<String, Vec<String>>,
+    /// Interface or base class → classes that extend or implement it.
+    subtypes: FxHashMap<String, Vec<String>>,
+    /// Type → method → callee keys (`file::Class.method`, Go `file::Method`).
+    methods: FxHashMap<String, FxHashMap<String, Vec<String>>>,
+    /// Function name → file → return type; `None` when definitions in the
+    /// file disagree.
+    function_returns: FxHashMap<String, FxHashMap<String, Option<String>>>,
+    /// Class → method → return type; `None` when overloads disagree.
+    method_returns: FxHashMap<String, FxHashMap<String, Option<String>>>,
+}
+
+impl TypeIndex {
+    /// Collect classes, fields, methods and return types from all files.
+    pub fn build(parse_results: &[ParseResult]) -> Self {
+        let mut index = Self::default();
+        for pr in parse_results {
+            for func in &pr.functions {
+                if let Some(ret) = func.return_type.as_deref() {
+                    let by_file = index.function_returns.entry(func.name.clone()).or_default();
+                    record_return(by_file, &pr.file, normalize_type(ret));
+                }
+            }
+            for class in &pr.classes {
+                for method in &class.methods {
+                    if let Some(ret) = method.return_type.as_deref() {
+                        let by_method = index.method_returns.entry(class.name.clone()).or_default();
+                        record_return(by_method, &method.name, normalize_type(ret));
+                    }
+                }
+            }
+
+            for class in &pr.classes {
+                index.types.insert(class.name.clone());
+                let supertypes: Vec<String> = class
+                    .extends
+                    .as_deref()
+                    .map(supertype_names)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .chain(class.implements.iter().filter_map(|i| normalize_type(i)))
+                    .filter(|s| *s != class.name)
+                    .collect();
+                for supertype in &supertypes {
+                    index.subtypes.entry(supertype.clone()).or_default().push(class.name.clone());
+                }
+                index.supertypes.entry(class.name.clone()).or_default().extend(supertypes);
+
+                let fields = index.fields.entry(class.name.clone()).or_default();
+                for property in &class.properties {
+                    if let Some(ty) = property.type_annotation.as_deref().and_then(normalize_type) {
+                        fields.insert(property.name.clone(), ty);
+                    }
+                }
+                // TypeScript parameter properties: `constructor(private repo: Repo)`.
+                if let Some(constructor) = class.methods.iter().find(|m| m.name == "constructor") {
+                    for param in &constructor.parameters {
+                        if let Some(ty) = param.type_annotation.as_deref().and_then(normalize_type) {
+                            fields.entry(param.name.clone()).or_insert(ty);
+                        }
+                    }
+                }
+
+                let methods = index.methods.entry(class.name.clone()).or_default();
+                for method in &class.methods {
+                    methods
+                        .entry(method.name.clone())
+                        .or_default()
+                        .push(format!("{}::{}.{}", pr.file, class.name, method.name));
+                }
+            }
+
+            // Go methods are top-level functions tied to their receiver's type.
+            for binding in pr.type_bindings.iter().filter(|b| b.kind == TypeBindingKind::Receiver) {
+                let Some(ty) = normalize_type(&binding.type_name) else { continue };
+                let Some(func) = pr.functions.iter().find(|f| f.line == binding.line) else { continue };
+                index.types.insert(ty.clone());
+                index
+                    .methods
+                    .entry(ty)
+                    .or_default()
+                    .entry(func.name.clone())
+                    .or_default()
+                    .push(format!("{}::{}", pr.file, func.name));
+            }
+        }
+
+        // Fields assigned through `this.x = ...` / `self.x = ...` (Python
+        // `__init__`, constructors that assign injected parameters).
+        for pr in parse_results {
+            for binding in &pr.type_bindings {
+                let Some(field) = binding.name.strip_prefix("this.").or_else(|| binding.name.strip_prefix("self.")) else {
+                    continue;
+                };
+                if field.contains('.') {
+                    continue;
+                }
+                let Some(class) = enclosing_class(pr, binding.line) else { continue };
+                let Some(ty) = index.binding_type(binding, pr, 0) else { continue };
+                index.fields.entry(class.name.clone()).or_default().entry(field.to_string()).or_insert(ty);
+            }
+        }
+        index
+    }
+
+    /// Resolve a method call through its receiver's inferred type, returning
+    /// the callee key.
+    pub fn resolve(&self, call_site: &CallSite, pr: &ParseResult) -> Option<String> {
+        let receiver_type = self.receiver_type(call_site, pr)?;
+        self.method_key(&receiver_type, &call_site.callee_name, &pr.file)
+    }
+
+    /// The inferred class of a call's receiver.
+    pub fn receiver_type(&self, call_site: &CallSite, pr: &ParseResult) -> Option<String> {
+        let receiver = call_site.receiver.as_deref()?.trim().replace("?.", ".").replace("!!", "");
+        let receiver = receiver.trim_end_matches('?');
+        if receiver.is_empty() || receiver.contains(['(', '[', ' ', '-', '>', '$']) {
+            return None;
+        }
+        let mut segments = receiver.split('.');
+        let head = segments.next()?;
+        let line = call_site.line;
+        let class = enclosing_class(pr, line);
+
+        let mut ty = match head {
+            "this" | "self" => class.map(|c| c.name.clone())?,
+            name => self
+                .local_type(name, line, pr, 0)
+                .or_else(|| class.and_then(|c| self.field_type(&c.name, name)))?,
+        };
+        for field in segments {
+            ty = self.field_type(&ty, field)?;
+        }
+        Some(ty)
+    }
+
+    /// The type of a local or parameter visible at `line`.
+    fn local_type(&self, name: &str, line: u32, pr: &ParseResult, depth: usize) -> Option<String> {
+        if depth > MAX_ALIAS_DEPTH {
+            return None;
+        }
+        let function = enclosing_function(pr, line);
+        let (start, end) = function.map_or((0, u32::MAX), |f| (f.line, f.end_line));
+
+        // The latest declaration before the use wins.
+        let binding = pr
+            .type_bindings
+            .iter()
+            .rfind(|b| b.name == name && start <= b.line && b.line <= line && b.line <= end);
+        if let Some(ty) = binding.and_then(|b| self.binding_type(b, pr, depth + 1)) {
+            return Some(ty);
+        }
+
+        let param = function?.parameters.iter().find(|p| p.name == name || p.bindings().contains(&name))?;
+        param.type_annotation.as_deref().and_then(normalize_type).or_else(|| {
+            // FastAPI `repo=Depends(get_repo)`: the provider's return type.
+            let provider = di_support::injected_provider(param.default_value.as_deref()?)?;
+            self.call_type(provider, pr, line)
+        })
+    }
+
+    fn binding_type(&self, binding: &TypeBinding, pr: &ParseResult, depth: usize) -> Option<String> {
+        match binding.kind {
+            TypeBindingKind::Declared | TypeBindingKind::Constructed | TypeBindingKind::Receiver => {
+                normalize_type(&binding.type_name)
+            }
+            TypeBindingKind::Call => self.call_type(&binding.type_name, pr, binding.line),
+            TypeBindingKind::Alias => self.local_type(&binding.type_name, binding.line, pr, depth + 1),
+        }
+    }
+
+    /// The type a plain call at `line` returns: a declared return type, a
+    /// constructor call (`UserRepo()`), or Go's `NewUserRepo()` convention.
+    ///
+    /// A method of the enclosing class or a function in the same file shadows
+    /// same-named functions elsewhere; those only count when they all agree.
+    fn call_type(&self, callee: &str, pr: &ParseResult, line: u32) -> Option<String> {
+        if let Some(class) = enclosing_class(pr, line) {
+            for t in self.hierarchy(&class.name) {
+                if let Some(ret) = self.method_returns.get(t.as_str()).and_then(|m| m.get(callee)) {
+                    return ret.clone();
+                }
+            }
+        }
+        if let Some(by_file) = self.function_returns.get(callee) {
+            if let Some(ret) = by_file.get(&pr.file) {
+                return ret.clone();
+            }
+            let mut rets = by_file.values();
+            let first = rets.next().cloned().flatten();
+            return first.filter(|ret| rets.all(|r| r.as_ref() == Some(ret)));
+        }
+        if self.types.contains(callee) {
+            return Some(callee.to_string());
+        }
+        callee
+            .strip_prefix("New")
+            .filter(|ty| self.types.contains(*ty))
+            .map(str::to_string)
+    }
+
+    /// A field's type, searching supertypes.
+    fn field_type(&self, ty: &str, field: &str) -> Option<String> {
+        self.hierarchy(ty)
+            .iter()
+            .find_map(|t| self.fields.get(t.as_str()).and_then(|f| f.get(field)).cloned())
+    }
+
+    /// Find `method` on `ty` or its supertypes; failing that, on the single
+    /// subtype of an interface that implements it.
+    ///
+    /// When several files declare the class, the caller's own file wins;
+    /// otherwise the call is ambiguous and left unresolved.
+    fn method_key(&self, ty: &str, method: &str, caller_file: &str) -> Option<String> {
+        let pick = |keys: &Vec<String>| {
+            let file_of = |key: &String| key.split_once("::").map_or(key.as_str(), |(file, _)| file).to_string();
+            let same_file = format!("{}::", caller_file);
+            if let Some(key) = keys.iter().find(|k| k.starts_with(&same_file)) {
+                return Some(key.clone());
+            }
+            let first = keys.first()?;
+            keys.iter().all(|k| file_of(k) == file_of(first)).then(|| first.clone())
+        };
+        for t in self.hierarchy(ty) {
+            if let Some(keys) = self.methods.get(t.as_str()).and_then(|m| m.get(method)) {
+                return pick(keys);
+            }
+        }
+        let implementations: Vec<&Vec<String>> = self
+            .subtypes
+            .get(ty)
+            .into_iter()
+            .flatten()
+            .filter_map(|sub| self.methods.get(sub).and_then(|m| m.get(method)))
+            .collect();
+        match implementations.as_slice() {
+            [only] => pick(only),
+            _ => None,
+        }
+    }
+
+    /// `ty` followed by its supertypes, breadth-first.
+    fn hierarchy(&self, ty: &str) -> Vec<String> {
+        let mut seen = vec![ty.to_string()];
+        let mut i = 0;
+        while i < seen.len() && seen.len() <= MAX_HIERARCHY_DEPTH {
+            for supertype in self.supertypes.get(&seen[i]).into_iter().flatten() {
+                if !seen.contains(supertype) {
+                    seen.push(supertype.clone());
+                }
+            }
+            i += 1;
+        }
+        seen
+    }
+}
+
+/// Record a definition's return type; `None` once definitions disagree.
+fn record_return(returns: &mut FxHashMap<String, Option<String>>, scope: &str, ret: Option<String>) {
+    returns
+        .entry(scope.to_string())
+        .and_modify(|known| {
+            if *known != ret {
+                *known = None;
+            }
+        })
+        .or_insert(ret);
+}
+
+/// The innermost class whose body contains `line`.
+fn enclosing_class(pr: &ParseResult, line: u32) -> Option<&ClassInfo> {
+    pr.classes
+        .iter()
+        .filter(|c| c.range.start.line <= line && line <= c.range.end.line)
+        .min_by_key(|c| c.range.end.line - c.range.start.line)
+}
+
+/// The innermost function whose body contains `line`.
+fn enclosing_function(pr: &ParseResult, line: u32) -> Option<&FunctionInfo> {
+    pr.functions
+        .iter()
+        .chain(pr.classes.iter().flat_map(|c| c.methods.iter()))
+        .filter(|f| f.line <= line && line <= f.end_line)
+        .min_by_key(|f| f.end_line - f.line)
+}
+
+/// Names in an `extends` clause: `extends Base`, `Base, Mixin`, `(models.Model)`.
+fn supertype_names(text: &str) -> Vec<String> {
+    let text = text.split("implements").next().unwrap_or(text);
+    text.split([',', '(', ')', ':', ' ', '\n', '\t'])
+        .filter(|t| !matches!(*t, "" | "extends" | "metaclass"))
+        .filter_map(normalize_type)
+        .collect()
+}
+
+/// Reduce a type expression to the class name methods are declared on:
+/// `: UserRepo`, `*UserRepo`, `Optional[UserRepo]`, `UserRepo | None`,
+/// `UserRepo?`, `"UserRepo"`, `repos.UserRepo`, `List<User>` → `List`.
+pub fn normalize_type(raw: &str) -> Option<String> {
+    let mut ty = raw.trim().trim_start_matches(':').trim().trim_matches(['"', '\'']).trim();
+    for prefix in ["readonly ", "const ", "&mut ", "&", "*", "mut "] {
+        ty = ty.strip_prefix(prefix).unwrap_or(ty).trim_start();
+    }
+    // Wrappers whose first argument is the real type.
+    for wrapper in ["Optional[", "Annotated[", "Optional<", "Nullable<", "Lazy<", "Provider<"] {
+        if let Some(inner) = ty.strip_prefix(wrapper) {
+            let end = inner.find([',', ']', '>']).unwrap_or(inner.len());
+            return normalize_type(&inner[..end]);
+        }
+    }
+    if ty.contains('|') {
+        return ty
+            .split('|')
+            .map(str::trim)
+            .find(|t| !matches!(*t, "None" | "null" | "undefined" | ""))
+            .and_then(normalize_type);
+    }
+    let ty = ty.split(['<', '[']).next().unwrap_or(ty).trim_end_matches(['?', '!']).trim();
+    let ty = ty.rsplit("::").next().unwrap_or(ty);
+    let ty = ty.rsplit('.').next().unwrap_or(ty);
+    let valid = ty.starts_with(|c: char| c.is_alphabetic() || c == '_')
+        && ty.chars().all(|c| c.is_alphanumeric() || c == '_');
+    valid.then(|| ty.to_string())
+}
@@ -8,6 +8,8 @@ use petgraph::stable_graph::StableGraph;
 use petgraph::Directed;
 use serde::{Deserialize, Serialize};
 
+use crate::parsers::types::ComplexityMetrics;
+
 /// The call graph: a directed graph of function calls.
 pub struct CallGraph {
     /// The underlying petgraph StableGraph.
@@ -99,6 +101,8 @@ pub struct FunctionNode {
     pub is_exported: bool,
     pub signature_hash: u64,
     pub body_hash: u64,
+    #[serde(default)]
+    pub complexity: ComplexityMetrics,
 }
 
 /// A call edge in the call graph.
@@ -114,8 +118,13 @@ pub struct CallEdge {
 pub enum Resolution {
     /// Same-file direct call. Confidence: 0.95.
     SameFile,
+    /// Method call whose receiver's class was inferred from declarations.
+    /// Confidence: 0.92.
+    TypedReceiver,
     /// Method call on a known receiver type. Confidence: 0.90.
     MethodCall,
+    /// Import resolved to a file through project manifests. Confidence: 0.85.
+    ManifestImport,
     /// DI injection resolution. Confidence: 0.80.
     DiInjection,
     /// Import-based resolution. Confidence: 0.75.
@@ -131,7 +140,9 @@ impl Resolution {
     pub fn default_confidence(&self) -> f32 {
         match self {
             Self::SameFile => 0.95,
+            Self::TypedReceiver => 0.92,
             Self::MethodCall => 0.90,
+            Self::ManifestImport => 0.85,
             Self::DiInjection => 0.80,
             Self::ImportBased => 0.75,
             Self::ExportBased => 0.60,
@@ -143,7 +154,9 @@ impl Resolution {
     pub fn name(&self) -> &'static str {
         match self {
             Self::SameFile => "same_file",
+            Self::TypedReceiver => "typed_receiver",
             Self::MethodCall => "method_call",
+            Self::ManifestImport => "manifest_import",
             Self::DiInjection => "di_injection",
             Self::ImportBased => "import_based",
             Self::ExportBased => "export_based",
@@ -155,7 +168,9 @@ impl Resolution {
     pub fn all_ordered() -> &'static [Resolution] {
         &[
             Self::SameFile,
+            Self::TypedReceiver,
             Self::MethodCall,
+            Self::ManifestImport,
             Self::DiInjection,
             Self::ImportBased,
             Self::ExportBased,
@@ -0,0 +1,60 @@
+//! Baseline filter — re-grades gate results so only new violations fail.
+
+use std::collections::HashSet;
+
+use serde_json::json;
+
+use super::fingerprint::{fingerprint_violations, FingerprintContext};
+use super::types::ViolationBaseline;
+use crate::enforcement::gates::GateResult;
+use crate::enforcement::rules::Violation;
+
+/// Applies a baseline to gate results.
+///
+/// Baselined violations stay in the result with `is_new = false`. A gate that
+/// failed because of its violations is re-graded on the new ones alone:
+/// it fails only if a new violation is an error, warns if any are left, and
+/// passes otherwise. Gates that fail for other reasons (a coverage threshold,
+/// a score regression) are left as they are.
+#[derive(Debug, Clone)]
+pub struct BaselineFilter {
+    fingerprints: HashSet<String>,
+    context: FingerprintContext,
+}
+
+impl BaselineFilter {
+    pub fn new(baseline: &ViolationBaseline, context: FingerprintContext) -> Self {
+        Self { fingerprints: baseline.fingerprints(), context }
+    }
+
+    /// Mark and re-grade one gate result. Returns the number of violations
+    /// matched by the baseline.
+    pub fn apply(&self, result: &mut GateResult) -> usize {
+        let candidates: Vec<&Violation> = result.violations.iter().filter(|v| !v.suppressed).collect();
+        let known: HashSet<usize> = fingerprint_violations(&candidates, &self.context)
+            .into_iter()
+            .enumerate()
+            .filter(|(_, fp)| self.fingerprints.contains(&fp.fingerprint))
+            .map(|(i, _)| i)
+            .collect();
+
+        let mut candidate = 0;
+        for violation in &mut result.violations {
+            if violation.suppressed {
+                continue;
+            }
+            violation.is_new = !known.contains(&candidate);
+            candidate += 1;
+        }
+
+        let baselined = known.len();
+        if baselined == 0 {
+            return 0;
+        }
+
+        let new = result.regrade(|v| v.is_new);
+        result.summary = format!("{} ({baselined} baselined, {new} new)", result.summary);
+        result.insert_detail("baseline", json!({ "baselined": baselined, "new": new }));
+        baselined
+    }
+}
@@ -0,0 +1,178 @@
+//! Line-independent violation fingerprints.
+//!
+//! A fingerprint combines the rule id, the file, the enclosing function and a
+//! hash of the whitespace-normalized source snippet. Identical findings that
+//! share all four are told apart by their order of appearance, so inserting
+//! code above a violation — or reformatting it — leaves its fingerprint alone.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::enforcement::rules::Violation;
+use crate::parsers::types::{FunctionInfo, ParseResult};
+use crate::scanner::hasher::hash_content;
+
+/// Snippets longer than this many lines are truncated before hashing.
+const MAX_SNIPPET_LINES: u32 = 10;
+
+/// A function's extent in a file. Lines are 0-based rows, inclusive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FunctionSpan {
+    pub name: String,
+    pub start_line: u32,
+    pub end_line: u32,
+}
+
+#[derive(Debug, Clone, Default)]
+struct FileContext {
+    lines: Vec<String>,
+    functions: Vec<FunctionSpan>,
+}
+
+/// Source text and function extents used to fingerprint violations.
+///
+/// Files without source fall back to an empty snippet; files without
+/// function data are treated as module-level code.
+#[derive(Debug, Clone, Default)]
+pub struct FingerprintContext {
+    files: HashMap<String, FileContext>,
+}
+
+impl FingerprintContext {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register the source text of `file`.
+    pub fn add_source(&mut self, file: &str, source: &str) {
+        self.files.entry(file.to_string()).or_default().lines =
+            source.lines().map(str::to_string).collect();
+    }
+
+    /// Register a function extent in `file`.
+    pub fn add_function(&mut self, file: &str, span: FunctionSpan) {
+        self.files.entry(file.to_string()).or_default().functions.push(span);
+    }
+
+    /// Register the functions and class methods of a parsed file.
+    pub fn add_parse_result(&mut self, parse_result: &ParseResult) {
+        for func in &parse_result.functions {
+            self.add_function(&parse_result.file, Self::span(func, None));
+        }
+        for class in &parse_result.classes {
+            for method in &class.methods {
+                self.add_function(&parse_result.file, Self::span(method, Some(&class.name)));
+            }
+        }
+    }
+
+    fn span(func: &FunctionInfo, class: Option<&str>) -> FunctionSpan {
+        let name = match (&func.qualified_name, class) {
+            (Some(qualified), _) => qualified.clone(),
+            (None, Some(class)) => format!("{class}.{}", func.name),
+            (None, None) => func.name.clone(),
+        };
+        FunctionSpan {
+            name,
+            start_line: func.line,
+            end_line: func.end_line.max(func.line),
+        }
+    }
+
+    /// Name of the innermost function containing `line` (0-based row).
+    pub fn enclosing_function(&self, file: &str, line: u32) -> Option<&str> {
+        self.files
+            .get(file)?
+            .functions
+            .iter()
+            .filter(|f| f.start_line <= line && line <= f.end_line)
+            .min_by_key(|f| (f.end_line - f.start_line, std::cmp::Reverse(f.start_line)))
+            .map(|f| f.name.as_str())
+    }
+
+    /// Whitespace-normalized source of rows `line..=end_line` (0-based, as
+    /// on `Violation`), or `None` if the source is unknown or the range is
+    /// out of bounds.
+    pub fn snippet(&self, file: &str, line: u32, end_line: Option<u32>) -> Option<String> {
+        let lines = &self.files.get(file)?.lines;
+        if line as usize >= lines.len() {
+            return None;
+        }
+        let end = end_line
+            .unwrap_or(line)
+            .clamp(line, line + MAX_SNIPPET_LINES - 1)
+            .min(lines.len() as u32 - 1);
+        let text = lines[line as usize..=end as usize].join("\n");
+        Some(normalize_snippet(&text))
+    }
+}
+
+/// Collapse all whitespace runs to a single space and trim.
+pub fn normalize_snippet(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// The stable identity of a violation, with the parts it was built from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ViolationFingerprint {
+    pub fingerprint: String,
+    pub rule_id: String,
+    pub file: String,
+    pub function: Option<String>,
+    pub snippet_hash: String,
+}
+
+/// Fingerprint `violations`, returning one fingerprint per input, in order.
+///
+/// Occurrence indices are assigned in (file, line, column) order, so the
+/// result doesn't depend on the order violations were produced in.
+pub fn fingerprint_violations(violations: &[&Violation], context: &FingerprintContext) -> Vec<ViolationFingerprint> {
+    let parts: Vec<(Option<String>, String)> = violations
+        .iter()
+        .map(|v| {
+            if v.file_level {
+                return (None, format!("{:016x}", hash_content(b"")));
+            }
+            let function = context.enclosing_function(&v.file, v.line).map(str::to_string);
+            let snippet = context.snippet(&v.file, v.line, v.end_line).unwrap_or_default();
+            (function, format!("{:016x}", hash_content(snippet.as_bytes())))
+        })
+        .collect();
+
+    let mut order: Vec<usize> = (0..violations.len()).collect();
+    order.sort_by(|&a, &b| {
+        let (va, vb) = (violations[a], violations[b]);
+        va.file.cmp(&vb.file)
+            .then(va.line.cmp(&vb.line))
+            .then(va.column.cmp(&vb.column))
+            .then(va.rule_id.cmp(&vb.rule_id))
+    });
+
+    let mut occurrences: HashMap<(&str, &str, Option<&str>, &str), usize> = HashMap::new();
+    let mut fingerprints: Vec<Option<ViolationFingerprint>> = vec![None; violations.len()];
+    for i in order {
+        let violation = violations[i];
+        let (function, snippet_hash) = &parts[i];
+        let occurrence = occurrences
+            .entry((&violation.rule_id, &violation.file, function.as_deref(), snippet_hash))
+            .or_insert(0);
+        let key = format!(
+            "{}\0{}\0{}\0{}\0{}",
+            violation.rule_id,
+            violation.file,
+            function.as_deref().unwrap_or(""),
+            snippet_hash,
+            occurrence
+        );
+        *occurrence += 1;
+        fingerprints[i] = Some(ViolationFingerprint {
+            fingerprint: format!("{:016x}", hash_content(key.as_bytes())),
+            rule_id: violation.rule_id.clone(),
+            file: violation.file.clone(),
+            function: function.clone(),
+            snippet_hash: snippet_hash.clone(),
+        });
+    }
+    fingerprints.into_iter().flatten().collect()
+}
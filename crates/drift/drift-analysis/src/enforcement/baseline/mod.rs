@@ -0,0 +1,14 @@
+//! Violation baseline — adopt gates on legacy code without fixing it first.
+//!
+//! `drift-baseline.json` records the violations that already exist, keyed by
+//! a line-independent fingerprint. With a baseline loaded, gates fail only
+//! on violations the baseline doesn't know about; `prune` shrinks it as
+//! issues get fixed.
+
+pub mod types;
+pub mod fingerprint;
+pub mod filter;
+
+pub use types::*;
+pub use fingerprint::{fingerprint_violations, FingerprintContext, FunctionSpan, ViolationFingerprint};
+pub use filter::BaselineFilter;
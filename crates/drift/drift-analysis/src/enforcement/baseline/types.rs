@@ -0,0 +1,178 @@
+//! Baseline file types — the committed `drift-baseline.json` snapshot.
+
+use std::collections::HashSet;
+use std::path::Path;
+
+use drift_core::errors::GateError;
+use serde::{Deserialize, Serialize};
+
+use super::fingerprint::{fingerprint_violations, FingerprintContext};
+use crate::enforcement::gates::GateResult;
+use crate::enforcement::rules::Violation;
+
+/// Default baseline file name, relative to the project root.
+pub const BASELINE_FILE: &str = "drift-baseline.json";
+
+/// Current baseline file format version.
+pub const BASELINE_VERSION: u32 = 1;
+
+/// A baselined violation. Only `fingerprint` is used for matching; the rest
+/// makes the file reviewable when it changes in a pull request.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BaselineEntry {
+    pub fingerprint: String,
+    pub rule_id: String,
+    pub file: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub function: Option<String>,
+    /// Line when the entry was recorded (1-based). Informational only.
+    #[serde(default)]
+    pub line: u32,
+    #[serde(default)]
+    pub message: String,
+}
+
+/// Snapshot of accepted, pre-existing violations.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ViolationBaseline {
+    pub version: u32,
+    /// Sorted by file, line and rule so regenerated files diff cleanly.
+    #[serde(default)]
+    pub violations: Vec<BaselineEntry>,
+}
+
+/// How current violations compare to a baseline.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BaselineDiff {
+    /// Fingerprints of violations absent from the baseline.
+    pub new: Vec<String>,
+    /// Number of violations matched by a baseline entry.
+    pub baselined: usize,
+    /// Baseline entries no longer reported — fixed, or moved out of scope.
+    pub fixed: Vec<BaselineEntry>,
+}
+
+impl Default for ViolationBaseline {
+    fn default() -> Self {
+        Self { version: BASELINE_VERSION, violations: Vec::new() }
+    }
+}
+
+impl ViolationBaseline {
+    /// Snapshot every unsuppressed violation in `results`.
+    pub fn from_results(results: &[GateResult], context: &FingerprintContext) -> Self {
+        let violations: Vec<&Violation> = results.iter().flat_map(|r| r.violations.iter()).collect();
+        Self::from_violations(&violations, context)
+    }
+
+    /// Snapshot every unsuppressed violation.
+    pub fn from_violations(violations: &[&Violation], context: &FingerprintContext) -> Self {
+        let violations: Vec<&Violation> = violations.iter().copied().filter(|v| !v.suppressed).collect();
+        let fingerprints = fingerprint_violations(&violations, context);
+        let mut entries: Vec<BaselineEntry> = violations
+            .iter()
+            .zip(fingerprints)
+            .map(|(v, fp)| BaselineEntry {
+                fingerprint: fp.fingerprint,
+                rule_id: fp.rule_id,
+                file: fp.file,
+                function: fp.function,
+                line: v.line,
+                message: v.message.clone(),
+            })
+            .collect();
+        entries.sort_by(|a, b| {
+            a.file.cmp(&b.file)
+                .then(a.line.cmp(&b.line))
+                .then(a.rule_id.cmp(&b.rule_id))
+                .then(a.fingerprint.cmp(&b.fingerprint))
+        });
+        entries.dedup_by(|a, b| a.fingerprint == b.fingerprint);
+        Self { version: BASELINE_VERSION, violations: entries }
+    }
+
+    /// Load a baseline file.
+    pub fn load(path: &Path) -> Result<Self, GateError> {
+        let content = std::fs::read_to_string(path).map_err(|e| baseline_error(path, e.to_string()))?;
+        let baseline: Self = serde_json::from_str(&content).map_err(|e| baseline_error(path, e.to_string()))?;
+        if baseline.version > BASELINE_VERSION {
+            return Err(baseline_error(
+                path,
+                format!("unsupported version {} (newest supported: {BASELINE_VERSION})", baseline.version),
+            ));
+        }
+        Ok(baseline)
+    }
+
+    /// Load `drift-baseline.json` from `root`, if present.
+    pub fn load_from_root(root: &Path) -> Result<Option<Self>, GateError> {
+        let path = root.join(BASELINE_FILE);
+        if !path.is_file() {
+            return Ok(None);
+        }
+        Self::load(&path).map(Some)
+    }
+
+    /// Write the baseline as pretty-printed JSON.
+    pub fn save(&self, path: &Path) -> Result<(), GateError> {
+        let mut json = serde_json::to_string_pretty(self).map_err(|e| baseline_error(path, e.to_string()))?;
+        json.push('\n');
+        std::fs::write(path, json).map_err(|e| baseline_error(path, e.to_string()))
+    }
+
+    pub fn len(&self) -> usize {
+        self.violations.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    pub fn contains(&self, fingerprint: &str) -> bool {
+        self.violations.iter().any(|e| e.fingerprint == fingerprint)
+    }
+
+    pub fn fingerprints(&self) -> HashSet<String> {
+        self.violations.iter().map(|e| e.fingerprint.clone()).collect()
+    }
+
+    /// Compare the violations in `results` against the baseline.
+    pub fn diff(&self, results: &[GateResult], context: &FingerprintContext) -> BaselineDiff {
+        let violations: Vec<&Violation> = results
+            .iter()
+            .flat_map(|r| r.violations.iter())
+            .filter(|v| !v.suppressed)
+            .collect();
+        let current: Vec<String> = fingerprint_violations(&violations, context)
+            .into_iter()
+            .map(|fp| fp.fingerprint)
+            .collect();
+
+        let known = self.fingerprints();
+        let seen: HashSet<&str> = current.iter().map(String::as_str).collect();
+        let (baselined, new): (Vec<String>, Vec<String>) = current.iter().cloned().partition(|fp| known.contains(fp));
+        BaselineDiff {
+            new,
+            baselined: baselined.len(),
+            fixed: self
+                .violations
+                .iter()
+                .filter(|e| !seen.contains(e.fingerprint.as_str()))
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// Drop entries whose violations are no longer reported. Never adds
+    /// entries, so new violations stay new. Returns the removed entries.
+    pub fn prune(&mut self, results: &[GateResult], context: &FingerprintContext) -> Vec<BaselineEntry> {
+        let fixed = self.diff(results, context).fixed;
+        let removed: HashSet<&str> = fixed.iter().map(|e| e.fingerprint.as_str()).collect();
+        self.violations.retain(|e| !removed.contains(e.fingerprint.as_str()));
+        fixed
+    }
+}
+
+fn baseline_error(path: &Path, message: String) -> GateError {
+    GateError::BaselineFailed { path: path.display().to_string(), message }
+}
@@ -59,6 +59,9 @@ impl QualityGate for ConstraintVerificationGate {
                         owasp_category: None,
                         suppressed: false,
                         is_new: false,
+                        owners: Vec::new(),
+                        last_author: None,
+                        file_level: cv.line.is_none(),
                     });
                 }
             }
@@ -0,0 +1,118 @@
+//! Gate 7 (opt-in): Contract Compatibility — Do API changes break consumers unannounced?
+
+use super::types::*;
+use crate::enforcement::rules::{Severity, Violation};
+
+/// Gate 7: Fails on breaking contract changes no commit in the range announced.
+pub struct ContractCompatibilityGate;
+
+impl QualityGate for ContractCompatibilityGate {
+    fn id(&self) -> GateId {
+        GateId::ContractCompatibility
+    }
+
+    fn name(&self) -> &'static str {
+        "Contract Compatibility"
+    }
+
+    fn description(&self) -> &'static str {
+        "Blocks breaking API contract changes between the base and head revisions unless announced"
+    }
+
+    fn diff_scoped(&self) -> bool {
+        // Removed endpoints live at base, outside any head-side change range.
+        false
+    }
+
+    fn evaluate(&self, input: &GateInput) -> GateResult {
+        let Some(ref diff) = input.contract_diff else {
+            return GateResult::skipped(
+                GateId::ContractCompatibility,
+                "No contract diff available (no base revision)".to_string(),
+            );
+        };
+
+        let breaking: Vec<_> = diff.breaking().collect();
+        if breaking.is_empty() {
+            return GateResult::pass(
+                GateId::ContractCompatibility,
+                100.0,
+                "No breaking contract changes".to_string(),
+            );
+        }
+
+        let mut violations = Vec::new();
+        let mut announced = Vec::new();
+        for endpoint in &breaking {
+            let consumers = match endpoint.consumers.len() {
+                0 => String::new(),
+                1 => " (1 frontend consumer)".to_string(),
+                n => format!(" ({n} frontend consumers)"),
+            };
+            for change in endpoint.breaking_changes() {
+                let message = format!("{} {}: {}{consumers}", endpoint.paradigm, endpoint.endpoint, change.message);
+                if endpoint.announced {
+                    announced.push(message);
+                    continue;
+                }
+                violations.push(Violation {
+                    id: format!(
+                        "contracts/{}-{}-{}-{}",
+                        change.change_type.name(),
+                        endpoint.file,
+                        endpoint.line,
+                        change.field.as_deref().unwrap_or(&endpoint.endpoint)
+                    ),
+                    file: endpoint.file.clone(),
+                    line: endpoint.line,
+                    column: None,
+                    end_line: None,
+                    end_column: None,
+                    severity: Severity::Error,
+                    pattern_id: "contract-compatibility".to_string(),
+                    rule_id: format!("contracts/{}", change.change_type.name()),
+                    message,
+                    quick_fix: None,
+                    cwe_id: None,
+                    owasp_category: None,
+                    suppressed: false,
+                    is_new: true,
+                    owners: Vec::new(),
+                    last_author: None,
+                    file_level: false,
+                });
+            }
+        }
+
+        let unannounced = breaking.iter().filter(|e| !e.announced).count();
+        let score = (breaking.len() - unannounced) as f64 / breaking.len() as f64 * 100.0;
+        let mut result = if violations.is_empty() {
+            GateResult::warn(
+                GateId::ContractCompatibility,
+                score,
+                format!("{} announced breaking change(s) in {} endpoint(s)", announced.len(), breaking.len()),
+                announced,
+            )
+        } else {
+            let mut result = GateResult::fail(
+                GateId::ContractCompatibility,
+                score,
+                format!(
+                    "{} unannounced breaking change(s) in {unannounced} endpoint(s)",
+                    violations.len()
+                ),
+                violations,
+            );
+            result.warnings = announced;
+            result
+        };
+        result.details = serde_json::json!({
+            "base": diff.base,
+            "head": diff.head,
+            "breaking_endpoints": breaking.len(),
+            "unannounced_endpoints": unannounced,
+            "endpoints": breaking,
+        });
+        result
+    }
+}
@@ -0,0 +1,279 @@
+//! Diff-aware gate scoping — evaluate only what a change range touches.
+//!
+//! `ChangedLines` holds the head-side lines a base..head diff adds or
+//! modifies. `DiffScopeFilter` drops violations outside the scope and
+//! re-grades each gate on the violations that remain.
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use drift_core::errors::GateError;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use super::types::GateResult;
+use crate::call_graph::types::CallGraph;
+use crate::enforcement::rules::Violation;
+use crate::graph::reachability::bfs::reachability_forward;
+
+/// Which violations a diff-scoped run reports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiffScope {
+    /// Violations whose location intersects changed lines.
+    NewCode,
+    /// Also violations in functions whose blast radius reaches a changed
+    /// function — i.e. code a changed function (transitively) calls into.
+    TouchedFunctions,
+}
+
+impl DiffScope {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::NewCode => "new_code",
+            Self::TouchedFunctions => "touched_functions",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "new_code" => Some(Self::NewCode),
+            "touched_functions" => Some(Self::TouchedFunctions),
+            _ => None,
+        }
+    }
+}
+
+/// Changed line ranges per file. Lines are 1-based and inclusive; paths are
+/// relative to the project root.
+#[derive(Debug, Clone, Default)]
+pub struct ChangedLines {
+    files: HashMap<String, Vec<(u32, u32)>>,
+}
+
+impl ChangedLines {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Mark lines `start..=end` of `file` as changed.
+    pub fn add_range(&mut self, file: &str, start: u32, end: u32) {
+        self.files.entry(file.to_string()).or_default().push((start.min(end), start.max(end)));
+    }
+
+    /// Mark `file` as changed without any line (e.g. a pure deletion at its end).
+    pub fn add_file(&mut self, file: &str) {
+        self.files.entry(file.to_string()).or_default();
+    }
+
+    /// Diff `base` against `head` — or against the working tree and index
+    /// when `head` is `None` — in the repository containing `root`.
+    ///
+    /// The diff starts from the merge base, so changes that landed on `base`
+    /// after the branch point don't count as changes of this range. Files
+    /// outside `root` are ignored.
+    pub fn from_git(root: &Path, base: &str, head: Option<&str>) -> Result<Self, GateError> {
+        let fail = |message: String| GateError::EvaluationFailed(format!("git diff {base}: {message}"));
+
+        let repo = git2::Repository::discover(root).map_err(|e| fail(e.to_string()))?;
+        let workdir = repo
+            .workdir()
+            .ok_or_else(|| fail("bare repositories are not supported".to_string()))?;
+        let prefix = root
+            .canonicalize()
+            .ok()
+            .and_then(|r| workdir.canonicalize().ok().and_then(|w| r.strip_prefix(w).ok().map(Path::to_path_buf)))
+            .unwrap_or_default();
+
+        let commit_of = |rev: &str| -> Result<git2::Commit<'_>, GateError> {
+            repo.revparse_single(rev)
+                .and_then(|o| o.peel_to_commit())
+                .map_err(|e| fail(format!("cannot resolve '{rev}': {e}")))
+        };
+        let base_commit = commit_of(base)?;
+        let head_commit = commit_of(head.unwrap_or("HEAD"))?;
+        let base_tree = repo
+            .merge_base(base_commit.id(), head_commit.id())
+            .and_then(|oid| repo.find_commit(oid))
+            .unwrap_or(base_commit)
+            .tree()
+            .map_err(|e| fail(e.to_string()))?;
+
+        let mut options = git2::DiffOptions::new();
+        options.context_lines(0);
+        let diff = match head {
+            Some(_) => {
+                let head_tree = head_commit.tree().map_err(|e| fail(e.to_string()))?;
+                repo.diff_tree_to_tree(Some(&base_tree), Some(&head_tree), Some(&mut options))
+            }
+            None => {
+                options
+                    .include_untracked(true)
+                    .recurse_untracked_dirs(true)
+                    .show_untracked_content(true);
+                repo.diff_tree_to_workdir_with_index(Some(&base_tree), Some(&mut options))
+            }
+        }
+        .map_err(|e| fail(e.to_string()))?;
+
+        let mut changed = Self::new();
+        let relative = |delta: &git2::DiffDelta<'_>| -> Option<String> {
+            let path = delta.new_file().path()?;
+            let path = path.strip_prefix(&prefix).ok()?;
+            Some(path.to_string_lossy().replace('\\', "/"))
+        };
+        let mut on_file = |delta: git2::DiffDelta<'_>, _progress: f32| {
+            if delta.status() != git2::Delta::Deleted {
+                if let Some(file) = relative(&delta) {
+                    changed.add_file(&file);
+                }
+            }
+            true
+        };
+        let mut ranges: Vec<(String, u32, u32)> = Vec::new();
+        let mut on_hunk = |delta: git2::DiffDelta<'_>, hunk: git2::DiffHunk<'_>| {
+            if let Some(file) = relative(&delta) {
+                // A pure deletion has no new lines; mark the line it happened at.
+                let start = hunk.new_start().max(1);
+                let end = start + hunk.new_lines().max(1) - 1;
+                ranges.push((file, start, end));
+            }
+            true
+        };
+        diff.foreach(&mut on_file, None, Some(&mut on_hunk), None)
+            .map_err(|e| fail(e.to_string()))?;
+        for (file, start, end) in ranges {
+            changed.add_range(&file, start, end);
+        }
+        Ok(changed)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    pub fn contains_file(&self, file: &str) -> bool {
+        self.files.contains_key(file)
+    }
+
+    /// Changed files, sorted.
+    pub fn files(&self) -> Vec<&str> {
+        let mut files: Vec<&str> = self.files.keys().map(String::as_str).collect();
+        files.sort_unstable();
+        files
+    }
+
+    /// Whether any changed line of `file` falls in `start..=end`.
+    pub fn intersects(&self, file: &str, start: u32, end: u32) -> bool {
+        self.files
+            .get(file)
+            .is_some_and(|ranges| ranges.iter().any(|&(s, e)| s <= end && start <= e))
+    }
+}
+
+/// Restricts gate results to the violations a change is responsible for.
+#[derive(Debug, Clone)]
+pub struct DiffScopeFilter {
+    scope: DiffScope,
+    changed: ChangedLines,
+    /// Per file, 1-based extents of functions in scope through the call graph.
+    touched: HashMap<String, Vec<(u32, u32)>>,
+}
+
+impl DiffScopeFilter {
+    pub fn new(scope: DiffScope, changed: ChangedLines) -> Self {
+        Self { scope, changed, touched: HashMap::new() }
+    }
+
+    /// Resolve touched functions through `graph`. Only used by
+    /// `DiffScope::TouchedFunctions`; without a graph that scope reports the
+    /// same violations as `NewCode`.
+    ///
+    /// A function's blast radius is its transitive callers, so it reaches a
+    /// changed function exactly when the function is reachable from one.
+    /// Walking forward from the changed functions gives the same set without
+    /// a blast-radius traversal per function.
+    pub fn with_call_graph(mut self, graph: &CallGraph) -> Self {
+        if self.scope != DiffScope::TouchedFunctions {
+            return self;
+        }
+        let mut in_scope = HashSet::new();
+        for node in graph.graph.node_indices() {
+            let f = &graph.graph[node];
+            // FunctionNode lines are 0-based tree-sitter rows.
+            if self.changed.intersects(&f.file, f.line + 1, f.end_line.max(f.line) + 1) {
+                in_scope.insert(node);
+                in_scope.extend(reachability_forward(graph, node, None).reachable);
+            }
+        }
+        for node in in_scope {
+            let f = &graph.graph[node];
+            self.touched
+                .entry(f.file.clone())
+                .or_default()
+                .push((f.line + 1, f.end_line.max(f.line) + 1));
+        }
+        self
+    }
+
+    pub fn scope(&self) -> DiffScope {
+        self.scope
+    }
+
+    pub fn changed(&self) -> &ChangedLines {
+        &self.changed
+    }
+
+    /// Whether `violation` falls inside the scope. File-level violations are
+    /// in scope when their file changed.
+    pub fn in_scope(&self, violation: &Violation) -> bool {
+        let Some((start, end)) = violation_lines(violation) else {
+            return self.changed.contains_file(&violation.file);
+        };
+        if self.changed.intersects(&violation.file, start, end) {
+            return true;
+        }
+        self.scope == DiffScope::TouchedFunctions
+            && self
+                .touched
+                .get(&violation.file)
+                .is_some_and(|spans| spans.iter().any(|&(s, e)| s <= start && start <= e))
+    }
+
+    /// Drop out-of-scope violations from `result` and re-grade it on the
+    /// rest. Returns the number of violations dropped.
+    pub fn apply(&self, result: &mut GateResult) -> usize {
+        let total = result.violations.len();
+        let kept = result.regrade(|v| self.in_scope(v));
+        result.violations.retain(|v| self.in_scope(v));
+        let dropped = total - result.violations.len();
+        if dropped > 0 {
+            result.summary = format!(
+                "{} ({kept} of {total} violations in {} scope)",
+                result.summary,
+                self.scope.name()
+            );
+        }
+        result.insert_detail(
+            "diff_scope",
+            json!({
+                "scope": self.scope.name(),
+                "changed_files": self.changed.files.len(),
+                "in_scope": result.violations.len(),
+                "out_of_scope": dropped,
+            }),
+        );
+        dropped
+    }
+}
+
+/// The 1-based, inclusive lines `violation` spans, or `None` when it concerns
+/// the whole file. Violation lines are 0-based tree-sitter rows.
+fn violation_lines(violation: &Violation) -> Option<(u32, u32)> {
+    if violation.file_level {
+        return None;
+    }
+    let start = violation.line;
+    let end = violation.end_line.unwrap_or(start).max(start);
+    Some((start + 1, end + 1))
+}
@@ -52,6 +52,9 @@ impl QualityGate for ErrorHandlingGate {
                 owasp_category: None,
                 suppressed: false,
                 is_new: false,
+                owners: Vec::new(),
+                last_author: None,
+                file_level: false,
             });
         }
 
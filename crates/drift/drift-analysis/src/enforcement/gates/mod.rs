@@ -1,4 +1,4 @@
-//! Quality gates — 6 gates with DAG-based orchestration.
+//! Quality gates — 6 default gates plus opt-in gates, with DAG-based orchestration.
 
 pub mod types;
 pub mod orchestrator;
@@ -9,7 +9,11 @@ pub mod test_coverage;
 pub mod error_handling;
 pub mod regression;
 pub mod progressive;
+pub mod diff_scope;
+pub mod contract_compatibility;
 
 pub use types::*;
 pub use orchestrator::GateOrchestrator;
 pub use progressive::{ProgressiveEnforcement, ProgressiveConfig};
+pub use diff_scope::{ChangedLines, DiffScope, DiffScopeFilter};
+pub use contract_compatibility::ContractCompatibilityGate;
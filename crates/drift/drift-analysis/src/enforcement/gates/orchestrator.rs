@@ -11,11 +11,15 @@ use super::pattern_compliance::PatternComplianceGate;
 use super::regression::RegressionGate;
 use super::security_boundaries::SecurityBoundariesGate;
 use super::test_coverage::TestCoverageGate;
+use super::diff_scope::DiffScopeFilter;
+use crate::enforcement::baseline::BaselineFilter;
 
 /// DAG-based gate orchestrator that respects gate dependencies.
 pub struct GateOrchestrator {
     gates: Vec<Box<dyn QualityGate>>,
     progressive: Option<ProgressiveEnforcement>,
+    baseline: Option<BaselineFilter>,
+    diff_scope: Option<DiffScopeFilter>,
     /// Per-gate timeout. Default: 30 seconds.
     gate_timeout: Duration,
 }
@@ -34,6 +38,8 @@ impl GateOrchestrator {
         Self {
             gates,
             progressive: None,
+            baseline: None,
+            diff_scope: None,
             gate_timeout: Duration::from_secs(30),
         }
     }
@@ -43,10 +49,18 @@ impl GateOrchestrator {
         Self {
             gates,
             progressive: None,
+            baseline: None,
+            diff_scope: None,
             gate_timeout: Duration::from_secs(30),
         }
     }
 
+    /// Add a gate to the set, e.g. an opt-in gate such as contract compatibility.
+    pub fn with_gate(mut self, gate: Box<dyn QualityGate>) -> Self {
+        self.gates.push(gate);
+        self
+    }
+
     /// Enable progressive enforcement with the given configuration.
     pub fn with_progressive(mut self, config: ProgressiveConfig) -> Self {
         if config.enabled {
@@ -55,6 +69,18 @@ impl GateOrchestrator {
         self
     }
 
+    /// Fail gates only on violations absent from the baseline.
+    pub fn with_baseline(mut self, filter: BaselineFilter) -> Self {
+        self.baseline = Some(filter);
+        self
+    }
+
+    /// Report only violations within a git change range.
+    pub fn with_diff_scope(mut self, filter: DiffScopeFilter) -> Self {
+        self.diff_scope = Some(filter);
+        self
+    }
+
     /// Set the per-gate execution timeout.
     pub fn with_timeout(mut self, timeout: Duration) -> Self {
         self.gate_timeout = timeout;
@@ -143,6 +169,18 @@ impl GateOrchestrator {
                     }
                 }
 
+                // Restrict to the diff scope
+                if let Some(ref diff_scope) = self.diff_scope {
+                    if gate.diff_scoped() {
+                        diff_scope.apply(&mut result);
+                    }
+                }
+
+                // Re-grade against the fingerprint baseline
+                if let Some(ref baseline) = self.baseline {
+                    baseline.apply(&mut result);
+                }
+
                 result
             };
 
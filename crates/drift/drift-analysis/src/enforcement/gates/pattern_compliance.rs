@@ -1,7 +1,7 @@
 //! Gate 1: Pattern Compliance — Are approved patterns followed?
 
 use super::types::*;
-use crate::enforcement::rules::{Severity, Violation};
+use crate::enforcement::rules::{QuickFixGenerator, Severity, Violation};
 
 /// Gate 1: Checks whether approved patterns are being followed.
 pub struct PatternComplianceGate;
@@ -20,6 +20,7 @@ impl QualityGate for PatternComplianceGate {
     }
 
     fn evaluate(&self, input: &GateInput) -> GateResult {
+        let fix_generator = QuickFixGenerator::new();
         let mut violations = Vec::new();
         let mut total_locations = 0usize;
         let mut total_outliers = 0usize;
@@ -55,11 +56,17 @@ impl QualityGate for PatternComplianceGate {
                         pattern.pattern_id,
                         pattern.confidence * 100.0
                     ),
-                    quick_fix: None,
+                    quick_fix: match input.sources.get(&outlier.file) {
+                        Some(source) => fix_generator.suggest_with_source(pattern, outlier, source),
+                        None => fix_generator.suggest(pattern, outlier),
+                    },
                     cwe_id: None,
                     owasp_category: None,
                     suppressed: false,
                     is_new: false,
+                    owners: Vec::new(),
+                    last_author: None,
+                    file_level: false,
                 });
             }
         }
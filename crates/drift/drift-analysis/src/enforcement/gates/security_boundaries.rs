@@ -63,6 +63,9 @@ impl QualityGate for SecurityBoundariesGate {
                 owasp_category: finding.owasp_categories.first().cloned(),
                 suppressed: false,
                 is_new: false,
+                owners: Vec::new(),
+                last_author: None,
+                file_level: false,
             });
         }
 
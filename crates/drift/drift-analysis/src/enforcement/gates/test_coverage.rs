@@ -30,7 +30,11 @@ impl QualityGate for TestCoverageGate {
             }
         };
 
-        let score = coverage_input.overall_coverage;
+        // Measured coverage from an imported report beats the inference.
+        let (score, evidence) = match coverage_input.measured_coverage {
+            Some(measured) => (measured, "measured"),
+            None => (coverage_input.overall_coverage, "inferred"),
+        };
         let threshold = coverage_input.threshold;
 
         let mut violations = Vec::new();
@@ -51,6 +55,9 @@ impl QualityGate for TestCoverageGate {
                 owasp_category: None,
                 suppressed: false,
                 is_new: false,
+                owners: Vec::new(),
+                last_author: None,
+                file_level: true,
             });
         }
 
@@ -58,14 +65,14 @@ impl QualityGate for TestCoverageGate {
             GateResult::pass(
                 GateId::TestCoverage,
                 score,
-                format!("Test coverage: {score:.1}% (threshold: {threshold:.1}%)"),
+                format!("Test coverage ({evidence}): {score:.1}% (threshold: {threshold:.1}%)"),
             )
         } else {
             GateResult::fail(
                 GateId::TestCoverage,
                 score,
                 format!(
-                    "Test coverage {score:.1}% below threshold {threshold:.1}%"
+                    "Test coverage ({evidence}) {score:.1}% below threshold {threshold:.1}%"
                 ),
                 violations,
             )
@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use std::fmt;
 
-/// The 6 quality gate identifiers.
+/// Quality gate identifiers.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 pub enum GateId {
@@ -14,6 +14,7 @@ pub enum GateId {
     TestCoverage,
     ErrorHandling,
     Regression,
+    ContractCompatibility,
 }
 
 impl GateId {
@@ -25,9 +26,12 @@ impl GateId {
             Self::TestCoverage => "test-coverage",
             Self::ErrorHandling => "error-handling",
             Self::Regression => "regression",
+            Self::ContractCompatibility => "contract-compatibility",
         }
     }
 
+    /// The 6 default gates. `ContractCompatibility` needs a base revision
+    /// and is added with `GateOrchestrator::with_gate`.
     pub fn all() -> &'static [GateId] {
         &[
             Self::PatternCompliance,
@@ -157,6 +161,44 @@ impl GateResult {
             error: Some(error),
         }
     }
+
+    /// Re-grade a gate that failed on its violations, counting only the
+    /// unsuppressed ones `counted` accepts: it stays failed if one of them is
+    /// an error, warns if any are left, and passes otherwise. Gates that
+    /// failed without violations (a threshold, a regression) are left alone.
+    /// Returns the number of violations counted.
+    pub fn regrade(&mut self, counted: impl Fn(&super::super::rules::Violation) -> bool) -> usize {
+        use super::super::rules::Severity;
+
+        let counted: Vec<&super::super::rules::Violation> =
+            self.violations.iter().filter(|v| !v.suppressed && counted(v)).collect();
+        let count = counted.len();
+        if self.status == GateStatus::Failed
+            && !self.violations.is_empty()
+            && !counted.iter().any(|v| v.severity == Severity::Error)
+        {
+            if counted.is_empty() {
+                self.status = GateStatus::Passed;
+            } else {
+                self.status = GateStatus::Warned;
+                let messages: Vec<String> = counted.iter().take(5).map(|v| v.message.clone()).collect();
+                self.warnings.extend(messages);
+            }
+            self.passed = true;
+        }
+        count
+    }
+
+    /// Add `key` to `details`, turning a null `details` into an object.
+    pub fn insert_detail(&mut self, key: &str, value: serde_json::Value) {
+        match self.details {
+            serde_json::Value::Object(ref mut map) => {
+                map.insert(key.to_string(), value);
+            }
+            serde_json::Value::Null => self.details = serde_json::json!({ key: value }),
+            _ => {}
+        }
+    }
 }
 
 /// Input provided to each gate by the orchestrator.
@@ -176,6 +218,44 @@ pub struct GateInput {
     pub baseline_violations: HashSet<String>,
     /// Optional feedback stats provider for FP-rate-aware gate evaluation.
     pub feedback_stats: Option<std::sync::Arc<dyn super::super::feedback::stats_provider::FeedbackStatsProvider>>,
+    /// Contract changes between the base and head revisions.
+    pub contract_diff: Option<std::sync::Arc<crate::structural::contracts::revisions::ContractDiff>>,
+    /// Raw file contents by path, for quick-fix edits.
+    pub sources: std::sync::Arc<HashMap<String, String>>,
+}
+
+impl GateInput {
+    /// Narrow the input to findings in `files`, for evaluating one part of
+    /// a repository on its own. Constraints without file-level violations,
+    /// coverage percentages, health scores and contract changes are
+    /// repository-wide and kept.
+    pub fn restrict_to(&self, files: &HashSet<String>) -> GateInput {
+        let in_scope = |file: &String| files.contains(file);
+        let mut input = self.clone();
+        input.files.retain(in_scope);
+        input.patterns = self
+            .patterns
+            .iter()
+            .filter_map(|p| {
+                let mut pattern = p.clone();
+                pattern.locations.retain(|l| in_scope(&l.file));
+                pattern.outliers.retain(|o| in_scope(&o.file));
+                (!pattern.locations.is_empty() || !pattern.outliers.is_empty()).then_some(pattern)
+            })
+            .collect();
+        for constraint in &mut input.constraints {
+            if !constraint.violations.is_empty() {
+                constraint.violations.retain(|v| in_scope(&v.file));
+                constraint.passed = constraint.violations.is_empty();
+            }
+        }
+        input.security_findings.retain(|f| in_scope(&f.file));
+        input.error_gaps.retain(|g| in_scope(&g.file));
+        if let Some(ref mut coverage) = input.test_coverage {
+            coverage.uncovered_files.retain(in_scope);
+        }
+        input
+    }
 }
 
 impl std::fmt::Debug for GateInput {
@@ -193,6 +273,7 @@ impl std::fmt::Debug for GateInput {
             .field("predecessor_results", &self.predecessor_results)
             .field("baseline_violations", &self.baseline_violations)
             .field("feedback_stats", &self.feedback_stats.as_ref().map(|_| "<FeedbackStatsProvider>"))
+            .field("contract_diff", &self.contract_diff)
             .finish()
     }
 }
@@ -230,6 +311,55 @@ pub struct TestCoverageInput {
     pub overall_coverage: f64,
     pub threshold: f64,
     pub uncovered_files: Vec<String>,
+    /// Coverage % backed by an imported coverage report, if one was merged.
+    /// The gate prefers it over the inferred `overall_coverage`.
+    pub measured_coverage: Option<f64>,
+}
+
+impl TestCoverageInput {
+    /// Build the gate input from a test topology coverage mapping. `None`
+    /// when the graph has no source functions.
+    ///
+    /// `overall_coverage` stays the call-graph inference. When measured
+    /// coverage was merged into the mapping, `measured_coverage` is set and
+    /// uncovered files are judged by it.
+    pub fn from_coverage_mapping(
+        graph: &crate::call_graph::types::CallGraph,
+        coverage: &crate::graph::test_topology::types::CoverageMapping,
+        threshold: f64,
+    ) -> Option<Self> {
+        let total = coverage.total_source_functions;
+        if total == 0 {
+            return None;
+        }
+
+        // A file is uncovered when none of its source functions are covered.
+        let mut file_covered: std::collections::BTreeMap<&str, bool> = std::collections::BTreeMap::new();
+        for idx in graph.graph.node_indices() {
+            if coverage.test_to_source.contains_key(&idx) {
+                continue;
+            }
+            let covered = file_covered.entry(graph.graph[idx].file.as_str()).or_insert(false);
+            *covered |= coverage.is_covered(idx);
+        }
+        let uncovered_files = file_covered
+            .into_iter()
+            .filter(|(_, covered)| !covered)
+            .map(|(file, _)| file.to_string())
+            .collect();
+
+        let overall_coverage = coverage.source_to_test.len() as f64 / total as f64 * 100.0;
+        let measured_coverage = coverage
+            .has_measured_data()
+            .then(|| coverage.covered_source_functions() as f64 / total as f64 * 100.0);
+
+        Some(Self {
+            overall_coverage,
+            threshold,
+            uncovered_files,
+            measured_coverage,
+        })
+    }
 }
 
 /// Error handling gap data for the error handling gate.
@@ -257,6 +387,11 @@ pub trait QualityGate: Send + Sync {
     fn dependencies(&self) -> Vec<GateId> {
         Vec::new()
     }
+    /// Whether a diff scope narrows this gate's violations. Gates that
+    /// already compare two revisions opt out.
+    fn diff_scoped(&self) -> bool {
+        true
+    }
 }
 
 /// Builder for constructing a populated `GateInput` from upstream analysis results.
@@ -422,6 +557,7 @@ impl GateInputBuilder {
             overall_coverage,
             threshold,
             uncovered_files,
+            measured_coverage: None,
         });
         self
     }
@@ -444,6 +580,18 @@ impl GateInputBuilder {
         self
     }
 
+    /// Set contract changes for the ContractCompatibility gate.
+    pub fn contract_diff(mut self, diff: crate::structural::contracts::revisions::ContractDiff) -> Self {
+        self.input.contract_diff = Some(std::sync::Arc::new(diff));
+        self
+    }
+
+    /// Set raw file contents so quick fixes carry concrete edits.
+    pub fn sources(mut self, sources: HashMap<String, String>) -> Self {
+        self.input.sources = std::sync::Arc::new(sources);
+        self
+    }
+
     /// Set baseline violation keys for is_new detection.
     /// Keys should be formatted as "file:line:rule_id".
     pub fn baseline_violations(mut self, baseline: HashSet<String>) -> Self {
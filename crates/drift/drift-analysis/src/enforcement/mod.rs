@@ -7,6 +7,8 @@
 //! - `policy` — 4 aggregation modes for gate results
 //! - `audit` — 5-factor health scoring, degradation detection
 //! - `feedback` — Tricorder-style FP tracking, auto-disable
+//! - `baseline` — fingerprinted snapshot of accepted violations
+//! - `ownership` — CODEOWNERS and git blame attribution of violations
 
 pub mod rules;
 pub mod gates;
@@ -14,3 +16,5 @@ pub mod reporters;
 pub mod policy;
 pub mod audit;
 pub mod feedback;
+pub mod baseline;
+pub mod ownership;
@@ -0,0 +1,91 @@
+//! Last authors of violating lines, via git blame at HEAD.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use drift_core::errors::GateError;
+
+/// A run of lines last changed by the same commit.
+#[derive(Debug, Clone)]
+struct BlameHunk {
+    start: u32,
+    end: u32,
+    author: String,
+}
+
+/// Blames files on demand and caches the result per file.
+pub struct Blamer {
+    repo: git2::Repository,
+    /// The root, relative to the repository working directory.
+    prefix: PathBuf,
+    root: String,
+    cache: HashMap<String, Vec<BlameHunk>>,
+}
+
+impl Blamer {
+    /// Open the repository containing `root`. File paths passed to
+    /// `last_author` are relative to `root`, or absolute under it.
+    pub fn open(root: &Path) -> Result<Self, GateError> {
+        let fail = |message: String| GateError::OwnershipFailed { path: root.display().to_string(), message };
+        let repo = git2::Repository::discover(root).map_err(|e| fail(e.message().to_string()))?;
+        let workdir = repo
+            .workdir()
+            .ok_or_else(|| fail("bare repositories are not supported".to_string()))?;
+        let prefix = root
+            .canonicalize()
+            .ok()
+            .and_then(|r| workdir.canonicalize().ok().and_then(|w| r.strip_prefix(w).ok().map(Path::to_path_buf)))
+            .unwrap_or_default();
+        Ok(Self {
+            repo,
+            prefix,
+            root: root.to_string_lossy().replace('\\', "/").trim_end_matches('/').to_string(),
+            cache: HashMap::new(),
+        })
+    }
+
+    /// Author of the commit that last changed `line` of `file`. `line` is
+    /// 0-based, like violation lines; git blame numbers lines from 1.
+    /// `None` for untracked files and lines past the committed content.
+    pub fn last_author(&mut self, file: &str, line: u32) -> Option<String> {
+        let line = line + 1;
+        let file = file.replace('\\', "/");
+        let relative = file
+            .strip_prefix(self.root.as_str())
+            .filter(|rest| rest.starts_with('/'))
+            .map(|rest| rest.trim_start_matches('/'))
+            .unwrap_or(&file)
+            .trim_start_matches("./")
+            .to_string();
+        if !self.cache.contains_key(&relative) {
+            let hunks = self.blame(&relative);
+            self.cache.insert(relative.clone(), hunks);
+        }
+        self.cache[&relative]
+            .iter()
+            .find(|h| h.start <= line && line <= h.end)
+            .map(|h| h.author.clone())
+    }
+
+    fn blame(&self, relative: &str) -> Vec<BlameHunk> {
+        let path = self.prefix.join(relative);
+        let Ok(blame) = self.repo.blame_file(&path, None) else {
+            return Vec::new();
+        };
+        blame
+            .iter()
+            .filter(|hunk| hunk.lines_in_hunk() > 0)
+            .map(|hunk| {
+                let signature = hunk.final_signature();
+                let author = signature
+                    .name()
+                    .filter(|n| !n.is_empty())
+                    .or_else(|| signature.email())
+                    .unwrap_or("unknown")
+                    .to_string();
+                let start = hunk.final_start_line() as u32;
+                BlameHunk { start, end: start + hunk.lines_in_hunk() as u32 - 1, author }
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,205 @@
+//! CODEOWNERS parsing — GitHub and GitLab syntax.
+//!
+//! Patterns follow gitignore rules: a leading `/` or an inner slash anchors
+//! a pattern to the root, a pattern without one matches at any depth, a
+//! trailing `/` matches directories only, and a directory owns everything
+//! below it. Within a section the last matching rule wins. GitLab sections
+//! (`[Docs]`, `^[Optional]`, `[Docs][2] @default-owner`) are independent:
+//! a file collects owners from every section with a matching rule, and a
+//! rule without owners takes its section's defaults.
+
+use std::path::{Path, PathBuf};
+
+use drift_core::errors::GateError;
+
+/// Where CODEOWNERS is looked for, relative to the root, in order.
+pub const CODEOWNERS_LOCATIONS: [&str; 4] = [".github/CODEOWNERS", ".gitlab/CODEOWNERS", "CODEOWNERS", "docs/CODEOWNERS"];
+
+#[derive(Debug, Clone)]
+struct Rule {
+    matchers: Vec<glob::Pattern>,
+    owners: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Section {
+    default_owners: Vec<String>,
+    rules: Vec<Rule>,
+}
+
+/// A parsed CODEOWNERS file.
+#[derive(Debug, Clone, Default)]
+pub struct CodeOwners {
+    sections: Vec<Section>,
+    /// Lines that could not be parsed, as "line N: reason".
+    pub errors: Vec<String>,
+    /// The file this was loaded from.
+    pub path: Option<PathBuf>,
+    /// The root the patterns are relative to, when loaded from disk.
+    root: Option<String>,
+}
+
+impl CodeOwners {
+    /// Parse CODEOWNERS content. Invalid lines are skipped and recorded in
+    /// `errors`, as GitHub does.
+    pub fn parse(content: &str) -> Self {
+        let mut owners = Self { sections: vec![Section::default()], ..Self::default() };
+        for (i, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(section) = parse_section(line) {
+                owners.sections.push(section);
+                continue;
+            }
+            let mut tokens = split_tokens(line).into_iter();
+            let Some(pattern) = tokens.next() else { continue };
+            match compile(&pattern) {
+                Ok(matchers) => {
+                    let rule = Rule { matchers, owners: tokens.collect() };
+                    owners.sections.last_mut().expect("default section").rules.push(rule);
+                }
+                Err(reason) => owners.errors.push(format!("line {}: {reason}", i + 1)),
+            }
+        }
+        owners
+    }
+
+    /// Load the first CODEOWNERS file under `root`, if any. Its patterns are
+    /// taken relative to `root`.
+    pub fn load(root: &Path) -> Result<Option<Self>, GateError> {
+        let Some(path) = CODEOWNERS_LOCATIONS.iter().map(|l| root.join(l)).find(|p| p.is_file()) else {
+            return Ok(None);
+        };
+        let content = std::fs::read_to_string(&path).map_err(|e| GateError::OwnershipFailed {
+            path: path.display().to_string(),
+            message: e.to_string(),
+        })?;
+        let mut owners = Self::parse(&content);
+        owners.path = Some(path);
+        owners.root = Some(root.to_string_lossy().replace('\\', "/").trim_end_matches('/').to_string());
+        Ok(Some(owners))
+    }
+
+    /// Owners of `file` (relative to the root, or absolute under the root
+    /// it was loaded from), in file order. Empty if no rule matches or the
+    /// winning rule clears ownership.
+    pub fn owners_of(&self, file: &str) -> Vec<String> {
+        let file = file.replace('\\', "/");
+        let file = self
+            .root
+            .as_deref()
+            .filter(|root| !root.is_empty())
+            .and_then(|root| file.strip_prefix(root))
+            .filter(|rest| rest.starts_with('/'))
+            .unwrap_or(&file)
+            .trim_start_matches('/')
+            .trim_start_matches("./");
+        let options = glob::MatchOptions { require_literal_separator: true, ..Default::default() };
+        let mut owners: Vec<String> = Vec::new();
+        for section in &self.sections {
+            let Some(rule) = section
+                .rules
+                .iter()
+                .rfind(|r| r.matchers.iter().any(|m| m.matches_with(file, options)))
+            else {
+                continue;
+            };
+            let rule_owners = if rule.owners.is_empty() { &section.default_owners } else { &rule.owners };
+            for owner in rule_owners {
+                if !owners.contains(owner) {
+                    owners.push(owner.clone());
+                }
+            }
+        }
+        owners
+    }
+
+    /// Number of ownership rules.
+    pub fn rule_count(&self) -> usize {
+        self.sections.iter().map(|s| s.rules.len()).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rule_count() == 0
+    }
+}
+
+/// A GitLab section header: `[Name]`, `^[Name]`, `[Name][2]`, each
+/// optionally followed by default owners.
+fn parse_section(line: &str) -> Option<Section> {
+    let rest = line.strip_prefix('^').unwrap_or(line).strip_prefix('[')?;
+    let (name, mut rest) = rest.split_once(']')?;
+    if name.trim().is_empty() {
+        return None;
+    }
+    // Required approvals.
+    if let Some(approvals) = rest.strip_prefix('[') {
+        let (count, after) = approvals.split_once(']')?;
+        count.trim().parse::<u32>().ok()?;
+        rest = after;
+    }
+    if !rest.is_empty() && !rest.starts_with(char::is_whitespace) {
+        return None;
+    }
+    Some(Section { default_owners: split_tokens(rest), rules: Vec::new() })
+}
+
+/// Split on unescaped whitespace, dropping a trailing ` # comment` and
+/// unescaping `\ ` and `\#`.
+fn split_tokens(line: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some(next @ (' ' | '#')) => current.push(next),
+                Some(next) => {
+                    current.push('\\');
+                    current.push(next);
+                }
+                None => current.push('\\'),
+            },
+            '#' if current.is_empty() => break,
+            c if c.is_whitespace() => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+            }
+            c => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+    tokens
+}
+
+/// Globs matching what a CODEOWNERS pattern owns.
+fn compile(pattern: &str) -> Result<Vec<glob::Pattern>, String> {
+    let dir_only = pattern.ends_with('/');
+    let trimmed = pattern.trim_end_matches('/');
+    let anchored = trimmed.starts_with('/') || trimmed.contains('/');
+    let body = trimmed.trim_start_matches('/');
+    if body.is_empty() {
+        return Err(format!("invalid pattern '{pattern}'"));
+    }
+    let base = if anchored || body.starts_with("**") { body.to_string() } else { format!("**/{body}") };
+    // `docs/*` owns the files in docs, not the ones further down.
+    let last = body.rsplit('/').next().unwrap_or(body);
+    let wildcard_leaf = last.contains('*') && last != "**";
+
+    let mut globs = Vec::new();
+    if !dir_only {
+        globs.push(base.clone());
+    }
+    if dir_only || !wildcard_leaf {
+        globs.push(format!("{base}/**"));
+    }
+    globs
+        .iter()
+        .map(|g| glob::Pattern::new(g).map_err(|e| format!("invalid pattern '{pattern}': {e}")))
+        .collect()
+}
@@ -0,0 +1,118 @@
+//! Code ownership — who owns a violation.
+//!
+//! Owners come from CODEOWNERS (GitHub or GitLab syntax); git blame can add
+//! the last author of each violating line. Reporters group violations by
+//! owner, and policy files can route thresholds per owner.
+
+pub mod codeowners;
+pub mod blame;
+
+pub use codeowners::{CodeOwners, CODEOWNERS_LOCATIONS};
+pub use blame::Blamer;
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::enforcement::gates::GateResult;
+use crate::enforcement::rules::{Severity, Violation};
+
+/// Group for violations no CODEOWNERS rule covers.
+pub const UNOWNED: &str = "(unowned)";
+
+/// Attaches owners and last authors to violations.
+#[derive(Default)]
+pub struct OwnershipResolver {
+    codeowners: Option<CodeOwners>,
+    blamer: Option<Blamer>,
+}
+
+impl OwnershipResolver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_codeowners(mut self, codeowners: CodeOwners) -> Self {
+        self.codeowners = Some(codeowners);
+        self
+    }
+
+    pub fn with_blame(mut self, blamer: Blamer) -> Self {
+        self.blamer = Some(blamer);
+        self
+    }
+
+    /// Whether there is anything to attribute with.
+    pub fn is_empty(&self) -> bool {
+        self.codeowners.is_none() && self.blamer.is_none()
+    }
+
+    /// Set `owners` and `last_author` on `violation`. Its file is relative
+    /// to the root CODEOWNERS and blame were loaded for.
+    pub fn attribute(&mut self, violation: &mut Violation) {
+        if let Some(ref codeowners) = self.codeowners {
+            violation.owners = codeowners.owners_of(&violation.file);
+        }
+        if let Some(ref mut blamer) = self.blamer {
+            violation.last_author = blamer.last_author(&violation.file, violation.line);
+        }
+    }
+
+    /// Attribute every violation in `results`.
+    pub fn attribute_results(&mut self, results: &mut [GateResult]) {
+        for violation in results.iter_mut().flat_map(|r| r.violations.iter_mut()) {
+            self.attribute(violation);
+        }
+    }
+}
+
+/// Unsuppressed violation counts for one owner.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OwnerSummary {
+    pub owner: String,
+    pub errors: usize,
+    pub warnings: usize,
+    pub total: usize,
+}
+
+/// Unsuppressed violations grouped by owner, most errors first. A violation
+/// with several owners counts for each; ones without go under `UNOWNED`.
+pub fn group_by_owner(results: &[GateResult]) -> Vec<(OwnerSummary, Vec<&Violation>)> {
+    let mut groups: BTreeMap<&str, Vec<&Violation>> = BTreeMap::new();
+    for violation in results.iter().flat_map(|r| &r.violations).filter(|v| !v.suppressed) {
+        if violation.owners.is_empty() {
+            groups.entry(UNOWNED).or_default().push(violation);
+        }
+        for owner in &violation.owners {
+            groups.entry(owner).or_default().push(violation);
+        }
+    }
+    let count = |violations: &[&Violation], severity: Severity| violations.iter().filter(|v| v.severity == severity).count();
+    let mut grouped: Vec<(OwnerSummary, Vec<&Violation>)> = groups
+        .into_iter()
+        .map(|(owner, violations)| {
+            let summary = OwnerSummary {
+                owner: owner.to_string(),
+                errors: count(&violations, Severity::Error),
+                warnings: count(&violations, Severity::Warning),
+                total: violations.len(),
+            };
+            (summary, violations)
+        })
+        .collect();
+    grouped.sort_by(|(a, _), (b, _)| {
+        b.errors
+            .cmp(&a.errors)
+            .then_with(|| b.total.cmp(&a.total))
+            .then_with(|| a.owner.cmp(&b.owner))
+    });
+    grouped
+}
+
+/// Whether any violation carries ownership information.
+pub fn has_ownership(results: &[GateResult]) -> bool {
+    results
+        .iter()
+        .flat_map(|r| &r.violations)
+        .any(|v| !v.owners.is_empty() || v.last_author.is_some())
+}
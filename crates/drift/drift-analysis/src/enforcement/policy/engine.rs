@@ -1,6 +1,6 @@
 //! Policy engine — aggregates gate results per mode.
 
-use crate::enforcement::gates::GateResult;
+use crate::enforcement::gates::{GateResult, GateStatus};
 use super::types::*;
 
 /// Policy engine: aggregates gate results according to the active policy.
@@ -28,7 +28,7 @@ impl PolicyEngine {
         // Required gates always block, regardless of aggregation mode
         let overall_passed = mode_passed && required_passed;
 
-        let gates_passed = results.iter().filter(|r| r.passed).count();
+        let gates_passed = results.iter().filter(|r| self.gate_passed(r)).count();
         let gates_failed = results.len() - gates_passed;
 
         let details = if !required_passed {
@@ -63,13 +63,25 @@ impl PolicyEngine {
             results
                 .iter()
                 .find(|r| r.gate_id == *required_id)
-                .is_some_and(|r| r.passed)
+                .is_some_and(|r| self.gate_passed(r))
         })
     }
 
+    /// Whether a gate passed and meets its per-gate threshold, if any.
+    /// Skipped gates have no meaningful score and are not held to one.
+    fn gate_passed(&self, result: &GateResult) -> bool {
+        result.passed
+            && (result.status == GateStatus::Skipped
+                || self
+                    .policy
+                    .gate_thresholds
+                    .get(result.gate_id.as_str())
+                    .map_or(true, |&min| result.score >= min))
+    }
+
     /// All gates must pass.
     fn all_must_pass(&self, results: &[GateResult]) -> (bool, f64) {
-        let all_pass = results.iter().all(|r| r.passed);
+        let all_pass = results.iter().all(|r| self.gate_passed(r));
         let avg_score = if results.is_empty() {
             100.0
         } else {
@@ -80,7 +92,7 @@ impl PolicyEngine {
 
     /// At least one gate must pass.
     fn any_must_pass(&self, results: &[GateResult]) -> (bool, f64) {
-        let any_pass = results.iter().any(|r| r.passed);
+        let any_pass = results.iter().any(|r| self.gate_passed(r));
         let max_score = results
             .iter()
             .map(|r| r.score)
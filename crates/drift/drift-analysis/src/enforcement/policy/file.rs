@@ -0,0 +1,242 @@
+//! Policy files — `drift-policy.toml` schema, loading and inheritance.
+//!
+//! ```toml
+//! default = "base"
+//!
+//! [policies.base]
+//! extends = "standard"
+//! threshold = 75
+//!
+//! [policies.payments]
+//! extends = "strict"
+//! gate_thresholds = { test-coverage = 90 }
+//!
+//! [[overrides]]
+//! paths = ["packages/payments/**"]
+//! policy = "payments"
+//!
+//! [[overrides]]
+//! paths = ["tools/**"]
+//! disabled_detectors = ["naming-*"]
+//! severity = { "pattern-compliance/*" = "warning" }
+//! ramp_start = "2026-09-01"
+//! ramp_end = "2026-12-01"
+//!
+//! [packages.web]
+//! policy = "base"
+//!
+//! [owners."@acme/payments"]
+//! threshold = 90
+//! ```
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+
+use drift_core::errors::GateError;
+use serde::{Deserialize, Serialize};
+
+use super::set::ScopedPolicy;
+use super::types::{AggregationMode, Policy, PolicyPreset};
+use crate::enforcement::gates::{DiffScope, GateId};
+use crate::enforcement::rules::Severity;
+
+/// Default policy file name, at the project root or a package root.
+pub const POLICY_FILE: &str = "drift-policy.toml";
+
+/// Settings a policy, override or package entry can set. Unset fields are
+/// inherited; maps merge key by key and detector lists accumulate.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PolicySettings {
+    pub aggregation_mode: Option<AggregationMode>,
+    pub threshold: Option<f64>,
+    pub weights: HashMap<String, f64>,
+    pub required_gates: Option<Vec<GateId>>,
+    pub progressive: Option<bool>,
+    pub ramp_up_days: Option<u32>,
+    /// Start of the progressive ramp ("YYYY-MM-DD").
+    pub ramp_start: Option<String>,
+    /// End of the progressive ramp ("YYYY-MM-DD"); sets `ramp_up_days`.
+    pub ramp_end: Option<String>,
+    pub diff_scope: Option<DiffScope>,
+    /// Minimum score per gate (gate_id → 0-100).
+    pub gate_thresholds: HashMap<String, f64>,
+    /// Detector or rule ids (globs) whose findings are ignored.
+    pub disabled_detectors: Vec<String>,
+    /// Severity remaps: rule or detector id (glob) → severity.
+    pub severity: BTreeMap<String, Severity>,
+}
+
+/// A named policy. `extends` names another policy in the file or a preset
+/// ("strict", "standard", "lenient"); without it, "standard" is the base.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PolicySpec {
+    pub extends: Option<String>,
+    #[serde(flatten)]
+    pub settings: PolicySettings,
+}
+
+/// Settings for files matching `paths` (globs relative to the file's root).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PathOverride {
+    pub paths: Vec<String>,
+    /// Switch matching files to this policy before applying `settings`.
+    pub policy: Option<String>,
+    #[serde(flatten)]
+    pub settings: PolicySettings,
+}
+
+/// Settings for a monorepo package, keyed by package name, or for a code
+/// owner, keyed by CODEOWNERS owner.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PackagePolicy {
+    pub policy: Option<String>,
+    #[serde(flatten)]
+    pub settings: PolicySettings,
+}
+
+/// A parsed `drift-policy.toml`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PolicyFile {
+    /// Policy for files no override matches. Default: the "standard" preset.
+    pub default: Option<String>,
+    pub policies: BTreeMap<String, PolicySpec>,
+    /// Applied in order; later overrides win.
+    pub overrides: Vec<PathOverride>,
+    pub packages: BTreeMap<String, PackagePolicy>,
+    /// Settings for files a CODEOWNERS owner owns.
+    pub owners: BTreeMap<String, PackagePolicy>,
+}
+
+impl PolicyFile {
+    /// Parse policy TOML. `path` is only used in error messages.
+    pub fn parse(content: &str, path: &str) -> Result<Self, GateError> {
+        let file: Self = toml::from_str(content).map_err(|e| policy_error(path, e.to_string()))?;
+        for spec in file.policies.values() {
+            validate_settings(&spec.settings, path)?;
+        }
+        for entry in &file.overrides {
+            if entry.paths.is_empty() {
+                return Err(policy_error(path, "override without `paths`".to_string()));
+            }
+            validate_settings(&entry.settings, path)?;
+        }
+        for entry in file.packages.values().chain(file.owners.values()) {
+            validate_settings(&entry.settings, path)?;
+        }
+        Ok(file)
+    }
+
+    /// Load a policy file.
+    pub fn load(path: &Path) -> Result<Self, GateError> {
+        let display = path.display().to_string();
+        let content = std::fs::read_to_string(path).map_err(|e| policy_error(&display, e.to_string()))?;
+        Self::parse(&content, &display)
+    }
+
+    /// Load `drift-policy.toml` from `dir`, if present.
+    pub fn load_from_dir(dir: &Path) -> Result<Option<Self>, GateError> {
+        let path = dir.join(POLICY_FILE);
+        if !path.is_file() {
+            return Ok(None);
+        }
+        Self::load(&path).map(Some)
+    }
+}
+
+/// Resolve policy `name`, following `extends`. `tables` are searched in
+/// order, so a package file's policies shadow the root file's; a policy
+/// that extends its own name continues the search past its table.
+pub(crate) fn resolve_policy(
+    name: &str,
+    tables: &[&BTreeMap<String, PolicySpec>],
+    path: &str,
+) -> Result<ScopedPolicy, GateError> {
+    resolve_in(name, tables, &mut Vec::new(), path)
+}
+
+fn resolve_in(
+    name: &str,
+    tables: &[&BTreeMap<String, PolicySpec>],
+    chain: &mut Vec<(String, usize)>,
+    path: &str,
+) -> Result<ScopedPolicy, GateError> {
+    let Some((index, spec)) = tables.iter().enumerate().find_map(|(i, t)| t.get(name).map(|s| (i, s))) else {
+        return preset(name)
+            .map(ScopedPolicy::new)
+            .ok_or_else(|| policy_error(path, format!("unknown policy '{name}'")));
+    };
+    // `tables` is always a suffix of the original list, so the number of
+    // tables from here to the end identifies the defining table.
+    let key = (name.to_string(), tables.len() - index);
+    if chain.contains(&key) {
+        let names: Vec<&str> = chain.iter().map(|(n, _)| n.as_str()).chain([name]).collect();
+        return Err(policy_error(path, format!("inheritance cycle: {}", names.join(" -> "))));
+    }
+    chain.push(key);
+
+    let mut scoped = match spec.extends.as_deref() {
+        Some(parent) if parent == name => resolve_in(parent, &tables[index + 1..], chain, path)?,
+        Some(parent) => resolve_in(parent, &tables[index..], chain, path)?,
+        None => ScopedPolicy::new(Policy::standard()),
+    };
+    scoped.policy.name = name.to_string();
+    scoped.policy.preset = PolicyPreset::Custom;
+    scoped.apply(&spec.settings);
+    chain.pop();
+    Ok(scoped)
+}
+
+fn preset(name: &str) -> Option<Policy> {
+    match name {
+        "strict" => Some(Policy::strict()),
+        "standard" => Some(Policy::standard()),
+        "lenient" => Some(Policy::lenient()),
+        _ => None,
+    }
+}
+
+fn validate_settings(settings: &PolicySettings, path: &str) -> Result<(), GateError> {
+    for date in [&settings.ramp_start, &settings.ramp_end].into_iter().flatten() {
+        if parse_date(date).is_none() {
+            return Err(policy_error(path, format!("invalid date '{date}' (expected YYYY-MM-DD)")));
+        }
+    }
+    if let (Some(start), Some(end)) = (&settings.ramp_start, &settings.ramp_end) {
+        if parse_date(end) < parse_date(start) {
+            return Err(policy_error(path, format!("ramp_end {end} is before ramp_start {start}")));
+        }
+    }
+    let globs = settings.disabled_detectors.iter().chain(settings.severity.keys());
+    for pattern in globs {
+        glob::Pattern::new(pattern).map_err(|e| policy_error(path, format!("invalid pattern '{pattern}': {e}")))?;
+    }
+    Ok(())
+}
+
+/// Parse a "YYYY-MM-DD" date into days since the Unix epoch.
+pub fn parse_date(date: &str) -> Option<u32> {
+    let mut parts = date.trim().splitn(3, '-');
+    let year: i64 = parts.next()?.parse().ok()?;
+    let month: i64 = parts.next()?.parse().ok()?;
+    let day: i64 = parts.next()?.parse().ok()?;
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+    // Days from civil (proleptic Gregorian calendar).
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    u32::try_from(era * 146_097 + doe - 719_468).ok()
+}
+
+pub(crate) fn policy_error(path: &str, message: String) -> GateError {
+    GateError::PolicyFileInvalid { path: path.to_string(), message }
+}
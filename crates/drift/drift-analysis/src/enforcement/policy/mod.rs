@@ -1,7 +1,15 @@
 //! Policy engine — aggregates gate results via 4 modes.
+//!
+//! Policies can also come from `drift-policy.toml`, with inheritance,
+//! glob-scoped overrides and per-package or per-owner policies (see `file`
+//! and `set`).
 
 pub mod types;
 pub mod engine;
+pub mod file;
+pub mod set;
 
 pub use types::*;
 pub use engine::PolicyEngine;
+pub use file::{parse_date, PackagePolicy, PathOverride, PolicyFile, PolicySettings, PolicySpec, POLICY_FILE};
+pub use set::{PolicyReport, PolicyScope, PolicySet, ScopeReport, ScopedPolicy};
@@ -0,0 +1,511 @@
+//! Policy sets — per-path policy resolution and scoped gate evaluation.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::Path;
+
+use drift_core::errors::GateError;
+use drift_core::workspace::monorepo::PackageInfo;
+use serde::{Deserialize, Serialize};
+
+use super::engine::PolicyEngine;
+use super::file::{parse_date, policy_error, resolve_policy, PolicyFile, PolicySettings, PolicySpec, POLICY_FILE};
+use super::types::{Policy, PolicyResult};
+use crate::enforcement::gates::{GateInput, GateOrchestrator, GateResult, GateStatus, ProgressiveConfig};
+use crate::enforcement::ownership::CodeOwners;
+use crate::enforcement::rules::{Severity, Violation};
+
+/// A fully resolved policy, plus the settings that act on individual
+/// findings rather than on gate aggregation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScopedPolicy {
+    pub policy: Policy,
+    /// Detector or rule id globs whose findings are ignored.
+    pub disabled_detectors: Vec<String>,
+    /// Rule or detector id glob → severity.
+    pub severity_overrides: BTreeMap<String, Severity>,
+    /// Start of the progressive ramp, in days since the Unix epoch. Without
+    /// it, progressive enforcement has nothing to count from and stays off.
+    pub ramp_start: Option<u32>,
+    /// Compiled forms of the glob ids above, built as settings are applied.
+    #[serde(skip)]
+    globs: HashMap<String, glob::Pattern>,
+}
+
+impl Default for ScopedPolicy {
+    fn default() -> Self {
+        Self::new(Policy::default())
+    }
+}
+
+impl ScopedPolicy {
+    pub fn new(policy: Policy) -> Self {
+        Self {
+            policy,
+            disabled_detectors: Vec::new(),
+            severity_overrides: BTreeMap::new(),
+            ramp_start: None,
+            globs: HashMap::new(),
+        }
+    }
+
+    /// Layer `settings` on top. Dates must already be validated.
+    pub(crate) fn apply(&mut self, settings: &PolicySettings) {
+        let policy = &mut self.policy;
+        if let Some(mode) = settings.aggregation_mode {
+            policy.aggregation_mode = mode;
+        }
+        if let Some(threshold) = settings.threshold {
+            policy.threshold = threshold;
+        }
+        policy.weights.extend(settings.weights.iter().map(|(k, v)| (k.clone(), *v)));
+        if let Some(ref gates) = settings.required_gates {
+            policy.required_gates = gates.clone();
+        }
+        if let Some(progressive) = settings.progressive {
+            policy.progressive = progressive;
+        }
+        if let Some(days) = settings.ramp_up_days {
+            policy.ramp_up_days = days;
+        }
+        if let Some(scope) = settings.diff_scope {
+            policy.diff_scope = Some(scope);
+        }
+        policy.gate_thresholds.extend(settings.gate_thresholds.iter().map(|(k, v)| (k.clone(), *v)));
+
+        if let Some(start) = settings.ramp_start.as_deref().and_then(parse_date) {
+            self.ramp_start = Some(start);
+        }
+        if let Some(end) = settings.ramp_end.as_deref().and_then(parse_date) {
+            if let Some(start) = self.ramp_start {
+                self.policy.ramp_up_days = end.saturating_sub(start);
+            }
+        }
+        for detector in &settings.disabled_detectors {
+            if !self.disabled_detectors.contains(detector) {
+                self.disabled_detectors.push(detector.clone());
+            }
+        }
+        self.severity_overrides.extend(settings.severity.iter().map(|(k, v)| (k.clone(), *v)));
+        for id in settings.disabled_detectors.iter().chain(settings.severity.keys()) {
+            if !self.globs.contains_key(id) {
+                if let Ok(pattern) = glob::Pattern::new(id) {
+                    self.globs.insert(id.clone(), pattern);
+                }
+            }
+        }
+    }
+
+    /// Whether `id` is `pattern` or matches it as a glob. Ids set without
+    /// going through [`Self::apply`] are compiled on the fly.
+    fn matches_id(&self, pattern: &str, id: &str) -> bool {
+        if pattern == id {
+            return true;
+        }
+        match self.globs.get(pattern) {
+            Some(glob) => glob.matches(id),
+            None => glob::Pattern::new(pattern).is_ok_and(|p| p.matches(id)),
+        }
+    }
+
+    /// Whether findings from detector or rule `id` are ignored.
+    pub fn is_disabled(&self, id: &str) -> bool {
+        self.disabled_detectors.iter().any(|p| self.matches_id(p, id))
+    }
+
+    fn is_violation_disabled(&self, violation: &Violation) -> bool {
+        self.is_disabled(&violation.pattern_id) || self.is_disabled(&violation.rule_id)
+    }
+
+    /// The remapped severity for `violation`, if any. An exact rule or
+    /// detector id wins over globs; among globs, the longest one wins.
+    pub fn severity_for(&self, violation: &Violation) -> Option<Severity> {
+        let ids = [violation.rule_id.as_str(), violation.pattern_id.as_str()];
+        ids.iter()
+            .find_map(|id| self.severity_overrides.get(*id))
+            .or_else(|| {
+                self.severity_overrides
+                    .iter()
+                    .filter(|(pattern, _)| ids.iter().any(|id| self.matches_id(pattern, id)))
+                    .max_by_key(|(pattern, _)| pattern.len())
+                    .map(|(_, severity)| severity)
+            })
+            .copied()
+    }
+
+    /// Progressive enforcement as of `today` (days since the Unix epoch).
+    pub fn progressive_config(&self, today: u32) -> ProgressiveConfig {
+        ProgressiveConfig {
+            enabled: self.policy.progressive && self.ramp_start.is_some(),
+            ramp_up_days: self.policy.ramp_up_days,
+            project_age_days: self.ramp_start.map_or(0, |start| today.saturating_sub(start)),
+        }
+    }
+
+    /// Drop patterns from disabled detectors before the gates see them.
+    pub fn apply_to_input(&self, input: &mut GateInput) {
+        if !self.disabled_detectors.is_empty() {
+            input.patterns.retain(|p| !self.is_disabled(&p.pattern_id));
+        }
+    }
+
+    /// Drop disabled findings, remap severities and re-grade the gate.
+    pub fn apply_to_result(&self, result: &mut GateResult) {
+        let mut changed = false;
+        for violation in &mut result.violations {
+            if let Some(severity) = self.severity_for(violation) {
+                changed |= violation.severity != severity;
+                violation.severity = severity;
+            }
+        }
+        let disabled = result.violations.iter().filter(|v| self.is_violation_disabled(v)).count();
+        if !changed && disabled == 0 {
+            return;
+        }
+
+        let escalated = result
+            .violations
+            .iter()
+            .any(|v| !v.suppressed && v.severity == Severity::Error && !self.is_violation_disabled(v));
+        if escalated && matches!(result.status, GateStatus::Passed | GateStatus::Warned) {
+            result.status = GateStatus::Failed;
+            result.passed = false;
+        } else {
+            result.regrade(|v| !self.is_violation_disabled(v));
+        }
+        result.violations.retain(|v| !self.is_violation_disabled(v));
+    }
+}
+
+/// One override layer: files matching `patterns`, or owned by `owner`,
+/// switch to `policy` (if set) and then get `settings` applied.
+#[derive(Debug, Clone)]
+struct PolicyLayer {
+    label: String,
+    patterns: Vec<glob::Pattern>,
+    owner: Option<String>,
+    policy: Option<ScopedPolicy>,
+    settings: PolicySettings,
+}
+
+impl PolicyLayer {
+    fn new(
+        label: String,
+        prefix: &str,
+        paths: &[String],
+        policy: Option<ScopedPolicy>,
+        settings: &PolicySettings,
+        source: &str,
+    ) -> Result<Self, GateError> {
+        let patterns = paths
+            .iter()
+            .map(|p| {
+                let p = p.trim_start_matches("./");
+                glob::Pattern::new(&format!("{prefix}{p}"))
+                    .map_err(|e| policy_error(source, format!("invalid path pattern '{p}': {e}")))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { label, patterns, owner: None, policy, settings: settings.clone() })
+    }
+
+    fn for_owner(owner: &str, policy: Option<ScopedPolicy>, settings: &PolicySettings) -> Self {
+        Self {
+            label: format!("owner {owner}"),
+            patterns: Vec::new(),
+            owner: Some(owner.to_string()),
+            policy,
+            settings: settings.clone(),
+        }
+    }
+
+    fn matches(&self, file: &str, owners: &[String]) -> bool {
+        if let Some(ref owner) = self.owner {
+            return owners.contains(owner);
+        }
+        let options = glob::MatchOptions { require_literal_separator: true, ..Default::default() };
+        self.patterns.iter().any(|p| p.matches_with(file, options))
+    }
+}
+
+/// Files governed by the same resolved policy.
+#[derive(Debug, Clone)]
+pub struct PolicyScope {
+    /// "default", or the matching layers joined with " > ".
+    pub label: String,
+    pub policy: ScopedPolicy,
+    pub files: Vec<String>,
+}
+
+/// Gate results and policy verdict for one scope.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScopeReport {
+    pub label: String,
+    pub files: Vec<String>,
+    pub results: Vec<GateResult>,
+    pub verdict: PolicyResult,
+}
+
+/// Outcome of evaluating a repository scope by scope.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PolicyReport {
+    pub scopes: Vec<ScopeReport>,
+}
+
+impl PolicyReport {
+    /// Whether every scope passed its policy.
+    pub fn passed(&self) -> bool {
+        self.scopes.iter().all(|s| s.verdict.overall_passed)
+    }
+
+    /// One result per gate across all scopes: violations and warnings are
+    /// combined, the gate passes only if it passed everywhere, and the score
+    /// is the lowest one.
+    pub fn merged_results(&self) -> Vec<GateResult> {
+        if self.scopes.len() == 1 {
+            return self.scopes[0].results.clone();
+        }
+        let mut merged: Vec<GateResult> = Vec::new();
+        for scope in &self.scopes {
+            for result in &scope.results {
+                let summary = format!("{}: {}", scope.label, result.summary);
+                let Some(gate) = merged.iter_mut().find(|g| g.gate_id == result.gate_id) else {
+                    let mut first = result.clone();
+                    first.summary = summary;
+                    first.details = serde_json::json!({ "scopes": { scope.label.clone(): result.details } });
+                    merged.push(first);
+                    continue;
+                };
+                // Skipped runs carry no score.
+                if result.status != GateStatus::Skipped {
+                    gate.score = if gate.status == GateStatus::Skipped {
+                        result.score
+                    } else {
+                        gate.score.min(result.score)
+                    };
+                }
+                if status_rank(result.status) > status_rank(gate.status) {
+                    gate.status = result.status;
+                }
+                gate.passed &= result.passed;
+                gate.summary = format!("{}; {summary}", gate.summary);
+                gate.violations.extend(result.violations.iter().cloned());
+                gate.warnings.extend(result.warnings.iter().cloned());
+                gate.execution_time_ms += result.execution_time_ms;
+                if gate.error.is_none() {
+                    gate.error = result.error.clone();
+                }
+                if let Some(scopes) = gate.details.get_mut("scopes").and_then(|s| s.as_object_mut()) {
+                    scopes.insert(scope.label.clone(), result.details.clone());
+                }
+            }
+        }
+        merged
+    }
+}
+
+fn status_rank(status: GateStatus) -> u8 {
+    match status {
+        GateStatus::Skipped => 0,
+        GateStatus::Passed => 1,
+        GateStatus::Warned => 2,
+        GateStatus::Failed => 3,
+        GateStatus::Errored => 4,
+    }
+}
+
+/// Policies for a repository: a default plus path-, package- and
+/// owner-scoped layers.
+///
+/// Layers apply in order — root `[packages.*]` entries and package policy
+/// files first, then root `[owners.*]` entries, then root `[[overrides]]`,
+/// then package-file overrides — and later layers win. Owner entries only
+/// apply once CODEOWNERS is attached with `with_codeowners`.
+#[derive(Debug, Clone)]
+pub struct PolicySet {
+    default: ScopedPolicy,
+    layers: Vec<PolicyLayer>,
+    codeowners: Option<CodeOwners>,
+    today: u32,
+}
+
+impl PolicySet {
+    /// A set with only a default policy.
+    pub fn new(default: ScopedPolicy) -> Self {
+        Self { default, layers: Vec::new(), codeowners: None, today: today() }
+    }
+
+    /// Resolve a root policy file and the package policy files next to it.
+    /// `packages` come from monorepo detection; their paths are relative to
+    /// the root.
+    pub fn from_files(root: &PolicyFile, packages: &[(PackageInfo, Option<PolicyFile>)]) -> Result<Self, GateError> {
+        let root_tables = [&root.policies];
+        let default = match root.default.as_deref() {
+            Some(name) => resolve_policy(name, &root_tables, POLICY_FILE)?,
+            None => ScopedPolicy::default(),
+        };
+        let mut set = Self::new(default);
+        let resolve = |name: Option<&str>, tables: &[&BTreeMap<String, PolicySpec>], source: &str| {
+            name.map(|n| resolve_policy(n, tables, source)).transpose()
+        };
+
+        let mut packages: Vec<&(PackageInfo, Option<PolicyFile>)> = packages.iter().collect();
+        packages.sort_by(|a, b| a.0.path.cmp(&b.0.path));
+        let package_glob = vec!["**".to_string()];
+        let mut package_overrides = Vec::new();
+        for (package, file) in packages {
+            let prefix = format!("{}/", package.path.to_string_lossy().replace('\\', "/").trim_end_matches('/'));
+            if let Some(entry) = root.packages.get(&package.name) {
+                let policy = resolve(entry.policy.as_deref(), &root_tables, POLICY_FILE)?;
+                let label = format!("package {}", package.name);
+                set.layers.push(PolicyLayer::new(label, &prefix, &package_glob, policy, &entry.settings, POLICY_FILE)?);
+            }
+            let Some(file) = file else { continue };
+            let source = format!("{prefix}{POLICY_FILE}");
+            let tables = [&file.policies, &root.policies];
+            if let Some(name) = file.default.as_deref() {
+                let policy = resolve_policy(name, &tables, &source)?;
+                set.layers.push(PolicyLayer::new(
+                    source.clone(),
+                    &prefix,
+                    &package_glob,
+                    Some(policy),
+                    &PolicySettings::default(),
+                    &source,
+                )?);
+            }
+            for entry in &file.overrides {
+                let policy = resolve(entry.policy.as_deref(), &tables, &source)?;
+                let label = format!("{source} {}", entry.paths.join(", "));
+                package_overrides.push(PolicyLayer::new(label, &prefix, &entry.paths, policy, &entry.settings, &source)?);
+            }
+        }
+        for (owner, entry) in &root.owners {
+            let policy = resolve(entry.policy.as_deref(), &root_tables, POLICY_FILE)?;
+            set.layers.push(PolicyLayer::for_owner(owner, policy, &entry.settings));
+        }
+        for entry in &root.overrides {
+            let policy = resolve(entry.policy.as_deref(), &root_tables, POLICY_FILE)?;
+            let label = entry.paths.join(", ");
+            set.layers.push(PolicyLayer::new(label, "", &entry.paths, policy, &entry.settings, POLICY_FILE)?);
+        }
+        set.layers.extend(package_overrides);
+        Ok(set)
+    }
+
+    /// Load `drift-policy.toml` from `root` and from each package root.
+    /// Returns `None` if there is no policy file anywhere. `[owners.*]`
+    /// entries need CODEOWNERS attached with `with_codeowners`.
+    pub fn load(root: &Path, packages: &[PackageInfo]) -> Result<Option<Self>, GateError> {
+        let root_file = PolicyFile::load_from_dir(root)?;
+        let mut with_files = Vec::new();
+        for package in packages {
+            let file = PolicyFile::load_from_dir(&root.join(&package.path))?;
+            with_files.push((package.clone(), file));
+        }
+        if root_file.is_none() && with_files.iter().all(|(_, f)| f.is_none()) {
+            return Ok(None);
+        }
+        Self::from_files(&root_file.unwrap_or_default(), &with_files).map(Some)
+    }
+
+    /// Route `[owners.*]` entries by these CODEOWNERS rules. File paths are
+    /// matched relative to the CODEOWNERS root.
+    pub fn with_codeowners(mut self, codeowners: CodeOwners) -> Self {
+        self.codeowners = Some(codeowners);
+        self
+    }
+
+    /// Evaluate progressive ramps as of `today` (days since the Unix epoch)
+    /// instead of the current date.
+    pub fn with_today(mut self, today: u32) -> Self {
+        self.today = today;
+        self
+    }
+
+    pub fn default_policy(&self) -> &ScopedPolicy {
+        &self.default
+    }
+
+    /// The policy governing `file`.
+    pub fn policy_for(&self, file: &str) -> ScopedPolicy {
+        self.resolve(&self.matching_layers(file))
+    }
+
+    fn matching_layers(&self, file: &str) -> Vec<usize> {
+        let owners = self.codeowners.as_ref().map(|c| c.owners_of(file)).unwrap_or_default();
+        (0..self.layers.len()).filter(|&i| self.layers[i].matches(file, &owners)).collect()
+    }
+
+    fn resolve(&self, layers: &[usize]) -> ScopedPolicy {
+        let mut scoped = self.default.clone();
+        for &i in layers {
+            let layer = &self.layers[i];
+            if let Some(ref policy) = layer.policy {
+                scoped = policy.clone();
+            }
+            scoped.apply(&layer.settings);
+        }
+        scoped
+    }
+
+    /// Group `files` by the policy that governs them, in first-seen order.
+    pub fn partition(&self, files: &[String]) -> Vec<PolicyScope> {
+        let mut groups: Vec<(Vec<usize>, Vec<String>)> = Vec::new();
+        let mut index: HashMap<Vec<usize>, usize> = HashMap::new();
+        for file in files {
+            let layers = self.matching_layers(file);
+            let slot = *index.entry(layers.clone()).or_insert_with(|| {
+                groups.push((layers, Vec::new()));
+                groups.len() - 1
+            });
+            groups[slot].1.push(file.clone());
+        }
+        groups
+            .into_iter()
+            .map(|(layers, files)| PolicyScope {
+                label: if layers.is_empty() {
+                    "default".to_string()
+                } else {
+                    layers.iter().map(|&i| self.layers[i].label.as_str()).collect::<Vec<_>>().join(" > ")
+                },
+                policy: self.resolve(&layers),
+                files,
+            })
+            .collect()
+    }
+
+    /// Run the gates once per scope on that scope's files and judge each run
+    /// by its own policy. `configure` adds run-wide settings (baseline, diff
+    /// scope) to each scope's orchestrator.
+    pub fn evaluate(
+        &self,
+        input: &GateInput,
+        configure: impl Fn(GateOrchestrator, &ScopedPolicy) -> GateOrchestrator,
+    ) -> Result<PolicyReport, String> {
+        let mut scopes = self.partition(&input.files);
+        if scopes.is_empty() {
+            scopes.push(PolicyScope { label: "default".to_string(), policy: self.default.clone(), files: Vec::new() });
+        }
+
+        let mut report = PolicyReport::default();
+        for scope in scopes {
+            let files: HashSet<String> = scope.files.iter().cloned().collect();
+            let mut scoped_input = input.restrict_to(&files);
+            scope.policy.apply_to_input(&mut scoped_input);
+
+            let orchestrator = GateOrchestrator::new().with_progressive(scope.policy.progressive_config(self.today));
+            let mut results = configure(orchestrator, &scope.policy).execute(&scoped_input)?;
+            for result in &mut results {
+                scope.policy.apply_to_result(result);
+            }
+            let verdict = PolicyEngine::new(scope.policy.policy.clone()).evaluate(&results);
+            report.scopes.push(ScopeReport { label: scope.label, files: scope.files, results, verdict });
+        }
+        Ok(report)
+    }
+}
+
+fn today() -> u32 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| (d.as_secs() / 86_400) as u32)
+}
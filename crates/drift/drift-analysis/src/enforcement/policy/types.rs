@@ -3,7 +3,7 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-use crate::enforcement::gates::GateId;
+use crate::enforcement::gates::{DiffScope, GateId};
 
 /// Policy presets.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -44,6 +44,13 @@ pub struct Policy {
     /// Progressive enforcement config.
     pub progressive: bool,
     pub ramp_up_days: u32,
+    /// Report only violations within the change range under review.
+    #[serde(default)]
+    pub diff_scope: Option<DiffScope>,
+    /// Minimum score per gate (gate_id → 0-100). A gate scoring below its
+    /// threshold counts as failed even if it passed on its own.
+    #[serde(default)]
+    pub gate_thresholds: HashMap<String, f64>,
 }
 
 impl Default for Policy {
@@ -64,6 +71,8 @@ impl Policy {
             required_gates: GateId::all().to_vec(),
             progressive: false,
             ramp_up_days: 0,
+            diff_scope: None,
+            gate_thresholds: HashMap::new(),
         }
     }
 
@@ -88,6 +97,8 @@ impl Policy {
             ],
             progressive: true,
             ramp_up_days: 30,
+            diff_scope: None,
+            gate_thresholds: HashMap::new(),
         }
     }
 
@@ -102,6 +113,8 @@ impl Policy {
             required_gates: Vec::new(),
             progressive: true,
             ramp_up_days: 60,
+            diff_scope: None,
+            gate_thresholds: HashMap::new(),
         }
     }
 }
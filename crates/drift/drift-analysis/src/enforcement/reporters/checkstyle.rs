@@ -0,0 +1,111 @@
+//! Checkstyle XML reporter — the de facto interchange format for lint results.
+//!
+//! Consumed by Jenkins warnings-ng, reviewdog, Danger and most review bots.
+//! Violations are grouped per `<file>`, files sorted by path, so output is
+//! stable across runs for the same findings.
+
+use std::collections::BTreeMap;
+
+use crate::enforcement::gates::GateResult;
+use crate::enforcement::rules::{Severity, Violation};
+use super::Reporter;
+
+/// Checkstyle XML reporter.
+///
+/// Each violation becomes an `<error>` with line, column, severity, message,
+/// and `source` set to `drift.<gate>.<rule_id>`. CWE/OWASP tags are appended
+/// to the message since Checkstyle has no dedicated attribute for them.
+pub struct CheckstyleReporter;
+
+impl CheckstyleReporter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn escape_xml(s: &str) -> String {
+        s.replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+            .replace('"', "&quot;")
+            .replace('\'', "&apos;")
+    }
+
+    /// Checkstyle knows `error`, `warning`, `info` and `ignore`; hints are
+    /// still worth surfacing, so they map to `info`.
+    fn severity_to_checkstyle(severity: &Severity) -> &'static str {
+        match severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Info | Severity::Hint => "info",
+        }
+    }
+
+    fn message(violation: &Violation) -> String {
+        let mut tags = Vec::new();
+        if let Some(cwe_id) = violation.cwe_id {
+            tags.push(format!("CWE-{cwe_id}"));
+        }
+        if let Some(ref owasp) = violation.owasp_category {
+            tags.push(owasp.clone());
+        }
+        if tags.is_empty() {
+            violation.message.clone()
+        } else {
+            format!("{} [{}]", violation.message, tags.join(", "))
+        }
+    }
+}
+
+impl Default for CheckstyleReporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Reporter for CheckstyleReporter {
+    fn name(&self) -> &'static str {
+        "checkstyle"
+    }
+
+    fn generate(&self, results: &[GateResult]) -> Result<String, String> {
+        let mut by_file: BTreeMap<&str, Vec<(&GateResult, &Violation)>> = BTreeMap::new();
+        for gate_result in results {
+            for violation in &gate_result.violations {
+                if violation.suppressed {
+                    continue;
+                }
+                by_file.entry(violation.file.as_str()).or_default().push((gate_result, violation));
+            }
+        }
+
+        let mut xml = String::new();
+        xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        xml.push_str("<checkstyle version=\"8.0\">\n");
+
+        for (file, mut entries) in by_file {
+            entries.sort_by(|(_, a), (_, b)| {
+                a.line.cmp(&b.line)
+                    .then(a.column.cmp(&b.column))
+                    .then(a.rule_id.cmp(&b.rule_id))
+            });
+            xml.push_str(&format!("  <file name=\"{}\">\n", Self::escape_xml(file)));
+            for (gate_result, violation) in entries {
+                xml.push_str(&format!("    <error line=\"{}\"", violation.line.max(1)));
+                if let Some(column) = violation.column {
+                    xml.push_str(&format!(" column=\"{column}\""));
+                }
+                xml.push_str(&format!(
+                    " severity=\"{}\" message=\"{}\" source=\"drift.{}.{}\" />\n",
+                    Self::severity_to_checkstyle(&violation.severity),
+                    Self::escape_xml(&Self::message(violation)),
+                    Self::escape_xml(gate_result.gate_id.as_str()),
+                    Self::escape_xml(&violation.rule_id),
+                ));
+            }
+            xml.push_str("  </file>\n");
+        }
+
+        xml.push_str("</checkstyle>\n");
+        Ok(xml)
+    }
+}
@@ -0,0 +1,154 @@
+//! Code Climate reporter — JSON issues per the Code Climate engine specification.
+//!
+//! A superset of the GitLab Code Quality subset: column positions, markdown
+//! `content`, `remediation_points` and engine metadata. Emitted as a JSON
+//! array, as `codeclimate analyze -f json` does.
+//! See: https://github.com/codeclimate/platform/blob/master/spec/analyzers/SPEC.md
+
+use std::collections::HashMap;
+
+use serde_json::{json, Value};
+
+use crate::enforcement::gates::GateResult;
+use crate::enforcement::rules::{Severity, Violation};
+use crate::scanner::hasher::hash_content;
+use super::gitlab::GitLabCodeQualityReporter;
+use super::Reporter;
+
+/// Code Climate engine-spec reporter.
+///
+/// Fingerprints are derived from rule, file and message — not the line — so
+/// an issue keeps its identity when unrelated edits shift it up or down.
+/// Identical findings in one file are told apart by their order of
+/// appearance.
+pub struct CodeClimateReporter;
+
+impl CodeClimateReporter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn severity_to_codeclimate(severity: &Severity) -> &'static str {
+        match severity {
+            Severity::Error => "critical",
+            Severity::Warning => "major",
+            Severity::Info => "minor",
+            Severity::Hint => "info",
+        }
+    }
+
+    /// Effort estimate; the spec's baseline of 50,000 is a trivial fix.
+    fn remediation_points(severity: &Severity) -> u64 {
+        match severity {
+            Severity::Error => 200_000,
+            Severity::Warning => 100_000,
+            Severity::Info => 50_000,
+            Severity::Hint => 25_000,
+        }
+    }
+
+    /// Stable fingerprint: xxh3 of rule, file, message and occurrence index.
+    fn fingerprint(violation: &Violation, occurrence: usize) -> String {
+        let key = format!(
+            "{}\0{}\0{}\0{}",
+            violation.rule_id, violation.file, violation.message, occurrence
+        );
+        format!("{:016x}", hash_content(key.as_bytes()))
+    }
+
+    fn location(violation: &Violation) -> Value {
+        let begin = violation.line.max(1);
+        let end = violation.end_line.unwrap_or(violation.line).max(begin);
+        match violation.column {
+            Some(column) => json!({
+                "path": violation.file,
+                "positions": {
+                    "begin": { "line": begin, "column": column.max(1) },
+                    "end": {
+                        "line": end,
+                        "column": violation.end_column.unwrap_or(column).max(1)
+                    }
+                }
+            }),
+            None => json!({
+                "path": violation.file,
+                "lines": { "begin": begin, "end": end }
+            }),
+        }
+    }
+
+    fn content_body(violation: &Violation) -> String {
+        let mut body = violation.message.clone();
+        if let Some(cwe_id) = violation.cwe_id {
+            body.push_str(&format!(
+                "\n\n**CWE:** [CWE-{cwe_id}](https://cwe.mitre.org/data/definitions/{cwe_id}.html)"
+            ));
+        }
+        if let Some(ref owasp) = violation.owasp_category {
+            body.push_str(&format!("\n\n**OWASP:** {owasp}"));
+        }
+        if let Some(ref fix) = violation.quick_fix {
+            body.push_str(&format!("\n\n**Suggested fix:** {}", fix.description));
+        }
+        body
+    }
+}
+
+impl Default for CodeClimateReporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Reporter for CodeClimateReporter {
+    fn name(&self) -> &'static str {
+        "codeclimate"
+    }
+
+    fn generate(&self, results: &[GateResult]) -> Result<String, String> {
+        let mut violations: Vec<&Violation> = results
+            .iter()
+            .flat_map(|r| r.violations.iter())
+            .filter(|v| !v.suppressed)
+            .collect();
+        // Occurrence indices must not depend on gate evaluation order.
+        violations.sort_by(|a, b| {
+            a.file.cmp(&b.file)
+                .then(a.line.cmp(&b.line))
+                .then(a.column.cmp(&b.column))
+                .then(a.rule_id.cmp(&b.rule_id))
+                .then(a.message.cmp(&b.message))
+        });
+
+        let mut occurrences: HashMap<(&str, &str, &str), usize> = HashMap::new();
+        let mut issues: Vec<Value> = Vec::with_capacity(violations.len());
+        for violation in violations {
+            let occurrence = occurrences
+                .entry((&violation.rule_id, &violation.file, &violation.message))
+                .or_insert(0);
+            let fingerprint = Self::fingerprint(violation, *occurrence);
+            *occurrence += 1;
+
+            let mut description = violation.message.clone();
+            if let Some(cwe_id) = violation.cwe_id {
+                description.push_str(&format!(" (CWE-{cwe_id})"));
+            }
+
+            let issue = json!({
+                "type": "issue",
+                "engine_name": "drift",
+                "check_name": violation.rule_id,
+                "description": description,
+                "content": { "body": Self::content_body(violation) },
+                "categories": GitLabCodeQualityReporter::categories_for_violation(violation),
+                "location": Self::location(violation),
+                "severity": Self::severity_to_codeclimate(&violation.severity),
+                "remediation_points": Self::remediation_points(&violation.severity),
+                "fingerprint": fingerprint
+            });
+            issues.push(issue);
+        }
+
+        serde_json::to_string_pretty(&issues).map_err(|e| e.to_string())
+    }
+}
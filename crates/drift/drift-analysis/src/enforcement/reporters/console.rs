@@ -1,6 +1,7 @@
 //! Console reporter — human-readable output with color codes.
 
 use crate::enforcement::gates::{GateResult, GateStatus};
+use crate::enforcement::ownership::{group_by_owner, has_ownership};
 use crate::enforcement::rules::Severity;
 use super::Reporter;
 
@@ -104,6 +105,12 @@ impl Reporter for ConsoleReporter {
                 if let Some(ref fix) = violation.quick_fix {
                     output.push_str(&format!("    💡 Fix: {}\n", fix.description));
                 }
+                if !violation.owners.is_empty() {
+                    output.push_str(&format!("    Owners: {}\n", violation.owners.join(", ")));
+                }
+                if let Some(ref author) = violation.last_author {
+                    output.push_str(&format!("    Last changed by: {author}\n"));
+                }
             }
 
             // Show warnings
@@ -114,6 +121,17 @@ impl Reporter for ConsoleReporter {
             output.push('\n');
         }
 
+        if has_ownership(results) {
+            output.push_str("─── By owner ───\n");
+            for (summary, _) in group_by_owner(results) {
+                output.push_str(&format!(
+                    "  {}: {} errors, {} warnings, {} total\n",
+                    summary.owner, summary.errors, summary.warnings, summary.total
+                ));
+            }
+            output.push('\n');
+        }
+
         // Summary
         let total_violations: usize = results.iter().map(|r| r.violations.len()).sum();
         let passed = results.iter().filter(|r| r.passed).count();
@@ -91,7 +91,9 @@ impl Reporter for GitLabCodeQualityReporter {
 }
 
 impl GitLabCodeQualityReporter {
-    fn categories_for_violation(violation: &Violation) -> Vec<&'static str> {
+    /// Code Quality categories; shared with the Code Climate reporter, whose
+    /// category set GitLab's is drawn from.
+    pub(super) fn categories_for_violation(violation: &Violation) -> Vec<&'static str> {
         let mut categories = Vec::new();
 
         if violation.cwe_id.is_some() || violation.owasp_category.is_some() {
@@ -1,9 +1,11 @@
 //! HTML reporter — self-contained HTML report with inline CSS/JS.
 //!
 //! Produces a single HTML file with no external dependencies that renders
-//! a violation list with severity, location, and quick fix suggestions.
+//! a violation list with severity, location, and quick fix suggestions,
+//! plus a per-owner summary when violations carry ownership.
 
 use crate::enforcement::gates::{GateResult, GateStatus};
+use crate::enforcement::ownership::{group_by_owner, has_ownership};
 use crate::enforcement::rules::Severity;
 use super::Reporter;
 
@@ -116,6 +118,24 @@ impl Reporter for HtmlReporter {
         ));
         html.push_str("</div>\n");
 
+        // Owner summary
+        if has_ownership(results) {
+            html.push_str("<div class=\"owners\">\n<h2>By owner</h2>\n");
+            html.push_str("<table class=\"violations\">\n");
+            html.push_str("<thead><tr><th>Owner</th><th>Errors</th><th>Warnings</th><th>Total</th></tr></thead>\n");
+            html.push_str("<tbody>\n");
+            for (summary, _) in group_by_owner(results) {
+                html.push_str(&format!(
+                    "<tr><td class=\"owner\">{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+                    Self::escape_html(&summary.owner),
+                    summary.errors,
+                    summary.warnings,
+                    summary.total
+                ));
+            }
+            html.push_str("</tbody>\n</table>\n</div>\n");
+        }
+
         // Gate sections
         for result in results {
             let status_cls = Self::status_class(&result.status);
@@ -194,6 +214,18 @@ impl Reporter for HtmlReporter {
                             Self::escape_html(owasp)
                         ));
                     }
+                    for owner in &violation.owners {
+                        msg.push_str(&format!(
+                            " <span class=\"tag tag-owner\">{}</span>",
+                            Self::escape_html(owner)
+                        ));
+                    }
+                    if let Some(ref author) = violation.last_author {
+                        msg.push_str(&format!(
+                            " <span class=\"last-author\">last changed by {}</span>",
+                            Self::escape_html(author)
+                        ));
+                    }
                     html.push_str(&format!("<td>{msg}</td>\n"));
                     html.push_str("</tr>\n");
                 }
@@ -263,6 +295,10 @@ h2 { font-size: 18px; margin-bottom: 8px; }
 .rule { font-family: 'SF Mono', Monaco, Consolas, monospace; font-size: 12px; color: #6f42c1; }
 .quick-fix { color: #28a745; font-size: 12px; }
 .tag { display: inline-block; padding: 1px 6px; border-radius: 3px; background: #e9ecef; font-size: 11px; margin-left: 4px; }
+.tag-owner { background: #e2d9f3; color: #4b2e83; }
+.last-author { color: #666; font-size: 11px; margin-left: 4px; }
+.owners { background: #fff; border-radius: 8px; padding: 16px; margin-bottom: 16px; border: 1px solid #ddd; }
+.owner { font-family: 'SF Mono', Monaco, Consolas, monospace; font-size: 12px; }
 .warnings { margin-top: 8px; }
 .warning-item { color: #856404; font-size: 13px; padding: 4px 0; }
 footer { text-align: center; color: #999; font-size: 12px; margin-top: 32px; padding: 16px 0; }
@@ -3,6 +3,7 @@
 use serde_json::json;
 
 use crate::enforcement::gates::GateResult;
+use crate::enforcement::ownership::group_by_owner;
 use super::Reporter;
 
 /// JSON reporter for machine-readable output.
@@ -38,6 +39,8 @@ impl Reporter for JsonReporter {
                         "owasp_category": v.owasp_category,
                         "suppressed": v.suppressed,
                         "is_new": v.is_new,
+                        "owners": v.owners,
+                        "last_author": v.last_author,
                     })).collect::<Vec<_>>(),
                     "warnings": r.warnings,
                     "execution_time_ms": r.execution_time_ms,
@@ -55,6 +58,7 @@ impl Reporter for JsonReporter {
             "total_violations": total_violations,
             "gate_count": results.len(),
             "gates": gates,
+            "by_owner": group_by_owner(results).into_iter().map(|(summary, _)| summary).collect::<Vec<_>>(),
         });
 
         serde_json::to_string_pretty(&output).map_err(|e| e.to_string())
@@ -0,0 +1,112 @@
+//! Reporter registry — named reporter factories, open to registration.
+//!
+//! Starts with the built-in formats; downstream crates register their own
+//! `Reporter` implementations, and template files can be registered as
+//! formats without writing Rust.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::Arc;
+
+use super::template::{TemplateReporter, TEMPLATE_EXTENSIONS};
+use super::{checkstyle, codeclimate, console, github, gitlab, html, json, junit, sarif, sonarqube, Reporter};
+
+type ReporterFactory = dyn Fn() -> Box<dyn Reporter> + Send + Sync;
+
+/// Registry of reporter formats by name.
+#[derive(Clone, Default)]
+pub struct ReporterRegistry {
+    factories: BTreeMap<String, Arc<ReporterFactory>>,
+}
+
+impl ReporterRegistry {
+    /// An empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A registry with the built-in formats.
+    pub fn with_builtins() -> Self {
+        let mut registry = Self::new();
+        registry.register("sarif", || Box::new(sarif::SarifReporter::new()));
+        registry.register("json", || Box::new(json::JsonReporter));
+        registry.register("console", || Box::new(console::ConsoleReporter::default()));
+        registry.register("github", || Box::new(github::GitHubCodeQualityReporter::new()));
+        registry.register("gitlab", || Box::new(gitlab::GitLabCodeQualityReporter::new()));
+        registry.register("junit", || Box::new(junit::JUnitReporter::new()));
+        registry.register("html", || Box::new(html::HtmlReporter::new()));
+        registry.register("sonarqube", || Box::new(sonarqube::SonarQubeReporter::new()));
+        registry.register("checkstyle", || Box::new(checkstyle::CheckstyleReporter::new()));
+        registry.register("codeclimate", || Box::new(codeclimate::CodeClimateReporter::new()));
+        registry
+    }
+
+    /// Register a reporter factory under `name`, replacing any existing one.
+    /// Returns `true` if a format of that name was replaced.
+    pub fn register<F>(&mut self, name: impl Into<String>, factory: F) -> bool
+    where
+        F: Fn() -> Box<dyn Reporter> + Send + Sync + 'static,
+    {
+        self.factories.insert(name.into(), Arc::new(factory)).is_some()
+    }
+
+    /// Compile a template file and register it under `name`.
+    pub fn register_template(&mut self, name: impl Into<String>, path: &Path) -> Result<(), String> {
+        let name = name.into();
+        let reporter = TemplateReporter::from_file(path)?.with_format(name.clone());
+        self.register(name, move || Box::new(reporter.clone()));
+        Ok(())
+    }
+
+    /// Register every template file in `dir` under its file name up to the
+    /// first `.` (`checkstyle.xml.j2` → `checkstyle`). Templates that fail to
+    /// compile are skipped with a warning. Returns the registered names.
+    pub fn register_templates_from_dir(&mut self, dir: &Path) -> Vec<String> {
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return Vec::new();
+        };
+        let mut paths: Vec<_> = entries
+            .flatten()
+            .map(|e| e.path())
+            .filter(|p| {
+                p.is_file()
+                    && p.extension()
+                        .and_then(|e| e.to_str())
+                        .is_some_and(|e| TEMPLATE_EXTENSIONS.contains(&e))
+            })
+            .collect();
+        paths.sort();
+
+        let mut registered = Vec::new();
+        for path in paths {
+            let Some(name) = path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .and_then(|n| n.split('.').next())
+                .filter(|n| !n.is_empty())
+                .map(str::to_string)
+            else {
+                continue;
+            };
+            match self.register_template(name.clone(), &path) {
+                Ok(()) => registered.push(name),
+                Err(e) => eprintln!("[drift] warning: skipping reporter template {}: {e}", path.display()),
+            }
+        }
+        registered
+    }
+
+    /// Create a reporter for `name`.
+    pub fn create(&self, name: &str) -> Option<Box<dyn Reporter>> {
+        self.factories.get(name).map(|factory| factory())
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.factories.contains_key(name)
+    }
+
+    /// Registered format names, sorted.
+    pub fn formats(&self) -> Vec<&str> {
+        self.factories.keys().map(String::as_str).collect()
+    }
+}
@@ -3,7 +3,7 @@
 use serde_json::{json, Value};
 
 use crate::enforcement::gates::GateResult;
-use crate::enforcement::rules::Severity;
+use crate::enforcement::rules::{Severity, TextEdit};
 use super::Reporter;
 
 /// SARIF 2.1.0 reporter.
@@ -55,7 +55,7 @@ impl SarifReporter {
                     }]
                 });
 
-                // Add properties (is_new, CWE, OWASP)
+                // Add properties (is_new, CWE, OWASP, ownership)
                 let mut properties = serde_json::Map::new();
                 properties.insert("isNew".to_string(), json!(violation.is_new));
                 if let Some(cwe_id) = violation.cwe_id {
@@ -64,15 +64,29 @@ impl SarifReporter {
                 if let Some(ref owasp) = violation.owasp_category {
                     properties.insert("owaspCategory".to_string(), json!(owasp));
                 }
+                if !violation.owners.is_empty() {
+                    properties.insert("owners".to_string(), json!(violation.owners));
+                }
+                if let Some(ref author) = violation.last_author {
+                    properties.insert("lastAuthor".to_string(), json!(author));
+                }
                 result["properties"] = Value::Object(properties);
 
                 // Add quick fix if available
                 if let Some(ref fix) = violation.quick_fix {
-                    result["fixes"] = json!([{
+                    let mut sarif_fix = json!({
                         "description": {
                             "text": fix.description
+                        },
+                        "properties": {
+                            "fixId": fix.fix_id,
+                            "strategy": fix.strategy.to_string()
                         }
-                    }]);
+                    });
+                    if fix.is_applicable() {
+                        sarif_fix["artifactChanges"] = artifact_changes(&fix.edits);
+                    }
+                    result["fixes"] = json!([sarif_fix]);
                 }
 
                 results.push(result);
@@ -220,6 +234,35 @@ impl SarifReporter {
     }
 }
 
+/// SARIF `artifactChanges` for a fix: one entry per file, regions as byte offsets.
+fn artifact_changes(edits: &[TextEdit]) -> Value {
+    let mut by_file: Vec<(&str, Vec<Value>)> = Vec::new();
+    for edit in edits {
+        let replacement = json!({
+            "deletedRegion": {
+                "byteOffset": edit.start_byte,
+                "byteLength": edit.end_byte - edit.start_byte
+            },
+            "insertedContent": {
+                "text": edit.replacement
+            }
+        });
+        match by_file.iter_mut().find(|(file, _)| *file == edit.file) {
+            Some((_, replacements)) => replacements.push(replacement),
+            None => by_file.push((&edit.file, vec![replacement])),
+        }
+    }
+    Value::Array(
+        by_file
+            .into_iter()
+            .map(|(file, replacements)| json!({
+                "artifactLocation": { "uri": file },
+                "replacements": replacements
+            }))
+            .collect(),
+    )
+}
+
 impl Default for SarifReporter {
     fn default() -> Self {
         Self::new()
@@ -0,0 +1,106 @@
+//! Template reporter — renders gate results through a user-supplied template.
+//!
+//! Templates use Jinja/Tera syntax (`{{ value }}`, `{% for %}`, filters). The
+//! context exposes:
+//!
+//! - `results`: every `GateResult`, serialized as the JSON reporter sees it
+//! - `violations`: all violations flattened, each with its `gate_id`
+//! - `overall_passed`, `total_violations`, `gate_count`
+//!
+//! Output is escaped by template name: `*.html`/`*.xml` (optionally followed by
+//! a template extension — `.j2`, `.jinja`, `.jinja2`, `.tera` or `.tmpl`)
+//! escape markup, `*.json` escapes JSON strings, anything else renders raw.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use minijinja::{AutoEscape, Environment};
+use serde_json::json;
+
+use crate::enforcement::gates::GateResult;
+use super::Reporter;
+
+/// Template file extensions, stripped before picking the escaping.
+pub(crate) const TEMPLATE_EXTENSIONS: &[&str] = &["j2", "jinja", "jinja2", "tera", "tmpl"];
+
+/// Reporter backed by a compiled template.
+#[derive(Clone)]
+pub struct TemplateReporter {
+    template_name: String,
+    /// The format name it is registered under; "template" until registered.
+    format: String,
+    env: Arc<Environment<'static>>,
+}
+
+impl TemplateReporter {
+    /// Compile a template. `template_name` drives auto-escaping (see module docs).
+    pub fn from_source(template_name: impl Into<String>, source: impl Into<String>) -> Result<Self, String> {
+        let template_name = template_name.into();
+        let mut env = Environment::new();
+        env.set_auto_escape_callback(auto_escape);
+        env.add_template_owned(template_name.clone(), source.into())
+            .map_err(|e| format!("template '{template_name}': {e}"))?;
+        Ok(Self { template_name, format: "template".to_string(), env: Arc::new(env) })
+    }
+
+    /// Read and compile a template file; the file name drives auto-escaping.
+    pub fn from_file(path: &Path) -> Result<Self, String> {
+        let source = std::fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()))?;
+        let name = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .ok_or_else(|| format!("{}: invalid template file name", path.display()))?;
+        Self::from_source(name, source)
+    }
+
+    /// Report under the given format name.
+    pub fn with_format(mut self, format: impl Into<String>) -> Self {
+        self.format = format.into();
+        self
+    }
+
+    pub fn template_name(&self) -> &str {
+        &self.template_name
+    }
+}
+
+impl Reporter for TemplateReporter {
+    fn name(&self) -> &str {
+        &self.format
+    }
+
+    fn generate(&self, results: &[GateResult]) -> Result<String, String> {
+        let violations: Vec<serde_json::Value> = results
+            .iter()
+            .flat_map(|r| {
+                r.violations.iter().map(move |v| {
+                    let mut value = serde_json::to_value(v).unwrap_or_default();
+                    value["gate_id"] = json!(r.gate_id);
+                    value
+                })
+            })
+            .collect();
+
+        let context = json!({
+            "results": results,
+            "violations": violations,
+            "overall_passed": results.iter().all(|r| r.passed),
+            "total_violations": violations.len(),
+            "gate_count": results.len(),
+        });
+
+        let template = self.env.get_template(&self.template_name).map_err(|e| e.to_string())?;
+        template
+            .render(minijinja::Value::from_serialize(&context))
+            .map_err(|e| format!("template '{}': {e}", self.template_name))
+    }
+}
+
+/// Escaping for a template name, ignoring a trailing template extension.
+fn auto_escape(name: &str) -> AutoEscape {
+    let name = name
+        .rsplit_once('.')
+        .filter(|(_, ext)| TEMPLATE_EXTENSIONS.contains(ext))
+        .map_or(name, |(stem, _)| stem);
+    minijinja::default_auto_escape_callback(name)
+}
@@ -40,7 +40,10 @@ impl RulesEvaluator {
                 let rule_id = format!("{}/{}", pattern.category, pattern.pattern_id);
                 let id = format!("{}-{}-{}", rule_id, outlier.file, outlier.line);
 
-                let quick_fix = self.fix_generator.suggest(pattern, outlier);
+                let quick_fix = match input.sources.get(&outlier.file) {
+                    Some(source) => self.fix_generator.suggest_with_source(pattern, outlier, source),
+                    None => self.fix_generator.suggest(pattern, outlier),
+                };
 
                 let suppressed = self.suppression_checker.is_suppressed(
                     &outlier.file,
@@ -81,6 +84,9 @@ impl RulesEvaluator {
                     owasp_category: pattern.owasp_categories.first().cloned(),
                     suppressed,
                     is_new,
+                    owners: Vec::new(),
+                    last_author: None,
+                    file_level: false,
                 });
             }
         }
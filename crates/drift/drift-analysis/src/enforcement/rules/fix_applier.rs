@@ -0,0 +1,179 @@
+//! Fix applier — applies a chosen set of quick fixes to the working tree.
+//!
+//! Fixes are atomic: either all of a fix's edits are applied or none are.
+//! When two fixes touch overlapping ranges, the one listed first wins and the
+//! other is reported as a conflict.
+
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use super::types::{QuickFix, TextEdit};
+
+/// A fix that was not applied, and why.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FixConflict {
+    pub fix_id: String,
+    /// The already-accepted fix whose edits overlap this one, if any.
+    pub conflicts_with: Option<String>,
+    pub file: String,
+    pub reason: String,
+}
+
+/// Outcome of planning or applying a set of fixes.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FixApplication {
+    /// IDs of the fixes whose edits were (or would be) applied.
+    pub applied: Vec<String>,
+    pub conflicts: Vec<FixConflict>,
+    /// Files written, relative to the root.
+    pub files_changed: Vec<String>,
+}
+
+/// Applies quick-fix edits to files under a root directory.
+pub struct FixApplier {
+    root: PathBuf,
+}
+
+impl FixApplier {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Select the fixes that can be applied together, in input order.
+    ///
+    /// Fixes without edits are skipped. An edit identical to one already
+    /// accepted is not a conflict — it is the same change suggested twice.
+    pub fn plan(fixes: &[QuickFix]) -> (Vec<&QuickFix>, Vec<FixConflict>) {
+        let mut accepted: Vec<&QuickFix> = Vec::new();
+        let mut conflicts = Vec::new();
+
+        for fix in fixes {
+            if fix.edits.is_empty() {
+                conflicts.push(FixConflict {
+                    fix_id: fix.fix_id.clone(),
+                    conflicts_with: None,
+                    file: String::new(),
+                    reason: "fix has no machine-applicable edits".to_string(),
+                });
+                continue;
+            }
+            let clash = fix.edits.iter().find_map(|edit| {
+                accepted.iter().find_map(|other| {
+                    other.edits.iter()
+                        .find(|o| *o != edit && o.overlaps(edit))
+                        .map(|_| (other.fix_id.clone(), edit.file.clone()))
+                })
+            });
+            match clash {
+                Some((other_id, file)) => conflicts.push(FixConflict {
+                    fix_id: fix.fix_id.clone(),
+                    conflicts_with: Some(other_id),
+                    file,
+                    reason: "overlapping edits".to_string(),
+                }),
+                None => accepted.push(fix),
+            }
+        }
+        (accepted, conflicts)
+    }
+
+    /// Apply `edits` to `source`. Returns `None` if any range is out of bounds,
+    /// splits a UTF-8 character, overlaps another edit, or no longer holds
+    /// the edit's original text.
+    pub fn apply_edits(source: &str, edits: &[&TextEdit]) -> Option<String> {
+        let mut sorted: Vec<&TextEdit> = edits.to_vec();
+        sorted.sort_by(|a, b| b.start_byte.cmp(&a.start_byte).then(b.end_byte.cmp(&a.end_byte)));
+
+        let mut result = source.to_string();
+        let mut limit = source.len();
+        for edit in sorted {
+            if edit.end_byte > limit || source.get(edit.start_byte..edit.end_byte) != Some(edit.original.as_str()) {
+                return None;
+            }
+            result.replace_range(edit.start_byte..edit.end_byte, &edit.replacement);
+            limit = edit.start_byte;
+        }
+        Some(result)
+    }
+
+    /// Plan and write the fixes to disk.
+    ///
+    /// Edits that no longer fit a file's current contents mark that file stale;
+    /// every fix touching a stale file is dropped, including its edits to
+    /// other files.
+    pub fn apply(&self, fixes: &[QuickFix]) -> std::io::Result<FixApplication> {
+        let (mut accepted, mut conflicts) = Self::plan(fixes);
+
+        let mut sources: BTreeMap<&str, String> = BTreeMap::new();
+        for fix in &accepted {
+            for edit in &fix.edits {
+                if !sources.contains_key(edit.file.as_str()) {
+                    sources.insert(&edit.file, std::fs::read_to_string(self.resolve(&edit.file))?);
+                }
+            }
+        }
+
+        let stale: HashSet<&str> = Self::rewrite(&accepted, &sources)
+            .into_iter()
+            .filter_map(|(file, updated)| updated.is_none().then_some(file))
+            .collect();
+        accepted.retain(|fix| match fix.edits.iter().find(|e| stale.contains(e.file.as_str())) {
+            Some(edit) => {
+                conflicts.push(FixConflict {
+                    fix_id: fix.fix_id.clone(),
+                    conflicts_with: None,
+                    file: edit.file.clone(),
+                    reason: "edits no longer match the file contents".to_string(),
+                });
+                false
+            }
+            None => true,
+        });
+
+        // Dropping edits can't invalidate the rest, so this pass is clean.
+        let mut files_changed = Vec::new();
+        for (file, updated) in Self::rewrite(&accepted, &sources) {
+            if let Some(updated) = updated {
+                std::fs::write(self.resolve(file), updated)?;
+                files_changed.push(file.to_string());
+            }
+        }
+
+        Ok(FixApplication {
+            applied: accepted.iter().map(|f| f.fix_id.clone()).collect(),
+            conflicts,
+            files_changed,
+        })
+    }
+
+    /// New contents per file for the given fixes; `None` where the edits don't fit.
+    fn rewrite<'a>(fixes: &[&'a QuickFix], sources: &BTreeMap<&str, String>) -> Vec<(&'a str, Option<String>)> {
+        let mut by_file: BTreeMap<&str, Vec<&TextEdit>> = BTreeMap::new();
+        let mut seen: HashSet<&TextEdit> = HashSet::new();
+        for fix in fixes {
+            for edit in &fix.edits {
+                if seen.insert(edit) {
+                    by_file.entry(edit.file.as_str()).or_default().push(edit);
+                }
+            }
+        }
+        by_file
+            .into_iter()
+            .map(|(file, edits)| {
+                let updated = sources.get(file).and_then(|source| Self::apply_edits(source, &edits));
+                (file, updated)
+            })
+            .collect()
+    }
+
+    fn resolve(&self, file: &str) -> PathBuf {
+        let path = Path::new(file);
+        if path.is_absolute() {
+            path.to_path_buf()
+        } else {
+            self.root.join(path)
+        }
+    }
+}
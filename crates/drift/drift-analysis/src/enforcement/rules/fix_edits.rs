@@ -0,0 +1,839 @@
+//! AST-derived text edits for quick fixes.
+//!
+//! Each strategy inspects the tree-sitter tree around the outlier and returns
+//! concrete edits, or nothing when the code doesn't have the shape the fix
+//! needs. Outlier positions are 0-based tree-sitter rows and columns.
+
+use tree_sitter::{Node, Parser, Point};
+
+use crate::scanner::language_detect::Language;
+
+use super::types::{OutlierLocation, PatternInfo, QuickFixStrategy, TextEdit};
+
+/// Compute the edits for `strategy` at the outlier location in `source`.
+pub fn compute_edits(
+    strategy: QuickFixStrategy,
+    pattern: &PatternInfo,
+    outlier: &OutlierLocation,
+    source: &str,
+    language: Language,
+) -> Vec<TextEdit> {
+    let ext = std::path::Path::new(&outlier.file).extension().and_then(|e| e.to_str());
+    let mut parser = Parser::new();
+    if parser.set_language(&language.ts_language_for_ext(ext)).is_err() {
+        return Vec::new();
+    }
+    let Some(tree) = parser.parse(source, None) else {
+        return Vec::new();
+    };
+    let root = tree.root_node();
+    let Some(anchor) = anchor_node(root, source, outlier) else {
+        return Vec::new();
+    };
+    let src = source.as_bytes();
+    let file = outlier.file.as_str();
+
+    match strategy {
+        QuickFixStrategy::Rename => rename_edits(pattern, root, anchor, src, file, language),
+        QuickFixStrategy::AddImport => add_import_edits(pattern, root, anchor, src, file, language),
+        QuickFixStrategy::WrapInTryCatch => wrap_in_try_edits(anchor, src, file, language),
+        QuickFixStrategy::UseParameterizedQuery => parameterized_query_edits(anchor, src, file, language),
+        _ => Vec::new(),
+    }
+}
+
+/// The smallest node at the outlier position. Without a column, the first
+/// non-whitespace character of the line is used.
+fn anchor_node<'t>(root: Node<'t>, source: &str, outlier: &OutlierLocation) -> Option<Node<'t>> {
+    let row = outlier.line as usize;
+    let line = source.lines().nth(row)?;
+    let column = match outlier.column {
+        Some(c) => c as usize,
+        None => line.len() - line.trim_start().len(),
+    };
+    let point = Point { row, column };
+    root.descendant_for_point_range(point, point)
+}
+
+fn text<'s>(node: Node, source: &'s [u8]) -> &'s str {
+    node.utf8_text(source).unwrap_or("")
+}
+
+fn edit(source: &[u8], file: &str, start_byte: usize, end_byte: usize, replacement: String) -> TextEdit {
+    let original = String::from_utf8_lossy(&source[start_byte..end_byte]).into_owned();
+    TextEdit { file: file.to_string(), start_byte, end_byte, replacement, original }
+}
+
+fn is_identifier(node: Node) -> bool {
+    node.kind().contains("identifier") || matches!(node.kind(), "constant" | "name")
+}
+
+/// The identifier the outlier points at: the anchor itself, the `name` of the
+/// declaration it belongs to, or the first identifier on its line.
+fn identifier_at(anchor: Node) -> Option<Node> {
+    if is_identifier(anchor) {
+        return Some(anchor);
+    }
+    let mut current = Some(anchor);
+    for _ in 0..3 {
+        let node = current?;
+        if let Some(name) = node.child_by_field_name("name").filter(|n| is_identifier(*n)) {
+            return Some(name);
+        }
+        current = node.parent();
+    }
+    first_descendant(anchor, &|n| is_identifier(n) && n.start_position().row == anchor.start_position().row)
+}
+
+fn first_descendant<'t>(node: Node<'t>, pred: &dyn Fn(Node) -> bool) -> Option<Node<'t>> {
+    if pred(node) {
+        return Some(node);
+    }
+    for i in 0..node.child_count() {
+        if let Some(found) = node.child(i).and_then(|c| first_descendant(c, pred)) {
+            return Some(found);
+        }
+    }
+    None
+}
+
+fn collect_descendants<'t>(node: Node<'t>, pred: &dyn Fn(Node) -> bool, out: &mut Vec<Node<'t>>) {
+    if pred(node) {
+        out.push(node);
+    }
+    for i in 0..node.child_count() {
+        if let Some(child) = node.child(i) {
+            collect_descendants(child, pred, out);
+        }
+    }
+}
+
+// ---- Rename ----
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NamingConvention {
+    Camel,
+    Pascal,
+    Snake,
+    ScreamingSnake,
+}
+
+impl NamingConvention {
+    /// Infer the target convention from a pattern ID such as `naming-camelCase`.
+    fn from_pattern_id(pattern_id: &str) -> Option<Self> {
+        let id = pattern_id.to_lowercase();
+        if id.contains("screaming") || id.contains("upper_snake") || id.contains("constant_case") {
+            Some(Self::ScreamingSnake)
+        } else if id.contains("pascal") {
+            Some(Self::Pascal)
+        } else if id.contains("camel") {
+            Some(Self::Camel)
+        } else if id.contains("snake") {
+            Some(Self::Snake)
+        } else {
+            None
+        }
+    }
+
+    fn apply(&self, name: &str) -> String {
+        // Leading underscores carry meaning (privacy, unused) and are kept.
+        let trimmed = name.trim_start_matches('_');
+        let prefix = &name[..name.len() - trimmed.len()];
+        let words = split_words(trimmed);
+        if words.is_empty() {
+            return name.to_string();
+        }
+        let capitalize = |w: &str| {
+            let mut chars = w.chars();
+            chars.next().map(|c| c.to_uppercase().chain(chars).collect::<String>()).unwrap_or_default()
+        };
+        let converted = match self {
+            Self::Camel => words.iter().enumerate()
+                .map(|(i, w)| if i == 0 { w.clone() } else { capitalize(w) })
+                .collect::<String>(),
+            Self::Pascal => words.iter().map(|w| capitalize(w)).collect::<String>(),
+            Self::Snake => words.join("_"),
+            Self::ScreamingSnake => words.join("_").to_uppercase(),
+        };
+        format!("{prefix}{converted}")
+    }
+}
+
+/// Split an identifier into lowercase words at `_`, `-` and case boundaries
+/// (`HTTPServer` → `http`, `server`).
+fn split_words(name: &str) -> Vec<String> {
+    let chars: Vec<char> = name.chars().collect();
+    let mut words = Vec::new();
+    let mut current = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if c == '_' || c == '-' {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            continue;
+        }
+        if c.is_uppercase() && !current.is_empty() {
+            let prev = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+            if prev.is_lowercase() || prev.is_ascii_digit() || (prev.is_uppercase() && next_is_lower) {
+                words.push(std::mem::take(&mut current));
+            }
+        }
+        current.extend(c.to_lowercase());
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+    words
+}
+
+/// Rename the binding the outlier points at, together with the references
+/// that resolve to it. Only identifier nodes are touched, so strings and
+/// comments are left alone.
+///
+/// Nothing is emitted when the rename can't be proven local to the file:
+/// imported, exported and member bindings, properties and keyword arguments
+/// that share the name, or a new name that is already in use.
+fn rename_edits(pattern: &PatternInfo, root: Node, anchor: Node, source: &[u8], file: &str, language: Language) -> Vec<TextEdit> {
+    let Some(convention) = NamingConvention::from_pattern_id(&pattern.pattern_id) else {
+        return Vec::new();
+    };
+    let Some(target) = identifier_at(anchor) else {
+        return Vec::new();
+    };
+    let old = text(target, source);
+    let new = convention.apply(old);
+    if old.is_empty() || new == old || is_member_name(target) {
+        return Vec::new();
+    }
+
+    let mut occurrences = Vec::new();
+    collect_descendants(root, &|n| is_identifier(n) && n.child_count() == 0 && text(n, source) == old, &mut occurrences);
+    let mut taken = Vec::new();
+    collect_descendants(root, &|n| is_identifier(n) && n.child_count() == 0 && text(n, source) == new, &mut taken);
+    if !taken.is_empty() || occurrences.iter().any(|n| n.kind() == "shorthand_property_identifier" || is_keyword_argument(*n)) {
+        return Vec::new();
+    }
+
+    let mut scopes = Vec::new();
+    for decl in occurrences.iter().filter(|n| is_declaration(**n, language)) {
+        if is_import_or_export(*decl) {
+            return Vec::new();
+        }
+        match binding_scope(*decl) {
+            Some(scope) => scopes.push(scope.id()),
+            // A class member: reachable from other files through the instance.
+            None => return Vec::new(),
+        }
+    }
+    let resolve = |node: Node| -> Option<usize> {
+        let mut current = Some(node);
+        while let Some(n) = current {
+            if (is_scope(n) || n.parent().is_none()) && scopes.contains(&n.id()) {
+                return Some(n.id());
+            }
+            current = n.parent();
+        }
+        None
+    };
+    // Undeclared in this file: a global or an import we didn't recognise.
+    let Some(scope) = resolve(target) else {
+        return Vec::new();
+    };
+    occurrences.iter()
+        .filter(|n| !is_member_name(**n) && resolve(**n) == Some(scope))
+        .map(|n| edit(source, file, n.start_byte(), n.end_byte(), new.clone()))
+        .collect()
+}
+
+/// Function-like nodes that open a new scope for their parameters and locals.
+fn is_scope(node: Node) -> bool {
+    let kind = node.kind();
+    ["function", "method", "lambda", "arrow", "closure", "func_literal", "constructor"]
+        .iter()
+        .any(|k| kind.contains(k))
+        && !["call", "type", "signature", "invocation"].iter().any(|k| kind.contains(k))
+}
+
+fn is_class_like(kind: &str) -> bool {
+    ["class", "interface", "struct", "impl", "trait", "object_declaration", "enum"]
+        .iter()
+        .any(|k| kind.contains(k))
+}
+
+/// Property and field names, which belong to an object rather than a scope.
+fn is_member_name(node: Node) -> bool {
+    if matches!(node.kind(), "property_identifier" | "field_identifier") {
+        return true;
+    }
+    let Some(parent) = node.parent() else { return false };
+    ["property", "attribute", "field", "name"]
+        .iter()
+        .any(|f| parent.child_by_field_name(f).is_some_and(|n| n.id() == node.id()))
+        && parent.child_by_field_name("object").or_else(|| parent.child_by_field_name("operand")).is_some()
+}
+
+fn is_keyword_argument(node: Node) -> bool {
+    node.parent().is_some_and(|p| {
+        matches!(p.kind(), "keyword_argument" | "named_argument" | "value_argument")
+            && p.child_by_field_name("name").is_some_and(|n| n.id() == node.id())
+    })
+}
+
+fn is_parameter(node: Node) -> bool {
+    // A default value references a binding; it doesn't declare one.
+    let is_default = node.parent().is_some_and(|p| {
+        ["value", "right", "default_value"]
+            .iter()
+            .any(|f| p.child_by_field_name(f).is_some_and(|n| n.id() == node.id()))
+    });
+    if is_default {
+        return false;
+    }
+    let mut current = node.parent();
+    for _ in 0..3 {
+        let Some(n) = current else { return false };
+        if n.kind().contains("parameter") {
+            return true;
+        }
+        if is_scope(n) {
+            return false;
+        }
+        current = n.parent();
+    }
+    false
+}
+
+/// Whether the identifier introduces a binding rather than referencing one.
+fn is_declaration(node: Node, language: Language) -> bool {
+    if is_parameter(node) {
+        return true;
+    }
+    let Some(parent) = node.parent() else { return false };
+    if is_member_name(node) || parent.kind().contains("call") || parent.kind().contains("invocation") {
+        return false;
+    }
+    let is_field = |field: &str| parent.child_by_field_name(field).is_some_and(|n| n.id() == node.id());
+    if is_field("name") || is_field("pattern") {
+        return true;
+    }
+    // Go's `x := ...` declares every name on the left.
+    if parent.kind() == "expression_list" && parent.parent().is_some_and(|p| p.kind() == "short_var_declaration") {
+        return true;
+    }
+    // Python and Ruby declare variables by assigning to them.
+    matches!(language, Language::Python | Language::Ruby) && parent.kind() == "assignment" && is_field("left")
+}
+
+fn is_import_or_export(node: Node) -> bool {
+    let mut current = node.parent();
+    // `export function f` wraps the definition that `f` names.
+    if let Some(def) = current.filter(|p| p.child_by_field_name("name").is_some_and(|n| n.id() == node.id())) {
+        current = def.parent();
+    }
+    while let Some(n) = current {
+        let kind = n.kind();
+        if kind.contains("import") || kind.contains("export") {
+            return true;
+        }
+        if is_scope(n) {
+            return false;
+        }
+        current = n.parent();
+    }
+    false
+}
+
+/// The scope a declaration binds in: the function for parameters, otherwise
+/// the nearest enclosing function or the file. `None` for class members.
+fn binding_scope(decl: Node) -> Option<Node> {
+    let mut current = decl.parent()?;
+    if !is_parameter(decl) && current.child_by_field_name("name").is_some_and(|n| n.id() == decl.id()) {
+        // A function or class name binds in the scope around the definition.
+        if is_scope(current) || is_class_like(current.kind()) {
+            current = current.parent()?;
+        }
+    }
+    loop {
+        if is_scope(current) || current.parent().is_none() {
+            return Some(current);
+        }
+        if is_class_like(current.kind()) {
+            return None;
+        }
+        current = current.parent()?;
+    }
+}
+
+// ---- AddImport ----
+
+/// The module to import, taken from the last `/` or `:` segment of the
+/// pattern ID (`import/lodash` → `lodash`).
+fn import_module(pattern_id: &str) -> Option<&str> {
+    let (_, module) = pattern_id.rsplit_once(['/', ':'])?;
+    (!module.is_empty()).then_some(module)
+}
+
+fn import_statement(language: Language, module: &str, symbol: Option<&str>) -> Option<String> {
+    let stmt = match (language, symbol) {
+        (Language::TypeScript | Language::JavaScript, Some(s)) => format!("import {{ {s} }} from \"{module}\";"),
+        (Language::TypeScript | Language::JavaScript, None) => format!("import \"{module}\";"),
+        (Language::Python, Some(s)) => format!("from {module} import {s}"),
+        (Language::Python, None) => format!("import {module}"),
+        (Language::Java, Some(s)) => format!("import {module}.{s};"),
+        (Language::Java, None) => format!("import {module}.*;"),
+        (Language::Kotlin, Some(s)) | (Language::Scala, Some(s)) => format!("import {module}.{s}"),
+        (Language::Kotlin, None) => format!("import {module}.*"),
+        (Language::Scala, None) => format!("import {module}._"),
+        (Language::CSharp, _) => format!("using {module};"),
+        (Language::Go, _) => format!("import \"{module}\""),
+        (Language::Rust, Some(s)) => format!("use {module}::{s};"),
+        (Language::Rust, None) => format!("use {module};"),
+        (Language::Ruby, _) => format!("require \"{module}\""),
+        (Language::Php, Some(s)) => format!("use {module}\\{s};"),
+        (Language::Php, None) => format!("use {module};"),
+        (Language::C | Language::Cpp, _) => format!("#include <{module}>"),
+        (Language::Swift, _) => format!("import {module}"),
+    };
+    Some(stmt)
+}
+
+fn is_import_node(node: Node, source: &[u8]) -> bool {
+    match node.kind() {
+        "import_statement" | "import_from_statement" | "import_declaration" | "import_list"
+        | "using_directive" | "use_declaration" | "namespace_use_declaration" | "preproc_include" => true,
+        // Ruby imports are plain method calls.
+        "call" => text(node, source).starts_with("require"),
+        _ => false,
+    }
+}
+
+/// Insert the import after the last top-level import, after the package
+/// header, or at the top of the file. Nothing is emitted when the module is
+/// already imported.
+fn add_import_edits(
+    pattern: &PatternInfo,
+    root: Node,
+    anchor: Node,
+    source: &[u8],
+    file: &str,
+    language: Language,
+) -> Vec<TextEdit> {
+    let Some(module) = import_module(&pattern.pattern_id) else {
+        return Vec::new();
+    };
+    let symbol = identifier_at(anchor)
+        .map(|n| text(n, source))
+        .filter(|s| !s.is_empty() && *s != module);
+    let Some(statement) = import_statement(language, module, symbol) else {
+        return Vec::new();
+    };
+
+    let top_level: Vec<Node> = (0..root.named_child_count()).filter_map(|i| root.named_child(i)).collect();
+    let imports: Vec<Node> = top_level.iter().copied().filter(|n| is_import_node(*n, source)).collect();
+    if imports.iter().any(|n| text(*n, source).contains(module)) {
+        return Vec::new();
+    }
+
+    let (offset, insertion) = if let Some(last) = imports.last() {
+        // Some grammars (preprocessor includes) keep the trailing newline in the node.
+        if text(*last, source).ends_with('\n') {
+            (last.end_byte(), format!("{statement}\n"))
+        } else {
+            (last.end_byte(), format!("\n{statement}"))
+        }
+    } else if let Some(header) = top_level.iter().find(|n| {
+        matches!(n.kind(), "package_declaration" | "package_clause" | "package_header" | "php_tag")
+    }) {
+        (header.end_byte(), format!("\n\n{statement}"))
+    } else {
+        (0, format!("{statement}\n"))
+    };
+    vec![edit(source, file, offset, offset, insertion)]
+}
+
+// ---- WrapInTryCatch ----
+
+fn is_block_like(kind: &str) -> bool {
+    matches!(
+        kind,
+        "statement_block" | "block" | "compound_statement" | "program" | "module" | "source_file"
+            | "translation_unit" | "compilation_unit" | "body_statement" | "statements"
+            | "function_body" | "constructor_body" | "then" | "else" | "do"
+    )
+}
+
+fn is_definition(kind: &str) -> bool {
+    ["function", "method", "class", "interface", "import", "package", "namespace", "struct", "enum", "impl", "trait", "lambda", "arrow"]
+        .iter()
+        .any(|k| kind.contains(k))
+}
+
+fn is_local_declaration(kind: &str) -> bool {
+    matches!(
+        kind,
+        "lexical_declaration" | "local_variable_declaration" | "local_declaration_statement"
+            | "declaration" | "property_declaration" | "val_definition" | "var_definition"
+    )
+}
+
+fn is_try(kind: &str) -> bool {
+    matches!(kind, "try_statement" | "try_expression" | "begin")
+}
+
+/// The statement containing the anchor: the first ancestor sitting directly in a block.
+fn enclosing_statement(anchor: Node) -> Option<Node> {
+    let mut current = anchor;
+    loop {
+        let parent = current.parent()?;
+        if is_block_like(parent.kind()) && current.is_named() {
+            return (!is_definition(current.kind())).then_some(current);
+        }
+        if is_definition(parent.kind()) && !is_block_like(current.kind()) {
+            return None;
+        }
+        current = parent;
+    }
+}
+
+fn line_indent(source: &[u8], byte: usize) -> String {
+    let line_start = source[..byte].iter().rposition(|&b| b == b'\n').map_or(0, |i| i + 1);
+    source[line_start..byte]
+        .iter()
+        .take_while(|b| **b == b' ' || **b == b'\t')
+        .map(|&b| b as char)
+        .collect()
+}
+
+/// Wrap the statement at the outlier in the language's error-handling block.
+/// The handler rethrows, so behavior is unchanged until someone fills it in.
+fn wrap_in_try_edits(anchor: Node, source: &[u8], file: &str, language: Language) -> Vec<TextEdit> {
+    let Some(stmt) = enclosing_statement(anchor) else {
+        return Vec::new();
+    };
+    // Already handled.
+    let mut ancestor = stmt.parent();
+    while let Some(node) = ancestor {
+        if is_try(node.kind()) {
+            return Vec::new();
+        }
+        if is_definition(node.kind()) {
+            break;
+        }
+        ancestor = node.parent();
+    }
+    // Moving a declaration into a block would hide it from the code after it.
+    let block_scoped = !matches!(language, Language::Python | Language::Ruby | Language::Php);
+    if block_scoped && is_local_declaration(stmt.kind()) {
+        return Vec::new();
+    }
+
+    let unit = match language {
+        Language::TypeScript | Language::JavaScript | Language::Ruby | Language::Scala => "  ",
+        _ => "    ",
+    };
+    let indent = line_indent(source, stmt.start_byte());
+    let inner = format!("{indent}{unit}");
+    let body = text(stmt, source).replace('\n', &format!("\n{unit}"));
+
+    let wrapped = match language {
+        Language::TypeScript | Language::JavaScript => format!(
+            "try {{\n{inner}{body}\n{indent}}} catch (error) {{\n{inner}throw error;\n{indent}}}"
+        ),
+        Language::Java => format!(
+            "try {{\n{inner}{body}\n{indent}}} catch (Exception e) {{\n{inner}throw new RuntimeException(e);\n{indent}}}"
+        ),
+        Language::Kotlin => format!(
+            "try {{\n{inner}{body}\n{indent}}} catch (e: Exception) {{\n{inner}throw e\n{indent}}}"
+        ),
+        Language::Scala => format!(
+            "try {{\n{inner}{body}\n{indent}}} catch {{\n{inner}case e: Exception => throw e\n{indent}}}"
+        ),
+        Language::CSharp => format!(
+            "try\n{indent}{{\n{inner}{body}\n{indent}}}\n{indent}catch (Exception)\n{indent}{{\n{inner}throw;\n{indent}}}"
+        ),
+        Language::Php => format!(
+            "try {{\n{inner}{body}\n{indent}}} catch (\\Throwable $e) {{\n{inner}throw $e;\n{indent}}}"
+        ),
+        Language::Cpp => format!(
+            "try {{\n{inner}{body}\n{indent}}} catch (...) {{\n{inner}throw;\n{indent}}}"
+        ),
+        Language::Python => format!(
+            "try:\n{inner}{body}\n{indent}except Exception:\n{inner}raise"
+        ),
+        Language::Ruby => format!(
+            "begin\n{inner}{body}\n{indent}rescue StandardError\n{inner}raise\n{indent}end"
+        ),
+        // No exceptions (Go, Rust, C) or checked `try` at each call site (Swift).
+        Language::Go | Language::Rust | Language::C | Language::Swift => return Vec::new(),
+    };
+    vec![edit(source, file, stmt.start_byte(), stmt.end_byte(), wrapped)]
+}
+
+// ---- UseParameterizedQuery ----
+
+enum QueryPart {
+    Literal(String),
+    Param(String),
+}
+
+#[derive(Default)]
+struct QueryParts {
+    /// Quote delimiter (including any kept string prefix) of the first literal.
+    quote: Option<String>,
+    parts: Vec<QueryPart>,
+}
+
+impl QueryParts {
+    fn set_quote(&mut self, quote: String) -> bool {
+        match &self.quote {
+            Some(existing) => *existing == quote,
+            None => {
+                self.quote = Some(quote);
+                true
+            }
+        }
+    }
+}
+
+fn is_call(kind: &str) -> bool {
+    matches!(kind, "call_expression" | "call")
+}
+
+/// The call at the outlier whose single argument is a dynamically built query.
+fn query_call<'t>(anchor: Node<'t>, source: &[u8], language: Language) -> Option<(Node<'t>, QueryParts)> {
+    let dynamic_arg = |call: Node<'t>| -> Option<(Node<'t>, QueryParts)> {
+        let args = call.child_by_field_name("arguments")?;
+        let named: Vec<Node> = (0..args.named_child_count())
+            .filter_map(|i| args.named_child(i))
+            .filter(|n| n.kind() != "comment")
+            .collect();
+        let [arg] = named.as_slice() else { return None };
+        let parts = query_parts(*arg, source, language)?;
+        Some((*arg, parts))
+    };
+
+    let mut current = Some(anchor);
+    while let Some(node) = current {
+        if is_call(node.kind()) {
+            if let Some(found) = dynamic_arg(node) {
+                return Some(found);
+            }
+        }
+        current = node.parent();
+    }
+    // The anchor may sit before the call, e.g. on `const rows = db.query(...)`.
+    let stmt = enclosing_statement(anchor)?;
+    let mut calls = Vec::new();
+    collect_descendants(stmt, &|n| is_call(n.kind()), &mut calls);
+    calls.into_iter().find_map(dynamic_arg)
+}
+
+fn query_parts(arg: Node, source: &[u8], language: Language) -> Option<QueryParts> {
+    let mut parts = QueryParts::default();
+    // `"... %s" % (a, b)` already has placeholders; only the binding changes.
+    if language == Language::Python && arg.kind() == "binary_operator" && operator(arg, source) == "%" {
+        let left = arg.child_by_field_name("left")?;
+        let right = arg.child_by_field_name("right")?;
+        if left.kind() != "string" || text(left, source).to_lowercase().starts_with('f') {
+            return None;
+        }
+        parts.quote = Some(String::new());
+        parts.parts.push(QueryPart::Literal(text(left, source).to_string()));
+        parts.parts.push(QueryPart::Param(text(right, source).to_string()));
+        return Some(parts);
+    }
+
+    if !flatten(arg, source, language, &mut parts) {
+        return None;
+    }
+    let has_literal = parts.parts.iter().any(|p| matches!(p, QueryPart::Literal(_)));
+    let has_param = parts.parts.iter().any(|p| matches!(p, QueryPart::Param(_)));
+    (has_literal && has_param).then_some(parts)
+}
+
+fn operator<'s>(node: Node, source: &'s [u8]) -> &'s str {
+    node.child_by_field_name("operator").map(|o| text(o, source)).unwrap_or("")
+}
+
+/// Flatten a string concatenation or interpolation into literal and parameter parts.
+fn flatten(node: Node, source: &[u8], language: Language, out: &mut QueryParts) -> bool {
+    match node.kind() {
+        "binary_expression" | "binary_operator" | "binary" if operator(node, source) == "+" => {
+            match (node.child_by_field_name("left"), node.child_by_field_name("right")) {
+                (Some(left), Some(right)) => {
+                    flatten(left, source, language, out) && flatten(right, source, language, out)
+                }
+                _ => false,
+            }
+        }
+        "parenthesized_expression" => match node.named_child(0) {
+            Some(inner) => flatten(inner, source, language, out),
+            None => false,
+        },
+        "string" | "template_string" | "interpreted_string_literal" | "raw_string_literal" => {
+            string_parts(node, source, language, out)
+        }
+        _ => {
+            out.parts.push(QueryPart::Param(text(node, source).to_string()));
+            true
+        }
+    }
+}
+
+fn string_parts(node: Node, source: &[u8], language: Language, out: &mut QueryParts) -> bool {
+    let raw = text(node, source);
+    match (language, node.kind()) {
+        (Language::Python, "string") => {
+            let mut is_f = false;
+            for i in 0..node.child_count() {
+                let Some(child) = node.child(i) else { continue };
+                match child.kind() {
+                    "string_start" => {
+                        let start = text(child, source);
+                        is_f = start.chars().any(|c| c == 'f' || c == 'F');
+                        let kept: String = start.chars().filter(|c| *c != 'f' && *c != 'F').collect();
+                        if !out.set_quote(kept) {
+                            return false;
+                        }
+                    }
+                    "string_content" | "escape_sequence" => {
+                        let mut lit = text(child, source).to_string();
+                        if is_f {
+                            lit = lit.replace("{{", "{").replace("}}", "}");
+                        }
+                        out.parts.push(QueryPart::Literal(lit));
+                    }
+                    "interpolation" => {
+                        let Some(expr) = child.child_by_field_name("expression") else { return false };
+                        out.parts.push(QueryPart::Param(text(expr, source).to_string()));
+                    }
+                    _ => {}
+                }
+            }
+            true
+        }
+        (_, "template_string") => {
+            if !out.set_quote("`".to_string()) {
+                return false;
+            }
+            for i in 0..node.child_count() {
+                let Some(child) = node.child(i) else { continue };
+                match child.kind() {
+                    "string_fragment" | "escape_sequence" => {
+                        out.parts.push(QueryPart::Literal(text(child, source).to_string()));
+                    }
+                    "template_substitution" => {
+                        let Some(expr) = child.named_child(0) else { return false };
+                        out.parts.push(QueryPart::Param(text(expr, source).to_string()));
+                    }
+                    _ => {}
+                }
+            }
+            true
+        }
+        (Language::Ruby, "string") => {
+            if !out.set_quote(raw.chars().next().map(String::from).unwrap_or_default()) {
+                return false;
+            }
+            for i in 0..node.child_count() {
+                let Some(child) = node.child(i) else { continue };
+                match child.kind() {
+                    "string_content" | "escape_sequence" => {
+                        out.parts.push(QueryPart::Literal(text(child, source).to_string()));
+                    }
+                    "interpolation" => {
+                        let Some(expr) = child.named_child(0) else { return false };
+                        out.parts.push(QueryPart::Param(text(expr, source).to_string()));
+                    }
+                    _ => {}
+                }
+            }
+            true
+        }
+        _ => {
+            // Plain quoted literal: keep the raw contents between the delimiters.
+            if raw.len() < 2 || !out.set_quote(raw[..1].to_string()) {
+                return false;
+            }
+            out.parts.push(QueryPart::Literal(raw[1..raw.len() - 1].to_string()));
+            true
+        }
+    }
+}
+
+/// Drop the SQL quotes around a parameter, as in `'` + name + `'`: the
+/// placeholder stands for the whole quoted value.
+fn unquote_params(parts: &mut [QueryPart]) {
+    for i in 1..parts.len().saturating_sub(1) {
+        let quoted = matches!(
+            (&parts[i - 1], &parts[i], &parts[i + 1]),
+            (QueryPart::Literal(before), QueryPart::Param(_), QueryPart::Literal(after))
+                if before.ends_with('\'') && after.starts_with('\'')
+        );
+        if !quoted {
+            continue;
+        }
+        if let QueryPart::Literal(before) = &mut parts[i - 1] {
+            before.pop();
+        }
+        if let QueryPart::Literal(after) = &mut parts[i + 1] {
+            after.remove(0);
+        }
+    }
+}
+
+/// Replace a concatenated or interpolated SQL argument with a placeholder
+/// string plus bound parameters, in the calling convention of the language's
+/// common drivers.
+fn parameterized_query_edits(anchor: Node, source: &[u8], file: &str, language: Language) -> Vec<TextEdit> {
+    let placeholder = match language {
+        Language::TypeScript | Language::JavaScript | Language::Go | Language::Ruby => "?",
+        Language::Python => "%s",
+        _ => return Vec::new(),
+    };
+    let Some((arg, parts)) = query_call(anchor, source, language) else {
+        return Vec::new();
+    };
+    let quote = parts.quote.clone().unwrap_or_default();
+    let mut parts = parts.parts;
+    if !quote.is_empty() {
+        unquote_params(&mut parts);
+    }
+
+    let mut sql = String::new();
+    let mut params = Vec::new();
+    for part in parts {
+        match part {
+            QueryPart::Literal(lit) if language == Language::Python && !quote.is_empty() => {
+                sql.push_str(&lit.replace('%', "%%"));
+            }
+            QueryPart::Literal(lit) => sql.push_str(&lit),
+            QueryPart::Param(expr) => {
+                if !quote.is_empty() {
+                    sql.push_str(placeholder);
+                }
+                params.push(expr);
+            }
+        }
+    }
+    // Python `%` formatting: the string already is the query.
+    let query = if quote.is_empty() {
+        sql
+    } else {
+        let closing: String = quote.chars().filter(|c| !c.is_alphabetic()).collect();
+        format!("{quote}{sql}{closing}")
+    };
+
+    let replacement = match language {
+        Language::TypeScript | Language::JavaScript => format!("{query}, [{}]", params.join(", ")),
+        Language::Python if quote.is_empty() => {
+            let bound = params.join(", ");
+            if bound.starts_with('(') { format!("{query}, {bound}") } else { format!("{query}, ({bound},)") }
+        }
+        Language::Python if params.len() == 1 => format!("{query}, ({},)", params[0]),
+        Language::Python => format!("{query}, ({})", params.join(", ")),
+        _ => format!("{query}, {}", params.join(", ")),
+    };
+    vec![edit(source, file, arg.start_byte(), arg.end_byte(), replacement)]
+}
@@ -3,9 +3,12 @@
 pub mod types;
 pub mod evaluator;
 pub mod quick_fixes;
+pub mod fix_edits;
+pub mod fix_applier;
 pub mod suppression;
 
 pub use types::*;
 pub use evaluator::RulesEvaluator;
 pub use quick_fixes::QuickFixGenerator;
+pub use fix_applier::{FixApplication, FixApplier, FixConflict};
 pub use suppression::SuppressionChecker;
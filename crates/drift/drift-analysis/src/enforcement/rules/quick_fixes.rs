@@ -1,5 +1,15 @@
 //! Quick-fix generator — 7 fix strategies for violations.
+//!
+//! `suggest` produces advisory fixes (description plus template). When the
+//! file's source is available, `suggest_with_source` also computes concrete
+//! edits from the AST for the strategies that support it.
 
+use std::path::Path;
+
+use crate::scanner::hasher::hash_content;
+use crate::scanner::language_detect::Language;
+
+use super::fix_edits::compute_edits;
 use super::types::*;
 
 /// Generates quick-fix suggestions for violations.
@@ -24,11 +34,49 @@ impl QuickFixGenerator {
         let strategy = self.select_strategy(pattern, outlier)?;
         let description = self.describe_fix(&strategy, pattern, outlier);
         let replacement = self.generate_replacement(&strategy, pattern, outlier);
+        let fix_id = fix_id(strategy, pattern, outlier, &[]);
 
         Some(QuickFix {
             strategy,
             description,
             replacement,
+            fix_id,
+            edits: Vec::new(),
+        })
+    }
+
+    /// Suggest a quick fix with machine-applicable edits computed from `source`.
+    ///
+    /// Falls back to the advisory fix when the language can't be determined or
+    /// the code at the outlier doesn't have the shape the strategy needs.
+    pub fn suggest_with_source(
+        &self,
+        pattern: &PatternInfo,
+        outlier: &OutlierLocation,
+        source: &str,
+    ) -> Option<QuickFix> {
+        let mut fix = self.suggest(pattern, outlier)?;
+        if let Some(language) = self.resolve_language(&outlier.file) {
+            fix.edits = compute_edits(fix.strategy, pattern, outlier, source, language);
+            if !fix.edits.is_empty() {
+                fix.fix_id = fix_id(fix.strategy, pattern, outlier, &fix.edits);
+            }
+        }
+        Some(fix)
+    }
+
+    /// Language of the file, falling back to the generator's language hint.
+    fn resolve_language(&self, file: &str) -> Option<Language> {
+        let ext = Path::new(file).extension().and_then(|e| e.to_str());
+        Language::from_extension(ext).or_else(|| {
+            let hint = self.language.as_deref()?.to_lowercase();
+            [
+                Language::TypeScript, Language::JavaScript, Language::Python, Language::Java,
+                Language::CSharp, Language::Go, Language::Rust, Language::Ruby, Language::Php,
+                Language::Kotlin, Language::Cpp, Language::C, Language::Swift, Language::Scala,
+            ]
+            .into_iter()
+            .find(|l| l.name().to_lowercase() == hint || (*l == Language::CSharp && hint == "csharp"))
         })
     }
 
@@ -40,9 +88,9 @@ impl QuickFixGenerator {
     ) -> Option<QuickFixStrategy> {
         match pattern.category.as_str() {
             "naming" | "convention" => Some(QuickFixStrategy::Rename),
-            "error_handling" => Some(QuickFixStrategy::WrapInTryCatch),
+            "error_handling" | "errors" => Some(QuickFixStrategy::WrapInTryCatch),
             "import" | "dependency" => Some(QuickFixStrategy::AddImport),
-            "type_safety" => Some(QuickFixStrategy::AddTypeAnnotation),
+            "type_safety" | "types" => Some(QuickFixStrategy::AddTypeAnnotation),
             "documentation" => Some(QuickFixStrategy::AddDocumentation),
             "test_coverage" => Some(QuickFixStrategy::AddTest),
             "complexity" | "decomposition" => Some(QuickFixStrategy::ExtractFunction),
@@ -172,6 +220,24 @@ impl QuickFixGenerator {
     }
 }
 
+/// Stable fix ID. Fixes with edits are keyed by the edits themselves, so the
+/// same change suggested twice dedupes; advisory fixes are keyed by location.
+fn fix_id(
+    strategy: QuickFixStrategy,
+    pattern: &PatternInfo,
+    outlier: &OutlierLocation,
+    edits: &[TextEdit],
+) -> String {
+    let mut key = format!("{strategy}|{}|{}", pattern.pattern_id, outlier.file);
+    if edits.is_empty() {
+        key.push_str(&format!("|{}", outlier.line));
+    }
+    for e in edits {
+        key.push_str(&format!("|{}:{}:{}:{}", e.file, e.start_byte, e.end_byte, e.replacement));
+    }
+    format!("{strategy}-{:016x}", hash_content(key.as_bytes()))
+}
+
 impl Default for QuickFixGenerator {
     fn default() -> Self {
         Self::new()
@@ -43,6 +43,51 @@ pub struct QuickFix {
     pub description: String,
     /// The replacement text, if applicable.
     pub replacement: Option<String>,
+    /// Stable identifier: the same fix for the same source yields the same ID.
+    #[serde(default)]
+    pub fix_id: String,
+    /// Machine-applicable edits computed from the AST. Empty for advisory fixes.
+    #[serde(default)]
+    pub edits: Vec<TextEdit>,
+}
+
+impl QuickFix {
+    /// Whether the fix carries concrete edits that can be applied automatically.
+    pub fn is_applicable(&self) -> bool {
+        !self.edits.is_empty()
+    }
+}
+
+/// A single text replacement over a byte range of a file.
+///
+/// An insertion has `start_byte == end_byte`; a deletion has an empty `replacement`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TextEdit {
+    pub file: String,
+    pub start_byte: usize,
+    pub end_byte: usize,
+    pub replacement: String,
+    /// The text the range held when the edit was computed. The edit only
+    /// applies while the file still has it there.
+    #[serde(default)]
+    pub original: String,
+}
+
+impl TextEdit {
+    /// Whether two edits to the same file touch overlapping ranges.
+    pub fn overlaps(&self, other: &TextEdit) -> bool {
+        if self.file != other.file {
+            return false;
+        }
+        let is_insert = self.start_byte == self.end_byte;
+        let other_is_insert = other.start_byte == other.end_byte;
+        if !is_insert && !other_is_insert {
+            return self.start_byte < other.end_byte && other.start_byte < self.end_byte;
+        }
+        // An insertion touching a replaced range, or another insertion at the
+        // same offset, has no well-defined order.
+        self.start_byte <= other.end_byte && other.start_byte <= self.end_byte
+    }
 }
 
 /// The 8 quick-fix strategies.
@@ -97,6 +142,15 @@ pub struct Violation {
     pub suppressed: bool,
     /// Whether this violation was introduced by the current change.
     pub is_new: bool,
+    /// Owning teams or users from CODEOWNERS.
+    #[serde(default)]
+    pub owners: Vec<String>,
+    /// Author of the last commit to change the line, from git blame.
+    #[serde(default)]
+    pub last_author: Option<String>,
+    /// Whether the violation concerns the whole file rather than `line`.
+    #[serde(default)]
+    pub file_level: bool,
 }
 
 /// Input data for the rules evaluator.
@@ -104,8 +158,11 @@ pub struct Violation {
 pub struct RulesInput {
     /// Detected patterns with their locations and outliers.
     pub patterns: Vec<PatternInfo>,
-    /// Source file contents for suppression checking.
+    /// Source file lines for suppression checking.
     pub source_lines: std::collections::HashMap<String, Vec<String>>,
+    /// Raw source file contents for quick-fix edits, whose byte offsets point
+    /// into these. Files without an entry get advisory fixes only.
+    pub sources: std::collections::HashMap<String, String>,
     /// Baseline violation keys (format: "file:line:rule_id") for is_new detection.
     pub baseline_violation_ids: std::collections::HashSet<String>,
 }
@@ -0,0 +1,785 @@
+//! Embedded SQL extraction — raw queries in string literals, concatenations,
+//! template strings and format calls, parsed into a statement model.
+//!
+//! The SQL parser is shallow by design: it recovers the statement's operation,
+//! the tables and columns it touches, and where program values are spliced into
+//! the query text, which is what data-access tracking and injection checks need.
+
+use serde::{Deserialize, Serialize};
+use tree_sitter::Node;
+
+use crate::scanner::language_detect::Language;
+
+/// Framework name stored for data access and boundaries found in embedded SQL.
+pub const RAW_SQL_FRAMEWORK: &str = "raw_sql";
+
+/// A SQL statement embedded in source code.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SqlStatement {
+    pub file: String,
+    pub line: u32,
+    pub column: u32,
+    /// Query text, with each interpolated value written as `${expression}`.
+    pub text: String,
+    /// Leading keyword, upper-cased (`SELECT`, `INSERT`, `CREATE`, ...).
+    pub verb: String,
+    pub operation: SqlOperation,
+    pub tables: Vec<String>,
+    pub columns: Vec<String>,
+    /// Program values spliced into the query text.
+    pub interpolations: Vec<SqlInterpolation>,
+    /// Bind parameters: `?`, `$1`, `:name`, `@name`, `%s`.
+    pub parameter_count: u32,
+}
+
+impl SqlStatement {
+    /// Whether values are spliced into the query text rather than bound.
+    pub fn is_dynamic(&self) -> bool {
+        !self.interpolations.is_empty()
+    }
+}
+
+/// What a statement does to the data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SqlOperation {
+    Read,
+    Write,
+    Ddl,
+}
+
+impl SqlOperation {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Read => "read",
+            Self::Write => "write",
+            Self::Ddl => "ddl",
+        }
+    }
+}
+
+/// A program value spliced into query text.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SqlInterpolation {
+    /// Source text of the spliced expression.
+    pub expression: String,
+    /// Clause the value lands in (`WHERE`, `ORDER BY`, `FROM`, ...).
+    pub clause: Option<String>,
+}
+
+/// Extract the SQL statements embedded in a file.
+pub fn extract_embedded_sql(
+    tree: &tree_sitter::Tree,
+    source: &[u8],
+    file: &str,
+    language: Language,
+) -> Vec<SqlStatement> {
+    let mut statements = Vec::new();
+    visit(tree.root_node(), source, file, language, &mut statements);
+    statements
+}
+
+/// The statement a single string-building expression produces, such as a
+/// query call's argument. `None` if the expression builds no SQL.
+pub fn statement_at(node: Node, source: &[u8], language: Language) -> Option<SqlStatement> {
+    let mut statement = parse_parts(&query_parts(node, source, language)?)?;
+    let start = node.start_position();
+    statement.line = start.row as u32;
+    statement.column = start.column as u32;
+    Some(statement)
+}
+
+/// Parse plain SQL text, with no interpolated values.
+pub fn parse_sql(sql: &str) -> Option<SqlStatement> {
+    parse_parts(&[Part::Text(sql.to_string())])
+}
+
+/// A piece of query text: literal SQL, or a value spliced in by the program.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Part {
+    Text(String),
+    Value(String),
+}
+
+fn visit(node: Node, source: &[u8], file: &str, language: Language, out: &mut Vec<SqlStatement>) {
+    if let Some(parts) = query_parts(node, source, language) {
+        if let Some(mut statement) = parse_parts(&parts) {
+            let start = node.start_position();
+            statement.file = file.to_string();
+            statement.line = start.row as u32;
+            statement.column = start.column as u32;
+            out.push(statement);
+            return;
+        }
+        if is_string_kind(node.kind()) {
+            return;
+        }
+    }
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        visit(child, source, file, language, out);
+    }
+}
+
+/// The query text a string-building expression produces, if `node` is one.
+fn query_parts(node: Node, source: &[u8], language: Language) -> Option<Vec<Part>> {
+    if let Some(parts) = format_parts(node, source) {
+        return Some(parts);
+    }
+    if is_string_kind(node.kind()) || is_concatenation(node, source, language) {
+        let mut parts = Vec::new();
+        flatten(node, source, language, &mut parts);
+        return parts.iter().any(|p| matches!(p, Part::Text(_))).then(|| merge(parts));
+    }
+    None
+}
+
+fn flatten(node: Node, source: &[u8], language: Language, parts: &mut Vec<Part>) {
+    let kind = node.kind();
+    if kind == "concatenated_string" {
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            flatten(child, source, language, parts);
+        }
+    } else if is_string_kind(kind) {
+        parts.extend(string_parts(node, source));
+    } else if kind == "parenthesized_expression" && node.named_child_count() == 1 {
+        if let Some(inner) = node.named_child(0) {
+            flatten(inner, source, language, parts);
+        }
+    } else if is_concatenation(node, source, language) {
+        let left = node.child_by_field_name("left").or_else(|| node.named_child(0));
+        let right = node.child_by_field_name("right").or_else(|| node.named_child(node.named_child_count().saturating_sub(1)));
+        for operand in [left, right].into_iter().flatten() {
+            flatten(operand, source, language, parts);
+        }
+    } else {
+        parts.push(Part::Value(text(node, source).to_string()));
+    }
+}
+
+/// A string literal's content, split at its interpolations.
+fn string_parts(node: Node, source: &[u8]) -> Vec<Part> {
+    let mut parts = Vec::new();
+    let mut pos = node.start_byte();
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        if is_interpolation_kind(child.kind()) {
+            parts.push(Part::Text(lossy(&source[pos..child.start_byte()])));
+            parts.push(Part::Value(value_expression(text(child, source))));
+            pos = child.end_byte();
+        }
+    }
+    parts.push(Part::Text(lossy(&source[pos..node.end_byte()])));
+
+    // Strip the opening and closing delimiters (`f"`, `@"`, `r#"`, `"""`, `` ` ``).
+    if let Some(Part::Text(first)) = parts.first_mut() {
+        let body = first.trim_start_matches(|c| "fFrRbBuU@$#".contains(c));
+        *first = strip_quote(body, str::strip_prefix).to_string();
+    }
+    if let Some(Part::Text(last)) = parts.last_mut() {
+        let body = last.trim_end_matches('#');
+        *last = strip_quote(body, str::strip_suffix).to_string();
+    }
+    parts
+}
+
+fn strip_quote<'s>(text: &'s str, strip: fn(&'s str, &'static str) -> Option<&'s str>) -> &'s str {
+    ["\"\"\"", "'''", "\"", "'", "`"].iter().find_map(|q| strip(text, q)).unwrap_or(text)
+}
+
+/// `id` from `${id}`, `#{id}`, `{id}` or `\(id)`.
+fn value_expression(raw: &str) -> String {
+    let raw = raw.trim();
+    for (open, close) in [("${", "}"), ("#{", "}"), ("{", "}"), ("\\(", ")")] {
+        if let Some(inner) = raw.strip_prefix(open).and_then(|r| r.strip_suffix(close)) {
+            return inner.trim().to_string();
+        }
+    }
+    raw.to_string()
+}
+
+fn merge(parts: Vec<Part>) -> Vec<Part> {
+    let mut merged: Vec<Part> = Vec::with_capacity(parts.len());
+    for part in parts {
+        match (merged.last_mut(), part) {
+            (Some(Part::Text(prev)), Part::Text(text)) => prev.push_str(&text),
+            (_, part) => merged.push(part),
+        }
+    }
+    merged
+}
+
+fn is_concatenation(node: Node, source: &[u8], language: Language) -> bool {
+    if !matches!(node.kind(), "binary_expression" | "binary_operator" | "additive_expression" | "binary") {
+        return false;
+    }
+    let concat = if language == Language::Php { "." } else { "+" };
+    operator(node, source) == Some(concat)
+}
+
+fn operator<'s>(node: Node, source: &'s [u8]) -> Option<&'s str> {
+    let op = node.child_by_field_name("operator").or_else(|| {
+        let mut cursor = node.walk();
+        let op = node.children(&mut cursor).find(|c| !c.is_named());
+        op
+    })?;
+    Some(text(op, source))
+}
+
+/// `"... %s" % (a,)`, `"... {}".format(a)`, `fmt.Sprintf("... %s", a)`,
+/// `String.format(...)` and `string.Format(...)`: the template with each
+/// format specifier replaced by its argument.
+fn format_parts(node: Node, source: &[u8]) -> Option<Vec<Part>> {
+    let (template, args): (Node, Vec<Node>) = match node.kind() {
+        "binary_operator" if operator(node, source) == Some("%") => {
+            let template = node.child_by_field_name("left").filter(|l| is_string_kind(l.kind()))?;
+            let right = node.child_by_field_name("right")?;
+            let args = if right.kind() == "tuple" { named_children(right) } else { vec![right] };
+            (template, args)
+        }
+        "call" | "call_expression" | "method_invocation" | "invocation_expression" => {
+            let args = named_children(node.child_by_field_name("arguments")?);
+            let callee = node.child_by_field_name("function")
+                .or_else(|| node.child_by_field_name("name"))
+                .map(|c| text(c, source))?;
+            let receiver = node.child_by_field_name("object")
+                .or_else(|| node.child_by_field_name("function").and_then(|f| f.child_by_field_name("object")));
+            match receiver {
+                // "... {}".format(a)
+                Some(receiver) if is_string_kind(receiver.kind()) && callee.ends_with("format") => (receiver, args),
+                _ if ["Sprintf", "sprintf", "format", "Format"].iter().any(|f| callee.ends_with(f)) => {
+                    let (first, rest) = args.split_first()?;
+                    if !is_string_kind(first.kind()) {
+                        return None;
+                    }
+                    (*first, rest.to_vec())
+                }
+                _ => return None,
+            }
+        }
+        _ => return None,
+    };
+
+    let args: Vec<String> = args.iter().map(|a| {
+        let value = a.child_by_field_name("value").unwrap_or(*a);
+        text(value, source).to_string()
+    }).collect();
+    let mut next = 0;
+    let mut parts = Vec::new();
+    for part in string_parts(template, source) {
+        match part {
+            Part::Text(t) => parts.extend(substitute_specifiers(&t, &args, &mut next)),
+            value => parts.push(value),
+        }
+    }
+    Some(merge(parts))
+}
+
+/// Split `text` at `%s`/`%(name)s`/`%d`/`%v` and `{}`/`{0}` specifiers.
+fn substitute_specifiers(text: &str, args: &[String], next: &mut usize) -> Vec<Part> {
+    let mut parts = Vec::new();
+    let mut literal = String::new();
+    let mut chars = text.char_indices().peekable();
+    let value = |index: Option<usize>, next: &mut usize| {
+        let i = index.unwrap_or_else(|| {
+            *next += 1;
+            *next - 1
+        });
+        Part::Value(args.get(i).cloned().unwrap_or_else(|| "?".to_string()))
+    };
+    while let Some((i, c)) = chars.next() {
+        let rest = &text[i + c.len_utf8()..];
+        match c {
+            '%' if rest.starts_with('%') => {
+                literal.push('%');
+                chars.next();
+            }
+            '%' => {
+                let len = specifier_len(rest);
+                if len == 0 {
+                    literal.push(c);
+                    continue;
+                }
+                for _ in 0..rest[..len].chars().count() {
+                    chars.next();
+                }
+                parts.push(Part::Text(std::mem::take(&mut literal)));
+                parts.push(value(None, next));
+            }
+            '{' if rest.starts_with('{') => {
+                literal.push('{');
+                chars.next();
+            }
+            '{' => match rest.find('}') {
+                Some(close) if rest[..close].chars().all(|c| c.is_alphanumeric() || c == '_' || c == ':') => {
+                    let index = rest[..close].split(':').next().and_then(|n| n.parse().ok());
+                    for _ in 0..=rest[..close].chars().count() {
+                        chars.next();
+                    }
+                    parts.push(Part::Text(std::mem::take(&mut literal)));
+                    parts.push(value(index, next));
+                }
+                _ => literal.push(c),
+            },
+            _ => literal.push(c),
+        }
+    }
+    parts.push(Part::Text(literal));
+    parts
+}
+
+/// Length of a printf-style conversion after the `%`, or 0.
+fn specifier_len(rest: &str) -> usize {
+    let bytes = rest.as_bytes();
+    let mut i = 0;
+    if bytes.first() == Some(&b'(') {
+        i = rest.find(')').map_or(0, |close| close + 1);
+        if i == 0 {
+            return 0;
+        }
+    }
+    while i < bytes.len() && b"-+ 0#.123456789".contains(&bytes[i]) {
+        i += 1;
+    }
+    match bytes.get(i) {
+        Some(b's' | b'd' | b'i' | b'f' | b'r' | b'v' | b'q' | b'x' | b'u') => i + 1,
+        _ => 0,
+    }
+}
+
+fn named_children(node: Node) -> Vec<Node> {
+    let mut cursor = node.walk();
+    node.named_children(&mut cursor).collect()
+}
+
+fn text<'s>(node: Node, source: &'s [u8]) -> &'s str {
+    node.utf8_text(source).unwrap_or("")
+}
+
+fn lossy(bytes: &[u8]) -> String {
+    String::from_utf8_lossy(bytes).into_owned()
+}
+
+fn is_string_kind(kind: &str) -> bool {
+    matches!(
+        kind,
+        "string"
+            | "string_literal"
+            | "template_string"
+            | "interpreted_string_literal"
+            | "raw_string_literal"
+            | "encapsed_string"
+            | "interpolated_string_expression"
+            | "verbatim_string_literal"
+            | "text_block"
+            | "line_string_literal"
+            | "multi_line_string_literal"
+            | "interpolated_string"
+            | "concatenated_string"
+    )
+}
+
+fn is_interpolation_kind(kind: &str) -> bool {
+    matches!(
+        kind,
+        "template_substitution"
+            | "interpolation"
+            | "interpolated_expression"
+            | "interpolated_identifier"
+            // PHP splices variables straight into double-quoted strings.
+            | "variable_name"
+            | "member_access_expression"
+            | "subscript_expression"
+            | "dynamic_variable_name"
+    )
+}
+
+// ---- SQL statement parsing ----
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Word(String),
+    Literal,
+    Param,
+    /// The n-th interpolated value.
+    Value(usize),
+    Punct(char),
+}
+
+fn parse_parts(parts: &[Part]) -> Option<SqlStatement> {
+    let mut tokens = Vec::new();
+    let mut values = Vec::new();
+    let mut in_string = None;
+    let mut text = String::new();
+    for part in parts {
+        match part {
+            Part::Text(t) => {
+                lex(t, &mut tokens, &mut in_string);
+                text.push_str(t);
+            }
+            Part::Value(v) => {
+                tokens.push(Token::Value(values.len()));
+                values.push(v.clone());
+                text.push_str(&format!("${{{v}}}"));
+            }
+        }
+    }
+
+    let mut start = 0;
+    while tokens.get(start) == Some(&Token::Punct('(')) {
+        start += 1;
+    }
+    let tokens = &tokens[start..];
+    let Some(Token::Word(first)) = tokens.first() else { return None };
+    // SQL keywords are written in one case; `Select the rows from...` is prose.
+    if first.chars().any(|c| c.is_lowercase()) && first.chars().any(|c| c.is_uppercase()) {
+        return None;
+    }
+    let verb = first.to_ascii_uppercase();
+    let has = |word: &str| tokens.iter().any(|t| matches!(t, Token::Word(w) if w.eq_ignore_ascii_case(word)));
+    let main_verb = if verb == "WITH" {
+        tokens.iter().find_map(|t| match t {
+            Token::Word(w) if ["SELECT", "INSERT", "UPDATE", "DELETE"].iter().any(|v| w.eq_ignore_ascii_case(v)) => {
+                Some(w.to_ascii_uppercase())
+            }
+            _ => None,
+        })?
+    } else {
+        verb.clone()
+    };
+    let operation = match main_verb.as_str() {
+        "SELECT" if has("FROM") => SqlOperation::Read,
+        "INSERT" | "REPLACE" | "MERGE" if has("INTO") => SqlOperation::Write,
+        "UPDATE" if has("SET") => SqlOperation::Write,
+        "DELETE" if has("FROM") => SqlOperation::Write,
+        "CREATE" | "ALTER" | "DROP" => {
+            let object = tokens.iter().skip(1).find_map(|t| match t {
+                Token::Word(w) => Some(w.to_ascii_uppercase()),
+                _ => None,
+            })?;
+            if !DDL_OBJECTS.contains(&object.as_str()) {
+                return None;
+            }
+            SqlOperation::Ddl
+        }
+        "TRUNCATE" => SqlOperation::Ddl,
+        _ => return None,
+    };
+
+    let mut statement = SqlStatement {
+        file: String::new(),
+        line: 0,
+        column: 0,
+        text: text.trim().to_string(),
+        verb,
+        operation,
+        tables: Vec::new(),
+        columns: Vec::new(),
+        interpolations: Vec::new(),
+        parameter_count: 0,
+    };
+    let ctes = if statement.verb == "WITH" { cte_names(tokens) } else { Vec::new() };
+    Walker { tokens, values: &values, statement: &mut statement, aliases: Vec::new(), ctes }.walk();
+    Some(statement)
+}
+
+/// Names bound by `WITH name AS (...)`, lower-cased.
+fn cte_names(tokens: &[Token]) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut depth = 0usize;
+    for (i, token) in tokens.iter().enumerate() {
+        match token {
+            Token::Punct('(') => depth += 1,
+            Token::Punct(')') => depth = depth.saturating_sub(1),
+            Token::Word(name) if depth == 0 => {
+                let is_as = matches!(tokens.get(i + 1), Some(Token::Word(w)) if w.eq_ignore_ascii_case("AS"));
+                if is_as && tokens.get(i + 2) == Some(&Token::Punct('(')) {
+                    names.push(name.to_ascii_lowercase());
+                }
+            }
+            _ => {}
+        }
+    }
+    names
+}
+
+const DDL_OBJECTS: &[&str] = &[
+    "TABLE", "INDEX", "VIEW", "SCHEMA", "DATABASE", "SEQUENCE", "TRIGGER", "UNIQUE", "TEMP",
+    "TEMPORARY", "MATERIALIZED", "FUNCTION", "PROCEDURE", "TYPE", "EXTENSION", "OR",
+];
+
+fn lex(text: &str, tokens: &mut Vec<Token>, in_string: &mut Option<char>) {
+    let chars: Vec<char> = text.chars().collect();
+    let word_char = |c: char| c.is_alphanumeric() || c == '_' || c == '$' || c == '.';
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        if let Some(quote) = *in_string {
+            if c == quote {
+                if next == Some(quote) {
+                    i += 1;
+                } else {
+                    *in_string = None;
+                }
+            }
+            i += 1;
+            continue;
+        }
+        match c {
+            '\'' => {
+                tokens.push(Token::Literal);
+                *in_string = Some('\'');
+                i += 1;
+            }
+            '"' | '`' | '[' => {
+                let close = if c == '[' { ']' } else { c };
+                let end = chars[i + 1..].iter().position(|&d| d == close).map_or(chars.len(), |p| i + 1 + p);
+                let name: String = chars[i + 1..end].iter().collect();
+                let name = name.trim_end_matches('\\');
+                let is_name = name.starts_with(|n: char| n.is_alphabetic() || n == '_')
+                    && name.chars().all(|n| n.is_alphanumeric() || matches!(n, '_' | '$' | '.'));
+                if !is_name {
+                    tokens.push(Token::Punct(c));
+                    i += 1;
+                } else {
+                    tokens.push(Token::Word(name.to_string()));
+                    i = end + 1;
+                }
+            }
+            '-' if next == Some('-') => {
+                i = chars[i..].iter().position(|&d| d == '\n').map_or(chars.len(), |p| i + p);
+            }
+            '/' if next == Some('*') => {
+                i = (i + 2..chars.len().saturating_sub(1))
+                    .find(|&j| chars[j] == '*' && chars[j + 1] == '/')
+                    .map_or(chars.len(), |j| j + 2);
+            }
+            '?' => {
+                tokens.push(Token::Param);
+                i += 1;
+                while i < chars.len() && chars[i].is_ascii_digit() {
+                    i += 1;
+                }
+            }
+            '$' | ':' | '@' if next.is_some_and(|n| n.is_alphanumeric() || n == '_')
+                && !(c == ':' && i > 0 && chars[i - 1] == ':') =>
+            {
+                tokens.push(Token::Param);
+                i += 1;
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+            }
+            '%' if specifier_len(&chars[i + 1..].iter().collect::<String>()) > 0 => {
+                let rest: String = chars[i + 1..].iter().collect();
+                tokens.push(Token::Param);
+                i += 1 + rest[..specifier_len(&rest)].chars().count();
+            }
+            _ if c.is_alphabetic() || c == '_' => {
+                let start = i;
+                while i < chars.len() && word_char(chars[i]) {
+                    i += 1;
+                }
+                tokens.push(Token::Word(chars[start..i].iter().collect()));
+            }
+            _ if c.is_ascii_digit() => {
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '.') {
+                    i += 1;
+                }
+                tokens.push(Token::Literal);
+            }
+            _ if c.is_whitespace() || c == '\\' => i += 1,
+            _ => {
+                tokens.push(Token::Punct(c));
+                i += 1;
+            }
+        }
+    }
+}
+
+struct Walker<'t> {
+    tokens: &'t [Token],
+    values: &'t [String],
+    statement: &'t mut SqlStatement,
+    /// Table aliases, and the tables themselves.
+    aliases: Vec<String>,
+    /// Names bound by `WITH`, which are not tables.
+    ctes: Vec<String>,
+}
+
+impl Walker<'_> {
+    fn word(&self, i: usize) -> Option<String> {
+        match self.tokens.get(i) {
+            Some(Token::Word(w)) => Some(w.to_ascii_uppercase()),
+            _ => None,
+        }
+    }
+
+    fn walk(&mut self) {
+        let mut clause: Option<String> = None;
+        let mut i = 0;
+        while i < self.tokens.len() {
+            match &self.tokens[i] {
+                Token::Value(n) => {
+                    self.statement.interpolations.push(SqlInterpolation {
+                        expression: self.values[*n].clone(),
+                        clause: clause.clone(),
+                    });
+                }
+                Token::Param => self.statement.parameter_count += 1,
+                Token::Word(word) => {
+                    let upper = word.to_ascii_uppercase();
+                    match upper.as_str() {
+                        "FROM" | "JOIN" | "INTO" | "USING" | "UPDATE" | "TABLE" | "TRUNCATE" => {
+                            clause = Some(upper.clone());
+                            i = self.read_tables(i + 1, &upper);
+                            continue;
+                        }
+                        "COLUMN" => {
+                            if let Some(Token::Word(column)) = self.tokens.get(i + 1) {
+                                let column = column.clone();
+                                self.add_column(&column);
+                                i += 2;
+                                continue;
+                            }
+                        }
+                        "BY" => {
+                            if let Some(prev) = self.word(i.wrapping_sub(1)).filter(|p| p == "ORDER" || p == "GROUP") {
+                                clause = Some(format!("{prev} BY"));
+                            }
+                        }
+                        _ if CLAUSES.contains(&upper.as_str()) => clause = Some(upper.clone()),
+                        _ if is_keyword(&upper) => {}
+                        _ => {
+                            let is_call = self.tokens.get(i + 1) == Some(&Token::Punct('('));
+                            let is_alias = self.word(i.wrapping_sub(1)).as_deref() == Some("AS");
+                            if !is_call && !is_alias {
+                                let word = word.clone();
+                                self.add_column(&word);
+                            }
+                        }
+                    }
+                }
+                Token::Literal | Token::Punct(_) => {}
+            }
+            i += 1;
+        }
+    }
+
+    /// Table references after `keyword`; returns the index to resume at.
+    fn read_tables(&mut self, mut i: usize, keyword: &str) -> usize {
+        loop {
+            while self.word(i).is_some_and(|w| matches!(w.as_str(), "IF" | "NOT" | "EXISTS" | "ONLY" | "LATERAL")) {
+                i += 1;
+            }
+            // Subqueries and dynamic table names are left to the main walk.
+            let Some(Token::Word(name)) = self.tokens.get(i) else { return i };
+            let upper = name.to_ascii_uppercase();
+            if is_keyword(&upper) {
+                return i;
+            }
+            let name = name.clone();
+            if !self.ctes.contains(&name.to_ascii_lowercase()) && !self.statement.tables.contains(&name) {
+                self.statement.tables.push(name.clone());
+            }
+            self.aliases.push(name.to_ascii_lowercase());
+            i += 1;
+
+            if matches!(keyword, "INTO" | "TABLE") && self.tokens.get(i) == Some(&Token::Punct('(')) {
+                return self.read_column_list(i + 1, keyword == "TABLE");
+            }
+            if self.word(i).as_deref() == Some("AS") {
+                i += 1;
+            }
+            if let Some(alias) = self.word(i).filter(|w| !is_keyword(w) && !CLAUSES.contains(&w.as_str())) {
+                self.aliases.push(alias.to_ascii_lowercase());
+                i += 1;
+            }
+            if keyword == "FROM" && self.tokens.get(i) == Some(&Token::Punct(',')) {
+                i += 1;
+                continue;
+            }
+            return i;
+        }
+    }
+
+    /// `INSERT INTO t (a, b)` columns, or `CREATE TABLE t (a INT, b TEXT, ...)`
+    /// column definitions; returns the index after the closing parenthesis.
+    fn read_column_list(&mut self, mut i: usize, definitions: bool) -> usize {
+        let mut depth = 1usize;
+        let mut expect_name = true;
+        while let Some(token) = self.tokens.get(i) {
+            match token {
+                Token::Punct('(') => depth += 1,
+                Token::Punct(')') => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return i + 1;
+                    }
+                }
+                Token::Punct(',') if depth == 1 => expect_name = true,
+                Token::Word(word) if depth == 1 && expect_name => {
+                    let upper = word.to_ascii_uppercase();
+                    if !(definitions && TABLE_CONSTRAINTS.contains(&upper.as_str())) {
+                        let word = word.clone();
+                        self.add_column(&word);
+                    }
+                    expect_name = !definitions;
+                }
+                Token::Value(n) => self.statement.interpolations.push(SqlInterpolation {
+                    expression: self.values[*n].clone(),
+                    clause: None,
+                }),
+                _ => {}
+            }
+            i += 1;
+        }
+        i
+    }
+
+    fn add_column(&mut self, word: &str) {
+        if word.ends_with('.') {
+            return;
+        }
+        let name = word.rsplit('.').next().unwrap_or(word);
+        if name.is_empty()
+            || is_keyword(&name.to_ascii_uppercase())
+            || self.aliases.iter().any(|a| a.eq_ignore_ascii_case(word))
+            || self.ctes.iter().any(|c| c.eq_ignore_ascii_case(word))
+            || self.statement.columns.iter().any(|c| c == name)
+        {
+            return;
+        }
+        self.statement.columns.push(name.to_string());
+    }
+}
+
+const CLAUSES: &[&str] = &[
+    "SELECT", "WHERE", "SET", "VALUES", "HAVING", "LIMIT", "OFFSET", "ON", "RETURNING",
+];
+
+const TABLE_CONSTRAINTS: &[&str] = &["PRIMARY", "CONSTRAINT", "FOREIGN", "UNIQUE", "CHECK", "INDEX", "KEY"];
+
+fn is_keyword(upper: &str) -> bool {
+    KEYWORDS.binary_search(&upper).is_ok()
+}
+
+/// Reserved words and common type names; sorted for binary search.
+const KEYWORDS: &[&str] = &[
+    "ADD", "ALL", "ALTER", "AND", "ANY", "ARRAY", "AS", "ASC", "AUTOINCREMENT", "AUTO_INCREMENT",
+    "BETWEEN", "BIGINT", "BIGSERIAL", "BLOB", "BOOL", "BOOLEAN", "BY", "BYTEA", "CASCADE", "CASE",
+    "CAST", "CHAR", "CHECK", "COLLATE", "COLUMN", "CONFLICT", "CONSTRAINT", "CREATE", "CROSS",
+    "CURRENT_DATE", "CURRENT_TIMESTAMP", "DATABASE", "DATE", "DECIMAL", "DEFAULT", "DELETE", "DESC",
+    "DISTINCT", "DO", "DOUBLE", "DROP", "DUPLICATE", "ELSE", "END", "ESCAPE", "EXCEPT", "EXISTS",
+    "FALSE", "FETCH", "FIRST", "FLOAT", "FOR", "FOREIGN", "FROM", "FULL", "GROUP", "HAVING", "IF",
+    "IGNORE", "ILIKE", "IN", "INDEX", "INNER", "INSERT", "INT", "INTEGER", "INTERSECT", "INTERVAL",
+    "INTO", "IS", "JOIN", "JSON", "JSONB", "KEY", "LAST", "LATERAL", "LEFT", "LIKE", "LIMIT",
+    "LOCKED", "MATCHED", "MATERIALIZED", "MERGE", "NATURAL", "NEXT", "NOT", "NOTHING", "NOW", "NOWAIT",
+    "NULL", "NULLS", "NUMERIC", "OF", "OFFSET", "ON", "ONLY", "OR", "ORDER", "OUTER", "OVER",
+    "PARTITION", "PRECISION", "PRIMARY", "REAL", "RECURSIVE", "REFERENCES", "RENAME", "REPLACE",
+    "RESTRICT", "RETURNING", "RIGHT", "ROW", "ROWS", "SCHEMA", "SELECT", "SERIAL", "SET", "SHARE",
+    "SKIP", "SMALLINT", "SOME", "TABLE", "TEMP", "TEMPORARY", "TEXT", "THEN", "TIME", "TIMESTAMP",
+    "TIMESTAMPTZ", "TO", "TOP", "TRUE", "TRUNCATE", "UNION", "UNIQUE", "UPDATE", "USING", "UUID",
+    "VALUES", "VARCHAR", "VIEW", "WHEN", "WHERE", "WINDOW", "WITH",
+];
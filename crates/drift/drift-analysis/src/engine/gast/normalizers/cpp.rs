@@ -5,7 +5,8 @@ use crate::engine::gast::base_normalizer::GASTNormalizer;
 use crate::engine::gast::types::GASTNode;
 use crate::scanner::language_detect::Language;
 
-/// C++ normalizer — used as a fallback for C-family languages without a dedicated normalizer.
+/// C++ normalizer — the tree-sitter-c grammar is a subset of tree-sitter-cpp,
+/// so the same node mapping serves both languages.
 pub struct CppNormalizer;
 
 impl GASTNormalizer for CppNormalizer {
@@ -17,15 +18,28 @@ impl GASTNormalizer for CppNormalizer {
                 let body = self.normalize_children(node, source);
                 GASTNode::Program { body }
             }
-            "function_definition" | "function_declarator" => self.normalize_function(node, source),
-            "class_specifier" | "struct_specifier" => {
+            "function_definition" => self.normalize_function(node, source),
+            "class_specifier" | "struct_specifier" | "union_specifier" => {
                 let name = node.child_by_field_name("name")
                     .and_then(|n| n.utf8_text(source).ok())
                     .unwrap_or("").to_string();
+                let bases = find_child_by_kind(node, "base_class_clause")
+                    .map(|clause| {
+                        let mut bases = Vec::new();
+                        for i in 0..clause.named_child_count() {
+                            if let Some(base) = clause.named_child(i) {
+                                if base.kind() != "access_specifier" {
+                                    bases.push(base.utf8_text(source).unwrap_or("").to_string());
+                                }
+                            }
+                        }
+                        bases
+                    })
+                    .unwrap_or_default();
                 let body = node.child_by_field_name("body")
                     .map(|n| self.normalize_children(&n, source))
                     .unwrap_or_default();
-                GASTNode::Class { name, bases: vec![], body, is_abstract: false }
+                GASTNode::Class { name, bases, body, is_abstract: false }
             }
             "enum_specifier" => self.normalize_enum(node, source),
             "namespace_definition" => {
@@ -39,23 +53,50 @@ impl GASTNormalizer for CppNormalizer {
             }
             "if_statement" => self.normalize_if(node, source),
             "for_statement" | "for_range_loop" => self.normalize_for(node, source),
-            "while_statement" => self.normalize_while(node, source),
+            "while_statement" | "do_statement" => self.normalize_while(node, source),
             "switch_statement" => self.normalize_switch(node, source),
             "try_statement" => self.normalize_try(node, source),
             "throw_statement" => self.normalize_throw(node, source),
             "return_statement" => self.normalize_return(node, source),
             "call_expression" => self.normalize_call(node, source),
+            // obj.field / ptr->field
+            "field_expression" => {
+                let object = node.child_by_field_name("argument")
+                    .map(|n| self.normalize_node(&n, source))
+                    .unwrap_or(GASTNode::Identifier { name: "unknown".to_string() });
+                let property = node.child_by_field_name("field")
+                    .map(|f| {
+                        // obj.template method<T>() → method
+                        f.child_by_field_name("name").unwrap_or(f)
+                    })
+                    .and_then(|n| n.utf8_text(source).ok())
+                    .unwrap_or("")
+                    .to_string();
+                GASTNode::MemberAccess { object: Box::new(object), property }
+            }
+            "new_expression" => {
+                let callee = node.child_by_field_name("type")
+                    .map(|n| self.normalize_node(&n, source))
+                    .unwrap_or(GASTNode::Identifier { name: "unknown".to_string() });
+                let arguments = node.child_by_field_name("arguments")
+                    .map(|n| self.normalize_children(&n, source))
+                    .unwrap_or_default();
+                GASTNode::NewExpression { callee: Box::new(callee), arguments }
+            }
             "preproc_include" => {
                 let path = node.child_by_field_name("path")
                     .and_then(|n| n.utf8_text(source).ok())
-                    .unwrap_or("").to_string();
+                    .unwrap_or("")
+                    .trim_matches(|c| c == '"' || c == '<' || c == '>')
+                    .to_string();
                 GASTNode::Import { source: path, specifiers: vec![] }
             }
             "compound_statement" => {
                 let stmts = self.normalize_children(node, source);
                 GASTNode::Block { statements: stmts }
             }
-            "identifier" | "field_identifier" | "type_identifier" | "namespace_identifier" => {
+            "identifier" | "field_identifier" | "type_identifier" | "namespace_identifier"
+            | "qualified_identifier" | "primitive_type" => {
                 let name = node.utf8_text(source).unwrap_or("").to_string();
                 GASTNode::Identifier { name }
             }
@@ -82,4 +123,80 @@ impl GASTNormalizer for CppNormalizer {
             }
         }
     }
+
+    /// C/C++ function names and parameters live on the nested `function_declarator`.
+    fn normalize_function(&self, node: &Node, source: &[u8]) -> GASTNode {
+        let declarator = node.child_by_field_name("declarator").and_then(function_declarator);
+        let name = declarator
+            .and_then(|d| d.child_by_field_name("declarator"))
+            .and_then(|n| n.utf8_text(source).ok())
+            .unwrap_or("")
+            .to_string();
+        let params = declarator
+            .and_then(|d| d.child_by_field_name("parameters"))
+            .map(|p| {
+                let mut params = Vec::new();
+                for i in 0..p.named_child_count() {
+                    if let Some(param) = p.named_child(i) {
+                        if param.kind() == "comment" {
+                            continue;
+                        }
+                        params.push(GASTNode::Parameter {
+                            name: param.child_by_field_name("declarator")
+                                .and_then(|d| d.utf8_text(source).ok())
+                                .unwrap_or("")
+                                .trim_start_matches(['*', '&'])
+                                .to_string(),
+                            type_annotation: param.child_by_field_name("type")
+                                .and_then(|t| t.utf8_text(source).ok())
+                                .map(|t| t.to_string()),
+                            default_value: param.child_by_field_name("default_value")
+                                .map(|d| Box::new(self.normalize_node(&d, source))),
+                            is_rest: param.kind().starts_with("variadic"),
+                        });
+                    }
+                }
+                params
+            })
+            .unwrap_or_default();
+        let body = node.child_by_field_name("body")
+            .map(|n| self.normalize_node(&n, source))
+            .unwrap_or(GASTNode::Block { statements: vec![] });
+        let return_type = node.child_by_field_name("type")
+            .and_then(|t| t.utf8_text(source).ok())
+            .map(|t| t.to_string());
+
+        GASTNode::Function {
+            name,
+            params,
+            body: Box::new(body),
+            is_async: false,
+            is_generator: false,
+            return_type,
+        }
+    }
+}
+
+/// Descend through pointer/reference declarators to the function declarator.
+fn function_declarator(node: Node) -> Option<Node> {
+    let mut current = node;
+    for _ in 0..8 {
+        if current.kind() == "function_declarator" {
+            return Some(current);
+        }
+        current = current.child_by_field_name("declarator")?;
+    }
+    None
+}
+
+fn find_child_by_kind<'a>(node: &Node<'a>, kind: &str) -> Option<Node<'a>> {
+    let count = node.child_count();
+    for i in 0..count {
+        if let Some(child) = node.child(i) {
+            if child.kind() == kind {
+                return Some(child);
+            }
+        }
+    }
+    None
 }
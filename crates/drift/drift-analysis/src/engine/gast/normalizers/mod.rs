@@ -1,4 +1,4 @@
-//! Language-specific GAST normalizers for 9 languages.
+//! Language-specific GAST normalizers for 11 languages.
 
 pub mod typescript;
 pub mod python;
@@ -9,6 +9,8 @@ pub mod rust_lang;
 pub mod php;
 pub mod ruby;
 pub mod cpp;
+pub mod swift;
+pub mod scala;
 
 use crate::scanner::language_detect::Language;
 use super::base_normalizer::GASTNormalizer;
@@ -26,6 +28,7 @@ pub fn normalizer_for(language: Language) -> Box<dyn GASTNormalizer> {
         Language::Ruby => Box::new(ruby::RubyNormalizer),
         Language::Kotlin => Box::new(java::JavaNormalizer), // Kotlin shares Java-like AST
         Language::Cpp | Language::C => Box::new(cpp::CppNormalizer),
-        Language::Swift | Language::Scala => Box::new(java::JavaNormalizer),
+        Language::Swift => Box::new(swift::SwiftNormalizer),
+        Language::Scala => Box::new(scala::ScalaNormalizer),
     }
 }
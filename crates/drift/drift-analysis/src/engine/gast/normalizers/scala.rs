@@ -0,0 +1,278 @@
+//! Scala GAST normalizer.
+
+use tree_sitter::Node;
+use crate::engine::gast::base_normalizer::GASTNormalizer;
+use crate::engine::gast::types::GASTNode;
+use crate::scanner::language_detect::Language;
+
+pub struct ScalaNormalizer;
+
+impl GASTNormalizer for ScalaNormalizer {
+    fn language(&self) -> Language { Language::Scala }
+
+    fn normalize_node(&self, node: &Node, source: &[u8]) -> GASTNode {
+        match node.kind() {
+            "compilation_unit" => {
+                let body = self.normalize_children(node, source);
+                GASTNode::Program { body }
+            }
+            "package_clause" => {
+                let name = text_of(node.child_by_field_name("name"), source);
+                let body = node.child_by_field_name("body")
+                    .map(|n| self.normalize_children(&n, source))
+                    .unwrap_or_default();
+                GASTNode::Namespace { name, body }
+            }
+            // `object` is a singleton class.
+            "class_definition" | "object_definition" => {
+                let name = text_of(node.child_by_field_name("name"), source);
+                let body = node.child_by_field_name("body")
+                    .map(|n| self.normalize_children(&n, source))
+                    .unwrap_or_default();
+                let is_abstract = find_child_by_kind(node, "modifiers")
+                    .is_some_and(|m| find_child_by_kind(&m, "abstract").is_some());
+                GASTNode::Class { name, bases: parent_types(node, source), body, is_abstract }
+            }
+            "trait_definition" => {
+                let name = text_of(node.child_by_field_name("name"), source);
+                let body = node.child_by_field_name("body")
+                    .map(|n| self.normalize_children(&n, source))
+                    .unwrap_or_default();
+                GASTNode::Interface { name, extends: parent_types(node, source), body }
+            }
+            "enum_definition" => {
+                let name = text_of(node.child_by_field_name("name"), source);
+                let members = node.child_by_field_name("body")
+                    .map(|n| self.normalize_children(&n, source))
+                    .unwrap_or_default();
+                GASTNode::Enum { name, members }
+            }
+            "function_definition" | "function_declaration" => self.normalize_function(node, source),
+            "lambda_expression" => self.normalize_lambda(node, source),
+            "val_definition" | "var_definition" => {
+                let name = text_of(node.child_by_field_name("pattern"), source);
+                let type_annotation = node.child_by_field_name("type")
+                    .and_then(|t| t.utf8_text(source).ok())
+                    .map(|t| t.to_string());
+                let value = node.child_by_field_name("value")
+                    .map(|v| Box::new(self.normalize_node(&v, source)));
+                GASTNode::VariableDeclaration {
+                    name,
+                    type_annotation,
+                    value,
+                    is_const: node.kind() == "val_definition",
+                }
+            }
+            "if_expression" => self.normalize_if(node, source),
+            "for_expression" => self.normalize_for(node, source),
+            "while_expression" | "do_while_expression" => self.normalize_while(node, source),
+            "match_expression" => self.normalize_switch(node, source),
+            "try_expression" => {
+                let try_block = node.child_by_field_name("body")
+                    .map(|n| self.normalize_node(&n, source))
+                    .unwrap_or(GASTNode::Block { statements: vec![] });
+                let catch_block = find_child_by_kind(node, "catch_clause")
+                    .map(|c| Box::new(GASTNode::Block { statements: self.normalize_children(&c, source) }));
+                let finally_block = find_child_by_kind(node, "finally_clause")
+                    .map(|f| Box::new(GASTNode::Block { statements: self.normalize_children(&f, source) }));
+                GASTNode::TryCatch {
+                    try_block: Box::new(try_block),
+                    catch_param: None,
+                    catch_block,
+                    finally_block,
+                }
+            }
+            "throw_expression" => {
+                let value = node.named_child(0)
+                    .map(|n| self.normalize_node(&n, source))
+                    .unwrap_or(GASTNode::NullLiteral);
+                GASTNode::Throw { value: Box::new(value) }
+            }
+            "return_expression" => {
+                let value = node.named_child(0).map(|n| Box::new(self.normalize_node(&n, source)));
+                GASTNode::Return { value }
+            }
+            "call_expression" => self.normalize_call(node, source),
+            "generic_function" => node.child_by_field_name("function")
+                .map(|f| self.normalize_node(&f, source))
+                .unwrap_or(GASTNode::Identifier { name: "unknown".to_string() }),
+            "field_expression" => {
+                let object = node.child_by_field_name("value")
+                    .map(|n| self.normalize_node(&n, source))
+                    .unwrap_or(GASTNode::Identifier { name: "unknown".to_string() });
+                let property = text_of(node.child_by_field_name("field"), source);
+                GASTNode::MemberAccess { object: Box::new(object), property }
+            }
+            "instance_expression" => {
+                let callee = (0..node.named_child_count())
+                    .filter_map(|i| node.named_child(i))
+                    .find(|c| c.kind() != "arguments")
+                    .map(|n| self.normalize_node(&n, source))
+                    .unwrap_or(GASTNode::Identifier { name: "unknown".to_string() });
+                let arguments = node.child_by_field_name("arguments")
+                    .map(|n| self.normalize_children(&n, source))
+                    .unwrap_or_default();
+                GASTNode::NewExpression { callee: Box::new(callee), arguments }
+            }
+            "import_declaration" => {
+                let mut path = Vec::new();
+                let mut specifiers = Vec::new();
+                for i in 0..node.named_child_count() {
+                    let Some(child) = node.named_child(i) else { continue };
+                    match child.kind() {
+                        "identifier" => path.push(text_of(Some(child), source)),
+                        "namespace_selectors" => {
+                            for j in 0..child.named_child_count() {
+                                let Some(sel) = child.named_child(j) else { continue };
+                                let (name, alias) = match sel.kind() {
+                                    "arrow_renamed_identifier" | "as_renamed_identifier" => (
+                                        text_of(sel.child_by_field_name("name"), source),
+                                        sel.child_by_field_name("alias").map(|a| text_of(Some(a), source)),
+                                    ),
+                                    _ => (text_of(Some(sel), source), None),
+                                };
+                                specifiers.push(GASTNode::ImportSpecifier { name, alias });
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                GASTNode::Import { source: path.join("."), specifiers }
+            }
+            "block" | "template_body" => {
+                let stmts = self.normalize_children(node, source);
+                GASTNode::Block { statements: stmts }
+            }
+            "identifier" | "type_identifier" | "stable_identifier" => {
+                let name = node.utf8_text(source).unwrap_or("").to_string();
+                GASTNode::Identifier { name }
+            }
+            "string" | "interpolated_string_expression" => {
+                let value = node.utf8_text(source).unwrap_or("").to_string();
+                GASTNode::StringLiteral { value }
+            }
+            "integer_literal" | "floating_point_literal" => {
+                let value = node.utf8_text(source).unwrap_or("0").to_string();
+                GASTNode::NumberLiteral { value }
+            }
+            "boolean_literal" => GASTNode::BoolLiteral {
+                value: node.utf8_text(source).ok() == Some("true"),
+            },
+            "null_literal" => GASTNode::NullLiteral,
+            "comment" | "block_comment" => {
+                let text = node.utf8_text(source).unwrap_or("").to_string();
+                let is_doc = text.starts_with("/**");
+                GASTNode::Comment { text, is_doc }
+            }
+            "annotation" => {
+                let name = text_of(node.child_by_field_name("name"), source);
+                let arguments = node.child_by_field_name("arguments")
+                    .map(|n| self.normalize_children(&n, source))
+                    .unwrap_or_default();
+                GASTNode::Decorator { name, arguments }
+            }
+            _ => {
+                let children = self.normalize_children(node, source);
+                GASTNode::Other { kind: node.kind().to_string(), children }
+            }
+        }
+    }
+
+    fn normalize_function(&self, node: &Node, source: &[u8]) -> GASTNode {
+        let name = text_of(node.child_by_field_name("name"), source);
+        // Curried and implicit parameter clauses are flattened in order.
+        let mut params = Vec::new();
+        let mut cursor = node.walk();
+        for clause in node.children_by_field_name("parameters", &mut cursor) {
+            if clause.kind() != "parameters" {
+                continue;
+            }
+            for i in 0..clause.named_child_count() {
+                let Some(param) = clause.named_child(i) else { continue };
+                if param.kind() != "parameter" {
+                    continue;
+                }
+                let type_annotation = param.child_by_field_name("type")
+                    .and_then(|t| t.utf8_text(source).ok())
+                    .map(|t| t.to_string());
+                params.push(GASTNode::Parameter {
+                    name: text_of(param.child_by_field_name("name"), source),
+                    is_rest: type_annotation.as_deref().is_some_and(|t| t.ends_with('*')),
+                    type_annotation,
+                    default_value: param.child_by_field_name("default_value")
+                        .map(|d| Box::new(self.normalize_node(&d, source))),
+                });
+            }
+        }
+        let body = node.child_by_field_name("body")
+            .map(|n| self.normalize_node(&n, source))
+            .unwrap_or(GASTNode::Block { statements: vec![] });
+        let return_type = node.child_by_field_name("return_type")
+            .and_then(|t| t.utf8_text(source).ok())
+            .map(|t| t.to_string());
+
+        GASTNode::Function {
+            name,
+            params,
+            body: Box::new(body),
+            is_async: false,
+            is_generator: false,
+            return_type,
+        }
+    }
+
+    fn normalize_lambda(&self, node: &Node, source: &[u8]) -> GASTNode {
+        let params = match node.child_by_field_name("parameters") {
+            Some(p) if p.kind() == "bindings" => (0..p.named_child_count())
+                .filter_map(|i| p.named_child(i))
+                .map(|b| GASTNode::Parameter {
+                    name: text_of(b.child_by_field_name("name"), source),
+                    type_annotation: b.child_by_field_name("type")
+                        .and_then(|t| t.utf8_text(source).ok())
+                        .map(|t| t.to_string()),
+                    default_value: None,
+                    is_rest: false,
+                })
+                .collect(),
+            Some(p) => vec![GASTNode::Parameter {
+                name: text_of(Some(p), source),
+                type_annotation: None,
+                default_value: None,
+                is_rest: false,
+            }],
+            None => vec![],
+        };
+        // The body is the last named child.
+        let body = node.named_child(node.named_child_count().saturating_sub(1))
+            .filter(|b| Some(*b) != node.child_by_field_name("parameters"))
+            .map(|b| self.normalize_node(&b, source))
+            .unwrap_or(GASTNode::Block { statements: vec![] });
+        GASTNode::Lambda { params, body: Box::new(body), is_async: false }
+    }
+}
+
+/// `extends A with B` → ["A", "B"].
+fn parent_types(node: &Node, source: &[u8]) -> Vec<String> {
+    let Some(clause) = node.child_by_field_name("extend") else { return vec![] };
+    let mut cursor = clause.walk();
+    clause.children_by_field_name("type", &mut cursor)
+        .filter(|t| t.kind() != "with")
+        .map(|t| text_of(Some(t), source))
+        .collect()
+}
+
+fn text_of(node: Option<Node>, source: &[u8]) -> String {
+    node.and_then(|n| n.utf8_text(source).ok()).unwrap_or("").to_string()
+}
+
+fn find_child_by_kind<'a>(node: &Node<'a>, kind: &str) -> Option<Node<'a>> {
+    let count = node.child_count();
+    for i in 0..count {
+        if let Some(child) = node.child(i) {
+            if child.kind() == kind {
+                return Some(child);
+            }
+        }
+    }
+    None
+}
@@ -0,0 +1,291 @@
+//! Swift GAST normalizer.
+
+use tree_sitter::Node;
+use crate::engine::gast::base_normalizer::GASTNormalizer;
+use crate::engine::gast::types::GASTNode;
+use crate::scanner::language_detect::Language;
+
+pub struct SwiftNormalizer;
+
+impl GASTNormalizer for SwiftNormalizer {
+    fn language(&self) -> Language { Language::Swift }
+
+    fn normalize_node(&self, node: &Node, source: &[u8]) -> GASTNode {
+        match node.kind() {
+            "source_file" => {
+                let body = self.normalize_children(node, source);
+                GASTNode::Program { body }
+            }
+            // class / struct / enum / actor / extension share one node kind.
+            "class_declaration" => {
+                let name = node.child_by_field_name("name")
+                    .and_then(|n| n.utf8_text(source).ok())
+                    .unwrap_or("").to_string();
+                let body = node.child_by_field_name("body")
+                    .map(|n| self.normalize_children(&n, source))
+                    .unwrap_or_default();
+                let kind = node.child_by_field_name("declaration_kind")
+                    .and_then(|k| k.utf8_text(source).ok())
+                    .unwrap_or("");
+                if kind == "enum" {
+                    GASTNode::Enum { name, members: body }
+                } else {
+                    GASTNode::Class { name, bases: inherited_types(node, source), body, is_abstract: false }
+                }
+            }
+            "protocol_declaration" => {
+                let name = node.child_by_field_name("name")
+                    .and_then(|n| n.utf8_text(source).ok())
+                    .unwrap_or("").to_string();
+                let body = node.child_by_field_name("body")
+                    .map(|n| self.normalize_children(&n, source))
+                    .unwrap_or_default();
+                GASTNode::Interface { name, extends: inherited_types(node, source), body }
+            }
+            "function_declaration" | "protocol_function_declaration" => self.normalize_function(node, source),
+            "init_declaration" => {
+                let params = self.normalize_parameters(node, source);
+                let body = node.child_by_field_name("body")
+                    .map(|n| self.normalize_node(&n, source))
+                    .unwrap_or(GASTNode::Block { statements: vec![] });
+                GASTNode::Constructor { params, body: Box::new(body) }
+            }
+            "lambda_literal" => self.normalize_lambda(node, source),
+            "property_declaration" => {
+                let name = node.child_by_field_name("name")
+                    .map(|p| p.child_by_field_name("bound_identifier").unwrap_or(p))
+                    .and_then(|n| n.utf8_text(source).ok())
+                    .unwrap_or("").to_string();
+                let type_annotation = find_child_by_kind(node, "type_annotation")
+                    .and_then(|t| t.utf8_text(source).ok())
+                    .map(|t| t.trim_start_matches(':').trim().to_string());
+                let value = node.child_by_field_name("value")
+                    .map(|v| Box::new(self.normalize_node(&v, source)));
+                let is_const = find_child_by_kind(node, "value_binding_pattern")
+                    .and_then(|b| b.child_by_field_name("mutability"))
+                    .and_then(|m| m.utf8_text(source).ok())
+                    == Some("let");
+                GASTNode::VariableDeclaration { name, type_annotation, value, is_const }
+            }
+            "if_statement" | "guard_statement" => self.normalize_if(node, source),
+            "for_statement" => self.normalize_for(node, source),
+            "while_statement" | "repeat_while_statement" => self.normalize_while(node, source),
+            "switch_statement" => self.normalize_switch(node, source),
+            "do_statement" => {
+                let try_block = find_child_by_kind(node, "statements")
+                    .map(|n| GASTNode::Block { statements: self.normalize_children(&n, source) })
+                    .unwrap_or(GASTNode::Block { statements: vec![] });
+                let catch = find_child_by_kind(node, "catch_block");
+                let catch_param = catch
+                    .and_then(|c| c.child_by_field_name("error"))
+                    .map(|p| Box::new(self.normalize_node(&p, source)));
+                let catch_block = catch
+                    .and_then(|c| find_child_by_kind(&c, "statements"))
+                    .map(|n| Box::new(GASTNode::Block { statements: self.normalize_children(&n, source) }));
+                GASTNode::TryCatch {
+                    try_block: Box::new(try_block),
+                    catch_param,
+                    catch_block,
+                    finally_block: None,
+                }
+            }
+            // return / throw / break / continue
+            "control_transfer_statement" => {
+                let value = node.child_by_field_name("result")
+                    .or_else(|| node.named_child(node.named_child_count().saturating_sub(1)))
+                    .filter(|v| v.kind() != "throw_keyword")
+                    .map(|v| Box::new(self.normalize_node(&v, source)));
+                if find_child_by_kind(node, "throw_keyword").is_some() {
+                    GASTNode::Throw { value: value.unwrap_or(Box::new(GASTNode::NullLiteral)) }
+                } else {
+                    GASTNode::Return { value }
+                }
+            }
+            "await_expression" => {
+                let value = node.child_by_field_name("expr")
+                    .map(|n| self.normalize_node(&n, source))
+                    .unwrap_or(GASTNode::NullLiteral);
+                GASTNode::Await { value: Box::new(value) }
+            }
+            "call_expression" => self.normalize_call(node, source),
+            "navigation_expression" => {
+                let object = node.child_by_field_name("target")
+                    .map(|n| self.normalize_node(&n, source))
+                    .unwrap_or(GASTNode::Identifier { name: "unknown".to_string() });
+                let property = node.child_by_field_name("suffix")
+                    .and_then(|s| s.child_by_field_name("suffix"))
+                    .and_then(|n| n.utf8_text(source).ok())
+                    .unwrap_or("")
+                    .to_string();
+                GASTNode::MemberAccess { object: Box::new(object), property }
+            }
+            "import_declaration" => {
+                let path = find_child_by_kind(node, "identifier")
+                    .and_then(|n| n.utf8_text(source).ok())
+                    .unwrap_or("")
+                    .to_string();
+                GASTNode::Import { source: path, specifiers: vec![] }
+            }
+            "function_body" | "statements" => {
+                let inner = find_child_by_kind(node, "statements").unwrap_or(*node);
+                let stmts = self.normalize_children(&inner, source);
+                GASTNode::Block { statements: stmts }
+            }
+            "simple_identifier" | "type_identifier" | "user_type" => {
+                let name = node.utf8_text(source).unwrap_or("").to_string();
+                GASTNode::Identifier { name }
+            }
+            "line_string_literal" | "multi_line_string_literal" | "raw_string_literal" => {
+                let value = node.utf8_text(source).unwrap_or("").to_string();
+                GASTNode::StringLiteral { value }
+            }
+            "integer_literal" | "real_literal" | "hex_literal" | "oct_literal" | "bin_literal" => {
+                let value = node.utf8_text(source).unwrap_or("0").to_string();
+                GASTNode::NumberLiteral { value }
+            }
+            "boolean_literal" => GASTNode::BoolLiteral {
+                value: node.utf8_text(source).ok() == Some("true"),
+            },
+            "nil" => GASTNode::NullLiteral,
+            "comment" | "multiline_comment" => {
+                let text = node.utf8_text(source).unwrap_or("").to_string();
+                let is_doc = text.starts_with("///") || text.starts_with("/**");
+                GASTNode::Comment { text, is_doc }
+            }
+            "attribute" => {
+                let name = find_child_by_kind(node, "user_type")
+                    .and_then(|n| n.utf8_text(source).ok())
+                    .unwrap_or("")
+                    .to_string();
+                GASTNode::Decorator { name, arguments: vec![] }
+            }
+            _ => {
+                let children = self.normalize_children(node, source);
+                GASTNode::Other { kind: node.kind().to_string(), children }
+            }
+        }
+    }
+
+    fn normalize_function(&self, node: &Node, source: &[u8]) -> GASTNode {
+        let name = node.child_by_field_name("name")
+            .and_then(|n| n.utf8_text(source).ok())
+            .unwrap_or("")
+            .to_string();
+        let params = self.normalize_parameters(node, source);
+        let body = node.child_by_field_name("body")
+            .map(|n| self.normalize_node(&n, source))
+            .unwrap_or(GASTNode::Block { statements: vec![] });
+        // The return type is the node after `->`.
+        let return_type = (0..node.child_count())
+            .filter_map(|i| node.child(i))
+            .skip_while(|c| c.kind() != "->")
+            .nth(1)
+            .and_then(|t| t.utf8_text(source).ok())
+            .map(|t| t.to_string());
+
+        GASTNode::Function {
+            name,
+            params,
+            body: Box::new(body),
+            is_async: find_child_by_kind(node, "async").is_some(),
+            is_generator: false,
+            return_type,
+        }
+    }
+
+    fn normalize_lambda(&self, node: &Node, source: &[u8]) -> GASTNode {
+        let params = find_child_by_kind(node, "lambda_function_type")
+            .and_then(|t| find_child_by_kind(&t, "lambda_function_type_parameters"))
+            .map(|list| {
+                (0..list.named_child_count())
+                    .filter_map(|i| list.named_child(i))
+                    .map(|p| GASTNode::Parameter {
+                        name: p.child_by_field_name("name")
+                            .and_then(|n| n.utf8_text(source).ok())
+                            .unwrap_or("")
+                            .to_string(),
+                        type_annotation: None,
+                        default_value: None,
+                        is_rest: false,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        let body = find_child_by_kind(node, "statements")
+            .map(|n| GASTNode::Block { statements: self.normalize_children(&n, source) })
+            .unwrap_or(GASTNode::Block { statements: vec![] });
+        GASTNode::Lambda { params, body: Box::new(body), is_async: false }
+    }
+
+    fn normalize_call(&self, node: &Node, source: &[u8]) -> GASTNode {
+        let callee = node.named_child(0)
+            .map(|n| self.normalize_node(&n, source))
+            .unwrap_or(GASTNode::Identifier { name: "unknown".to_string() });
+        let mut arguments = Vec::new();
+        if let Some(suffix) = find_child_by_kind(node, "call_suffix") {
+            for i in 0..suffix.named_child_count() {
+                let Some(part) = suffix.named_child(i) else { continue };
+                match part.kind() {
+                    "value_arguments" => {
+                        for j in 0..part.named_child_count() {
+                            if let Some(value) = part.named_child(j).and_then(|a| a.child_by_field_name("value")) {
+                                arguments.push(self.normalize_node(&value, source));
+                            }
+                        }
+                    }
+                    // Trailing closure
+                    "lambda_literal" => arguments.push(self.normalize_node(&part, source)),
+                    _ => {}
+                }
+            }
+        }
+
+        if let GASTNode::MemberAccess { object, property } = callee {
+            return GASTNode::MethodCall { receiver: object, method: property, arguments };
+        }
+        GASTNode::Call { callee: Box::new(callee), arguments }
+    }
+}
+
+impl SwiftNormalizer {
+    /// Swift parameters are direct children of the declaration.
+    fn normalize_parameters(&self, node: &Node, source: &[u8]) -> Vec<GASTNode> {
+        let mut params = Vec::new();
+        for i in 0..node.named_child_count() {
+            let Some(param) = node.named_child(i) else { continue };
+            if param.kind() != "parameter" {
+                continue;
+            }
+            let mut cursor = param.walk();
+            // Both the local name and the type are labelled `name` by the grammar.
+            let named: Vec<Node> = param.children_by_field_name("name", &mut cursor).collect();
+            params.push(GASTNode::Parameter {
+                name: named.first().and_then(|n| n.utf8_text(source).ok()).unwrap_or("").to_string(),
+                type_annotation: named.get(1).and_then(|n| n.utf8_text(source).ok()).map(|t| t.to_string()),
+                default_value: None,
+                is_rest: find_child_by_kind(&param, "...").is_some(),
+            });
+        }
+        params
+    }
+}
+
+fn inherited_types(node: &Node, source: &[u8]) -> Vec<String> {
+    (0..node.named_child_count())
+        .filter_map(|i| node.named_child(i))
+        .filter(|c| c.kind() == "inheritance_specifier")
+        .filter_map(|c| c.utf8_text(source).ok().map(|t| t.to_string()))
+        .collect()
+}
+
+fn find_child_by_kind<'a>(node: &Node<'a>, kind: &str) -> Option<Node<'a>> {
+    let count = node.child_count();
+    for i in 0..count {
+        if let Some(child) = node.child(i) {
+            if child.kind() == kind {
+                return Some(child);
+            }
+        }
+    }
+    None
+}
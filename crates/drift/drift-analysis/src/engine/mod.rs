@@ -7,6 +7,7 @@ pub mod types;
 pub mod visitor;
 pub mod pipeline;
 pub mod string_extraction;
+pub mod embedded_sql;
 pub mod regex_engine;
 pub mod resolution;
 pub mod incremental;
@@ -18,4 +19,4 @@ pub use visitor::{DetectorHandler, FileDetectorHandler, LearningDetectorHandler,
 pub use pipeline::AnalysisPipeline;
 pub use resolution::ResolutionIndex;
 pub use incremental::IncrementalAnalyzer;
-pub use toml_patterns::{TomlPatternLoader, CompiledQuery};
+pub use toml_patterns::{TomlPatternLoader, TomlQueryHandler, CompiledQuery};
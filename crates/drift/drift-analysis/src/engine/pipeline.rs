@@ -1,7 +1,7 @@
 //! 4-phase per-file analysis pipeline.
 //!
 //! Phase 1: AST pattern detection via single-pass visitor
-//! Phase 2: String literal extraction
+//! Phase 2: String literal and embedded SQL extraction
 //! Phase 3: Regex matching on extracted strings
 //! Phase 4: Resolution index building
 
@@ -12,6 +12,7 @@ use crate::parsers::types::ParseResult;
 use super::regex_engine::RegexEngine;
 use super::resolution::ResolutionIndex;
 use super::string_extraction;
+use super::embedded_sql;
 use super::types::AnalysisResult;
 use super::visitor::{DetectionContext, DetectionEngine};
 
@@ -69,6 +70,12 @@ impl AnalysisPipeline {
             parse_result.language,
         );
         result.strings_extracted = extracted_strings.len();
+        result.sql_statements = embedded_sql::extract_embedded_sql(
+            tree,
+            source,
+            &parse_result.file,
+            parse_result.language,
+        );
         result.phase_times_us[1] = phase2_start.elapsed().as_micros() as u64;
 
         // Phase 3: Regex matching on extracted strings
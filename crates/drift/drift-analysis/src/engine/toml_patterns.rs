@@ -1,13 +1,27 @@
 //! Declarative TOML pattern definitions — user-extensible without recompiling (AD3).
 //!
 //! Each `CompiledQuery` carries `cwe_ids: SmallVec<[u32; 2]>` and `owasp: Option<String>`.
+//!
+//! A pattern matches either by `pattern` (a regex) or by `query` (a tree-sitter
+//! S-expression query). Queries are compiled once per declared language and run
+//! by `TomlQueryHandler` during the single-pass visitor. Text predicates
+//! (`#eq?`, `#match?`, `#any-of?` and their negations) are evaluated by
+//! tree-sitter itself. The `@match` capture, when present, marks the reported
+//! node; otherwise the first capture not starting with `_` is used.
+
+use std::sync::Arc;
 
 use serde::{Deserialize, Serialize};
 use smallvec::SmallVec;
+use tree_sitter::{Node, QueryCursor, StreamingIterator};
 
 use drift_core::errors::DetectionError;
 
-use super::types::PatternCategory;
+use crate::frameworks::loader::parse_language;
+use crate::scanner::language_detect::Language;
+
+use super::types::{DetectionMethod, PatternCategory, PatternMatch};
+use super::visitor::{DetectionContext, DetectorHandler};
 
 /// A TOML-defined pattern definition.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -16,7 +30,11 @@ pub struct TomlPatternDef {
     pub name: String,
     pub description: Option<String>,
     pub category: String,
+    #[serde(default)]
     pub pattern: String,
+    /// Tree-sitter S-expression query, compiled for each entry in `languages`.
+    #[serde(default)]
+    pub query: Option<String>,
     #[serde(default)]
     pub node_types: Vec<String>,
     #[serde(default)]
@@ -42,6 +60,8 @@ pub struct CompiledQuery {
     pub description: Option<String>,
     pub category: PatternCategory,
     pub regex: Option<regex::Regex>,
+    /// Compiled tree-sitter queries, one per declared language.
+    pub ast_queries: Vec<(Language, Arc<tree_sitter::Query>)>,
     pub node_types: Vec<String>,
     pub languages: Vec<String>,
     pub confidence: f32,
@@ -84,6 +104,31 @@ impl TomlPatternLoader {
         Self::load_from_str(&content)
     }
 
+    /// Load every `*.toml` file in a directory, skipping files that fail to load.
+    pub fn load_from_dir(dir: &std::path::Path) -> Vec<CompiledQuery> {
+        let mut paths: Vec<_> = match std::fs::read_dir(dir) {
+            Ok(entries) => entries
+                .flatten()
+                .map(|e| e.path())
+                .filter(|p| p.extension().is_some_and(|ext| ext == "toml"))
+                .collect(),
+            Err(_) => return Vec::new(),
+        };
+        paths.sort();
+
+        let mut queries = Vec::new();
+        for path in paths {
+            match Self::load_from_file(&path) {
+                Ok(loaded) => queries.extend(loaded),
+                Err(e) => eprintln!(
+                    "[drift] warning: failed to load custom patterns '{}': {e}",
+                    path.display()
+                ),
+            }
+        }
+        queries
+    }
+
     /// Compile a single pattern definition into a query.
     fn compile(def: TomlPatternDef) -> Result<CompiledQuery, DetectionError> {
         let category = PatternCategory::parse_str(&def.category).ok_or_else(|| {
@@ -104,6 +149,37 @@ impl TomlPatternLoader {
             None
         };
 
+        let mut ast_queries = Vec::new();
+        if let Some(query) = def.query.as_deref().filter(|q| !q.trim().is_empty()) {
+            if def.languages.is_empty() {
+                return Err(DetectionError::InvalidPattern(format!(
+                    "pattern '{}' has a query but no languages",
+                    def.id
+                )));
+            }
+            for name in &def.languages {
+                let language = parse_language(name).ok_or_else(|| {
+                    DetectionError::InvalidPattern(format!(
+                        "unknown language '{name}' in pattern '{}'",
+                        def.id
+                    ))
+                })?;
+                let compiled = tree_sitter::Query::new(&language.ts_language(), query).map_err(|e| {
+                    DetectionError::QueryCompilationFailed(format!(
+                        "query error in pattern '{}' for {}: {e}",
+                        def.id,
+                        language.name()
+                    ))
+                })?;
+                ast_queries.push((language, Arc::new(compiled)));
+            }
+        } else if regex.is_none() {
+            return Err(DetectionError::InvalidPattern(format!(
+                "pattern '{}' has neither a pattern nor a query",
+                def.id
+            )));
+        }
+
         let mut cwe_ids = SmallVec::new();
         for id in &def.cwe_ids {
             cwe_ids.push(*id);
@@ -115,6 +191,7 @@ impl TomlPatternLoader {
             description: def.description,
             category,
             regex,
+            ast_queries,
             node_types: def.node_types,
             languages: def.languages,
             confidence: def.confidence,
@@ -123,3 +200,113 @@ impl TomlPatternLoader {
         })
     }
 }
+
+/// Runs the tree-sitter queries of TOML patterns as part of the visitor pass.
+///
+/// Queries match whole subtrees, so each one runs once per file from the root
+/// node instead of once per visited node.
+pub struct TomlQueryHandler {
+    queries: Vec<CompiledQuery>,
+    languages: Vec<Language>,
+    visited_root: bool,
+    matches: Vec<PatternMatch>,
+}
+
+impl TomlQueryHandler {
+    /// Create a handler from loaded patterns. Regex-only patterns are ignored.
+    pub fn new(queries: Vec<CompiledQuery>) -> Self {
+        let queries: Vec<CompiledQuery> = queries
+            .into_iter()
+            .filter(|q| !q.ast_queries.is_empty())
+            .collect();
+        let mut languages: Vec<Language> = Vec::new();
+        for (language, _) in queries.iter().flat_map(|q| &q.ast_queries) {
+            if !languages.contains(language) {
+                languages.push(*language);
+            }
+        }
+        Self {
+            queries,
+            languages,
+            visited_root: false,
+            matches: Vec::new(),
+        }
+    }
+
+    /// Number of patterns with at least one compiled query.
+    pub fn query_count(&self) -> usize {
+        self.queries.len()
+    }
+
+    fn run_queries(&mut self, root: &Node, source: &[u8], ctx: &DetectionContext) {
+        for pattern in &self.queries {
+            let Some((_, query)) = pattern.ast_queries.iter().find(|(l, _)| *l == ctx.language) else {
+                continue;
+            };
+            let names = query.capture_names();
+            let report_index = query.capture_index_for_name("match");
+
+            let mut cursor = QueryCursor::new();
+            let mut matches = cursor.matches(query, *root, source);
+            while let Some(m) = matches.next() {
+                let capture = match report_index {
+                    Some(idx) => m.captures.iter().find(|c| c.index == idx),
+                    None => m.captures.iter().find(|c| !names[c.index as usize].starts_with('_')),
+                };
+                let Some(capture) = capture else { continue };
+                let node = capture.node;
+                if !pattern.node_types.is_empty() && !pattern.node_types.iter().any(|t| t == node.kind()) {
+                    continue;
+                }
+
+                let start = node.start_position();
+                self.matches.push(PatternMatch {
+                    file: ctx.file.to_string(),
+                    line: start.row as u32,
+                    column: start.column as u32,
+                    pattern_id: pattern.id.clone(),
+                    confidence: pattern.confidence,
+                    cwe_ids: pattern.cwe_ids.clone(),
+                    owasp: pattern.owasp.clone(),
+                    detection_method: DetectionMethod::TomlPattern,
+                    category: pattern.category,
+                    matched_text: node.utf8_text(source).unwrap_or("").to_string(),
+                });
+            }
+        }
+    }
+}
+
+impl DetectorHandler for TomlQueryHandler {
+    fn id(&self) -> &str {
+        "toml-queries"
+    }
+
+    fn node_types(&self) -> &[&str] {
+        &[]
+    }
+
+    fn languages(&self) -> &[Language] {
+        &self.languages
+    }
+
+    fn on_enter(&mut self, node: &Node, source: &[u8], ctx: &DetectionContext) {
+        // The traversal is depth-first, so the first node entered is the root.
+        if self.visited_root {
+            return;
+        }
+        self.visited_root = true;
+        self.run_queries(node, source, ctx);
+    }
+
+    fn on_exit(&mut self, _node: &Node, _source: &[u8], _ctx: &DetectionContext) {}
+
+    fn results(&self) -> Vec<PatternMatch> {
+        self.matches.clone()
+    }
+
+    fn reset(&mut self) {
+        self.visited_root = false;
+        self.matches.clear();
+    }
+}
@@ -5,6 +5,8 @@ use smallvec::SmallVec;
 
 use crate::scanner::language_detect::Language;
 
+use super::embedded_sql::SqlStatement;
+
 /// Result of analyzing a single file through all 4 phases.
 #[derive(Debug, Clone)]
 pub struct AnalysisResult {
@@ -12,6 +14,8 @@ pub struct AnalysisResult {
     pub language: Language,
     pub matches: Vec<PatternMatch>,
     pub strings_extracted: usize,
+    /// SQL statements embedded in the file's strings (phase 2).
+    pub sql_statements: Vec<SqlStatement>,
     pub regex_matches: usize,
     pub resolution_entries: usize,
     pub analysis_time_us: u64,
@@ -155,6 +159,7 @@ impl Default for AnalysisResult {
             language: Language::TypeScript,
             matches: Vec::new(),
             strings_extracted: 0,
+            sql_statements: Vec::new(),
             regex_matches: 0,
             resolution_entries: 0,
             analysis_time_us: 0,
@@ -9,9 +9,10 @@ use smallvec::SmallVec;
 use drift_core::errors::DetectionError;
 
 use crate::engine::types::PatternCategory;
+use crate::graph::taint::framework_specs::TaintFramework;
 use crate::scanner::language_detect::Language;
 
-use super::types::{DetectSignal, FrameworkSpec, MatchBlock, PatternDef};
+use super::types::{DetectSignal, FrameworkSpec, MatchBlock, PatternDef, TaintSpecRef};
 
 /// A compiled framework pack ready for matching.
 #[derive(Debug, Clone)]
@@ -28,6 +29,15 @@ pub struct CompiledFrameworkPack {
     pub patterns: Vec<CompiledPattern>,
     /// Pack version string.
     pub version: Option<String>,
+    /// Built-in taint specs this pack switches on.
+    pub taint_specs: Vec<CompiledTaintSpec>,
+}
+
+/// A built-in taint spec with the signals that select it within its pack.
+#[derive(Debug, Clone)]
+pub struct CompiledTaintSpec {
+    pub framework: TaintFramework,
+    pub detect_signals: Vec<CompiledDetectSignal>,
 }
 
 /// Compiled detection signal.
@@ -122,7 +132,7 @@ pub fn load_from_file(path: &std::path::Path) -> Result<CompiledFrameworkPack, D
     load_from_str(&content)
 }
 
-fn compile_spec(spec: FrameworkSpec) -> Result<CompiledFrameworkPack, DetectionError> {
+fn compile_spec(mut spec: FrameworkSpec) -> Result<CompiledFrameworkPack, DetectionError> {
     let languages: Vec<Language> = spec
         .framework
         .languages
@@ -140,6 +150,14 @@ fn compile_spec(spec: FrameworkSpec) -> Result<CompiledFrameworkPack, DetectionE
         })
         .collect();
 
+    let taint_specs = std::mem::take(&mut spec.framework.taint)
+        .into_iter()
+        .filter_map(|t| match compile_taint_spec(t) {
+            Ok(t) => Some(t),
+            Err(e) => { eprintln!("[drift] warning: skipping taint spec in pack '{}': {e}", spec.framework.name); None }
+        })
+        .collect();
+
     let mut patterns = Vec::with_capacity(spec.patterns.len());
     for def in spec.patterns {
         match compile_pattern(def) {
@@ -162,9 +180,20 @@ fn compile_spec(spec: FrameworkSpec) -> Result<CompiledFrameworkPack, DetectionE
         detect_signals,
         patterns,
         version: spec.framework.version,
+        taint_specs,
     })
 }
 
+fn compile_taint_spec(spec: TaintSpecRef) -> Result<CompiledTaintSpec, DetectionError> {
+    let framework = TaintFramework::all()
+        .iter()
+        .find(|f| f.name() == spec.framework)
+        .copied()
+        .ok_or_else(|| DetectionError::InvalidPattern(format!("unknown taint framework '{}'", spec.framework)))?;
+    let detect_signals = spec.detect_by.into_iter().map(compile_detect_signal).collect::<Result<_, _>>()?;
+    Ok(CompiledTaintSpec { framework, detect_signals })
+}
+
 fn compile_detect_signal(signal: DetectSignal) -> Result<CompiledDetectSignal, DetectionError> {
     match signal {
         DetectSignal::Import { import } => Ok(CompiledDetectSignal::Import(import)),
@@ -404,7 +433,8 @@ fn build_aho_corasick(patterns: &[String]) -> Option<AhoCorasick> {
         .ok()
 }
 
-fn parse_language(s: &str) -> Option<Language> {
+/// Language from a name or common alias in pack and pattern TOML.
+pub(crate) fn parse_language(s: &str) -> Option<Language> {
     match s.to_lowercase().as_str() {
         "typescript" | "ts" => Some(Language::TypeScript),
         "javascript" | "js" => Some(Language::JavaScript),
@@ -420,6 +450,6 @@ fn parse_language(s: &str) -> Option<Language> {
         "c" => Some(Language::C),
         "swift" => Some(Language::Swift),
         "scala" => Some(Language::Scala),
-        _ => { eprintln!("[drift] warning: unknown framework language '{s}'"); None }
+        _ => { eprintln!("[drift] warning: unknown language '{s}'"); None }
     }
 }
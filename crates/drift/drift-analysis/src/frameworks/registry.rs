@@ -7,6 +7,8 @@ use std::path::Path;
 
 use drift_core::errors::DetectionError;
 
+use crate::graph::taint::framework_specs::TaintFramework;
+
 use super::diagnostics::FrameworkDiagnostics;
 use super::loader::{self, CompiledDetectSignal, CompiledFrameworkPack};
 
@@ -107,6 +109,11 @@ impl FrameworkPackRegistry {
         loader::load_from_str(toml_str)
     }
 
+    /// All loaded packs.
+    pub fn packs(&self) -> &[CompiledFrameworkPack] {
+        &self.packs
+    }
+
     /// Consume the registry and return all packs.
     pub fn into_packs(self) -> Vec<CompiledFrameworkPack> {
         self.packs
@@ -140,6 +147,29 @@ impl FrameworkPackRegistry {
         detected
     }
 
+    /// Frameworks whose built-in taint specs apply: those named by the packs
+    /// [`Self::evaluate_signals`] detects, narrowed by each spec's own signals
+    /// (the pack's when the spec has none).
+    ///
+    /// Unlike pattern matching, taint specs only switch on for whole-module
+    /// imports: `koala` or `express-validator` don't make Koa or Express
+    /// request objects taint sources.
+    pub fn detect_taint_frameworks(&mut self, files: &[String], dependencies: &[String]) -> Vec<TaintFramework> {
+        let detected = self.evaluate_signals(files, dependencies);
+        let mut frameworks = Vec::new();
+        for pack in self.packs.iter().filter(|p| detected.contains(&p.name)) {
+            for spec in &pack.taint_specs {
+                let signals = if spec.detect_signals.is_empty() { &pack.detect_signals } else { &spec.detect_signals };
+                let selected = signals.is_empty()
+                    || signals.iter().any(|s| taint_signal_matches(s, files, dependencies));
+                if selected && !frameworks.contains(&spec.framework) {
+                    frameworks.push(spec.framework);
+                }
+            }
+        }
+        frameworks
+    }
+
     /// Check if a pack should be disabled based on config.
     fn is_pack_disabled(name: &str, config: &FrameworkConfig) -> bool {
         if let Some(ref enabled) = config.enabled_only {
@@ -158,7 +188,11 @@ pub fn evaluate_pack_signals(
     if pack.detect_signals.is_empty() {
         return true; // No signals = always active
     }
-    pack.detect_signals.iter().any(|signal| match signal {
+    pack.detect_signals.iter().any(|signal| signal_matches(signal, files, dependencies))
+}
+
+fn signal_matches(signal: &CompiledDetectSignal, files: &[String], dependencies: &[String]) -> bool {
+    match signal {
         CompiledDetectSignal::Import(src) => {
             // Import signals match if any tracked file would import this
             dependencies.iter().any(|d| d.contains(src.as_str()))
@@ -175,7 +209,23 @@ pub fn evaluate_pack_signals(
         CompiledDetectSignal::Dependency(dep) => {
             dependencies.iter().any(|d| d == dep)
         }
-    })
+    }
+}
+
+/// Like [`signal_matches`], but an import must be the module itself or one
+/// of its submodules.
+fn taint_signal_matches(signal: &CompiledDetectSignal, files: &[String], dependencies: &[String]) -> bool {
+    match signal {
+        CompiledDetectSignal::Import(src) => dependencies.iter().any(|d| is_module_or_submodule(d, src)),
+        _ => signal_matches(signal, files, dependencies),
+    }
+}
+
+/// `echo/v4` and `axum::extract` belong to `echo` and `axum`; `koala` does
+/// not belong to `koa`.
+fn is_module_or_submodule(dependency: &str, module: &str) -> bool {
+    dependency.strip_prefix(module)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with(['/', ':', '.', '\\']))
 }
 
 /// Built-in framework packs embedded at compile time.
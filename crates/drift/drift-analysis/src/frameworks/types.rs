@@ -28,6 +28,20 @@ pub struct FrameworkMeta {
     pub detect_by: Vec<DetectSignal>,
     /// Pack version string (e.g., "1.0.0").
     pub version: Option<String>,
+    /// Built-in taint specs switched on when this pack is detected.
+    #[serde(default)]
+    pub taint: Vec<TaintSpecRef>,
+}
+
+/// A built-in taint spec named by a pack.
+#[derive(Debug, Clone, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct TaintSpecRef {
+    /// Taint spec name (e.g., "express", "gin").
+    pub framework: String,
+    /// Signals that narrow the spec within its pack, e.g. one Go web
+    /// framework among several. Empty applies it whenever the pack does.
+    #[serde(default)]
+    pub detect_by: Vec<DetectSignal>,
 }
 
 /// Signal used to auto-detect a framework in a project.
@@ -4,6 +4,7 @@ use drift_core::types::collections::FxHashSet;
 use petgraph::graph::NodeIndex;
 
 use crate::call_graph::types::CallGraph;
+use crate::structural::hotspots::ChurnReport;
 
 use super::types::{BlastRadius, RiskScore};
 
@@ -15,6 +16,17 @@ pub fn compute_blast_radius(
     graph: &CallGraph,
     function_id: NodeIndex,
     max_callers_for_normalization: u32,
+) -> BlastRadius {
+    compute_blast_radius_with_change_frequency(graph, function_id, max_callers_for_normalization, 0.0)
+}
+
+/// Compute the blast radius for a function whose change frequency
+/// (0.0-1.0) is known from git history.
+pub fn compute_blast_radius_with_change_frequency(
+    graph: &CallGraph,
+    function_id: NodeIndex,
+    max_callers_for_normalization: u32,
+    change_frequency: f32,
 ) -> BlastRadius {
     let (callers, max_depth) = transitive_callers(graph, function_id);
     let caller_count = callers.len() as u32;
@@ -26,7 +38,7 @@ pub fn compute_blast_radius(
     let node = &graph.graph[function_id];
     let sensitivity = compute_sensitivity(node);
 
-    // CG-IMPACT-02: Estimate complexity from line span
+    // CG-IMPACT-02: Complexity from the parser's metrics, else line span
     let complexity = compute_complexity_estimate(node);
 
     // Test coverage: approximate — functions in test files are covered
@@ -37,7 +49,7 @@ pub fn compute_blast_radius(
         sensitivity,
         test_coverage,
         complexity,
-        0.0, // Change frequency: requires git history (out of scope)
+        change_frequency,
     );
 
     BlastRadius {
@@ -60,6 +72,23 @@ pub fn compute_all_blast_radii(graph: &CallGraph) -> Vec<BlastRadius> {
         .collect()
 }
 
+/// Compute blast radius for all functions, scoring change frequency from
+/// git churn.
+pub fn compute_all_blast_radii_with_churn(graph: &CallGraph, churn: &ChurnReport) -> Vec<BlastRadius> {
+    let max_callers = graph.function_count().max(1) as u32;
+    let frequencies = churn.change_frequencies();
+
+    graph
+        .graph
+        .node_indices()
+        .map(|idx| {
+            let node = &graph.graph[idx];
+            let change_frequency = frequencies.get(&node.file, &node.name);
+            compute_blast_radius_with_change_frequency(graph, idx, max_callers, change_frequency)
+        })
+        .collect()
+}
+
 /// CG-IMPACT-01: Compute sensitivity score from node properties.
 fn compute_sensitivity(node: &crate::call_graph::types::FunctionNode) -> f32 {
     let mut score = 0.0f32;
@@ -94,8 +123,26 @@ fn compute_sensitivity(node: &crate::call_graph::types::FunctionNode) -> f32 {
     score.min(1.0)
 }
 
-/// CG-IMPACT-02: Estimate complexity from function line span.
+/// CG-IMPACT-02: Normalize complexity to 0-1. Measured functions use the
+/// higher of their cyclomatic and cognitive scores; unmeasured ones fall
+/// back to line span.
 fn compute_complexity_estimate(node: &crate::call_graph::types::FunctionNode) -> f32 {
+    let metrics = node.complexity;
+    if metrics.is_measured() {
+        // Cyclomatic: 1-5 → low, 5-10 → medium, 25+ → high
+        let c = metrics.cyclomatic as f32;
+        let cyclomatic = if c <= 5.0 {
+            (c - 1.0) / 20.0
+        } else if c <= 10.0 {
+            0.2 + (c - 5.0) * 0.06
+        } else {
+            (0.5 + (c - 10.0) / 30.0).min(1.0)
+        };
+        // Cognitive: SonarSource flags 15; 25+ → high
+        let cognitive = (metrics.cognitive as f32 / 25.0).min(1.0);
+        return cyclomatic.max(cognitive);
+    }
+
     let line_span = node.end_line.saturating_sub(node.line) as f32;
     // Normalize: 0-10 lines → low, 10-50 → medium, 50+ → high
     if line_span <= 10.0 {
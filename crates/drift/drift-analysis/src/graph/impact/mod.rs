@@ -6,6 +6,9 @@ pub mod dead_code;
 pub mod path_finding;
 
 pub use types::*;
-pub use blast_radius::{compute_blast_radius, compute_all_blast_radii};
+pub use blast_radius::{
+    compute_all_blast_radii, compute_all_blast_radii_with_churn, compute_blast_radius,
+    compute_blast_radius_with_change_frequency,
+};
 pub use dead_code::{detect_dead_code, detect_dead_code_with_resolution_rate, detect_unreachable};
 pub use path_finding::{shortest_path, k_shortest_paths};
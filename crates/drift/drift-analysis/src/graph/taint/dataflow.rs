@@ -0,0 +1,1109 @@
+//! AST def-use dataflow for intraprocedural taint.
+//!
+//! Walks a function body in the tree-sitter syntax tree and tracks taint per
+//! access path (`q`, `req.query`, `this.name`). Each definition replaces the
+//! facts held for its target, branches are walked separately and joined, and
+//! a sanitizer only protects the value it returns — so `q = escape(q)` inside
+//! one branch leaves `q` tainted on the other path. Validation calls used as
+//! `if` conditions protect the guarded path only.
+//!
+//! Constructs are recognised by node kind across the supported grammars;
+//! anything unrecognised is walked child by child in source order.
+
+use drift_core::types::collections::FxHashMap;
+use tree_sitter::Node;
+
+use crate::engine::embedded_sql;
+use crate::parsers::types::{FunctionInfo, ParseResult};
+use crate::scanner::language_detect::Language;
+
+use super::registry::TaintRegistry;
+use super::types::*;
+
+/// Facts kept per variable before the oldest are dropped.
+const MAX_FACTS: usize = 8;
+/// Assignment hops kept per fact.
+const MAX_HOPS: usize = 6;
+/// Loop bodies are re-walked until the state is stable or this bound.
+const MAX_LOOP_PASSES: usize = 3;
+
+/// One way a value can be tainted: a source, the sanitizers applied on the
+/// way, and the assignments it passed through.
+#[derive(Debug, Clone, PartialEq)]
+struct Fact {
+    source: usize,
+    sanitizers: Vec<usize>,
+    hops: Vec<(u32, u32, String)>,
+}
+
+type Taint = Vec<Fact>;
+
+/// Per-path dataflow state. `unreachable` marks a path that has left the
+/// function (`return`, `throw`); it joins as the identity.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct State {
+    vars: FxHashMap<String, Taint>,
+    unreachable: bool,
+}
+
+impl State {
+    /// Join two paths. A variable is tainted if either path taints it; a
+    /// fact is sanitized only if it is sanitized on every path.
+    fn join(mut self, other: State) -> State {
+        if self.unreachable {
+            return other;
+        }
+        if other.unreachable {
+            return self;
+        }
+        let keys: Vec<String> = self.vars.keys().chain(other.vars.keys()).cloned().collect();
+        for key in keys {
+            let theirs = other.defined(&key).unwrap_or_default();
+            let ours = self.defined(&key).unwrap_or_default();
+            let mut merged = ours;
+            union_into(&mut merged, theirs);
+            self.vars.insert(key, merged);
+        }
+        self
+    }
+
+    /// Facts for `path` from its own definition or the nearest defined
+    /// prefix (`req` → `req.query`).
+    fn defined(&self, path: &str) -> Option<Taint> {
+        if let Some(facts) = self.vars.get(path) {
+            return Some(facts.clone());
+        }
+        let mut prefix = path;
+        while let Some(dot) = prefix.rfind('.') {
+            prefix = &prefix[..dot];
+            if let Some(facts) = self.vars.get(prefix) {
+                return Some(facts.clone());
+            }
+        }
+        None
+    }
+
+    /// Facts stored on fields of `path` (`obj.f` when reading `obj`).
+    fn fields(&self, path: &str) -> Taint {
+        let mut facts = Vec::new();
+        let prefix = format!("{path}.");
+        for (key, value) in &self.vars {
+            if key.starts_with(&prefix) {
+                union_into(&mut facts, value.clone());
+            }
+        }
+        facts
+    }
+
+    /// Strong update: `path` now holds `facts`, and its fields are gone.
+    fn define(&mut self, path: &str, facts: Taint) {
+        let prefix = format!("{path}.");
+        self.vars.retain(|k, _| !k.starts_with(&prefix));
+        self.vars.insert(path.to_string(), facts);
+    }
+
+    /// Forget `path`, e.g. when a closure parameter shadows it.
+    fn forget(&mut self, path: &str) {
+        let prefix = format!("{path}.");
+        self.vars.retain(|k, _| k != path && !k.starts_with(&prefix));
+    }
+}
+
+fn union_into(into: &mut Taint, facts: Taint) {
+    for fact in facts {
+        if !into.iter().any(|f| f.source == fact.source && f.sanitizers == fact.sanitizers) {
+            into.push(fact);
+        }
+    }
+    if into.len() > MAX_FACTS {
+        into.drain(..into.len() - MAX_FACTS);
+    }
+}
+
+/// Analyze one function body. `node` is the function's syntax node.
+pub(crate) fn analyze_function_node(
+    func: &FunctionInfo,
+    node: Node,
+    source: &[u8],
+    parse_result: &ParseResult,
+    registry: &TaintRegistry,
+) -> Vec<TaintFlow> {
+    let mut analyzer = Analyzer {
+        src: source,
+        file: &parse_result.file,
+        language: parse_result.language,
+        func,
+        registry,
+        sources: Vec::new(),
+        source_ids: FxHashMap::default(),
+        sanitizers: Vec::new(),
+        sanitizer_ids: FxHashMap::default(),
+        flows: Vec::new(),
+        flow_ids: FxHashMap::default(),
+    };
+
+    let mut state = State::default();
+    for param in &func.parameters {
+        let declared = registry.match_parameter_source(param, func, parse_result);
+        for name in param.bindings() {
+            if let Some(pattern) = declared.or_else(|| registry.match_source(name)) {
+                let id = analyzer.source(name, func.line, 0, pattern.source_type);
+                state.define(name, vec![Fact { source: id, sanitizers: Vec::new(), hops: Vec::new() }]);
+            }
+        }
+    }
+
+    analyzer.visit_function_body(node, &mut state);
+    analyzer.flows
+}
+
+/// Syntax nodes that define a function, method, lambda or closure.
+pub(crate) fn is_function_kind(kind: &str) -> bool {
+    matches!(
+        kind,
+        "function_declaration"
+            | "function_expression"
+            | "function"
+            | "generator_function"
+            | "generator_function_declaration"
+            | "arrow_function"
+            | "method_definition"
+            | "function_definition"
+            | "lambda"
+            | "method_declaration"
+            | "constructor_declaration"
+            | "local_function_statement"
+            | "lambda_expression"
+            | "func_literal"
+            | "function_item"
+            | "closure_expression"
+            | "method"
+            | "singleton_method"
+            | "anonymous_function"
+            | "anonymous_function_creation_expression"
+            | "lambda_literal"
+    )
+}
+
+struct Analyzer<'a> {
+    src: &'a [u8],
+    file: &'a str,
+    language: Language,
+    func: &'a FunctionInfo,
+    registry: &'a TaintRegistry,
+    sources: Vec<TaintSource>,
+    /// Source expression → index in `sources`, so every read of
+    /// `req.query` refers to the same source.
+    source_ids: FxHashMap<String, usize>,
+    sanitizers: Vec<TaintSanitizer>,
+    /// Sanitizer call (start byte) → index in `sanitizers`.
+    sanitizer_ids: FxHashMap<usize, usize>,
+    flows: Vec<TaintFlow>,
+    /// (sink start byte, source) → index in `flows`; loop bodies are walked
+    /// more than once.
+    flow_ids: FxHashMap<(usize, usize), usize>,
+}
+
+impl<'a> Analyzer<'a> {
+    fn text(&self, node: Node) -> &'a str {
+        node.utf8_text(self.src).unwrap_or("")
+    }
+
+    fn source(&mut self, expression: &str, line: u32, column: u32, source_type: SourceType) -> usize {
+        if let Some(&id) = self.source_ids.get(expression) {
+            return id;
+        }
+        let id = self.sources.len();
+        self.sources.push(TaintSource {
+            file: self.file.to_string(),
+            line,
+            column,
+            expression: expression.to_string(),
+            source_type,
+            label: TaintLabel::new(id as u64, source_type),
+        });
+        self.source_ids.insert(expression.to_string(), id);
+        id
+    }
+
+    fn sanitizer(&mut self, node: Node, expression: &str) -> Option<usize> {
+        if let Some(&id) = self.sanitizer_ids.get(&node.start_byte()) {
+            return Some(id);
+        }
+        let pattern = self.registry.match_sanitizer(expression)?;
+        let id = self.sanitizers.len();
+        self.sanitizers.push(TaintSanitizer {
+            file: self.file.to_string(),
+            line: node.start_position().row as u32,
+            expression: expression.to_string(),
+            sanitizer_type: pattern.sanitizer_type,
+            labels_sanitized: pattern.protects_against.clone(),
+        });
+        self.sanitizer_ids.insert(node.start_byte(), id);
+        Some(id)
+    }
+
+    /// Walk a function's body, skipping its name and signature.
+    fn visit_function_body(&mut self, node: Node, state: &mut State) {
+        if let Some(body) = node.child_by_field_name("body") {
+            self.visit(body, state);
+            return;
+        }
+        let mut cursor = node.walk();
+        let children: Vec<(Option<&str>, Node)> = node
+            .named_children(&mut cursor)
+            .enumerate()
+            .map(|(i, child)| (node.field_name_for_named_child(i as u32), child))
+            .collect();
+        for (field, child) in children {
+            let signature = matches!(
+                field,
+                Some("name" | "parameters" | "parameter" | "return_type" | "type_parameters" | "type" | "receiver" | "result")
+            );
+            if !signature && !child.kind().contains("parameter") {
+                self.visit(child, state);
+            }
+        }
+    }
+
+    /// Visit `node` in source order, updating `state`, and return the taint
+    /// of its value.
+    fn visit(&mut self, node: Node, state: &mut State) -> Taint {
+        if state.unreachable || node.is_extra() {
+            return Vec::new();
+        }
+        let kind = node.kind();
+        match kind {
+            _ if is_function_kind(kind) => {
+                self.visit_closure(node, state);
+                Vec::new()
+            }
+            _ if is_identifier_kind(kind) || is_member_kind(kind) || is_index_kind(kind) => {
+                self.visit_path(node, state)
+            }
+            _ if is_call_kind(kind) => self.visit_call(node, state),
+            _ if is_assignment_kind(kind) => self.visit_assignment(node, state),
+            _ if is_branch_kind(kind) => self.visit_branch(node, state),
+            _ if is_loop_kind(kind) => {
+                self.visit_loop(node, state);
+                Vec::new()
+            }
+            _ if is_switch_kind(kind) => self.visit_switch(node, state),
+            _ if is_try_kind(kind) => {
+                self.visit_try(node, state);
+                Vec::new()
+            }
+            _ if is_exit_kind(kind) => {
+                self.visit_children(node, state);
+                state.unreachable = true;
+                Vec::new()
+            }
+            "pair" | "keyword_argument" | "named_argument" | "keyed_element" => match node.child_by_field_name("value") {
+                Some(value) => self.visit(value, state),
+                None => self.visit_children(node, state),
+            },
+            "comment" | "line_comment" | "block_comment" | "string_fragment" | "escape_sequence" => Vec::new(),
+            _ => self.visit_children(node, state),
+        }
+    }
+
+    fn visit_children(&mut self, node: Node, state: &mut State) -> Taint {
+        let mut cursor = node.walk();
+        let children: Vec<Node> = node.named_children(&mut cursor).collect();
+        let mut taint = Vec::new();
+        for child in children {
+            let facts = self.visit(child, state);
+            union_into(&mut taint, facts);
+        }
+        taint
+    }
+
+    /// Read a variable, field or element.
+    fn visit_path(&mut self, node: Node, state: &mut State) -> Taint {
+        let Some(path) = self.path_of(node) else {
+            // Computed element or member of a call result: the value is as
+            // tainted as the object it is read from.
+            return self.visit_children(node, state);
+        };
+        self.read(&path, node, state)
+    }
+
+    fn read(&mut self, path: &str, node: Node, state: &State) -> Taint {
+        // A local definition, even a clean one, shadows any source of the
+        // same name; parameters are only defined when they are sources.
+        let mut facts = match state.defined(path) {
+            Some(facts) => facts,
+            None => self.source_fact(path, node).unwrap_or_default(),
+        };
+        union_into(&mut facts, state.fields(path));
+        facts
+    }
+
+    fn source_fact(&mut self, path: &str, node: Node) -> Option<Taint> {
+        let pattern = self.registry.match_source(path)?;
+        let position = node.start_position();
+        let id = self.source(path, position.row as u32, position.column as u32, pattern.source_type);
+        Some(vec![Fact { source: id, sanitizers: Vec::new(), hops: Vec::new() }])
+    }
+
+    /// Access path of a variable, field or constant-keyed element, e.g.
+    /// `req.query.id` for `req.query["id"]`.
+    fn path_of(&self, node: Node) -> Option<String> {
+        let kind = node.kind();
+        if is_identifier_kind(kind) {
+            let text = self.text(node).trim_start_matches('$');
+            return (!text.is_empty()).then(|| text.to_string());
+        }
+        if matches!(kind, "parenthesized_expression" | "parenthesized") {
+            return self.path_of(node.named_child(0)?);
+        }
+        if is_member_kind(kind) {
+            let object = first_field(node, &["object", "operand", "value", "expression", "argument", "path", "scope"])?;
+            let member = first_field(node, &["property", "attribute", "field", "name"])?;
+            return Some(format!("{}.{}", self.path_of(object)?, self.text(member).trim_start_matches('$')));
+        }
+        if is_index_kind(kind) {
+            let object = node.named_child(0)?;
+            let key = node.named_child(node.named_child_count().checked_sub(1)?)?;
+            if key.id() == object.id() || !is_constant_key(key.kind()) {
+                return None;
+            }
+            let key = self.text(key).trim_matches(|c| matches!(c, '"' | '\'' | ':' | '`'));
+            return Some(format!("{}.{}", self.path_of(object)?, key));
+        }
+        if kind == "call" && node.child_by_field_name("arguments").is_none() && node.child_by_field_name("block").is_none() {
+            let receiver = node.child_by_field_name("receiver")?;
+            let method = node.child_by_field_name("method")?;
+            return Some(format!("{}.{}", self.path_of(receiver)?, self.text(method)));
+        }
+        None
+    }
+
+    fn visit_call(&mut self, node: Node, state: &mut State) -> Taint {
+        if node.kind() == "call" && self.path_of(node).is_some() {
+            return self.visit_path(node, state);
+        }
+        let (name, receiver) = match self.call_target(node) {
+            Some(target) => target,
+            None => return self.visit_children(node, state),
+        };
+
+        // `user_input.trim()` on a tracked variable is a use, not a source.
+        if state.defined(&name).is_none() {
+            if let Some(facts) = self.source_fact(&name, node) {
+                if let Some(args) = call_arguments(node) {
+                    self.visit(args, state);
+                }
+                return facts;
+            }
+        }
+
+        let mut taint = match receiver {
+            Some(receiver) => self.visit(receiver, state),
+            None => Vec::new(),
+        };
+        let mut arg_taint = Vec::new();
+        let mut per_arg = Vec::new();
+        let args: Vec<Node> = match call_arguments(node) {
+            Some(list) => {
+                let mut cursor = list.walk();
+                list.named_children(&mut cursor).collect()
+            }
+            None => Vec::new(),
+        };
+        for &arg in &args {
+            let facts = self.visit(arg, state);
+            per_arg.push(facts.clone());
+            union_into(&mut arg_taint, facts);
+        }
+
+        if let Some(sanitizer) = self.sanitizer(node, &name) {
+            union_into(&mut taint, arg_taint);
+            for fact in &mut taint {
+                if !fact.sanitizers.contains(&sanitizer) {
+                    fact.sanitizers.push(sanitizer);
+                    fact.sanitizers.sort_unstable();
+                }
+            }
+            return taint;
+        }
+
+        if let Some(pattern) = self.registry.match_sink(&name) {
+            let sink = TaintSink {
+                file: self.file.to_string(),
+                line: node.start_position().row as u32,
+                column: node.start_position().column as u32,
+                expression: name.clone(),
+                sink_type: pattern.sink_type,
+                required_sanitizers: pattern.required_sanitizers.clone(),
+            };
+            // Bind values passed alongside a query are parameters, not
+            // query text: only values interpolated into the query can inject.
+            let reaching = match pattern.sink_type {
+                SinkType::SqlQuery if node.kind() != "macro_invocation" => self.query_taint(&name, &args, &per_arg),
+                _ => arg_taint.clone(),
+            };
+            self.report(node, sink, &reaching);
+        }
+        union_into(&mut taint, arg_taint);
+        taint
+    }
+
+    /// Taint that reaches a query call's SQL text. The argument that builds
+    /// a SQL statement is the query; its taint counts only if the statement
+    /// splices values in. When no argument builds SQL in place (the query
+    /// is a variable), the argument at the usual query position is used.
+    fn query_taint(&self, callee: &str, args: &[Node], per_arg: &[Taint]) -> Taint {
+        let built = args.iter().enumerate().find_map(|(i, &arg)| {
+            embedded_sql::statement_at(arg, self.src, self.language).map(|statement| (i, statement))
+        });
+        match built {
+            Some((i, statement)) if statement.is_dynamic() => per_arg[i].clone(),
+            Some(_) => Vec::new(),
+            None => per_arg.get(query_argument(callee)).cloned().unwrap_or_default(),
+        }
+    }
+
+    /// Resolve a call's callee name and receiver expression.
+    fn call_target<'t>(&self, node: Node<'t>) -> Option<(String, Option<Node<'t>>)> {
+        match node.kind() {
+            "new_expression" | "object_creation_expression" => {
+                // PHP leaves the class name of `new Response(...)` unlabeled.
+                let ty = first_field(node, &["constructor", "type"])
+                    .or_else(|| node.named_child(0).filter(|c| matches!(c.kind(), "name" | "qualified_name")))?;
+                return Some((format!("new {}", self.path_of(ty).unwrap_or_else(|| self.text(ty).to_string())), None));
+            }
+            "macro_invocation" => {
+                let name = first_field(node, &["macro"])?;
+                return Some((self.text(name).to_string(), None));
+            }
+            _ => {}
+        }
+        if let Some(function) = node.child_by_field_name("function") {
+            if is_member_kind(function.kind()) {
+                let object = first_field(function, &["object", "operand", "value", "expression", "argument", "path", "scope"]);
+                let member = first_field(function, &["property", "attribute", "field", "name"])?;
+                let name = self.path_of(function).unwrap_or_else(|| self.text(member).to_string());
+                return Some((name, object));
+            }
+            let name = self.path_of(function).unwrap_or_else(|| self.text(function).to_string());
+            return Some((name, None));
+        }
+        let method = first_field(node, &["name", "method"])?;
+        let receiver = first_field(node, &["object", "receiver", "scope"]);
+        let method = self.text(method).to_string();
+        let name = match receiver.and_then(|r| self.path_of(r)) {
+            Some(path) => format!("{path}.{method}"),
+            None => method,
+        };
+        Some((name, receiver))
+    }
+
+    /// Record a flow into `sink` for every source reaching it. A source is
+    /// sanitized only if every fact carrying it passed a sanitizer that
+    /// protects this sink type.
+    fn report(&mut self, node: Node, sink: TaintSink, taint: &Taint) {
+        let mut by_source: Vec<usize> = taint.iter().map(|f| f.source).collect();
+        by_source.sort_unstable();
+        by_source.dedup();
+
+        for source_id in by_source {
+            let facts: Vec<&Fact> = taint.iter().filter(|f| f.source == source_id).collect();
+            let is_sanitized = facts.iter().all(|f| {
+                f.sanitizers.iter().any(|&s| self.sanitizers[s].labels_sanitized.contains(&sink.sink_type))
+            });
+            let key = (node.start_byte(), source_id);
+            if let Some(&existing) = self.flow_ids.get(&key) {
+                if self.flows[existing].is_sanitized && !is_sanitized {
+                    self.flows[existing] = self.flow(source_id, &sink, &facts, false);
+                }
+                continue;
+            }
+            self.flow_ids.insert(key, self.flows.len());
+            let flow = self.flow(source_id, &sink, &facts, is_sanitized);
+            self.flows.push(flow);
+        }
+    }
+
+    fn flow(&self, source_id: usize, sink: &TaintSink, facts: &[&Fact], is_sanitized: bool) -> TaintFlow {
+        let source = self.sources[source_id].clone();
+        let function = self.func.name.clone();
+
+        let mut path = vec![TaintHop {
+            file: source.file.clone(),
+            line: source.line,
+            column: source.column,
+            function: function.clone(),
+            description: format!("Taint introduced from {}", source.source_type.name()),
+        }];
+        let trail = facts.iter().find(|f| is_sanitized || f.sanitizers.is_empty()).or(facts.first());
+        for (line, column, var) in trail.map(|f| f.hops.as_slice()).unwrap_or_default() {
+            path.push(TaintHop {
+                file: source.file.clone(),
+                line: *line,
+                column: *column,
+                function: function.clone(),
+                description: format!("Assigned to `{var}`"),
+            });
+        }
+        if path.last().map_or(true, |hop| hop.line != sink.line) {
+            path.push(TaintHop {
+                file: sink.file.clone(),
+                line: sink.line,
+                column: sink.column,
+                function,
+                description: format!("Taint flows to {} sink", sink.sink_type.name()),
+            });
+        }
+
+        let sanitizers_applied = if is_sanitized {
+            let mut ids: Vec<usize> = facts.iter().flat_map(|f| f.sanitizers.iter().copied()).collect();
+            ids.sort_unstable();
+            ids.dedup();
+            ids.into_iter().map(|s| self.sanitizers[s].clone()).collect()
+        } else {
+            Vec::new()
+        };
+
+        TaintFlow {
+            source,
+            sink: sink.clone(),
+            path,
+            is_sanitized,
+            sanitizers_applied,
+            cwe_id: sink.sink_type.cwe_id(),
+            confidence: if is_sanitized { 0.3 } else { 0.85 },
+        }
+    }
+
+    fn visit_assignment(&mut self, node: Node, state: &mut State) -> Taint {
+        let Some(target) = first_field(node, &["left", "name", "pattern", "declarator"]) else {
+            return self.visit_children(node, state);
+        };
+        let value = first_field(node, &["right", "value"]).or_else(|| trailing_value(node, target));
+        let Some(value) = value else {
+            // Declaration without initializer: a fresh, clean local.
+            if let Some(path) = self.path_of(target) {
+                state.define(&path, Vec::new());
+            }
+            return Vec::new();
+        };
+
+        let compound = is_compound_assignment(node.kind()) || self.operator_of(node).is_some_and(|op| op != "=" && op != ":=");
+        if is_list_kind(target.kind()) && is_list_kind(value.kind()) && target.named_child_count() == value.named_child_count() {
+            let mut cursor = target.walk();
+            let targets: Vec<Node> = target.named_children(&mut cursor).collect();
+            let mut cursor = value.walk();
+            let values: Vec<Node> = value.named_children(&mut cursor).collect();
+            let facts: Vec<Taint> = values.into_iter().map(|v| self.visit(v, state)).collect();
+            for (target, facts) in targets.into_iter().zip(facts) {
+                self.assign(target, None, facts, compound, state);
+            }
+            return Vec::new();
+        }
+
+        let facts = self.visit(value, state);
+        self.assign(target, Some(value), facts.clone(), compound, state);
+        facts
+    }
+
+    fn operator_of(&self, node: Node) -> Option<&'a str> {
+        node.child_by_field_name("operator").map(|op| self.text(op))
+    }
+
+    /// Bind `facts` to an assignment target. Plain variables and fields are
+    /// strong updates; elements and compound assignments add to the
+    /// existing taint; patterns bind every name they declare.
+    fn assign(&mut self, target: Node, value: Option<Node>, facts: Taint, weak: bool, state: &mut State) {
+        let kind = target.kind();
+        if is_index_kind(kind) && self.path_of(target).is_none() {
+            let Some(object) = target.named_child(0) else { return };
+            self.visit_index_operands(target, state);
+            self.assign(object, None, facts, true, state);
+            return;
+        }
+        match self.path_of(target) {
+            Some(path) => self.assign_path(&path, target, facts, weak, state),
+            None => self.bind_pattern(target, value, &facts, state),
+        }
+    }
+
+    fn assign_path(&mut self, path: &str, target: Node, mut facts: Taint, weak: bool, state: &mut State) {
+        let kind = target.kind();
+        if let Some(pattern) = self.registry.match_sink(path) {
+            if is_member_kind(kind) {
+                let sink = TaintSink {
+                    file: self.file.to_string(),
+                    line: target.start_position().row as u32,
+                    column: target.start_position().column as u32,
+                    expression: path.to_string(),
+                    sink_type: pattern.sink_type,
+                    required_sanitizers: pattern.required_sanitizers.clone(),
+                };
+                self.report(target, sink, &facts);
+            }
+        }
+
+        let position = target.start_position();
+        for fact in &mut facts {
+            if fact.hops.len() < MAX_HOPS {
+                fact.hops.push((position.row as u32, position.column as u32, path.to_string()));
+            }
+        }
+        if weak {
+            let mut existing = self.read(path, target, state);
+            union_into(&mut existing, facts);
+            facts = existing;
+        }
+        state.define(path, facts);
+    }
+
+    fn visit_index_operands(&mut self, target: Node, state: &mut State) {
+        let mut cursor = target.walk();
+        let operands: Vec<Node> = target.named_children(&mut cursor).skip(1).collect();
+        for operand in operands {
+            self.visit(operand, state);
+        }
+    }
+
+    /// Destructuring: `{ id } = req.query` reads `req.query.id`; any other
+    /// name in the pattern gets the whole value's taint.
+    fn bind_pattern(&mut self, pattern: Node, value: Option<Node>, facts: &Taint, state: &mut State) {
+        let value_path = value.and_then(|v| self.path_of(v));
+        let mut stack = vec![pattern];
+        while let Some(node) = stack.pop() {
+            let kind = node.kind();
+            if kind == "shorthand_property_identifier_pattern" || is_identifier_kind(kind) {
+                let name = self.text(node).trim_start_matches('$').to_string();
+                let bound = match (&value_path, kind) {
+                    (Some(base), "shorthand_property_identifier_pattern") => self.read(&format!("{base}.{name}"), node, state),
+                    _ => facts.clone(),
+                };
+                if !name.is_empty() {
+                    self.assign_path(&name, node, bound, false, state);
+                }
+                continue;
+            }
+            if is_type_kind(kind) {
+                continue;
+            }
+            let mut cursor = node.walk();
+            let children: Vec<Node> = node.named_children(&mut cursor).collect();
+            for (i, child) in children.into_iter().enumerate() {
+                // Keys and defaults in patterns are not bindings.
+                if matches!(node.field_name_for_named_child(i as u32), Some("key" | "type" | "right")) {
+                    continue;
+                }
+                stack.push(child);
+            }
+        }
+    }
+
+    /// `if`/`else`, ternaries and `unless`: each arm starts from the state
+    /// after the condition, and the arms are joined. Without an `else`, the
+    /// fall-through path joins as well.
+    fn visit_branch(&mut self, node: Node, state: &mut State) -> Taint {
+        let condition = node.child_by_field_name("condition");
+        let (guarded, negated) = match condition {
+            Some(c) => self.guards(c),
+            None => (Vec::new(), false),
+        };
+        let negated = negated ^ matches!(node.kind(), "unless" | "unless_modifier");
+        if let Some(condition) = condition {
+            self.visit(condition, state);
+        }
+
+        let mut when_true = state.clone();
+        let mut when_false = state.clone();
+        let guarded_state = if negated { &mut when_false } else { &mut when_true };
+        for (path, sanitizer) in &guarded {
+            let mut facts = self.read(path, node, guarded_state);
+            for fact in &mut facts {
+                if !fact.sanitizers.contains(sanitizer) {
+                    fact.sanitizers.push(*sanitizer);
+                    fact.sanitizers.sort_unstable();
+                }
+            }
+            guarded_state.define(path, facts);
+        }
+
+        let mut taint = Vec::new();
+        let consequence = first_field(node, &["consequence", "body"]);
+        if let Some(consequence) = consequence {
+            let facts = self.visit(consequence, &mut when_true);
+            union_into(&mut taint, facts);
+        }
+
+        let mut cursor = node.walk();
+        let alternatives: Vec<Node> = node.children_by_field_name("alternative", &mut cursor).collect();
+        let exhaustive = alternatives.iter().any(|a| !matches!(a.kind(), "elif_clause" | "else_if_clause"));
+        let mut joined = when_true;
+        for alternative in alternatives {
+            let mut arm = when_false.clone();
+            let facts = self.visit(alternative, &mut arm);
+            union_into(&mut taint, facts);
+            joined = joined.join(arm);
+        }
+        if !exhaustive {
+            joined = joined.join(when_false);
+        }
+        *state = joined;
+        taint
+    }
+
+    /// Sanitizer calls in a condition (`if (isValid(q))`, `if (!isValid(q))`):
+    /// the checked paths and whether the check is negated.
+    fn guards(&mut self, condition: Node) -> (Vec<(String, usize)>, bool) {
+        let mut node = condition;
+        let mut negated = false;
+        loop {
+            let kind = node.kind();
+            let text = self.text(node);
+            if matches!(kind, "parenthesized_expression" | "parenthesized" | "condition_clause") {
+                match node.named_child(0) {
+                    Some(inner) => node = inner,
+                    None => break,
+                }
+            } else if (kind.contains("unary") || kind == "not_operator")
+                && (text.starts_with('!') || text.starts_with("not"))
+            {
+                match node.named_child(node.named_child_count().saturating_sub(1)) {
+                    Some(inner) => {
+                        negated = !negated;
+                        node = inner;
+                    }
+                    None => break,
+                }
+            } else {
+                break;
+            }
+        }
+        if !is_call_kind(node.kind()) {
+            return (Vec::new(), negated);
+        }
+        let Some((name, _)) = self.call_target(node) else {
+            return (Vec::new(), negated);
+        };
+        let Some(sanitizer) = self.sanitizer(node, &name) else {
+            return (Vec::new(), negated);
+        };
+        let mut guarded = Vec::new();
+        if let Some(args) = call_arguments(node) {
+            let mut cursor = args.walk();
+            for arg in args.named_children(&mut cursor) {
+                let arg = if arg.kind() == "argument" { arg.named_child(0).unwrap_or(arg) } else { arg };
+                if let Some(path) = self.path_of(arg) {
+                    guarded.push((path, sanitizer));
+                }
+            }
+        }
+        (guarded, negated)
+    }
+
+    /// Loops: bind the loop variable, then walk the body until the state
+    /// stops changing. The body may not run, so the entry state joins in.
+    fn visit_loop(&mut self, node: Node, state: &mut State) {
+        let body = node.child_by_field_name("body");
+        let iterated = first_field(node, &["right", "value"]);
+        let variable = first_field(node, &["left", "name", "pattern"]);
+        match (variable, iterated) {
+            (Some(variable), Some(iterated)) => {
+                let facts = self.visit(iterated, state);
+                self.assign(variable, None, facts, false, state);
+            }
+            _ => {
+                let mut cursor = node.walk();
+                let header: Vec<Node> = node
+                    .named_children(&mut cursor)
+                    .filter(|c| Some(c.id()) != body.map(|b| b.id()))
+                    .collect();
+                for child in header {
+                    self.visit(child, state);
+                }
+            }
+        }
+        let Some(body) = body else { return };
+        for _ in 0..MAX_LOOP_PASSES {
+            let before = state.clone();
+            let mut after = state.clone();
+            self.visit(body, &mut after);
+            let joined = before.clone().join(after);
+            if joined == before {
+                break;
+            }
+            *state = joined;
+        }
+    }
+
+    /// `switch`/`match`/`case`: each case starts from the state after the
+    /// subject; without a default case the entry state joins as well.
+    fn visit_switch(&mut self, node: Node, state: &mut State) -> Taint {
+        let subject = first_field(node, &["value", "condition", "subject"]);
+        if let Some(subject) = subject {
+            self.visit(subject, state);
+        }
+        let container = node.child_by_field_name("body").unwrap_or(node);
+        let mut cursor = container.walk();
+        let cases: Vec<Node> = container
+            .named_children(&mut cursor)
+            .filter(|c| Some(c.id()) != subject.map(|s| s.id()) && !c.is_extra())
+            .collect();
+
+        let entry = state.clone();
+        let mut joined = State { unreachable: true, ..State::default() };
+        let mut has_default = false;
+        let mut taint = Vec::new();
+        for case in cases {
+            let text = self.text(case).trim_start();
+            has_default |= case.kind().contains("default")
+                || case.kind() == "else"
+                || text.starts_with("default")
+                || text.starts_with("_ =>")
+                || text.starts_with("case _:");
+            let mut arm = entry.clone();
+            let facts = self.visit(case, &mut arm);
+            union_into(&mut taint, facts);
+            joined = joined.join(arm);
+        }
+        if !has_default {
+            joined = joined.join(entry);
+        }
+        *state = joined;
+        taint
+    }
+
+    /// `try`/`catch`/`finally`: handlers may start anywhere in the body, so
+    /// they see the join of the entry and exit states.
+    fn visit_try(&mut self, node: Node, state: &mut State) {
+        let entry = state.clone();
+        let mut cursor = node.walk();
+        let children: Vec<Node> = node.named_children(&mut cursor).collect();
+        let (handlers, rest): (Vec<Node>, Vec<Node>) = children.into_iter().partition(|c| {
+            let kind = c.kind();
+            kind.contains("catch") || kind.contains("except") || kind.contains("rescue")
+        });
+        let (finally, body): (Vec<Node>, Vec<Node>) = rest.into_iter().partition(|c| {
+            let kind = c.kind();
+            kind.contains("finally") || kind.contains("ensure")
+        });
+
+        for child in body {
+            self.visit(child, state);
+        }
+        let handler_entry = entry.join(state.clone());
+        let mut joined = state.clone();
+        for handler in handlers {
+            let mut arm = handler_entry.clone();
+            self.visit(handler, &mut arm);
+            joined = joined.join(arm);
+        }
+        *state = joined;
+        for child in finally {
+            self.visit(child, state);
+        }
+    }
+
+    /// Closures and nested functions may run any number of times, so their
+    /// body is walked as an optional branch. Parameters shadow outer names.
+    fn visit_closure(&mut self, node: Node, state: &mut State) {
+        let mut inner = state.clone();
+        if let Some(params) = first_field(node, &["parameters", "parameter"]) {
+            let mut stack = vec![params];
+            while let Some(param) = stack.pop() {
+                if is_identifier_kind(param.kind()) {
+                    inner.forget(self.text(param).trim_start_matches('$'));
+                    continue;
+                }
+                let mut cursor = param.walk();
+                stack.extend(param.named_children(&mut cursor).filter(|c| !is_type_kind(c.kind())));
+            }
+        }
+        self.visit_function_body(node, &mut inner);
+        inner.unreachable = false;
+        *state = state.clone().join(inner);
+    }
+}
+
+fn first_field<'t>(node: Node<'t>, fields: &[&str]) -> Option<Node<'t>> {
+    fields.iter().find_map(|f| node.child_by_field_name(f))
+}
+
+fn call_arguments(node: Node) -> Option<Node> {
+    if let Some(args) = node.child_by_field_name("arguments") {
+        return Some(args);
+    }
+    (0..node.named_child_count())
+        .filter_map(|i| node.named_child(i))
+        .find(|c| matches!(c.kind(), "token_tree" | "argument_list" | "arguments"))
+}
+
+/// Position of the SQL text among a query call's arguments when none of them
+/// is a SQL literal: Go's
+/// `QueryContext(ctx, query, args...)` family takes a context first.
+fn query_argument(callee: &str) -> usize {
+    usize::from(callee.ends_with("Context"))
+}
+
+/// Initializer without a field name, e.g. C# `variable_declarator`.
+fn trailing_value<'t>(node: Node<'t>, target: Node<'t>) -> Option<Node<'t>> {
+    let last = node.named_child(node.named_child_count().checked_sub(1)?)?;
+    (last.id() != target.id() && last.kind() != "bracketed_argument_list").then_some(last)
+}
+
+fn is_identifier_kind(kind: &str) -> bool {
+    matches!(
+        kind,
+        "identifier"
+            | "simple_identifier"
+            | "variable_name"
+            | "instance_variable"
+            | "shorthand_property_identifier"
+            | "this"
+            | "self"
+    )
+}
+
+fn is_member_kind(kind: &str) -> bool {
+    matches!(
+        kind,
+        "member_expression"
+            | "attribute"
+            | "field_access"
+            | "field_expression"
+            | "selector_expression"
+            | "member_access_expression"
+            | "nullsafe_member_access_expression"
+            | "scoped_identifier"
+            | "navigation_expression"
+    )
+}
+
+fn is_index_kind(kind: &str) -> bool {
+    matches!(
+        kind,
+        "subscript_expression"
+            | "subscript"
+            | "element_reference"
+            | "index_expression"
+            | "element_access_expression"
+            | "array_access"
+    )
+}
+
+fn is_constant_key(kind: &str) -> bool {
+    matches!(
+        kind,
+        "string" | "string_literal" | "interpreted_string_literal" | "raw_string_literal" | "simple_symbol" | "encapsed_string"
+    )
+}
+
+fn is_call_kind(kind: &str) -> bool {
+    matches!(
+        kind,
+        "call_expression"
+            | "call"
+            | "method_invocation"
+            | "invocation_expression"
+            | "function_call_expression"
+            | "member_call_expression"
+            | "nullsafe_member_call_expression"
+            | "scoped_call_expression"
+            | "macro_invocation"
+            | "new_expression"
+            | "object_creation_expression"
+    )
+}
+
+fn is_assignment_kind(kind: &str) -> bool {
+    matches!(
+        kind,
+        "variable_declarator"
+            | "assignment_expression"
+            | "assignment"
+            | "assignment_statement"
+            | "short_var_declaration"
+            | "var_spec"
+            | "const_spec"
+            | "let_declaration"
+            | "init_declarator"
+            | "named_expression"
+            | "range_clause"
+    ) || is_compound_assignment(kind)
+}
+
+fn is_compound_assignment(kind: &str) -> bool {
+    matches!(
+        kind,
+        "augmented_assignment_expression" | "augmented_assignment" | "compound_assignment_expr" | "operator_assignment"
+    )
+}
+
+fn is_list_kind(kind: &str) -> bool {
+    matches!(kind, "expression_list" | "pattern_list" | "tuple_pattern" | "tuple" | "left_assignment_list" | "right_assignment_list")
+}
+
+fn is_branch_kind(kind: &str) -> bool {
+    matches!(
+        kind,
+        "if_statement"
+            | "if_expression"
+            | "if"
+            | "elsif"
+            | "unless"
+            | "if_modifier"
+            | "unless_modifier"
+            | "ternary_expression"
+            | "conditional_expression"
+            | "conditional"
+    )
+}
+
+fn is_loop_kind(kind: &str) -> bool {
+    matches!(
+        kind,
+        "for_statement"
+            | "for_in_statement"
+            | "for_of_statement"
+            | "enhanced_for_statement"
+            | "foreach_statement"
+            | "while_statement"
+            | "do_statement"
+            | "for_expression"
+            | "while_expression"
+            | "loop_expression"
+            | "for"
+            | "while"
+            | "until"
+            | "while_modifier"
+            | "until_modifier"
+    )
+}
+
+fn is_switch_kind(kind: &str) -> bool {
+    matches!(
+        kind,
+        "switch_statement"
+            | "switch_expression"
+            | "expression_switch_statement"
+            | "type_switch_statement"
+            | "select_statement"
+            | "match_expression"
+            | "match_statement"
+            | "case"
+    )
+}
+
+fn is_try_kind(kind: &str) -> bool {
+    matches!(kind, "try_statement" | "try_expression" | "begin")
+}
+
+fn is_exit_kind(kind: &str) -> bool {
+    matches!(
+        kind,
+        "return_statement" | "return_expression" | "return" | "throw_statement" | "throw_expression" | "raise_statement"
+    )
+}
+
+fn is_type_kind(kind: &str) -> bool {
+    kind.contains("type") && kind != "type_switch_statement"
+}
@@ -20,6 +20,13 @@ pub enum TaintFramework {
     NestJs,
     Gin,
     Actix,
+    FastApi,
+    NextJs,
+    Axum,
+    Echo,
+    Fiber,
+    Symfony,
+    Hono,
 }
 
 impl TaintFramework {
@@ -37,6 +44,13 @@ impl TaintFramework {
             Self::NestJs => "nestjs",
             Self::Gin => "gin",
             Self::Actix => "actix",
+            Self::FastApi => "fastapi",
+            Self::NextJs => "nextjs",
+            Self::Axum => "axum",
+            Self::Echo => "echo",
+            Self::Fiber => "fiber",
+            Self::Symfony => "symfony",
+            Self::Hono => "hono",
         }
     }
 
@@ -46,6 +60,8 @@ impl TaintFramework {
             Self::Express, Self::Django, Self::Flask, Self::Spring,
             Self::AspNet, Self::Rails, Self::Laravel, Self::Fastify,
             Self::Koa, Self::NestJs, Self::Gin, Self::Actix,
+            Self::FastApi, Self::NextJs, Self::Axum, Self::Echo,
+            Self::Fiber, Self::Symfony, Self::Hono,
         ]
     }
 }
@@ -65,6 +81,13 @@ pub fn apply_framework_specs(registry: &mut TaintRegistry, framework: TaintFrame
         TaintFramework::NestJs => apply_nestjs(registry),
         TaintFramework::Gin => apply_gin(registry),
         TaintFramework::Actix => apply_actix(registry),
+        TaintFramework::FastApi => apply_fastapi(registry),
+        TaintFramework::NextJs => apply_nextjs(registry),
+        TaintFramework::Axum => apply_axum(registry),
+        TaintFramework::Echo => apply_echo(registry),
+        TaintFramework::Fiber => apply_fiber(registry),
+        TaintFramework::Symfony => apply_symfony(registry),
+        TaintFramework::Hono => apply_hono(registry),
     }
 }
 
@@ -259,3 +282,230 @@ fn apply_actix(registry: &mut TaintRegistry) {
         });
     }
 }
+
+/// Parameter markers (`q: str = Query(None)`, `Annotated[str, Header()]`),
+/// the raw `Request`, and pydantic models taken by route handlers.
+/// `Depends(get_user)` parameters are sources when `get_user` takes one.
+fn apply_fastapi(registry: &mut TaintRegistry) {
+    let sources = [
+        "fastapi.Query", "fastapi.Path", "fastapi.Body", "fastapi.Form",
+        "fastapi.Header", "fastapi.Cookie", "fastapi.File", "fastapi.UploadFile",
+        "fastapi.Request", "starlette.requests.Request", "pydantic.BaseModel",
+    ];
+    for s in &sources {
+        registry.add_source(SourcePattern {
+            pattern: s.to_string(),
+            source_type: SourceType::UserInput,
+            framework: Some("fastapi".to_string()),
+        });
+    }
+    registry.add_sink(SinkPattern {
+        pattern: "HTMLResponse".to_string(),
+        sink_type: SinkType::HtmlOutput,
+        required_sanitizers: vec![SanitizerType::HtmlEscape],
+        framework: Some("fastapi".to_string()),
+    });
+    registry.add_sink(SinkPattern {
+        pattern: "RedirectResponse".to_string(),
+        sink_type: SinkType::HttpRedirect,
+        required_sanitizers: vec![SanitizerType::UrlEncode],
+        framework: Some("fastapi".to_string()),
+    });
+}
+
+/// Route handlers receive a `NextRequest`; pages and server actions read
+/// `searchParams`, `params` and submitted `FormData`.
+fn apply_nextjs(registry: &mut TaintRegistry) {
+    let sources = ["next/server.NextRequest", "searchParams", "formData.get", "formData.getAll"];
+    for s in &sources {
+        registry.add_source(SourcePattern {
+            pattern: s.to_string(),
+            source_type: SourceType::UserInput,
+            framework: Some("nextjs".to_string()),
+        });
+    }
+    registry.add_sink(SinkPattern {
+        pattern: "NextResponse.redirect".to_string(),
+        sink_type: SinkType::HttpRedirect,
+        required_sanitizers: vec![SanitizerType::UrlEncode],
+        framework: Some("nextjs".to_string()),
+    });
+    registry.add_sink(SinkPattern {
+        pattern: "NextResponse.rewrite".to_string(),
+        sink_type: SinkType::HttpRedirect,
+        required_sanitizers: vec![SanitizerType::UrlEncode],
+        framework: Some("nextjs".to_string()),
+    });
+    registry.add_sink(SinkPattern {
+        pattern: "permanentRedirect".to_string(),
+        sink_type: SinkType::HttpRedirect,
+        required_sanitizers: vec![SanitizerType::UrlEncode],
+        framework: Some("nextjs".to_string()),
+    });
+}
+
+/// Extractors are matched by type, qualified through `use` declarations.
+fn apply_axum(registry: &mut TaintRegistry) {
+    let sources = [
+        "axum::extract::Query", "axum::extract::Path", "axum::extract::Json",
+        "axum::extract::Form", "axum::extract::RawQuery", "axum::extract::Multipart",
+        "axum::Json", "axum::Form", "axum::http::HeaderMap", "axum_extra::TypedHeader",
+    ];
+    for s in &sources {
+        registry.add_source(SourcePattern {
+            pattern: s.to_string(),
+            source_type: SourceType::UserInput,
+            framework: Some("axum".to_string()),
+        });
+    }
+    registry.add_sink(SinkPattern {
+        pattern: "Html".to_string(),
+        sink_type: SinkType::HtmlOutput,
+        required_sanitizers: vec![SanitizerType::HtmlEscape],
+        framework: Some("axum".to_string()),
+    });
+    for redirect in ["Redirect::to", "Redirect::temporary", "Redirect::permanent"] {
+        registry.add_sink(SinkPattern {
+            pattern: redirect.to_string(),
+            sink_type: SinkType::HttpRedirect,
+            required_sanitizers: vec![SanitizerType::UrlEncode],
+            framework: Some("axum".to_string()),
+        });
+    }
+    registry.add_sanitizer(SanitizerPattern {
+        pattern: "html_escape::encode_text".to_string(),
+        sanitizer_type: SanitizerType::HtmlEscape,
+        protects_against: vec![SinkType::HtmlOutput],
+        framework: Some("axum".to_string()),
+    });
+}
+
+fn apply_echo(registry: &mut TaintRegistry) {
+    let sources = [
+        "c.QueryParam", "c.QueryParams", "c.QueryString", "c.Param", "c.ParamValues",
+        "c.FormValue", "c.FormParams", "c.FormFile", "c.Cookie", "c.Request",
+    ];
+    for s in &sources {
+        registry.add_source(SourcePattern {
+            pattern: s.to_string(),
+            source_type: SourceType::UserInput,
+            framework: Some("echo".to_string()),
+        });
+    }
+    registry.add_sink(SinkPattern {
+        pattern: "c.HTML".to_string(),
+        sink_type: SinkType::HtmlOutput,
+        required_sanitizers: vec![SanitizerType::HtmlEscape],
+        framework: Some("echo".to_string()),
+    });
+    registry.add_sink(SinkPattern {
+        pattern: "c.Redirect".to_string(),
+        sink_type: SinkType::HttpRedirect,
+        required_sanitizers: vec![SanitizerType::UrlEncode],
+        framework: Some("echo".to_string()),
+    });
+    registry.add_sink(SinkPattern {
+        pattern: "c.File".to_string(),
+        sink_type: SinkType::FileRead,
+        required_sanitizers: vec![SanitizerType::PathValidate],
+        framework: Some("echo".to_string()),
+    });
+}
+
+fn apply_fiber(registry: &mut TaintRegistry) {
+    let sources = [
+        "c.Query", "c.Queries", "c.Params", "c.AllParams", "c.FormValue",
+        "c.FormFile", "c.Body", "c.Get", "c.Cookies",
+    ];
+    for s in &sources {
+        registry.add_source(SourcePattern {
+            pattern: s.to_string(),
+            source_type: SourceType::UserInput,
+            framework: Some("fiber".to_string()),
+        });
+    }
+    registry.add_sink(SinkPattern {
+        pattern: "c.Redirect".to_string(),
+        sink_type: SinkType::HttpRedirect,
+        required_sanitizers: vec![SanitizerType::UrlEncode],
+        framework: Some("fiber".to_string()),
+    });
+    registry.add_sink(SinkPattern {
+        pattern: "c.SendFile".to_string(),
+        sink_type: SinkType::FileRead,
+        required_sanitizers: vec![SanitizerType::PathValidate],
+        framework: Some("fiber".to_string()),
+    });
+    registry.add_sink(SinkPattern {
+        pattern: "c.Download".to_string(),
+        sink_type: SinkType::FileRead,
+        required_sanitizers: vec![SanitizerType::PathValidate],
+        framework: Some("fiber".to_string()),
+    });
+}
+
+/// `$request->query->get('q')` and friends on `Symfony\Component\HttpFoundation\Request`.
+fn apply_symfony(registry: &mut TaintRegistry) {
+    let sources = [
+        "request.query", "request.request", "request.attributes", "request.cookies",
+        "request.headers", "request.files", "request.getContent", "request.toArray",
+        "request.getPayload",
+    ];
+    for s in &sources {
+        registry.add_source(SourcePattern {
+            pattern: s.to_string(),
+            source_type: SourceType::UserInput,
+            framework: Some("symfony".to_string()),
+        });
+    }
+    registry.add_sink(SinkPattern {
+        pattern: "new Response".to_string(),
+        sink_type: SinkType::HtmlOutput,
+        required_sanitizers: vec![SanitizerType::HtmlEscape],
+        framework: Some("symfony".to_string()),
+    });
+    registry.add_sink(SinkPattern {
+        pattern: "new RedirectResponse".to_string(),
+        sink_type: SinkType::HttpRedirect,
+        required_sanitizers: vec![SanitizerType::UrlEncode],
+        framework: Some("symfony".to_string()),
+    });
+    registry.add_sink(SinkPattern {
+        pattern: "this.redirect".to_string(),
+        sink_type: SinkType::HttpRedirect,
+        required_sanitizers: vec![SanitizerType::UrlEncode],
+        framework: Some("symfony".to_string()),
+    });
+    registry.add_sanitizer(SanitizerPattern {
+        pattern: "htmlspecialchars".to_string(),
+        sanitizer_type: SanitizerType::HtmlEscape,
+        protects_against: vec![SinkType::HtmlOutput],
+        framework: Some("symfony".to_string()),
+    });
+}
+
+fn apply_hono(registry: &mut TaintRegistry) {
+    let sources = [
+        "c.req.query", "c.req.queries", "c.req.param", "c.req.header", "c.req.json",
+        "c.req.text", "c.req.formData", "c.req.parseBody", "c.req.raw",
+    ];
+    for s in &sources {
+        registry.add_source(SourcePattern {
+            pattern: s.to_string(),
+            source_type: SourceType::UserInput,
+            framework: Some("hono".to_string()),
+        });
+    }
+    registry.add_sink(SinkPattern {
+        pattern: "c.html".to_string(),
+        sink_type: SinkType::HtmlOutput,
+        required_sanitizers: vec![SanitizerType::HtmlEscape],
+        framework: Some("hono".to_string()),
+    });
+    registry.add_sink(SinkPattern {
+        pattern: "c.redirect".to_string(),
+        sink_type: SinkType::HttpRedirect,
+        required_sanitizers: vec![SanitizerType::UrlEncode],
+        framework: Some("hono".to_string()),
+    });
+}
@@ -3,22 +3,27 @@
 //! Phase 2 of taint analysis. Propagates taint across function boundaries
 //! using the call graph and function summaries.
 //! Performance target: <100ms per function.
+//!
+//! Summaries and the flows traced from each function can be cached across
+//! runs; see [`super::summaries`].
 
 use drift_core::errors::TaintError;
 use drift_core::types::collections::{FxHashMap, FxHashSet};
 use petgraph::graph::NodeIndex;
+use serde::{Deserialize, Serialize};
 
 use crate::call_graph::types::CallGraph;
-use crate::parsers::types::ParseResult;
+use crate::parsers::types::{CallSite, ParseResult};
 
 use super::registry::TaintRegistry;
+use super::summaries::{content_hash, function_id, CachedSummary, SummaryCache, SummaryStats};
 use super::types::*;
 
 /// Maximum depth for interprocedural taint propagation.
 const MAX_TAINT_DEPTH: usize = 50;
 
 /// Summary of a function's taint behavior.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct FunctionSummary {
     /// Parameters that propagate taint to the return value.
     pub tainted_params: FxHashSet<usize>,
@@ -28,6 +33,16 @@ pub struct FunctionSummary {
     pub internal_sinks: Vec<SinkType>,
     /// Sources within this function.
     pub internal_sources: Vec<SourceType>,
+    /// Sink types neutralized by sanitizer calls within this function.
+    #[serde(default)]
+    pub sanitized_sinks: Vec<SinkType>,
+}
+
+impl FunctionSummary {
+    /// Whether taint originates in this function.
+    pub fn is_source(&self) -> bool {
+        !self.internal_sources.is_empty() || !self.tainted_params.is_empty()
+    }
 }
 
 /// Analyze interprocedural taint flows across the call graph.
@@ -37,32 +52,105 @@ pub fn analyze_interprocedural(
     registry: &TaintRegistry,
     max_depth: Option<usize>,
 ) -> Result<Vec<TaintFlow>, TaintError> {
-    let max_d = max_depth.unwrap_or(MAX_TAINT_DEPTH);
-
-    // Phase 1: Build function summaries
-    let summaries = build_function_summaries(call_graph, parse_results, registry);
-
-    // Phase 2: Propagate taint through call graph
-    let flows = propagate_taint(call_graph, parse_results, registry, &summaries, max_d)?;
-
-    Ok(flows)
+    let mut cache = SummaryCache::new();
+    analyze_interprocedural_incremental(call_graph, parse_results, registry, max_depth, &mut cache)
+        .map(|(flows, _)| flows)
 }
 
-/// Build function summaries for all functions in the call graph.
-fn build_function_summaries(
+/// Interprocedural analysis that reuses `cache` from a previous run.
+///
+/// Only the dirty cone — functions whose content or callees changed, and
+/// their transitive callers — is re-summarized and re-traced; all other
+/// summaries and flows come from the cache, which is updated in place.
+pub fn analyze_interprocedural_incremental(
     call_graph: &CallGraph,
     parse_results: &[ParseResult],
     registry: &TaintRegistry,
-) -> FxHashMap<NodeIndex, FunctionSummary> {
-    let mut summaries = FxHashMap::default();
+    max_depth: Option<usize>,
+    cache: &mut SummaryCache,
+) -> Result<(Vec<TaintFlow>, SummaryStats), TaintError> {
+    let max_d = max_depth.unwrap_or(MAX_TAINT_DEPTH);
+    let fingerprint = registry.fingerprint();
+    let graph = &call_graph.graph;
+
+    // Phase 1: Find the dirty cone
+    let mut keys: FxHashMap<NodeIndex, (String, u64, Vec<String>)> = FxHashMap::default();
+    let mut dirty: FxHashSet<NodeIndex> = FxHashSet::default();
+    let mut queue = std::collections::VecDeque::new();
+    for idx in graph.node_indices() {
+        let node = &graph[idx];
+        let id = function_id(node);
+        let hash = content_hash(node, fingerprint);
+        let mut callees: Vec<String> = graph
+            .neighbors_directed(idx, petgraph::Direction::Outgoing)
+            .map(|callee| function_id(&graph[callee]))
+            .collect();
+        callees.sort();
+        callees.dedup();
+        let fresh = cache
+            .get(&id)
+            .is_some_and(|entry| entry.content_hash == hash && entry.callees == callees);
+        if !fresh && dirty.insert(idx) {
+            queue.push_back(idx);
+        }
+        keys.insert(idx, (id, hash, callees));
+    }
+    while let Some(idx) = queue.pop_front() {
+        for caller in graph.neighbors_directed(idx, petgraph::Direction::Incoming) {
+            if dirty.insert(caller) {
+                queue.push_back(caller);
+            }
+        }
+    }
 
-    for idx in call_graph.graph.node_indices() {
-        let node = &call_graph.graph[idx];
-        let summary = build_single_summary(node, parse_results, registry);
+    // Phase 2: Build function summaries for the dirty cone, reuse the rest
+    let mut summaries = FxHashMap::default();
+    for idx in graph.node_indices() {
+        let summary = match cache.get(&keys[&idx].0) {
+            Some(entry) if !dirty.contains(&idx) => entry.summary.clone(),
+            _ => build_single_summary(&graph[idx], parse_results, registry),
+        };
         summaries.insert(idx, summary);
     }
 
-    summaries
+    // Phase 3: Propagate taint through call graph from dirty sources
+    let mut flows = Vec::new();
+    let mut stats = SummaryStats::default();
+    for idx in graph.node_indices() {
+        let (id, hash, callees) = &keys[&idx];
+        if !dirty.contains(&idx) {
+            if let Some(entry) = cache.get(id) {
+                // Labels are numbered by node index, which is not stable
+                // across call graph builds.
+                flows.extend(entry.flows.iter().cloned().map(|mut flow| {
+                    flow.source.label.id = idx.index() as u64;
+                    flow
+                }));
+                stats.reused += 1;
+                continue;
+            }
+        }
+        let summary = &summaries[&idx];
+        let source_flows = if summary.is_source() {
+            propagate_from_source(call_graph, idx, &summaries, max_d)?
+        } else {
+            Vec::new()
+        };
+        flows.extend(source_flows.iter().cloned());
+        cache.insert(CachedSummary {
+            function_id: id.clone(),
+            content_hash: *hash,
+            callees: callees.clone(),
+            summary: summary.clone(),
+            flows: source_flows,
+        });
+        stats.recomputed += 1;
+    }
+
+    let live: FxHashSet<String> = keys.into_values().map(|(id, _, _)| id).collect();
+    stats.removed = cache.retain(&live);
+
+    Ok((flows, stats))
 }
 
 /// Build a summary for a single function.
@@ -80,6 +168,19 @@ fn build_single_summary(
         None => return summary,
     };
 
+    // Sanitizer calls within the function's span
+    for call in &pr.call_sites {
+        if call.line >= node.line && call.line <= node.end_line {
+            if let Some(sanitizer) = registry.match_sanitizer(&call_name(call)) {
+                for sink_type in &sanitizer.protects_against {
+                    if !summary.sanitized_sinks.contains(sink_type) {
+                        summary.sanitized_sinks.push(*sink_type);
+                    }
+                }
+            }
+        }
+    }
+
     // Find the function info
     let func = pr.functions.iter().find(|f| f.name == node.name);
     let func = match func {
@@ -89,7 +190,9 @@ fn build_single_summary(
 
     // Check parameters for source patterns
     for (i, param) in func.parameters.iter().enumerate() {
-        if registry.match_source(&param.name).is_some() {
+        if registry.match_source(&param.name).is_some()
+            || registry.match_parameter_source(param, func, pr).is_some()
+        {
             summary.tainted_params.insert(i);
             summary.returns_taint = true;
         }
@@ -98,11 +201,7 @@ fn build_single_summary(
     // Check call sites within function for sinks/sources
     for call in &pr.call_sites {
         if call.line >= func.line && call.line <= func.end_line {
-            let full_name = if let Some(ref receiver) = call.receiver {
-                format!("{}.{}", receiver, call.callee_name)
-            } else {
-                call.callee_name.clone()
-            };
+            let full_name = call_name(call);
 
             if let Some(sink_pattern) = registry.match_sink(&full_name) {
                 summary.internal_sinks.push(sink_pattern.sink_type);
@@ -117,140 +216,109 @@ fn build_single_summary(
     summary
 }
 
-/// Propagate taint through the call graph using function summaries.
-fn propagate_taint(
+fn call_name(call: &CallSite) -> String {
+    if let Some(ref receiver) = call.receiver {
+        format!("{}.{}", receiver, call.callee_name)
+    } else {
+        call.callee_name.clone()
+    }
+}
+
+/// Trace taint forward from one source function through the call graph.
+fn propagate_from_source(
     call_graph: &CallGraph,
-    parse_results: &[ParseResult],
-    registry: &TaintRegistry,
+    source_idx: NodeIndex,
     summaries: &FxHashMap<NodeIndex, FunctionSummary>,
     max_depth: usize,
 ) -> Result<Vec<TaintFlow>, TaintError> {
     let mut flows = Vec::new();
 
-    // Find all source nodes (functions that introduce taint)
-    let source_nodes: Vec<NodeIndex> = summaries
-        .iter()
-        .filter(|(_, s)| !s.internal_sources.is_empty() || !s.tainted_params.is_empty())
-        .map(|(idx, _)| *idx)
-        .collect();
-
-    // For each source, trace taint forward through the call graph
-    for source_idx in &source_nodes {
-        let source_summary = &summaries[source_idx];
-        let source_node = &call_graph.graph[*source_idx];
-
-        let source_type = source_summary
-            .internal_sources
-            .first()
-            .copied()
-            .unwrap_or(SourceType::UserInput);
-
-        let source = TaintSource {
-            file: source_node.file.clone(),
-            line: source_node.line,
-            column: 0,
-            expression: source_node.name.clone(),
-            source_type,
-            label: TaintLabel::new(source_idx.index() as u64, source_type),
-        };
+    let source_summary = &summaries[&source_idx];
+    let source_node = &call_graph.graph[source_idx];
+
+    let source_type = source_summary
+        .internal_sources
+        .first()
+        .copied()
+        .unwrap_or(SourceType::UserInput);
+
+    let source = TaintSource {
+        file: source_node.file.clone(),
+        line: source_node.line,
+        column: 0,
+        expression: source_node.name.clone(),
+        source_type,
+        label: TaintLabel::new(source_idx.index() as u64, source_type),
+    };
+
+    // BFS forward from source, looking for sinks
+    let mut visited = FxHashSet::default();
+    let mut queue = std::collections::VecDeque::new();
+    visited.insert(source_idx);
+    queue.push_back((source_idx, vec![source_idx], 0usize));
+
+    while let Some((current, path_nodes, depth)) = queue.pop_front() {
+        if depth > max_depth {
+            return Err(TaintError::PathTooLong {
+                length: depth,
+                max: max_depth,
+            });
+        }
 
-        // BFS forward from source, looking for sinks
-        let mut visited = FxHashSet::default();
-        let mut queue = std::collections::VecDeque::new();
-        visited.insert(*source_idx);
-        queue.push_back((*source_idx, vec![source_node.clone()], 0usize));
-
-        while let Some((current, path_nodes, depth)) = queue.pop_front() {
-            if depth > max_depth {
-                return Err(TaintError::PathTooLong {
-                    length: depth,
-                    max: max_depth,
+        // Check if current function has sinks
+        if let Some(current_summary) = summaries.get(&current) {
+            let current_node = &call_graph.graph[current];
+
+            for sink_type in &current_summary.internal_sinks {
+                // Check if any sanitizer along the path neutralizes this sink
+                let is_sanitized = path_nodes.iter().any(|idx| {
+                    summaries.get(idx).is_some_and(|s| s.sanitized_sinks.contains(sink_type))
                 });
-            }
 
-            // Check if current function has sinks
-            if let Some(current_summary) = summaries.get(&current) {
-                let current_node = &call_graph.graph[current];
-
-                for sink_type in &current_summary.internal_sinks {
-                    // Check if any sanitizer along the path neutralizes this sink
-                    let is_sanitized = check_path_sanitized(
-                        &path_nodes, parse_results, registry, sink_type,
-                    );
-
-                    let sink = TaintSink {
-                        file: current_node.file.clone(),
-                        line: current_node.line,
-                        column: 0,
-                        expression: current_node.name.clone(),
-                        sink_type: *sink_type,
-                        required_sanitizers: Vec::new(),
-                    };
-
-                    let taint_path: Vec<TaintHop> = path_nodes
-                        .iter()
-                        .map(|n| TaintHop {
+                let sink = TaintSink {
+                    file: current_node.file.clone(),
+                    line: current_node.line,
+                    column: 0,
+                    expression: current_node.name.clone(),
+                    sink_type: *sink_type,
+                    required_sanitizers: Vec::new(),
+                };
+
+                let taint_path: Vec<TaintHop> = path_nodes
+                    .iter()
+                    .map(|idx| {
+                        let n = &call_graph.graph[*idx];
+                        TaintHop {
                             file: n.file.clone(),
                             line: n.line,
                             column: 0,
                             function: n.name.clone(),
                             description: format!("Taint propagates through {}", n.name),
-                        })
-                        .collect();
-
-                    flows.push(TaintFlow {
-                        source: source.clone(),
-                        sink,
-                        path: taint_path,
-                        is_sanitized,
-                        sanitizers_applied: Vec::new(),
-                        cwe_id: sink_type.cwe_id(),
-                        confidence: if is_sanitized { 0.3 } else { 0.75 },
-                    });
-                }
-            }
-
-            // Continue BFS to callees
-            for neighbor in call_graph.graph.neighbors_directed(current, petgraph::Direction::Outgoing) {
-                if visited.insert(neighbor) {
-                    let neighbor_node = call_graph.graph[neighbor].clone();
-                    let mut new_path = path_nodes.clone();
-                    new_path.push(neighbor_node);
-                    queue.push_back((neighbor, new_path, depth + 1));
-                }
+                        }
+                    })
+                    .collect();
+
+                flows.push(TaintFlow {
+                    source: source.clone(),
+                    sink,
+                    path: taint_path,
+                    is_sanitized,
+                    sanitizers_applied: Vec::new(),
+                    cwe_id: sink_type.cwe_id(),
+                    confidence: if is_sanitized { 0.3 } else { 0.75 },
+                });
             }
         }
-    }
 
-    Ok(flows)
-}
-
-/// Check if the path from source to sink has appropriate sanitization.
-fn check_path_sanitized(
-    path_nodes: &[crate::call_graph::types::FunctionNode],
-    parse_results: &[ParseResult],
-    registry: &TaintRegistry,
-    sink_type: &SinkType,
-) -> bool {
-    for node in path_nodes {
-        if let Some(pr) = parse_results.iter().find(|pr| pr.file == node.file) {
-            for call in &pr.call_sites {
-                if call.line >= node.line && call.line <= node.end_line {
-                    let full_name = if let Some(ref receiver) = call.receiver {
-                        format!("{}.{}", receiver, call.callee_name)
-                    } else {
-                        call.callee_name.clone()
-                    };
-
-                    if let Some(sanitizer) = registry.match_sanitizer(&full_name) {
-                        if sanitizer.protects_against.contains(sink_type) {
-                            return true;
-                        }
-                    }
-                }
+        // Continue BFS to callees
+        for neighbor in call_graph.graph.neighbors_directed(current, petgraph::Direction::Outgoing) {
+            if visited.insert(neighbor) {
+                let mut new_path = path_nodes.clone();
+                new_path.push(neighbor);
+                queue.push_back((neighbor, new_path, depth + 1));
             }
         }
     }
 
-    false
+    Ok(flows)
 }
@@ -1,21 +1,26 @@
 //! Taint analysis — source/sink/sanitizer model with 17 CWE categories.
 //!
 //! Two-phase analysis:
-//! 1. Intraprocedural (<1ms/function) — within-function dataflow
-//! 2. Interprocedural (<100ms/function) — cross-function via summaries
+//! 1. Intraprocedural (<1ms/function) — within-function dataflow, def-use
+//!    over the syntax tree when the source is available
+//! 2. Interprocedural (<100ms/function) — cross-function via summaries,
+//!    cached per function and recomputed only for the dirty cone
 //!
 //! TOML-driven registry for extensibility. SARIF output for CI integration.
 
 pub mod types;
 pub mod registry;
 pub mod intraprocedural;
+pub mod dataflow;
 pub mod interprocedural;
+pub mod summaries;
 pub mod propagation;
 pub mod sarif;
 pub mod framework_specs;
 
 pub use types::*;
 pub use registry::TaintRegistry;
-pub use intraprocedural::analyze_intraprocedural;
-pub use interprocedural::analyze_interprocedural;
+pub use intraprocedural::{analyze_intraprocedural, analyze_intraprocedural_with_source};
+pub use interprocedural::{analyze_interprocedural, analyze_interprocedural_incremental};
+pub use summaries::{CachedSummary, SummaryCache, SummaryStats};
 pub use sarif::generate_sarif;
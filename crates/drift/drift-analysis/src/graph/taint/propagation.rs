@@ -11,6 +11,8 @@ pub struct PropagationContext {
     labels: FxHashMap<String, TaintLabel>,
     /// Applied sanitizers.
     sanitizers: Vec<TaintSanitizer>,
+    /// Variable → sink types its current value has been sanitized for.
+    protected: FxHashMap<String, Vec<SinkType>>,
     /// Next label ID.
     next_id: u64,
 }
@@ -25,6 +27,7 @@ impl PropagationContext {
         let label = TaintLabel::new(self.next_id, source_type);
         self.next_id += 1;
         self.labels.insert(var.to_string(), label.clone());
+        self.protected.remove(var);
         label
     }
 
@@ -32,15 +35,27 @@ impl PropagationContext {
     pub fn propagate(&mut self, from: &str, to: &str) {
         if let Some(label) = self.labels.get(from).cloned() {
             self.labels.insert(to.to_string(), label);
+            match self.protected.get(from).cloned() {
+                Some(sinks) => self.protected.insert(to.to_string(), sinks),
+                None => self.protected.remove(to),
+            };
         }
     }
 
     /// Merge taint labels at a join point (e.g., ternary, phi node).
+    ///
+    /// The result is sanitized for a sink type only if every tainted input is.
     pub fn merge(&mut self, vars: &[&str], target: &str) {
         let mut merged_label: Option<TaintLabel> = None;
+        let mut merged_protected: Option<Vec<SinkType>> = None;
 
         for var in vars {
             if let Some(label) = self.labels.get(*var) {
+                let protected = self.protected.get(*var).cloned().unwrap_or_default();
+                merged_protected = Some(match merged_protected {
+                    None => protected,
+                    Some(existing) => existing.into_iter().filter(|s| protected.contains(s)).collect(),
+                });
                 match &mut merged_label {
                     None => merged_label = Some(label.clone()),
                     Some(existing) => {
@@ -61,6 +76,10 @@ impl PropagationContext {
 
         if let Some(label) = merged_label {
             self.labels.insert(target.to_string(), label);
+            match merged_protected.filter(|p| !p.is_empty()) {
+                Some(sinks) => self.protected.insert(target.to_string(), sinks),
+                None => self.protected.remove(target),
+            };
         }
     }
 
@@ -68,6 +87,12 @@ impl PropagationContext {
     pub fn sanitize(&mut self, var: &str, sanitizer_type: SanitizerType, sink_types: &[SinkType]) {
         if let Some(label) = self.labels.get_mut(var) {
             label.apply_sanitizer(sanitizer_type);
+            let protected = self.protected.entry(var.to_string()).or_default();
+            for sink in sink_types {
+                if !protected.contains(sink) {
+                    protected.push(*sink);
+                }
+            }
         }
         self.sanitizers.push(TaintSanitizer {
             file: String::new(),
@@ -84,8 +109,11 @@ impl PropagationContext {
     }
 
     /// Check if a variable's taint has been sanitized for a specific sink type.
-    pub fn is_sanitized_for(&self, _var: &str, sink_type: &SinkType) -> bool {
-        self.sanitizers.iter().any(|s| s.labels_sanitized.contains(sink_type))
+    ///
+    /// Only sanitizers applied to `var` itself (or to the value it was
+    /// assigned from) count; re-tainting the variable resets it.
+    pub fn is_sanitized_for(&self, var: &str, sink_type: &SinkType) -> bool {
+        self.protected.get(var).is_some_and(|sinks| sinks.contains(sink_type))
     }
 
     /// Get the taint label for a variable.
@@ -107,6 +135,7 @@ impl PropagationContext {
     pub fn clear(&mut self) {
         self.labels.clear();
         self.sanitizers.clear();
+        self.protected.clear();
     }
 }
 
@@ -126,6 +155,8 @@ pub fn propagate_through_collection(
         if ctx.is_tainted(element_var) {
             if let Some(label) = ctx.get_label(element_var).cloned() {
                 ctx.labels.insert(collection_var.to_string(), label);
+                // One unsanitized element leaves the whole collection tainted.
+                ctx.protected.remove(collection_var);
             }
         }
     } else {
@@ -5,6 +5,9 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::parsers::types::{FunctionInfo, ImportInfo, ParameterInfo, ParseResult};
+
+use super::framework_specs::{apply_framework_specs, TaintFramework};
 use super::types::{SanitizerType, SinkType, SourceType};
 
 /// TOML-driven taint registry.
@@ -73,6 +76,16 @@ impl TaintRegistry {
         registry
     }
 
+    /// Create a registry with the built-in defaults plus the specs of the
+    /// given frameworks.
+    pub fn with_frameworks(frameworks: &[TaintFramework]) -> Self {
+        let mut registry = Self::with_defaults();
+        for framework in frameworks {
+            apply_framework_specs(&mut registry, *framework);
+        }
+        registry
+    }
+
     /// Load additional patterns from TOML string.
     pub fn load_toml(&mut self, toml_str: &str) -> Result<(), toml::de::Error> {
         let config: RegistryConfig = toml::from_str(toml_str)?;
@@ -96,6 +109,75 @@ impl TaintRegistry {
         self.sources.iter().find(|p| pattern_matches(expression, &p.pattern))
     }
 
+    /// Check if a parameter is a source by how it is declared rather than
+    /// by name: its type (`Query<Params>`, `NextRequest`), a marker default
+    /// (`q: str = Query(None)`), or, on decorated handlers, the base of the
+    /// model class it is typed with (`item: Item` where `Item(BaseModel)`).
+    /// Names are qualified by the file's imports, so `Query` imported from
+    /// `fastapi` matches the pattern `fastapi.Query`.
+    ///
+    /// A default injecting a function of the same file
+    /// (`user = Depends(get_user)`) is a source when that function takes one.
+    pub fn match_parameter_source(
+        &self,
+        param: &ParameterInfo,
+        func: &FunctionInfo,
+        parse_result: &ParseResult,
+    ) -> Option<&SourcePattern> {
+        self.declared_source(param, func, parse_result, 2)
+    }
+
+    fn declared_source(
+        &self,
+        param: &ParameterInfo,
+        func: &FunctionInfo,
+        parse_result: &ParseResult,
+        depth: u8,
+    ) -> Option<&SourcePattern> {
+        let imports = &parse_result.imports;
+        let matching = |name: &str| {
+            qualified_names(name, imports).iter().find_map(|q| self.match_source(q))
+        };
+
+        let mut names = Vec::new();
+        if let Some(ty) = param.type_annotation.as_deref() {
+            let ty = ty.trim_start_matches(':').trim();
+            names.push(type_head(ty));
+            // `Annotated[str, Query(max_length=50)]` carries markers as metadata.
+            if let Some(metadata) = ty.strip_prefix("Annotated[") {
+                names.extend(metadata.split(',').skip(1).map(type_head));
+            }
+        }
+        let default = param.default_value.as_deref().map(str::trim).filter(|d| d.contains('('));
+        if let Some(call) = default {
+            names.push(type_head(call));
+        }
+        names.retain(|n| !n.is_empty());
+        if let Some(pattern) = names.iter().find_map(|n| matching(n)) {
+            return Some(pattern);
+        }
+
+        if !func.decorators.is_empty() {
+            let base = names.iter().find_map(|n| {
+                parse_result.classes.iter().find(|c| c.name == *n)?.extends.as_deref()
+            });
+            if let Some(pattern) = base.and_then(|b| matching(type_head(b))) {
+                return Some(pattern);
+            }
+        }
+
+        if depth == 0 {
+            return None;
+        }
+        let injected = default
+            .and_then(|d| d.split_once('('))
+            .map(|(_, args)| type_head(args.split([',', ')']).next().unwrap_or("")))?;
+        let dependency = parse_result.functions.iter().find(|f| f.name == injected)?;
+        dependency.parameters.iter().find_map(|p| {
+            self.match_source(&p.name).or_else(|| self.declared_source(p, dependency, parse_result, depth - 1))
+        })
+    }
+
     /// Check if an expression matches a sink pattern.
     /// CG-TAINT-04/05: Anchored matching — no bidirectional substring.
     pub fn match_sink(&self, expression: &str) -> Option<&SinkPattern> {
@@ -108,6 +190,13 @@ impl TaintRegistry {
         self.sanitizers.iter().find(|p| pattern_matches(expression, &p.pattern))
     }
 
+    /// Hash of every pattern, so cached results can be invalidated when
+    /// the registry changes.
+    pub fn fingerprint(&self) -> u64 {
+        let patterns = serde_json::to_string(&(&self.sources, &self.sinks, &self.sanitizers)).unwrap_or_default();
+        crate::scanner::hasher::hash_content(patterns.as_bytes())
+    }
+
     /// Add a custom source pattern.
     pub fn add_source(&mut self, pattern: SourcePattern) {
         self.sources.push(pattern);
@@ -155,6 +244,8 @@ impl TaintRegistry {
             ("spawn", SinkType::OsCommand, &[SanitizerType::ShellEscape]),
             ("system", SinkType::OsCommand, &[SanitizerType::ShellEscape]),
             ("popen", SinkType::OsCommand, &[SanitizerType::ShellEscape]),
+            ("execvp", SinkType::OsCommand, &[SanitizerType::ShellEscape]),
+            ("execlp", SinkType::OsCommand, &[SanitizerType::ShellEscape]),
             ("subprocess.run", SinkType::OsCommand, &[SanitizerType::ShellEscape]),
             ("subprocess.call", SinkType::OsCommand, &[SanitizerType::ShellEscape]),
             ("Runtime.exec", SinkType::OsCommand, &[SanitizerType::ShellEscape]),
@@ -182,6 +273,10 @@ impl TaintRegistry {
             ("new RegExp", SinkType::RegexConstruction, &[SanitizerType::InputValidation]),
             ("xml.parse", SinkType::XmlParsing, &[SanitizerType::InputValidation]),
             ("upload", SinkType::FileUpload, &[SanitizerType::InputValidation]),
+            // C/C++ unbounded buffer copies — CWE-120
+            ("strcpy", SinkType::Custom(120), &[SanitizerType::InputValidation]),
+            ("strcat", SinkType::Custom(120), &[SanitizerType::InputValidation]),
+            ("sqlite3_exec", SinkType::SqlQuery, &[SanitizerType::SqlParameterize]),
         ];
 
         for (pattern, sink_type, sanitizers) in sink_defs {
@@ -228,6 +323,42 @@ impl TaintRegistry {
     }
 }
 
+/// Leading type or callee name: `Query` in `Query<Params>`, `&Path<u32>` or
+/// `Query(None)`.
+fn type_head(text: &str) -> &str {
+    let text = text.trim().trim_start_matches('&');
+    let text = text.strip_prefix("mut ").unwrap_or(text).trim_start();
+    let end = text.find(['<', '[', '(', ' ', '|', ',', ')', ']']).unwrap_or(text.len());
+    &text[..end]
+}
+
+/// `name` qualified by the imports that bring it into scope: `Query` with
+/// `from fastapi import Query` is `fastapi.Query`, `Json` with
+/// `use axum::Json` is `axum::Json`. Unqualified names are left out, so a
+/// local `Params` type is not mistaken for the `params` source.
+fn qualified_names(name: &str, imports: &[ImportInfo]) -> Vec<String> {
+    let mut names = Vec::new();
+    if name.contains("::") || name.contains('.') {
+        names.push(name.to_string());
+    }
+    let split = name.find("::").or_else(|| name.find('.')).unwrap_or(name.len());
+    let (first, rest) = name.split_at(split);
+    for import in imports {
+        let source = import.source.as_str();
+        let sep = if source.contains("::") { "::" } else { "." };
+        let last = source.rsplit([':', '/', '.', '\\']).next().unwrap_or(source);
+        if let Some(spec) = import.specifiers.iter().find(|s| s.alias.as_deref().unwrap_or(&s.name) == first) {
+            names.push(format!("{source}{sep}{}{rest}", spec.name));
+        } else if last == first {
+            names.push(format!("{source}{rest}"));
+        } else if import.specifiers.is_empty() {
+            // Specifiers are not always recorded (`from fastapi import Query`).
+            names.push(format!("{source}{sep}{name}"));
+        }
+    }
+    names
+}
+
 /// CG-TAINT-04/05/06: Anchored pattern matching.
 ///
 /// Matches if:
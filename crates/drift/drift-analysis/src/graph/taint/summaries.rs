@@ -0,0 +1,125 @@
+//! Persisted, incremental taint function summaries.
+//!
+//! Summaries are keyed by function id (`file::name`, the call graph key) and
+//! a content hash over the function's body, signature and span and the taint
+//! registry. A function is recomputed when its hash or its set of callees
+//! changes, and so is everything that calls it, transitively — the dirty
+//! cone, found by walking the reverse call graph. Every other function keeps
+//! its summary and the interprocedural flows that start at it, so the cache
+//! can be carried across `IncrementalCallGraph` updates and scans.
+
+use drift_core::types::collections::{FxHashMap, FxHashSet};
+use serde::{Deserialize, Serialize};
+
+use crate::call_graph::types::FunctionNode;
+use crate::scanner::hasher::hash_content;
+
+use super::interprocedural::FunctionSummary;
+use super::types::TaintFlow;
+
+/// A function's summary as persisted between runs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedSummary {
+    pub function_id: String,
+    /// Hash of the function and the registry the summary was computed with.
+    pub content_hash: u64,
+    /// Function ids of its callees at the time, sorted.
+    pub callees: Vec<String>,
+    pub summary: FunctionSummary,
+    /// Interprocedural flows whose source is this function.
+    pub flows: Vec<TaintFlow>,
+}
+
+/// Summaries from previous runs, plus the changes made since they were
+/// loaded so callers can write back only what changed.
+#[derive(Debug, Clone, Default)]
+pub struct SummaryCache {
+    entries: FxHashMap<String, CachedSummary>,
+    updated: FxHashSet<String>,
+    removed: Vec<String>,
+}
+
+/// What an incremental run reused and recomputed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SummaryStats {
+    pub reused: usize,
+    pub recomputed: usize,
+    pub removed: usize,
+}
+
+impl SummaryCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load persisted entries. Nothing is pending afterwards.
+    pub fn from_entries(entries: impl IntoIterator<Item = CachedSummary>) -> Self {
+        Self {
+            entries: entries.into_iter().map(|e| (e.function_id.clone(), e)).collect(),
+            ..Self::default()
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn get(&self, function_id: &str) -> Option<&CachedSummary> {
+        self.entries.get(function_id)
+    }
+
+    /// Entries recomputed since the cache was loaded, to be persisted.
+    pub fn updated(&self) -> Vec<&CachedSummary> {
+        let mut updated: Vec<&CachedSummary> = self.updated.iter().filter_map(|id| self.entries.get(id)).collect();
+        updated.sort_by(|a, b| a.function_id.cmp(&b.function_id));
+        updated
+    }
+
+    /// Function ids dropped because they left the call graph.
+    pub fn removed(&self) -> &[String] {
+        &self.removed
+    }
+
+    /// Clear pending changes once they have been persisted.
+    pub fn mark_persisted(&mut self) {
+        self.updated.clear();
+        self.removed.clear();
+    }
+
+    pub(crate) fn insert(&mut self, entry: CachedSummary) {
+        self.updated.insert(entry.function_id.clone());
+        self.removed.retain(|id| *id != entry.function_id);
+        self.entries.insert(entry.function_id.clone(), entry);
+    }
+
+    /// Drop entries for functions not in `live`.
+    pub(crate) fn retain(&mut self, live: &FxHashSet<String>) -> usize {
+        let stale: Vec<String> = self.entries.keys().filter(|id| !live.contains(*id)).cloned().collect();
+        for id in &stale {
+            self.entries.remove(id);
+            self.updated.remove(id);
+        }
+        let count = stale.len();
+        self.removed.extend(stale);
+        count
+    }
+}
+
+/// Call graph key of a function.
+pub fn function_id(node: &FunctionNode) -> String {
+    format!("{}::{}", node.file, node.name)
+}
+
+/// Content hash of a function under a registry fingerprint. The span is
+/// included because cached flows carry line numbers.
+pub fn content_hash(node: &FunctionNode, registry_fingerprint: u64) -> u64 {
+    let key = format!(
+        "{}\0{}\0{}\0{}\0{:x}\0{:x}\0{:x}",
+        node.file, node.name, node.line, node.end_line, node.body_hash, node.signature_hash, registry_fingerprint,
+    );
+    hash_content(key.as_bytes())
+}
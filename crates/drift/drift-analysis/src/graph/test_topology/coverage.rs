@@ -1,14 +1,16 @@
 //! Coverage mapping via call graph BFS.
 //!
 //! Maps test functions to the source functions they cover by following
-//! outgoing call edges from test functions.
+//! outgoing call edges from test functions. Measured line coverage from an
+//! imported report can be merged on top; it overrides the inference for the
+//! functions it instruments.
 
 use drift_core::types::collections::FxHashSet;
 use petgraph::graph::NodeIndex;
 
 use crate::call_graph::types::CallGraph;
 
-use super::types::CoverageMapping;
+use super::types::{CoverageEvidence, CoverageMapping, CoverageReport, MeasuredCoverage};
 
 /// Compute coverage mapping from the call graph.
 ///
@@ -40,9 +42,52 @@ pub fn compute_coverage(graph: &CallGraph) -> CoverageMapping {
         mapping.test_to_source.insert(test_idx, covered);
     }
 
+    for &source_idx in mapping.source_to_test.keys() {
+        mapping.evidence.insert(source_idx, CoverageEvidence::Inferred);
+    }
+
+    mapping
+}
+
+/// Compute the coverage mapping and merge measured coverage from `report`.
+pub fn compute_coverage_with_report(graph: &CallGraph, report: &CoverageReport) -> CoverageMapping {
+    let mut mapping = compute_coverage(graph);
+    merge_measured_coverage(&mut mapping, graph, report);
     mapping
 }
 
+/// Merge measured line coverage into an inferred mapping and tag every
+/// covered or measured source function with its evidence source.
+///
+/// A function is measured when the report instruments at least one line in
+/// its span. Functions the report doesn't instrument keep the inference.
+pub fn merge_measured_coverage(mapping: &mut CoverageMapping, graph: &CallGraph, report: &CoverageReport) {
+    let (_, source_nodes) = classify_nodes(graph);
+
+    for idx in source_nodes {
+        let node = &graph.graph[idx];
+        let inferred = mapping.source_to_test.contains_key(&idx);
+
+        // Function lines are 0-based; report lines are 1-based.
+        let measured = report.lines_for(&node.file).and_then(|lines| {
+            let span = lines.range(node.line + 1..=node.end_line.max(node.line) + 1);
+            let (total, hit) = span.fold((0u32, 0u32), |(t, h), (_, &hits)| (t + 1, h + u32::from(hits > 0)));
+            (total > 0).then_some(MeasuredCoverage { lines_total: total, lines_hit: hit })
+        });
+
+        let evidence = match measured {
+            Some(m) if m.is_covered() && inferred => CoverageEvidence::MeasuredAndInferred,
+            Some(_) => CoverageEvidence::Measured,
+            None if inferred => CoverageEvidence::Inferred,
+            None => continue,
+        };
+        if let Some(m) = measured {
+            mapping.measured.insert(idx, m);
+        }
+        mapping.evidence.insert(idx, evidence);
+    }
+}
+
 /// Classify nodes into test functions and source functions.
 fn classify_nodes(graph: &CallGraph) -> (Vec<NodeIndex>, Vec<NodeIndex>) {
     let mut test_nodes = Vec::new();
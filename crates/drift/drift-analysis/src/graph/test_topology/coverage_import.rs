@@ -0,0 +1,244 @@
+//! Coverage report import — LCOV, Cobertura XML, JaCoCo XML, Go coverprofile.
+//!
+//! Reports are reduced to per-file line hit counts (1-based lines). When the
+//! same line appears more than once — in several blocks, methods or reports —
+//! the highest hit count wins.
+
+use std::path::{Path, PathBuf};
+
+use drift_core::errors::CallGraphError;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::reader::Reader;
+
+use super::types::{CoverageFormat, CoverageReport};
+
+impl CoverageFormat {
+    /// Detect the report format from its contents, falling back to the file name.
+    pub fn detect(path: &Path, content: &str) -> Option<CoverageFormat> {
+        let head = content.trim_start();
+        if head.starts_with("mode:") {
+            return Some(Self::GoCoverprofile);
+        }
+        if head.starts_with('<') {
+            let prefix: String = head.chars().take(2048).collect();
+            if prefix.contains("<coverage") {
+                return Some(Self::Cobertura);
+            }
+            if prefix.contains("<report") || prefix.contains("JACOCO") {
+                return Some(Self::JaCoCo);
+            }
+            return None;
+        }
+        if head.lines().any(|l| l.starts_with("SF:")) {
+            return Some(Self::Lcov);
+        }
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("info") | Some("lcov") => Some(Self::Lcov),
+            _ => None,
+        }
+    }
+}
+
+/// Default output locations of common coverage tools, relative to the project root.
+const WELL_KNOWN_REPORTS: &[&str] = &[
+    "coverage/lcov.info",
+    "lcov.info",
+    "coverage/cobertura-coverage.xml",
+    "coverage.xml",
+    "target/site/jacoco/jacoco.xml",
+    "build/reports/jacoco/test/jacocoTestReport.xml",
+    "coverage.out",
+    "cover.out",
+];
+
+/// Coverage reports present at well-known locations under `root`.
+pub fn discover_coverage_reports(root: &Path) -> Vec<PathBuf> {
+    WELL_KNOWN_REPORTS
+        .iter()
+        .map(|rel| root.join(rel))
+        .filter(|p| p.is_file())
+        .collect()
+}
+
+/// Read and parse a coverage report, detecting its format.
+pub fn load_coverage_report(path: &Path) -> Result<CoverageReport, CallGraphError> {
+    let content = std::fs::read_to_string(path).map_err(|e| import_error(path, e.to_string()))?;
+    let format = CoverageFormat::detect(path, &content)
+        .ok_or_else(|| import_error(path, "unrecognized coverage report format".to_string()))?;
+    parse_coverage_report(&content, format).map_err(|message| import_error(path, message))
+}
+
+/// Parse a coverage report in the given format.
+pub fn parse_coverage_report(content: &str, format: CoverageFormat) -> Result<CoverageReport, String> {
+    let mut report = CoverageReport::default();
+    match format {
+        CoverageFormat::Lcov => parse_lcov(content, &mut report)?,
+        CoverageFormat::Cobertura => parse_cobertura(content, &mut report)?,
+        CoverageFormat::JaCoCo => parse_jacoco(content, &mut report)?,
+        CoverageFormat::GoCoverprofile => parse_go_coverprofile(content, &mut report)?,
+    }
+    report.formats.push(format);
+    Ok(report)
+}
+
+fn import_error(path: &Path, message: String) -> CallGraphError {
+    CallGraphError::CoverageImportFailed { path: path.display().to_string(), message }
+}
+
+/// LCOV tracefile: `SF:<path>`, `DA:<line>,<hits>[,<checksum>]`, `end_of_record`.
+fn parse_lcov(content: &str, report: &mut CoverageReport) -> Result<(), String> {
+    let mut current: Option<String> = None;
+    for (i, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if let Some(path) = line.strip_prefix("SF:") {
+            current = Some(path.to_string());
+            report.add_file(path);
+        } else if let Some(data) = line.strip_prefix("DA:") {
+            let file = current.as_deref().ok_or_else(|| format!("line {}: DA record outside of SF block", i + 1))?;
+            let mut fields = data.split(',');
+            let (Some(line_no), Some(hits)) = (fields.next(), fields.next()) else {
+                return Err(format!("line {}: malformed DA record", i + 1));
+            };
+            let line_no = line_no.parse::<u32>().map_err(|e| format!("line {}: {e}", i + 1))?;
+            // Some tools emit negative or fractional counts; anything positive is a hit.
+            let hits = hits.parse::<f64>().map_err(|e| format!("line {}: {e}", i + 1))?.max(0.0) as u64;
+            report.record(file, line_no, hits);
+        } else if line == "end_of_record" {
+            current = None;
+        }
+    }
+    Ok(())
+}
+
+/// Go coverprofile: `mode: <mode>` then `file:l1.c1,l2.c2 <stmts> <count>` per block.
+fn parse_go_coverprofile(content: &str, report: &mut CoverageReport) -> Result<(), String> {
+    for (i, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with("mode:") {
+            continue;
+        }
+        let malformed = || format!("line {}: malformed coverprofile block", i + 1);
+        let (location, counts) = line.split_once(' ').ok_or_else(malformed)?;
+        let (file, range) = location.rsplit_once(':').ok_or_else(malformed)?;
+        let (start, end) = range.split_once(',').ok_or_else(malformed)?;
+        let start_line = start.split('.').next().and_then(|l| l.parse::<u32>().ok()).ok_or_else(malformed)?;
+        let end_line = end.split('.').next().and_then(|l| l.parse::<u32>().ok()).ok_or_else(malformed)?;
+        let count = counts.split_whitespace().nth(1).and_then(|c| c.parse::<u64>().ok()).ok_or_else(malformed)?;
+        for line_no in start_line..=end_line {
+            report.record(file, line_no, count);
+        }
+    }
+    Ok(())
+}
+
+/// Cobertura XML: `<class filename="…">` containing `<line number="…" hits="…"/>`.
+/// Lines repeated under `<methods>` merge with the class-level ones.
+///
+/// Relative file names resolve against the `<sources>` roots. A file can't be
+/// traced back to one root from the report alone, so it is recorded under
+/// each of them; only the root it really lives in matches a project path.
+fn parse_cobertura(content: &str, report: &mut CoverageReport) -> Result<(), String> {
+    let mut reader = Reader::from_str(content);
+    let mut sources: Vec<String> = Vec::new();
+    let mut in_source = false;
+    let mut current: Vec<String> = Vec::new();
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(e)) if e.local_name().as_ref() == b"source" => in_source = true,
+            Ok(Event::Start(e)) | Ok(Event::Empty(e)) => match e.local_name().as_ref() {
+                b"class" => {
+                    if let Some(file) = attr(&e, b"filename") {
+                        current = resolve_source(&sources, &file);
+                        for file in &current {
+                            report.add_file(file);
+                        }
+                    }
+                }
+                b"line" => {
+                    let (Some(number), Some(hits)) = (attr(&e, b"number"), attr(&e, b"hits")) else {
+                        continue;
+                    };
+                    if let (Ok(number), Ok(hits)) = (number.parse::<u32>(), hits.parse::<u64>()) {
+                        for file in &current {
+                            report.record(file, number, hits);
+                        }
+                    }
+                }
+                _ => {}
+            },
+            Ok(Event::Text(text)) if in_source => {
+                let root = text.unescape().map_err(|e| format!("XML error at byte {}: {e}", reader.error_position()))?;
+                let root = root.trim().trim_end_matches(['/', '\\']);
+                if !root.is_empty() && root != "." {
+                    sources.push(root.to_string());
+                }
+            }
+            Ok(Event::End(e)) => match e.local_name().as_ref() {
+                b"source" => in_source = false,
+                b"class" => current.clear(),
+                _ => {}
+            },
+            Ok(Event::Eof) => break,
+            Err(e) => return Err(format!("XML error at byte {}: {e}", reader.error_position())),
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Paths of a Cobertura file name under each source root.
+fn resolve_source(sources: &[String], file: &str) -> Vec<String> {
+    if sources.is_empty() || Path::new(file).is_absolute() {
+        return vec![file.to_string()];
+    }
+    sources.iter().map(|root| format!("{root}/{file}")).collect()
+}
+
+/// JaCoCo XML: `<package name="a/b">` → `<sourcefile name="C.java">` →
+/// `<line nr="…" ci="…"/>`, where `ci` counts covered instructions.
+fn parse_jacoco(content: &str, report: &mut CoverageReport) -> Result<(), String> {
+    let mut reader = Reader::from_str(content);
+    let mut package = String::new();
+    let mut current: Option<String> = None;
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(e)) | Ok(Event::Empty(e)) => match e.local_name().as_ref() {
+                b"package" => package = attr(&e, b"name").unwrap_or_default(),
+                b"sourcefile" => {
+                    if let Some(name) = attr(&e, b"name") {
+                        let file = if package.is_empty() { name } else { format!("{package}/{name}") };
+                        report.add_file(&file);
+                        current = Some(file);
+                    }
+                }
+                b"line" => {
+                    let (Some(file), Some(nr), Some(ci)) = (current.as_deref(), attr(&e, b"nr"), attr(&e, b"ci")) else {
+                        continue;
+                    };
+                    if let (Ok(nr), Ok(ci)) = (nr.parse::<u32>(), ci.parse::<u64>()) {
+                        report.record(file, nr, ci);
+                    }
+                }
+                _ => {}
+            },
+            Ok(Event::End(e)) => match e.local_name().as_ref() {
+                b"sourcefile" => current = None,
+                b"package" => package.clear(),
+                _ => {}
+            },
+            Ok(Event::Eof) => break,
+            Err(e) => return Err(format!("XML error at byte {}: {e}", reader.error_position())),
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+fn attr(element: &BytesStart, name: &[u8]) -> Option<String> {
+    element
+        .attributes()
+        .flatten()
+        .find(|a| a.key.local_name().as_ref() == name)
+        .and_then(|a| a.unescape_value().ok())
+        .map(|v| v.into_owned())
+}
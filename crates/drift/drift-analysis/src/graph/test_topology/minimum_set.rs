@@ -8,12 +8,14 @@ use super::types::{CoverageMapping, MinimumTestSet};
 /// Compute the minimum test set that covers all source functions.
 ///
 /// Uses a greedy set cover algorithm: repeatedly select the test
-/// that covers the most uncovered source functions.
+/// that covers the most uncovered source functions. Functions that measured
+/// coverage shows never ran are left out: no test actually reaches them.
 pub fn compute_minimum_test_set(coverage: &CoverageMapping) -> MinimumTestSet {
     let mut uncovered: FxHashSet<NodeIndex> = coverage
         .source_to_test
         .keys()
         .copied()
+        .filter(|f| coverage.is_covered(*f))
         .collect();
 
     let total_functions = uncovered.len();
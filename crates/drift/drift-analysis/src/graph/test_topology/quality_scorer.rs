@@ -12,10 +12,20 @@ pub fn compute_quality_score(
     parse_results: &[ParseResult],
 ) -> TestQualityScore {
     let coverage = compute_coverage(graph);
+    compute_quality_score_with_coverage(graph, parse_results, &coverage)
+}
+
+/// Compute the quality score from an existing coverage mapping, e.g. one
+/// with measured coverage merged in.
+pub fn compute_quality_score_with_coverage(
+    graph: &CallGraph,
+    parse_results: &[ParseResult],
+    coverage: &CoverageMapping,
+) -> TestQualityScore {
     let smells = detect_all_smells(parse_results, graph);
 
-    let coverage_breadth = compute_coverage_breadth(&coverage);
-    let coverage_depth = compute_coverage_depth(&coverage);
+    let coverage_breadth = compute_coverage_breadth(coverage);
+    let coverage_depth = compute_coverage_depth(coverage);
     let assertion_density = compute_assertion_density(parse_results);
     let mock_ratio = compute_mock_ratio(parse_results);
     let isolation = compute_isolation(parse_results);
@@ -42,21 +52,29 @@ pub fn compute_quality_score(
 }
 
 /// Coverage breadth: % of source functions covered by at least 1 test.
+/// Measured coverage is preferred where present.
 fn compute_coverage_breadth(coverage: &CoverageMapping) -> f32 {
     if coverage.total_source_functions == 0 {
         return 0.0;
     }
-    let covered = coverage.source_to_test.len();
+    let covered = coverage.covered_source_functions();
     (covered as f32 / coverage.total_source_functions as f32).min(1.0)
 }
 
 /// Coverage depth: average number of tests per covered source function.
+/// Links that measured coverage refutes don't count.
 fn compute_coverage_depth(coverage: &CoverageMapping) -> f32 {
-    if coverage.source_to_test.is_empty() {
+    let linked: Vec<usize> = coverage
+        .source_to_test
+        .iter()
+        .filter(|(f, _)| coverage.is_covered(**f))
+        .map(|(_, tests)| tests.len())
+        .collect();
+    if linked.is_empty() {
         return 0.0;
     }
-    let total_tests: usize = coverage.source_to_test.values().map(|t| t.len()).sum();
-    let avg = total_tests as f32 / coverage.source_to_test.len() as f32;
+    let total_tests: usize = linked.iter().sum();
+    let avg = total_tests as f32 / linked.len() as f32;
     // Normalize: 3+ tests per function = 1.0
     (avg / 3.0).min(1.0)
 }
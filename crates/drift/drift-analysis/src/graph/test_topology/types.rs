@@ -1,5 +1,7 @@
 //! Test topology types — quality scores, smells, coverage.
 
+use std::collections::BTreeMap;
+
 use drift_core::types::collections::{FxHashMap, FxHashSet};
 use petgraph::graph::NodeIndex;
 use serde::{Deserialize, Serialize};
@@ -168,6 +170,190 @@ pub struct CoverageMapping {
     pub total_source_functions: usize,
     /// Total test functions.
     pub total_test_functions: usize,
+    /// Line coverage from an imported report, per source function.
+    pub measured: FxHashMap<NodeIndex, MeasuredCoverage>,
+    /// Where each source function's coverage verdict comes from.
+    pub evidence: FxHashMap<NodeIndex, CoverageEvidence>,
+}
+
+impl CoverageMapping {
+    /// Whether any function has measured coverage.
+    pub fn has_measured_data(&self) -> bool {
+        !self.measured.is_empty()
+    }
+
+    /// Whether a source function is covered. Measured data wins over the
+    /// call-graph inference when both exist.
+    pub fn is_covered(&self, function: NodeIndex) -> bool {
+        match self.measured.get(&function) {
+            Some(m) => m.is_covered(),
+            None => self.source_to_test.contains_key(&function),
+        }
+    }
+
+    /// Number of covered source functions, preferring measured data.
+    pub fn covered_source_functions(&self) -> usize {
+        let inferred_only = self
+            .source_to_test
+            .keys()
+            .filter(|f| !self.measured.contains_key(f))
+            .count();
+        let measured = self.measured.values().filter(|m| m.is_covered()).count();
+        inferred_only + measured
+    }
+}
+
+/// Measured line coverage for one function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MeasuredCoverage {
+    /// Instrumented lines inside the function.
+    pub lines_total: u32,
+    /// Instrumented lines executed at least once.
+    pub lines_hit: u32,
+}
+
+impl MeasuredCoverage {
+    pub fn is_covered(&self) -> bool {
+        self.lines_hit > 0
+    }
+
+    pub fn ratio(&self) -> f32 {
+        if self.lines_total == 0 {
+            return 0.0;
+        }
+        self.lines_hit as f32 / self.lines_total as f32
+    }
+}
+
+/// Evidence behind a function's coverage verdict.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CoverageEvidence {
+    /// Reached from a test through the call graph; no report covers the function.
+    Inferred,
+    /// Taken from an imported coverage report. Overrides the inference,
+    /// including when the report shows the function never ran.
+    Measured,
+    /// The report and the call graph agree the function is covered.
+    MeasuredAndInferred,
+}
+
+impl CoverageEvidence {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Inferred => "inferred",
+            Self::Measured => "measured",
+            Self::MeasuredAndInferred => "measured_and_inferred",
+        }
+    }
+}
+
+/// Supported coverage report formats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CoverageFormat {
+    Lcov,
+    Cobertura,
+    JaCoCo,
+    GoCoverprofile,
+}
+
+impl CoverageFormat {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Lcov => "lcov",
+            Self::Cobertura => "cobertura",
+            Self::JaCoCo => "jacoco",
+            Self::GoCoverprofile => "go_coverprofile",
+        }
+    }
+}
+
+/// Line hits imported from one or more coverage reports.
+#[derive(Debug, Clone, Default)]
+pub struct CoverageReport {
+    /// Normalized file path (as written in the report) → 1-based line → hit count.
+    files: FxHashMap<String, BTreeMap<u32, u64>>,
+    /// File name → report paths ending in it, for suffix lookups.
+    by_name: FxHashMap<String, Vec<String>>,
+    /// Formats merged into this report.
+    pub formats: Vec<CoverageFormat>,
+}
+
+impl CoverageReport {
+    /// Register a file even if it has no instrumented lines.
+    pub fn add_file(&mut self, file: &str) {
+        self.lines_mut(normalize_report_path(file));
+    }
+
+    /// Record hits for a line, keeping the highest count seen.
+    pub fn record(&mut self, file: &str, line: u32, hits: u64) {
+        let entry = self.lines_mut(normalize_report_path(file)).entry(line).or_insert(0);
+        *entry = (*entry).max(hits);
+    }
+
+    /// Merge another report into this one.
+    pub fn merge(&mut self, other: CoverageReport) {
+        for (file, lines) in other.files {
+            let target = self.lines_mut(file);
+            for (line, hits) in lines {
+                let entry = target.entry(line).or_insert(0);
+                *entry = (*entry).max(hits);
+            }
+        }
+        for format in other.formats {
+            if !self.formats.contains(&format) {
+                self.formats.push(format);
+            }
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    /// Number of files in the report.
+    pub fn len(&self) -> usize {
+        self.files.len()
+    }
+
+    /// Line hits for a project file. Report paths may be absolute or rooted
+    /// elsewhere (Go import paths, Java package dirs), so a match on whole
+    /// trailing path segments is accepted when the exact path isn't present.
+    pub fn lines_for(&self, file: &str) -> Option<&BTreeMap<u32, u64>> {
+        let file = normalize_report_path(file);
+        if let Some(lines) = self.files.get(&file) {
+            return Some(lines);
+        }
+        self.by_name
+            .get(file_name(&file))?
+            .iter()
+            .filter(|path| path_suffix_match(path, &file))
+            // Prefer the longest (most specific) report path.
+            .max_by_key(|path| path.len())
+            .and_then(|path| self.files.get(path))
+    }
+
+    fn lines_mut(&mut self, file: String) -> &mut BTreeMap<u32, u64> {
+        if !self.files.contains_key(&file) {
+            self.by_name.entry(file_name(&file).to_string()).or_default().push(file.clone());
+        }
+        self.files.entry(file).or_default()
+    }
+}
+
+fn normalize_report_path(path: &str) -> String {
+    let path = path.replace('\\', "/");
+    path.trim_start_matches("./").to_string()
+}
+
+fn file_name(path: &str) -> &str {
+    path.rsplit('/').next().unwrap_or(path)
+}
+
+fn path_suffix_match(a: &str, b: &str) -> bool {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    long.len() > short.len()
+        && long.ends_with(short)
+        && long.as_bytes()[long.len() - short.len() - 1] == b'/'
 }
 
 /// Result of minimum test set computation.
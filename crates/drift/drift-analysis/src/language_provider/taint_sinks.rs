@@ -31,6 +31,8 @@ pub enum SinkCategory {
     Logging,
     Redirect,
     Eval,
+    /// Unbounded copies and format strings (C/C++ `strcpy`, `sprintf`).
+    MemoryCorruption,
 }
 
 /// Severity of a taint sink.
@@ -54,7 +56,7 @@ pub fn extract_sinks(language: Language) -> Vec<TaintSink> {
         Language::Php => php_sinks(),
         Language::Rust => rust_sinks(),
         Language::Kotlin => kotlin_sinks(),
-        Language::Cpp | Language::C => csharp_sinks(),
+        Language::Cpp | Language::C => c_cpp_sinks(language),
         Language::Swift | Language::Scala => java_sinks(),
     }
 }
@@ -170,3 +172,25 @@ fn kotlin_sinks() -> Vec<TaintSink> {
         TaintSink { name: "redirect".into(), receiver: None, category: SinkCategory::Redirect, language: Language::Kotlin, tainted_params: vec![0], severity: SinkSeverity::Medium },
     ]
 }
+
+fn c_cpp_sinks(language: Language) -> Vec<TaintSink> {
+    vec![
+        TaintSink { name: "system".into(), receiver: None, category: SinkCategory::CommandExecution, language, tainted_params: vec![0], severity: SinkSeverity::Critical },
+        TaintSink { name: "popen".into(), receiver: None, category: SinkCategory::CommandExecution, language, tainted_params: vec![0], severity: SinkSeverity::Critical },
+        TaintSink { name: "execl".into(), receiver: None, category: SinkCategory::CommandExecution, language, tainted_params: vec![0, 1], severity: SinkSeverity::Critical },
+        TaintSink { name: "execlp".into(), receiver: None, category: SinkCategory::CommandExecution, language, tainted_params: vec![0, 1], severity: SinkSeverity::Critical },
+        TaintSink { name: "execv".into(), receiver: None, category: SinkCategory::CommandExecution, language, tainted_params: vec![0, 1], severity: SinkSeverity::Critical },
+        TaintSink { name: "execvp".into(), receiver: None, category: SinkCategory::CommandExecution, language, tainted_params: vec![0, 1], severity: SinkSeverity::Critical },
+        TaintSink { name: "strcpy".into(), receiver: None, category: SinkCategory::MemoryCorruption, language, tainted_params: vec![1], severity: SinkSeverity::High },
+        TaintSink { name: "strcat".into(), receiver: None, category: SinkCategory::MemoryCorruption, language, tainted_params: vec![1], severity: SinkSeverity::High },
+        TaintSink { name: "sprintf".into(), receiver: None, category: SinkCategory::MemoryCorruption, language, tainted_params: vec![1, 2], severity: SinkSeverity::High },
+        TaintSink { name: "vsprintf".into(), receiver: None, category: SinkCategory::MemoryCorruption, language, tainted_params: vec![1, 2], severity: SinkSeverity::High },
+        TaintSink { name: "printf".into(), receiver: None, category: SinkCategory::MemoryCorruption, language, tainted_params: vec![0], severity: SinkSeverity::Medium },
+        TaintSink { name: "fopen".into(), receiver: None, category: SinkCategory::FileRead, language, tainted_params: vec![0], severity: SinkSeverity::High },
+        // fwrite(ptr, size, nmemb, stream): like the other FileWrite sinks, the
+        // content written is the sink, so tainted data in `ptr` reaches the file.
+        TaintSink { name: "fwrite".into(), receiver: None, category: SinkCategory::FileWrite, language, tainted_params: vec![0], severity: SinkSeverity::High },
+        TaintSink { name: "mysql_query".into(), receiver: None, category: SinkCategory::SqlExecution, language, tainted_params: vec![1], severity: SinkSeverity::Critical },
+        TaintSink { name: "sqlite3_exec".into(), receiver: None, category: SinkCategory::SqlExecution, language, tainted_params: vec![1], severity: SinkSeverity::Critical },
+    ]
+}
@@ -0,0 +1,261 @@
+//! Cyclomatic and cognitive complexity from a function's AST.
+//!
+//! The walk is language-agnostic: it classifies tree-sitter node kinds from
+//! every supported grammar into branches, loops, switches, cases, handlers,
+//! ternaries and boolean operators. Only named nodes are classified, so
+//! keyword tokens (`if`, `for`) never match — Ruby's named `if` does.
+//!
+//! Cyclomatic complexity is 1 plus one per branch, loop, non-default case,
+//! catch, ternary and `&&`/`||`. Cognitive complexity follows the SonarSource
+//! rules: structures add 1 plus their nesting level, `else if`/`else` add a
+//! flat 1, as do each run of like boolean operators and each labelled jump.
+//! Nested functions and lambdas raise the nesting level but are counted
+//! toward the enclosing function. Recursion is not counted.
+
+use tree_sitter::Node;
+
+use super::types::ComplexityMetrics;
+
+const BRANCH_KINDS: &[&str] = &[
+    "if_statement", "if_expression", "if", "unless", "guard_statement",
+    "if_modifier", "unless_modifier",
+];
+
+const ELSE_IF_KINDS: &[&str] = &["elif_clause", "else_if_clause", "elsif"];
+
+/// Wrappers around an `else` body; an `if` alone inside one is an `else if`.
+/// Kotlin wraps both branches in `control_structure_body`.
+const ELSE_KINDS: &[&str] = &["else_clause", "else", "control_structure_body"];
+
+const LOOP_KINDS: &[&str] = &[
+    "for_statement", "for_in_statement", "enhanced_for_statement", "foreach_statement",
+    "for_range_loop", "for_expression", "while_statement", "while_expression",
+    "do_statement", "do_while_statement", "do_while_expression", "repeat_while_statement",
+    "loop_expression", "for", "while", "until", "while_modifier", "until_modifier",
+];
+
+const SWITCH_KINDS: &[&str] = &[
+    "switch_statement", "switch_expression", "expression_switch_statement",
+    "type_switch_statement", "select_statement", "match_statement", "match_expression",
+    "when_expression", "case",
+];
+
+const CASE_KINDS: &[&str] = &[
+    "switch_case", "switch_default", "case_clause", "switch_block_statement_group",
+    "switch_rule", "expression_case", "type_case", "communication_case", "default_case",
+    "match_arm", "switch_section", "switch_expression_arm", "case_statement",
+    "default_statement", "match_conditional_expression", "match_default_expression",
+    "when", "when_entry", "switch_entry",
+];
+
+const CATCH_KINDS: &[&str] = &["catch_clause", "except_clause", "rescue", "catch_block"];
+
+const TERNARY_KINDS: &[&str] = &["ternary_expression", "conditional_expression", "conditional"];
+
+const BOOLEAN_KINDS: &[&str] = &[
+    "binary_expression", "boolean_operator", "binary", "infix_expression",
+    "conjunction_expression", "disjunction_expression",
+];
+
+const FUNCTION_KINDS: &[&str] = &[
+    "function_declaration", "function_expression", "function_definition", "arrow_function",
+    "method_definition", "method_declaration", "generator_function",
+    "generator_function_declaration", "lambda", "lambda_expression", "lambda_literal",
+    "closure_expression", "func_literal", "anonymous_function",
+    "anonymous_function_creation_expression", "local_function_statement", "function_item",
+];
+
+/// Measure a function or lambda node. The body is walked when the grammar
+/// exposes one, otherwise the node's children are.
+pub fn measure(node: Node, source: &[u8], parameter_count: usize) -> ComplexityMetrics {
+    let mut walker = Walker { source, cyclomatic: 1, cognitive: 0, max_nesting: 0 };
+    match node.child_by_field_name("body") {
+        Some(body) => walker.visit(body, 0, 0),
+        None => walker.visit_children(node, 0, 0),
+    }
+    ComplexityMetrics {
+        cyclomatic: walker.cyclomatic,
+        cognitive: walker.cognitive,
+        max_nesting: walker.max_nesting,
+        parameter_count: parameter_count as u32,
+    }
+}
+
+struct Walker<'a> {
+    source: &'a [u8],
+    cyclomatic: u32,
+    cognitive: u32,
+    max_nesting: u32,
+}
+
+impl Walker<'_> {
+    /// `nesting` is the cognitive nesting level, `depth` the control
+    /// structure depth reported as `max_nesting`.
+    fn visit(&mut self, node: Node, nesting: u32, depth: u32) {
+        if !node.is_named() {
+            return;
+        }
+        let kind = node.kind();
+        if BRANCH_KINDS.contains(&kind) {
+            self.structure(nesting, depth);
+            self.cyclomatic += 1;
+            self.branches(node, nesting, depth);
+        } else if LOOP_KINDS.contains(&kind) || CATCH_KINDS.contains(&kind) {
+            self.structure(nesting, depth);
+            self.cyclomatic += 1;
+            self.visit_children(node, nesting + 1, depth + 1);
+        } else if SWITCH_KINDS.contains(&kind) {
+            self.structure(nesting, depth);
+            self.visit_children(node, nesting + 1, depth + 1);
+        } else if CASE_KINDS.contains(&kind) {
+            if !self.is_default_case(node) {
+                self.cyclomatic += 1;
+            }
+            self.visit_children(node, nesting, depth);
+        } else if TERNARY_KINDS.contains(&kind) {
+            self.cognitive += 1 + nesting;
+            self.cyclomatic += 1;
+            self.visit_children(node, nesting + 1, depth);
+        } else if BOOLEAN_KINDS.contains(&kind) {
+            if let Some(op) = self.boolean_operator(node) {
+                self.cyclomatic += 1;
+                let continues_run = node.parent().is_some_and(|p| self.boolean_operator(p) == Some(op));
+                if !continues_run {
+                    self.cognitive += 1;
+                }
+            }
+            self.visit_children(node, nesting, depth);
+        } else if FUNCTION_KINDS.contains(&kind) {
+            self.visit_children(node, nesting + 1, depth);
+        } else {
+            if self.is_labelled_jump(node) {
+                self.cognitive += 1;
+            }
+            self.visit_children(node, nesting, depth);
+        }
+    }
+
+    fn visit_children(&mut self, node: Node, nesting: u32, depth: u32) {
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            self.visit(child, nesting, depth);
+        }
+    }
+
+    /// A nesting structure: cognitive 1 + nesting, one level deeper.
+    fn structure(&mut self, nesting: u32, depth: u32) {
+        self.cognitive += 1 + nesting;
+        self.max_nesting = self.max_nesting.max(depth + 1);
+    }
+
+    /// Children of an `if`-like node at its level: the condition and
+    /// consequence nest one deeper; `else`/`elif` alternatives — by field,
+    /// by kind, or after an `else` keyword (Swift, Kotlin) — are chained.
+    fn branches(&mut self, node: Node, nesting: u32, depth: u32) {
+        let mut in_else = false;
+        let mut cursor = node.walk();
+        if !cursor.goto_first_child() {
+            return;
+        }
+        loop {
+            let child = cursor.node();
+            // Swift's `else` is a named node with no children.
+            if !child.is_named() || (child.kind() == "else" && child.named_child_count() == 0) {
+                in_else |= child.kind() == "else" && node.kind() != "guard_statement";
+            } else if in_else
+                || cursor.field_name() == Some("alternative")
+                || ELSE_IF_KINDS.contains(&child.kind())
+                || matches!(child.kind(), "else_clause" | "else")
+            {
+                self.alternative(child, nesting, depth);
+            } else {
+                self.visit(child, nesting + 1, depth + 1);
+            }
+            if !cursor.goto_next_sibling() {
+                break;
+            }
+        }
+    }
+
+    fn alternative(&mut self, node: Node, nesting: u32, depth: u32) {
+        let kind = node.kind();
+        if BRANCH_KINDS.contains(&kind) || ELSE_IF_KINDS.contains(&kind) {
+            // `else if`: a flat increment, no extra nesting.
+            self.cognitive += 1;
+            self.cyclomatic += 1;
+            self.branches(node, nesting, depth);
+            return;
+        }
+        if ELSE_KINDS.contains(&kind) {
+            if let Some(inner) = single_named_child(node) {
+                if BRANCH_KINDS.contains(&inner.kind()) || ELSE_IF_KINDS.contains(&inner.kind()) {
+                    self.alternative(inner, nesting, depth);
+                    return;
+                }
+            }
+            self.cognitive += 1;
+            self.visit_children(node, nesting + 1, depth + 1);
+            return;
+        }
+        self.cognitive += 1;
+        self.visit(node, nesting + 1, depth + 1);
+    }
+
+    /// `&&` or `||` (and their keyword spellings) for a boolean node.
+    fn boolean_operator(&self, node: Node) -> Option<&'static str> {
+        match node.kind() {
+            "conjunction_expression" => return Some("&&"),
+            "disjunction_expression" => return Some("||"),
+            k if !BOOLEAN_KINDS.contains(&k) => return None,
+            _ => {}
+        }
+        let op = node.child_by_field_name("operator")?;
+        match op.utf8_text(self.source).ok()? {
+            "&&" | "and" => Some("&&"),
+            "||" | "or" => Some("||"),
+            _ => None,
+        }
+    }
+
+    fn is_default_case(&self, node: Node) -> bool {
+        if node.kind().contains("default") {
+            return true;
+        }
+        if let Some(first) = node.child(0) {
+            if first.kind().contains("default") || first.kind() == "else" {
+                return true;
+            }
+        }
+        node.named_child(0)
+            .and_then(|n| n.utf8_text(self.source).ok())
+            .is_some_and(|text| {
+                let text = text.trim();
+                text == "_" || text.starts_with("default")
+            })
+    }
+
+    /// `goto`, and `break`/`continue` to a label.
+    fn is_labelled_jump(&self, node: Node) -> bool {
+        match node.kind() {
+            "goto_statement" => true,
+            "break_statement" | "continue_statement" => has_named_child(
+                node, &["statement_identifier", "label_name", "identifier", "simple_identifier"],
+            ),
+            "break_expression" | "continue_expression" => has_named_child(node, &["label", "loop_label"]),
+            _ => false,
+        }
+    }
+}
+
+fn single_named_child(node: Node) -> Option<Node> {
+    let mut cursor = node.walk();
+    let mut named = node.named_children(&mut cursor).filter(|c| !c.kind().contains("comment"));
+    let first = named.next()?;
+    named.next().is_none().then_some(first)
+}
+
+fn has_named_child(node: Node, kinds: &[&str]) -> bool {
+    let mut cursor = node.walk();
+    let found = node.named_children(&mut cursor).any(|c| kinds.contains(&c.kind()));
+    found
+}
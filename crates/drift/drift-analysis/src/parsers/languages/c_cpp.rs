@@ -0,0 +1,689 @@
+//! C and C++ parsers.
+//!
+//! The C-family grammars don't fit the generic walker in `languages/mod.rs`:
+//! function names sit inside nested declarators, imports are `#include`
+//! directives, and out-of-line methods are qualified (`Server::stop`). This
+//! module owns structural and call extraction for both grammars and reuses the
+//! shared literal and doc-comment helpers from the parent module.
+
+use std::path::Path;
+
+use drift_core::errors::ParseError;
+use smallvec::SmallVec;
+use tree_sitter::Node;
+
+use super::{extract_doc_comment_for_node, extract_numeric_literal, extract_string_literal, node_text, parse_with_language};
+use crate::parsers::complexity::measure;
+use crate::parsers::traits::LanguageParser;
+use crate::parsers::types::*;
+use crate::scanner::hasher::hash_content;
+use crate::scanner::language_detect::Language;
+
+pub struct CParser;
+
+impl Default for CParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CParser {
+    pub fn new() -> Self { Self }
+}
+
+impl LanguageParser for CParser {
+    fn language(&self) -> Language { Language::C }
+    fn extensions(&self) -> &[&str] { &["c", "h"] }
+
+    fn parse(&self, source: &[u8], path: &Path) -> Result<ParseResult, ParseError> {
+        parse_with_language(source, path, Language::C, tree_sitter_c::LANGUAGE.into())
+    }
+}
+
+pub struct CppParser;
+
+impl Default for CppParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CppParser {
+    pub fn new() -> Self { Self }
+}
+
+impl LanguageParser for CppParser {
+    fn language(&self) -> Language { Language::Cpp }
+    fn extensions(&self) -> &[&str] { &["cpp", "cc", "cxx", "hpp", "hxx", "hh"] }
+
+    fn parse(&self, source: &[u8], path: &Path) -> Result<ParseResult, ParseError> {
+        parse_with_language(source, path, Language::Cpp, tree_sitter_cpp::LANGUAGE.into())
+    }
+}
+
+/// Extract functions, classes, namespaces, includes, call sites, literals and
+/// error handling from a C or C++ syntax tree.
+pub(super) fn extract_c_family(result: &mut ParseResult, root: Node, source: &[u8], file: &str) {
+    let mut namespaces = Vec::new();
+    walk(result, root, source, file, &mut namespaces, 0);
+}
+
+fn walk(
+    result: &mut ParseResult,
+    node: Node,
+    source: &[u8],
+    file: &str,
+    namespaces: &mut Vec<String>,
+    depth: usize,
+) {
+    match node.kind() {
+        "function_definition" => {
+            let internal = in_anonymous_namespace(namespaces);
+            if let Some(func) = extract_function(node, source, file, None, internal) {
+                result.functions.push(func);
+            }
+        }
+        // Bodyless specifiers are forward declarations or type references.
+        "class_specifier" | "struct_specifier" | "union_specifier"
+            if node.child_by_field_name("body").is_some() =>
+        {
+            if let Some(class) = extract_class(node, source, file, namespaces) {
+                result.classes.push(class);
+            }
+        }
+        "enum_specifier" if node.child_by_field_name("body").is_some() => {
+            if let Some(class) = extract_enum(node, source, namespaces) {
+                result.classes.push(class);
+            }
+        }
+        "namespace_definition" => {
+            let name = node.child_by_field_name("name")
+                .map(|n| node_text(n, source))
+                .unwrap_or_default();
+            namespaces.push(name);
+            let qualified = qualified_namespace(namespaces);
+            // Keep the deepest namespace of the first namespace chain in the file.
+            let extends_current = match &result.namespace {
+                None => true,
+                Some(current) => qualified.starts_with(&format!("{}::", current)),
+            };
+            if extends_current && !qualified.is_empty() {
+                result.namespace = Some(qualified);
+            }
+            if let Some(body) = node.child_by_field_name("body") {
+                walk_children(result, body, source, file, namespaces, depth);
+            }
+            namespaces.pop();
+            return;
+        }
+        "preproc_include" => {
+            if let Some(path) = node.child_by_field_name("path") {
+                let include = node_text(path, source)
+                    .trim_matches(|c| c == '"' || c == '<' || c == '>')
+                    .to_string();
+                if !include.is_empty() {
+                    result.imports.push(ImportInfo {
+                        source: include,
+                        specifiers: SmallVec::new(),
+                        is_type_only: false,
+                        file: file.to_string(),
+                        line: node.start_position().row as u32,
+                    });
+                }
+            }
+        }
+        "call_expression" => {
+            if let Some(call) = extract_call_site(node, source, file) {
+                result.call_sites.push(call);
+            }
+        }
+        "string_literal" | "raw_string_literal" => {
+            if let Some(lit) = extract_string_literal(node, source, file) {
+                result.string_literals.push(lit);
+            }
+            // String content children carry no extra information.
+            return;
+        }
+        "number_literal" => {
+            if let Some(lit) = extract_numeric_literal(node, source, file) {
+                result.numeric_literals.push(lit);
+            }
+        }
+        "comment" => {
+            let text = node_text(node, source);
+            let trimmed = text.trim();
+            // Doxygen: /** ... */ and /// ... (plus //! for file-level docs)
+            let style = if trimmed.starts_with("/**") && trimmed.ends_with("*/") {
+                Some(DocCommentStyle::JsDoc)
+            } else if trimmed.starts_with("///") || trimmed.starts_with("//!") {
+                Some(DocCommentStyle::TripleSlash)
+            } else {
+                None
+            };
+            if let Some(style) = style {
+                result.doc_comments.push(DocCommentInfo {
+                    text: text.clone(),
+                    style,
+                    file: file.to_string(),
+                    line: node.start_position().row as u32,
+                    range: Range::from_ts_node(&node),
+                });
+            }
+        }
+        "try_statement" => {
+            let mut caught_type = None;
+            let mut has_body = true;
+            for i in 0..node.child_count() {
+                if let Some(child) = node.child(i) {
+                    if child.kind() == "catch_clause" {
+                        caught_type = extract_catch_type(child, source);
+                        has_body = child.child_by_field_name("body")
+                            .is_some_and(|b| b.named_child_count() > 0);
+                    }
+                }
+            }
+            result.error_handling.push(ErrorHandlingInfo {
+                kind: ErrorHandlingKind::TryCatch,
+                file: file.to_string(),
+                line: node.start_position().row as u32,
+                end_line: node.end_position().row as u32,
+                range: Range::from_ts_node(&node),
+                caught_type,
+                has_body,
+                function_scope: enclosing_function_name(node, source),
+            });
+        }
+        "throw_statement" => {
+            result.error_handling.push(ErrorHandlingInfo {
+                kind: ErrorHandlingKind::Throw,
+                file: file.to_string(),
+                line: node.start_position().row as u32,
+                end_line: node.end_position().row as u32,
+                range: Range::from_ts_node(&node),
+                caught_type: None,
+                has_body: false,
+                function_scope: enclosing_function_name(node, source),
+            });
+        }
+        _ => {}
+    }
+
+    walk_children(result, node, source, file, namespaces, depth);
+}
+
+fn walk_children(
+    result: &mut ParseResult,
+    node: Node,
+    source: &[u8],
+    file: &str,
+    namespaces: &mut Vec<String>,
+    depth: usize,
+) {
+    if depth >= 50 {
+        return;
+    }
+    let mut cursor = node.walk();
+    if cursor.goto_first_child() {
+        loop {
+            walk(result, cursor.node(), source, file, namespaces, depth + 1);
+            if !cursor.goto_next_sibling() {
+                break;
+            }
+        }
+    }
+}
+
+// ---- Declarations ----
+
+/// Extract a function or method definition.
+///
+/// `class_name` is set for definitions inside a class body; out-of-line
+/// definitions (`void Server::stop()`) derive it from the qualified declarator.
+fn extract_function(
+    node: Node,
+    source: &[u8],
+    file: &str,
+    class_name: Option<&str>,
+    internal_linkage: bool,
+) -> Option<FunctionInfo> {
+    let declarator = node.child_by_field_name("declarator")?;
+    let func_declarator = find_function_declarator(declarator)?;
+    let name_node = func_declarator.child_by_field_name("declarator")?;
+
+    let (name, scope) = split_declarator_name(name_node, source);
+    if name.is_empty() {
+        return None;
+    }
+    let owner = class_name.map(str::to_string).or(scope);
+    let qualified_name = owner.map(|o| format!("{}.{}", o, name));
+
+    let return_type = node.child_by_field_name("type").map(|t| {
+        let mut ty = node_text(t, source);
+        match declarator.kind() {
+            "pointer_declarator" => ty.push('*'),
+            "reference_declarator" => ty.push('&'),
+            _ => {}
+        }
+        ty
+    });
+
+    let parameters = func_declarator.child_by_field_name("parameters")
+        .map(|p| extract_parameters(p, source))
+        .unwrap_or_default();
+    let params_text = func_declarator.child_by_field_name("parameters")
+        .map(|p| node_text(p, source))
+        .unwrap_or_default();
+    let sig_return = return_type.as_deref().unwrap_or("");
+    let sig_hash = hash_content(format!("{}({}){}", name, params_text, sig_return).as_bytes());
+
+    let body_text = node.child_by_field_name("body")
+        .map(|b| node_text(b, source))
+        .unwrap_or_default();
+
+    let is_static = has_storage_class(node, source, "static");
+    let visibility = if class_name.is_none() && is_static {
+        // File-local linkage is the closest C analogue to private.
+        Visibility::Private
+    } else {
+        Visibility::Public
+    };
+
+    // Templates wrap the definition; doc comments attach to the wrapper.
+    let outer = template_parent(node).unwrap_or(node);
+
+    let complexity = measure(node, source, parameters.len());
+    Some(FunctionInfo {
+        name,
+        qualified_name,
+        file: file.to_string(),
+        line: node.start_position().row as u32,
+        column: node.start_position().column as u32,
+        end_line: node.end_position().row as u32,
+        parameters,
+        return_type,
+        generic_params: extract_template_params(node, source),
+        visibility,
+        is_exported: !is_static && !internal_linkage,
+        is_async: false,
+        is_generator: false,
+        is_abstract: false,
+        range: Range::from_ts_node(&node),
+        decorators: Vec::new(),
+        doc_comment: extract_doc_comment_for_node(outer, source),
+        body_hash: hash_content(body_text.as_bytes()),
+        signature_hash: sig_hash,
+        complexity,
+    })
+}
+
+fn extract_class(node: Node, source: &[u8], file: &str, namespaces: &[String]) -> Option<ClassInfo> {
+    let name = specifier_name(node, source)?;
+    let class_kind = match node.kind() {
+        "class_specifier" => ClassKind::Class,
+        "union_specifier" => ClassKind::Union,
+        _ => ClassKind::Struct,
+    };
+
+    // C++ bases: the first is treated as the superclass, the rest as mixins.
+    let mut bases: Vec<String> = Vec::new();
+    for i in 0..node.named_child_count() {
+        if let Some(child) = node.named_child(i) {
+            if child.kind() == "base_class_clause" {
+                for j in 0..child.named_child_count() {
+                    if let Some(base) = child.named_child(j) {
+                        if matches!(base.kind(), "type_identifier" | "qualified_identifier" | "template_type") {
+                            bases.push(node_text(base, source));
+                        }
+                    }
+                }
+            }
+        }
+    }
+    let mut bases = bases.into_iter();
+    let extends = bases.next();
+    let implements: SmallVec<[String; 2]> = bases.collect();
+
+    // `class` members default to private, `struct`/`union` members to public.
+    let mut access = if class_kind == ClassKind::Class { Visibility::Private } else { Visibility::Public };
+    let mut methods = Vec::new();
+    let mut properties = Vec::new();
+    let mut is_abstract = false;
+
+    if let Some(body) = node.child_by_field_name("body") {
+        for i in 0..body.named_child_count() {
+            let Some(child) = body.named_child(i) else { continue };
+            match child.kind() {
+                "access_specifier" => {
+                    access = match node_text(child, source).trim() {
+                        "private" => Visibility::Private,
+                        "protected" => Visibility::Protected,
+                        _ => Visibility::Public,
+                    };
+                }
+                "function_definition" | "template_declaration" => {
+                    let def = if child.kind() == "template_declaration" {
+                        (0..child.named_child_count())
+                            .filter_map(|j| child.named_child(j))
+                            .find(|c| c.kind() == "function_definition")
+                    } else {
+                        Some(child)
+                    };
+                    if let Some(def) = def {
+                        if let Some(mut func) = extract_function(def, source, file, Some(&name), false) {
+                            func.visibility = access;
+                            func.is_exported = access == Visibility::Public;
+                            methods.push(func);
+                        }
+                    }
+                }
+                "field_declaration" => {
+                    let declarator = child.child_by_field_name("declarator");
+                    if declarator.is_some_and(|d| find_function_declarator(d).is_some()) {
+                        // Method prototype; `= 0` marks a pure virtual.
+                        if child.child_by_field_name("default_value")
+                            .is_some_and(|v| node_text(v, source).trim() == "0")
+                        {
+                            is_abstract = true;
+                        }
+                    } else if let Some(prop_name) = declarator.and_then(|d| innermost_identifier(d, source)) {
+                        let type_annotation = child.child_by_field_name("type").map(|t| node_text(t, source));
+                        properties.push(PropertyInfo {
+                            name: prop_name,
+                            type_annotation,
+                            is_static: has_storage_class(child, source, "static"),
+                            is_readonly: has_type_qualifier(child, source, "const"),
+                            visibility: access,
+                            line: child.start_position().row as u32,
+                            decorators: Vec::new(),
+                            tag: None,
+                            initializer: None,
+                        });
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let namespace = qualified_namespace(namespaces);
+
+    Some(ClassInfo {
+        name,
+        namespace: if namespace.is_empty() { None } else { Some(namespace) },
+        extends,
+        implements,
+        generic_params: extract_template_params(node, source),
+        is_exported: !in_anonymous_namespace(namespaces),
+        is_abstract,
+        class_kind,
+        methods,
+        properties,
+        range: Range::from_ts_node(&node),
+        decorators: Vec::new(),
+    })
+}
+
+fn extract_enum(node: Node, source: &[u8], namespaces: &[String]) -> Option<ClassInfo> {
+    let name = specifier_name(node, source)?;
+    let namespace = qualified_namespace(namespaces);
+    Some(ClassInfo {
+        name,
+        namespace: if namespace.is_empty() { None } else { Some(namespace) },
+        extends: None,
+        implements: SmallVec::new(),
+        generic_params: SmallVec::new(),
+        is_exported: !in_anonymous_namespace(namespaces),
+        is_abstract: false,
+        class_kind: ClassKind::Enum,
+        methods: Vec::new(),
+        properties: Vec::new(),
+        range: Range::from_ts_node(&node),
+        decorators: Vec::new(),
+    })
+}
+
+fn extract_parameters(param_list: Node, source: &[u8]) -> SmallVec<[ParameterInfo; 4]> {
+    let mut params = SmallVec::new();
+    for i in 0..param_list.named_child_count() {
+        let Some(child) = param_list.named_child(i) else { continue };
+        match child.kind() {
+            "parameter_declaration" | "optional_parameter_declaration" => {
+                let type_annotation = child.child_by_field_name("type").map(|t| node_text(t, source));
+                let declarator = child.child_by_field_name("declarator");
+                // C's `f(void)` declares no parameters.
+                if declarator.is_none() && type_annotation.as_deref() == Some("void") {
+                    continue;
+                }
+                params.push(ParameterInfo {
+                    name: declarator
+                        .and_then(|d| innermost_identifier(d, source))
+                        .unwrap_or_default(),
+                    type_annotation,
+                    default_value: child.child_by_field_name("default_value").map(|d| node_text(d, source)),
+                    is_rest: false,
+                });
+            }
+            "variadic_parameter_declaration" | "variadic_parameter" => {
+                params.push(ParameterInfo {
+                    name: child.child_by_field_name("declarator")
+                        .and_then(|d| innermost_identifier(d, source))
+                        .unwrap_or_else(|| "...".to_string()),
+                    type_annotation: child.child_by_field_name("type").map(|t| node_text(t, source)),
+                    default_value: None,
+                    is_rest: true,
+                });
+            }
+            _ => {}
+        }
+    }
+    params
+}
+
+fn extract_template_params(node: Node, source: &[u8]) -> SmallVec<[GenericParam; 2]> {
+    let mut generics = SmallVec::new();
+    let Some(template) = template_parent(node) else { return generics };
+    let Some(list) = template.child_by_field_name("parameters") else { return generics };
+    for i in 0..list.named_child_count() {
+        let Some(param) = list.named_child(i) else { continue };
+        let name = match param.kind() {
+            "type_parameter_declaration" | "variadic_type_parameter_declaration"
+            | "optional_type_parameter_declaration" => (0..param.named_child_count())
+                .filter_map(|j| param.named_child(j))
+                .find(|c| c.kind() == "type_identifier")
+                .map(|c| node_text(c, source)),
+            "parameter_declaration" | "optional_parameter_declaration" => param
+                .child_by_field_name("declarator")
+                .and_then(|d| innermost_identifier(d, source)),
+            _ => None,
+        };
+        if let Some(name) = name {
+            generics.push(GenericParam { name, bounds: SmallVec::new() });
+        }
+    }
+    generics
+}
+
+// ---- Calls ----
+
+fn extract_call_site(node: Node, source: &[u8], file: &str) -> Option<CallSite> {
+    let function = node.child_by_field_name("function")?;
+    let (callee_name, receiver) = match function.kind() {
+        "identifier" => (node_text(function, source), None),
+        // obj.method() / ptr->method() / obj.template method<T>()
+        "field_expression" => {
+            let field = function.child_by_field_name("field")?;
+            let method = if field.kind() == "template_method" {
+                field.child_by_field_name("name").map(|n| node_text(n, source))?
+            } else {
+                node_text(field, source)
+            };
+            let receiver = function.child_by_field_name("argument").map(|a| node_text(a, source));
+            (method, receiver)
+        }
+        // ns::func() / Class::staticMethod()
+        "qualified_identifier" => {
+            let (name, scope) = split_declarator_name(function, source);
+            (name, scope)
+        }
+        // func<T>()
+        "template_function" => {
+            let name = function.child_by_field_name("name")?;
+            let (name, scope) = split_declarator_name(name, source);
+            (name, scope)
+        }
+        _ => return None,
+    };
+    if callee_name.is_empty() {
+        return None;
+    }
+
+    let argument_count = node.child_by_field_name("arguments")
+        .map(|a| a.named_child_count().min(u8::MAX as usize) as u8)
+        .unwrap_or(0);
+
+    Some(CallSite {
+        callee_name,
+        receiver,
+        file: file.to_string(),
+        line: node.start_position().row as u32,
+        column: node.start_position().column as u32,
+        argument_count,
+        is_await: node.parent().is_some_and(|p| p.kind() == "co_await_expression"),
+    })
+}
+
+fn extract_catch_type(node: Node, source: &[u8]) -> Option<String> {
+    let params = node.child_by_field_name("parameters")?;
+    for i in 0..params.named_child_count() {
+        if let Some(param) = params.named_child(i) {
+            if let Some(ty) = param.child_by_field_name("type") {
+                return Some(node_text(ty, source));
+            }
+        }
+    }
+    // catch (...)
+    let text = node_text(params, source);
+    if text.contains("...") { Some("...".to_string()) } else { None }
+}
+
+// ---- Helpers ----
+
+/// Descend through pointer/reference/parenthesized declarators to the function declarator.
+fn find_function_declarator(node: Node) -> Option<Node> {
+    let mut current = node;
+    for _ in 0..8 {
+        match current.kind() {
+            "function_declarator" => return Some(current),
+            "pointer_declarator" | "reference_declarator" | "parenthesized_declarator"
+            | "attributed_declarator" => {
+                current = current.child_by_field_name("declarator")
+                    .or_else(|| current.named_child(current.named_child_count().checked_sub(1)?))?;
+            }
+            _ => return None,
+        }
+    }
+    None
+}
+
+/// Split a (possibly qualified) declarator name into `(name, immediate scope)`.
+///
+/// `a::Server::stop` → `("stop", Some("Server"))`, `run` → `("run", None)`.
+fn split_declarator_name(node: Node, source: &[u8]) -> (String, Option<String>) {
+    if node.kind() == "qualified_identifier" {
+        let scope = node.child_by_field_name("scope").map(|s| {
+            let text = node_text(s, source);
+            // Strip template arguments, then keep the innermost scope segment.
+            let base = text.split('<').next().unwrap_or(&text).to_string();
+            base.rsplit("::").next().unwrap_or(&base).to_string()
+        });
+        let name = node.child_by_field_name("name")
+            .map(|n| split_declarator_name(n, source).0)
+            .unwrap_or_default();
+        return (name, scope.filter(|s| !s.is_empty()));
+    }
+    if node.kind() == "template_function" || node.kind() == "template_method" {
+        if let Some(name) = node.child_by_field_name("name") {
+            return split_declarator_name(name, source);
+        }
+    }
+    (node_text(node, source), None)
+}
+
+/// Find the declared identifier at the bottom of a declarator chain
+/// (`*const name[4]` → `name`).
+fn innermost_identifier(node: Node, source: &[u8]) -> Option<String> {
+    let mut current = node;
+    for _ in 0..8 {
+        match current.kind() {
+            "identifier" | "field_identifier" => return Some(node_text(current, source)),
+            _ => {
+                current = current.child_by_field_name("declarator")
+                    .or_else(|| current.named_child(0))?;
+            }
+        }
+    }
+    None
+}
+
+/// Name of a class/struct/union/enum specifier, falling back to the typedef
+/// name for `typedef struct { ... } Name;`.
+fn specifier_name(node: Node, source: &[u8]) -> Option<String> {
+    if let Some(name) = node.child_by_field_name("name") {
+        let text = node_text(name, source);
+        if !text.is_empty() {
+            return Some(text);
+        }
+    }
+    let parent = node.parent()?;
+    if parent.kind() == "type_definition" {
+        let declarator = parent.child_by_field_name("declarator")?;
+        return Some(node_text(declarator, source));
+    }
+    None
+}
+
+fn template_parent(node: Node) -> Option<Node> {
+    node.parent().filter(|p| p.kind() == "template_declaration")
+}
+
+fn has_storage_class(node: Node, source: &[u8], class: &str) -> bool {
+    (0..node.named_child_count())
+        .filter_map(|i| node.named_child(i))
+        .any(|c| c.kind() == "storage_class_specifier" && node_text(c, source) == class)
+}
+
+fn has_type_qualifier(node: Node, source: &[u8], qualifier: &str) -> bool {
+    (0..node.named_child_count())
+        .filter_map(|i| node.named_child(i))
+        .any(|c| c.kind() == "type_qualifier" && node_text(c, source) == qualifier)
+}
+
+fn qualified_namespace(namespaces: &[String]) -> String {
+    namespaces.iter()
+        .filter(|n| !n.is_empty())
+        .cloned()
+        .collect::<Vec<_>>()
+        .join("::")
+}
+
+/// Anonymous namespaces give their contents internal linkage.
+fn in_anonymous_namespace(namespaces: &[String]) -> bool {
+    namespaces.iter().any(|n| n.is_empty())
+}
+
+/// Walk up to the nearest enclosing function definition and return its name.
+fn enclosing_function_name(node: Node, source: &[u8]) -> Option<String> {
+    let mut current = node.parent();
+    while let Some(parent) = current {
+        if parent.kind() == "function_definition" {
+            let declarator = parent.child_by_field_name("declarator")?;
+            let name_node = find_function_declarator(declarator)?.child_by_field_name("declarator")?;
+            return Some(split_declarator_name(name_node, source).0);
+        }
+        if parent.kind() == "lambda_expression" {
+            return None;
+        }
+        current = parent.parent();
+    }
+    None
+}
@@ -1,5 +1,6 @@
 //! Per-language parser implementations.
 
+pub mod c_cpp;
 pub mod csharp;
 pub mod go;
 pub mod java;
@@ -9,6 +10,8 @@ pub mod php;
 pub mod python;
 pub mod ruby;
 pub mod rust_lang;
+pub mod scala;
+pub mod swift;
 pub mod typescript;
 
 use std::path::Path;
@@ -18,6 +21,7 @@ use drift_core::errors::ParseError;
 use smallvec::SmallVec;
 use tree_sitter::{Node, Parser};
 
+use super::complexity::measure;
 use super::error_tolerant::count_errors;
 use super::types::*;
 use crate::scanner::language_detect::Language;
@@ -82,8 +86,15 @@ pub fn parse_with_language_and_tree(
     };
 
     // Extract functions, classes, imports, exports from the tree
-    extract_structure(&mut result, root, source, &file_str);
-    extract_calls(&mut result, root, source, &file_str);
+    match language {
+        Language::C | Language::Cpp => c_cpp::extract_c_family(&mut result, root, source, &file_str),
+        Language::Swift => swift::extract_swift(&mut result, root, source, &file_str),
+        Language::Scala => scala::extract_scala(&mut result, root, source, &file_str),
+        _ => {
+            extract_structure(&mut result, root, source, &file_str);
+            extract_calls(&mut result, root, source, &file_str);
+        }
+    }
 
     result.parse_time_us = start.elapsed().as_micros() as u64;
     Ok((result, tree))
@@ -315,6 +326,10 @@ fn extract_calls_recursive(
                 result.call_sites.push(call);
             }
         }
+        "variable_declarator" | "assignment_expression" | "assignment" | "property_declaration"
+        | "short_var_declaration" | "var_spec" | "parameter_declaration" => {
+            extract_type_bindings(node, source, &mut result.type_bindings);
+        }
         "decorator" | "attribute" | "attribute_item" | "annotation"
         | "marker_annotation" => {
             if let Some(dec) = extract_decorator(node, source) {
@@ -547,6 +562,9 @@ fn extract_function(node: Node, source: &[u8], file: &str) -> Option<FunctionInf
     // DP-FUNC-04: Link decorators from previous siblings
     let decorators = extract_decorators_for_node(node, source);
 
+    let parameters = extract_parameters(node, source);
+    let complexity = measure(node, source, parameters.len());
+
     Some(FunctionInfo {
         name: name.clone(),
         qualified_name: None,
@@ -554,7 +572,7 @@ fn extract_function(node: Node, source: &[u8], file: &str) -> Option<FunctionInf
         line: node.start_position().row as u32,
         column: node.start_position().column as u32,
         end_line: node.end_position().row as u32,
-        parameters: extract_parameters(node, source),
+        parameters,
         return_type,
         generic_params,
         visibility,
@@ -567,6 +585,7 @@ fn extract_function(node: Node, source: &[u8], file: &str) -> Option<FunctionInf
         doc_comment,
         body_hash: hash_content(body_text.as_bytes()),
         signature_hash: sig_hash,
+        complexity,
     })
 }
 
@@ -595,6 +614,8 @@ fn extract_arrow_function(node: Node, source: &[u8], file: &str) -> Option<Funct
 
     let generic_params = extract_generic_params(node, source);
     let doc_comment = extract_doc_comment_for_node(node, source);
+    let parameters = extract_parameters(node, source);
+    let complexity = measure(node, source, parameters.len());
 
     Some(FunctionInfo {
         name,
@@ -603,7 +624,7 @@ fn extract_arrow_function(node: Node, source: &[u8], file: &str) -> Option<Funct
         line: node.start_position().row as u32,
         column: node.start_position().column as u32,
         end_line: node.end_position().row as u32,
-        parameters: extract_parameters(node, source),
+        parameters,
         return_type: None,
         generic_params,
         visibility: Visibility::Public,
@@ -616,17 +637,22 @@ fn extract_arrow_function(node: Node, source: &[u8], file: &str) -> Option<Funct
         doc_comment,
         body_hash: hash_content(body_text.as_bytes()),
         signature_hash: 0,
+        complexity,
     })
 }
 
-fn extract_class(node: Node, source: &[u8], file: &str, _lang: Language) -> Option<ClassInfo> {
+fn extract_class(node: Node, source: &[u8], file: &str, lang: Language) -> Option<ClassInfo> {
     let name = find_child_text(&node, source, &[
         "identifier", "type_identifier", "constant", "name",
     ])?;
 
     let extends = node.child_by_field_name("superclass")
         .or_else(|| find_child_by_kind(&node, "class_heritage"))
-        .and_then(|n| extract_text_from_node(n, source));
+        .and_then(|n| extract_text_from_node(n, source))
+        .or_else(|| match lang {
+            Language::Python => python_base_class(node, source),
+            _ => None,
+        });
 
     // DP-CLASS-01: Extract implements
     let implements = extract_implements(node, source);
@@ -639,20 +665,41 @@ fn extract_class(node: Node, source: &[u8], file: &str, _lang: Language) -> Opti
     let is_exported = detect_is_exported(node, source, &name, visibility);
 
     // DP-CLASS-03: Link decorators to classes
-    let decorators = extract_decorators_for_node(node, source);
+    let decorators = extract_member_decorators(node, source);
 
     let mut methods = Vec::new();
     let mut properties = Vec::new();
 
+    // Kotlin `class A(private val repo: Repo)` declares properties in its primary constructor.
+    if let Some(constructor) = find_child_by_kind(&node, "primary_constructor") {
+        let mut cursor = constructor.walk();
+        for parameter in constructor.named_children(&mut cursor).filter(|c| c.kind() == "class_parameter") {
+            let Some(name) = find_child_text(&parameter, source, &["simple_identifier"]) else { continue };
+            properties.push(PropertyInfo {
+                name,
+                type_annotation: find_kotlin_type(parameter).map(|t| node_text(t, source)),
+                is_static: false,
+                is_readonly: find_child_by_kind(&parameter, "binding_pattern_kind")
+                    .is_some_and(|k| node_text(k, source) == "val"),
+                visibility: Visibility::Public,
+                line: parameter.start_position().row as u32,
+                decorators: Vec::new(),
+                tag: None,
+                initializer: None,
+            });
+        }
+    }
+
     // Extract methods and properties from class body
-    if let Some(body) = node.child_by_field_name("body") {
+    let body = node.child_by_field_name("body").or_else(|| find_child_by_kind(&node, "class_body"));
+    if let Some(body) = body {
         let mut cursor = body.walk();
         if cursor.goto_first_child() {
             loop {
                 let child = cursor.node();
                 match child.kind() {
                     "method_definition" | "method_declaration" | "method"
-                    | "function_definition" | "function_item" => {
+                    | "function_definition" | "function_item" | "function_declaration" => {
                         if let Some(mut func) = extract_function(child, source, file) {
                             func.qualified_name = Some(format!("{}.{}", name, func.name));
                             methods.push(func);
@@ -688,6 +735,16 @@ fn extract_class(node: Node, source: &[u8], file: &str, _lang: Language) -> Opti
     })
 }
 
+/// First base of a Python class: `models.Model` in
+/// `class User(models.Model, metaclass=M)`. Keyword arguments are not bases.
+fn python_base_class(node: Node, source: &[u8]) -> Option<String> {
+    let bases = node.child_by_field_name("superclasses")?;
+    let mut cursor = bases.walk();
+    let first = bases.named_children(&mut cursor)
+        .find(|b| matches!(b.kind(), "identifier" | "attribute" | "subscript"));
+    first.map(|b| node_text(b, source))
+}
+
 fn extract_interface(node: Node, source: &[u8], _file: &str) -> Option<ClassInfo> {
     let name = find_child_text(&node, source, &["identifier", "type_identifier", "name"])?;
     let generic_params = extract_generic_params(node, source);
@@ -705,7 +762,7 @@ fn extract_interface(node: Node, source: &[u8], _file: &str) -> Option<ClassInfo
         methods: Vec::new(),
         properties: Vec::new(),
         range: Range::from_ts_node(&node),
-        decorators: extract_decorators_for_node(node, source),
+        decorators: extract_member_decorators(node, source),
     })
 }
 
@@ -724,12 +781,71 @@ fn extract_struct(node: Node, source: &[u8], _file: &str) -> Option<ClassInfo> {
         is_abstract: false,
         class_kind: ClassKind::Struct,
         methods: Vec::new(),
-        properties: Vec::new(),
+        properties: extract_struct_fields(node, source),
         range: Range::from_ts_node(&node),
-        decorators: Vec::new(),
+        decorators: extract_decorators_for_node(node, source),
     })
 }
 
+/// Fields of a Rust `struct_item` or a Go `type_spec`. A Go embedded field
+/// has no name of its own and is recorded under its type, e.g. `gorm.Model`.
+fn extract_struct_fields(node: Node, source: &[u8]) -> Vec<PropertyInfo> {
+    let list = node.child_by_field_name("body").or_else(|| {
+        let struct_type = node.child_by_field_name("type")?;
+        let mut cursor = struct_type.walk();
+        let list = struct_type.named_children(&mut cursor).find(|c| c.kind() == "field_declaration_list");
+        list
+    });
+    let Some(list) = list else { return Vec::new() };
+
+    let mut properties = Vec::new();
+    let mut cursor = list.walk();
+    for field in list.named_children(&mut cursor) {
+        if field.kind() != "field_declaration" {
+            continue;
+        }
+        let type_annotation = field.child_by_field_name("type").map(|t| node_text(t, source));
+        let tag = field.child_by_field_name("tag")
+            .map(|t| node_text(t, source).trim_matches('`').to_string());
+        let decorators = extract_decorators_for_node(field, source);
+        let mut names: Vec<String> = {
+            let mut name_cursor = field.walk();
+            let names = field.children_by_field_name("name", &mut name_cursor)
+                .map(|n| node_text(n, source))
+                .collect();
+            names
+        };
+        if names.is_empty() {
+            match &type_annotation {
+                Some(ty) => names.push(ty.trim_start_matches('*').to_string()),
+                None => continue,
+            }
+        }
+        let is_rust = field.parent().and_then(|p| p.parent()).is_some_and(|p| p.kind() == "struct_item");
+        for name in names {
+            let visibility = if is_rust {
+                if has_child_kind(&field, "visibility_modifier") { Visibility::Public } else { Visibility::Private }
+            } else if name.starts_with(|c: char| c.is_uppercase()) || name.contains('.') {
+                Visibility::Public
+            } else {
+                Visibility::Private
+            };
+            properties.push(PropertyInfo {
+                name,
+                type_annotation: type_annotation.clone(),
+                is_static: false,
+                is_readonly: false,
+                visibility,
+                line: field.start_position().row as u32,
+                decorators: decorators.clone(),
+                tag: tag.clone(),
+                initializer: None,
+            });
+        }
+    }
+    properties
+}
+
 fn extract_enum(node: Node, source: &[u8], _file: &str) -> Option<ClassInfo> {
     let name = find_child_text(&node, source, &["type_identifier", "identifier", "name"])?;
     let visibility = extract_visibility(node, source);
@@ -1043,6 +1159,7 @@ fn extract_call_target(node: Node, source: &[u8]) -> Option<(String, Option<Stri
             | "field_expression" | "attribute" | "navigation_expression" => {
                 let obj = func.child_by_field_name("object")
                     .or_else(|| func.child_by_field_name("operand"))
+                    .or_else(|| func.child_by_field_name("expression"))
                     .map(|n| node_text(n, source));
                 let prop = func.child_by_field_name("property")
                     .or_else(|| func.child_by_field_name("field"))
@@ -1061,6 +1178,15 @@ fn extract_call_target(node: Node, source: &[u8]) -> Option<(String, Option<Stri
         let obj = node.child_by_field_name("object").map(|n| node_text(n, source));
         return Some((node_text(method, source), obj));
     }
+    // Kotlin: `repo.save()` is a navigation_expression followed by a call_suffix
+    if let Some(nav) = node.named_child(0).filter(|c| c.kind() == "navigation_expression") {
+        let method = nav.named_child(nav.named_child_count().saturating_sub(1))
+            .filter(|s| s.kind() == "navigation_suffix")
+            .and_then(|s| find_child_text(&s, source, &["simple_identifier"]));
+        if let Some(method) = method {
+            return Some((method, nav.named_child(0).map(|n| node_text(n, source))));
+        }
+    }
     // Try direct child identifier
     if let Some(name) = find_child_text(&node, source, &["identifier", "name", "simple_identifier"]) {
         return Some((name, None));
@@ -1072,9 +1198,146 @@ fn extract_call_target(node: Node, source: &[u8]) -> Option<(String, Option<Stri
     None
 }
 
+/// Record the names a declaration or assignment binds to a known type.
+fn extract_type_bindings(node: Node, source: &[u8], bindings: &mut Vec<TypeBinding>) {
+    let line = node.start_position().row as u32;
+    let mut push = |name: String, (kind, type_name): (TypeBindingKind, String)| {
+        if !name.is_empty() && !type_name.is_empty() {
+            bindings.push(TypeBinding { name, type_name, kind, line });
+        }
+    };
+    match node.kind() {
+        // TS/JS `const repo: Repo = ...`, Java/C# `Repo repo = new Repo()`
+        "variable_declarator" => {
+            let name = node.child_by_field_name("name")
+                .filter(|n| n.kind() == "identifier")
+                .or_else(|| find_child_by_kind(&node, "identifier"));
+            let Some(name) = name else { return };
+            let declared = node.child_by_field_name("type")
+                .or_else(|| node.parent().and_then(|p| p.child_by_field_name("type")))
+                .map(|t| node_text(t, source))
+                .filter(|t| t.trim_start_matches(':').trim() != "var");
+            let value = node.child_by_field_name("value").or_else(|| {
+                let mut cursor = node.walk();
+                let value = node.named_children(&mut cursor).find(|c| c.id() != name.id());
+                value
+            });
+            let binding = match declared {
+                Some(declared) => Some((TypeBindingKind::Declared, declared)),
+                None => value.and_then(|v| value_type(v, source)),
+            };
+            if let Some(binding) = binding {
+                push(node_text(name, source), binding);
+            }
+        }
+        // `this.repo = repo`, Python `self.repo: Repo = repo`, `r = Repo()`
+        "assignment_expression" | "assignment" => {
+            let Some(left) = node.child_by_field_name("left") else { return };
+            if !matches!(left.kind(), "identifier" | "member_expression" | "attribute" | "field_access" | "member_access_expression") {
+                return;
+            }
+            let binding = match node.child_by_field_name("type") {
+                Some(declared) => Some((TypeBindingKind::Declared, node_text(declared, source))),
+                None => node.child_by_field_name("right").and_then(|v| value_type(v, source)),
+            };
+            if let Some(binding) = binding {
+                push(node_text(left, source), binding);
+            }
+        }
+        // Kotlin `val repo: Repo = ...` / `val repo = Repo()`
+        "property_declaration" => {
+            let Some(declaration) = find_child_by_kind(&node, "variable_declaration") else { return };
+            let Some(name) = find_child_text(&declaration, source, &["simple_identifier"]) else { return };
+            let binding = match find_kotlin_type(declaration) {
+                Some(declared) => Some((TypeBindingKind::Declared, node_text(declared, source))),
+                None => {
+                    let mut cursor = node.walk();
+                    let value = node.named_children(&mut cursor).find(|c| c.id() != declaration.id() && c.kind() != "binding_pattern_kind" && c.kind() != "modifiers");
+                    value.and_then(|v| value_type(v, source))
+                }
+            };
+            if let Some(binding) = binding {
+                push(name, binding);
+            }
+        }
+        // Go `r, err := NewRepo()`
+        "short_var_declaration" => {
+            let (Some(left), Some(right)) = (node.child_by_field_name("left"), node.child_by_field_name("right")) else { return };
+            let mut left_cursor = left.walk();
+            let mut right_cursor = right.walk();
+            let values: Vec<Node> = right.named_children(&mut right_cursor).collect();
+            for (i, name) in left.named_children(&mut left_cursor).enumerate() {
+                // A single call may return several values; its first is the typed one.
+                let value = values.get(i).copied().filter(|_| i == 0 || values.len() > 1);
+                if let Some(binding) = value.and_then(|v| value_type(v, source)) {
+                    push(node_text(name, source), binding);
+                }
+            }
+        }
+        // Go `var repo Repo` / `var repo = NewRepo()`
+        "var_spec" => {
+            let declared = node.child_by_field_name("type").map(|t| (TypeBindingKind::Declared, node_text(t, source)));
+            let binding = declared.or_else(|| {
+                let value = node.child_by_field_name("value")?;
+                let first = value.named_child(0).filter(|_| value.kind() == "expression_list").unwrap_or(value);
+                value_type(first, source)
+            });
+            let Some(binding) = binding else { return };
+            let mut cursor = node.walk();
+            for name in node.children_by_field_name("name", &mut cursor) {
+                push(node_text(name, source), binding.clone());
+            }
+        }
+        // Go parameters, including method receivers
+        "parameter_declaration" => {
+            let Some(declared) = node.child_by_field_name("type") else { return };
+            let is_receiver = node.parent().is_some_and(|list| {
+                list.parent()
+                    .and_then(|m| m.child_by_field_name("receiver"))
+                    .is_some_and(|r| r.id() == list.id())
+            });
+            let kind = if is_receiver { TypeBindingKind::Receiver } else { TypeBindingKind::Declared };
+            let mut cursor = node.walk();
+            for name in node.children_by_field_name("name", &mut cursor) {
+                push(node_text(name, source), (kind, node_text(declared, source)));
+            }
+        }
+        _ => {}
+    }
+}
+
+/// The type an initializer gives its binding.
+fn value_type(value: Node, source: &[u8]) -> Option<(TypeBindingKind, String)> {
+    match value.kind() {
+        "new_expression" => value.child_by_field_name("constructor")
+            .map(|c| (TypeBindingKind::Constructed, node_text(c, source))),
+        "object_creation_expression" | "composite_literal" => value.child_by_field_name("type")
+            .map(|t| (TypeBindingKind::Constructed, node_text(t, source))),
+        // Go `&Repo{}`
+        "unary_expression" => value.child_by_field_name("operand")
+            .filter(|o| o.kind() == "composite_literal")
+            .and_then(|o| value_type(o, source)),
+        "await_expression" | "parenthesized_expression" => value.named_child(0).and_then(|v| value_type(v, source)),
+        "call_expression" | "call" | "invocation_expression" | "method_invocation" => {
+            // Only plain function calls; `factory.create()` says nothing about its type.
+            let (callee, receiver) = extract_call_target(value, source)?;
+            receiver.is_none().then_some((TypeBindingKind::Call, callee))
+        }
+        "identifier" | "simple_identifier" => Some((TypeBindingKind::Alias, node_text(value, source))),
+        _ => None,
+    }
+}
+
+/// Kotlin has no `type` field; the type is a `user_type` or `nullable_type` child.
+fn find_kotlin_type(node: Node) -> Option<Node> {
+    let mut cursor = node.walk();
+    let found = node.named_children(&mut cursor).find(|c| matches!(c.kind(), "user_type" | "nullable_type"));
+    found
+}
+
 fn extract_decorator(node: Node, source: &[u8]) -> Option<DecoratorInfo> {
     let name = find_child_text(&node, source, &[
-        "identifier", "name", "type_identifier", "call_expression",
+        "identifier", "name", "type_identifier", "call_expression", "qualified_name",
     ]).unwrap_or_else(|| node_text(node, source));
 
     Some(DecoratorInfo {
@@ -1117,7 +1380,9 @@ fn extract_numeric_literal(node: Node, source: &[u8], file: &str) -> Option<Nume
 
 fn extract_parameters(node: Node, source: &[u8]) -> SmallVec<[ParameterInfo; 4]> {
     let mut params = SmallVec::new();
-    if let Some(param_list) = node.child_by_field_name("parameters") {
+    let param_list = node.child_by_field_name("parameters")
+        .or_else(|| find_child_by_kind(&node, "function_value_parameters"));
+    if let Some(param_list) = param_list {
         let mut cursor = param_list.walk();
         if cursor.goto_first_child() {
             loop {
@@ -1125,11 +1390,18 @@ fn extract_parameters(node: Node, source: &[u8]) -> SmallVec<[ParameterInfo; 4]>
                 match child.kind() {
                     "required_parameter" | "optional_parameter" | "formal_parameter"
                     | "parameter" | "identifier" | "typed_parameter"
-                    | "default_parameter" | "rest_parameter" | "spread_parameter" => {
-                        let name = find_child_text(&child, source, &[
-                            "identifier", "name", "simple_identifier",
-                        ]).unwrap_or_else(|| node_text(child, source));
+                    | "default_parameter" | "typed_default_parameter"
+                    | "rest_parameter" | "spread_parameter" => {
+                        // C# puts the type before the name, so prefer the `name` field.
+                        let name = child.child_by_field_name("name")
+                            .filter(|n| n.kind() == "identifier")
+                            .map(|n| node_text(n, source))
+                            .or_else(|| find_child_text(&child, source, &[
+                                "identifier", "name", "simple_identifier",
+                            ]))
+                            .unwrap_or_else(|| node_text(child, source));
                         let type_ann = child.child_by_field_name("type")
+                            .or_else(|| find_kotlin_type(child))
                             .map(|t| node_text(t, source));
                         let default = child.child_by_field_name("value")
                             .or_else(|| child.child_by_field_name("default_value"))
@@ -1154,13 +1426,41 @@ fn extract_parameters(node: Node, source: &[u8]) -> SmallVec<[ParameterInfo; 4]>
 fn extract_property(node: Node, source: &[u8]) -> Option<PropertyInfo> {
     let name = find_child_text(&node, source, &[
         "property_identifier", "identifier", "name", "field_identifier",
-    ])?;
+    ]).or_else(|| {
+        // Java names the variable declarator, PHP the property element (`$email`).
+        let declarator = node.child_by_field_name("declarator").or_else(|| {
+            let mut cursor = node.walk();
+            let element = node.named_children(&mut cursor).find(|c| c.kind() == "property_element");
+            element
+        })?;
+        let name = declarator.child_by_field_name("name")?;
+        Some(node_text(name, source).trim_start_matches('$').to_string())
+    }).or_else(|| {
+        // C# and Kotlin wrap the name (and type) in a variable_declaration.
+        let declaration = find_child_by_kind(&node, "variable_declaration")?;
+        find_child_text(&declaration, source, &["simple_identifier"]).or_else(|| {
+            let declarator = find_child_by_kind(&declaration, "variable_declarator")?;
+            find_child_text(&declarator, source, &["identifier"])
+        })
+    })?;
+    let declaration = find_child_by_kind(&node, "variable_declaration");
     Some(PropertyInfo {
         name,
-        type_annotation: node.child_by_field_name("type").map(|t| node_text(t, source)),
-        is_static: has_child_kind(&node, "static"),
+        type_annotation: node.child_by_field_name("type")
+            .or_else(|| declaration.and_then(|d| d.child_by_field_name("type").or_else(|| find_kotlin_type(d))))
+            .map(|t| node_text(t, source)),
+        // Java keeps `static` inside a `modifiers` node.
+        is_static: has_child_kind(&node, "static")
+            || find_child_by_kind(&node, "modifiers").is_some_and(|m| has_child_kind(&m, "static")),
         is_readonly: has_child_kind(&node, "readonly"),
         visibility: Visibility::Public,
+        line: node.start_position().row as u32,
+        decorators: extract_member_decorators(node, source),
+        tag: None,
+        initializer: node.child_by_field_name("declarator")
+            .and_then(|d| d.child_by_field_name("value"))
+            .or_else(|| node.child_by_field_name("value"))
+            .map(|v| node_text(v, source)),
     })
 }
 
@@ -1405,6 +1705,33 @@ fn extract_decorators_for_node(node: Node, source: &[u8]) -> Vec<DecoratorInfo>
     decorators
 }
 
+/// Decorators preceding a class or member, plus those its grammar nests
+/// inside the declaration: Java `modifiers`, C# and PHP attribute lists and
+/// TypeScript decorators.
+fn extract_member_decorators(node: Node, source: &[u8]) -> Vec<DecoratorInfo> {
+    let mut decorators = extract_decorators_for_node(node, source);
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        match child.kind() {
+            "decorator" => decorators.extend(extract_decorator(child, source)),
+            "modifiers" | "attribute_list" => collect_nested_decorators(child, source, &mut decorators),
+            _ => {}
+        }
+    }
+    decorators
+}
+
+fn collect_nested_decorators(node: Node, source: &[u8], out: &mut Vec<DecoratorInfo>) {
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        match child.kind() {
+            "annotation" | "marker_annotation" | "attribute" => out.extend(extract_decorator(child, source)),
+            "attribute_group" => collect_nested_decorators(child, source, out),
+            _ => {}
+        }
+    }
+}
+
 /// DP-CLASS-01: Extract implements list from a class node.
 fn extract_implements(node: Node, source: &[u8]) -> SmallVec<[String; 2]> {
     let mut implements = SmallVec::new();
@@ -0,0 +1,580 @@
+//! Scala parser.
+//!
+//! tree-sitter-scala splits declarations across `class_definition`,
+//! `object_definition`, `trait_definition` and `enum_definition`, allows several
+//! parameter clauses per definition (curried and `implicit`/`using` lists), and
+//! spells imports as dotted `path` fields with selector blocks. This module owns
+//! extraction for Scala and reuses the shared literal and doc-comment helpers.
+
+use std::path::Path;
+
+use drift_core::errors::ParseError;
+use smallvec::SmallVec;
+use tree_sitter::Node;
+
+use super::{extract_doc_comment_for_node, extract_numeric_literal, extract_string_literal, node_text, parse_with_language};
+use crate::parsers::complexity::measure;
+use crate::parsers::traits::LanguageParser;
+use crate::parsers::types::*;
+use crate::scanner::hasher::hash_content;
+use crate::scanner::language_detect::Language;
+
+pub struct ScalaParser;
+
+impl Default for ScalaParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ScalaParser {
+    pub fn new() -> Self { Self }
+}
+
+impl LanguageParser for ScalaParser {
+    fn language(&self) -> Language { Language::Scala }
+    fn extensions(&self) -> &[&str] { &["scala", "sc"] }
+
+    fn parse(&self, source: &[u8], path: &Path) -> Result<ParseResult, ParseError> {
+        parse_with_language(source, path, Language::Scala, tree_sitter_scala::LANGUAGE.into())
+    }
+}
+
+/// Extract definitions, lambdas, imports, call sites, literals and error
+/// handling from a Scala syntax tree.
+pub(super) fn extract_scala(result: &mut ParseResult, root: Node, source: &[u8], file: &str) {
+    walk(result, root, source, file, 0);
+}
+
+fn walk(result: &mut ParseResult, node: Node, source: &[u8], file: &str, depth: usize) {
+    match node.kind() {
+        "function_definition" | "function_declaration" | "given_definition" => {
+            if let Some(func) = extract_function(node, source, file, None) {
+                result.functions.push(func);
+            }
+        }
+        "lambda_expression" => {
+            result.functions.push(extract_lambda(node, source, file));
+        }
+        "class_definition" | "object_definition" | "trait_definition" | "enum_definition" => {
+            if let Some(class) = extract_class(node, source, file, result.namespace.as_deref()) {
+                result.classes.push(class);
+            }
+        }
+        "package_clause" if result.namespace.is_none() => {
+            result.namespace = node.child_by_field_name("name").map(|n| node_text(n, source));
+        }
+        "import_declaration" => {
+            result.imports.extend(extract_imports(node, source, file));
+        }
+        "call_expression" => {
+            if let Some(call) = extract_call_site(node, source, file) {
+                result.call_sites.push(call);
+            }
+        }
+        "annotation" => {
+            result.decorators.push(extract_annotation(node, source));
+        }
+        "string" | "interpolated_string_expression" => {
+            if let Some(lit) = extract_string_literal(node, source, file) {
+                result.string_literals.push(lit);
+            }
+            return;
+        }
+        "integer_literal" | "floating_point_literal" => {
+            if let Some(lit) = extract_numeric_literal(node, source, file) {
+                result.numeric_literals.push(lit);
+            }
+        }
+        "comment" | "block_comment" => {
+            let text = node_text(node, source);
+            let trimmed = text.trim();
+            // Scaladoc uses the /** ... */ block syntax.
+            if trimmed.starts_with("/**") && trimmed.ends_with("*/") {
+                result.doc_comments.push(DocCommentInfo {
+                    text: text.clone(),
+                    style: DocCommentStyle::JsDoc,
+                    file: file.to_string(),
+                    line: node.start_position().row as u32,
+                    range: Range::from_ts_node(&node),
+                });
+            }
+            return;
+        }
+        "try_expression" => {
+            let catch = find_child(node, "catch_clause");
+            let first_case = catch.and_then(|c| find_descendant(c, "case_clause"));
+            result.error_handling.push(ErrorHandlingInfo {
+                kind: if catch.is_some() { ErrorHandlingKind::TryCatch } else { ErrorHandlingKind::TryFinally },
+                file: file.to_string(),
+                line: node.start_position().row as u32,
+                end_line: node.end_position().row as u32,
+                range: Range::from_ts_node(&node),
+                caught_type: first_case.and_then(|c| extract_case_type(c, source)),
+                has_body: catch.map_or(true, |_| first_case.is_some_and(|c| c.child_by_field_name("body").is_some())),
+                function_scope: enclosing_function_name(node, source),
+            });
+        }
+        "throw_expression" => {
+            result.error_handling.push(ErrorHandlingInfo {
+                kind: ErrorHandlingKind::Throw,
+                file: file.to_string(),
+                line: node.start_position().row as u32,
+                end_line: node.end_position().row as u32,
+                range: Range::from_ts_node(&node),
+                caught_type: None,
+                has_body: false,
+                function_scope: enclosing_function_name(node, source),
+            });
+        }
+        _ => {}
+    }
+
+    if depth < 50 {
+        let mut cursor = node.walk();
+        if cursor.goto_first_child() {
+            loop {
+                walk(result, cursor.node(), source, file, depth + 1);
+                if !cursor.goto_next_sibling() {
+                    break;
+                }
+            }
+        }
+    }
+}
+
+// ---- Declarations ----
+
+fn extract_function(node: Node, source: &[u8], file: &str, owner: Option<&str>) -> Option<FunctionInfo> {
+    // Anonymous givens (`given Ordering[Int] with ...`) have no callable name.
+    let name = node.child_by_field_name("name").map(|n| node_text(n, source))?;
+    let return_type = node.child_by_field_name("return_type")
+        .map(|t| node_text(t, source))
+        .filter(|t| t != ":");
+
+    // Curried and implicit clauses all contribute parameters.
+    let mut parameters = SmallVec::new();
+    let mut params_text = String::new();
+    let mut cursor = node.walk();
+    for clause in node.children_by_field_name("parameters", &mut cursor) {
+        if clause.kind() == "parameters" {
+            params_text.push_str(&node_text(clause, source));
+            collect_parameters(clause, source, &mut parameters);
+        }
+    }
+    let sig_hash = hash_content(format!("{}{}{}", name, params_text, return_type.as_deref().unwrap_or("")).as_bytes());
+    let body_text = node.child_by_field_name("body").map(|b| node_text(b, source)).unwrap_or_default();
+
+    let visibility = visibility_of(node, source);
+    let complexity = measure(node, source, parameters.len());
+    Some(FunctionInfo {
+        name: name.clone(),
+        qualified_name: owner.map(|o| format!("{}.{}", o, name)),
+        file: file.to_string(),
+        line: node.start_position().row as u32,
+        column: node.start_position().column as u32,
+        end_line: node.end_position().row as u32,
+        parameters,
+        return_type,
+        generic_params: extract_generic_params(node, source),
+        visibility,
+        is_exported: visibility != Visibility::Private,
+        is_async: false,
+        is_generator: false,
+        is_abstract: node.kind() == "function_declaration",
+        range: Range::from_ts_node(&node),
+        decorators: extract_annotations(node, source),
+        doc_comment: extract_doc_comment_for_node(node, source),
+        body_hash: hash_content(body_text.as_bytes()),
+        signature_hash: sig_hash,
+        complexity,
+    })
+}
+
+/// Lambdas are named after the `val`/`var` they are bound to, like arrow
+/// functions in the JS/TS walker; inline lambdas are anonymous.
+fn extract_lambda(node: Node, source: &[u8], file: &str) -> FunctionInfo {
+    let name = node.parent()
+        .filter(|p| matches!(p.kind(), "val_definition" | "var_definition"))
+        .and_then(|p| p.child_by_field_name("pattern"))
+        .map(|n| node_text(n, source))
+        .unwrap_or_else(|| "<anonymous>".to_string());
+
+    let mut parameters = SmallVec::new();
+    if let Some(params) = node.child_by_field_name("parameters") {
+        match params.kind() {
+            "identifier" | "wildcard" => parameters.push(ParameterInfo {
+                name: node_text(params, source),
+                type_annotation: None,
+                default_value: None,
+                is_rest: false,
+            }),
+            "bindings" => {
+                for i in 0..params.named_child_count() {
+                    let Some(binding) = params.named_child(i) else { continue };
+                    parameters.push(ParameterInfo {
+                        name: binding.child_by_field_name("name").map(|n| node_text(n, source)).unwrap_or_default(),
+                        type_annotation: binding.child_by_field_name("type").map(|t| node_text(t, source)),
+                        default_value: None,
+                        is_rest: false,
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let complexity = measure(node, source, parameters.len());
+    FunctionInfo {
+        name,
+        qualified_name: None,
+        file: file.to_string(),
+        line: node.start_position().row as u32,
+        column: node.start_position().column as u32,
+        end_line: node.end_position().row as u32,
+        parameters,
+        return_type: None,
+        generic_params: SmallVec::new(),
+        visibility: Visibility::Public,
+        is_exported: false,
+        is_async: false,
+        is_generator: false,
+        is_abstract: false,
+        range: Range::from_ts_node(&node),
+        decorators: Vec::new(),
+        doc_comment: None,
+        body_hash: hash_content(node_text(node, source).as_bytes()),
+        signature_hash: 0,
+        complexity,
+    }
+}
+
+fn extract_class(node: Node, source: &[u8], file: &str, namespace: Option<&str>) -> Option<ClassInfo> {
+    let name = node.child_by_field_name("name").map(|n| node_text(n, source))?;
+    let is_case = find_child(node, "case").is_some();
+    let class_kind = match node.kind() {
+        "trait_definition" => ClassKind::Trait,
+        "enum_definition" => ClassKind::Enum,
+        // Case classes are immutable data carriers, the Scala counterpart of records.
+        "class_definition" if is_case => ClassKind::Record,
+        _ => ClassKind::Class,
+    };
+
+    // `extends A with B with C`: A is the parent, the rest are mixins.
+    let mut parents = Vec::new();
+    if let Some(clause) = node.child_by_field_name("extend") {
+        let mut cursor = clause.walk();
+        for ty in clause.children_by_field_name("type", &mut cursor) {
+            if ty.kind() != "with" {
+                parents.push(node_text(ty, source));
+            }
+        }
+    }
+    let mut parents = parents.into_iter();
+    let extends = parents.next();
+    let implements: SmallVec<[String; 2]> = parents.collect();
+
+    let mut methods = Vec::new();
+    let mut properties = Vec::new();
+
+    // Case class parameters, and `val`/`var` class parameters, are fields.
+    let mut cursor = node.walk();
+    for clause in node.children_by_field_name("class_parameters", &mut cursor) {
+        for i in 0..clause.named_child_count() {
+            let Some(param) = clause.named_child(i) else { continue };
+            if param.kind() != "class_parameter" {
+                continue;
+            }
+            let binding = find_child(param, "val").or_else(|| find_child(param, "var"));
+            if !is_case && binding.is_none() {
+                continue;
+            }
+            let Some(prop_name) = param.child_by_field_name("name") else { continue };
+            properties.push(PropertyInfo {
+                name: node_text(prop_name, source),
+                type_annotation: param.child_by_field_name("type").map(|t| node_text(t, source)),
+                is_static: false,
+                is_readonly: binding.map_or(true, |b| b.kind() == "val"),
+                visibility: visibility_of(param, source),
+                line: param.start_position().row as u32,
+                decorators: Vec::new(),
+                tag: None,
+                initializer: None,
+            });
+        }
+    }
+
+    if let Some(body) = node.child_by_field_name("body") {
+        for i in 0..body.named_child_count() {
+            let Some(member) = body.named_child(i) else { continue };
+            match member.kind() {
+                "function_definition" | "function_declaration" | "given_definition" => {
+                    if let Some(method) = extract_function(member, source, file, Some(&name)) {
+                        methods.push(method);
+                    }
+                }
+                "val_definition" | "var_definition" | "val_declaration" | "var_declaration" => {
+                    let prop_name = member.child_by_field_name("pattern")
+                        .or_else(|| member.child_by_field_name("name"))
+                        .map(|n| node_text(n, source));
+                    if let Some(prop_name) = prop_name {
+                        properties.push(PropertyInfo {
+                            name: prop_name,
+                            type_annotation: member.child_by_field_name("type").map(|t| node_text(t, source)),
+                            // Members of an `object` are the Scala equivalent of statics.
+                            is_static: node.kind() == "object_definition",
+                            is_readonly: member.kind().starts_with("val"),
+                            visibility: visibility_of(member, source),
+                            line: member.start_position().row as u32,
+                            decorators: Vec::new(),
+                            tag: None,
+                            initializer: None,
+                        });
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let visibility = visibility_of(node, source);
+    Some(ClassInfo {
+        name,
+        namespace: namespace.map(str::to_string),
+        extends,
+        implements,
+        generic_params: extract_generic_params(node, source),
+        is_exported: visibility != Visibility::Private,
+        is_abstract: class_kind == ClassKind::Trait || has_modifier(node, "abstract"),
+        class_kind,
+        methods,
+        properties,
+        range: Range::from_ts_node(&node),
+        decorators: extract_annotations(node, source),
+    })
+}
+
+fn collect_parameters(clause: Node, source: &[u8], params: &mut SmallVec<[ParameterInfo; 4]>) {
+    for i in 0..clause.named_child_count() {
+        let Some(param) = clause.named_child(i) else { continue };
+        if param.kind() != "parameter" {
+            continue;
+        }
+        let type_annotation = param.child_by_field_name("type").map(|t| node_text(t, source));
+        params.push(ParameterInfo {
+            name: param.child_by_field_name("name").map(|n| node_text(n, source)).unwrap_or_default(),
+            is_rest: type_annotation.as_deref().is_some_and(|t| t.ends_with('*')),
+            type_annotation,
+            default_value: param.child_by_field_name("default_value").map(|d| node_text(d, source)),
+        });
+    }
+}
+
+fn extract_generic_params(node: Node, source: &[u8]) -> SmallVec<[GenericParam; 2]> {
+    let mut generics = SmallVec::new();
+    let Some(list) = find_child(node, "type_parameters") else { return generics };
+    let mut cursor = list.walk();
+    for name in list.children_by_field_name("name", &mut cursor) {
+        // Bounds (`<: Base`, `: Ordering`) follow their parameter name.
+        let mut bounds = SmallVec::new();
+        let mut next = name.next_named_sibling();
+        while let Some(bound) = next {
+            if bound.kind() == "identifier" {
+                break;
+            }
+            let text = node_text(bound, source);
+            let text = text.trim_start_matches(['<', '>', ':', '%']).trim();
+            if !text.is_empty() {
+                bounds.push(text.to_string());
+            }
+            next = bound.next_named_sibling();
+        }
+        generics.push(GenericParam { name: node_text(name, source), bounds });
+    }
+    generics
+}
+
+fn extract_imports(node: Node, source: &[u8], file: &str) -> Vec<ImportInfo> {
+    let mut path = Vec::new();
+    let mut specifiers: SmallVec<[ImportSpecifier; 4]> = SmallVec::new();
+    let mut wildcard = false;
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        match child.kind() {
+            "identifier" | "operator_identifier" => path.push(node_text(child, source)),
+            "namespace_wildcard" | "wildcard" => wildcard = true,
+            "namespace_selectors" => {
+                for i in 0..child.named_child_count() {
+                    let Some(sel) = child.named_child(i) else { continue };
+                    match sel.kind() {
+                        "arrow_renamed_identifier" | "as_renamed_identifier" => {
+                            let name = sel.child_by_field_name("name").map(|n| node_text(n, source)).unwrap_or_default();
+                            let alias = sel.child_by_field_name("alias").map(|n| node_text(n, source));
+                            specifiers.push(ImportSpecifier { name, alias });
+                        }
+                        "namespace_wildcard" | "wildcard" => wildcard = true,
+                        _ => specifiers.push(ImportSpecifier { name: node_text(sel, source), alias: None }),
+                    }
+                }
+            }
+            "as_renamed_identifier" => {
+                // import a.b.{C => D} in Scala 3 syntax: import a.b.C as D
+                if let Some(name) = child.child_by_field_name("name") {
+                    path.push(node_text(name, source));
+                }
+                if let Some(alias) = child.child_by_field_name("alias") {
+                    let name = path.last().cloned().unwrap_or_default();
+                    specifiers.push(ImportSpecifier { name, alias: Some(node_text(alias, source)) });
+                }
+            }
+            _ => {}
+        }
+    }
+    if path.is_empty() {
+        return Vec::new();
+    }
+
+    // Mirror the Java convention: the source is the full path and a single
+    // imported member is also listed as a specifier.
+    if specifiers.is_empty() && !wildcard {
+        if let Some(last) = path.last() {
+            specifiers.push(ImportSpecifier { name: last.clone(), alias: None });
+        }
+    }
+
+    vec![ImportInfo {
+        source: path.join("."),
+        specifiers,
+        is_type_only: false,
+        file: file.to_string(),
+        line: node.start_position().row as u32,
+    }]
+}
+
+// ---- Calls ----
+
+fn extract_call_site(node: Node, source: &[u8], file: &str) -> Option<CallSite> {
+    let mut function = node.child_by_field_name("function")?;
+    // foo[T](x) wraps the callee in a generic_function.
+    if function.kind() == "generic_function" {
+        function = function.child_by_field_name("function")?;
+    }
+    let (callee_name, receiver) = match function.kind() {
+        "identifier" | "operator_identifier" => (node_text(function, source), None),
+        "field_expression" => {
+            let method = function.child_by_field_name("field").map(|f| node_text(f, source))?;
+            let receiver = function.child_by_field_name("value").map(|v| node_text(v, source));
+            (method, receiver)
+        }
+        _ => return None,
+    };
+
+    let argument_count = node.child_by_field_name("arguments")
+        .map(|a| a.named_child_count().min(u8::MAX as usize) as u8)
+        .unwrap_or(0);
+
+    Some(CallSite {
+        callee_name,
+        receiver,
+        file: file.to_string(),
+        line: node.start_position().row as u32,
+        column: node.start_position().column as u32,
+        argument_count,
+        is_await: false,
+    })
+}
+
+// ---- Modifiers ----
+
+fn visibility_of(node: Node, source: &[u8]) -> Visibility {
+    let Some(modifiers) = find_child(node, "modifiers") else { return Visibility::Public };
+    for i in 0..modifiers.named_child_count() {
+        if let Some(m) = modifiers.named_child(i) {
+            if m.kind() == "access_modifier" {
+                let text = node_text(m, source);
+                if text.starts_with("private") {
+                    return Visibility::Private;
+                }
+                if text.starts_with("protected") {
+                    return Visibility::Protected;
+                }
+            }
+        }
+    }
+    Visibility::Public
+}
+
+fn has_modifier(node: Node, keyword: &str) -> bool {
+    find_child(node, "modifiers").is_some_and(|m| find_child(m, keyword).is_some())
+}
+
+fn extract_annotations(node: Node, source: &[u8]) -> Vec<DecoratorInfo> {
+    (0..node.named_child_count())
+        .filter_map(|i| node.named_child(i))
+        .filter(|c| c.kind() == "annotation")
+        .map(|a| extract_annotation(a, source))
+        .collect()
+}
+
+fn extract_annotation(node: Node, source: &[u8]) -> DecoratorInfo {
+    let name = node.child_by_field_name("name")
+        .map(|n| node_text(n, source))
+        .unwrap_or_else(|| node_text(node, source).trim_start_matches('@').to_string());
+    let mut arguments = SmallVec::new();
+    let mut cursor = node.walk();
+    for args in node.children_by_field_name("arguments", &mut cursor) {
+        for i in 0..args.named_child_count() {
+            if let Some(arg) = args.named_child(i) {
+                arguments.push(DecoratorArgument { key: None, value: node_text(arg, source) });
+            }
+        }
+    }
+    DecoratorInfo {
+        name,
+        arguments,
+        raw_text: node_text(node, source),
+        range: Range::from_ts_node(&node),
+    }
+}
+
+// ---- Utilities ----
+
+/// `case e: IOException =>` → `IOException`; `case NonFatal(e) =>` → `NonFatal`.
+fn extract_case_type(case: Node, source: &[u8]) -> Option<String> {
+    let pattern = case.child_by_field_name("pattern")?;
+    match pattern.kind() {
+        "typed_pattern" => pattern.child_by_field_name("type").map(|t| node_text(t, source)),
+        "case_class_pattern" => pattern.child_by_field_name("type").map(|t| node_text(t, source)),
+        _ => Some(node_text(pattern, source)),
+    }
+}
+
+fn enclosing_function_name(node: Node, source: &[u8]) -> Option<String> {
+    let mut current = node.parent();
+    while let Some(parent) = current {
+        if matches!(parent.kind(), "function_definition" | "given_definition") {
+            return parent.child_by_field_name("name").map(|n| node_text(n, source));
+        }
+        current = parent.parent();
+    }
+    None
+}
+
+fn find_child<'a>(node: Node<'a>, kind: &str) -> Option<Node<'a>> {
+    (0..node.child_count())
+        .filter_map(|i| node.child(i))
+        .find(|c| c.kind() == kind)
+}
+
+fn find_descendant<'a>(node: Node<'a>, kind: &str) -> Option<Node<'a>> {
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        if child.kind() == kind {
+            return Some(child);
+        }
+        if let Some(found) = find_descendant(child, kind) {
+            return Some(found);
+        }
+    }
+    None
+}
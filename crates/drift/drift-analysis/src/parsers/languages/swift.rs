@@ -0,0 +1,647 @@
+//! Swift parser.
+//!
+//! tree-sitter-swift models classes, structs, enums, actors and extensions as
+//! a single `class_declaration` distinguished by its `declaration_kind`, keeps
+//! parameters as direct children of the function, and represents closures as
+//! `lambda_literal`. None of that fits the generic walker, so this module owns
+//! extraction for Swift and reuses the shared literal and doc-comment helpers.
+
+use std::path::Path;
+
+use drift_core::errors::ParseError;
+use smallvec::SmallVec;
+use tree_sitter::Node;
+
+use super::{extract_doc_comment_for_node, extract_numeric_literal, extract_string_literal, node_text, parse_with_language};
+use crate::parsers::complexity::measure;
+use crate::parsers::traits::LanguageParser;
+use crate::parsers::types::*;
+use crate::scanner::hasher::hash_content;
+use crate::scanner::language_detect::Language;
+
+pub struct SwiftParser;
+
+impl Default for SwiftParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SwiftParser {
+    pub fn new() -> Self { Self }
+}
+
+impl LanguageParser for SwiftParser {
+    fn language(&self) -> Language { Language::Swift }
+    fn extensions(&self) -> &[&str] { &["swift"] }
+
+    fn parse(&self, source: &[u8], path: &Path) -> Result<ParseResult, ParseError> {
+        parse_with_language(source, path, Language::Swift, tree_sitter_swift::LANGUAGE.into())
+    }
+}
+
+/// Extract declarations, closures, imports, call sites, literals and error
+/// handling from a Swift syntax tree.
+///
+/// Extensions are folded into the extended type when it is declared in the
+/// same file; otherwise they are reported as a class entry of their own so the
+/// added methods and conformances are not lost.
+pub(super) fn extract_swift(result: &mut ParseResult, root: Node, source: &[u8], file: &str) {
+    let mut extensions = Vec::new();
+    walk(result, &mut extensions, root, source, file, 0);
+
+    for ext in extensions {
+        match result.classes.iter_mut().find(|c| c.name == ext.name) {
+            Some(target) => {
+                target.methods.extend(ext.methods);
+                target.properties.extend(ext.properties);
+                for conformance in ext.implements {
+                    if !target.implements.contains(&conformance) && target.extends.as_ref() != Some(&conformance) {
+                        target.implements.push(conformance);
+                    }
+                }
+            }
+            None => result.classes.push(ext),
+        }
+    }
+}
+
+fn walk(
+    result: &mut ParseResult,
+    extensions: &mut Vec<ClassInfo>,
+    node: Node,
+    source: &[u8],
+    file: &str,
+    depth: usize,
+) {
+    match node.kind() {
+        "function_declaration" | "init_declaration" | "deinit_declaration"
+        | "protocol_function_declaration" => {
+            if let Some(func) = extract_function(node, source, file, None) {
+                result.functions.push(func);
+            }
+        }
+        "lambda_literal" => {
+            result.functions.push(extract_closure(node, source, file));
+        }
+        "class_declaration" => {
+            if let Some(class) = extract_class(node, source, file) {
+                if declaration_kind(node, source) == "extension" {
+                    extensions.push(class);
+                } else {
+                    result.classes.push(class);
+                }
+            }
+        }
+        "protocol_declaration" => {
+            if let Some(protocol) = extract_protocol(node, source, file) {
+                result.classes.push(protocol);
+            }
+        }
+        "import_declaration" => {
+            if let Some(path) = find_child(node, "identifier") {
+                result.imports.push(ImportInfo {
+                    source: node_text(path, source),
+                    specifiers: SmallVec::new(),
+                    is_type_only: false,
+                    file: file.to_string(),
+                    line: node.start_position().row as u32,
+                });
+            }
+        }
+        "call_expression" => {
+            if let Some(call) = extract_call_site(node, source, file) {
+                result.call_sites.push(call);
+            }
+        }
+        "attribute" => {
+            result.decorators.push(extract_attribute(node, source));
+        }
+        "line_string_literal" | "multi_line_string_literal" | "raw_string_literal" => {
+            if let Some(lit) = extract_string_literal(node, source, file) {
+                result.string_literals.push(lit);
+            }
+        }
+        "integer_literal" | "real_literal" => {
+            if let Some(lit) = extract_numeric_literal(node, source, file) {
+                result.numeric_literals.push(lit);
+            }
+        }
+        "comment" | "multiline_comment" => {
+            let text = node_text(node, source);
+            let trimmed = text.trim();
+            let style = if trimmed.starts_with("/**") && trimmed.ends_with("*/") {
+                Some(DocCommentStyle::JsDoc)
+            } else if trimmed.starts_with("///") {
+                Some(DocCommentStyle::TripleSlash)
+            } else {
+                None
+            };
+            if let Some(style) = style {
+                result.doc_comments.push(DocCommentInfo {
+                    text: text.clone(),
+                    style,
+                    file: file.to_string(),
+                    line: node.start_position().row as u32,
+                    range: Range::from_ts_node(&node),
+                });
+            }
+        }
+        "do_statement" => {
+            let catch = find_child(node, "catch_block");
+            result.error_handling.push(ErrorHandlingInfo {
+                kind: if catch.is_some() { ErrorHandlingKind::TryCatch } else { ErrorHandlingKind::TryFinally },
+                file: file.to_string(),
+                line: node.start_position().row as u32,
+                end_line: node.end_position().row as u32,
+                range: Range::from_ts_node(&node),
+                caught_type: catch.and_then(|c| extract_catch_type(c, source)),
+                has_body: catch.map_or(true, |c| find_child(c, "statements").is_some()),
+                function_scope: enclosing_function_name(node, source),
+            });
+        }
+        "control_transfer_statement" if find_child(node, "throw_keyword").is_some() => {
+            result.error_handling.push(ErrorHandlingInfo {
+                kind: ErrorHandlingKind::Throw,
+                file: file.to_string(),
+                line: node.start_position().row as u32,
+                end_line: node.end_position().row as u32,
+                range: Range::from_ts_node(&node),
+                caught_type: None,
+                has_body: false,
+                function_scope: enclosing_function_name(node, source),
+            });
+        }
+        // `try!` traps on error, the Swift analogue of `unwrap()`.
+        "try_expression" => {
+            let forced = find_child(node, "try_operator")
+                .is_some_and(|op| node_text(op, source).trim_end().ends_with('!'));
+            if forced {
+                result.error_handling.push(ErrorHandlingInfo {
+                    kind: ErrorHandlingKind::Unwrap,
+                    file: file.to_string(),
+                    line: node.start_position().row as u32,
+                    end_line: node.end_position().row as u32,
+                    range: Range::from_ts_node(&node),
+                    caught_type: None,
+                    has_body: false,
+                    function_scope: enclosing_function_name(node, source),
+                });
+            }
+        }
+        _ => {}
+    }
+
+    if depth < 50 {
+        let mut cursor = node.walk();
+        if cursor.goto_first_child() {
+            loop {
+                walk(result, extensions, cursor.node(), source, file, depth + 1);
+                if !cursor.goto_next_sibling() {
+                    break;
+                }
+            }
+        }
+    }
+}
+
+// ---- Declarations ----
+
+fn extract_function(node: Node, source: &[u8], file: &str, owner: Option<&str>) -> Option<FunctionInfo> {
+    let name = match node.kind() {
+        "init_declaration" => "init".to_string(),
+        "deinit_declaration" => "deinit".to_string(),
+        _ => node.child_by_field_name("name").map(|n| node_text(n, source))?,
+    };
+    let return_type = return_type(node, source);
+    let parameters = extract_parameters(node, source);
+
+    let params_text = parameters.iter()
+        .map(|p| format!("{}:{}", p.name, p.type_annotation.as_deref().unwrap_or("")))
+        .collect::<Vec<_>>()
+        .join(",");
+    let sig_hash = hash_content(format!("{}({}){}", name, params_text, return_type.as_deref().unwrap_or("")).as_bytes());
+    let body_text = node.child_by_field_name("body").map(|b| node_text(b, source)).unwrap_or_default();
+
+    let modifiers = find_child(node, "modifiers");
+    let visibility = modifiers.map(|m| visibility_of(m, source)).unwrap_or(Visibility::Public);
+
+    let complexity = measure(node, source, parameters.len());
+    Some(FunctionInfo {
+        name: name.clone(),
+        qualified_name: owner.map(|o| format!("{}.{}", o, name)),
+        file: file.to_string(),
+        line: node.start_position().row as u32,
+        column: node.start_position().column as u32,
+        end_line: node.end_position().row as u32,
+        parameters,
+        return_type,
+        generic_params: extract_generic_params(node, source),
+        visibility,
+        is_exported: modifiers.map_or(true, |m| is_exported(m, source)),
+        is_async: find_child(node, "async").is_some(),
+        is_generator: false,
+        // Protocol requirements have no body.
+        is_abstract: node.kind() == "protocol_function_declaration",
+        range: Range::from_ts_node(&node),
+        decorators: modifiers.map(|m| extract_attributes(m, source)).unwrap_or_default(),
+        doc_comment: extract_doc_comment_for_node(node, source),
+        body_hash: hash_content(body_text.as_bytes()),
+        signature_hash: sig_hash,
+        complexity,
+    })
+}
+
+/// Closures are named after the binding they are assigned to, like arrow
+/// functions in the JS/TS walker; trailing and inline closures are anonymous.
+fn extract_closure(node: Node, source: &[u8], file: &str) -> FunctionInfo {
+    let name = node.parent()
+        .filter(|p| p.kind() == "property_declaration")
+        .and_then(|p| p.child_by_field_name("name"))
+        .and_then(|pattern| pattern.child_by_field_name("bound_identifier").or(Some(pattern)))
+        .map(|n| node_text(n, source))
+        .unwrap_or_else(|| "<anonymous>".to_string());
+
+    let lambda_type = find_child(node, "lambda_function_type");
+    let mut parameters = SmallVec::new();
+    if let Some(params) = lambda_type.and_then(|t| find_child(t, "lambda_function_type_parameters")) {
+        for i in 0..params.named_child_count() {
+            let Some(param) = params.named_child(i) else { continue };
+            if param.kind() != "lambda_parameter" {
+                continue;
+            }
+            let mut cursor = param.walk();
+            let typed: Vec<Node> = param.children_by_field_name("name", &mut cursor).collect();
+            parameters.push(ParameterInfo {
+                name: typed.first().map(|n| node_text(*n, source)).unwrap_or_default(),
+                type_annotation: typed.get(1).map(|n| node_text(*n, source)),
+                default_value: None,
+                is_rest: false,
+            });
+        }
+    }
+    let return_type = lambda_type.and_then(|t| return_type(t, source));
+    let body_text = find_child(node, "statements").map(|b| node_text(b, source)).unwrap_or_default();
+
+    let complexity = measure(node, source, parameters.len());
+    FunctionInfo {
+        name,
+        qualified_name: None,
+        file: file.to_string(),
+        line: node.start_position().row as u32,
+        column: node.start_position().column as u32,
+        end_line: node.end_position().row as u32,
+        parameters,
+        return_type,
+        generic_params: SmallVec::new(),
+        visibility: Visibility::Public,
+        is_exported: false,
+        is_async: lambda_type.is_some_and(|t| find_child(t, "async").is_some()),
+        is_generator: false,
+        is_abstract: false,
+        range: Range::from_ts_node(&node),
+        decorators: Vec::new(),
+        doc_comment: None,
+        body_hash: hash_content(body_text.as_bytes()),
+        signature_hash: 0,
+        complexity,
+    }
+}
+
+fn extract_class(node: Node, source: &[u8], file: &str) -> Option<ClassInfo> {
+    let kind = declaration_kind(node, source);
+    // Extensions name a `user_type`; `Array<Int>` extends `Array`.
+    let name_node = node.child_by_field_name("name")?;
+    let name = find_child(name_node, "type_identifier")
+        .map(|n| node_text(n, source))
+        .unwrap_or_else(|| node_text(name_node, source));
+
+    let class_kind = match kind.as_str() {
+        "struct" => ClassKind::Struct,
+        "enum" => ClassKind::Enum,
+        _ => ClassKind::Class,
+    };
+
+    // Only classes have a superclass; for everything else, and for every
+    // entry after the first, the inheritance list is protocol conformance.
+    let mut inherited = Vec::new();
+    for i in 0..node.named_child_count() {
+        if let Some(child) = node.named_child(i) {
+            if child.kind() == "inheritance_specifier" {
+                inherited.push(node_text(child, source));
+            }
+        }
+    }
+    let mut inherited = inherited.into_iter();
+    let extends = if class_kind == ClassKind::Class && kind != "extension" { inherited.next() } else { None };
+    let implements: SmallVec<[String; 2]> = inherited.collect();
+
+    let mut methods = Vec::new();
+    let mut properties = Vec::new();
+    if let Some(body) = node.child_by_field_name("body") {
+        for i in 0..body.named_child_count() {
+            let Some(member) = body.named_child(i) else { continue };
+            match member.kind() {
+                "function_declaration" | "init_declaration" | "deinit_declaration" => {
+                    if let Some(method) = extract_function(member, source, file, Some(&name)) {
+                        methods.push(method);
+                    }
+                }
+                "property_declaration" => {
+                    if let Some(prop) = extract_property(member, source) {
+                        properties.push(prop);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let modifiers = find_child(node, "modifiers");
+    Some(ClassInfo {
+        name,
+        namespace: None,
+        extends,
+        implements,
+        generic_params: extract_generic_params(node, source),
+        is_exported: modifiers.map_or(true, |m| is_exported(m, source)),
+        is_abstract: false,
+        class_kind,
+        methods,
+        properties,
+        range: Range::from_ts_node(&node),
+        decorators: modifiers.map(|m| extract_attributes(m, source)).unwrap_or_default(),
+    })
+}
+
+fn extract_protocol(node: Node, source: &[u8], file: &str) -> Option<ClassInfo> {
+    let name = node.child_by_field_name("name").map(|n| node_text(n, source))?;
+    let mut implements = SmallVec::new();
+    for i in 0..node.named_child_count() {
+        if let Some(child) = node.named_child(i) {
+            if child.kind() == "inheritance_specifier" {
+                implements.push(node_text(child, source));
+            }
+        }
+    }
+
+    let mut methods = Vec::new();
+    let mut properties = Vec::new();
+    if let Some(body) = node.child_by_field_name("body") {
+        for i in 0..body.named_child_count() {
+            let Some(member) = body.named_child(i) else { continue };
+            match member.kind() {
+                "protocol_function_declaration" => {
+                    if let Some(method) = extract_function(member, source, file, Some(&name)) {
+                        methods.push(method);
+                    }
+                }
+                "protocol_property_declaration" => {
+                    if let Some(prop) = extract_property(member, source) {
+                        properties.push(prop);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let modifiers = find_child(node, "modifiers");
+    Some(ClassInfo {
+        name,
+        namespace: None,
+        extends: None,
+        implements,
+        generic_params: SmallVec::new(),
+        is_exported: modifiers.map_or(true, |m| is_exported(m, source)),
+        is_abstract: true,
+        class_kind: ClassKind::Interface,
+        methods,
+        properties,
+        range: Range::from_ts_node(&node),
+        decorators: modifiers.map(|m| extract_attributes(m, source)).unwrap_or_default(),
+    })
+}
+
+fn extract_property(node: Node, source: &[u8]) -> Option<PropertyInfo> {
+    let pattern = node.child_by_field_name("name")?;
+    let name = pattern.child_by_field_name("bound_identifier")
+        .map(|n| node_text(n, source))
+        .unwrap_or_else(|| node_text(pattern, source));
+    let type_annotation = find_child(node, "type_annotation")
+        .map(|t| node_text(t, source).trim_start_matches(':').trim().to_string());
+    let is_readonly = find_child(node, "value_binding_pattern")
+        .and_then(|b| b.child_by_field_name("mutability"))
+        .is_some_and(|m| node_text(m, source) == "let");
+    let modifiers = find_child(node, "modifiers");
+    Some(PropertyInfo {
+        name,
+        type_annotation,
+        is_static: modifiers.is_some_and(|m| has_modifier(m, source, &["static", "class"])),
+        is_readonly,
+        visibility: modifiers.map(|m| visibility_of(m, source)).unwrap_or(Visibility::Public),
+        line: node.start_position().row as u32,
+        decorators: Vec::new(),
+        tag: None,
+        initializer: None,
+    })
+}
+
+/// Parameters are direct children of the declaration; a default value is the
+/// `default_value` field that follows its parameter.
+fn extract_parameters(node: Node, source: &[u8]) -> SmallVec<[ParameterInfo; 4]> {
+    let mut params: SmallVec<[ParameterInfo; 4]> = SmallVec::new();
+    let mut cursor = node.walk();
+    if !cursor.goto_first_child() {
+        return params;
+    }
+    loop {
+        let child = cursor.node();
+        if child.kind() == "parameter" {
+            let mut inner = child.walk();
+            // The grammar labels both the parameter name and its type `name`.
+            let named: Vec<Node> = child.children_by_field_name("name", &mut inner).collect();
+            params.push(ParameterInfo {
+                name: named.first().map(|n| node_text(*n, source)).unwrap_or_default(),
+                type_annotation: named.get(1).map(|n| node_text(*n, source)),
+                default_value: None,
+                is_rest: find_child(child, "...").is_some(),
+            });
+        } else if cursor.field_name() == Some("default_value") {
+            if let Some(last) = params.last_mut() {
+                last.default_value = Some(node_text(child, source));
+            }
+        }
+        if !cursor.goto_next_sibling() {
+            break;
+        }
+    }
+    params
+}
+
+fn extract_generic_params(node: Node, source: &[u8]) -> SmallVec<[GenericParam; 2]> {
+    let mut generics = SmallVec::new();
+    let Some(list) = find_child(node, "type_parameters") else { return generics };
+    for i in 0..list.named_child_count() {
+        let Some(param) = list.named_child(i) else { continue };
+        if param.kind() != "type_parameter" {
+            continue;
+        }
+        let Some(name) = find_child(param, "type_identifier") else { continue };
+        let bounds = param.child_by_field_name("name")
+            .map(|b| SmallVec::from_iter([node_text(b, source)]))
+            .unwrap_or_default();
+        generics.push(GenericParam { name: node_text(name, source), bounds });
+    }
+    generics
+}
+
+/// The return type is whichever type follows the `->` token.
+fn return_type(node: Node, source: &[u8]) -> Option<String> {
+    let mut seen_arrow = false;
+    for i in 0..node.child_count() {
+        let child = node.child(i)?;
+        if seen_arrow {
+            return Some(node_text(child, source));
+        }
+        seen_arrow = child.kind() == "->";
+    }
+    None
+}
+
+// ---- Calls ----
+
+fn extract_call_site(node: Node, source: &[u8], file: &str) -> Option<CallSite> {
+    let callee = node.named_child(0)?;
+    let suffix = find_child(node, "call_suffix")?;
+    let arguments = find_child(suffix, "value_arguments");
+    // `cache[id]` parses as a call with bracketed arguments; subscripts aren't calls.
+    if arguments.and_then(|a| a.child(0)).is_some_and(|open| open.kind() == "[") {
+        return None;
+    }
+
+    let (callee_name, receiver) = match callee.kind() {
+        "simple_identifier" => (node_text(callee, source), None),
+        "navigation_expression" => {
+            let method = callee.child_by_field_name("suffix")
+                .and_then(|s| s.child_by_field_name("suffix"))
+                .map(|n| node_text(n, source))?;
+            // `try a.b()` parses with the `try` bound to the receiver.
+            let receiver = callee.child_by_field_name("target")
+                .map(|t| if t.kind() == "try_expression" { t.child_by_field_name("expr").unwrap_or(t) } else { t })
+                .map(|t| node_text(t, source));
+            (method, receiver)
+        }
+        _ => return None,
+    };
+
+    let value_args = arguments
+        .map(|a| (0..a.named_child_count()).filter(|&i| a.named_child(i).is_some_and(|c| c.kind() == "value_argument")).count())
+        .unwrap_or(0);
+    let trailing_closures = (0..suffix.named_child_count())
+        .filter(|&i| suffix.named_child(i).is_some_and(|c| c.kind() == "lambda_literal"))
+        .count();
+
+    Some(CallSite {
+        callee_name,
+        receiver,
+        file: file.to_string(),
+        line: node.start_position().row as u32,
+        column: node.start_position().column as u32,
+        argument_count: (value_args + trailing_closures).min(u8::MAX as usize) as u8,
+        is_await: node.parent().is_some_and(|p| p.kind() == "await_expression"),
+    })
+}
+
+// ---- Modifiers ----
+
+fn declaration_kind(node: Node, source: &[u8]) -> String {
+    node.child_by_field_name("declaration_kind")
+        .map(|k| node_text(k, source))
+        .unwrap_or_default()
+}
+
+fn visibility_of(modifiers: Node, source: &[u8]) -> Visibility {
+    for i in 0..modifiers.named_child_count() {
+        if let Some(m) = modifiers.named_child(i) {
+            if m.kind() == "visibility_modifier" {
+                let text = node_text(m, source);
+                if text.starts_with("private") || text.starts_with("fileprivate") {
+                    return Visibility::Private;
+                }
+            }
+        }
+    }
+    Visibility::Public
+}
+
+/// `internal` (the default) is visible module-wide, so only `private` and
+/// `fileprivate` declarations are hidden from other files.
+fn is_exported(modifiers: Node, source: &[u8]) -> bool {
+    visibility_of(modifiers, source) != Visibility::Private
+}
+
+fn has_modifier(modifiers: Node, source: &[u8], keywords: &[&str]) -> bool {
+    (0..modifiers.named_child_count())
+        .filter_map(|i| modifiers.named_child(i))
+        .any(|m| keywords.contains(&node_text(m, source).as_str()))
+}
+
+fn extract_attributes(modifiers: Node, source: &[u8]) -> Vec<DecoratorInfo> {
+    (0..modifiers.named_child_count())
+        .filter_map(|i| modifiers.named_child(i))
+        .filter(|m| m.kind() == "attribute")
+        .map(|m| extract_attribute(m, source))
+        .collect()
+}
+
+fn extract_attribute(node: Node, source: &[u8]) -> DecoratorInfo {
+    let name = find_child(node, "user_type")
+        .map(|t| node_text(t, source))
+        .unwrap_or_else(|| node_text(node, source).trim_start_matches('@').to_string());
+    let mut arguments = SmallVec::new();
+    for i in 0..node.named_child_count() {
+        let Some(arg) = node.named_child(i) else { continue };
+        if arg.kind() != "user_type" {
+            arguments.push(DecoratorArgument { key: None, value: node_text(arg, source) });
+        }
+    }
+    DecoratorInfo {
+        name,
+        arguments,
+        raw_text: node_text(node, source),
+        range: Range::from_ts_node(&node),
+    }
+}
+
+// ---- Utilities ----
+
+fn extract_catch_type(catch: Node, source: &[u8]) -> Option<String> {
+    // catch let error as NetworkError / catch NetworkError.timeout
+    let pattern = catch.child_by_field_name("error")?;
+    pattern.child_by_field_name("name")
+        .map(|t| node_text(t, source))
+        .or_else(|| Some(node_text(pattern, source)))
+}
+
+fn enclosing_function_name(node: Node, source: &[u8]) -> Option<String> {
+    let mut current = node.parent();
+    while let Some(parent) = current {
+        match parent.kind() {
+            "function_declaration" => {
+                return parent.child_by_field_name("name").map(|n| node_text(n, source));
+            }
+            "init_declaration" => return Some("init".to_string()),
+            "deinit_declaration" => return Some("deinit".to_string()),
+            _ => {}
+        }
+        current = parent.parent();
+    }
+    None
+}
+
+fn find_child<'a>(node: Node<'a>, kind: &str) -> Option<Node<'a>> {
+    (0..node.child_count())
+        .filter_map(|i| node.child(i))
+        .find(|c| c.kind() == kind)
+}
@@ -5,6 +5,7 @@ use std::path::Path;
 use drift_core::errors::ParseError;
 
 use super::cache::ParseCache;
+use super::languages::c_cpp::{CParser, CppParser};
 use super::languages::csharp::CSharpParser;
 use super::languages::go::GoParser;
 use super::languages::java::JavaParser;
@@ -14,6 +15,8 @@ use super::languages::php::PhpParser;
 use super::languages::python::PythonParser;
 use super::languages::ruby::RubyParser;
 use super::languages::rust_lang::RustParser;
+use super::languages::scala::ScalaParser;
+use super::languages::swift::SwiftParser;
 use super::languages::typescript::TypeScriptParser;
 use super::traits::LanguageParser;
 use super::types::ParseResult;
@@ -33,6 +36,10 @@ pub struct ParserManager {
     ruby: RubyParser,
     php: PhpParser,
     kotlin: KotlinParser,
+    c: CParser,
+    cpp: CppParser,
+    swift: SwiftParser,
+    scala: ScalaParser,
 }
 
 impl ParserManager {
@@ -50,6 +57,10 @@ impl ParserManager {
             ruby: RubyParser::new(),
             php: PhpParser::new(),
             kotlin: KotlinParser::new(),
+            c: CParser::new(),
+            cpp: CppParser::new(),
+            swift: SwiftParser::new(),
+            scala: ScalaParser::new(),
         }
     }
 
@@ -74,10 +85,10 @@ impl ParserManager {
             Language::Ruby => &self.ruby,
             Language::Php => &self.php,
             Language::Kotlin => &self.kotlin,
-            // C/C++ use C# parser as closest approximation until dedicated parsers are added
-            Language::Cpp | Language::C => &self.csharp,
-            // Swift/Scala use Java parser as closest approximation
-            Language::Swift | Language::Scala => &self.java,
+            Language::C => &self.c,
+            Language::Cpp => &self.cpp,
+            Language::Swift => &self.swift,
+            Language::Scala => &self.scala,
         }
     }
 
@@ -105,7 +116,7 @@ impl ParserManager {
             return Ok(cached);
         }
 
-        // Parse (fallback parsers may set the wrong language, e.g. CSharp for C files)
+        // Parse
         let parser = self.parser_for(lang);
         let mut result = parser.parse(source, path)?;
         result.language = lang;
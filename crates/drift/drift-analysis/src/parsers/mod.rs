@@ -1,6 +1,7 @@
-//! Tree-sitter parser subsystem — 10 languages, thread_local instances, parse cache.
+//! Tree-sitter parser subsystem — 12 languages, thread_local instances, parse cache.
 
 pub mod cache;
+pub mod complexity;
 pub mod error_tolerant;
 pub mod languages;
 pub mod macros;
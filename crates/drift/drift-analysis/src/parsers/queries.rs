@@ -27,8 +27,10 @@ pub fn structure_query_for(lang: Language) -> &'static str {
         Language::Ruby => RUBY_STRUCTURE_QUERY,
         Language::Php => PHP_STRUCTURE_QUERY,
         Language::Kotlin => KOTLIN_STRUCTURE_QUERY,
-        Language::Cpp | Language::C => CSHARP_STRUCTURE_QUERY,
-        Language::Swift | Language::Scala => JAVA_STRUCTURE_QUERY,
+        Language::C => C_STRUCTURE_QUERY,
+        Language::Cpp => CPP_STRUCTURE_QUERY,
+        Language::Swift => SWIFT_STRUCTURE_QUERY,
+        Language::Scala => SCALA_STRUCTURE_QUERY,
     }
 }
 
@@ -44,8 +46,10 @@ pub fn calls_query_for(lang: Language) -> &'static str {
         Language::Ruby => RUBY_CALLS_QUERY,
         Language::Php => PHP_CALLS_QUERY,
         Language::Kotlin => KOTLIN_CALLS_QUERY,
-        Language::Cpp | Language::C => CSHARP_CALLS_QUERY,
-        Language::Swift | Language::Scala => JAVA_CALLS_QUERY,
+        Language::C => C_CALLS_QUERY,
+        Language::Cpp => CPP_CALLS_QUERY,
+        Language::Swift => SWIFT_CALLS_QUERY,
+        Language::Scala => SCALA_CALLS_QUERY,
     }
 }
 
@@ -395,3 +399,183 @@ const KOTLIN_CALLS_QUERY: &str = r#"
 (try_expression) @try_catch
 (throw) @throw
 "#;
+
+// ---- C ----
+
+const C_STRUCTURE_QUERY: &str = r#"
+(function_definition
+  declarator: (function_declarator
+    declarator: (identifier) @function.name)) @function.def
+
+(struct_specifier
+  name: (type_identifier) @struct.name
+  body: (field_declaration_list)) @struct.def
+
+(union_specifier
+  name: (type_identifier) @union.name
+  body: (field_declaration_list)) @union.def
+
+(enum_specifier
+  name: (type_identifier) @enum.name
+  body: (enumerator_list)) @enum.def
+
+(preproc_include) @import
+"#;
+
+const C_CALLS_QUERY: &str = r#"
+(call_expression
+  function: (identifier) @call.name) @call
+
+(call_expression
+  function: (field_expression
+    argument: (identifier) @call.receiver
+    field: (field_identifier) @call.method)) @call.member
+
+(string_literal) @string_literal
+(number_literal) @numeric_literal
+"#;
+
+// ---- C++ ----
+
+const CPP_STRUCTURE_QUERY: &str = r#"
+(function_definition
+  declarator: (function_declarator
+    declarator: (identifier) @function.name)) @function.def
+
+(function_definition
+  declarator: (function_declarator
+    declarator: (field_identifier) @method.name)) @method.def
+
+(function_definition
+  declarator: (function_declarator
+    declarator: (qualified_identifier
+      name: (identifier) @method.name))) @method.out_of_line
+
+(class_specifier
+  name: (type_identifier) @class.name
+  body: (field_declaration_list)) @class.def
+
+(struct_specifier
+  name: (type_identifier) @struct.name
+  body: (field_declaration_list)) @struct.def
+
+(enum_specifier
+  name: (type_identifier) @enum.name) @enum.def
+
+(namespace_definition) @namespace
+
+(preproc_include) @import
+"#;
+
+const CPP_CALLS_QUERY: &str = r#"
+(call_expression
+  function: (identifier) @call.name) @call
+
+(call_expression
+  function: (field_expression
+    argument: (_) @call.receiver
+    field: (field_identifier) @call.method)) @call.member
+
+(call_expression
+  function: (qualified_identifier
+    scope: (_) @call.receiver
+    name: (identifier) @call.method)) @call.qualified
+
+(string_literal) @string_literal
+(raw_string_literal) @string_literal
+(number_literal) @numeric_literal
+
+(try_statement) @try_catch
+(throw_statement) @throw
+"#;
+
+// ---- Swift ----
+
+const SWIFT_STRUCTURE_QUERY: &str = r#"
+(function_declaration
+  name: (simple_identifier) @function.name) @function.def
+
+(init_declaration) @constructor.def
+
+(class_declaration
+  name: (type_identifier) @class.name) @class.def
+
+(class_declaration
+  name: (user_type) @extension.name) @extension.def
+
+(protocol_declaration
+  name: (type_identifier) @interface.name) @interface.def
+
+(lambda_literal) @function.closure
+
+(import_declaration) @import
+"#;
+
+const SWIFT_CALLS_QUERY: &str = r#"
+(call_expression
+  (simple_identifier) @call.name) @call
+
+(call_expression
+  (navigation_expression
+    target: (_) @call.receiver
+    suffix: (navigation_suffix
+      suffix: (simple_identifier) @call.method))) @call.member
+
+(attribute
+  (user_type
+    (type_identifier) @decorator.name)) @decorator
+
+(line_string_literal) @string_literal
+(multi_line_string_literal) @string_literal
+(integer_literal) @numeric_literal
+(real_literal) @numeric_literal
+
+(do_statement) @try_catch
+(try_expression) @try
+"#;
+
+// ---- Scala ----
+
+const SCALA_STRUCTURE_QUERY: &str = r#"
+(function_definition
+  name: (identifier) @function.name) @function.def
+
+(function_declaration
+  name: (identifier) @function.name) @function.decl
+
+(class_definition
+  name: (identifier) @class.name) @class.def
+
+(object_definition
+  name: (identifier) @object.name) @object.def
+
+(trait_definition
+  name: (identifier) @trait.name) @trait.def
+
+(lambda_expression) @function.lambda
+
+(import_declaration) @import
+
+(package_clause) @package
+"#;
+
+const SCALA_CALLS_QUERY: &str = r#"
+(call_expression
+  function: (identifier) @call.name) @call
+
+(call_expression
+  function: (field_expression
+    value: (_) @call.receiver
+    field: (identifier) @call.method)) @call.member
+
+(annotation
+  name: (_) @decorator.name) @decorator
+
+(string) @string_literal
+(interpolated_string_expression) @string_literal
+(integer_literal) @numeric_literal
+(floating_point_literal) @numeric_literal
+
+(try_expression) @try_catch
+(throw_expression) @throw
+"#;
@@ -24,6 +24,9 @@ pub struct ParseResult {
     // Call & reference extraction
     pub call_sites: Vec<CallSite>,
     pub decorators: Vec<DecoratorInfo>,
+    /// Variables and fields whose type is known from their declaration.
+    #[serde(default)]
+    pub type_bindings: Vec<TypeBinding>,
 
     // Literal extraction
     pub string_literals: Vec<StringLiteralInfo>,
@@ -51,6 +54,7 @@ impl Default for ParseResult {
             exports: Vec::new(),
             call_sites: Vec::new(),
             decorators: Vec::new(),
+            type_bindings: Vec::new(),
             string_literals: Vec::new(),
             numeric_literals: Vec::new(),
             error_handling: Vec::new(),
@@ -85,6 +89,27 @@ pub struct FunctionInfo {
     pub doc_comment: Option<String>,
     pub body_hash: u64,
     pub signature_hash: u64,
+    #[serde(default)]
+    pub complexity: ComplexityMetrics,
+}
+
+/// Complexity of a function body, measured from its AST during parsing.
+/// The default (all zero) means the function was not measured.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ComplexityMetrics {
+    /// McCabe cyclomatic complexity: 1 + decision points.
+    pub cyclomatic: u32,
+    /// Cognitive complexity, per the SonarSource rules.
+    pub cognitive: u32,
+    /// Deepest nesting of control structures.
+    pub max_nesting: u32,
+    pub parameter_count: u32,
+}
+
+impl ComplexityMetrics {
+    pub fn is_measured(&self) -> bool {
+        self.cyclomatic > 0
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -140,6 +165,33 @@ pub struct CallSite {
     pub is_await: bool,
 }
 
+/// A name whose type follows from its declaration or initializer, e.g.
+/// `const repo = new UserRepo()`, `UserRepo repo`, `r := &UserRepo{}` or
+/// `self.repo = repo`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TypeBinding {
+    /// Bound name: a local (`repo`) or a field on `this`/`self` (`this.repo`).
+    pub name: String,
+    /// Declared type, constructed type, callee or aliased name, per `kind`.
+    pub type_name: String,
+    pub kind: TypeBindingKind,
+    pub line: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TypeBindingKind {
+    /// Type annotation or typed declaration: `repo: UserRepo`, `UserRepo repo`.
+    Declared,
+    /// Constructor or literal: `new UserRepo()`, `&UserRepo{}`.
+    Constructed,
+    /// Result of calling `type_name`: `NewUserRepo()`, Kotlin/Python `UserRepo()`.
+    Call,
+    /// Copy of another name: `self.repo = repo`.
+    Alias,
+    /// Go method receiver: `func (s *Service) Find()`.
+    Receiver,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ImportInfo {
     pub source: String,
@@ -268,6 +320,81 @@ pub struct ParameterInfo {
     pub is_rest: bool,
 }
 
+impl ParameterInfo {
+    /// Names the parameter binds: usually just `name`, but a destructuring
+    /// parameter binds each name in its pattern, e.g. `params` in Rust's
+    /// `Query(params): Query<Params>` or TypeScript's `{ params }: Props`,
+    /// and `id` in `{ params: id }`.
+    pub fn bindings(&self) -> Vec<&str> {
+        let name = self.name.as_str();
+        if name.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '$') {
+            return vec![name.trim_start_matches('$')];
+        }
+        // The pattern ends at its top-level type annotation or default; a
+        // `::` path separator does not end it.
+        let bytes = name.as_bytes();
+        let mut depth = 0i32;
+        let mut end = name.len();
+        for (i, &b) in bytes.iter().enumerate() {
+            match b {
+                b'(' | b'[' | b'{' | b'<' => depth += 1,
+                b')' | b']' | b'}' | b'>' => depth -= 1,
+                b':' if bytes.get(i + 1) == Some(&b':') || (i > 0 && bytes[i - 1] == b':') => {}
+                b':' | b'=' if depth == 0 => {
+                    end = i;
+                    break;
+                }
+                _ => {}
+            }
+        }
+        let pattern = &name[..end];
+        let mut names = Vec::new();
+        let mut depth = 0i32;
+        // Depth of the default value being skipped, as in `{ a = b }`.
+        let mut default_at: Option<i32> = None;
+        let mut chars = pattern.char_indices().peekable();
+        while let Some((start, c)) = chars.next() {
+            if c.is_alphabetic() || c == '_' {
+                let mut len = pattern.len() - start;
+                while let Some(&(i, next)) = chars.peek() {
+                    if !(next.is_alphanumeric() || next == '_') {
+                        len = i - start;
+                        break;
+                    }
+                    chars.next();
+                }
+                let word = &pattern[start..start + len];
+                let after = pattern[start + len..].trim_start();
+                // `Query` in `Query(params)` names the extractor and `a` in
+                // `{ a: b }` names a field; neither is a binding.
+                let is_key = after.starts_with(':') && !after.starts_with("::");
+                if default_at.is_none()
+                    && !is_key
+                    && !after.starts_with(['(', '{'])
+                    && !after.starts_with("::")
+                    && !matches!(word, "mut" | "ref")
+                {
+                    names.push(word);
+                }
+                continue;
+            }
+            match c {
+                '(' | '[' | '{' | '<' => depth += 1,
+                ')' | ']' | '}' | '>' => {
+                    if default_at == Some(depth) {
+                        default_at = None;
+                    }
+                    depth -= 1;
+                }
+                ',' if default_at == Some(depth) => default_at = None,
+                '=' if default_at.is_none() => default_at = Some(depth),
+                _ => {}
+            }
+        }
+        names
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PropertyInfo {
     pub name: String,
@@ -275,6 +402,18 @@ pub struct PropertyInfo {
     pub is_static: bool,
     pub is_readonly: bool,
     pub visibility: Visibility,
+    #[serde(default)]
+    pub line: u32,
+    /// Annotations, attributes and decorators on the declaration.
+    #[serde(default)]
+    pub decorators: Vec<DecoratorInfo>,
+    /// Go struct tag, without the backquotes.
+    #[serde(default)]
+    pub tag: Option<String>,
+    /// Source of the initializer expression, e.g. `createField(...)` in
+    /// `TableField<R, Long> ID = createField(...)`.
+    #[serde(default)]
+    pub initializer: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
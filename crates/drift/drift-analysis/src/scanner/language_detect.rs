@@ -100,10 +100,10 @@ impl Language {
             Language::Ruby => tree_sitter_ruby::LANGUAGE.into(),
             Language::Php => tree_sitter_php::LANGUAGE_PHP.into(),
             Language::Kotlin => tree_sitter_kotlin_sg::LANGUAGE.into(),
-            // C/C++ use C# grammar as fallback until tree-sitter-c/tree-sitter-cpp deps are added
-            Language::Cpp | Language::C => tree_sitter_c_sharp::LANGUAGE.into(),
-            // Swift/Scala use Java grammar as fallback until dedicated deps are added
-            Language::Swift | Language::Scala => tree_sitter_java::LANGUAGE.into(),
+            Language::C => tree_sitter_c::LANGUAGE.into(),
+            Language::Cpp => tree_sitter_cpp::LANGUAGE.into(),
+            Language::Swift => tree_sitter_swift::LANGUAGE.into(),
+            Language::Scala => tree_sitter_scala::LANGUAGE.into(),
         }
     }
 
@@ -0,0 +1,264 @@
+//! Secret scanning across git history.
+//!
+//! Walks commits oldest-first with git2, scans the lines each commit adds
+//! with the compiled secret patterns, and de-duplicates findings by
+//! fingerprint so a secret is reported once, at the commit that introduced
+//! it. Merges are scanned for the lines added while resolving them. Scans
+//! resume from a previous [`SecretHistory`], so CI only scans the commits
+//! added since.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::Path;
+
+use drift_core::errors::DetectionError;
+
+use super::secrets::CompiledSecretDetector;
+use super::types::{HistoricalSecret, Secret, SecretHistory};
+
+/// Diffs of files larger than this are skipped (generated bundles, dumps).
+const MAX_FILE_BYTES: u64 = 1024 * 1024;
+
+/// Scans the lines added by each commit for secrets.
+pub struct SecretHistoryScanner {
+    detector: CompiledSecretDetector,
+    max_commits: Option<usize>,
+}
+
+impl SecretHistoryScanner {
+    pub fn new() -> Self {
+        Self {
+            detector: CompiledSecretDetector::new(),
+            max_commits: None,
+        }
+    }
+
+    /// Stop each scan after `max` commits; resume later to continue.
+    pub fn with_max_commits(mut self, max: usize) -> Self {
+        self.max_commits = Some(max);
+        self
+    }
+
+    /// Scan the full history reachable from HEAD.
+    pub fn scan(&self, repo_path: &Path) -> Result<SecretHistory, DetectionError> {
+        self.resume(repo_path, SecretHistory::default())
+    }
+
+    /// Scan the commits reachable from HEAD that `previous` has not seen,
+    /// merging their findings into it. If none of the commits `previous`
+    /// scanned up to are still in the repository (history was rewritten),
+    /// the full history is rescanned.
+    pub fn resume(&self, repo_path: &Path, previous: SecretHistory) -> Result<SecretHistory, DetectionError> {
+        let repo = git2::Repository::discover(repo_path).map_err(git_error)?;
+        let head = match repo.head().and_then(|h| h.peel_to_commit()) {
+            Ok(commit) => commit,
+            // Nothing committed yet.
+            Err(_) => return Ok(previous),
+        };
+
+        let mut history = previous;
+        let mut revwalk = repo.revwalk().map_err(git_error)?;
+        revwalk.push(head.id()).map_err(git_error)?;
+        // State saved before tips were tracked only has the last commit.
+        let known: Vec<String> = if history.scanned_tips.is_empty() {
+            history.last_commit.iter().cloned().collect()
+        } else {
+            history.scanned_tips.clone()
+        };
+        let previous_tips: Vec<git2::Oid> = known
+            .iter()
+            .filter_map(|sha| git2::Oid::from_str(sha).ok())
+            .filter(|oid| repo.find_commit(*oid).is_ok())
+            .collect();
+        if previous_tips.is_empty() && !known.is_empty() {
+            tracing::warn!("last scanned commits not found, rescanning full history");
+            history = SecretHistory::default();
+        }
+        for tip in &previous_tips {
+            revwalk.hide(*tip).map_err(git_error)?;
+        }
+        // Parents before children, so the first sighting is the introducing
+        // commit and a capped scan leaves no gaps behind it.
+        revwalk
+            .set_sorting(git2::Sort::TOPOLOGICAL | git2::Sort::REVERSE)
+            .map_err(git_error)?;
+
+        let mut index: HashMap<String, usize> = history
+            .secrets
+            .iter()
+            .enumerate()
+            .map(|(i, s)| (s.fingerprint.clone(), i))
+            .collect();
+
+        let mut scanned: Vec<git2::Oid> = Vec::new();
+        let mut parents: HashSet<git2::Oid> = HashSet::new();
+        for oid in revwalk {
+            if self.max_commits.is_some_and(|max| scanned.len() >= max) {
+                break;
+            }
+            let commit = repo.find_commit(oid.map_err(git_error)?).map_err(git_error)?;
+            history.last_commit = Some(commit.id().to_string());
+            history.commits_scanned += 1;
+            scanned.push(commit.id());
+            parents.extend(commit.parent_ids());
+
+            let author = commit.author();
+            let author = match (author.name(), author.email()) {
+                (Some(name), Some(email)) => format!("{name} <{email}>"),
+                (Some(name), None) => name.to_string(),
+                (None, email) => email.unwrap_or("unknown").to_string(),
+            };
+
+            for (file, lines) in added_lines(&repo, &commit)? {
+                for (secret, fingerprint) in self.detect_added(&file, &lines) {
+                    match index.get(&fingerprint) {
+                        Some(&i) => {
+                            let known = &mut history.secrets[i];
+                            known.occurrences += 1;
+                            if !known.files.contains(&file) {
+                                known.files.push(file.clone());
+                            }
+                        }
+                        None => {
+                            index.insert(fingerprint.clone(), history.secrets.len());
+                            history.secrets.push(HistoricalSecret {
+                                secret,
+                                fingerprint,
+                                first_commit: commit.id().to_string(),
+                                author: author.clone(),
+                                timestamp: commit.time().seconds(),
+                                occurrences: 1,
+                                files: vec![file.clone()],
+                                present_at_head: false,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+
+        history.scanned_tips = scanned_tips(&repo, &previous_tips, &scanned, &parents);
+        self.mark_present_at_head(&repo, &head, &mut history)?;
+        Ok(history)
+    }
+
+    /// Detect secrets in a file's added lines, reporting new-side line numbers.
+    fn detect_added(&self, file: &str, lines: &[(u32, String)]) -> Vec<(Secret, String)> {
+        let content: Vec<&str> = lines.iter().map(|(_, text)| text.as_str()).collect();
+        let mut found = self.detector.detect_fingerprinted(&content.join("\n"), file);
+        for (secret, _) in &mut found {
+            if let Some((line, _)) = lines.get(secret.line as usize - 1) {
+                secret.line = *line;
+            }
+        }
+        found
+    }
+
+    /// Re-scan the HEAD version of every file a secret was ever added to.
+    fn mark_present_at_head(
+        &self,
+        repo: &git2::Repository,
+        head: &git2::Commit,
+        history: &mut SecretHistory,
+    ) -> Result<(), DetectionError> {
+        let tree = head.tree().map_err(git_error)?;
+        let mut files: Vec<&str> = history.secrets.iter().flat_map(|s| s.files.iter().map(String::as_str)).collect();
+        files.sort_unstable();
+        files.dedup();
+
+        let mut present = HashSet::new();
+        for file in files {
+            let Ok(entry) = tree.get_path(Path::new(file)) else { continue };
+            let Ok(blob) = repo.find_blob(entry.id()) else { continue };
+            if blob.size() as u64 > MAX_FILE_BYTES || blob.is_binary() {
+                continue;
+            }
+            let content = String::from_utf8_lossy(blob.content());
+            present.extend(self.detector.detect_fingerprinted(&content, file).into_iter().map(|(_, f)| f));
+        }
+        for secret in &mut history.secrets {
+            secret.present_at_head = present.contains(&secret.fingerprint);
+        }
+        Ok(())
+    }
+}
+
+impl Default for SecretHistoryScanner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The commits a scan has covered up to: scanned commits no other scanned
+/// commit builds on, and earlier tips that are not ancestors of them.
+fn scanned_tips(
+    repo: &git2::Repository,
+    previous: &[git2::Oid],
+    scanned: &[git2::Oid],
+    parents: &HashSet<git2::Oid>,
+) -> Vec<String> {
+    let mut tips: Vec<git2::Oid> = scanned.iter().filter(|oid| !parents.contains(oid)).copied().collect();
+    for &old in previous {
+        let covered = parents.contains(&old)
+            || tips.iter().any(|&tip| repo.graph_descendant_of(tip, old).unwrap_or(false));
+        if !covered {
+            tips.push(old);
+        }
+    }
+    tips.iter().map(|oid| oid.to_string()).collect()
+}
+
+/// Lines a commit adds, by file. A merge adds only the lines none of its
+/// parents had, i.e. those written while resolving it.
+fn added_lines(repo: &git2::Repository, commit: &git2::Commit) -> Result<BTreeMap<String, Vec<(u32, String)>>, DetectionError> {
+    let mut added = added_since(repo, commit, commit.parent(0).ok())?;
+    for parent in commit.parents().skip(1) {
+        let theirs = added_since(repo, commit, Some(parent))?;
+        added.retain(|file, lines| {
+            let Some(other) = theirs.get(file) else { return false };
+            let other: HashSet<&str> = other.iter().map(|(_, text)| text.as_str()).collect();
+            lines.retain(|(_, text)| other.contains(text.as_str()));
+            !lines.is_empty()
+        });
+    }
+    Ok(added)
+}
+
+/// Lines a commit adds relative to `parent` (or to nothing), by file.
+fn added_since(
+    repo: &git2::Repository,
+    commit: &git2::Commit,
+    parent: Option<git2::Commit>,
+) -> Result<BTreeMap<String, Vec<(u32, String)>>, DetectionError> {
+    let tree = commit.tree().map_err(git_error)?;
+    let parent_tree = parent.and_then(|p| p.tree().ok());
+    let mut options = git2::DiffOptions::new();
+    options.context_lines(0).max_size(MAX_FILE_BYTES as i64);
+    let diff = repo
+        .diff_tree_to_tree(parent_tree.as_ref(), Some(&tree), Some(&mut options))
+        .map_err(git_error)?;
+
+    let mut added: BTreeMap<String, Vec<(u32, String)>> = BTreeMap::new();
+    diff.foreach(
+        &mut |_, _| true,
+        None,
+        None,
+        Some(&mut |delta, _, line| {
+            if line.origin() == '+' {
+                if let (Some(path), Some(lineno)) = (delta.new_file().path(), line.new_lineno()) {
+                    let text = String::from_utf8_lossy(line.content());
+                    added
+                        .entry(path.to_string_lossy().replace('\\', "/"))
+                        .or_default()
+                        .push((lineno, text.trim_end_matches(['\n', '\r']).to_string()));
+                }
+            }
+            true
+        }),
+    )
+    .map_err(git_error)?;
+    Ok(added)
+}
+
+fn git_error(e: git2::Error) -> DetectionError {
+    DetectionError::Git(e.message().to_string())
+}
@@ -4,6 +4,7 @@ pub mod types;
 pub mod extractor;
 pub mod magic_numbers;
 pub mod secrets;
+pub mod history;
 pub mod entropy;
 pub mod inconsistency;
 pub mod dead_constants;
@@ -125,9 +125,25 @@ impl CompiledSecretDetector {
     /// Detect secrets using pre-compiled patterns (no regex recompilation).
     pub fn detect(&self, content: &str, file_path: &str) -> Vec<Secret> {
         let mut results = Vec::new();
+        self.scan(content, file_path, |secret, _| results.push(secret));
+        results
+    }
+
+    /// Detect secrets along with a fingerprint of each matched value, so the
+    /// same secret can be recognised across files and commits without
+    /// keeping the value itself.
+    pub fn detect_fingerprinted(&self, content: &str, file_path: &str) -> Vec<(Secret, String)> {
+        let mut results = Vec::new();
+        self.scan(content, file_path, |secret, matched| {
+            let fingerprint = fingerprint(secret.pattern_name.as_str(), matched);
+            results.push((secret, fingerprint));
+        });
+        results
+    }
 
+    fn scan(&self, content: &str, file_path: &str, mut emit: impl FnMut(Secret, &str)) {
         if content.bytes().any(|b| b == 0) {
-            return results;
+            return;
         }
 
         for (pattern_def, re) in &self.compiled {
@@ -156,7 +172,7 @@ impl CompiledSecretDetector {
 
                     let redacted = redact_value(matched);
 
-                    results.push(Secret {
+                    emit(Secret {
                         pattern_name: pattern_def.name.to_string(),
                         redacted_value: redacted,
                         file: file_path.to_string(),
@@ -165,12 +181,10 @@ impl CompiledSecretDetector {
                         entropy: ent,
                         confidence: confidence.min(1.0),
                         cwe_ids: pattern_def.cwe_ids.to_vec(),
-                    });
+                    }, matched);
                 }
             }
         }
-
-        results
     }
 }
 
@@ -180,6 +194,12 @@ pub fn detect_secrets(content: &str, file_path: &str) -> Vec<Secret> {
     CompiledSecretDetector::new().detect(content, file_path)
 }
 
+/// Stable fingerprint of a matched secret: xxh3-128 of pattern name and value.
+fn fingerprint(pattern_name: &str, value: &str) -> String {
+    let key = format!("{pattern_name}\0{value}");
+    format!("{:032x}", xxhash_rust::xxh3::xxh3_128(key.as_bytes()))
+}
+
 /// Redact a secret value, keeping only the first 4 and last 4 characters.
 fn redact_value(value: &str) -> String {
     if value.len() <= 12 {
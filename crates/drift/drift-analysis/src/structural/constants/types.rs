@@ -42,6 +42,41 @@ pub struct Secret {
     pub cwe_ids: Vec<u32>,
 }
 
+/// A secret found in git history, de-duplicated by fingerprint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoricalSecret {
+    /// The first sighting: file and line in the introducing commit.
+    pub secret: Secret,
+    /// Hash of pattern and value; the same secret added again shares it.
+    pub fingerprint: String,
+    /// Commit that first added the secret.
+    pub first_commit: String,
+    /// Author of that commit (`Name <email>`).
+    pub author: String,
+    /// Commit time (seconds since epoch).
+    pub timestamp: i64,
+    /// Number of commits that added the secret.
+    pub occurrences: u32,
+    /// Files the secret was added to.
+    pub files: Vec<String>,
+    /// Whether the secret is still in one of those files at HEAD.
+    pub present_at_head: bool,
+}
+
+/// Result of a history scan, and the state to resume it from.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SecretHistory {
+    /// Last commit scanned.
+    pub last_commit: Option<String>,
+    /// Commits whose ancestors have all been scanned; a resumed scan skips
+    /// everything reachable from them.
+    #[serde(default)]
+    pub scanned_tips: Vec<String>,
+    /// Commits scanned so far, across resumes.
+    pub commits_scanned: usize,
+    pub secrets: Vec<HistoricalSecret>,
+}
+
 /// A detected magic number (unnamed numeric literal in code).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MagicNumber {
@@ -107,6 +142,20 @@ impl SecretSeverity {
             Self::Suppressed => "suppressed",
         }
     }
+
+    /// Inverse of [`Self::name`].
+    pub fn parse_str(name: &str) -> Option<Self> {
+        Some(match name {
+            "critical" => Self::Critical,
+            "high" => Self::High,
+            "medium" => Self::Medium,
+            "low" => Self::Low,
+            "info" => Self::Info,
+            "false_positive" => Self::FalsePositive,
+            "suppressed" => Self::Suppressed,
+            _ => return None,
+        })
+    }
 }
 
 impl std::fmt::Display for SecretSeverity {
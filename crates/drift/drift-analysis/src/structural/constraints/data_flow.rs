@@ -0,0 +1,416 @@
+//! DataFlow invariants — verified against the call graph.
+//!
+//! Target formats:
+//! - `"<from> -> <via> -> <to>"` — every call path from `from` to a call of
+//!   `to` must pass through `via` first.
+//! - `"<from> !-> <to>"` — no call path from `from` may reach `to`.
+//!
+//! `from` is a file glob (`routes/**`), a model field (`User.email`, `User.*`
+//! for every sensitive field, or a sensitivity class such as `User.pii`), or
+//! a function name glob. `via` and `to` are matched against calls the way
+//! taint sanitizers and sinks are: `db.*` matches `db.query`, and
+//! `validateInput` matches `validator.validateInput`.
+
+use drift_core::types::collections::{FxHashMap, FxHashSet};
+use petgraph::graph::NodeIndex;
+use petgraph::visit::EdgeRef;
+
+use crate::boundaries::types::SensitiveField;
+use crate::call_graph::types::CallGraph;
+use crate::graph::reachability::field_flow::{track_field_flow, TrackedField};
+use crate::graph::taint::registry::{SanitizerPattern, SinkPattern, TaintRegistry};
+use crate::graph::taint::types::{SanitizerType, SinkType};
+use crate::parsers::types::ParseResult;
+
+use super::types::ConstraintViolation;
+
+/// A parsed DataFlow constraint target.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataFlowSpec {
+    pub from: FlowOrigin,
+    /// Call every path must pass through. `None` forbids the flow outright.
+    pub via: Option<String>,
+    pub to: String,
+}
+
+/// Where tracked data originates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FlowOrigin {
+    /// Functions in files matching a glob.
+    Files(String),
+    /// Fields of a model; `field` may be `*` or a sensitivity class.
+    Field { model: String, field: String },
+    /// Functions whose name matches a glob.
+    Functions(String),
+}
+
+impl DataFlowSpec {
+    /// Parse a constraint target. Returns `None` if it is malformed.
+    pub fn parse(target: &str) -> Option<Self> {
+        if let Some((from, to)) = target.split_once("!->") {
+            return Some(Self {
+                from: FlowOrigin::parse(from.trim())?,
+                via: None,
+                to: non_empty(to)?,
+            });
+        }
+        let parts: Vec<&str> = target.split("->").collect();
+        match parts.as_slice() {
+            [from, via, to] => Some(Self {
+                from: FlowOrigin::parse(from.trim())?,
+                via: Some(non_empty(via)?),
+                to: non_empty(to)?,
+            }),
+            _ => None,
+        }
+    }
+}
+
+impl FlowOrigin {
+    fn parse(s: &str) -> Option<Self> {
+        if s.is_empty() {
+            return None;
+        }
+        if s.contains('/') {
+            return Some(Self::Files(s.to_string()));
+        }
+        match s.split_once('.') {
+            Some((model, field)) if model.starts_with(char::is_uppercase) && !field.is_empty() => {
+                Some(Self::Field { model: model.to_string(), field: field.to_string() })
+            }
+            _ => Some(Self::Functions(s.to_string())),
+        }
+    }
+}
+
+impl std::fmt::Display for FlowOrigin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Files(glob) | Self::Functions(glob) => f.write_str(glob),
+            Self::Field { model, field } => write!(f, "{}.{}", model, field),
+        }
+    }
+}
+
+fn non_empty(s: &str) -> Option<String> {
+    let s = s.trim();
+    (!s.is_empty()).then(|| s.to_string())
+}
+
+/// Call graph plus the per-function facts DataFlow constraints need.
+pub struct DataFlowContext {
+    graph: CallGraph,
+    /// Calls made within each function: (callee expression, line).
+    calls: FxHashMap<NodeIndex, Vec<(String, u32)>>,
+    /// Parameter, return and owning class types of each function.
+    types: FxHashMap<NodeIndex, Vec<String>>,
+    sensitive_fields: Vec<SensitiveField>,
+}
+
+impl DataFlowContext {
+    pub fn new(graph: CallGraph) -> Self {
+        Self {
+            graph,
+            calls: FxHashMap::default(),
+            types: FxHashMap::default(),
+            sensitive_fields: Vec::new(),
+        }
+    }
+
+    /// Record the calls each function makes and the types it handles.
+    pub fn with_parse_results(mut self, parse_results: &[ParseResult]) -> Self {
+        let by_file: FxHashMap<&str, &ParseResult> =
+            parse_results.iter().map(|pr| (pr.file.as_str(), pr)).collect();
+
+        for idx in self.graph.graph.node_indices() {
+            let node = &self.graph.graph[idx];
+            let Some(pr) = by_file.get(node.file.as_str()) else {
+                continue;
+            };
+            let calls: Vec<(String, u32)> = pr
+                .call_sites
+                .iter()
+                .filter(|call| call.line >= node.line && call.line <= node.end_line)
+                .map(|call| match &call.receiver {
+                    Some(receiver) => (format!("{}.{}", receiver, call.callee_name), call.line),
+                    None => (call.callee_name.clone(), call.line),
+                })
+                .collect();
+            self.calls.insert(idx, calls);
+
+            let func = pr
+                .functions
+                .iter()
+                .find(|f| f.name == node.name && f.line == node.line)
+                .or_else(|| pr.functions.iter().find(|f| f.name == node.name));
+            if let Some(func) = func {
+                let mut types: Vec<String> = func
+                    .parameters
+                    .iter()
+                    .filter_map(|p| p.type_annotation.clone())
+                    .chain(func.return_type.clone())
+                    .collect();
+                if let Some((owner, _)) = func.qualified_name.as_deref().and_then(|q| q.rsplit_once('.')) {
+                    types.push(owner.to_string());
+                }
+                self.types.insert(idx, types);
+            }
+        }
+        self
+    }
+
+    /// Sensitive fields from boundary detection, used to expand `Model.*`
+    /// and `Model.<sensitivity>` origins.
+    pub fn with_sensitive_fields(mut self, fields: Vec<SensitiveField>) -> Self {
+        self.sensitive_fields = fields;
+        self
+    }
+
+    pub fn graph(&self) -> &CallGraph {
+        &self.graph
+    }
+
+    /// Check `spec`, limiting origins to files containing `scope`.
+    pub fn verify(&self, spec: &DataFlowSpec, scope: Option<&str>) -> Vec<ConstraintViolation> {
+        let sink_type = SinkType::Custom(0);
+        let mut registry = TaintRegistry::new();
+        registry.add_sink(SinkPattern {
+            pattern: call_pattern(&spec.to),
+            sink_type,
+            required_sanitizers: Vec::new(),
+            framework: None,
+        });
+        if let Some(via) = &spec.via {
+            registry.add_sanitizer(SanitizerPattern {
+                pattern: call_pattern(via),
+                sanitizer_type: SanitizerType::Custom,
+                protects_against: vec![sink_type],
+                framework: None,
+            });
+        }
+
+        let mut violations = Vec::new();
+        for origin in self.origins(&spec.from, scope) {
+            match &spec.from {
+                FlowOrigin::Field { model, field } => {
+                    for tracked in self.tracked_fields(model, field) {
+                        let flow = track_field_flow(&self.graph, origin, &tracked, None);
+                        let transformed: FxHashSet<NodeIndex> =
+                            flow.path.iter().filter(|hop| hop.transformed).map(|hop| hop.node).collect();
+                        let label = format!("'{}'", tracked);
+                        violations.extend(self.trace(
+                            spec, &registry, origin, &label,
+                            Some(&flow.access_points), &transformed,
+                        ));
+                    }
+                }
+                _ => {
+                    violations.extend(self.trace(
+                        spec, &registry, origin, "Data",
+                        None, &FxHashSet::default(),
+                    ));
+                }
+            }
+        }
+        violations
+    }
+
+    fn origins(&self, from: &FlowOrigin, scope: Option<&str>) -> Vec<NodeIndex> {
+        let glob = match from {
+            FlowOrigin::Files(p) | FlowOrigin::Functions(p) => match glob::Pattern::new(p) {
+                Ok(glob) => Some(glob),
+                Err(_) => return Vec::new(),
+            },
+            FlowOrigin::Field { .. } => None,
+        };
+        self.graph
+            .graph
+            .node_indices()
+            .filter(|&idx| {
+                let node = &self.graph.graph[idx];
+                if !scope.map_or(true, |s| node.file.contains(s)) {
+                    return false;
+                }
+                match (from, &glob) {
+                    (FlowOrigin::Files(_), Some(glob)) => glob.matches(&node.file),
+                    (FlowOrigin::Functions(_), Some(glob)) => {
+                        glob.matches(&node.name)
+                            || node.qualified_name.as_deref().is_some_and(|q| glob.matches(q))
+                    }
+                    (FlowOrigin::Field { model, .. }, _) => self
+                        .types
+                        .get(&idx)
+                        .is_some_and(|types| types.iter().any(|t| mentions_type(t, model))),
+                    _ => false,
+                }
+            })
+            .collect()
+    }
+
+    fn tracked_fields(&self, model: &str, field: &str) -> Vec<TrackedField> {
+        let sensitive = self.sensitive_fields.iter().filter(|sf| sf.model_name == model);
+        let mut fields: Vec<TrackedField> = if field == "*" {
+            sensitive.map(|sf| TrackedField::new(model, sf.field_name.clone())).collect()
+        } else if is_sensitivity_class(field) {
+            let class = field.to_lowercase();
+            sensitive
+                .filter(|sf| sf.sensitivity.name() == class)
+                .map(|sf| TrackedField::new(model, sf.field_name.clone()))
+                .collect()
+        } else {
+            vec![TrackedField::new(model, field)]
+        };
+        fields.sort_by(|a, b| a.field.cmp(&b.field));
+        fields.dedup();
+        fields
+    }
+
+    /// Search forward from `origin` for calls to the sink on a path that
+    /// has not passed through `via`. The search state is (function,
+    /// protected), so a protected path does not hide an unprotected one.
+    fn trace(
+        &self,
+        spec: &DataFlowSpec,
+        registry: &TaintRegistry,
+        origin: NodeIndex,
+        label: &str,
+        allowed: Option<&FxHashSet<NodeIndex>>,
+        transformed: &FxHashSet<NodeIndex>,
+    ) -> Vec<ConstraintViolation> {
+        let graph = &self.graph.graph;
+        let no_calls = Vec::new();
+        let mut violations = Vec::new();
+        let mut reported: FxHashSet<NodeIndex> = FxHashSet::default();
+        let mut parents: FxHashMap<(NodeIndex, bool), (NodeIndex, bool)> = FxHashMap::default();
+        let mut visited = FxHashSet::default();
+        let mut queue = std::collections::VecDeque::new();
+        visited.insert((origin, false));
+        queue.push_back((origin, false));
+
+        while let Some((current, protected)) = queue.pop_front() {
+            let node = &graph[current];
+            let calls = self.calls.get(&current).unwrap_or(&no_calls);
+            let sanitized_by = |line: u32| {
+                protected
+                    || calls.iter().any(|(expr, l)| *l <= line && registry.match_sanitizer(expr).is_some())
+            };
+
+            // A sink called from this function
+            let sink_call = calls
+                .iter()
+                .filter(|(expr, _)| registry.match_sink(expr).is_some())
+                .find(|(_, line)| !sanitized_by(*line));
+            if let Some((expr, line)) = sink_call {
+                if reported.insert(current) {
+                    let mut path = self.path_to(&parents, (current, protected), transformed);
+                    path.push(format!("{} ({}:{})", expr, node.file, line));
+                    violations.push(self.violation(spec, origin, label, expr, path));
+                }
+            }
+
+            let is_via = self.node_matches(current, |expr| registry.match_sanitizer(expr).is_some());
+            for edge in graph.edges_directed(current, petgraph::Direction::Outgoing) {
+                let next = edge.target();
+                if allowed.is_some_and(|allowed| !allowed.contains(&next)) {
+                    continue;
+                }
+                let next_protected = is_via || sanitized_by(edge.weight().call_site_line);
+                // The sink may itself be a function in the graph
+                if !next_protected && self.node_matches(next, |expr| registry.match_sink(expr).is_some()) {
+                    if reported.insert(next) {
+                        let mut path = self.path_to(&parents, (current, protected), transformed);
+                        path.push(self.hop(next, transformed));
+                        let sink = graph[next].name.clone();
+                        violations.push(self.violation(spec, origin, label, &sink, path));
+                    }
+                    continue;
+                }
+                let state = (next, next_protected);
+                if visited.insert(state) {
+                    parents.insert(state, (current, protected));
+                    queue.push_back(state);
+                }
+            }
+        }
+        violations
+    }
+
+    fn node_matches(&self, idx: NodeIndex, matches: impl Fn(&str) -> bool) -> bool {
+        let node = &self.graph.graph[idx];
+        matches(&node.name) || node.qualified_name.as_deref().is_some_and(&matches)
+    }
+
+    fn path_to(
+        &self,
+        parents: &FxHashMap<(NodeIndex, bool), (NodeIndex, bool)>,
+        mut state: (NodeIndex, bool),
+        transformed: &FxHashSet<NodeIndex>,
+    ) -> Vec<String> {
+        let mut path = vec![self.hop(state.0, transformed)];
+        while let Some(&parent) = parents.get(&state) {
+            path.push(self.hop(parent.0, transformed));
+            state = parent;
+        }
+        path.reverse();
+        path
+    }
+
+    fn hop(&self, idx: NodeIndex, transformed: &FxHashSet<NodeIndex>) -> String {
+        let node = &self.graph.graph[idx];
+        if transformed.contains(&idx) {
+            format!("{}::{} (transformed)", node.file, node.name)
+        } else {
+            format!("{}::{}", node.file, node.name)
+        }
+    }
+
+    fn violation(
+        &self,
+        spec: &DataFlowSpec,
+        origin: NodeIndex,
+        label: &str,
+        sink: &str,
+        path: Vec<String>,
+    ) -> ConstraintViolation {
+        let node = &self.graph.graph[origin];
+        let (message, expected) = match &spec.via {
+            Some(via) => (
+                format!(
+                    "{} from '{}' reaches '{}' without passing through '{}'",
+                    label, node.name, sink, via
+                ),
+                format!("All paths from {} to {} pass through {}", spec.from, spec.to, via),
+            ),
+            None => (
+                format!("{} from '{}' reaches '{}'", label, node.name, sink),
+                format!("{} never reaches {}", spec.from, spec.to),
+            ),
+        };
+        ConstraintViolation {
+            file: node.file.clone(),
+            line: Some(node.line),
+            message,
+            expected,
+            actual: path.join(" -> "),
+            path,
+        }
+    }
+}
+
+/// Registry pattern for a call glob: `db.*` → `db`, which the taint
+/// registry matches as a dotted prefix.
+fn call_pattern(pattern: &str) -> String {
+    pattern.trim_end_matches('*').trim_end_matches('.').to_string()
+}
+
+fn is_sensitivity_class(field: &str) -> bool {
+    matches!(field.to_lowercase().as_str(), "pii" | "credentials" | "financial" | "health")
+}
+
+/// Whether a type annotation names `model` (`User`, `User[]`,
+/// `Promise<User>`, `Optional[User]`), not merely a longer identifier.
+fn mentions_type(annotation: &str, model: &str) -> bool {
+    annotation
+        .split(|c: char| !c.is_alphanumeric() && c != '_')
+        .any(|word| word == model)
+}
@@ -2,6 +2,9 @@
 
 use drift_core::types::collections::FxHashMap;
 
+use crate::parsers::types::ComplexityMetrics;
+
+use super::data_flow::{DataFlowContext, DataFlowSpec};
 use super::types::{Constraint, ConstraintViolation, InvariantType, VerificationResult};
 
 /// Detects invariant violations using AST-based analysis.
@@ -15,6 +18,8 @@ pub struct InvariantDetector {
     imports: FxHashMap<String, Vec<String>>,
     /// File sizes (line counts).
     file_sizes: FxHashMap<String, u32>,
+    /// Call graph facts for DataFlow constraints.
+    data_flow: Option<DataFlowContext>,
 }
 
 /// Minimal function info for constraint checking.
@@ -23,6 +28,8 @@ pub struct FunctionInfo {
     pub name: String,
     pub line: u32,
     pub is_exported: bool,
+    /// Parser metrics; unmeasured functions fall back to the per-file proxy.
+    pub complexity: ComplexityMetrics,
 }
 
 impl InvariantDetector {
@@ -31,6 +38,7 @@ impl InvariantDetector {
             functions: FxHashMap::default(),
             imports: FxHashMap::default(),
             file_sizes: FxHashMap::default(),
+            data_flow: None,
         }
     }
 
@@ -47,6 +55,12 @@ impl InvariantDetector {
         self.file_sizes.insert(file.to_string(), line_count);
     }
 
+    /// Provide the call graph DataFlow constraints are checked against.
+    /// Without it they pass vacuously.
+    pub fn set_data_flow(&mut self, context: DataFlowContext) {
+        self.data_flow = Some(context);
+    }
+
     /// Verify a constraint against the registered codebase data.
     pub fn verify(&self, constraint: &Constraint) -> VerificationResult {
         if !constraint.enabled {
@@ -69,7 +83,7 @@ impl InvariantDetector {
             InvariantType::MustColocate => self.check_must_colocate(constraint),
             InvariantType::MustSeparate => self.check_must_separate(constraint),
             InvariantType::MustFollow => self.check_must_follow(constraint),
-            InvariantType::DataFlow => vec![], // Requires call graph — deferred to Phase 6
+            InvariantType::DataFlow => self.check_data_flow(constraint),
         };
 
         VerificationResult {
@@ -93,6 +107,7 @@ impl InvariantDetector {
                 message: format!("Required symbol '{}' not found in codebase", target),
                 expected: format!("Symbol '{}' exists", target),
                 actual: "Not found".to_string(),
+                path: Vec::new(),
             }]
         }
     }
@@ -109,6 +124,7 @@ impl InvariantDetector {
                         message: format!("Forbidden symbol '{}' found", target),
                         expected: format!("Symbol '{}' does not exist", target),
                         actual: format!("Found at {}:{}", file, f.line),
+                        path: Vec::new(),
                     });
                 }
             }
@@ -136,6 +152,7 @@ impl InvariantDetector {
                         message: format!("'{}' must appear before '{}'", a, b),
                         expected: format!("{} (line {}) before {} (line {})", a, la, b, lb),
                         actual: format!("{} at line {}, {} at line {}", a, la, b, lb),
+                        path: Vec::new(),
                     });
                 }
             }
@@ -163,6 +180,7 @@ impl InvariantDetector {
                         message: format!("'{}' must appear after '{}'", a, b),
                         expected: format!("{} after {}", a, b),
                         actual: format!("{} at line {}, {} at line {}", a, la, b, lb),
+                        path: Vec::new(),
                     });
                 }
             }
@@ -188,6 +206,7 @@ impl InvariantDetector {
                             ),
                             expected: format!("{} naming", convention),
                             actual: f.name.clone(),
+                            path: Vec::new(),
                         });
                     }
                 }
@@ -219,6 +238,7 @@ impl InvariantDetector {
                             ),
                             expected: format!("No imports from {} to {}", allowed_dst, allowed_src),
                             actual: format!("{} imports {}", file, import),
+                            path: Vec::new(),
                         });
                     }
                 }
@@ -249,6 +269,7 @@ impl InvariantDetector {
                             ),
                             expected: format!("No imports from {} to {}", forbidden_src, forbidden_dst),
                             actual: format!("{} imports {}", file, import),
+                            path: Vec::new(),
                         });
                     }
                 }
@@ -271,6 +292,7 @@ impl InvariantDetector {
                         message: format!("File exceeds size limit: {} lines (max {})", size, limit),
                         expected: format!("<= {} lines", limit),
                         actual: format!("{} lines", size),
+                        path: Vec::new(),
                     });
                 }
             }
@@ -279,25 +301,65 @@ impl InvariantDetector {
     }
 
     fn check_complexity_limit(&self, constraint: &Constraint) -> Vec<ConstraintViolation> {
-        // Simplified: check function count per file as a proxy for complexity
-        let limit: usize = constraint.target.parse().unwrap_or(20);
-        let mut violations = Vec::new();
+        // Target: "N" bounds the function count per file; "metric:N[,metric:N]"
+        // bounds each function, where metric is cyclomatic, cognitive,
+        // nesting or params. Both forms can be combined.
+        let mut file_limit = None;
+        let mut limits = Vec::new();
+        for part in constraint.target.split(',') {
+            match part.split_once(':') {
+                Some((metric, value)) => {
+                    let Some(metric) = ComplexityMetric::parse(metric.trim()) else { continue };
+                    if let Ok(limit) = value.trim().parse::<u32>() {
+                        limits.push((metric, limit));
+                    }
+                }
+                None => file_limit = part.trim().parse::<usize>().ok().or(file_limit),
+            }
+        }
+        if file_limit.is_none() && limits.is_empty() {
+            file_limit = Some(20);
+        }
 
+        let mut violations = Vec::new();
         let files = self.scoped_files(constraint.scope.as_deref());
         for file in files {
-            if let Some(fns) = self.functions.get(file) {
-                if fns.len() > limit {
-                    violations.push(ConstraintViolation {
-                        file: file.to_string(),
-                        line: None,
-                        message: format!(
-                            "File has {} functions (max {})",
-                            fns.len(),
-                            limit
-                        ),
-                        expected: format!("<= {} functions", limit),
-                        actual: format!("{} functions", fns.len()),
-                    });
+            let Some(fns) = self.functions.get(file) else { continue };
+
+            if let Some(limit) = file_limit.filter(|limit| fns.len() > *limit) {
+                violations.push(ConstraintViolation {
+                    file: file.to_string(),
+                    line: None,
+                    message: format!(
+                        "File has {} functions (max {})",
+                        fns.len(),
+                        limit
+                    ),
+                    expected: format!("<= {} functions", limit),
+                    actual: format!("{} functions", fns.len()),
+                    path: Vec::new(),
+                });
+            }
+
+            // Functions the parser didn't measure have no metrics to check.
+            for f in fns.iter().filter(|f| f.complexity.is_measured()) {
+                for &(metric, limit) in &limits {
+                    let value = metric.value(&f.complexity);
+                    if value > limit {
+                        violations.push(ConstraintViolation {
+                            file: file.to_string(),
+                            line: Some(f.line),
+                            message: format!(
+                                "'{}' has {} (max {})",
+                                f.name,
+                                metric.describe(value),
+                                limit
+                            ),
+                            expected: format!("{} <= {}", metric.label(), limit),
+                            actual: metric.describe(value),
+                            path: Vec::new(),
+                        });
+                    }
                 }
             }
         }
@@ -323,6 +385,7 @@ impl InvariantDetector {
                     message: format!("'{}' and '{}' must be colocated", a, b),
                     expected: "Both in same file".to_string(),
                     actual: format!("{} in {}, {} in {}", a, fa, b, fb),
+                    path: Vec::new(),
                 }]
             }
             _ => vec![],
@@ -348,12 +411,21 @@ impl InvariantDetector {
                     message: format!("'{}' and '{}' must be in separate files", a, b),
                     expected: "Different files".to_string(),
                     actual: format!("Both in {}", fa),
+                    path: Vec::new(),
                 }]
             }
             _ => vec![],
         }
     }
 
+    fn check_data_flow(&self, constraint: &Constraint) -> Vec<ConstraintViolation> {
+        // Target: "from -> via -> to" or "from !-> to" (see data_flow.rs)
+        let (Some(context), Some(spec)) = (&self.data_flow, DataFlowSpec::parse(&constraint.target)) else {
+            return vec![];
+        };
+        context.verify(&spec, constraint.scope.as_deref())
+    }
+
     fn find_symbol_file(&self, symbol: &str) -> Option<String> {
         for (file, fns) in &self.functions {
             if fns.iter().any(|f| f.name == symbol) {
@@ -376,6 +448,52 @@ impl InvariantDetector {
     }
 }
 
+/// A metric a ComplexityLimit target can bound.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ComplexityMetric {
+    Cyclomatic,
+    Cognitive,
+    Nesting,
+    Params,
+}
+
+impl ComplexityMetric {
+    fn parse(name: &str) -> Option<Self> {
+        match name {
+            "cyclomatic" => Some(Self::Cyclomatic),
+            "cognitive" => Some(Self::Cognitive),
+            "nesting" => Some(Self::Nesting),
+            "params" => Some(Self::Params),
+            _ => None,
+        }
+    }
+
+    fn value(self, metrics: &ComplexityMetrics) -> u32 {
+        match self {
+            Self::Cyclomatic => metrics.cyclomatic,
+            Self::Cognitive => metrics.cognitive,
+            Self::Nesting => metrics.max_nesting,
+            Self::Params => metrics.parameter_count,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Cyclomatic => "cyclomatic complexity",
+            Self::Cognitive => "cognitive complexity",
+            Self::Nesting => "nesting depth",
+            Self::Params => "parameters",
+        }
+    }
+
+    fn describe(self, value: u32) -> String {
+        match self {
+            Self::Params => format!("{} parameters", value),
+            _ => format!("{} {}", self.label(), value),
+        }
+    }
+}
+
 impl Default for InvariantDetector {
     fn default() -> Self {
         Self::new()
@@ -2,6 +2,7 @@
 
 pub mod types;
 pub mod detector;
+pub mod data_flow;
 pub mod synthesizer;
 pub mod store;
 pub mod verifier;
@@ -9,6 +10,7 @@ pub mod freezing;
 
 pub use types::*;
 pub use detector::InvariantDetector;
+pub use data_flow::{DataFlowContext, DataFlowSpec};
 pub use synthesizer::ConstraintSynthesizer;
 pub use store::ConstraintStore;
 pub use verifier::ConstraintVerifier;
@@ -46,7 +46,8 @@ pub enum InvariantType {
     LayerBoundary,
     /// Module/file/function must not exceed a size limit.
     SizeLimit,
-    /// Cyclomatic complexity must not exceed a threshold.
+    /// Function complexity (cyclomatic, cognitive, nesting, parameters)
+    /// must not exceed a threshold.
     ComplexityLimit,
 }
 
@@ -110,6 +111,9 @@ pub struct ConstraintViolation {
     pub message: String,
     pub expected: String,
     pub actual: String,
+    /// Offending call path for data-flow violations, origin first.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub path: Vec<String>,
 }
 
 /// A frozen baseline snapshot for regression detection.
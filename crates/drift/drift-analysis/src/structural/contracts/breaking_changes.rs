@@ -3,10 +3,17 @@
 use super::types::*;
 
 /// Classify breaking changes between two versions of a contract.
+///
+/// Avro contracts follow Avro's schema-resolution rules under the default
+/// (backward) compatibility mode; see [`classify_avro_changes`].
 pub fn classify_breaking_changes(
     old_contract: &Contract,
     new_contract: &Contract,
 ) -> Vec<BreakingChange> {
+    if old_contract.framework == "avro" && new_contract.framework == "avro" {
+        return classify_avro_changes(old_contract, new_contract, AvroCompatibility::default());
+    }
+
     let mut changes = Vec::new();
 
     let old_endpoints: std::collections::HashMap<String, &Endpoint> = old_contract
@@ -293,3 +300,300 @@ fn is_array_type(type_str: &str) -> bool {
         || type_str.starts_with("repeated:") || type_str.starts_with("List<")
         || type_str.ends_with("[]")
 }
+
+/// Classify changes between two versions of an Avro schema under a
+/// compatibility mode.
+///
+/// A change is breaking only when data written with one version can't be
+/// resolved by a reader on the other in a direction the mode requires:
+/// adding a field without a default breaks backward compatibility, removing
+/// one breaks forward compatibility, and widening promotions such as
+/// `int` → `long` only break forward.
+pub fn classify_avro_changes(
+    old_contract: &Contract,
+    new_contract: &Contract,
+    mode: AvroCompatibility,
+) -> Vec<BreakingChange> {
+    let mut changes = Vec::new();
+    if mode == AvroCompatibility::None {
+        return changes;
+    }
+
+    for old_record in &old_contract.endpoints {
+        let Some(new_record) = new_contract.endpoints.iter().find(|e| e.path == old_record.path) else {
+            changes.push(BreakingChange {
+                change_type: BreakingChangeType::EndpointRemoved,
+                endpoint: old_record.path.clone(),
+                field: None,
+                severity: MismatchSeverity::Critical,
+                message: format!("Record {} was removed", old_record.path),
+            });
+            continue;
+        };
+        if mode.checks_backward() {
+            classify_avro_reader(&mut changes, &old_record.request_fields, &new_record.request_fields, &new_record.path, true);
+        }
+        if mode.checks_forward() {
+            classify_avro_reader(&mut changes, &new_record.request_fields, &old_record.request_fields, &new_record.path, false);
+        }
+    }
+
+    // Full compatibility can report the same field from both directions.
+    let mut seen = std::collections::HashSet::new();
+    changes.retain(|c| seen.insert((c.change_type, c.endpoint.clone(), c.field.clone())));
+    changes
+}
+
+/// Classify each version of a schema against the ones before it, oldest
+/// first.
+///
+/// Non-transitive modes compare each version with its predecessor only;
+/// transitive modes compare it with every earlier version. Messages name
+/// the two source files.
+pub fn classify_avro_evolution(versions: &[&Contract], mode: AvroCompatibility) -> Vec<BreakingChange> {
+    let mut changes = Vec::new();
+    for (i, new_version) in versions.iter().enumerate().skip(1) {
+        let earliest = if mode.is_transitive() { 0 } else { i - 1 };
+        for old_version in &versions[earliest..i] {
+            for mut change in classify_avro_changes(old_version, new_version, mode) {
+                change.message = format!(
+                    "{} → {}: {}",
+                    old_version.source_file, new_version.source_file, change.message
+                );
+                changes.push(change);
+            }
+        }
+    }
+    changes
+}
+
+/// Check schema evolution across a registry-style directory of Avro schemas.
+///
+/// Versions of a subject live side by side in one directory with a version
+/// number in the file name (`user-created/v1.avsc`, `user-created/v2.avsc`
+/// or `user_created.3.avsc`). Schemas are grouped by directory and primary
+/// record, ordered by version and checked with [`classify_avro_evolution`].
+/// Unversioned schemas are skipped.
+pub fn classify_avro_registry(contracts: &[Contract], mode: AvroCompatibility) -> Vec<BreakingChange> {
+    let mut subjects: std::collections::BTreeMap<(String, String), Vec<(u64, &Contract)>> =
+        std::collections::BTreeMap::new();
+    for contract in contracts.iter().filter(|c| c.framework == "avro") {
+        let (Some(version), Some(record)) = (schema_version(&contract.source_file), contract.endpoints.first()) else {
+            continue;
+        };
+        let directory = contract.source_file.rsplit_once('/').map_or("", |(dir, _)| dir);
+        subjects
+            .entry((directory.to_string(), record.path.clone()))
+            .or_default()
+            .push((version, contract));
+    }
+
+    let mut changes = Vec::new();
+    for mut versions in subjects.into_values() {
+        versions.sort_by_key(|(version, _)| *version);
+        let ordered: Vec<&Contract> = versions.into_iter().map(|(_, c)| c).collect();
+        changes.extend(classify_avro_evolution(&ordered, mode));
+    }
+    changes
+}
+
+/// Version number at the end of a schema file name (`v2.avsc`, `order.3.avsc`).
+fn schema_version(path: &str) -> Option<u64> {
+    let file = path.rsplit(['/', '\\']).next().unwrap_or(path);
+    let stem = file.strip_suffix(".avsc").unwrap_or(file);
+    let digits: String = stem.chars().rev().take_while(|c| c.is_ascii_digit()).collect();
+    if digits.is_empty() {
+        return None;
+    }
+    digits.chars().rev().collect::<String>().parse().ok()
+}
+
+/// Report fields a reader can't resolve from data in the writer's schema.
+/// `reader_is_new` selects backward (new reads old) or forward wording.
+fn classify_avro_reader(
+    changes: &mut Vec<BreakingChange>,
+    writer_fields: &[FieldSpec],
+    reader_fields: &[FieldSpec],
+    endpoint_path: &str,
+    reader_is_new: bool,
+) {
+    let direction = if reader_is_new { "backward" } else { "forward" };
+    for reader in reader_fields {
+        let Some(writer) = writer_fields.iter().find(|f| f.name == reader.name) else {
+            // Readers fill in missing fields from their default.
+            if reader.required {
+                let (change_type, message) = if reader_is_new {
+                    (
+                        BreakingChangeType::RequiredAdded,
+                        format!("Field '{}' added without a default, breaking {direction} compatibility", reader.name),
+                    )
+                } else {
+                    (
+                        BreakingChangeType::FieldRemoved,
+                        format!("Field '{}' removed but has no default, breaking {direction} compatibility", reader.name),
+                    )
+                };
+                changes.push(BreakingChange {
+                    change_type,
+                    endpoint: endpoint_path.to_string(),
+                    field: Some(reader.name.clone()),
+                    severity: MismatchSeverity::High,
+                    message,
+                });
+            }
+            continue;
+        };
+
+        let Some(problem) = avro_resolution_error(writer, reader) else { continue };
+        let (old, new) = if reader_is_new { (writer, reader) } else { (reader, writer) };
+        let (change_type, severity, message) = match problem {
+            AvroResolutionError::Nullable => (
+                BreakingChangeType::NullabilityChanged,
+                MismatchSeverity::High,
+                format!("Field '{}' nullability changed, breaking {direction} compatibility", reader.name),
+            ),
+            AvroResolutionError::ArrayScalar => (
+                if is_array_type(&old.field_type) { BreakingChangeType::ArrayToScalar } else { BreakingChangeType::ScalarToArray },
+                MismatchSeverity::Critical,
+                format!(
+                    "Field '{}' changed from {} to {}, breaking {direction} compatibility",
+                    reader.name, old.field_type, new.field_type
+                ),
+            ),
+            AvroResolutionError::EnumSymbols(missing) if reader_is_new => (
+                BreakingChangeType::EnumValueRemoved,
+                MismatchSeverity::High,
+                format!("Field '{}' enum symbols removed: {}", reader.name, missing.join(", ")),
+            ),
+            AvroResolutionError::EnumSymbols(missing) => (
+                BreakingChangeType::SchemaIncompatible,
+                MismatchSeverity::High,
+                format!(
+                    "Field '{}' enum symbols added that old readers don't know: {}",
+                    reader.name,
+                    missing.join(", ")
+                ),
+            ),
+            AvroResolutionError::Type => (
+                BreakingChangeType::TypeChanged,
+                MismatchSeverity::High,
+                format!(
+                    "Field '{}' type changed from {} to {}, breaking {direction} compatibility",
+                    reader.name, old.field_type, new.field_type
+                ),
+            ),
+        };
+        changes.push(BreakingChange {
+            change_type,
+            endpoint: endpoint_path.to_string(),
+            field: Some(reader.name.clone()),
+            severity,
+            message,
+        });
+    }
+}
+
+/// Why a reader's field can't resolve data written with the writer's field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum AvroResolutionError {
+    /// The writer may write null and the reader doesn't accept it.
+    Nullable,
+    /// One side is an array and the other isn't.
+    ArrayScalar,
+    /// Enum symbols the writer may write that the reader lacks.
+    EnumSymbols(Vec<String>),
+    /// The types don't resolve.
+    Type,
+}
+
+/// Avro schema resolution for one field, `None` if the reader can read it.
+pub(crate) fn avro_resolution_error(writer: &FieldSpec, reader: &FieldSpec) -> Option<AvroResolutionError> {
+    if writer.nullable && !reader.nullable {
+        return Some(AvroResolutionError::Nullable);
+    }
+    resolve_avro_type(&writer.field_type, &reader.field_type).err()
+}
+
+fn resolve_avro_type(writer: &str, reader: &str) -> Result<(), AvroResolutionError> {
+    if writer == reader {
+        return Ok(());
+    }
+    let w = split_generic(writer);
+    let r = split_generic(reader);
+
+    // Every branch the writer may write must resolve; the reader may pick
+    // any of its branches.
+    if let Some(("union", branches)) = w {
+        for branch in split_type_list(branches) {
+            resolve_avro_type(branch, reader)?;
+        }
+        return Ok(());
+    }
+    if let Some(("union", branches)) = r {
+        let resolves = split_type_list(branches)
+            .into_iter()
+            .any(|branch| resolve_avro_type(writer, branch).is_ok());
+        return if resolves { Ok(()) } else { Err(AvroResolutionError::Type) };
+    }
+
+    match (w, r) {
+        (Some(("array", w_items)), Some(("array", r_items))) => resolve_avro_type(w_items, r_items),
+        (Some(("map", w_values)), Some(("map", r_values))) => resolve_avro_type(w_values, r_values),
+        (Some(("enum", w_symbols)), Some(("enum", r_symbols))) => {
+            let known: Vec<&str> = r_symbols.split(',').collect();
+            let missing: Vec<String> = w_symbols
+                .split(',')
+                .filter(|s| !s.is_empty() && !known.contains(s))
+                .map(str::to_string)
+                .collect();
+            if missing.is_empty() { Ok(()) } else { Err(AvroResolutionError::EnumSymbols(missing)) }
+        }
+        _ if is_array_type(writer) != is_array_type(reader) => Err(AvroResolutionError::ArrayScalar),
+        (None, None) if avro_promotes(writer, reader) || unqualified(writer) == unqualified(reader) => Ok(()),
+        _ => Err(AvroResolutionError::Type),
+    }
+}
+
+/// Avro's permitted primitive promotions.
+fn avro_promotes(writer: &str, reader: &str) -> bool {
+    matches!(
+        (writer, reader),
+        ("int", "long" | "float" | "double")
+            | ("long", "float" | "double")
+            | ("float", "double")
+            | ("string", "bytes")
+            | ("bytes", "string")
+    )
+}
+
+/// Named types resolve by unqualified name.
+fn unqualified(name: &str) -> &str {
+    name.rsplit('.').next().unwrap_or(name)
+}
+
+/// Split `array<T>` into `("array", "T")`.
+fn split_generic(type_str: &str) -> Option<(&str, &str)> {
+    let inner = type_str.strip_suffix('>')?;
+    let (head, inner) = inner.split_once('<')?;
+    Some((head, inner))
+}
+
+/// Split a comma-separated type list, respecting nested `<...>`.
+fn split_type_list(list: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, ch) in list.char_indices() {
+        match ch {
+            '<' => depth += 1,
+            '>' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(&list[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&list[start..]);
+    parts
+}
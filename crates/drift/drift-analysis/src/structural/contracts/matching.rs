@@ -1,5 +1,6 @@
 //! BE↔FE matching via path similarity + schema compatibility scoring.
 
+use super::breaking_changes::{avro_resolution_error, AvroResolutionError};
 use super::types::*;
 
 /// Match backend endpoints to frontend consumers.
@@ -29,6 +30,121 @@ pub fn match_contracts(
     matches
 }
 
+/// Which side of an event an endpoint's schema describes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EventRole {
+    Producer,
+    Consumer,
+}
+
+impl EventRole {
+    /// Infer the role from the schema's location: a path segment naming a
+    /// producer/publisher or consumer/subscriber/listener. Schemas in a
+    /// shared directory have no role.
+    pub fn of_path(path: &str) -> Option<Self> {
+        let lower = path.to_lowercase();
+        let segments: Vec<&str> = lower.split(['/', '\\']).collect();
+        let has = |words: &[&str]| segments.iter().any(|s| words.iter().any(|w| s.contains(w)));
+        match (
+            has(&["producer", "publisher"]),
+            has(&["consumer", "subscriber", "listener"]),
+        ) {
+            (true, false) => Some(Self::Producer),
+            (false, true) => Some(Self::Consumer),
+            _ => None,
+        }
+    }
+}
+
+/// Match event producers to consumers across `EventDriven` contracts.
+///
+/// Each service keeps its own copy of an event schema; the role of a copy
+/// comes from its path (see [`EventRole::of_path`]).
+pub fn match_event_contracts(contracts: &[Contract]) -> Vec<ContractMatch> {
+    let mut producers = Vec::new();
+    let mut consumers = Vec::new();
+    for contract in contracts.iter().filter(|c| c.paradigm == Paradigm::EventDriven) {
+        match EventRole::of_path(&contract.source_file) {
+            Some(EventRole::Producer) => producers.extend(contract.endpoints.iter().cloned()),
+            Some(EventRole::Consumer) => consumers.extend(contract.endpoints.iter().cloned()),
+            None => {}
+        }
+    }
+    match_events(&producers, &consumers)
+}
+
+/// Match producer event schemas to consumer schemas of the same event.
+///
+/// Events match by full name, or with lower confidence by unqualified name.
+/// The consumer reads what the producer writes, so mismatches follow Avro
+/// schema resolution with the producer as writer: a consumer field without
+/// a default must be produced, and every produced type must resolve to the
+/// consumer's.
+pub fn match_events(producers: &[Endpoint], consumers: &[Endpoint]) -> Vec<ContractMatch> {
+    let mut matches = Vec::new();
+    for producer in producers {
+        for consumer in consumers {
+            let confidence = if producer.path == consumer.path {
+                1.0
+            } else if unqualified(&producer.path) == unqualified(&consumer.path) {
+                0.7
+            } else {
+                continue;
+            };
+            matches.push(ContractMatch {
+                backend: producer.clone(),
+                frontend: consumer.clone(),
+                confidence,
+                mismatches: detect_event_mismatches(producer, consumer),
+            });
+        }
+    }
+    matches.sort_by(|a, b| b.confidence.partial_cmp(&a.confidence).unwrap_or(std::cmp::Ordering::Equal));
+    matches
+}
+
+fn unqualified(name: &str) -> &str {
+    name.rsplit('.').next().unwrap_or(name)
+}
+
+fn detect_event_mismatches(producer: &Endpoint, consumer: &Endpoint) -> Vec<ContractMismatch> {
+    let mut mismatches = Vec::new();
+    for read in &consumer.request_fields {
+        let Some(written) = producer.request_fields.iter().find(|f| f.name == read.name) else {
+            if read.required {
+                mismatches.push(ContractMismatch {
+                    backend_endpoint: producer.path.clone(),
+                    frontend_call: consumer.path.clone(),
+                    mismatch_type: MismatchType::FieldMissing,
+                    severity: MismatchSeverity::High,
+                    message: format!("Consumer field '{}' has no default and is not produced", read.name),
+                });
+            }
+            continue;
+        };
+        let Some(problem) = avro_resolution_error(written, read) else { continue };
+        let (mismatch_type, severity) = match problem {
+            AvroResolutionError::Nullable => (MismatchType::Nullable, MismatchSeverity::High),
+            AvroResolutionError::ArrayScalar => (MismatchType::ArrayScalar, MismatchSeverity::Critical),
+            AvroResolutionError::EnumSymbols(_) => (MismatchType::EnumValue, MismatchSeverity::High),
+            AvroResolutionError::Type => (MismatchType::TypeMismatch, MismatchSeverity::High),
+        };
+        let detail = match problem {
+            AvroResolutionError::Nullable => "producer may send null, consumer doesn't accept it".to_string(),
+            AvroResolutionError::EnumSymbols(missing) => format!("consumer doesn't know {}", missing.join(", ")),
+            _ => format!("producer={}, consumer={}", written.field_type, read.field_type),
+        };
+        mismatches.push(ContractMismatch {
+            backend_endpoint: producer.path.clone(),
+            frontend_call: consumer.path.clone(),
+            mismatch_type,
+            severity,
+            message: format!("Field '{}': {detail}", read.name),
+        });
+    }
+    mismatches
+}
+
 /// Compute match confidence between a backend endpoint and frontend call.
 fn compute_match_confidence(backend: &Endpoint, frontend: &Endpoint) -> f64 {
     let mut score = 0.0;
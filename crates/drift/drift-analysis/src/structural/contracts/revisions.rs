@@ -0,0 +1,443 @@
+//! Contract diffing between git revisions.
+//!
+//! `ContractSnapshot` extracts the contracts of one revision straight from
+//! git objects, without a checkout. `ContractDiff` compares two snapshots
+//! per paradigm and endpoint, lists the frontend consumers each change
+//! affects, and records which breaking changes the range announced.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use drift_core::errors::ContractError;
+use serde::{Deserialize, Serialize};
+
+use super::breaking_changes::classify_breaking_changes;
+use super::extractors::ExtractorRegistry;
+use super::matching::match_contracts;
+use super::schema_parsers::SchemaParserRegistry;
+use super::types::*;
+use crate::parsers::manager::ParserManager;
+
+/// Larger files are generated or vendored and skipped.
+const MAX_FILE_BYTES: usize = 1024 * 1024;
+
+/// Extensions the endpoint extractors understand.
+const SOURCE_EXTENSIONS: &[&str] = &[
+    "ts", "tsx", "js", "jsx", "mjs", "cjs", "py", "rb", "java", "kt", "cs", "go", "rs", "php",
+];
+
+/// Revision label of a snapshot taken from the working tree.
+pub const WORKTREE: &str = "WORKTREE";
+
+/// The contracts and frontend consumers of one revision.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ContractSnapshot {
+    /// Commit id the snapshot was taken at, or `WORKTREE`.
+    pub revision: String,
+    /// Backend and schema contracts.
+    pub contracts: Vec<Contract>,
+    /// Frontend calls to backend endpoints.
+    pub consumers: Vec<Endpoint>,
+}
+
+impl ContractSnapshot {
+    /// Extract contracts from `(path, content)` pairs. Paths are relative
+    /// to the project root.
+    pub fn from_files<'a>(revision: &str, files: impl IntoIterator<Item = (&'a str, &'a str)>) -> Self {
+        let extractors = ExtractorRegistry::new();
+        let schemas = SchemaParserRegistry::new();
+        let parsers = ParserManager::new();
+        let mut snapshot = Self { revision: revision.to_string(), ..Self::default() };
+
+        for (path, content) in files {
+            if is_test_or_fixture(path) {
+                continue;
+            }
+            if schemas.handles(path) {
+                snapshot.contracts.extend(schemas.parse_file(content, path));
+                continue;
+            }
+            if !is_source_file(path) {
+                continue;
+            }
+            let parse_result = parsers.parse(content.as_bytes(), Path::new(path)).ok();
+            for (framework, endpoints) in extractors.extract_all_with_context(content, path, parse_result.as_ref()) {
+                if framework == "frontend" {
+                    snapshot.consumers.extend(endpoints);
+                    continue;
+                }
+                let field_count: usize = endpoints
+                    .iter()
+                    .map(|e| e.request_fields.len() + e.response_fields.len())
+                    .sum();
+                snapshot.contracts.push(Contract {
+                    id: format!("{path}:{framework}"),
+                    paradigm: if framework == "trpc" { Paradigm::Trpc } else { Paradigm::Rest },
+                    endpoints,
+                    source_file: path.to_string(),
+                    confidence: if field_count > 0 { 0.9 } else { 0.6 },
+                    framework,
+                });
+            }
+        }
+        snapshot
+    }
+
+    /// Extract contracts at `revision` in the repository containing `root`,
+    /// reading blobs from the object database. Files outside `root` are
+    /// ignored.
+    pub fn from_git(root: &Path, revision: &str) -> Result<Self, ContractError> {
+        let (repo, prefix) = open_repository(root)?;
+        let commit = resolve(&repo, revision)?;
+        let tree = commit.tree().map_err(git_error)?;
+        let schemas = SchemaParserRegistry::new();
+
+        let mut files: Vec<(String, String)> = Vec::new();
+        tree.walk(git2::TreeWalkMode::PreOrder, |dir, entry| {
+            if entry.kind() != Some(git2::ObjectType::Blob) {
+                return git2::TreeWalkResult::Ok;
+            }
+            let Some(path) = entry.name().and_then(|name| relative_path(&prefix, &format!("{dir}{name}"))) else {
+                return git2::TreeWalkResult::Ok;
+            };
+            if !is_candidate(&schemas, &path) {
+                return git2::TreeWalkResult::Ok;
+            }
+            if let Ok(blob) = repo.find_blob(entry.id()) {
+                if let Some(content) = text_content(blob.content()) {
+                    files.push((path, content));
+                }
+            }
+            git2::TreeWalkResult::Ok
+        })
+        .map_err(git_error)?;
+
+        let revision = commit.id().to_string();
+        Ok(Self::from_files(&revision, files.iter().map(|(p, c)| (p.as_str(), c.as_str()))))
+    }
+
+    /// Extract contracts from the working tree: tracked files as they are
+    /// on disk, plus untracked files that aren't ignored.
+    pub fn from_worktree(root: &Path) -> Result<Self, ContractError> {
+        let (repo, prefix) = open_repository(root)?;
+        let workdir = repo
+            .workdir()
+            .ok_or_else(|| ContractError::Git("bare repositories have no working tree".to_string()))?
+            .to_path_buf();
+
+        let mut paths: Vec<String> = repo
+            .index()
+            .map_err(git_error)?
+            .iter()
+            .map(|entry| String::from_utf8_lossy(&entry.path).into_owned())
+            .collect();
+        let mut options = git2::StatusOptions::new();
+        options.include_untracked(true).recurse_untracked_dirs(true);
+        for status in repo.statuses(Some(&mut options)).map_err(git_error)?.iter() {
+            if status.status().contains(git2::Status::WT_NEW) {
+                if let Some(path) = status.path() {
+                    paths.push(path.to_string());
+                }
+            }
+        }
+        paths.sort_unstable();
+        paths.dedup();
+
+        let schemas = SchemaParserRegistry::new();
+        let files: Vec<(String, String)> = paths
+            .iter()
+            .filter_map(|path| {
+                let relative = relative_path(&prefix, path)?;
+                if !is_candidate(&schemas, &relative) {
+                    return None;
+                }
+                let bytes = std::fs::read(workdir.join(path)).ok()?;
+                Some((relative, text_content(&bytes)?))
+            })
+            .collect();
+        Ok(Self::from_files(WORKTREE, files.iter().map(|(p, c)| (p.as_str(), c.as_str()))))
+    }
+
+    /// Endpoints of every contract in `paradigm`.
+    pub fn endpoints(&self, paradigm: Paradigm) -> Vec<Endpoint> {
+        self.contracts
+            .iter()
+            .filter(|c| c.paradigm == paradigm)
+            .flat_map(|c| c.endpoints.iter().cloned())
+            .collect()
+    }
+}
+
+/// The changes to one endpoint between two revisions.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EndpointChanges {
+    pub paradigm: Paradigm,
+    /// Path, operation name, RPC or channel.
+    pub endpoint: String,
+    /// Methods or operation types the endpoint had at base.
+    pub methods: Vec<String>,
+    /// Where the endpoint is defined at head, or at base if it was removed.
+    pub file: String,
+    pub line: u32,
+    pub changes: Vec<BreakingChange>,
+    /// Frontend calls at head that match the endpoint as it was at base.
+    pub consumers: Vec<Endpoint>,
+    /// Whether a commit in the range announced the change.
+    pub announced: bool,
+}
+
+impl EndpointChanges {
+    pub fn is_breaking(&self) -> bool {
+        self.changes.iter().any(|c| c.change_type.is_breaking())
+    }
+
+    /// Changes that break consumers.
+    pub fn breaking_changes(&self) -> impl Iterator<Item = &BreakingChange> {
+        self.changes.iter().filter(|c| c.change_type.is_breaking())
+    }
+}
+
+/// Contract changes between a base and a head revision.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ContractDiff {
+    pub base: String,
+    pub head: String,
+    /// Changed endpoints, by paradigm and endpoint.
+    pub endpoints: Vec<EndpointChanges>,
+}
+
+impl ContractDiff {
+    /// Compare two snapshots. Endpoints are matched across files, so moving
+    /// a handler to another file is not a change.
+    pub fn between(base: &ContractSnapshot, head: &ContractSnapshot) -> Self {
+        let mut endpoints = Vec::new();
+        for &paradigm in Paradigm::all() {
+            let old_endpoints = base.endpoints(paradigm);
+            let new_endpoints = head.endpoints(paradigm);
+            if old_endpoints.is_empty() && new_endpoints.is_empty() {
+                continue;
+            }
+            // Keep a framework every contract shares, so framework-specific
+            // rules such as Avro's schema resolution still apply.
+            let mut frameworks = base.contracts.iter().chain(&head.contracts)
+                .filter(|c| c.paradigm == paradigm)
+                .map(|c| c.framework.as_str());
+            let first = frameworks.next().unwrap_or_default();
+            let framework = if frameworks.all(|f| f == first) { first } else { "" };
+            let merged = |endpoints: Vec<Endpoint>, revision: &str| Contract {
+                id: format!("{}@{revision}", paradigm.name()),
+                paradigm,
+                endpoints,
+                source_file: String::new(),
+                framework: framework.to_string(),
+                confidence: 1.0,
+            };
+            let old_contract = merged(old_endpoints, &base.revision);
+            let new_contract = merged(new_endpoints, &head.revision);
+
+            let mut by_endpoint: BTreeMap<String, Vec<BreakingChange>> = BTreeMap::new();
+            for change in classify_breaking_changes(&old_contract, &new_contract) {
+                by_endpoint.entry(change.endpoint.clone()).or_default().push(change);
+            }
+            for (endpoint, mut changes) in by_endpoint {
+                changes.sort_by(|a, b| a.message.cmp(&b.message));
+                let at = |contract: &Contract| -> Vec<Endpoint> {
+                    contract.endpoints.iter().filter(|e| e.path == endpoint).cloned().collect()
+                };
+                let (old, new) = (at(&old_contract), at(&new_contract));
+
+                let mut methods: Vec<String> = old.iter().map(|e| e.method.clone()).collect();
+                methods.sort_unstable();
+                methods.dedup();
+                let (file, line) = new
+                    .first()
+                    .or(old.first())
+                    .map(|e| (e.file.clone(), e.line))
+                    .unwrap_or_default();
+
+                let mut consumers: Vec<Endpoint> = match_contracts(&old, &head.consumers)
+                    .into_iter()
+                    .map(|m| m.frontend)
+                    .collect();
+                consumers.sort_by(|a, b| (&a.file, a.line).cmp(&(&b.file, b.line)));
+                consumers.dedup_by(|a, b| a.file == b.file && a.line == b.line);
+
+                endpoints.push(EndpointChanges {
+                    paradigm,
+                    endpoint,
+                    methods,
+                    file,
+                    line,
+                    changes,
+                    consumers,
+                    announced: false,
+                });
+            }
+        }
+        Self { base: base.revision.clone(), head: head.revision.clone(), endpoints }
+    }
+
+    /// Diff `base` against `head` — or against the working tree when `head`
+    /// is `None` — in the repository containing `root`.
+    ///
+    /// As with diff-scoped gates, the base snapshot is taken at the merge
+    /// base. Breaking changes count as announced when a commit in the range
+    /// names their endpoint in a `BREAKING CHANGE:` footer or a `type!:`
+    /// subject.
+    pub fn from_git(root: &Path, base: &str, head: Option<&str>) -> Result<Self, ContractError> {
+        let (repo, _) = open_repository(root)?;
+        let base_commit = resolve(&repo, base)?;
+        let head_commit = resolve(&repo, head.unwrap_or("HEAD"))?;
+        let merge_base = repo.merge_base(base_commit.id(), head_commit.id()).unwrap_or(base_commit.id());
+
+        let base_snapshot = ContractSnapshot::from_git(root, &merge_base.to_string())?;
+        let head_snapshot = match head {
+            Some(_) => ContractSnapshot::from_git(root, &head_commit.id().to_string())?,
+            None => ContractSnapshot::from_worktree(root)?,
+        };
+
+        let mut revwalk = repo.revwalk().map_err(git_error)?;
+        revwalk.push(head_commit.id()).map_err(git_error)?;
+        revwalk.hide(merge_base).map_err(git_error)?;
+        let mut notes = Vec::new();
+        for oid in revwalk {
+            let commit = repo.find_commit(oid.map_err(git_error)?).map_err(git_error)?;
+            notes.extend(breaking_change_notes(&String::from_utf8_lossy(commit.message_bytes())));
+        }
+
+        let mut diff = Self::between(&base_snapshot, &head_snapshot);
+        diff.announce(&notes);
+        Ok(diff)
+    }
+
+    /// Mark changes to endpoints named in any of `notes` as announced.
+    pub fn announce<S: AsRef<str>>(&mut self, notes: &[S]) {
+        for endpoint in &mut self.endpoints {
+            endpoint.announced |= notes.iter().any(|n| mentions(n.as_ref(), &endpoint.endpoint));
+        }
+    }
+
+    /// Endpoints with at least one breaking change.
+    pub fn breaking(&self) -> impl Iterator<Item = &EndpointChanges> {
+        self.endpoints.iter().filter(|e| e.is_breaking())
+    }
+
+    /// Endpoints with breaking changes no commit announced.
+    pub fn unannounced(&self) -> impl Iterator<Item = &EndpointChanges> {
+        self.breaking().filter(|e| !e.announced)
+    }
+}
+
+/// Breaking-change announcements in a commit message, per the
+/// Conventional Commits spec: the text of `BREAKING CHANGE:` (or
+/// `BREAKING-CHANGE:`) footers, and the description of a `type!:` subject.
+pub fn breaking_change_notes(message: &str) -> Vec<String> {
+    let mut notes = Vec::new();
+    let mut lines = message.lines();
+    if let Some(subject) = lines.next() {
+        if let Some((kind, description)) = subject.split_once(':') {
+            if kind.ends_with('!') && !kind.contains(' ') {
+                notes.push(description.trim().to_string());
+            }
+        }
+    }
+    let mut current: Option<String> = None;
+    for line in lines {
+        let footer = ["BREAKING CHANGE:", "BREAKING-CHANGE:"]
+            .iter()
+            .find_map(|token| line.strip_prefix(token));
+        if let Some(text) = footer {
+            notes.extend(current.take());
+            current = Some(text.trim().to_string());
+        } else if line.trim().is_empty() || is_trailer(line) {
+            notes.extend(current.take());
+        } else if let Some(note) = current.as_mut() {
+            note.push(' ');
+            note.push_str(line.trim());
+        }
+    }
+    notes.extend(current);
+    notes
+}
+
+/// `Token: value` or `Token #value`, the start of another footer.
+fn is_trailer(line: &str) -> bool {
+    let token_len = line.find(|c: char| !(c.is_alphanumeric() || c == '-')).unwrap_or(0);
+    token_len > 0 && (line[token_len..].starts_with(": ") || line[token_len..].starts_with(" #"))
+}
+
+/// Whether `note` names `endpoint`, matched case-insensitively and not as
+/// part of a longer path or identifier.
+fn mentions(note: &str, endpoint: &str) -> bool {
+    if endpoint.is_empty() {
+        return false;
+    }
+    let note = note.to_lowercase();
+    let endpoint = endpoint.to_lowercase();
+    let continues = |c: char| c.is_alphanumeric() || c == '_' || c == '/' || c == '{';
+    note.match_indices(&endpoint).any(|(at, _)| {
+        let before = note[..at].chars().next_back();
+        let after = note[at + endpoint.len()..].chars().next();
+        !before.is_some_and(|c| c.is_alphanumeric() || c == '_') && !after.is_some_and(continues)
+    })
+}
+
+fn open_repository(root: &Path) -> Result<(git2::Repository, PathBuf), ContractError> {
+    let repo = git2::Repository::discover(root).map_err(git_error)?;
+    let prefix = repo
+        .workdir()
+        .and_then(|w| w.canonicalize().ok())
+        .and_then(|w| root.canonicalize().ok()?.strip_prefix(w).ok().map(Path::to_path_buf))
+        .unwrap_or_default();
+    Ok((repo, prefix))
+}
+
+fn resolve<'r>(repo: &'r git2::Repository, revision: &str) -> Result<git2::Commit<'r>, ContractError> {
+    repo.revparse_single(revision)
+        .and_then(|o| o.peel_to_commit())
+        .map_err(|e| ContractError::RevisionNotFound { revision: revision.to_string(), message: e.to_string() })
+}
+
+fn git_error(e: git2::Error) -> ContractError {
+    ContractError::Git(e.to_string())
+}
+
+/// `path` relative to the project root, if it lies under it.
+fn relative_path(prefix: &Path, path: &str) -> Option<String> {
+    let relative = Path::new(path).strip_prefix(prefix).ok()?;
+    Some(relative.to_string_lossy().replace('\\', "/"))
+}
+
+fn text_content(bytes: &[u8]) -> Option<String> {
+    if bytes.len() > MAX_FILE_BYTES || bytes.contains(&0) {
+        return None;
+    }
+    String::from_utf8(bytes.to_vec()).ok()
+}
+
+fn is_candidate(schemas: &SchemaParserRegistry, path: &str) -> bool {
+    (is_source_file(path) || schemas.handles(path)) && !is_test_or_fixture(path)
+}
+
+fn is_source_file(path: &str) -> bool {
+    Path::new(path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| SOURCE_EXTENSIONS.contains(&e))
+}
+
+/// Tests and fixtures embed code samples that would read as phantom endpoints.
+fn is_test_or_fixture(path: &str) -> bool {
+    let lower = path.to_lowercase();
+    let name = lower.rsplit('/').next().unwrap_or(&lower);
+    if name.contains(".test.") || name.contains(".spec.") || name.contains("_test.") {
+        return true;
+    }
+    lower.split('/').any(|segment| {
+        matches!(
+            segment,
+            "test" | "tests" | "__tests__" | "__mocks__" | "fixtures" | "test-fixtures" | "testdata" | "e2e"
+                | "node_modules" | "vendor"
+        )
+    })
+}
@@ -0,0 +1,180 @@
+//! Avro schema parser (`.avsc`) for event payloads.
+
+use std::collections::HashMap;
+
+use super::SchemaParser;
+use crate::structural::contracts::types::*;
+
+const PRIMITIVES: &[&str] = &["null", "boolean", "int", "long", "float", "double", "bytes", "string"];
+
+/// Parses Avro schemas into event-driven contracts.
+///
+/// Every named record becomes an endpoint keyed by its full name, with its
+/// fields recorded as `request_fields` like AsyncAPI message payloads. A
+/// field is required when it has no default and nullable when its type is a
+/// union with `"null"`. Types are rendered as `array<T>`, `map<T>`,
+/// `enum<A,B>`, `fixed<N>` and `union<A,B>` so that schema resolution can be
+/// checked from the `FieldSpec` alone.
+pub struct AvroParser;
+
+impl SchemaParser for AvroParser {
+    fn parse(&self, content: &str, file_path: &str) -> Vec<Contract> {
+        let value: serde_json::Value = match serde_json::from_str(content) {
+            Ok(v) => v,
+            Err(_) => return vec![],
+        };
+
+        let mut names = NamedTypes::default();
+        render(&value, "", &mut names);
+        if names.records.is_empty() {
+            return vec![];
+        }
+
+        let endpoints = names
+            .records
+            .into_iter()
+            .map(|(name, fields)| Endpoint {
+                method: "RECORD".to_string(),
+                path: name,
+                request_fields: fields,
+                response_fields: vec![],
+                file: file_path.to_string(),
+                line: 0,
+            })
+            .collect();
+
+        vec![Contract {
+            id: format!("avro:{}", file_path),
+            paradigm: Paradigm::EventDriven,
+            endpoints,
+            source_file: file_path.to_string(),
+            framework: "avro".to_string(),
+            confidence: 0.95,
+        }]
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["avsc"]
+    }
+
+    fn schema_type(&self) -> &str {
+        "avro"
+    }
+}
+
+/// Records and enums declared so far, for resolving references by name.
+#[derive(Default)]
+struct NamedTypes {
+    records: Vec<(String, Vec<FieldSpec>)>,
+    enums: HashMap<String, String>,
+}
+
+/// Render a schema as a type string, returning whether it admits `null`.
+fn render(schema: &serde_json::Value, namespace: &str, names: &mut NamedTypes) -> (String, bool) {
+    match schema {
+        serde_json::Value::String(name) => {
+            if PRIMITIVES.contains(&name.as_str()) {
+                return (name.clone(), false);
+            }
+            let full = qualify(name, namespace);
+            let rendered = names.enums.get(&full).cloned().unwrap_or(full);
+            (rendered, false)
+        }
+        serde_json::Value::Array(branches) => {
+            let nullable = branches.iter().any(|b| b.as_str() == Some("null"));
+            let types: Vec<String> = branches
+                .iter()
+                .filter(|b| b.as_str() != Some("null"))
+                .map(|b| nested(b, namespace, names))
+                .collect();
+            match types.as_slice() {
+                [single] => (single.clone(), nullable),
+                _ => (format!("union<{}>", types.join(",")), nullable),
+            }
+        }
+        serde_json::Value::Object(obj) => {
+            let kind = match obj.get("type") {
+                Some(serde_json::Value::String(kind)) => kind.as_str(),
+                Some(inner) => return render(inner, namespace, names),
+                None => return ("any".to_string(), false),
+            };
+            let rendered = match kind {
+                "record" | "error" => render_record(schema, namespace, names),
+                "enum" => {
+                    let symbols: Vec<&str> = obj
+                        .get("symbols")
+                        .and_then(|s| s.as_array())
+                        .map(|s| s.iter().filter_map(|v| v.as_str()).collect())
+                        .unwrap_or_default();
+                    let rendered = format!("enum<{}>", symbols.join(","));
+                    if let Some(name) = obj.get("name").and_then(|n| n.as_str()) {
+                        let ns = obj.get("namespace").and_then(|n| n.as_str()).unwrap_or(namespace);
+                        names.enums.insert(qualify(name, ns), rendered.clone());
+                    }
+                    rendered
+                }
+                "array" => {
+                    let items = obj.get("items").map(|i| nested(i, namespace, names));
+                    format!("array<{}>", items.unwrap_or_else(|| "any".to_string()))
+                }
+                "map" => {
+                    let values = obj.get("values").map(|v| nested(v, namespace, names));
+                    format!("map<{}>", values.unwrap_or_else(|| "any".to_string()))
+                }
+                "fixed" => {
+                    let size = obj.get("size").and_then(|s| s.as_u64()).unwrap_or(0);
+                    format!("fixed<{size}>")
+                }
+                // Primitives with a logicalType keep their underlying type.
+                other => return render(&serde_json::Value::String(other.to_string()), namespace, names),
+            };
+            (rendered, false)
+        }
+        _ => ("any".to_string(), false),
+    }
+}
+
+/// Render a type nested inside another, keeping a nullable union explicit.
+fn nested(schema: &serde_json::Value, namespace: &str, names: &mut NamedTypes) -> String {
+    match render(schema, namespace, names) {
+        (rendered, true) => format!("union<null,{rendered}>"),
+        (rendered, false) => rendered,
+    }
+}
+
+fn render_record(schema: &serde_json::Value, namespace: &str, names: &mut NamedTypes) -> String {
+    let name = schema.get("name").and_then(|n| n.as_str()).unwrap_or("anonymous");
+    let ns = schema.get("namespace").and_then(|n| n.as_str()).unwrap_or(namespace);
+    let full = qualify(name, ns);
+    // Names inside the record resolve against the record's own namespace.
+    let inner_ns = full.rsplit_once('.').map(|(ns, _)| ns.to_string()).unwrap_or_default();
+
+    let index = names.records.len();
+    names.records.push((full.clone(), vec![]));
+    let mut fields = Vec::new();
+    for field in schema.get("fields").and_then(|f| f.as_array()).into_iter().flatten() {
+        let Some(field_name) = field.get("name").and_then(|n| n.as_str()) else {
+            continue;
+        };
+        let (field_type, nullable) = field
+            .get("type")
+            .map(|t| render(t, &inner_ns, names))
+            .unwrap_or_else(|| ("any".to_string(), false));
+        fields.push(FieldSpec {
+            name: field_name.to_string(),
+            field_type,
+            required: field.get("default").is_none(),
+            nullable,
+        });
+    }
+    names.records[index].1 = fields;
+    full
+}
+
+fn qualify(name: &str, namespace: &str) -> String {
+    if name.contains('.') || namespace.is_empty() {
+        name.to_string()
+    } else {
+        format!("{namespace}.{name}")
+    }
+}
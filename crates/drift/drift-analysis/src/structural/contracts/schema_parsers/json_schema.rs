@@ -0,0 +1,184 @@
+//! JSON Schema parser (draft-04 through 2020-12).
+
+use super::SchemaParser;
+use crate::structural::contracts::types::*;
+
+/// Parses standalone JSON Schema documents describing REST payloads.
+///
+/// Only files that declare a json-schema.org `$schema` or are named
+/// `*.schema.json` are read; other JSON is left to the other parsers.
+pub struct JsonSchemaParser;
+
+impl SchemaParser for JsonSchemaParser {
+    fn parse(&self, content: &str, file_path: &str) -> Vec<Contract> {
+        if !content.contains("json-schema.org") && !file_path.to_lowercase().ends_with(".schema.json") {
+            return vec![];
+        }
+        let root: serde_json::Value = match serde_json::from_str(content) {
+            Ok(v) => v,
+            Err(_) => return vec![],
+        };
+        if !root.is_object() || root.get("openapi").is_some() || root.get("asyncapi").is_some() {
+            return vec![];
+        }
+
+        let name = root
+            .get("$id")
+            .or_else(|| root.get("title"))
+            .and_then(|v| v.as_str())
+            .map(str::to_string)
+            .unwrap_or_else(|| file_stem(file_path));
+
+        let mut endpoints = Vec::new();
+        if root.get("properties").is_some() || root.get("allOf").is_some() {
+            endpoints.push(schema_endpoint(&name, &root, &root, file_path));
+        }
+        // Reusable definitions are payloads of their own.
+        for key in ["$defs", "definitions"] {
+            if let Some(defs) = root.get(key).and_then(|d| d.as_object()) {
+                for (def_name, schema) in defs {
+                    if schema.get("properties").is_some() {
+                        let path = format!("{name}#/{key}/{def_name}");
+                        endpoints.push(schema_endpoint(&path, schema, &root, file_path));
+                    }
+                }
+            }
+        }
+
+        if endpoints.is_empty() {
+            return vec![];
+        }
+
+        vec![Contract {
+            id: format!("json_schema:{}", file_path),
+            paradigm: Paradigm::Rest,
+            endpoints,
+            source_file: file_path.to_string(),
+            framework: "json_schema".to_string(),
+            confidence: 0.85,
+        }]
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["json"]
+    }
+
+    fn schema_type(&self) -> &str {
+        "json_schema"
+    }
+}
+
+/// A schema is a payload: its properties are what producers send and
+/// consumers read, so they are recorded as response fields.
+fn schema_endpoint(path: &str, schema: &serde_json::Value, root: &serde_json::Value, file_path: &str) -> Endpoint {
+    let mut fields = Vec::new();
+    collect_fields(schema, root, &mut fields, 0);
+    Endpoint {
+        method: "SCHEMA".to_string(),
+        path: path.to_string(),
+        request_fields: vec![],
+        response_fields: fields,
+        file: file_path.to_string(),
+        line: 0,
+    }
+}
+
+fn collect_fields(schema: &serde_json::Value, root: &serde_json::Value, fields: &mut Vec<FieldSpec>, depth: usize) {
+    if depth > 8 {
+        return;
+    }
+    let schema = resolve_ref(schema, root);
+    if let Some(parts) = schema.get("allOf").and_then(|a| a.as_array()) {
+        for part in parts {
+            collect_fields(part, root, fields, depth + 1);
+        }
+    }
+
+    let required: Vec<&str> = schema
+        .get("required")
+        .and_then(|r| r.as_array())
+        .map(|r| r.iter().filter_map(|v| v.as_str()).collect())
+        .unwrap_or_default();
+    if let Some(properties) = schema.get("properties").and_then(|p| p.as_object()) {
+        for (name, prop) in properties {
+            if fields.iter().any(|f| f.name == *name) {
+                continue;
+            }
+            let (field_type, nullable) = type_of(prop, root);
+            fields.push(FieldSpec {
+                name: name.clone(),
+                field_type,
+                required: required.contains(&name.as_str()),
+                nullable,
+            });
+        }
+    }
+}
+
+/// Type name and nullability of a property schema.
+fn type_of(prop: &serde_json::Value, root: &serde_json::Value) -> (String, bool) {
+    if let Some(reference) = prop.get("$ref").and_then(|r| r.as_str()) {
+        let target = resolve_ref(prop, root);
+        if target.get("properties").is_none() {
+            if let Some(t) = target.get("type") {
+                return type_of(&serde_json::json!({ "type": t, "enum": target.get("enum") }), root);
+            }
+        }
+        return (reference.rsplit('/').next().unwrap_or(reference).to_string(), false);
+    }
+    for key in ["oneOf", "anyOf"] {
+        if let Some(options) = prop.get(key).and_then(|o| o.as_array()) {
+            let nullable = options.iter().any(|o| o.get("type").and_then(|t| t.as_str()) == Some("null"));
+            let types: Vec<String> = options
+                .iter()
+                .filter(|o| o.get("type").and_then(|t| t.as_str()) != Some("null"))
+                .map(|o| type_of(o, root).0)
+                .collect();
+            let field_type = match types.as_slice() {
+                [single] => single.clone(),
+                _ => types.join("|"),
+            };
+            return (field_type, nullable);
+        }
+    }
+
+    let (base, nullable) = match prop.get("type") {
+        Some(serde_json::Value::String(t)) => (t.clone(), false),
+        Some(serde_json::Value::Array(types)) => {
+            let names: Vec<&str> = types.iter().filter_map(|t| t.as_str()).collect();
+            let nullable = names.contains(&"null");
+            let rest: Vec<&str> = names.into_iter().filter(|t| *t != "null").collect();
+            (rest.join("|"), nullable)
+        }
+        _ if prop.get("properties").is_some() => ("object".to_string(), false),
+        _ => ("any".to_string(), false),
+    };
+    let nullable = nullable || prop.get("nullable").and_then(|n| n.as_bool()).unwrap_or(false);
+    if base == "array" {
+        let items = prop.get("items").map(|i| type_of(i, root).0).unwrap_or_else(|| "any".to_string());
+        return (format!("array<{items}>"), nullable);
+    }
+    if let Some(values) = prop.get("enum").and_then(|e| e.as_array()) {
+        let symbols: Vec<String> = values
+            .iter()
+            .map(|v| v.as_str().map(str::to_string).unwrap_or_else(|| v.to_string()))
+            .collect();
+        return (format!("enum<{}>", symbols.join(",")), nullable);
+    }
+    (base, nullable)
+}
+
+/// Resolve a same-document `$ref` such as `#/$defs/Address`.
+fn resolve_ref<'a>(value: &'a serde_json::Value, root: &'a serde_json::Value) -> &'a serde_json::Value {
+    value
+        .get("$ref")
+        .and_then(|r| r.as_str())
+        .and_then(|r| r.strip_prefix('#'))
+        .and_then(|pointer| root.pointer(pointer))
+        .unwrap_or(value)
+}
+
+fn file_stem(file_path: &str) -> String {
+    let name = file_path.rsplit(['/', '\\']).next().unwrap_or(file_path);
+    name.trim_end_matches(".json").trim_end_matches(".schema").to_string()
+}
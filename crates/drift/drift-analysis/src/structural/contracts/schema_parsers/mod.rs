@@ -4,6 +4,9 @@ pub mod openapi;
 pub mod graphql;
 pub mod protobuf;
 pub mod asyncapi;
+pub mod json_schema;
+pub mod avro;
+pub mod thrift;
 
 use super::types::Contract;
 
@@ -16,3 +19,55 @@ pub trait SchemaParser: Send + Sync {
     /// Schema type name.
     fn schema_type(&self) -> &str;
 }
+
+/// Registry of all schema parsers.
+pub struct SchemaParserRegistry {
+    parsers: Vec<Box<dyn SchemaParser>>,
+}
+
+impl SchemaParserRegistry {
+    /// Create a registry with all built-in parsers.
+    pub fn new() -> Self {
+        Self {
+            parsers: vec![
+                Box::new(openapi::OpenApiParser),
+                Box::new(graphql::GraphqlParser),
+                Box::new(protobuf::ProtobufParser),
+                Box::new(asyncapi::AsyncApiParser),
+                Box::new(json_schema::JsonSchemaParser),
+                Box::new(avro::AvroParser),
+                Box::new(thrift::ThriftParser),
+            ],
+        }
+    }
+
+    /// Whether any parser handles the extension of `file_path`.
+    pub fn handles(&self, file_path: &str) -> bool {
+        let extension = extension(file_path);
+        self.parsers.iter().any(|p| p.extensions().contains(&extension.as_str()))
+    }
+
+    /// Parse a schema file with every parser that handles its extension.
+    pub fn parse_file(&self, content: &str, file_path: &str) -> Vec<Contract> {
+        let extension = extension(file_path);
+        self.parsers
+            .iter()
+            .filter(|p| p.extensions().contains(&extension.as_str()))
+            .flat_map(|p| p.parse(content, file_path))
+            .collect()
+    }
+}
+
+impl Default for SchemaParserRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn extension(file_path: &str) -> String {
+    std::path::Path::new(file_path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or_default()
+        .to_ascii_lowercase()
+}
@@ -0,0 +1,266 @@
+//! Thrift IDL parser (RPC services).
+
+use super::SchemaParser;
+use crate::structural::contracts::types::*;
+
+/// Parses Thrift service definitions.
+///
+/// Thrift services are RPC contracts like gRPC's, so they share the gRPC
+/// paradigm with framework `"thrift"`. Method arguments become request
+/// fields; the fields of a struct return type become response fields.
+pub struct ThriftParser;
+
+impl SchemaParser for ThriftParser {
+    fn parse(&self, content: &str, file_path: &str) -> Vec<Contract> {
+        let content = strip_comments(content);
+        let structs = parse_struct_definitions(&content);
+
+        let mut endpoints = Vec::new();
+        for (_, body, body_start) in blocks(&content, "service") {
+            for (name, args, return_type, offset) in parse_methods(body) {
+                let response_fields = match structs.iter().find(|(n, _)| *n == return_type) {
+                    Some((_, fields)) => fields.clone(),
+                    None if return_type == "void" => vec![],
+                    // Thrift wraps non-struct results in a field named `success`.
+                    None => vec![FieldSpec {
+                        name: "success".to_string(),
+                        field_type: return_type,
+                        required: true,
+                        nullable: false,
+                    }],
+                };
+                endpoints.push(Endpoint {
+                    method: "RPC".to_string(),
+                    path: name,
+                    request_fields: parse_fields(args),
+                    response_fields,
+                    file: file_path.to_string(),
+                    line: line_of(&content, body_start + offset),
+                });
+            }
+        }
+
+        if endpoints.is_empty() {
+            return vec![];
+        }
+
+        vec![Contract {
+            id: format!("thrift:{}", file_path),
+            paradigm: Paradigm::Grpc,
+            endpoints,
+            source_file: file_path.to_string(),
+            framework: "thrift".to_string(),
+            confidence: 0.90,
+        }]
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["thrift"]
+    }
+
+    fn schema_type(&self) -> &str {
+        "thrift"
+    }
+}
+
+/// Remove `//`, `#` and `/* */` comments, keeping newlines for line numbers.
+fn strip_comments(content: &str) -> String {
+    let mut out = String::with_capacity(content.len());
+    let mut chars = content.chars().peekable();
+    let mut in_string: Option<char> = None;
+    while let Some(ch) = chars.next() {
+        if let Some(quote) = in_string {
+            out.push(ch);
+            if ch == quote {
+                in_string = None;
+            }
+            continue;
+        }
+        match ch {
+            '"' | '\'' => {
+                in_string = Some(ch);
+                out.push(ch);
+            }
+            '#' => skip_line(&mut chars, &mut out),
+            '/' if chars.peek() == Some(&'/') => skip_line(&mut chars, &mut out),
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut prev = ' ';
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        out.push('\n');
+                    }
+                    if prev == '*' && c == '/' {
+                        break;
+                    }
+                    prev = c;
+                }
+            }
+            _ => out.push(ch),
+        }
+    }
+    out
+}
+
+fn skip_line(chars: &mut std::iter::Peekable<std::str::Chars<'_>>, out: &mut String) {
+    for c in chars.by_ref() {
+        if c == '\n' {
+            out.push('\n');
+            break;
+        }
+    }
+}
+
+/// Find `keyword Name [extends Base] { ... }` blocks: (name, body, body offset).
+fn blocks<'a>(content: &'a str, keyword: &str) -> Vec<(String, &'a str, usize)> {
+    let mut found = Vec::new();
+    let mut pos = 0;
+    while let Some(idx) = content[pos..].find(keyword) {
+        let start = pos + idx;
+        let after = start + keyword.len();
+        pos = after;
+        let preceded = content[..start].chars().next_back().map_or(true, |c| c.is_whitespace() || c == ';');
+        if !preceded || !content[after..].starts_with(char::is_whitespace) {
+            continue;
+        }
+        let Some(brace) = content[after..].find('{') else { break };
+        let header = content[after..after + brace].trim();
+        let name = header.split_whitespace().next().unwrap_or_default();
+        if name.is_empty() || !name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+            continue;
+        }
+        let body_start = after + brace + 1;
+        let Some(body_end) = find_matching_brace(content, body_start) else { break };
+        found.push((name.to_string(), &content[body_start..body_end], body_start));
+        pos = body_end;
+    }
+    found
+}
+
+fn find_matching_brace(content: &str, start: usize) -> Option<usize> {
+    let mut depth = 1;
+    for (i, ch) in content[start..].char_indices() {
+        match ch {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(start + i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Parse `struct`, `union` and `exception` definitions for field resolution.
+fn parse_struct_definitions(content: &str) -> Vec<(String, Vec<FieldSpec>)> {
+    ["struct", "union", "exception"]
+        .iter()
+        .flat_map(|keyword| blocks(content, keyword))
+        .map(|(name, body, _)| (name, parse_fields(body)))
+        .collect()
+}
+
+/// Parse methods from a service body: (name, argument list, return type,
+/// offset).
+fn parse_methods(body: &str) -> Vec<(String, &str, String, usize)> {
+    let mut methods = Vec::new();
+    let mut pos = 0;
+    while let Some(open) = body[pos..].find('(') {
+        let open = pos + open;
+        let Some(close) = find_matching_paren(body, open + 1) else { break };
+        let signature: Vec<&str> = split_top_level(&body[pos..open], &[' ', '\t', '\n', '\r', ',', ';'])
+            .into_iter()
+            .filter(|t| *t != "oneway")
+            .collect();
+        pos = close + 1;
+
+        // Skip a `throws (...)` clause.
+        let rest = body[pos..].trim_start();
+        if let Some(after) = rest.strip_prefix("throws") {
+            if let Some(throws_open) = after.find('(') {
+                let throws_start = body.len() - after.len() + throws_open + 1;
+                pos = find_matching_paren(body, throws_start).map_or(body.len(), |end| end + 1);
+            }
+        }
+
+        if let [.., return_type, name] = signature.as_slice() {
+            methods.push((name.to_string(), &body[open + 1..close], return_type.to_string(), open));
+        }
+    }
+    methods
+}
+
+fn find_matching_paren(content: &str, start: usize) -> Option<usize> {
+    let mut depth = 1;
+    for (i, ch) in content[start..].char_indices() {
+        match ch {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(start + i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Parse a field list: `1: required string name = "x", 2: optional i32 age;`.
+fn parse_fields(block: &str) -> Vec<FieldSpec> {
+    let mut fields = Vec::new();
+    for item in split_top_level(block, &[',', ';', '\n']) {
+        let (declaration, default) = match item.split_once('=') {
+            Some((decl, default)) => (decl, Some(default)),
+            None => (item, None),
+        };
+        // Drop the field id.
+        let declaration = declaration.split_once(':').map_or(declaration, |(_, rest)| rest);
+        let mut tokens = split_top_level(declaration, &[' ', '\t', '\r']);
+        let qualifier = match tokens.first() {
+            Some(&q) if q == "required" || q == "optional" => {
+                tokens.remove(0);
+                q
+            }
+            _ => "",
+        };
+        let [field_type, name, ..] = tokens.as_slice() else { continue };
+        fields.push(FieldSpec {
+            name: name.to_string(),
+            field_type: field_type.replace(' ', ""),
+            required: qualifier != "optional" && default.is_none(),
+            nullable: false,
+        });
+    }
+    fields
+}
+
+/// Split on any separator outside `<...>`, `(...)` and `{...}`, dropping
+/// empty pieces.
+fn split_top_level<'a>(text: &'a str, separators: &[char]) -> Vec<&'a str> {
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut start = 0;
+    for (i, ch) in text.char_indices() {
+        match ch {
+            '<' | '(' | '{' => depth += 1,
+            '>' | ')' | '}' => depth -= 1,
+            c if depth == 0 && separators.contains(&c) => {
+                parts.push(text[start..i].trim());
+                start = i + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    parts.push(text[start..].trim());
+    parts.retain(|p| !p.is_empty());
+    parts
+}
+
+fn line_of(content: &str, offset: usize) -> u32 {
+    content[..offset].matches('\n').count() as u32 + 1
+}
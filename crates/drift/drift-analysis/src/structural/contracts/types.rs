@@ -164,6 +164,31 @@ pub enum BreakingChangeType {
 }
 
 impl BreakingChangeType {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::EndpointRemoved => "endpoint_removed",
+            Self::FieldRemoved => "field_removed",
+            Self::TypeChanged => "type_changed",
+            Self::RequiredAdded => "required_added",
+            Self::OptionalToRequired => "optional_to_required",
+            Self::EnumValueRemoved => "enum_value_removed",
+            Self::PathChanged => "path_changed",
+            Self::MethodChanged => "method_changed",
+            Self::ResponseShapeChanged => "response_shape_changed",
+            Self::AuthRequirementAdded => "auth_requirement_added",
+            Self::RateLimitAdded => "rate_limit_added",
+            Self::DeprecationRemoved => "deprecation_removed",
+            Self::VersionRemoved => "version_removed",
+            Self::SchemaIncompatible => "schema_incompatible",
+            Self::FieldRenamed => "field_renamed",
+            Self::NullabilityChanged => "nullability_changed",
+            Self::ArrayToScalar => "array_to_scalar",
+            Self::ScalarToArray => "scalar_to_array",
+            Self::DefaultRemoved => "default_removed",
+            Self::ValidationAdded => "validation_added",
+        }
+    }
+
     pub fn is_breaking(&self) -> bool {
         !matches!(
             self,
@@ -172,6 +197,68 @@ impl BreakingChangeType {
     }
 }
 
+/// Avro schema-evolution compatibility mode, as configured per subject in a
+/// schema registry.
+///
+/// Backward: consumers on the new schema can read data written with the old
+/// one. Forward: consumers on the old schema can read data written with the
+/// new one. Full: both. Transitive modes check against every earlier
+/// version instead of only the previous one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum AvroCompatibility {
+    #[default]
+    Backward,
+    BackwardTransitive,
+    Forward,
+    ForwardTransitive,
+    Full,
+    FullTransitive,
+    None,
+}
+
+impl AvroCompatibility {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Backward => "backward",
+            Self::BackwardTransitive => "backward_transitive",
+            Self::Forward => "forward",
+            Self::ForwardTransitive => "forward_transitive",
+            Self::Full => "full",
+            Self::FullTransitive => "full_transitive",
+            Self::None => "none",
+        }
+    }
+
+    /// Parse a mode name, accepting schema-registry spelling (`FULL_TRANSITIVE`).
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "backward" => Some(Self::Backward),
+            "backward_transitive" => Some(Self::BackwardTransitive),
+            "forward" => Some(Self::Forward),
+            "forward_transitive" => Some(Self::ForwardTransitive),
+            "full" => Some(Self::Full),
+            "full_transitive" => Some(Self::FullTransitive),
+            "none" => Some(Self::None),
+            _ => None,
+        }
+    }
+
+    /// Whether new readers must read old data.
+    pub fn checks_backward(&self) -> bool {
+        matches!(self, Self::Backward | Self::BackwardTransitive | Self::Full | Self::FullTransitive)
+    }
+
+    /// Whether old readers must read new data.
+    pub fn checks_forward(&self) -> bool {
+        matches!(self, Self::Forward | Self::ForwardTransitive | Self::Full | Self::FullTransitive)
+    }
+
+    /// Whether every earlier version is checked, not just the previous one.
+    pub fn is_transitive(&self) -> bool {
+        matches!(self, Self::BackwardTransitive | Self::ForwardTransitive | Self::FullTransitive)
+    }
+}
+
 /// A matched pair of backend endpoint and frontend consumer.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ContractMatch {
@@ -0,0 +1,278 @@
+//! Logical (change) coupling — files that change together in git history.
+//!
+//! Walks the first-parent history newest-first. Support is the number of
+//! commits touching both files of a pair; confidence is the share of one
+//! file's commits that also touch the other. Bulk commits (reformatting,
+//! vendoring, mass renames) couple everything and are skipped. Pairs are
+//! rolled up to modules against the import graph, so co-changing modules
+//! in different workspace packages with no import edge surface as hidden
+//! dependencies.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use drift_core::errors::DetectionError;
+use drift_core::types::collections::{FxHashMap, FxHashSet};
+use drift_core::workspace::monorepo::PackageInfo;
+
+use super::types::{CycleInfo, FileCoChange, ImportGraph, LogicalCouplingReport, ModuleCoChange};
+
+/// Default minimum commits a pair must share.
+const DEFAULT_MIN_SUPPORT: u32 = 3;
+
+/// Default minimum confidence in either direction.
+const DEFAULT_MIN_CONFIDENCE: f64 = 0.5;
+
+/// Default commit size above which a commit is treated as bulk.
+const DEFAULT_MAX_FILES_PER_COMMIT: usize = 30;
+
+/// Mines co-change pairs from git history.
+pub struct ChangeCouplingAnalyzer {
+    max_commits: Option<usize>,
+    since: Option<i64>,
+    min_support: u32,
+    min_confidence: f64,
+    max_files_per_commit: usize,
+}
+
+impl ChangeCouplingAnalyzer {
+    pub fn new() -> Self {
+        Self {
+            max_commits: None,
+            since: None,
+            min_support: DEFAULT_MIN_SUPPORT,
+            min_confidence: DEFAULT_MIN_CONFIDENCE,
+            max_files_per_commit: DEFAULT_MAX_FILES_PER_COMMIT,
+        }
+    }
+
+    /// Read at most `max` commits, newest first.
+    pub fn with_max_commits(mut self, max: usize) -> Self {
+        self.max_commits = Some(max);
+        self
+    }
+
+    /// Ignore commits older than `timestamp` (Unix seconds).
+    pub fn with_since(mut self, timestamp: i64) -> Self {
+        self.since = Some(timestamp);
+        self
+    }
+
+    /// Report pairs sharing at least `support` commits.
+    pub fn with_min_support(mut self, support: u32) -> Self {
+        self.min_support = support.max(1);
+        self
+    }
+
+    /// Report pairs whose confidence in either direction reaches `confidence`.
+    pub fn with_min_confidence(mut self, confidence: f64) -> Self {
+        self.min_confidence = confidence.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Skip commits touching more than `max` files.
+    pub fn with_max_files_per_commit(mut self, max: usize) -> Self {
+        self.max_files_per_commit = max.max(2);
+        self
+    }
+
+    /// Mine co-change pairs for the repository containing `repo_path`.
+    pub fn analyze(&self, repo_path: &Path) -> Result<LogicalCouplingReport, DetectionError> {
+        let repo = git2::Repository::discover(repo_path).map_err(git_error)?;
+        let root = repo
+            .workdir()
+            .map(|p| p.to_string_lossy().replace('\\', "/").trim_end_matches('/').to_string())
+            .unwrap_or_default();
+        let mut report = LogicalCouplingReport { root, ..LogicalCouplingReport::default() };
+        let head = match repo.head().and_then(|h| h.peel_to_commit()) {
+            Ok(commit) => commit,
+            // Nothing committed yet.
+            Err(_) => return Ok(report),
+        };
+        report.head_commit = Some(head.id().to_string());
+
+        // Historical path → current path.
+        let mut renamed: HashMap<String, String> = HashMap::new();
+        let mut file_commits: FxHashMap<String, u32> = FxHashMap::default();
+        let mut pair_commits: FxHashMap<(String, String), u32> = FxHashMap::default();
+
+        let mut revwalk = repo.revwalk().map_err(git_error)?;
+        revwalk.push(head.id()).map_err(git_error)?;
+        revwalk.simplify_first_parent().map_err(git_error)?;
+
+        for oid in revwalk {
+            if self.max_commits.is_some_and(|max| report.commits_analyzed as usize >= max) {
+                break;
+            }
+            let commit = repo.find_commit(oid.map_err(git_error)?).map_err(git_error)?;
+            if self.since.is_some_and(|since| commit.time().seconds() < since) {
+                break;
+            }
+            report.commits_analyzed += 1;
+
+            let mut changed: Vec<String> = Vec::new();
+            for (path, old_path) in changed_paths(&repo, &commit)? {
+                let current = renamed.get(&path).cloned().unwrap_or(path);
+                if let Some(old_path) = old_path {
+                    renamed.insert(old_path, current.clone());
+                }
+                changed.push(current);
+            }
+            changed.sort();
+            changed.dedup();
+            if changed.len() > self.max_files_per_commit {
+                report.commits_skipped += 1;
+                continue;
+            }
+
+            for (i, file) in changed.iter().enumerate() {
+                *file_commits.entry(file.clone()).or_default() += 1;
+                for other in &changed[i + 1..] {
+                    *pair_commits.entry((file.clone(), other.clone())).or_default() += 1;
+                }
+            }
+        }
+
+        // Only paths that still exist are reported.
+        let tree = head.tree().map_err(git_error)?;
+        let exists = |file: &str| tree.get_path(Path::new(file)).is_ok();
+        report.pairs = pair_commits
+            .into_iter()
+            .filter(|(_, support)| *support >= self.min_support)
+            .filter_map(|((file_a, file_b), co_changes)| {
+                let commits = file_commits[&file_a].min(file_commits[&file_b]);
+                let confidence = co_changes as f64 / commits.max(1) as f64;
+                (confidence >= self.min_confidence && exists(&file_a) && exists(&file_b)).then_some(FileCoChange {
+                    file_a,
+                    file_b,
+                    co_changes,
+                    confidence,
+                })
+            })
+            .collect();
+        report.pairs.sort_by(|a, b| {
+            b.co_changes
+                .cmp(&a.co_changes)
+                .then_with(|| b.confidence.total_cmp(&a.confidence))
+                .then_with(|| (&a.file_a, &a.file_b).cmp(&(&b.file_a, &b.file_b)))
+        });
+        Ok(report)
+    }
+}
+
+impl Default for ChangeCouplingAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Roll file pairs up to module pairs, marking whether an import edge
+/// connects the modules, whether they share a dependency cycle and which
+/// workspace package each side is in. Pairs within one module, or with a
+/// file outside the graph, are dropped.
+///
+/// `packages` come from monorepo detection; their paths are relative to the
+/// repository root, like the report's files.
+pub fn module_change_coupling(
+    report: &LogicalCouplingReport,
+    graph: &ImportGraph,
+    cycles: &[CycleInfo],
+    packages: &[PackageInfo],
+) -> Vec<ModuleCoChange> {
+    let package_prefixes: Vec<(String, &str)> = packages
+        .iter()
+        .map(|p| {
+            let prefix = format!("{}/", p.path.to_string_lossy().replace('\\', "/").trim_end_matches('/'));
+            (prefix, p.name.as_str())
+        })
+        .collect();
+    // The innermost package wins when packages nest.
+    let package_of = |file: &str| {
+        package_prefixes
+            .iter()
+            .filter(|(prefix, _)| file.starts_with(prefix.as_str()))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map(|(_, name)| name.to_string())
+    };
+    let module_of = |file: &str| {
+        graph
+            .file_modules
+            .get(file)
+            .or_else(|| graph.file_modules.get(&format!("{}/{}", report.root, file)))
+    };
+    let imports = |from: &str, to: &str| graph.edges.get(from).is_some_and(|targets| targets.iter().any(|t| t == to));
+    let cycle_members: Vec<FxHashSet<&str>> = cycles
+        .iter()
+        .map(|c| c.members.iter().map(String::as_str).collect())
+        .collect();
+
+    let mut modules: FxHashMap<(&str, &str), ModuleCoChange> = FxHashMap::default();
+    // Pairs arrive strongest first, so the first pair seen for a module
+    // pair is its strongest link.
+    for pair in &report.pairs {
+        let (Some(a), Some(b)) = (module_of(&pair.file_a), module_of(&pair.file_b)) else { continue };
+        if a == b {
+            continue;
+        }
+        let (file_a, file_b) = if a < b { (&pair.file_a, &pair.file_b) } else { (&pair.file_b, &pair.file_a) };
+        let (a, b) = if a < b { (a, b) } else { (b, a) };
+        modules
+            .entry((a, b))
+            .or_insert_with(|| ModuleCoChange {
+                module_a: a.clone(),
+                module_b: b.clone(),
+                co_changes: pair.co_changes,
+                confidence: pair.confidence,
+                file_a: file_a.clone(),
+                file_b: file_b.clone(),
+                file_pairs: 0,
+                import_edge: imports(a, b) || imports(b, a),
+                in_cycle: cycle_members.iter().any(|m| m.contains(a.as_str()) && m.contains(b.as_str())),
+                package_a: package_of(file_a),
+                package_b: package_of(file_b),
+            })
+            .file_pairs += 1;
+    }
+
+    let mut result: Vec<ModuleCoChange> = modules.into_values().collect();
+    result.sort_by(|a, b| {
+        b.co_changes
+            .cmp(&a.co_changes)
+            .then_with(|| b.confidence.total_cmp(&a.confidence))
+            .then_with(|| (&a.module_a, &a.module_b).cmp(&(&b.module_a, &b.module_b)))
+    });
+    result
+}
+
+/// Paths a commit changed relative to its first parent, with the old path
+/// of renames.
+fn changed_paths(
+    repo: &git2::Repository,
+    commit: &git2::Commit,
+) -> Result<Vec<(String, Option<String>)>, DetectionError> {
+    let tree = commit.tree().map_err(git_error)?;
+    let parent_tree = commit.parent(0).ok().and_then(|p| p.tree().ok());
+    let mut diff = repo
+        .diff_tree_to_tree(parent_tree.as_ref(), Some(&tree), None)
+        .map_err(git_error)?;
+    diff.find_similar(Some(git2::DiffFindOptions::new().renames(true)))
+        .map_err(git_error)?;
+
+    let path_of = |file: git2::DiffFile<'_>| file.path().map(|p| p.to_string_lossy().replace('\\', "/"));
+    Ok(diff
+        .deltas()
+        .filter_map(|delta| {
+            let status = delta.status();
+            if status == git2::Delta::Deleted {
+                return path_of(delta.old_file()).map(|path| (path, None));
+            }
+            let path = path_of(delta.new_file())?;
+            let old_path = path_of(delta.old_file()).filter(|old| status == git2::Delta::Renamed && *old != path);
+            Some((path, old_path))
+        })
+        .collect())
+}
+
+fn git_error(e: git2::Error) -> DetectionError {
+    DetectionError::Git(e.message().to_string())
+}
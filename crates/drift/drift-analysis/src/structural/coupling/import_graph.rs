@@ -48,10 +48,12 @@ impl ImportGraphBuilder {
         let mut edges: FxHashMap<String, FxHashSet<String>> = FxHashMap::default();
         let mut abstract_counts: FxHashMap<String, u32> = FxHashMap::default();
         let mut total_type_counts: FxHashMap<String, u32> = FxHashMap::default();
+        let mut file_modules: FxHashMap<String, String> = FxHashMap::default();
 
         // Collect all modules
         for file in self.file_imports.keys() {
             let module = self.file_to_module(file);
+            file_modules.insert(file.clone(), module.clone());
             module_set.insert(module);
         }
 
@@ -87,6 +89,7 @@ impl ImportGraphBuilder {
             modules,
             abstract_counts,
             total_type_counts,
+            file_modules,
         }
     }
 
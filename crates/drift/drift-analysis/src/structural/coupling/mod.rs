@@ -2,16 +2,20 @@
 //!
 //! Computes Ce (efferent), Ca (afferent), I (instability), A (abstractness),
 //! D (distance from main sequence) per module. Detects dependency cycles via
-//! Tarjan's SCC and suggests cycle-breaking edges.
+//! Tarjan's SCC and suggests cycle-breaking edges. Logical coupling mined
+//! from git co-change complements the static graph, flagging modules that
+//! change together without importing each other.
 
 pub mod types;
 pub mod import_graph;
 pub mod martin_metrics;
 pub mod cycle_detection;
 pub mod zones;
+pub mod change_coupling;
 
 pub use types::*;
 pub use import_graph::ImportGraphBuilder;
 pub use martin_metrics::compute_martin_metrics;
 pub use cycle_detection::detect_cycles;
 pub use zones::classify_zone;
+pub use change_coupling::{module_change_coupling, ChangeCouplingAnalyzer};
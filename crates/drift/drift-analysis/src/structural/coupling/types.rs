@@ -80,6 +80,8 @@ pub struct ImportGraph {
     pub abstract_counts: FxHashMap<String, u32>,
     /// Total type counts per module.
     pub total_type_counts: FxHashMap<String, u32>,
+    /// Source file → its module.
+    pub file_modules: FxHashMap<String, String>,
 }
 
 /// Trend direction for coupling metrics over time.
@@ -106,3 +108,62 @@ pub struct CouplingAnalysisResult {
     pub cycles: Vec<CycleInfo>,
     pub module_count: usize,
 }
+
+/// Two files that change in the same commits.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileCoChange {
+    /// Paths relative to the repository root, `file_a < file_b`.
+    pub file_a: String,
+    pub file_b: String,
+    /// Support: commits touching both files.
+    pub co_changes: u32,
+    /// The higher of P(b changes | a changes) and P(a changes | b changes).
+    pub confidence: f64,
+}
+
+/// Co-change mined from git history.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LogicalCouplingReport {
+    /// Pairs above the support and confidence thresholds, strongest first.
+    pub pairs: Vec<FileCoChange>,
+    pub commits_analyzed: u32,
+    /// Bulk commits ignored because they touch too many files.
+    pub commits_skipped: u32,
+    pub head_commit: Option<String>,
+    /// Repository working directory.
+    pub root: String,
+}
+
+/// Logical coupling between two modules, with the static picture alongside.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModuleCoChange {
+    /// `module_a < module_b`.
+    pub module_a: String,
+    pub module_b: String,
+    /// Support and confidence of the most strongly coupled file pair.
+    pub co_changes: u32,
+    pub confidence: f64,
+    /// That file pair.
+    pub file_a: String,
+    pub file_b: String,
+    /// File pairs linking the two modules.
+    pub file_pairs: u32,
+    /// Whether either module imports the other.
+    pub import_edge: bool,
+    /// Whether both modules are in the same dependency cycle. They then
+    /// depend on each other through the cycle even without a direct import,
+    /// so the co-change is explained, and breaking the cycle is the fix.
+    pub in_cycle: bool,
+    /// Workspace packages holding `file_a` and `file_b`; `None` outside any
+    /// package or in a single-package repository.
+    pub package_a: Option<String>,
+    pub package_b: Option<String>,
+}
+
+impl ModuleCoChange {
+    /// Modules in different packages that change together although no
+    /// import edge or dependency cycle connects them.
+    pub fn is_hidden(&self) -> bool {
+        !self.import_edge && !self.in_cycle && self.package_a != self.package_b
+    }
+}
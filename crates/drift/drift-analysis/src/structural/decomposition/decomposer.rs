@@ -22,6 +22,8 @@ pub struct FileEntry {
     pub path: String,
     pub line_count: u64,
     pub language: String,
+    /// Summed cyclomatic complexity of the file's functions; 0 if unmeasured.
+    pub cyclomatic: u64,
 }
 
 /// Decompose a codebase into logical modules, applying priors as boundary adjustments.
@@ -68,10 +70,11 @@ pub fn decompose_with_priors(
     // Step 8: Set convention profiles and complexity
     for module in &mut modules {
         module.convention_profile = infer_convention_profile(&module.files);
-        module.estimated_complexity = input.files.iter()
+        let files: Vec<&FileEntry> = input.files.iter()
             .filter(|f| module.files.contains(&f.path))
-            .map(|f| f.line_count)
-            .sum();
+            .collect();
+        module.estimated_complexity = files.iter().map(|f| f.line_count).sum();
+        module.cyclomatic_complexity = files.iter().map(|f| f.cyclomatic).sum();
     }
 
     // Sort deterministically by name
@@ -107,6 +110,7 @@ fn cluster_by_directory(input: &DecompositionInput) -> Vec<LogicalModule> {
             cohesion: 0.0,
             coupling: 0.0,
             estimated_complexity: 0,
+            cyclomatic_complexity: 0,
             applied_priors: Vec::new(),
         }
     }).collect()
@@ -336,6 +340,7 @@ fn apply_split(
             cohesion: 0.0,
             coupling: 0.0,
             estimated_complexity: 0,
+            cyclomatic_complexity: 0,
             applied_priors: vec![applied_prior.clone()],
         });
     }
@@ -390,6 +395,7 @@ fn apply_merge(
         cohesion: 0.0,
         coupling: 0.0,
         estimated_complexity: 0,
+        cyclomatic_complexity: 0,
         applied_priors: vec![applied_prior],
     });
 }
@@ -60,6 +60,9 @@ pub struct LogicalModule {
     pub coupling: f64,
     /// Estimated complexity (total lines across files).
     pub estimated_complexity: u64,
+    /// Total cyclomatic complexity of the functions in measured files.
+    #[serde(default)]
+    pub cyclomatic_complexity: u64,
     /// Applied priors (if any).
     pub applied_priors: Vec<AppliedPrior>,
 }
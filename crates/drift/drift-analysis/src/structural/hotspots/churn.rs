@@ -0,0 +1,415 @@
+//! Git churn — per-file and per-function change history.
+//!
+//! Walks the first-parent history newest-first with git2. File churn counts
+//! commits, added and removed lines and distinct authors. Function churn
+//! attributes each diff hunk to the functions whose lines it touches; the
+//! functions' current line ranges are mapped back through every diff, so
+//! hunks in old commits land on the right function even after code above
+//! it moved. Renames are followed, so churn stays with the current path.
+
+use std::collections::{BTreeSet, HashMap};
+use std::path::Path;
+
+use drift_core::errors::DetectionError;
+use drift_core::types::collections::{FxHashMap, FxHashSet};
+
+use crate::parsers::types::ParseResult;
+
+use super::types::{ChurnReport, FileChurn, FunctionChurn, FunctionSpan};
+
+/// Diffs of files larger than this are skipped (generated bundles, dumps).
+const MAX_FILE_BYTES: i64 = 1024 * 1024;
+
+/// Default half-life for recency weighting, in days.
+const DEFAULT_HALF_LIFE_DAYS: f64 = 90.0;
+
+const SECONDS_PER_DAY: f64 = 86_400.0;
+
+/// Computes file and function churn from git history.
+pub struct ChurnAnalyzer {
+    max_commits: Option<usize>,
+    since: Option<i64>,
+    half_life_days: f64,
+}
+
+/// A diff hunk's old and new line ranges.
+#[derive(Debug, Clone, Copy)]
+struct Hunk {
+    old_start: u32,
+    old_lines: u32,
+    new_start: u32,
+    new_lines: u32,
+}
+
+#[derive(Default)]
+struct FileAccumulator {
+    commits: u32,
+    lines_added: u32,
+    lines_removed: u32,
+    authors: BTreeSet<String>,
+    last_changed: i64,
+    recency_weighted: f64,
+}
+
+#[derive(Default)]
+struct FunctionAccumulator {
+    commits: u32,
+    lines_changed: u32,
+    recency_weighted: f64,
+}
+
+#[derive(Default)]
+struct FileDiff {
+    old_path: Option<String>,
+    added_file: bool,
+    deleted: bool,
+    lines_added: u32,
+    lines_removed: u32,
+    hunks: Vec<Hunk>,
+}
+
+impl ChurnAnalyzer {
+    pub fn new() -> Self {
+        Self {
+            max_commits: None,
+            since: None,
+            half_life_days: DEFAULT_HALF_LIFE_DAYS,
+        }
+    }
+
+    /// Read at most `max` commits, newest first.
+    pub fn with_max_commits(mut self, max: usize) -> Self {
+        self.max_commits = Some(max);
+        self
+    }
+
+    /// Ignore commits older than `timestamp` (Unix seconds).
+    pub fn with_since(mut self, timestamp: i64) -> Self {
+        self.since = Some(timestamp);
+        self
+    }
+
+    /// A commit `half_life` days older than HEAD counts half as much.
+    pub fn with_half_life_days(mut self, half_life: f64) -> Self {
+        self.half_life_days = half_life.max(f64::EPSILON);
+        self
+    }
+
+    /// Compute churn for the repository containing `repo_path`. `functions`
+    /// are attributed hunks by their current line ranges; their paths may
+    /// be absolute or relative to the repository root.
+    pub fn analyze(&self, repo_path: &Path, functions: &[FunctionSpan]) -> Result<ChurnReport, DetectionError> {
+        let repo = git2::Repository::discover(repo_path).map_err(git_error)?;
+        let root = repo
+            .workdir()
+            .map(|p| p.to_string_lossy().replace('\\', "/").trim_end_matches('/').to_string())
+            .unwrap_or_default();
+        let mut report = ChurnReport { root, ..ChurnReport::default() };
+        let head = match repo.head().and_then(|h| h.peel_to_commit()) {
+            Ok(commit) => commit,
+            // Nothing committed yet.
+            Err(_) => return Ok(report),
+        };
+        report.head_commit = Some(head.id().to_string());
+        let now = head.time().seconds();
+
+        // Current line ranges per path, mapped back as history is walked.
+        let mut ranges: HashMap<String, Vec<(usize, u32, u32)>> = HashMap::new();
+        for (i, span) in functions.iter().enumerate() {
+            let file = report.relative(&span.file).to_string();
+            ranges.entry(file).or_default().push((i, span.line, span.end_line));
+        }
+        // Historical path → current path.
+        let mut renamed: HashMap<String, String> = HashMap::new();
+        let mut files: HashMap<String, FileAccumulator> = HashMap::new();
+        let mut function_churn: HashMap<usize, FunctionAccumulator> = HashMap::new();
+
+        let mut revwalk = repo.revwalk().map_err(git_error)?;
+        revwalk.push(head.id()).map_err(git_error)?;
+        revwalk.simplify_first_parent().map_err(git_error)?;
+
+        for oid in revwalk {
+            if self.max_commits.is_some_and(|max| report.commits_analyzed as usize >= max) {
+                break;
+            }
+            let commit = repo.find_commit(oid.map_err(git_error)?).map_err(git_error)?;
+            let time = commit.time().seconds();
+            if self.since.is_some_and(|since| time < since) {
+                break;
+            }
+            report.commits_analyzed += 1;
+            let age_days = (now - time).max(0) as f64 / SECONDS_PER_DAY;
+            let weight = 0.5f64.powf(age_days / self.half_life_days);
+            let author = {
+                let signature = commit.author();
+                signature
+                    .email()
+                    .map(str::to_lowercase)
+                    .or_else(|| signature.name().map(str::to_string))
+                    .unwrap_or_else(|| "unknown".to_string())
+            };
+
+            for (path, diff) in commit_diff(&repo, &commit)? {
+                let current = renamed.get(&path).cloned().unwrap_or_else(|| path.clone());
+                if !diff.deleted {
+                    let file = files.entry(current.clone()).or_default();
+                    file.commits += 1;
+                    file.lines_added += diff.lines_added;
+                    file.lines_removed += diff.lines_removed;
+                    file.authors.insert(author.clone());
+                    file.last_changed = file.last_changed.max(time);
+                    file.recency_weighted += weight;
+                }
+
+                for (i, start, end) in ranges.get_mut(&path).into_iter().flatten() {
+                    let changed: u32 = diff
+                        .hunks
+                        .iter()
+                        .filter(|h| touches(h, *start, *end))
+                        .map(|h| h.old_lines + h.new_lines)
+                        .sum();
+                    if changed > 0 {
+                        let churn = function_churn.entry(*i).or_default();
+                        churn.commits += 1;
+                        churn.lines_changed += changed;
+                        churn.recency_weighted += weight;
+                    }
+                    *start = to_old_line(*start, &diff.hunks);
+                    *end = to_old_line(*end, &diff.hunks).max(*start);
+                }
+
+                // Older commits see the file under its old path, or not at all.
+                let spans = ranges.remove(&path);
+                if diff.added_file {
+                    continue;
+                }
+                let older_path = match diff.old_path {
+                    Some(old_path) => {
+                        renamed.insert(old_path.clone(), current);
+                        old_path
+                    }
+                    None => path,
+                };
+                if let Some(spans) = spans {
+                    ranges.insert(older_path, spans);
+                }
+            }
+        }
+
+        // Only paths that still exist are reported.
+        let tree = head.tree().map_err(git_error)?;
+        report.files = files
+            .into_iter()
+            .filter(|(file, _)| tree.get_path(Path::new(file)).is_ok())
+            .map(|(file, acc)| FileChurn {
+                file,
+                commits: acc.commits,
+                lines_added: acc.lines_added,
+                lines_removed: acc.lines_removed,
+                authors: acc.authors.len() as u32,
+                last_changed: acc.last_changed,
+                recency_weighted: acc.recency_weighted,
+            })
+            .collect();
+        report.files.sort_by(|a, b| {
+            b.recency_weighted
+                .total_cmp(&a.recency_weighted)
+                .then_with(|| a.file.cmp(&b.file))
+        });
+
+        report.functions = function_churn
+            .into_iter()
+            .map(|(i, acc)| FunctionChurn {
+                file: report.relative(&functions[i].file).to_string(),
+                name: functions[i].name.clone(),
+                line: functions[i].line,
+                commits: acc.commits,
+                lines_changed: acc.lines_changed,
+                recency_weighted: acc.recency_weighted,
+            })
+            .collect();
+        report.functions.sort_by(|a, b| {
+            b.recency_weighted
+                .total_cmp(&a.recency_weighted)
+                .then_with(|| (&a.file, a.line).cmp(&(&b.file, b.line)))
+        });
+        Ok(report)
+    }
+}
+
+impl Default for ChurnAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ChurnReport {
+    /// `file` relative to the repository root.
+    pub fn relative<'a>(&self, file: &'a str) -> &'a str {
+        if self.root.is_empty() {
+            return file;
+        }
+        file.strip_prefix(self.root.as_str())
+            .and_then(|rest| rest.strip_prefix('/'))
+            .unwrap_or(file)
+    }
+
+    /// Normalized change frequency (0.0-1.0) per function, keyed by
+    /// `(relative file, function name)`, plus the per-file fallback for
+    /// functions no hunk touched.
+    pub fn change_frequencies(&self) -> ChangeFrequencies<'_> {
+        let max_function = self.functions.iter().map(|f| f.recency_weighted).fold(0.0, f64::max);
+        let max_file = self.files.iter().map(|f| f.recency_weighted).fold(0.0, f64::max);
+        let normalize = |value: f64, max: f64| if max > 0.0 { (value / max) as f32 } else { 0.0 };
+        ChangeFrequencies {
+            report: self,
+            functions: self
+                .functions
+                .iter()
+                .map(|f| ((f.file.as_str(), f.name.as_str()), normalize(f.recency_weighted, max_function)))
+                .collect(),
+            attributed: self.functions.iter().map(|f| f.file.as_str()).collect(),
+            files: self
+                .files
+                .iter()
+                .map(|f| (f.file.as_str(), normalize(f.recency_weighted, max_file)))
+                .collect(),
+        }
+    }
+}
+
+/// Normalized change frequencies for risk scoring.
+pub struct ChangeFrequencies<'a> {
+    report: &'a ChurnReport,
+    functions: FxHashMap<(&'a str, &'a str), f32>,
+    /// Files with at least one attributed function.
+    attributed: FxHashSet<&'a str>,
+    files: FxHashMap<&'a str, f32>,
+}
+
+impl ChangeFrequencies<'_> {
+    /// The function's change frequency. Files whose functions were not
+    /// attributed fall back to the file's; untouched functions in
+    /// attributed files score 0.
+    pub fn get(&self, file: &str, function: &str) -> f32 {
+        let file = self.report.relative(file);
+        if let Some(&frequency) = self.functions.get(&(file, function)) {
+            return frequency;
+        }
+        if self.attributed.contains(file) {
+            return 0.0;
+        }
+        self.files.get(file).copied().unwrap_or(0.0)
+    }
+}
+
+/// Spans named like call graph nodes: each function the parser reports
+/// under its own name, and each class method as `Class.method`. Methods a
+/// parser also lists among the functions get both.
+///
+/// Parsers number lines from 0; spans use the 1-based numbers diff hunks do.
+pub fn spans_from_parse_results(parse_results: &[ParseResult]) -> Vec<FunctionSpan> {
+    let mut spans = Vec::new();
+    for pr in parse_results {
+        let span = |name: String, line: u32, end_line: u32| FunctionSpan {
+            file: pr.file.clone(),
+            name,
+            line: line + 1,
+            end_line: end_line + 1,
+        };
+        spans.extend(pr.functions.iter().map(|f| span(f.name.clone(), f.line, f.end_line)));
+        for class in &pr.classes {
+            spans.extend(
+                class
+                    .methods
+                    .iter()
+                    .map(|m| span(format!("{}.{}", class.name, m.name), m.line, m.end_line)),
+            );
+        }
+    }
+    spans
+}
+
+/// Whether a hunk changes lines inside `start..=end` (new-side numbers).
+fn touches(hunk: &Hunk, start: u32, end: u32) -> bool {
+    if hunk.new_lines == 0 {
+        // A pure deletion sits after `new_start`.
+        return start <= hunk.new_start && hunk.new_start < end;
+    }
+    let hunk_end = hunk.new_start + hunk.new_lines - 1;
+    hunk.new_start <= end && start <= hunk_end
+}
+
+/// Map a new-side line number to the old side of the diff.
+fn to_old_line(line: u32, hunks: &[Hunk]) -> u32 {
+    let mut delta: i64 = 0;
+    for hunk in hunks {
+        if hunk.new_lines == 0 {
+            if line > hunk.new_start {
+                delta += hunk.old_lines as i64;
+                continue;
+            }
+            break;
+        }
+        let new_end = hunk.new_start + hunk.new_lines;
+        if line >= new_end {
+            delta += hunk.old_lines as i64 - hunk.new_lines as i64;
+        } else if line >= hunk.new_start {
+            // Inside a changed region: the nearest old line.
+            let offset = (line - hunk.new_start).min(hunk.old_lines.saturating_sub(1));
+            return (hunk.old_start + offset).max(1);
+        } else {
+            break;
+        }
+    }
+    (line as i64 + delta).max(1) as u32
+}
+
+/// A commit's changes relative to its first parent, by new path.
+fn commit_diff(repo: &git2::Repository, commit: &git2::Commit) -> Result<Vec<(String, FileDiff)>, DetectionError> {
+    let tree = commit.tree().map_err(git_error)?;
+    let parent_tree = commit.parent(0).ok().and_then(|p| p.tree().ok());
+    let mut options = git2::DiffOptions::new();
+    options.context_lines(0).max_size(MAX_FILE_BYTES);
+    let mut diff = repo
+        .diff_tree_to_tree(parent_tree.as_ref(), Some(&tree), Some(&mut options))
+        .map_err(git_error)?;
+    diff.find_similar(Some(git2::DiffFindOptions::new().renames(true)))
+        .map_err(git_error)?;
+
+    let path_of = |file: git2::DiffFile<'_>| file.path().map(|p| p.to_string_lossy().replace('\\', "/"));
+    let mut files = Vec::new();
+    for (i, delta) in diff.deltas().enumerate() {
+        let status = delta.status();
+        let deleted = status == git2::Delta::Deleted;
+        let path = if deleted { path_of(delta.old_file()) } else { path_of(delta.new_file()) };
+        let Some(path) = path else { continue };
+        let mut file = FileDiff {
+            old_path: path_of(delta.old_file()).filter(|old| status == git2::Delta::Renamed && *old != path),
+            added_file: status == git2::Delta::Added,
+            deleted,
+            ..FileDiff::default()
+        };
+        // Binary and oversized files have no patch.
+        if let Some(patch) = git2::Patch::from_diff(&diff, i).map_err(git_error)? {
+            let (_, added, removed) = patch.line_stats().map_err(git_error)?;
+            file.lines_added = added as u32;
+            file.lines_removed = removed as u32;
+            for h in 0..patch.num_hunks() {
+                let (hunk, _) = patch.hunk(h).map_err(git_error)?;
+                file.hunks.push(Hunk {
+                    old_start: hunk.old_start(),
+                    old_lines: hunk.old_lines(),
+                    new_start: hunk.new_start(),
+                    new_lines: hunk.new_lines(),
+                });
+            }
+        }
+        files.push((path, file));
+    }
+    Ok(files)
+}
+
+fn git_error(e: git2::Error) -> DetectionError {
+    DetectionError::Git(e.message().to_string())
+}
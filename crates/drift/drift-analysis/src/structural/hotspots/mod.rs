@@ -0,0 +1,16 @@
+//! Hotspots — complexity rollups, git churn and risk ranking.
+//!
+//! Ranks files by summed cognitive complexity, with the function driving
+//! each file's score, so refactoring effort goes where code is hardest to
+//! understand. Git churn adds how often and how recently code changes;
+//! files that are complex, churned and coupled rank as risk hotspots.
+
+pub mod types;
+pub mod report;
+pub mod churn;
+pub mod ranking;
+
+pub use types::*;
+pub use report::{compute_hotspots, functions_from_parse_results};
+pub use churn::{spans_from_parse_results, ChangeFrequencies, ChurnAnalyzer};
+pub use ranking::{file_coupling, rank_risk_hotspots};
@@ -0,0 +1,81 @@
+//! Risk hotspot ranking — churn × complexity × coupling.
+
+use drift_core::types::collections::{FxHashMap, FxHashSet};
+
+use crate::call_graph::types::CallGraph;
+
+use super::types::{ChurnReport, HotspotReport, HotspotTrend, RiskHotspot};
+
+/// Relative score changes within this band are reported as stable.
+const TREND_TOLERANCE: f64 = 0.10;
+
+/// Number of other files each file calls or is called from.
+pub fn file_coupling(graph: &CallGraph) -> FxHashMap<String, u32> {
+    let mut neighbours: FxHashMap<&str, FxHashSet<&str>> = FxHashMap::default();
+    for edge in graph.graph.edge_indices() {
+        let Some((from, to)) = graph.graph.edge_endpoints(edge) else { continue };
+        let (from, to) = (graph.graph[from].file.as_str(), graph.graph[to].file.as_str());
+        if from != to {
+            neighbours.entry(from).or_default().insert(to);
+            neighbours.entry(to).or_default().insert(from);
+        }
+    }
+    neighbours
+        .into_iter()
+        .map(|(file, others)| (file.to_string(), others.len() as u32))
+        .collect()
+}
+
+/// Rank files that are both churned and complex. Each factor is normalized
+/// against the highest file; coupling amplifies the score rather than
+/// gating it, so an isolated file can still be a hotspot. `previous` holds
+/// the last analysis's scores by relative path, for trends.
+pub fn rank_risk_hotspots(
+    complexity: &HotspotReport,
+    churn: &ChurnReport,
+    coupling: &FxHashMap<String, u32>,
+    previous: &FxHashMap<String, f64>,
+) -> Vec<RiskHotspot> {
+    let complexity: FxHashMap<&str, f64> = complexity
+        .files
+        .iter()
+        .map(|f| (churn.relative(&f.file), f.score))
+        .collect();
+    let coupling: FxHashMap<&str, u32> = coupling.iter().map(|(f, n)| (churn.relative(f), *n)).collect();
+    let max_churn = churn.files.iter().map(|f| f.recency_weighted).fold(0.0, f64::max);
+    let max_complexity = complexity.values().copied().fold(0.0, f64::max);
+    let max_coupling = coupling.values().copied().max().unwrap_or(0) as f64;
+    let ratio = |value: f64, max: f64| if max > 0.0 { value / max } else { 0.0 };
+
+    let mut hotspots: Vec<RiskHotspot> = churn
+        .files
+        .iter()
+        .filter_map(|f| {
+            let churn_factor = ratio(f.recency_weighted, max_churn);
+            let complexity_factor = ratio(complexity.get(f.file.as_str()).copied()?, max_complexity);
+            let coupling_factor = ratio(coupling.get(f.file.as_str()).copied().unwrap_or(0) as f64, max_coupling);
+            let score = churn_factor * complexity_factor * (1.0 + coupling_factor);
+            (score > 0.0).then(|| RiskHotspot {
+                file: f.file.clone(),
+                churn: churn_factor,
+                complexity: complexity_factor,
+                coupling: coupling_factor,
+                score,
+                commits: f.commits,
+                authors: f.authors,
+                trend: trend(score, previous.get(&f.file).copied()),
+            })
+        })
+        .collect();
+    hotspots.sort_by(|a, b| b.score.total_cmp(&a.score).then_with(|| a.file.cmp(&b.file)));
+    hotspots
+}
+
+fn trend(score: f64, previous: Option<f64>) -> HotspotTrend {
+    match previous {
+        None => HotspotTrend::New,
+        Some(prev) if score > prev * (1.0 + TREND_TOLERANCE) => HotspotTrend::Rising,
+        Some(prev) if score < prev * (1.0 - TREND_TOLERANCE) => HotspotTrend::Falling,
+        Some(_) => HotspotTrend::Stable,
+    }
+}
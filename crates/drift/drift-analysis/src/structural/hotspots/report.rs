@@ -0,0 +1,73 @@
+//! Hotspot report computation.
+
+use drift_core::types::collections::{FxHashMap, FxHashSet};
+
+use crate::parsers::types::ParseResult;
+
+use super::types::{FileHotspot, FunctionComplexity, HotspotReport};
+
+/// Measured functions and class methods from parse results. Methods some
+/// parsers also list as functions are taken once.
+pub fn functions_from_parse_results(parse_results: &[ParseResult]) -> Vec<FunctionComplexity> {
+    let mut functions = Vec::new();
+    for pr in parse_results {
+        let mut seen = FxHashSet::default();
+        let all = pr.functions.iter().chain(pr.classes.iter().flat_map(|c| c.methods.iter()));
+        for f in all.filter(|f| f.complexity.is_measured()) {
+            if seen.insert((f.line, f.name.as_str())) {
+                functions.push(FunctionComplexity {
+                    file: pr.file.clone(),
+                    name: f.name.clone(),
+                    line: f.line,
+                    complexity: f.complexity,
+                });
+            }
+        }
+    }
+    functions
+}
+
+/// Roll measured functions up per file and rank the files. Unmeasured
+/// functions are ignored.
+pub fn compute_hotspots(functions: &[FunctionComplexity]) -> HotspotReport {
+    let mut by_file: FxHashMap<&str, FileHotspot> = FxHashMap::default();
+    let mut function_count = 0;
+
+    for f in functions.iter().filter(|f| f.complexity.is_measured()) {
+        function_count += 1;
+        let m = &f.complexity;
+        let entry = by_file.entry(&f.file).or_insert_with(|| FileHotspot {
+            file: f.file.clone(),
+            function_count: 0,
+            total_cyclomatic: 0,
+            max_cyclomatic: 0,
+            total_cognitive: 0,
+            max_cognitive: 0,
+            max_nesting: 0,
+            hottest_function: f.name.clone(),
+            hottest_line: f.line,
+            score: 0.0,
+        });
+        entry.function_count += 1;
+        entry.total_cyclomatic += m.cyclomatic;
+        entry.max_cyclomatic = entry.max_cyclomatic.max(m.cyclomatic);
+        entry.total_cognitive += m.cognitive;
+        if m.cognitive > entry.max_cognitive {
+            entry.max_cognitive = m.cognitive;
+            entry.hottest_function = f.name.clone();
+            entry.hottest_line = f.line;
+        }
+        entry.max_nesting = entry.max_nesting.max(m.max_nesting);
+        entry.score = entry.total_cognitive as f64;
+    }
+
+    let mut files: Vec<FileHotspot> = by_file.into_values().collect();
+    files.sort_by(|a, b| {
+        b.score
+            .total_cmp(&a.score)
+            .then(b.total_cyclomatic.cmp(&a.total_cyclomatic))
+            .then_with(|| a.file.cmp(&b.file))
+    });
+
+    HotspotReport { files, function_count }
+}
@@ -0,0 +1,135 @@
+//! Complexity hotspot types.
+
+use serde::{Deserialize, Serialize};
+
+use crate::parsers::types::ComplexityMetrics;
+
+/// A measured function, from parse results or the functions table.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FunctionComplexity {
+    pub file: String,
+    pub name: String,
+    pub line: u32,
+    pub complexity: ComplexityMetrics,
+}
+
+/// Complexity rolled up for one file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileHotspot {
+    pub file: String,
+    pub function_count: u32,
+    pub total_cyclomatic: u32,
+    pub max_cyclomatic: u32,
+    pub total_cognitive: u32,
+    pub max_cognitive: u32,
+    pub max_nesting: u32,
+    /// The function with the highest cognitive complexity.
+    pub hottest_function: String,
+    pub hottest_line: u32,
+    /// Ranking score: summed cognitive complexity.
+    pub score: f64,
+}
+
+/// Files ranked by hotspot score, highest first.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HotspotReport {
+    pub files: Vec<FileHotspot>,
+    /// Measured functions across all files.
+    pub function_count: u32,
+}
+
+impl HotspotReport {
+    /// The `n` hottest files.
+    pub fn top(&self, n: usize) -> &[FileHotspot] {
+        &self.files[..n.min(self.files.len())]
+    }
+}
+
+/// A function's current location, used to attribute diff hunks to it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FunctionSpan {
+    pub file: String,
+    pub name: String,
+    pub line: u32,
+    pub end_line: u32,
+}
+
+/// Change history for one file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileChurn {
+    /// Path relative to the repository root.
+    pub file: String,
+    pub commits: u32,
+    pub lines_added: u32,
+    pub lines_removed: u32,
+    /// Distinct commit authors.
+    pub authors: u32,
+    /// Unix time of the most recent change.
+    pub last_changed: i64,
+    /// Commits weighted by age: each counts `0.5^(age / half_life)`.
+    pub recency_weighted: f64,
+}
+
+/// Change history for one function, from the hunks that touched its lines.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FunctionChurn {
+    pub file: String,
+    pub name: String,
+    pub line: u32,
+    pub commits: u32,
+    /// Added plus removed lines inside the function.
+    pub lines_changed: u32,
+    pub recency_weighted: f64,
+}
+
+/// Churn for every file and function changed in the analyzed history.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ChurnReport {
+    /// Files, most recency-weighted churn first.
+    pub files: Vec<FileChurn>,
+    /// Functions, most recency-weighted churn first.
+    pub functions: Vec<FunctionChurn>,
+    pub commits_analyzed: u32,
+    /// HEAD when the history was read.
+    pub head_commit: Option<String>,
+    /// Repository working directory, for matching absolute paths.
+    pub root: String,
+}
+
+/// Direction of a file's hotspot score since the previous analysis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HotspotTrend {
+    /// Not ranked in the previous analysis.
+    New,
+    Rising,
+    Stable,
+    Falling,
+}
+
+impl HotspotTrend {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::New => "new",
+            Self::Rising => "rising",
+            Self::Stable => "stable",
+            Self::Falling => "falling",
+        }
+    }
+}
+
+/// A file ranked by churn × complexity × coupling.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RiskHotspot {
+    pub file: String,
+    /// Recency-weighted churn relative to the most churned file (0.0-1.0).
+    pub churn: f64,
+    /// Summed cognitive complexity relative to the most complex file (0.0-1.0).
+    pub complexity: f64,
+    /// Files connected through call edges relative to the most coupled file (0.0-1.0).
+    pub coupling: f64,
+    /// `churn × complexity × (1 + coupling)`.
+    pub score: f64,
+    pub commits: u32,
+    pub authors: u32,
+    pub trend: HotspotTrend,
+}
@@ -12,3 +12,4 @@ pub mod dna;
 pub mod owasp_cwe;
 pub mod crypto;
 pub mod decomposition;
+pub mod hotspots;
@@ -1,5 +1,5 @@
 #![allow(unused_imports, clippy::useless_vec)]
-//! Boundary Detection tests — T2-BND-01 through T2-BND-06.
+//! Boundary Detection tests — T2-BND-01 through T2-BND-17.
 //!
 //! Tests for boundary detection: ORM framework detection, sensitive field detection,
 //! false-positive filters, confidence scoring, field extractors.
@@ -10,7 +10,7 @@ use drift_analysis::boundaries::detector::BoundaryDetector;
 use drift_analysis::boundaries::extractors::create_all_extractors;
 use drift_analysis::boundaries::sensitive::SensitiveFieldDetector;
 use drift_analysis::boundaries::types::{
-    ExtractedField, ExtractedModel, OrmFramework, SensitivityType,
+    ExtractedField, ExtractedModel, OrmFramework, RelationshipKind, SensitivityType,
 };
 use drift_analysis::parsers::manager::ParserManager;
 use drift_analysis::parsers::types::ParseResult;
@@ -279,7 +279,7 @@ fn t2_bnd_05_confidence_scoring() {
     eprintln!("User.ssn confidence: {}, Config.ssn confidence: {}", user_conf, config_conf);
 }
 
-// ---- T2-BND-06: All 10 field extractors produce valid output ----
+// ---- T2-BND-06: All 20 field extractors produce valid output ----
 
 #[test]
 fn t2_bnd_06_all_extractors() {
@@ -287,8 +287,8 @@ fn t2_bnd_06_all_extractors() {
 
     assert_eq!(
         extractors.len(),
-        10,
-        "should have 10 field extractors, got {}",
+        20,
+        "should have 20 field extractors, got {}",
         extractors.len()
     );
 
@@ -304,6 +304,16 @@ fn t2_bnd_06_all_extractors() {
         OrmFramework::EfCore,
         OrmFramework::Hibernate,
         OrmFramework::Eloquent,
+        OrmFramework::Drizzle,
+        OrmFramework::Knex,
+        OrmFramework::Gorm,
+        OrmFramework::Ent,
+        OrmFramework::Diesel,
+        OrmFramework::SeaOrm,
+        OrmFramework::Dapper,
+        OrmFramework::Doctrine,
+        OrmFramework::MyBatis,
+        OrmFramework::Jooq,
     ];
 
     let extractor_frameworks: Vec<OrmFramework> = extractors.iter().map(|e| e.framework()).collect();
@@ -326,3 +336,484 @@ fn t2_bnd_06_all_extractors() {
         );
     }
 }
+
+// ---- Helpers for the per-framework extractor tests ----
+
+fn detect_one(file: &str, source: &str) -> drift_analysis::boundaries::types::BoundaryScanResult {
+    BoundaryDetector::new().detect(&[parse_file(source, file)]).unwrap()
+}
+
+fn model<'a>(
+    result: &'a drift_analysis::boundaries::types::BoundaryScanResult,
+    name: &str,
+) -> &'a ExtractedModel {
+    result.models.iter().find(|m| m.name == name).unwrap_or_else(|| {
+        panic!(
+            "model {name} not extracted; got {:?}",
+            result.models.iter().map(|m| &m.name).collect::<Vec<_>>()
+        )
+    })
+}
+
+fn field<'a>(model: &'a ExtractedModel, name: &str) -> &'a ExtractedField {
+    model.fields.iter().find(|f| f.name == name).unwrap_or_else(|| {
+        panic!(
+            "field {name} missing from {}; got {:?}",
+            model.name,
+            model.fields.iter().map(|f| &f.name).collect::<Vec<_>>()
+        )
+    })
+}
+
+fn has_relationship(model: &ExtractedModel, kind: RelationshipKind, target: &str) -> bool {
+    model.relationships.iter().any(|r| r.kind == kind && r.target_model == target)
+}
+
+fn is_sensitive(
+    result: &drift_analysis::boundaries::types::BoundaryScanResult,
+    model: &str,
+    field: &str,
+) -> bool {
+    result.sensitive_fields.iter().any(|s| s.model_name == model && s.field_name == field)
+}
+
+// ---- T2-BND-07: Drizzle tables ----
+
+#[test]
+fn t2_bnd_07_drizzle_extractor() {
+    let source = r#"import { pgTable, serial, text, varchar, integer } from 'drizzle-orm/pg-core';
+
+export const users = pgTable('users', {
+  id: serial('id').primaryKey(),
+  email: varchar('email', { length: 256 }).notNull().unique(),
+  passwordHash: text('password_hash').notNull(),
+  bio: text('bio'),
+});
+
+export const posts = pgTable('posts', {
+  id: serial('id').primaryKey(),
+  authorId: integer('author_id').references(() => users.id).notNull(),
+});
+"#;
+    let result = detect_one("src/db/schema.ts", source);
+    assert!(result.frameworks_detected.contains(&OrmFramework::Drizzle));
+
+    let users = model(&result, "users");
+    assert_eq!(users.table_name.as_deref(), Some("users"));
+    assert!(field(users, "id").is_primary_key);
+    assert!(field(users, "email").is_unique);
+    assert!(!field(users, "email").is_nullable);
+    assert!(field(users, "bio").is_nullable);
+    assert!(is_sensitive(&result, "users", "password_hash"));
+
+    let posts = model(&result, "posts");
+    assert!(has_relationship(posts, RelationshipKind::BelongsTo, "users"));
+}
+
+// ---- T2-BND-08: Knex migrations ----
+
+#[test]
+fn t2_bnd_08_knex_extractor() {
+    let source = r#"const knex = require('knex');
+
+exports.up = function (knex) {
+  return knex.schema.createTable('users', (table) => {
+    table.increments('id');
+    table.string('email').notNullable().unique();
+    table.string('ssn');
+    table.integer('team_id').references('id').inTable('teams');
+    table.timestamps();
+  });
+};
+"#;
+    let result = detect_one("migrations/001_users.js", source);
+    assert!(result.frameworks_detected.contains(&OrmFramework::Knex));
+
+    let user = model(&result, "User");
+    assert_eq!(user.table_name.as_deref(), Some("users"));
+    assert!(field(user, "id").is_primary_key);
+    assert!(field(user, "email").is_unique);
+    assert!(!field(user, "email").is_nullable);
+    field(user, "created_at");
+    assert!(has_relationship(user, RelationshipKind::BelongsTo, "Team"));
+    assert!(is_sensitive(&result, "User", "ssn"));
+}
+
+// ---- T2-BND-09: GORM structs ----
+
+#[test]
+fn t2_bnd_09_gorm_extractor() {
+    let source = r#"package models
+
+import "gorm.io/gorm"
+
+type User struct {
+	gorm.Model
+	Email    string `gorm:"uniqueIndex;not null"`
+	Password string `gorm:"not null"`
+	Posts    []Post
+}
+
+type Post struct {
+	gorm.Model
+	Title  string
+	UserID uint
+	User   User
+}
+"#;
+    let result = detect_one("models/user.go", source);
+    assert!(result.frameworks_detected.contains(&OrmFramework::Gorm));
+
+    let user = model(&result, "User");
+    assert_eq!(user.table_name.as_deref(), Some("users"));
+    assert!(field(user, "ID").is_primary_key);
+    assert!(field(user, "Email").is_unique);
+    assert!(!field(user, "Email").is_nullable);
+    assert!(has_relationship(user, RelationshipKind::HasMany, "Post"));
+    assert!(is_sensitive(&result, "User", "Password"));
+
+    let post = model(&result, "Post");
+    assert!(has_relationship(post, RelationshipKind::BelongsTo, "User"));
+}
+
+// ---- T2-BND-10: Ent schemas ----
+
+#[test]
+fn t2_bnd_10_ent_extractor() {
+    let source = r#"package schema
+
+import (
+	"entgo.io/ent"
+	"entgo.io/ent/schema/edge"
+	"entgo.io/ent/schema/field"
+)
+
+type User struct {
+	ent.Schema
+}
+
+func (User) Fields() []ent.Field {
+	return []ent.Field{
+		field.String("email").Unique(),
+		field.String("phone_number").Optional(),
+	}
+}
+
+func (User) Edges() []ent.Edge {
+	return []ent.Edge{
+		edge.To("pets", Pet.Type),
+	}
+}
+"#;
+    let result = detect_one("ent/schema/user.go", source);
+    assert!(result.frameworks_detected.contains(&OrmFramework::Ent));
+
+    let user = model(&result, "User");
+    assert!(field(user, "id").is_primary_key);
+    assert!(field(user, "email").is_unique);
+    assert!(field(user, "phone_number").is_nullable);
+    assert!(has_relationship(user, RelationshipKind::HasMany, "Pet"));
+    assert!(is_sensitive(&result, "User", "email"));
+}
+
+// ---- T2-BND-11: Diesel models ----
+
+#[test]
+fn t2_bnd_11_diesel_extractor() {
+    let source = r#"use diesel::prelude::*;
+
+#[derive(Queryable, Selectable, Identifiable, Associations)]
+#[diesel(table_name = posts)]
+#[diesel(belongs_to(User))]
+pub struct Post {
+    pub id: i32,
+    pub user_id: i32,
+    pub title: String,
+    pub api_key: Option<String>,
+}
+"#;
+    let result = detect_one("src/models.rs", source);
+    assert!(result.frameworks_detected.contains(&OrmFramework::Diesel));
+
+    let post = model(&result, "Post");
+    assert_eq!(post.table_name.as_deref(), Some("posts"));
+    assert!(field(post, "id").is_primary_key);
+    assert!(field(post, "api_key").is_nullable);
+    assert!(!field(post, "title").is_nullable);
+    assert!(post.relationships.iter().any(|r| r.kind == RelationshipKind::BelongsTo
+        && r.target_model == "User"
+        && r.foreign_key.as_deref() == Some("user_id")));
+    assert!(is_sensitive(&result, "Post", "api_key"));
+}
+
+// ---- T2-BND-12: SeaORM entities ----
+
+#[test]
+fn t2_bnd_12_sea_orm_extractor() {
+    let source = r#"use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "users")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    #[sea_orm(unique)]
+    pub email: String,
+    pub date_of_birth: Option<Date>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(has_many = "super::post::Entity")]
+    Post,
+}
+"#;
+    let result = detect_one("src/entity/users.rs", source);
+    assert!(result.frameworks_detected.contains(&OrmFramework::SeaOrm));
+
+    let user = model(&result, "User");
+    assert_eq!(user.table_name.as_deref(), Some("users"));
+    assert!(field(user, "id").is_primary_key);
+    assert!(field(user, "email").is_unique);
+    assert!(field(user, "date_of_birth").is_nullable);
+    assert!(has_relationship(user, RelationshipKind::HasMany, "Post"));
+    assert!(is_sensitive(&result, "User", "date_of_birth"));
+}
+
+// ---- T2-BND-13: Dapper classes ----
+
+#[test]
+fn t2_bnd_13_dapper_extractor() {
+    let source = r#"using Dapper;
+using Dapper.Contrib.Extensions;
+
+[Table("Customers")]
+public class Customer
+{
+    [Key]
+    public int Id { get; set; }
+    public string Email { get; set; }
+    public string PasswordHash { get; set; }
+    public DateTime? LastLogin { get; set; }
+    public List<Order> Orders { get; set; }
+}
+
+public class Order
+{
+    public int Id { get; set; }
+    public int CustomerId { get; set; }
+    public Customer Customer { get; set; }
+}
+
+public class Repository
+{
+    public IEnumerable<Order> All(IDbConnection db) => db.Query<Order>("SELECT * FROM Orders");
+}
+"#;
+    let result = detect_one("Data/Customer.cs", source);
+    assert!(result.frameworks_detected.contains(&OrmFramework::Dapper));
+
+    let customer = model(&result, "Customer");
+    assert_eq!(customer.table_name.as_deref(), Some("Customers"));
+    assert!(field(customer, "Id").is_primary_key);
+    assert!(field(customer, "LastLogin").is_nullable);
+    assert!(has_relationship(customer, RelationshipKind::HasMany, "Order"));
+    assert!(is_sensitive(&result, "Customer", "PasswordHash"));
+
+    let order = model(&result, "Order");
+    assert!(has_relationship(order, RelationshipKind::BelongsTo, "Customer"));
+}
+
+// ---- T2-BND-14: Doctrine entities, attributes and docblock annotations ----
+
+#[test]
+fn t2_bnd_14_doctrine_extractor() {
+    let source = r#"<?php
+namespace App\Entity;
+
+use Doctrine\ORM\Mapping as ORM;
+
+#[ORM\Entity]
+#[ORM\Table(name: 'users')]
+class User
+{
+    #[ORM\Id]
+    #[ORM\Column(type: 'integer')]
+    private int $id;
+
+    #[ORM\Column(type: 'string', unique: true)]
+    private string $email;
+
+    #[ORM\Column(type: 'string', nullable: true)]
+    private ?string $phone;
+
+    #[ORM\ManyToOne(targetEntity: Team::class)]
+    #[ORM\JoinColumn(name: 'team_id')]
+    private Team $team;
+
+    private array $cache;
+}
+
+/**
+ * @ORM\Entity
+ * @ORM\Table(name="teams")
+ */
+class Team
+{
+    /**
+     * @ORM\Id
+     * @ORM\Column(type="integer")
+     */
+    private $id;
+
+    /**
+     * @ORM\OneToMany(targetEntity="App\Entity\User", mappedBy="team")
+     */
+    private $members;
+}
+"#;
+    let result = detect_one("src/Entity/User.php", source);
+    assert!(result.frameworks_detected.contains(&OrmFramework::Doctrine));
+
+    let user = model(&result, "User");
+    assert_eq!(user.table_name.as_deref(), Some("users"));
+    assert!(field(user, "id").is_primary_key);
+    assert!(field(user, "email").is_unique);
+    assert!(field(user, "phone").is_nullable);
+    assert!(!user.fields.iter().any(|f| f.name == "cache"), "unmapped property is not a column");
+    assert!(user.relationships.iter().any(|r| r.kind == RelationshipKind::BelongsTo
+        && r.target_model == "Team"
+        && r.foreign_key.as_deref() == Some("team_id")));
+    assert!(is_sensitive(&result, "User", "email"));
+
+    let team = model(&result, "Team");
+    assert_eq!(team.table_name.as_deref(), Some("teams"));
+    assert!(field(team, "id").is_primary_key);
+    assert!(has_relationship(team, RelationshipKind::HasMany, "User"));
+}
+
+// ---- T2-BND-15: MyBatis-Plus entities and annotated mappers ----
+
+#[test]
+fn t2_bnd_15_mybatis_extractor() {
+    let entity = r#"import com.baomidou.mybatisplus.annotation.TableName;
+import com.baomidou.mybatisplus.annotation.TableId;
+import com.baomidou.mybatisplus.annotation.TableField;
+
+@TableName("sys_user")
+public class SysUser {
+    private static final long serialVersionUID = 1L;
+    @TableId
+    private Long userId;
+    private String password;
+    @TableField(exist = false)
+    private String roleNames;
+}
+"#;
+    let result = detect_one("src/main/java/SysUser.java", entity);
+    assert!(result.frameworks_detected.contains(&OrmFramework::MyBatis));
+    let user = model(&result, "SysUser");
+    assert_eq!(user.table_name.as_deref(), Some("sys_user"));
+    assert!(field(user, "userId").is_primary_key);
+    assert!(!user.fields.iter().any(|f| f.name == "roleNames" || f.name == "serialVersionUID"));
+    assert!(is_sensitive(&result, "SysUser", "password"));
+
+    let mapper = r#"import org.apache.ibatis.annotations.*;
+
+@Mapper
+public interface AccountMapper {
+    @Select("SELECT * FROM accounts WHERE id = #{id}")
+    @Results({
+        @Result(property = "id", column = "id", id = true),
+        @Result(property = "email", column = "email"),
+        @Result(property = "owner", column = "owner_id", javaType = Owner.class, one = @One(select = "findOwner"))
+    })
+    Account findById(long id);
+}
+"#;
+    let result = detect_one("src/main/java/AccountMapper.java", mapper);
+    let account = model(&result, "Account");
+    assert_eq!(account.table_name.as_deref(), Some("accounts"));
+    assert!(field(account, "id").is_primary_key);
+    field(account, "email");
+    assert!(has_relationship(account, RelationshipKind::HasOne, "Owner"));
+}
+
+// ---- T2-BND-16: jOOQ generated tables ----
+
+#[test]
+fn t2_bnd_16_jooq_extractor() {
+    let source = r#"import org.jooq.TableField;
+import org.jooq.impl.TableImpl;
+
+public class Posts extends TableImpl<PostsRecord> {
+    public final TableField<PostsRecord, Long> ID = createField(DSL.name("id"), SQLDataType.BIGINT.nullable(false).identity(true), this, "");
+    public final TableField<PostsRecord, String> BODY = createField(DSL.name("body"), SQLDataType.CLOB, this, "");
+    public final TableField<PostsRecord, String> TITLE = createField(DSL.name("title"), SQLDataType.VARCHAR(100).nullable(true), this, "");
+    public final TableField<PostsRecord, String> AUTHOR_EMAIL = createField(DSL.name("author_email"), SQLDataType.VARCHAR(255).nullable(false), this, "");
+
+    public Users users() {
+        return new Users(this, Keys.POSTS__POSTS_AUTHOR_ID_FKEY);
+    }
+}
+"#;
+    let result = detect_one("src/generated/tables/Posts.java", source);
+    assert!(result.frameworks_detected.contains(&OrmFramework::Jooq));
+
+    let posts = model(&result, "Posts");
+    assert!(field(posts, "id").is_primary_key);
+    assert!(field(posts, "body").is_nullable);
+    assert!(field(posts, "title").is_nullable);
+    assert!(!field(posts, "title").is_primary_key);
+    assert!(!field(posts, "author_email").is_nullable);
+    assert!(has_relationship(posts, RelationshipKind::BelongsTo, "Users"));
+    assert!(is_sensitive(&result, "Posts", "author_email"));
+}
+
+// ---- T2-BND-17: Raw SQL linked to sensitive fields ----
+
+#[test]
+fn t2_bnd_17_sql_sensitive_access() {
+    use drift_analysis::engine::embedded_sql::parse_sql;
+
+    let schema = r#"import { pgTable, serial, text, varchar } from 'drizzle-orm/pg-core';
+
+export const users = pgTable('users', {
+  id: serial('id').primaryKey(),
+  email: varchar('email', { length: 256 }).notNull(),
+  passwordHash: text('password_hash').notNull(),
+  bio: text('bio'),
+});
+"#;
+    let result = detect_one("src/db/schema.ts", schema);
+    let statements: Vec<_> = [
+        "SELECT u.email, u.bio, c.card_number FROM users u JOIN cards c ON c.user_id = u.id",
+        "UPDATE users SET password_hash = ? WHERE id = ?",
+        "SELECT title FROM posts",
+    ]
+    .iter()
+    .map(|sql| parse_sql(sql).unwrap())
+    .collect();
+
+    let accesses = BoundaryDetector::new().link_sql_statements(&statements, &result);
+    let find = |column: &str| accesses.iter().find(|a| a.column == column);
+
+    let email = find("email").expect("email is linked to the users model");
+    assert_eq!(email.table, "users");
+    assert_eq!(email.model_name.as_deref(), Some("users"));
+    assert_eq!(email.operation, "read");
+    assert_eq!(email.sensitivity, SensitivityType::Pii);
+
+    let password = find("password_hash").expect("password_hash write is linked");
+    assert_eq!(password.operation, "write");
+    assert_eq!(password.sensitivity, SensitivityType::Credentials);
+
+    let card = find("card_number").expect("unmapped table is classified by column name");
+    assert_eq!(card.table, "cards");
+    assert!(card.model_name.is_none());
+    assert_eq!(card.sensitivity, SensitivityType::Financial);
+
+    assert!(find("bio").is_none());
+    assert!(find("user_id").is_none());
+    assert!(find("title").is_none());
+}
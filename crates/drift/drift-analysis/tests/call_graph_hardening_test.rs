@@ -46,6 +46,7 @@ fn make_node(file: &str, name: &str, exported: bool) -> FunctionNode {
         is_exported: exported,
         signature_hash: 0,
         body_hash: 0,
+        complexity: Default::default(),
     }
 }
 
@@ -78,6 +79,7 @@ fn make_function(name: &str, line: u32, end_line: u32) -> FunctionInfo {
         doc_comment: None,
         body_hash: 0,
         signature_hash: 0,
+        complexity: Default::default(),
     }
 }
 
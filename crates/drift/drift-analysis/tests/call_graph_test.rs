@@ -1,8 +1,9 @@
 #![allow(clippy::field_reassign_with_default, clippy::redundant_closure, clippy::useless_vec, unused_variables, unused_imports)]
-//! Call Graph tests — T2-CG-01 through T2-CG-12.
+//! Call Graph tests — T2-CG-01 through T2-CG-18.
 //!
-//! Tests for the call graph builder: 6 resolution strategies, BFS traversal,
-//! entry point detection, cycle handling, incremental updates, CTE fallback.
+//! Tests for the call graph builder: 8 resolution strategies, BFS traversal,
+//! entry point detection, cycle handling, incremental updates, CTE fallback,
+//! manifest-aware module resolution, receiver type inference.
 
 use std::path::Path;
 use std::time::Instant;
@@ -11,6 +12,7 @@ use drift_analysis::call_graph::builder::CallGraphBuilder;
 use drift_analysis::call_graph::cte_fallback;
 use drift_analysis::call_graph::di_support::{detect_di_frameworks, DI_FRAMEWORKS};
 use drift_analysis::call_graph::incremental::IncrementalCallGraph;
+use drift_analysis::call_graph::module_resolver::{ModuleResolver, ModuleTarget};
 use drift_analysis::call_graph::traversal::{bfs_forward, bfs_inverse, detect_entry_points};
 use drift_analysis::call_graph::types::{
     CallEdge, CallGraph, CallGraphStats, FunctionNode, Resolution,
@@ -341,9 +343,9 @@ function c1() { return 3; }
 
 #[test]
 fn t2_cg_08_resolution_fallback_chain() {
-    // Verify the fallback order: SameFile > MethodCall > DiInjection > ImportBased > ExportBased > Fuzzy
+    // Verify the fallback order: SameFile > TypedReceiver > MethodCall > ManifestImport > DiInjection > ImportBased > ExportBased > Fuzzy
     let strategies = Resolution::all_ordered();
-    assert_eq!(strategies.len(), 6, "should have 6 resolution strategies");
+    assert_eq!(strategies.len(), 8, "should have 8 resolution strategies");
 
     // Verify decreasing confidence order
     let confidences: Vec<f32> = strategies.iter().map(|s| s.default_confidence()).collect();
@@ -360,7 +362,9 @@ fn t2_cg_08_resolution_fallback_chain() {
 
     // Verify specific confidences
     assert_eq!(Resolution::SameFile.default_confidence(), 0.95);
+    assert_eq!(Resolution::TypedReceiver.default_confidence(), 0.92);
     assert_eq!(Resolution::MethodCall.default_confidence(), 0.90);
+    assert_eq!(Resolution::ManifestImport.default_confidence(), 0.85);
     assert_eq!(Resolution::DiInjection.default_confidence(), 0.80);
     assert_eq!(Resolution::ImportBased.default_confidence(), 0.75);
     assert_eq!(Resolution::ExportBased.default_confidence(), 0.60);
@@ -503,3 +507,384 @@ fn t2_cg_12_empty_codebase() {
     assert_eq!(stats.total_edges, 0);
     assert_eq!(stats.entry_points, 0);
 }
+
+// ---- T2-CG-13: Module resolver — TypeScript paths, workspace packages ----
+
+#[test]
+fn t2_cg_13_module_resolver_typescript() {
+    let resolver = ModuleResolver::from_files([
+        (
+            "tsconfig.base.json",
+            r#"{
+                // shared settings
+                "compilerOptions": {
+                    "baseUrl": ".",
+                    "paths": { "@app/*": ["apps/web/src/*"], "@config": ["config/index.ts"], },
+                },
+            }"#,
+        ),
+        ("apps/web/tsconfig.json", r#"{ "extends": "../../tsconfig.base.json" }"#),
+        ("apps/web/package.json", r#"{ "name": "web", "dependencies": { "react": "18" } }"#),
+        ("apps/web/src/main.ts", ""),
+        ("apps/web/src/utils/format.ts", ""),
+        ("apps/web/src/components/index.tsx", ""),
+        ("apps/web/src/esm.ts", ""),
+        ("config/index.ts", ""),
+        (
+            "packages/shared/package.json",
+            r#"{ "name": "@acme/shared", "exports": { ".": { "import": "./dist/index.js" }, "./*": "./dist/*.js" } }"#,
+        ),
+        ("packages/shared/src/index.ts", ""),
+        ("packages/shared/src/dates.ts", ""),
+    ]);
+    let resolve = |source: &str| resolver.resolve("apps/web/src/main.ts", source).map(|r| r.target);
+    let file = |path: &str| Some(ModuleTarget::File(path.to_string()));
+
+    assert_eq!(resolve("@app/utils/format"), file("apps/web/src/utils/format.ts"));
+    assert_eq!(resolve("@config"), file("config/index.ts"));
+    assert_eq!(resolve("./components"), file("apps/web/src/components/index.tsx"));
+    assert_eq!(resolve("./esm.js"), file("apps/web/src/esm.ts"), ".js specifiers map to .ts sources");
+    // Exports name build output; the source file is resolved instead.
+    assert_eq!(resolve("@acme/shared"), file("packages/shared/src/index.ts"));
+    assert_eq!(resolve("@acme/shared/dates"), file("packages/shared/src/dates.ts"));
+    assert_eq!(resolve("react"), Some(ModuleTarget::Package("react".to_string())));
+    assert_eq!(resolve("node:fs"), Some(ModuleTarget::Package("node:fs".to_string())));
+    assert_eq!(resolve("left-pad"), None, "undeclared packages stay unresolved");
+
+    assert_eq!(resolver.owning_manifest("apps/web/src/main.ts"), Some("apps/web/tsconfig.json"));
+    assert_eq!(
+        resolver.resolve("apps/web/src/main.ts", "@app/utils/format").unwrap().manifest.as_deref(),
+        Some("apps/web/tsconfig.json")
+    );
+}
+
+// ---- T2-CG-14: Module resolver — Python src layout, go.mod, Cargo crates ----
+
+#[test]
+fn t2_cg_14_module_resolver_python_go_rust() {
+    let resolver = ModuleResolver::from_files([
+        (
+            "py/pyproject.toml",
+            "[project]\nname = \"acme\"\ndependencies = [\"requests>=2.0\", \"PyYAML\"]\n\n[tool.setuptools.packages.find]\nwhere = [\"src\"]\n",
+        ),
+        ("py/src/acme/__init__.py", ""),
+        ("py/src/acme/core/__init__.py", ""),
+        ("py/src/acme/core/util.py", ""),
+        ("py/src/acme/core/sib.py", ""),
+        ("svc/go.mod", "module github.com/acme/svc\n\ngo 1.22\n\nrequire (\n\tgithub.com/google/uuid v1.6.0\n)\n"),
+        ("svc/main.go", ""),
+        ("svc/internal/util/util.go", ""),
+        ("rs/Cargo.toml", "[package]\nname = \"my-lib\"\n\n[dependencies]\nserde = \"1\"\n"),
+        ("rs/src/lib.rs", ""),
+        ("rs/src/net/mod.rs", ""),
+        ("rs/src/net/client.rs", ""),
+        ("rs/src/utils.rs", ""),
+    ]);
+    let file = |path: &str| Some(ModuleTarget::File(path.to_string()));
+    let package = |name: &str| Some(ModuleTarget::Package(name.to_string()));
+
+    let py = |source: &str| resolver.resolve("py/src/acme/core/util.py", source).map(|r| r.target);
+    assert_eq!(py("acme.core.util"), file("py/src/acme/core/util.py"));
+    assert_eq!(py("import acme.core"), file("py/src/acme/core/__init__.py"));
+    assert_eq!(py("acme.core.util.helper"), file("py/src/acme/core/util.py"), "trailing attribute is dropped");
+    assert_eq!(py(".sib"), file("py/src/acme/core/sib.py"));
+    assert_eq!(py("..core.sib"), file("py/src/acme/core/sib.py"));
+    assert_eq!(py("requests.adapters"), package("requests"));
+    assert_eq!(py("yaml"), None, "import names differing from the distribution name are not guessed");
+
+    let go = |source: &str| resolver.resolve("svc/main.go", source).map(|r| r.target);
+    assert_eq!(go("github.com/acme/svc/internal/util"), Some(ModuleTarget::Directory("svc/internal/util".to_string())));
+    assert_eq!(go("github.com/google/uuid"), package("github.com/google/uuid"));
+    assert_eq!(go("net/http"), package("net/http"));
+
+    let rs = |importer: &str, source: &str| resolver.resolve(importer, source).map(|r| r.target);
+    assert_eq!(rs("rs/src/lib.rs", "crate::utils::format"), file("rs/src/utils.rs"));
+    assert_eq!(rs("rs/src/lib.rs", "my_lib::net::client::Client"), file("rs/src/net/client.rs"));
+    assert_eq!(rs("rs/src/net/client.rs", "use super::super::utils as u;"), file("rs/src/utils.rs"));
+    assert_eq!(rs("rs/src/net/mod.rs", "self::client::connect"), file("rs/src/net/client.rs"));
+    assert_eq!(rs("rs/src/lib.rs", "serde::Serialize"), package("serde"));
+    assert_eq!(rs("rs/src/lib.rs", "std::collections::HashMap"), package("std"));
+
+    assert_eq!(resolver.owning_manifest("svc/internal/util/util.go"), Some("svc/go.mod"));
+    assert_eq!(resolver.manifests().len(), 3);
+}
+
+// ---- T2-CG-15: ManifestImport edges and per-manifest diagnostics ----
+
+#[test]
+fn t2_cg_15_manifest_import_resolution() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    let write = |path: &str, content: &str| {
+        let full = root.join(path);
+        std::fs::create_dir_all(full.parent().unwrap()).unwrap();
+        std::fs::write(&full, content).unwrap();
+        full
+    };
+    write("tsconfig.json", r#"{ "compilerOptions": { "baseUrl": ".", "paths": { "@app/*": ["src/*"] } } }"#);
+    write("node_modules/lib/tsconfig.json", "{}");
+    // Two exported functions with the same name; only the manifest knows which one is imported.
+    let legacy = write("legacy/format/index.ts", "export function formatDate(d: Date) { return 'legacy'; }\n");
+    let current = write("src/format/index.ts", "export function formatDate(d: Date) { return 'iso'; }\n");
+    let main = write(
+        "src/main.ts",
+        "// entry point\nimport { formatDate } from '@app/format';\n\nexport function render() {\n    return formatDate(new Date());\n}\n",
+    );
+
+    let parse = |path: &Path| parse_file(&std::fs::read_to_string(path).unwrap(), path.to_str().unwrap());
+    let results = vec![parse(&legacy), parse(&current), parse(&main)];
+
+    let resolver = ModuleResolver::from_root(root);
+    assert_eq!(resolver.manifests().len(), 1, "node_modules is not searched");
+    let (graph, stats) = CallGraphBuilder::new()
+        .with_module_resolver(resolver)
+        .build(&results)
+        .unwrap();
+
+    let caller = graph.get_node(&format!("{}::render", main.display())).unwrap();
+    let callee = graph.get_node(&format!("{}::formatDate", current.display())).unwrap();
+    let edge = graph.graph.edges_connecting(caller, callee).next().expect("edge to src/format");
+    assert_eq!(edge.weight().resolution, Resolution::ManifestImport);
+    assert_eq!(edge.weight().confidence, 0.85);
+    assert_eq!(stats.resolution_counts.get("manifest_import"), Some(&1));
+    assert_eq!(stats.diagnostics.by_manifest.get("tsconfig.json"), Some(&(1, 1)));
+    assert_eq!(stats.diagnostics.manifest_resolution_rate("tsconfig.json"), Some(1.0));
+
+    // Without manifests the same call is left to the heuristic strategies.
+    let (_, stats) = CallGraphBuilder::new().build(&results).unwrap();
+    assert_eq!(stats.resolution_counts.get("manifest_import"), None);
+    assert!(stats.diagnostics.by_manifest.is_empty());
+}
+
+// ---- T2-CG-16: Receiver type inference — TS, Java, Kotlin, C# ----
+
+/// Resolution of the edge between two keys, if any.
+fn edge_between(graph: &CallGraph, caller: &str, callee: &str) -> Option<Resolution> {
+    let caller = graph.get_node(caller)?;
+    let callee = graph.get_node(callee)?;
+    graph.graph.edges_connecting(caller, callee).next().map(|e| e.weight().resolution)
+}
+
+#[test]
+fn t2_cg_16_typed_receiver_class_languages() {
+    let cases = [
+        (
+            ("repos.ts", "export class UserRepo { save(u: string) { return u; } }\nexport class OrderRepo { save(o: string) { return o; } }\n"),
+            (
+                "service.ts",
+                r#"import { UserRepo, OrderRepo } from './repos';
+export class Service {
+    constructor(private users: UserRepo, private orders: OrderRepo) {}
+    place() {
+        this.orders.save('o');
+    }
+}
+export function register() {
+    const repo = new UserRepo();
+    repo.save('u');
+}
+"#,
+            ),
+            ("place", "OrderRepo.save"),
+            ("register", "UserRepo.save"),
+        ),
+        (
+            ("Repos.java", "class UserRepo { void save(String u) {} }\nclass OrderRepo { void save(String o) {} }\n"),
+            (
+                "Service.java",
+                r#"class Service {
+    private final OrderRepo orders;
+    void place() {
+        orders.save("o");
+    }
+    void register() {
+        UserRepo repo = new UserRepo();
+        repo.save("u");
+    }
+}
+"#,
+            ),
+            ("place", "OrderRepo.save"),
+            ("register", "UserRepo.save"),
+        ),
+        (
+            ("Repos.kt", "class UserRepo {\n    fun save(u: String) {}\n}\nclass OrderRepo {\n    fun save(o: String) {}\n}\n"),
+            (
+                "Service.kt",
+                r#"class Service(private val orders: OrderRepo) {
+    fun place() {
+        orders.save("o")
+    }
+    fun register() {
+        val repo = UserRepo()
+        repo.save("u")
+    }
+}
+"#,
+            ),
+            ("place", "OrderRepo.save"),
+            ("register", "UserRepo.save"),
+        ),
+        (
+            ("Repos.cs", "class UserRepo { public void Save(string u) {} }\nclass OrderRepo { public void Save(string o) {} }\n"),
+            (
+                "Service.cs",
+                r#"class Service {
+    private readonly OrderRepo _orders;
+    public void Place() {
+        _orders.Save("o");
+    }
+    public void Register() {
+        var repo = new UserRepo();
+        repo.Save("u");
+    }
+}
+"#,
+            ),
+            ("Place", "OrderRepo.Save"),
+            ("Register", "UserRepo.Save"),
+        ),
+    ];
+
+    for ((repo_file, repo_src), (svc_file, svc_src), field_call, local_call) in cases {
+        let results = vec![parse_file(repo_src, repo_file), parse_file(svc_src, svc_file)];
+        let (graph, stats) = CallGraphBuilder::new().build(&results).unwrap();
+        for (caller, callee) in [field_call, local_call] {
+            let caller = format!("{svc_file}::{caller}");
+            let callee = format!("{repo_file}::{callee}");
+            assert_eq!(
+                edge_between(&graph, &caller, &callee),
+                Some(Resolution::TypedReceiver),
+                "{caller} -> {callee}"
+            );
+        }
+        // The same-named method on the other class is never the target.
+        let (caller, _) = field_call;
+        let wrong = local_call.1;
+        assert_eq!(edge_between(&graph, &format!("{svc_file}::{caller}"), &format!("{repo_file}::{wrong}")), None);
+        assert_eq!(stats.resolution_counts.get("typed_receiver"), Some(&2), "{svc_file}");
+    }
+}
+
+// ---- T2-CG-17: Receiver type inference — Python annotations, DI, Go ----
+
+#[test]
+fn t2_cg_17_typed_receiver_python_go() {
+    let repos = parse_file(
+        r#"class UserRepo:
+    def save(self, u):
+        return u
+
+class OrderRepo:
+    def save(self, o):
+        return o
+
+def get_user_repo() -> UserRepo:
+    return UserRepo()
+"#,
+        "repos.py",
+    );
+    let service = parse_file(
+        r#"from fastapi import Depends
+from repos import UserRepo, OrderRepo, get_user_repo
+
+class Service:
+    def __init__(self, orders: OrderRepo):
+        self.orders = orders
+
+    def place(self):
+        self.orders.save("o")
+
+def handler(repo=Depends(get_user_repo)):
+    repo.save("u")
+
+def archive(repo: "Optional[OrderRepo]"):
+    repo.save("o")
+"#,
+        "service.py",
+    );
+    let (graph, _) = CallGraphBuilder::new().build(&[repos, service]).unwrap();
+    for (caller, callee) in [
+        ("service.py::place", "repos.py::OrderRepo.save"),
+        ("service.py::handler", "repos.py::UserRepo.save"),
+        ("service.py::archive", "repos.py::OrderRepo.save"),
+    ] {
+        assert_eq!(edge_between(&graph, caller, callee), Some(Resolution::TypedReceiver), "{caller} -> {callee}");
+    }
+    assert_eq!(edge_between(&graph, "service.py::handler", "repos.py::OrderRepo.save"), None);
+
+    // Go methods are keyed by file, so each repo lives in its own file.
+    let user_repo = parse_file(
+        "package repos\n\ntype UserRepo struct{}\n\nfunc NewUserRepo() *UserRepo { return &UserRepo{} }\n\nfunc (r *UserRepo) Save(u string) {}\n",
+        "user_repo.go",
+    );
+    let order_repo = parse_file(
+        "package repos\n\ntype OrderRepo struct{}\n\nfunc (r *OrderRepo) Save(o string) {}\n",
+        "order_repo.go",
+    );
+    let service = parse_file(
+        r#"package repos
+
+type Service struct {
+    orders *OrderRepo
+}
+
+func (s *Service) Place() {
+    s.orders.Save("o")
+}
+
+func Register() {
+    u := NewUserRepo()
+    u.Save("u")
+    var o OrderRepo
+    o.Save("o")
+}
+"#,
+        "service.go",
+    );
+    let (graph, _) = CallGraphBuilder::new().build(&[user_repo, order_repo, service]).unwrap();
+    for (caller, callee) in [
+        ("service.go::Place", "order_repo.go::Save"),
+        ("service.go::Register", "user_repo.go::Save"),
+        ("service.go::Register", "order_repo.go::Save"),
+    ] {
+        assert_eq!(edge_between(&graph, caller, callee), Some(Resolution::TypedReceiver), "{caller} -> {callee}");
+    }
+}
+
+// ---- T2-CG-18: Receiver type inference — ambiguous classes and return types ----
+
+#[test]
+fn t2_cg_18_typed_receiver_ambiguity() {
+    // Two files declare `Repo`: only the caller's own file disambiguates.
+    let a = parse_file("export class Repo { save(x: string) { return x; } }\nexport function local() {\n    const r = new Repo();\n    r.save('a');\n}\n", "a.ts");
+    let b = parse_file("export class Repo { save(x: string) { return x; } }\n", "b.ts");
+    let c = parse_file("export function remote() {\n    const r = new Repo();\n    r.save('c');\n}\n", "c.ts");
+    let (graph, _) = CallGraphBuilder::new().build(&[a, b, c]).unwrap();
+    assert_eq!(edge_between(&graph, "a.ts::local", "a.ts::Repo.save"), Some(Resolution::TypedReceiver));
+    for callee in ["a.ts::Repo.save", "b.ts::Repo.save"] {
+        assert_ne!(edge_between(&graph, "c.ts::remote", callee), Some(Resolution::TypedReceiver), "{callee}");
+    }
+
+    // Same-named factories in different files return different types.
+    let repos = parse_file(
+        "class UserRepo:\n    def save(self, u):\n        return u\n\nclass OrderRepo:\n    def save(self, o):\n        return o\n",
+        "repos.py",
+    );
+    let users = parse_file(
+        "from repos import UserRepo\n\ndef make() -> UserRepo:\n    return UserRepo()\n\ndef add():\n    r = make()\n    r.save(1)\n",
+        "users.py",
+    );
+    let orders = parse_file(
+        "from repos import OrderRepo\n\ndef make() -> OrderRepo:\n    return OrderRepo()\n\ndef add():\n    r = make()\n    r.save(1)\n",
+        "orders.py",
+    );
+    let other = parse_file("def sync():\n    r = make()\n    r.save(1)\n", "other.py");
+    let (graph, _) = CallGraphBuilder::new().build(&[repos, users, orders, other]).unwrap();
+    assert_eq!(edge_between(&graph, "users.py::add", "repos.py::UserRepo.save"), Some(Resolution::TypedReceiver));
+    assert_eq!(edge_between(&graph, "orders.py::add", "repos.py::OrderRepo.save"), Some(Resolution::TypedReceiver));
+    assert_eq!(edge_between(&graph, "users.py::add", "repos.py::OrderRepo.save"), None);
+    for callee in ["repos.py::UserRepo.save", "repos.py::OrderRepo.save"] {
+        assert_ne!(edge_between(&graph, "other.py::sync", callee), Some(Resolution::TypedReceiver), "{callee}");
+    }
+}
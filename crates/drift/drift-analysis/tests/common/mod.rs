@@ -0,0 +1,70 @@
+//! Git repository fixtures shared by the history-based tests.
+#![allow(dead_code)]
+
+use std::path::Path;
+
+/// Write `content` to `path` under `root`, creating parent directories.
+pub fn write_file(root: &Path, path: &str, content: &str) {
+    let path = root.join(path);
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(path, content).unwrap();
+}
+
+/// Append a line to `path` under `root`, creating the file if needed.
+pub fn append_line(root: &Path, path: &str, line: &str) {
+    let existing = std::fs::read_to_string(root.join(path)).unwrap_or_default();
+    write_file(root, path, &format!("{existing}{line}\n"));
+}
+
+/// Commit the whole working tree on top of HEAD as `author`.
+pub fn commit(repo: &git2::Repository, author: &str, message: &str) -> String {
+    commit_signed(repo, &signature(author, None), message, &head_parent(repo), Some("HEAD"))
+}
+
+/// Like `commit`, with author and committer time set to `time`.
+pub fn commit_at(repo: &git2::Repository, author: &str, time: i64, message: &str) -> String {
+    commit_signed(repo, &signature(author, Some(time)), message, &head_parent(repo), Some("HEAD"))
+}
+
+/// Commit the working tree with the given parents, moving `update_ref` to it.
+pub fn commit_on(repo: &git2::Repository, author: &str, message: &str, parents: &[&str], update_ref: Option<&str>) -> String {
+    let parents: Vec<String> = parents.iter().map(|p| p.to_string()).collect();
+    commit_signed(repo, &signature(author, None), message, &parents, update_ref)
+}
+
+fn head_parent(repo: &git2::Repository) -> Vec<String> {
+    repo.head()
+        .ok()
+        .and_then(|h| h.peel_to_commit().ok())
+        .map(|c| c.id().to_string())
+        .into_iter()
+        .collect()
+}
+
+fn signature(author: &str, time: Option<i64>) -> git2::Signature<'static> {
+    let email = format!("{}@example.com", author.to_lowercase());
+    match time {
+        Some(time) => git2::Signature::new(author, &email, &git2::Time::new(time, 0)).unwrap(),
+        None => git2::Signature::now(author, &email).unwrap(),
+    }
+}
+
+fn commit_signed(
+    repo: &git2::Repository,
+    signature: &git2::Signature,
+    message: &str,
+    parents: &[String],
+    update_ref: Option<&str>,
+) -> String {
+    let mut index = repo.index().unwrap();
+    index.add_all(["*"], git2::IndexAddOption::DEFAULT, None).unwrap();
+    index.update_all(["*"], None).unwrap();
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let parents: Vec<git2::Commit> = parents
+        .iter()
+        .map(|sha| repo.find_commit(git2::Oid::from_str(sha).unwrap()).unwrap())
+        .collect();
+    let parents: Vec<&git2::Commit> = parents.iter().collect();
+    repo.commit(update_ref, signature, signature, message, &tree, &parents).unwrap().to_string()
+}
@@ -1,4 +1,4 @@
-//! Phase 5 constraint system tests (T5-CON-01 through T5-CON-06).
+//! Phase 5 constraint system tests (T5-CON-01 through T5-CON-09).
 
 use drift_analysis::structural::constraints::types::*;
 use drift_analysis::structural::constraints::detector::{InvariantDetector, FunctionInfo};
@@ -6,6 +6,43 @@ use drift_analysis::structural::constraints::verifier::ConstraintVerifier;
 use drift_analysis::structural::constraints::synthesizer::ConstraintSynthesizer;
 use drift_analysis::structural::constraints::freezing::FreezingArchRule;
 use drift_analysis::structural::constraints::store::ConstraintStore;
+use drift_analysis::structural::constraints::data_flow::{DataFlowContext, DataFlowSpec, FlowOrigin};
+use drift_analysis::boundaries::types::{SensitiveField, SensitivityType};
+use drift_analysis::call_graph::CallGraphBuilder;
+use drift_analysis::parsers::manager::ParserManager;
+use drift_analysis::parsers::types::ParseResult;
+
+fn parse_files(files: &[(&str, &str)]) -> Vec<ParseResult> {
+    let parser = ParserManager::new();
+    files
+        .iter()
+        .map(|(path, source)| parser.parse(source.as_bytes(), std::path::Path::new(path)).unwrap())
+        .collect()
+}
+
+fn data_flow_detector(files: &[(&str, &str)], sensitive: Vec<SensitiveField>) -> InvariantDetector {
+    let prs = parse_files(files);
+    let (graph, _) = CallGraphBuilder::new().build(&prs).unwrap();
+    let mut detector = InvariantDetector::new();
+    detector.set_data_flow(
+        DataFlowContext::new(graph)
+            .with_parse_results(&prs)
+            .with_sensitive_fields(sensitive),
+    );
+    detector
+}
+
+fn data_flow_constraint(target: &str) -> Constraint {
+    Constraint {
+        id: "data-flow".into(),
+        description: target.into(),
+        invariant_type: InvariantType::DataFlow,
+        target: target.into(),
+        scope: None,
+        source: ConstraintSource::Manual,
+        enabled: true,
+    }
+}
 
 /// T5-CON-01: At least 6 of 12 invariant types verified.
 #[test]
@@ -93,11 +130,11 @@ fn test_constraint_synthesis() {
 
     // Register files with camelCase function names
     let camel_functions = vec![
-        FunctionInfo { name: "getUserById".into(), line: 1, is_exported: true },
-        FunctionInfo { name: "createOrder".into(), line: 5, is_exported: true },
-        FunctionInfo { name: "validateInput".into(), line: 10, is_exported: true },
-        FunctionInfo { name: "processPayment".into(), line: 15, is_exported: true },
-        FunctionInfo { name: "sendNotification".into(), line: 20, is_exported: true },
+        FunctionInfo { name: "getUserById".into(), line: 1, is_exported: true, complexity: Default::default() },
+        FunctionInfo { name: "createOrder".into(), line: 5, is_exported: true, complexity: Default::default() },
+        FunctionInfo { name: "validateInput".into(), line: 10, is_exported: true, complexity: Default::default() },
+        FunctionInfo { name: "processPayment".into(), line: 15, is_exported: true, complexity: Default::default() },
+        FunctionInfo { name: "sendNotification".into(), line: 20, is_exported: true, complexity: Default::default() },
     ];
 
     for i in 0..20 {
@@ -171,3 +208,154 @@ fn test_empty_codebase_constraints() {
     // must_not_exist should pass on empty codebase
     assert!(result_not_exist.passed);
 }
+
+/// T5-CON-07: DataFlow — routes must pass through validateInput before db.*,
+/// with the offending call path as evidence.
+#[test]
+fn test_data_flow_requires_sanitizer() {
+    let detector = data_flow_detector(&[
+        ("src/routes/users.ts", r#"import { loadUser, saveUser } from '../services/users';
+export function getUser(req) {
+  return loadUser(req.params.id);
+}
+export function createUser(req) {
+  const input = validateInput(req.body);
+  return saveUser(input);
+}
+"#),
+        ("src/services/users.ts", r#"
+export function loadUser(id) {
+  return db.query(id);
+}
+export function saveUser(input) {
+  return db.insert(input);
+}
+"#),
+    ], Vec::new());
+
+    let result = detector.verify(&data_flow_constraint("src/routes/** -> validateInput -> db.*"));
+    assert!(!result.passed);
+    assert_eq!(result.violations.len(), 1, "{:?}", result.violations);
+
+    // getUser reaches db.query through loadUser without validation;
+    // createUser validates before calling saveUser
+    let violation = &result.violations[0];
+    assert!(violation.message.contains("'getUser'"));
+    assert_eq!(violation.path[0], "src/routes/users.ts::getUser");
+    assert_eq!(violation.path[1], "src/services/users.ts::loadUser");
+    assert!(violation.path[2].starts_with("db.query"));
+    assert_eq!(violation.actual, violation.path.join(" -> "));
+
+    // Scoped to a file no route lives in: nothing to check
+    let mut scoped = data_flow_constraint("src/routes/** -> validateInput -> db.*");
+    scoped.scope = Some("src/admin".into());
+    assert!(detector.verify(&scoped).passed);
+}
+
+/// T5-CON-08: DataFlow — PII fields from User must never reach logger.*.
+#[test]
+fn test_data_flow_pii_never_reaches_logger() {
+    let sensitive = ["email", "ssn"]
+        .iter()
+        .map(|field| SensitiveField {
+            model_name: "User".into(),
+            field_name: field.to_string(),
+            file: "src/models/user.ts".into(),
+            line: 1,
+            sensitivity: SensitivityType::Pii,
+            confidence: 0.9,
+            matched_pattern: field.to_string(),
+        })
+        .collect();
+    let detector = data_flow_detector(&[
+        ("src/services/audit.ts", r#"
+function audit(user: User) {
+  record(user);
+}
+function record(entry) {
+  logger.info(entry);
+}
+function greet(account: Account) {
+  record(account);
+}
+"#),
+    ], sensitive);
+
+    let result = detector.verify(&data_flow_constraint("User.pii !-> logger.*"));
+    assert!(!result.passed);
+    let fields: Vec<&str> = result.violations.iter().map(|v| v.message.as_str()).collect();
+    assert_eq!(fields.len(), 2, "one violation per PII field: {:?}", fields);
+    assert!(fields[0].starts_with("'User.email' from 'audit' reaches 'logger.info'"));
+    assert!(fields[1].starts_with("'User.ssn' from 'audit'"));
+    assert_eq!(result.violations[0].path.len(), 3);
+
+    // No credential fields are known on User, so nothing is tracked
+    assert!(detector.verify(&data_flow_constraint("User.credentials !-> logger.*")).passed);
+    // Without a call graph, DataFlow constraints pass vacuously
+    assert!(InvariantDetector::new().verify(&data_flow_constraint("User.pii !-> logger.*")).passed);
+
+    assert_eq!(
+        DataFlowSpec::parse("User.pii !-> logger.*").unwrap().from,
+        FlowOrigin::Field { model: "User".into(), field: "pii".into() }
+    );
+    assert!(DataFlowSpec::parse("routes/** -> db.*").is_none(), "two-part flows need !->");
+}
+
+/// T5-CON-09: ComplexityLimit — per-function limits from parser metrics.
+#[test]
+fn test_complexity_limit_per_function() {
+    let parser = ParserManager::new();
+    let pr = parser.parse(br#"
+export function simple(a) {
+  return a + 1;
+}
+export function tangled(a, b, c) {
+  for (const x of a) {
+    if (x && b) {
+      while (c) {
+        if (x > c) { c--; } else { break; }
+      }
+    }
+  }
+}
+"#, std::path::Path::new("src/core/logic.ts")).unwrap();
+
+    let mut detector = InvariantDetector::new();
+    let functions = pr.functions.iter().map(|f| FunctionInfo {
+        name: f.name.clone(),
+        line: f.line,
+        is_exported: f.is_exported,
+        complexity: f.complexity,
+    }).collect();
+    detector.add_file("src/core/logic.ts", functions, vec![], 14);
+
+    let limit = |target: &str| Constraint {
+        id: "complexity".into(),
+        description: "Keep functions simple".into(),
+        invariant_type: InvariantType::ComplexityLimit,
+        target: target.into(),
+        scope: None,
+        source: ConstraintSource::Manual,
+        enabled: true,
+    };
+
+    // Cyclomatic: tangled has 6, simple has 1.
+    let result = detector.verify(&limit("cyclomatic:5"));
+    assert_eq!(result.violations.len(), 1);
+    let v = &result.violations[0];
+    assert_eq!(v.line, Some(4));
+    assert_eq!(v.message, "'tangled' has cyclomatic complexity 6 (max 5)");
+    assert!(detector.verify(&limit("cyclomatic:6")).passed);
+
+    // A bare number still bounds the function count per file.
+    assert!(detector.verify(&limit("5")).passed);
+    let result = detector.verify(&limit("1"));
+    assert_eq!(result.violations.len(), 1);
+    assert_eq!(result.violations[0].message, "File has 2 functions (max 1)");
+    assert_eq!(detector.verify(&limit("1,cyclomatic:5")).violations.len(), 2);
+
+    // Named metrics, several at once.
+    let result = detector.verify(&limit("cognitive:10,nesting:3,params:2"));
+    let actual: Vec<&str> = result.violations.iter().map(|v| v.actual.as_str()).collect();
+    assert_eq!(actual, vec!["cognitive complexity 12", "nesting depth 4", "3 parameters"]);
+}
@@ -1,10 +1,13 @@
-//! Phase 5 contract tracking tests (T5-CTR-01 through T5-CTR-08).
+//! Phase 5 contract tracking tests (T5-CTR-01 through T5-CTR-17).
 
 use drift_analysis::structural::contracts::types::*;
 use drift_analysis::structural::contracts::breaking_changes::classify_breaking_changes;
 use drift_analysis::structural::contracts::confidence::bayesian_confidence;
 use drift_analysis::structural::contracts::matching::match_contracts;
 
+mod common;
+use common::{commit, write_file};
+
 /// T5-CTR-01: Contract tracking extracts endpoints from 5+ REST frameworks.
 #[test]
 fn test_framework_coverage() {
@@ -240,3 +243,491 @@ fn test_mismatch_types() {
     ];
     assert_eq!(types.len(), 7);
 }
+
+const OPENAPI_V1: &str = r#"openapi: 3.0.0
+info: { title: users, version: "1" }
+paths:
+  /users/{id}:
+    get:
+      responses:
+        "200":
+          content:
+            application/json:
+              schema:
+                type: object
+                required: [id, email]
+                properties:
+                  id: { type: string }
+                  email: { type: string }
+"#;
+
+const PROTO_V1: &str = "syntax = \"proto3\";
+message GetUserRequest { string id = 1; }
+message User { string id = 1; string email = 2; }
+service Users {
+  rpc GetUser(GetUserRequest) returns (User);
+  rpc DeleteUser(GetUserRequest) returns (User);
+}
+";
+
+const SERVER_V1: &str = "import express from 'express';
+const app = express();
+app.get('/api/orders', (req, res) => res.json([]));
+app.get('/api/health', (req, res) => res.send('ok'));
+";
+
+const CLIENT: &str = "export async function loadOrders() {
+  const res = await fetch('/api/orders');
+  return res.json();
+}
+";
+
+/// A repository with REST (OpenAPI + Express), gRPC and a frontend consumer at `base`.
+fn contract_repo() -> (tempfile::TempDir, git2::Repository, String) {
+    let dir = tempfile::tempdir().unwrap();
+    let repo = git2::Repository::init(dir.path()).unwrap();
+    write_file(dir.path(), "api/openapi.yaml", OPENAPI_V1);
+    write_file(dir.path(), "proto/users.proto", PROTO_V1);
+    write_file(dir.path(), "src/server.ts", SERVER_V1);
+    write_file(dir.path(), "web/orders.ts", CLIENT);
+    write_file(dir.path(), "tests/fixtures/server.ts", "app.get('/api/phantom', handler);\n");
+    let base = commit(&repo, "drift", "initial");
+    (dir, repo, base)
+}
+
+/// T5-CTR-09: Snapshots are extracted from git objects, not the checkout.
+#[test]
+fn test_contract_snapshot_from_git() {
+    use drift_analysis::structural::contracts::revisions::ContractSnapshot;
+
+    let (dir, _repo, base) = contract_repo();
+    // The working tree no longer matches the commit.
+    std::fs::remove_file(dir.path().join("proto/users.proto")).unwrap();
+
+    let snapshot = ContractSnapshot::from_git(dir.path(), &base).unwrap();
+    assert_eq!(snapshot.revision, base);
+    let paradigms: Vec<Paradigm> = snapshot.contracts.iter().map(|c| c.paradigm).collect();
+    assert!(paradigms.contains(&Paradigm::Rest));
+    assert!(paradigms.contains(&Paradigm::Grpc));
+    let grpc = snapshot.endpoints(Paradigm::Grpc);
+    assert_eq!(grpc.len(), 2);
+    assert_eq!(grpc[0].file, "proto/users.proto");
+    let rest: Vec<String> = snapshot.endpoints(Paradigm::Rest).iter().map(|e| e.path.clone()).collect();
+    assert!(rest.contains(&"/users/{id}".to_string()));
+    assert!(rest.contains(&"/api/orders".to_string()));
+    assert!(!rest.contains(&"/api/phantom".to_string()), "fixtures are not contracts");
+    assert_eq!(snapshot.consumers.len(), 1);
+    assert_eq!(snapshot.consumers[0].file, "web/orders.ts");
+
+    assert!(ContractSnapshot::from_git(dir.path(), "no-such-revision").is_err());
+}
+
+/// T5-CTR-10: Base..head report per paradigm and endpoint, with affected consumers.
+#[test]
+fn test_contract_diff_between_revisions() {
+    use drift_analysis::structural::contracts::revisions::ContractDiff;
+
+    let (dir, repo, base) = contract_repo();
+    write_file(dir.path(), "api/openapi.yaml", &OPENAPI_V1.replace("[id, email]", "[id]").replace("                  email: { type: string }\n", ""));
+    write_file(dir.path(), "src/moved.ts", "import express from 'express';\nconst app = express();\napp.get('/api/health', (req, res) => res.send('ok'));\n");
+    write_file(dir.path(), "src/server.ts", "import express from 'express';\nconst app = express();\n");
+    write_file(dir.path(), "proto/users.proto", &PROTO_V1.replace("  rpc DeleteUser(GetUserRequest) returns (User);\n", ""));
+    let head = commit(&repo, "drift", "Tidy the API");
+
+    let diff = ContractDiff::from_git(dir.path(), &base, Some(&head)).unwrap();
+    assert_eq!(diff.base, base);
+    assert_eq!(diff.head, head);
+    let find = |paradigm: Paradigm, endpoint: &str| {
+        diff.endpoints.iter().find(|e| e.paradigm == paradigm && e.endpoint == endpoint)
+    };
+
+    let orders = find(Paradigm::Rest, "/api/orders").expect("removed route");
+    assert_eq!(orders.changes[0].change_type, BreakingChangeType::EndpointRemoved);
+    assert_eq!(orders.methods, vec!["GET"]);
+    assert_eq!(orders.file, "src/server.ts");
+    assert_eq!(orders.consumers.len(), 1, "web/orders.ts still calls it");
+    assert_eq!(orders.consumers[0].file, "web/orders.ts");
+
+    let user = find(Paradigm::Rest, "/users/{id}").expect("changed response");
+    assert_eq!(user.changes[0].change_type, BreakingChangeType::FieldRemoved);
+    assert_eq!(user.changes[0].field.as_deref(), Some("email"));
+    assert!(user.consumers.is_empty());
+
+    let delete = find(Paradigm::Grpc, "DeleteUser").expect("removed rpc");
+    assert_eq!(delete.file, "proto/users.proto");
+
+    // Moving a handler between files is not a change.
+    assert!(find(Paradigm::Rest, "/api/health").is_none());
+    assert_eq!(diff.unannounced().count(), 3);
+}
+
+/// T5-CTR-11: Conventional-commit announcements cover the endpoints they name.
+#[test]
+fn test_contract_diff_announcements() {
+    use drift_analysis::structural::contracts::revisions::{breaking_change_notes, ContractDiff};
+
+    let notes = breaking_change_notes(
+        "feat(api)!: drop DeleteUser\n\nUsers are archived instead.\n\nBREAKING CHANGE: GET /api/orders moved\nto the orders service.\nRefs: #12\n",
+    );
+    assert_eq!(notes, vec!["drop DeleteUser", "GET /api/orders moved to the orders service."]);
+    assert!(breaking_change_notes("fix: handle BREAKING CHANGE: in body text").is_empty());
+
+    let (dir, repo, base) = contract_repo();
+    write_file(dir.path(), "proto/users.proto", &PROTO_V1.replace("  rpc DeleteUser(GetUserRequest) returns (User);\n", ""));
+    commit(&repo, "drift", "feat(users)!: remove the DeleteUser rpc");
+    write_file(dir.path(), "src/server.ts", "import express from 'express';\nconst app = express();\napp.get('/api/health', (req, res) => res.send('ok'));\n");
+
+    // Uncommitted removal of /api/orders is diffed against the working tree.
+    let diff = ContractDiff::from_git(dir.path(), &base, None).unwrap();
+    let announced: Vec<&str> = diff.breaking().filter(|e| e.announced).map(|e| e.endpoint.as_str()).collect();
+    let unannounced: Vec<&str> = diff.unannounced().map(|e| e.endpoint.as_str()).collect();
+    assert_eq!(announced, vec!["DeleteUser"]);
+    assert_eq!(unannounced, vec!["/api/orders"]);
+
+    // An announcement must name the endpoint itself, not a longer path.
+    let mut diff = diff;
+    diff.announce(&["removed /api/orders/{id}"]);
+    assert_eq!(diff.unannounced().count(), 1);
+    diff.announce(&["Removed /API/orders."]);
+    assert_eq!(diff.unannounced().count(), 0);
+}
+
+/// T5-CTR-12: The contract compatibility gate blocks unannounced breaking changes only.
+#[test]
+fn test_contract_compatibility_gate() {
+    use drift_analysis::enforcement::gates::*;
+    use drift_analysis::enforcement::rules::Severity;
+    use drift_analysis::structural::contracts::revisions::{ContractDiff, ContractSnapshot};
+
+    let base = ContractSnapshot::from_files("base", [
+        ("api/openapi.yaml", OPENAPI_V1),
+        ("src/server.ts", SERVER_V1),
+        ("web/orders.ts", CLIENT),
+    ]);
+    let head = ContractSnapshot::from_files("head", [
+        ("api/openapi.yaml", OPENAPI_V1),
+        ("src/server.ts", "import express from 'express';\nconst app = express();\napp.get('/api/health', (req, res) => res.send('ok'));\n"),
+        ("web/orders.ts", CLIENT),
+    ]);
+    let diff = ContractDiff::between(&base, &head);
+    let gate = ContractCompatibilityGate;
+
+    let result = gate.evaluate(&GateInput::default());
+    assert_eq!(result.status, GateStatus::Skipped);
+
+    let result = gate.evaluate(&GateInputBuilder::new().contract_diff(diff.clone()).build());
+    assert!(!result.passed);
+    assert_eq!(result.violations.len(), 1);
+    let violation = &result.violations[0];
+    assert_eq!(violation.rule_id, "contracts/endpoint_removed");
+    assert_eq!(violation.severity, Severity::Error);
+    assert_eq!(violation.file, "src/server.ts");
+    assert!(violation.message.contains("(1 frontend consumer)"), "{}", violation.message);
+    assert_eq!(result.details["unannounced_endpoints"], 1);
+
+    let mut announced = diff.clone();
+    announced.announce(&["GET /api/orders is retired"]);
+    let result = gate.evaluate(&GateInputBuilder::new().contract_diff(announced).build());
+    assert!(result.passed);
+    assert_eq!(result.status, GateStatus::Warned);
+    assert_eq!(result.warnings.len(), 1);
+
+    // Opt-in, and not narrowed by diff scopes: the removal happened on no head-side line.
+    let input = GateInputBuilder::new().contract_diff(diff).build();
+    let results = GateOrchestrator::new()
+        .with_gate(Box::new(ContractCompatibilityGate))
+        .with_diff_scope(DiffScopeFilter::new(DiffScope::NewCode, ChangedLines::new()))
+        .execute(&input)
+        .unwrap();
+    assert_eq!(results.len(), 7);
+    let contracts = results.iter().find(|r| r.gate_id == GateId::ContractCompatibility).unwrap();
+    assert!(!contracts.passed);
+    assert_eq!(contracts.violations.len(), 1);
+}
+
+/// T5-CTR-13: JSON Schema parser — properties, `$defs`, `$ref` and nullability.
+#[test]
+fn test_json_schema_parser() {
+    use drift_analysis::structural::contracts::schema_parsers::SchemaParserRegistry;
+
+    let schema = r##"{
+  "$schema": "https://json-schema.org/draft/2020-12/schema",
+  "$id": "Order",
+  "type": "object",
+  "required": ["id", "status"],
+  "properties": {
+    "id": { "type": "string" },
+    "status": { "$ref": "#/$defs/Status" },
+    "note": { "type": ["string", "null"] },
+    "items": { "type": "array", "items": { "$ref": "#/$defs/LineItem" } }
+  },
+  "$defs": {
+    "Status": { "type": "string", "enum": ["open", "paid"] },
+    "LineItem": {
+      "type": "object",
+      "required": ["sku"],
+      "properties": { "sku": { "type": "string" }, "qty": { "type": "integer" } }
+    }
+  }
+}"##;
+
+    let registry = SchemaParserRegistry::new();
+    let contracts: Vec<Contract> = registry
+        .parse_file(schema, "schemas/order.json")
+        .into_iter()
+        .filter(|c| c.framework == "json_schema")
+        .collect();
+    assert_eq!(contracts.len(), 1);
+    assert_eq!(contracts[0].paradigm, Paradigm::Rest);
+    let paths: Vec<&str> = contracts[0].endpoints.iter().map(|e| e.path.as_str()).collect();
+    assert_eq!(paths, ["Order", "Order#/$defs/LineItem"]);
+
+    let fields = &contracts[0].endpoints[0].response_fields;
+    let field = |name: &str| fields.iter().find(|f| f.name == name).unwrap();
+    assert!(field("id").required);
+    assert_eq!(field("status").field_type, "enum<open,paid>");
+    assert!(!field("note").required);
+    assert!(field("note").nullable);
+    assert_eq!(field("note").field_type, "string");
+    assert_eq!(field("items").field_type, "array<LineItem>");
+
+    // Plain JSON is not a schema.
+    let plain = registry.parse_file(r#"{"properties": {"a": 1}}"#, "config/settings.json");
+    assert!(plain.iter().all(|c| c.framework != "json_schema"));
+}
+
+const USER_CREATED_V1: &str = r#"{
+  "type": "record",
+  "name": "UserCreated",
+  "namespace": "com.acme.users",
+  "fields": [
+    { "name": "id", "type": "long" },
+    { "name": "email", "type": ["null", "string"], "default": null },
+    { "name": "plan", "type": { "type": "enum", "name": "Plan", "symbols": ["FREE", "PRO"] } },
+    { "name": "address", "type": {
+        "type": "record", "name": "Address",
+        "fields": [ { "name": "city", "type": "string" } ]
+    } },
+    { "name": "tags", "type": { "type": "array", "items": "string" }, "default": [] },
+    { "name": "previous_plan", "type": ["null", "Plan"], "default": null }
+  ]
+}"#;
+
+/// T5-CTR-14: Avro parser — records, namespaces, enums, unions and defaults.
+#[test]
+fn test_avro_parser() {
+    use drift_analysis::structural::contracts::schema_parsers::SchemaParserRegistry;
+
+    let contracts = SchemaParserRegistry::new().parse_file(USER_CREATED_V1, "schemas/user-created/v1.avsc");
+    assert_eq!(contracts.len(), 1);
+    let contract = &contracts[0];
+    assert_eq!(contract.paradigm, Paradigm::EventDriven);
+    assert_eq!(contract.framework, "avro");
+    let paths: Vec<&str> = contract.endpoints.iter().map(|e| e.path.as_str()).collect();
+    assert_eq!(paths, ["com.acme.users.UserCreated", "com.acme.users.Address"]);
+
+    let fields = &contract.endpoints[0].request_fields;
+    let field = |name: &str| fields.iter().find(|f| f.name == name).unwrap();
+    assert_eq!(field("id").field_type, "long");
+    assert!(field("id").required);
+    assert_eq!(field("email").field_type, "string");
+    assert!(field("email").nullable);
+    assert!(!field("email").required, "a default makes the field optional");
+    assert_eq!(field("plan").field_type, "enum<FREE,PRO>");
+    assert_eq!(field("address").field_type, "com.acme.users.Address");
+    assert_eq!(field("tags").field_type, "array<string>");
+    // Named enums resolve by reference.
+    assert_eq!(field("previous_plan").field_type, "enum<FREE,PRO>");
+    assert!(field("previous_plan").nullable);
+}
+
+/// T5-CTR-15: Thrift parser — services, struct resolution, comments and throws.
+#[test]
+fn test_thrift_parser() {
+    use drift_analysis::structural::contracts::schema_parsers::thrift::ThriftParser;
+    use drift_analysis::structural::contracts::schema_parsers::SchemaParser;
+
+    let idl = r#"namespace java com.acme.billing
+
+# Invoices are immutable once issued.
+struct Invoice {
+  1: required string id,
+  2: optional i64 amount_cents
+  3: map<string, string> metadata = {},
+}
+
+exception NotFound { 1: string message }
+
+/* Billing RPC */
+service Billing extends base.Base {
+  Invoice getInvoice(1: string id) throws (1: NotFound missing),
+  // Fire and forget.
+  oneway void ping();
+  list<Invoice> listInvoices(1: string customer,
+                             2: optional i32 limit = 50)
+}
+"#;
+
+    let contracts = ThriftParser.parse(idl, "idl/billing.thrift");
+    assert_eq!(contracts.len(), 1);
+    assert_eq!(contracts[0].framework, "thrift");
+    assert_eq!(contracts[0].paradigm, Paradigm::Grpc);
+    let endpoints = &contracts[0].endpoints;
+    let paths: Vec<&str> = endpoints.iter().map(|e| e.path.as_str()).collect();
+    assert_eq!(paths, ["getInvoice", "ping", "listInvoices"]);
+
+    let get = &endpoints[0];
+    assert_eq!(get.line, 14);
+    assert_eq!(get.request_fields.len(), 1);
+    assert_eq!(get.request_fields[0].name, "id");
+    let response: Vec<(&str, &str, bool)> = get
+        .response_fields
+        .iter()
+        .map(|f| (f.name.as_str(), f.field_type.as_str(), f.required))
+        .collect();
+    assert_eq!(
+        response,
+        [("id", "string", true), ("amount_cents", "i64", false), ("metadata", "map<string,string>", false)]
+    );
+
+    assert!(endpoints[1].request_fields.is_empty());
+    assert!(endpoints[1].response_fields.is_empty());
+
+    let list = &endpoints[2];
+    assert_eq!(list.request_fields.len(), 2);
+    assert!(list.request_fields[0].required);
+    assert!(!list.request_fields[1].required);
+    assert_eq!(list.response_fields[0].name, "success");
+    assert_eq!(list.response_fields[0].field_type, "list<Invoice>");
+}
+
+fn avro(file: &str, schema: &str) -> Contract {
+    use drift_analysis::structural::contracts::schema_parsers::avro::AvroParser;
+    use drift_analysis::structural::contracts::schema_parsers::SchemaParser;
+
+    AvroParser.parse(schema, file).remove(0)
+}
+
+/// T5-CTR-16: Avro schema evolution under backward, forward and full compatibility.
+#[test]
+fn test_avro_compatibility() {
+    use drift_analysis::structural::contracts::breaking_changes::{
+        classify_avro_changes, classify_avro_registry,
+    };
+
+    let v1 = avro("schemas/user-created/v1.avsc", USER_CREATED_V1);
+    let kinds = |old: &Contract, new: &Contract, mode: AvroCompatibility| -> Vec<(BreakingChangeType, String)> {
+        let mut kinds: Vec<_> = classify_avro_changes(old, new, mode)
+            .into_iter()
+            .map(|c| (c.change_type, c.field.unwrap_or_default()))
+            .collect();
+        kinds.sort_by(|a, b| a.1.cmp(&b.1));
+        kinds
+    };
+
+    // Widening int-like types and adding a defaulted field is backward compatible only.
+    let widened = avro(
+        "schemas/user-created/v2.avsc",
+        &USER_CREATED_V1
+            .replace(r#""name": "id", "type": "long""#, r#""name": "id", "type": "double""#)
+            .replace(r#"{ "name": "tags""#, r#"{ "name": "locale", "type": "string", "default": "en" },
+    { "name": "tags""#),
+    );
+    assert!(kinds(&v1, &widened, AvroCompatibility::Backward).is_empty());
+    assert_eq!(
+        kinds(&v1, &widened, AvroCompatibility::Forward),
+        [(BreakingChangeType::TypeChanged, "id".to_string())]
+    );
+
+    // A new field without a default breaks backward; removing a field without
+    // a default breaks forward; full reports both, once each.
+    let reshaped = avro(
+        "schemas/user-created/v3.avsc",
+        &USER_CREATED_V1
+            .replace(r#"{ "name": "id", "type": "long" },"#, r#"{ "name": "tenant", "type": "string" },"#),
+    );
+    assert_eq!(
+        kinds(&v1, &reshaped, AvroCompatibility::Backward),
+        [(BreakingChangeType::RequiredAdded, "tenant".to_string())]
+    );
+    assert_eq!(
+        kinds(&v1, &reshaped, AvroCompatibility::Forward),
+        [(BreakingChangeType::FieldRemoved, "id".to_string())]
+    );
+    assert_eq!(kinds(&v1, &reshaped, AvroCompatibility::Full).len(), 2);
+    assert!(kinds(&v1, &reshaped, AvroCompatibility::None).is_empty());
+
+    // Enum symbols: removal breaks backward, addition breaks forward.
+    let fewer = avro("v.avsc", &USER_CREATED_V1.replace(r#"["FREE", "PRO"]"#, r#"["FREE"]"#));
+    let backward = classify_avro_changes(&v1, &fewer, AvroCompatibility::Backward);
+    assert!(backward.iter().any(|c| c.change_type == BreakingChangeType::EnumValueRemoved && c.message.contains("PRO")));
+    assert!(classify_avro_changes(&fewer, &v1, AvroCompatibility::Backward).is_empty());
+    assert!(classify_avro_changes(&fewer, &v1, AvroCompatibility::Forward)
+        .iter()
+        .all(|c| c.change_type == BreakingChangeType::SchemaIncompatible));
+
+    // Dropping null from a union breaks new readers of old data.
+    let required_email = avro("v.avsc", &USER_CREATED_V1.replace(r#"["null", "string"], "default": null"#, r#""string""#));
+    let changes = kinds(&v1, &required_email, AvroCompatibility::Backward);
+    assert!(changes.contains(&(BreakingChangeType::NullabilityChanged, "email".to_string())), "{changes:?}");
+
+    // The generic classifier applies Avro rules (backward by default).
+    assert!(classify_breaking_changes(&v1, &widened).is_empty());
+    assert_eq!(AvroCompatibility::from_name("FULL_TRANSITIVE"), Some(AvroCompatibility::FullTransitive));
+
+    // Registry directory: versions ordered by number, transitive modes check every earlier version.
+    let registry = vec![reshaped.clone(), v1.clone(), widened.clone()];
+    let changes = classify_avro_registry(&registry, AvroCompatibility::Backward);
+    assert_eq!(changes.len(), 1);
+    assert!(changes[0].message.starts_with("schemas/user-created/v2.avsc → schemas/user-created/v3.avsc"), "{}", changes[0].message);
+    let transitive = classify_avro_registry(&registry, AvroCompatibility::BackwardTransitive);
+    assert_eq!(transitive.len(), 2);
+    assert!(transitive.iter().any(|c| c.message.starts_with("schemas/user-created/v1.avsc → schemas/user-created/v3.avsc")));
+}
+
+/// T5-CTR-17: Event producers matched to consumers with Avro resolution rules.
+#[test]
+fn test_event_producer_consumer_matching() {
+    use drift_analysis::structural::contracts::matching::{match_event_contracts, EventRole};
+
+    assert_eq!(EventRole::of_path("billing-producer/src/main/avro/user.avsc"), Some(EventRole::Producer));
+    assert_eq!(EventRole::of_path("services/mailer/consumers/user.avsc"), Some(EventRole::Consumer));
+    assert_eq!(EventRole::of_path("schemas/user-created/v1.avsc"), None);
+
+    let producer = avro("users-producer/avro/user_created.avsc", USER_CREATED_V1);
+    // The consumer expects an extra field with no default, a narrower id,
+    // a non-null email and doesn't know the PRO plan (used by two fields).
+    let consumer_schema = USER_CREATED_V1
+        .replace(r#""name": "id", "type": "long""#, r#""name": "id", "type": "int""#)
+        .replace(r#"["null", "string"], "default": null"#, r#""string""#)
+        .replace(r#""symbols": ["FREE", "PRO"]"#, r#""symbols": ["FREE"]"#)
+        .replace(r#"{ "name": "tags""#, r#"{ "name": "locale", "type": "string" },
+    { "name": "tags""#);
+    let consumer = avro("mailer-consumer/avro/user_created.avsc", &consumer_schema);
+    let shared = avro("schemas/user-created/v1.avsc", USER_CREATED_V1);
+
+    let matches = match_event_contracts(&[producer, consumer, shared]);
+    let user_created: Vec<_> = matches.iter().filter(|m| m.backend.path == "com.acme.users.UserCreated").collect();
+    assert_eq!(user_created.len(), 1, "shared registry schemas have no role");
+    let m = user_created[0];
+    assert_eq!(m.confidence, 1.0);
+    assert_eq!(m.frontend.file, "mailer-consumer/avro/user_created.avsc");
+    let mut types: Vec<MismatchType> = m.mismatches.iter().map(|x| x.mismatch_type).collect();
+    types.sort_by_key(|t| t.name());
+    assert_eq!(
+        types,
+        [
+            MismatchType::EnumValue,
+            MismatchType::EnumValue,
+            MismatchType::FieldMissing,
+            MismatchType::Nullable,
+            MismatchType::TypeMismatch,
+        ],
+        "{:?}",
+        m.mismatches
+    );
+
+    // Addresses are compatible.
+    let address = matches.iter().find(|m| m.backend.path == "com.acme.users.Address").unwrap();
+    assert!(address.mismatches.is_empty());
+}
@@ -1,10 +1,15 @@
 #![allow(clippy::field_reassign_with_default, unused_imports)]
-//! Phase 5 coupling analysis tests (T5-CPL-01 through T5-CPL-07).
+//! Phase 5 coupling analysis tests (T5-CPL-01 through T5-CPL-09).
 
 use drift_analysis::structural::coupling::types::*;
 use drift_analysis::structural::coupling::martin_metrics::compute_martin_metrics;
 use drift_analysis::structural::coupling::cycle_detection::detect_cycles;
 use drift_analysis::structural::coupling::zones::classify_zone;
+use drift_analysis::structural::coupling::{module_change_coupling, ChangeCouplingAnalyzer, ImportGraphBuilder};
+use drift_core::workspace::monorepo::PackageInfo;
+
+mod common;
+use common::{append_line, commit, write_file};
 
 /// T5-CPL-01: Martin metrics computed correctly on known module graph.
 #[test]
@@ -146,3 +151,108 @@ fn test_single_module_graph() {
     let cycles = detect_cycles(&graph);
     assert!(cycles.is_empty());
 }
+
+/// T5-CPL-08: Co-change pairs are mined from history; bulk commits are
+/// skipped, weak pairs filtered and renames followed.
+#[test]
+fn test_change_coupling_from_git_history() {
+    let dir = tempfile::tempdir().unwrap();
+    let repo = git2::Repository::init(dir.path()).unwrap();
+    let root = dir.path();
+
+    // Bulk: touches more files than the limit.
+    for file in ["api/routes.ts", "db/schema.ts", "ui/form.ts", "lone.ts"] {
+        write_file(root, file, "export const version = 1;\nexport const owner = 'platform';\nexport const stable = true;\n");
+    }
+    commit(&repo, "Alice", "scaffold");
+    for i in 0..3 {
+        append_line(root, "api/routes.ts", &format!("export const route{i} = {i};"));
+        append_line(root, "db/schema.ts", &format!("export const column{i} = {i};"));
+        commit(&repo, "Alice", "add column and route");
+    }
+    append_line(root, "api/routes.ts", "export const form = true;");
+    append_line(root, "ui/form.ts", "export const submit = true;");
+    commit(&repo, "Alice", "form route");
+    std::fs::rename(root.join("db/schema.ts"), root.join("db/tables.ts")).unwrap();
+    append_line(root, "api/routes.ts", "export const tables = true;");
+    commit(&repo, "Alice", "rename schema");
+
+    let report = ChangeCouplingAnalyzer::new().with_max_files_per_commit(3).analyze(root).unwrap();
+    assert_eq!(report.commits_analyzed, 6);
+    assert_eq!(report.commits_skipped, 1);
+    assert!(report.head_commit.is_some());
+    // routes changed in 5 commits and schema/tables in 4, always together.
+    assert_eq!(report.pairs.len(), 1, "{:?}", report.pairs);
+    let pair = &report.pairs[0];
+    assert_eq!((pair.file_a.as_str(), pair.file_b.as_str()), ("api/routes.ts", "db/tables.ts"));
+    assert_eq!(pair.co_changes, 4);
+    assert!((pair.confidence - 1.0).abs() < 1e-9);
+
+    // The single form/routes co-change passes once support is lowered.
+    let report = ChangeCouplingAnalyzer::new()
+        .with_max_files_per_commit(3)
+        .with_min_support(1)
+        .with_min_confidence(0.2)
+        .analyze(root)
+        .unwrap();
+    assert!(report.pairs.iter().any(|p| p.file_a == "api/routes.ts" && p.file_b == "ui/form.ts"));
+}
+
+/// T5-CPL-09: File pairs roll up to module pairs; pairs in different
+/// packages with no import edge or shared cycle are hidden dependencies.
+#[test]
+fn test_module_change_coupling() {
+    let mut builder = ImportGraphBuilder::new(1);
+    builder.add_file("/repo/api/a.ts", &["/repo/db/x.ts".to_string()]);
+    for file in ["/repo/api/b.ts", "/repo/db/x.ts", "/repo/db/y.ts", "/repo/ui/f.ts"] {
+        builder.add_file(file, &[]);
+    }
+    let graph = builder.build();
+    let cycles = vec![CycleInfo { members: vec!["db".into(), "api".into()], break_suggestions: vec![] }];
+
+    let pair = |file_a: &str, file_b: &str, co_changes: u32, confidence: f64| FileCoChange {
+        file_a: file_a.into(),
+        file_b: file_b.into(),
+        co_changes,
+        confidence,
+    };
+    let report = LogicalCouplingReport {
+        pairs: vec![
+            pair("api/a.ts", "db/x.ts", 6, 0.9),
+            pair("api/a.ts", "api/b.ts", 5, 1.0),
+            pair("api/a.ts", "ui/f.ts", 4, 0.8),
+            pair("api/b.ts", "db/y.ts", 3, 0.6),
+            pair("api/a.ts", "docs/notes.md", 3, 0.6),
+        ],
+        root: "/repo".into(),
+        ..LogicalCouplingReport::default()
+    };
+
+    // Without workspace packages nothing crosses a package boundary.
+    let modules = module_change_coupling(&report, &graph, &cycles, &[]);
+    assert_eq!(modules.len(), 2);
+    let db = &modules[0];
+    assert_eq!((db.module_a.as_str(), db.module_b.as_str()), ("api", "db"));
+    assert_eq!((db.co_changes, db.file_pairs), (6, 2));
+    assert_eq!((db.file_a.as_str(), db.file_b.as_str()), ("api/a.ts", "db/x.ts"));
+    assert!(db.import_edge && db.in_cycle && !db.is_hidden());
+    let ui = &modules[1];
+    assert_eq!((ui.module_a.as_str(), ui.module_b.as_str()), ("api", "ui"));
+    assert!(!ui.import_edge && !ui.in_cycle && !ui.is_hidden());
+
+    let package = |name: &str, path: &str| PackageInfo {
+        name: name.into(),
+        path: path.into(),
+        language: None,
+        framework: None,
+        dependencies: vec![],
+    };
+    let packages = [package("server", "api"), package("web", "ui"), package("store", "db/")];
+    let modules = module_change_coupling(&report, &graph, &cycles, &packages);
+    let db = &modules[0];
+    assert_eq!((db.package_a.as_deref(), db.package_b.as_deref()), (Some("server"), Some("store")));
+    assert!(!db.is_hidden());
+    let ui = &modules[1];
+    assert_eq!((ui.package_a.as_deref(), ui.package_b.as_deref()), (Some("server"), Some("web")));
+    assert!(ui.is_hidden());
+}
@@ -47,6 +47,7 @@ fn make_parse_result() -> ParseResult {
                 range: Range { start: Position { line: 10, column: 0 }, end: Position { line: 20, column: 1 } },
                 decorators: vec![], doc_comment: Some("Authenticates a user".to_string()),
                 body_hash: 111, signature_hash: 222,
+                complexity: Default::default(),
             },
             FunctionInfo {
                 name: "processPayment".to_string(),
@@ -64,6 +65,7 @@ fn make_parse_result() -> ParseResult {
                 range: Range { start: Position { line: 25, column: 0 }, end: Position { line: 40, column: 1 } },
                 decorators: vec![], doc_comment: None,
                 body_hash: 333, signature_hash: 444,
+                complexity: Default::default(),
             },
             FunctionInfo {
                 name: "UserProfile".to_string(),
@@ -78,6 +80,7 @@ fn make_parse_result() -> ParseResult {
                 range: Range { start: Position { line: 45, column: 0 }, end: Position { line: 60, column: 1 } },
                 decorators: vec![], doc_comment: None,
                 body_hash: 555, signature_hash: 666,
+                complexity: Default::default(),
             },
         ],
         classes: vec![
@@ -93,9 +96,9 @@ fn make_parse_result() -> ParseResult {
                 class_kind: ClassKind::Class,
                 methods: vec![],
                 properties: vec![
-                    PropertyInfo { name: "id".to_string(), type_annotation: Some("number".to_string()), is_static: false, is_readonly: false, visibility: Visibility::Public },
-                    PropertyInfo { name: "email".to_string(), type_annotation: Some("string".to_string()), is_static: false, is_readonly: false, visibility: Visibility::Public },
-                    PropertyInfo { name: "password".to_string(), type_annotation: Some("string".to_string()), is_static: false, is_readonly: false, visibility: Visibility::Private },
+                    PropertyInfo { name: "id".to_string(), type_annotation: Some("number".to_string()), is_static: false, is_readonly: false, visibility: Visibility::Public, line: 0, decorators: Vec::new(), tag: None, initializer: None },
+                    PropertyInfo { name: "email".to_string(), type_annotation: Some("string".to_string()), is_static: false, is_readonly: false, visibility: Visibility::Public, line: 0, decorators: Vec::new(), tag: None, initializer: None },
+                    PropertyInfo { name: "password".to_string(), type_annotation: Some("string".to_string()), is_static: false, is_readonly: false, visibility: Visibility::Private, line: 0, decorators: Vec::new(), tag: None, initializer: None },
                 ],
                 range: Range { start: Position { line: 5, column: 0 }, end: Position { line: 50, column: 1 } },
                 decorators: vec![
@@ -199,6 +202,7 @@ fn make_parse_result() -> ParseResult {
         error_count: 0,
         error_ranges: vec![],
         has_errors: false,
+        type_bindings: Vec::new(),
     }
 }
 
@@ -473,9 +477,9 @@ fn make_sequelize_pr() -> ParseResult {
         class_kind: ClassKind::Class,
         methods: vec![],
         properties: vec![
-            PropertyInfo { name: "id".to_string(), type_annotation: Some("number".to_string()), is_static: false, is_readonly: false, visibility: Visibility::Public },
-            PropertyInfo { name: "email".to_string(), type_annotation: Some("string".to_string()), is_static: false, is_readonly: false, visibility: Visibility::Public },
-            PropertyInfo { name: "ssn".to_string(), type_annotation: Some("string".to_string()), is_static: false, is_readonly: false, visibility: Visibility::Private },
+            PropertyInfo { name: "id".to_string(), type_annotation: Some("number".to_string()), is_static: false, is_readonly: false, visibility: Visibility::Public, line: 0, decorators: Vec::new(), tag: None, initializer: None },
+            PropertyInfo { name: "email".to_string(), type_annotation: Some("string".to_string()), is_static: false, is_readonly: false, visibility: Visibility::Public, line: 0, decorators: Vec::new(), tag: None, initializer: None },
+            PropertyInfo { name: "ssn".to_string(), type_annotation: Some("string".to_string()), is_static: false, is_readonly: false, visibility: Visibility::Private, line: 0, decorators: Vec::new(), tag: None, initializer: None },
         ],
         range: Range { start: Position { line: 1, column: 0 }, end: Position { line: 20, column: 1 } },
         decorators: vec![DecoratorInfo { name: "Table".to_string(), arguments: SmallVec::new(), raw_text: "@Table".to_string(), range: Range::default() }],
@@ -491,7 +495,7 @@ fn make_sequelize_pr() -> ParseResult {
 #[test]
 fn deep_all_boundary_extractors() {
     let extractors = extractors::create_all_extractors();
-    assert_eq!(extractors.len(), 10);
+    assert_eq!(extractors.len(), 20);
     for ext in &extractors {
         let fw = ext.framework();
         assert!(!fw.name().is_empty());
@@ -524,7 +528,7 @@ fn deep_typeorm_extractor() {
         implements: SmallVec::new(), generic_params: SmallVec::new(),
         is_exported: true, is_abstract: false, class_kind: ClassKind::Class,
         methods: vec![], properties: vec![
-            PropertyInfo { name: "id".to_string(), type_annotation: Some("number".to_string()), is_static: false, is_readonly: false, visibility: Visibility::Public },
+            PropertyInfo { name: "id".to_string(), type_annotation: Some("number".to_string()), is_static: false, is_readonly: false, visibility: Visibility::Public, line: 0, decorators: Vec::new(), tag: None, initializer: None },
         ],
         range: Range { start: Position { line: 1, column: 0 }, end: Position { line: 10, column: 1 } },
         decorators: vec![DecoratorInfo { name: "Entity".to_string(), arguments: SmallVec::new(), raw_text: "@Entity()".to_string(), range: Range::default() }],
@@ -557,7 +561,7 @@ fn deep_django_extractor() {
         implements: SmallVec::new(), generic_params: SmallVec::new(),
         is_exported: false, is_abstract: false, class_kind: ClassKind::Class,
         methods: vec![], properties: vec![
-            PropertyInfo { name: "email".to_string(), type_annotation: None, is_static: false, is_readonly: false, visibility: Visibility::Public },
+            PropertyInfo { name: "email".to_string(), type_annotation: None, is_static: false, is_readonly: false, visibility: Visibility::Public, line: 0, decorators: Vec::new(), tag: None, initializer: None },
         ],
         range: Range { start: Position { line: 3, column: 0 }, end: Position { line: 10, column: 0 } },
         decorators: vec![],
@@ -585,7 +589,7 @@ fn deep_sqlalchemy_extractor() {
         implements: SmallVec::new(), generic_params: SmallVec::new(),
         is_exported: false, is_abstract: false, class_kind: ClassKind::Class,
         methods: vec![], properties: vec![
-            PropertyInfo { name: "id".to_string(), type_annotation: None, is_static: false, is_readonly: false, visibility: Visibility::Public },
+            PropertyInfo { name: "id".to_string(), type_annotation: None, is_static: false, is_readonly: false, visibility: Visibility::Public, line: 0, decorators: Vec::new(), tag: None, initializer: None },
         ],
         range: Range { start: Position { line: 5, column: 0 }, end: Position { line: 15, column: 0 } },
         decorators: vec![],
@@ -644,7 +648,7 @@ fn deep_ef_core_extractor() {
         implements: SmallVec::new(), generic_params: SmallVec::new(),
         is_exported: true, is_abstract: false, class_kind: ClassKind::Class,
         methods: vec![], properties: vec![
-            PropertyInfo { name: "Id".to_string(), type_annotation: Some("int".to_string()), is_static: false, is_readonly: false, visibility: Visibility::Public },
+            PropertyInfo { name: "Id".to_string(), type_annotation: Some("int".to_string()), is_static: false, is_readonly: false, visibility: Visibility::Public, line: 0, decorators: Vec::new(), tag: None, initializer: None },
         ],
         range: Range { start: Position { line: 5, column: 0 }, end: Position { line: 15, column: 0 } },
         decorators: vec![],
@@ -671,7 +675,7 @@ fn deep_hibernate_extractor() {
         implements: SmallVec::new(), generic_params: SmallVec::new(),
         is_exported: true, is_abstract: false, class_kind: ClassKind::Class,
         methods: vec![], properties: vec![
-            PropertyInfo { name: "id".to_string(), type_annotation: Some("Long".to_string()), is_static: false, is_readonly: false, visibility: Visibility::Private },
+            PropertyInfo { name: "id".to_string(), type_annotation: Some("Long".to_string()), is_static: false, is_readonly: false, visibility: Visibility::Private, line: 0, decorators: Vec::new(), tag: None, initializer: None },
         ],
         range: Range { start: Position { line: 5, column: 0 }, end: Position { line: 20, column: 0 } },
         decorators: vec![DecoratorInfo { name: "Entity".to_string(), arguments: SmallVec::new(), raw_text: "@Entity".to_string(), range: Range::default() }],
@@ -832,6 +836,7 @@ fn deep_query_strings() {
         Language::TypeScript, Language::JavaScript, Language::Python,
         Language::Java, Language::CSharp, Language::Go,
         Language::Rust, Language::Ruby, Language::Php, Language::Kotlin,
+        Language::C, Language::Cpp, Language::Swift, Language::Scala,
     ];
     for lang in languages {
         let sq = queries::structure_query_for(lang);
@@ -217,6 +217,7 @@ fn engine_resolution_resolve() {
         is_exported: true, is_async: false, is_generator: false, is_abstract: false,
         range: Range::default(), decorators: vec![], doc_comment: None,
         body_hash: 0, signature_hash: 0,
+        complexity: Default::default(),
     });
     pr.classes.push(ClassInfo {
         name: "UserService".to_string(), namespace: None, extends: None,
@@ -233,6 +234,7 @@ fn engine_resolution_resolve() {
             is_exported: false, is_async: false, is_generator: false, is_abstract: false,
             range: Range::default(), decorators: vec![], doc_comment: None,
             body_hash: 0, signature_hash: 0,
+            complexity: Default::default(),
         }],
         properties: vec![],
         range: Range::default(), decorators: vec![],
@@ -261,6 +263,7 @@ fn engine_resolution_resolve() {
         is_exported: true, is_async: false, is_generator: false, is_abstract: false,
         range: Range::default(), decorators: vec![], doc_comment: None,
         body_hash: 0, signature_hash: 0,
+        complexity: Default::default(),
     });
 
     let index = ResolutionIndex::build(&[pr, pr2]);
@@ -387,6 +390,7 @@ fn cg_di_support() {
         range: Range::default(),
         decorators: vec![DecoratorInfo { name: "Injectable".to_string(), arguments: SmallVec::new(), raw_text: "@Injectable()".to_string(), range: Range::default() }],
         doc_comment: None, body_hash: 0, signature_hash: 0,
+        complexity: Default::default(),
     });
 
     let detected = di_support::detect_di_frameworks(&[pr]);
@@ -639,8 +643,8 @@ fn extractor_ef_core_with_data() {
         implements: SmallVec::new(), generic_params: SmallVec::new(),
         is_exported: true, is_abstract: false, class_kind: ClassKind::Class,
         methods: vec![], properties: vec![
-            PropertyInfo { name: "Id".to_string(), type_annotation: Some("int".to_string()), is_static: false, is_readonly: false, visibility: Visibility::Public },
-            PropertyInfo { name: "Name".to_string(), type_annotation: Some("string".to_string()), is_static: false, is_readonly: false, visibility: Visibility::Public },
+            PropertyInfo { name: "Id".to_string(), type_annotation: Some("int".to_string()), is_static: false, is_readonly: false, visibility: Visibility::Public, line: 0, decorators: Vec::new(), tag: None, initializer: None },
+            PropertyInfo { name: "Name".to_string(), type_annotation: Some("string".to_string()), is_static: false, is_readonly: false, visibility: Visibility::Public, line: 0, decorators: Vec::new(), tag: None, initializer: None },
         ],
         range: Range { start: Position { line: 5, column: 0 }, end: Position { line: 20, column: 0 } },
         decorators: vec![],
@@ -669,8 +673,8 @@ fn extractor_mongoose_with_data() {
         implements: SmallVec::new(), generic_params: SmallVec::new(),
         is_exported: true, is_abstract: false, class_kind: ClassKind::Class,
         methods: vec![], properties: vec![
-            PropertyInfo { name: "_id".to_string(), type_annotation: Some("ObjectId".to_string()), is_static: false, is_readonly: false, visibility: Visibility::Public },
-            PropertyInfo { name: "email".to_string(), type_annotation: Some("string".to_string()), is_static: false, is_readonly: false, visibility: Visibility::Public },
+            PropertyInfo { name: "_id".to_string(), type_annotation: Some("ObjectId".to_string()), is_static: false, is_readonly: false, visibility: Visibility::Public, line: 0, decorators: Vec::new(), tag: None, initializer: None },
+            PropertyInfo { name: "email".to_string(), type_annotation: Some("string".to_string()), is_static: false, is_readonly: false, visibility: Visibility::Public, line: 0, decorators: Vec::new(), tag: None, initializer: None },
         ],
         range: Range { start: Position { line: 3, column: 0 }, end: Position { line: 10, column: 0 } },
         decorators: vec![],
@@ -697,8 +701,8 @@ fn extractor_prisma_with_data() {
         implements: SmallVec::new(), generic_params: SmallVec::new(),
         is_exported: true, is_abstract: false, class_kind: ClassKind::Class,
         methods: vec![], properties: vec![
-            PropertyInfo { name: "id".to_string(), type_annotation: Some("string".to_string()), is_static: false, is_readonly: false, visibility: Visibility::Public },
-            PropertyInfo { name: "email".to_string(), type_annotation: Some("string".to_string()), is_static: false, is_readonly: false, visibility: Visibility::Public },
+            PropertyInfo { name: "id".to_string(), type_annotation: Some("string".to_string()), is_static: false, is_readonly: false, visibility: Visibility::Public, line: 0, decorators: Vec::new(), tag: None, initializer: None },
+            PropertyInfo { name: "email".to_string(), type_annotation: Some("string".to_string()), is_static: false, is_readonly: false, visibility: Visibility::Public, line: 0, decorators: Vec::new(), tag: None, initializer: None },
         ],
         range: Range { start: Position { line: 5, column: 0 }, end: Position { line: 10, column: 0 } },
         decorators: vec![],
@@ -720,8 +724,8 @@ fn extractor_typeorm_with_data() {
         implements: SmallVec::new(), generic_params: SmallVec::new(),
         is_exported: true, is_abstract: false, class_kind: ClassKind::Class,
         methods: vec![], properties: vec![
-            PropertyInfo { name: "id".to_string(), type_annotation: Some("number".to_string()), is_static: false, is_readonly: false, visibility: Visibility::Public },
-            PropertyInfo { name: "name".to_string(), type_annotation: Some("string".to_string()), is_static: false, is_readonly: false, visibility: Visibility::Public },
+            PropertyInfo { name: "id".to_string(), type_annotation: Some("number".to_string()), is_static: false, is_readonly: false, visibility: Visibility::Public, line: 0, decorators: Vec::new(), tag: None, initializer: None },
+            PropertyInfo { name: "name".to_string(), type_annotation: Some("string".to_string()), is_static: false, is_readonly: false, visibility: Visibility::Public, line: 0, decorators: Vec::new(), tag: None, initializer: None },
         ],
         range: Range { start: Position { line: 3, column: 0 }, end: Position { line: 10, column: 0 } },
         decorators: vec![DecoratorInfo { name: "Entity".to_string(), arguments: SmallVec::new(), raw_text: "@Entity()".to_string(), range: Range::default() }],
@@ -743,8 +747,8 @@ fn extractor_hibernate_with_data() {
         implements: SmallVec::new(), generic_params: SmallVec::new(),
         is_exported: true, is_abstract: false, class_kind: ClassKind::Class,
         methods: vec![], properties: vec![
-            PropertyInfo { name: "id".to_string(), type_annotation: Some("Long".to_string()), is_static: false, is_readonly: false, visibility: Visibility::Private },
-            PropertyInfo { name: "email".to_string(), type_annotation: Some("String".to_string()), is_static: false, is_readonly: false, visibility: Visibility::Private },
+            PropertyInfo { name: "id".to_string(), type_annotation: Some("Long".to_string()), is_static: false, is_readonly: false, visibility: Visibility::Private, line: 0, decorators: Vec::new(), tag: None, initializer: None },
+            PropertyInfo { name: "email".to_string(), type_annotation: Some("String".to_string()), is_static: false, is_readonly: false, visibility: Visibility::Private, line: 0, decorators: Vec::new(), tag: None, initializer: None },
         ],
         range: Range { start: Position { line: 5, column: 0 }, end: Position { line: 20, column: 0 } },
         decorators: vec![DecoratorInfo { name: "Entity".to_string(), arguments: SmallVec::new(), raw_text: "@Entity".to_string(), range: Range::default() }],
@@ -767,8 +771,8 @@ fn extractor_eloquent_with_data() {
         implements: SmallVec::new(), generic_params: SmallVec::new(),
         is_exported: false, is_abstract: false, class_kind: ClassKind::Class,
         methods: vec![], properties: vec![
-            PropertyInfo { name: "id".to_string(), type_annotation: None, is_static: false, is_readonly: false, visibility: Visibility::Public },
-            PropertyInfo { name: "title".to_string(), type_annotation: None, is_static: false, is_readonly: false, visibility: Visibility::Public },
+            PropertyInfo { name: "id".to_string(), type_annotation: None, is_static: false, is_readonly: false, visibility: Visibility::Public, line: 0, decorators: Vec::new(), tag: None, initializer: None },
+            PropertyInfo { name: "title".to_string(), type_annotation: None, is_static: false, is_readonly: false, visibility: Visibility::Public, line: 0, decorators: Vec::new(), tag: None, initializer: None },
         ],
         range: Range { start: Position { line: 5, column: 0 }, end: Position { line: 15, column: 0 } },
         decorators: vec![],
@@ -791,7 +795,7 @@ fn extractor_active_record_with_data() {
         implements: SmallVec::new(), generic_params: SmallVec::new(),
         is_exported: false, is_abstract: false, class_kind: ClassKind::Class,
         methods: vec![], properties: vec![
-            PropertyInfo { name: "id".to_string(), type_annotation: None, is_static: false, is_readonly: false, visibility: Visibility::Public },
+            PropertyInfo { name: "id".to_string(), type_annotation: None, is_static: false, is_readonly: false, visibility: Visibility::Public, line: 0, decorators: Vec::new(), tag: None, initializer: None },
         ],
         range: Range { start: Position { line: 1, column: 0 }, end: Position { line: 5, column: 0 } },
         decorators: vec![],
@@ -267,6 +267,8 @@ fn critical_policy_weighted_mode_math() {
         required_gates: vec![],
         progressive: false,
         ramp_up_days: 0,
+        diff_scope: None,
+        gate_thresholds: HashMap::new(),
     };
 
     let results = vec![
@@ -300,6 +302,8 @@ fn critical_policy_weighted_missing_weight_fallback() {
         required_gates: vec![],
         progressive: false,
         ramp_up_days: 0,
+        diff_scope: None,
+        gate_thresholds: HashMap::new(),
     };
 
     let results = vec![
@@ -327,6 +331,8 @@ fn critical_policy_any_must_pass_all_fail() {
         required_gates: vec![],
         progressive: false,
         ramp_up_days: 0,
+        diff_scope: None,
+        gate_thresholds: HashMap::new(),
     };
 
     let results = vec![
@@ -352,6 +358,8 @@ fn critical_policy_required_gate_missing_from_results() {
         required_gates: vec![GateId::SecurityBoundaries], // required but not in results
         progressive: false,
         ramp_up_days: 0,
+        diff_scope: None,
+        gate_thresholds: HashMap::new(),
     };
 
     let results = vec![
@@ -379,6 +387,8 @@ fn critical_policy_threshold_exact_boundary() {
         required_gates: vec![],
         progressive: false,
         ramp_up_days: 0,
+        diff_scope: None,
+        gate_thresholds: HashMap::new(),
     };
 
     let results = vec![
@@ -953,6 +963,9 @@ fn critical_sarif_line_zero_clamped() {
             rule_id: "test/rule".to_string(), message: "test".to_string(),
             quick_fix: None, cwe_id: None, owasp_category: None,
             suppressed: false, is_new: false,
+            owners: Vec::new(),
+            last_author: None,
+            file_level: false,
         }],
         warnings: vec![], execution_time_ms: 0,
         details: serde_json::Value::Null, error: None,
@@ -984,6 +997,9 @@ fn critical_sarif_both_cwe_and_owasp() {
             cwe_id: Some(89),
             owasp_category: Some("A03:2021-Injection".to_string()),
             suppressed: false, is_new: false,
+            owners: Vec::new(),
+            last_author: None,
+            file_level: false,
         }],
         warnings: vec![], execution_time_ms: 0,
         details: serde_json::Value::Null, error: None,
@@ -1018,6 +1034,9 @@ fn critical_sarif_rule_deduplication() {
         message: format!("Violation {i}"),
         quick_fix: None, cwe_id: None, owasp_category: None,
         suppressed: false, is_new: false,
+        owners: Vec::new(),
+        last_author: None,
+        file_level: false,
     }).collect();
 
     let results = vec![GateResult {
@@ -1123,6 +1142,9 @@ fn critical_is_new_flag_preserved() {
         rule_id: "test/new".to_string(), message: "New violation".to_string(),
         quick_fix: None, cwe_id: None, owasp_category: None,
         suppressed: false, is_new: true,
+        owners: Vec::new(),
+        last_author: None,
+        file_level: false,
     };
 
     // Through JSON serialization
@@ -1211,6 +1233,9 @@ fn critical_storage_null_optional_fields() {
         owasp_category: None, // NULL
         suppressed: false,
         is_new: false,
+        owners: None,
+        last_author: None,
+        quick_fix_id: None, quick_fix_edits: None,
     };
 
     insert_violation(&conn, &v).unwrap();
@@ -1247,6 +1272,9 @@ fn critical_storage_upsert_no_duplicates() {
         owasp_category: None,
         suppressed: false,
         is_new: false,
+        owners: None,
+        last_author: None,
+        quick_fix_id: None, quick_fix_edits: None,
     };
 
     let v2 = ViolationRow {
@@ -1266,6 +1294,9 @@ fn critical_storage_upsert_no_duplicates() {
         owasp_category: None,
         suppressed: false,
         is_new: false,
+        owners: None,
+        last_author: None,
+        quick_fix_id: None, quick_fix_edits: None,
     };
 
     insert_violation(&conn, &v1).unwrap();
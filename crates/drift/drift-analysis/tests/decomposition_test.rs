@@ -1,4 +1,4 @@
-//! Phase 5 decomposition tests (T5-DECOMP-01 through T5-DECOMP-27).
+//! Phase 5 decomposition tests (T5-DECOMP-01 through T5-DECOMP-28).
 
 use drift_analysis::structural::decomposition::types::*;
 use drift_analysis::structural::decomposition::decomposer::{decompose_with_priors, DecompositionInput, FileEntry};
@@ -10,6 +10,7 @@ fn make_input(files: &[&str], call_edges: &[(&str, &str)]) -> DecompositionInput
             path: f.to_string(),
             line_count: 100,
             language: "typescript".into(),
+            cyclomatic: 0,
         }).collect(),
         call_edges: call_edges.iter().map(|(a, b)| (a.to_string(), b.to_string(), "call".into())).collect(),
         data_access: vec![],
@@ -212,3 +213,15 @@ fn test_boundary_adjustment_variants() {
     let _ = serde_json::to_string(&merge).unwrap();
     let _ = serde_json::to_string(&reclassify).unwrap();
 }
+
+/// T5-DECOMP-28: Line count and cyclomatic complexity are summed separately;
+/// unmeasured files add no cyclomatic complexity.
+#[test]
+fn test_module_cyclomatic_complexity() {
+    let mut input = make_input(&["src/billing/invoice.ts", "src/billing/tax.ts"], &[]);
+    input.files[0].cyclomatic = 42;
+    let modules = decompose_with_priors(&input, &[]);
+    let billing = modules.iter().find(|m| m.name == "billing").unwrap();
+    assert_eq!(billing.estimated_complexity, 200);
+    assert_eq!(billing.cyclomatic_complexity, 42);
+}
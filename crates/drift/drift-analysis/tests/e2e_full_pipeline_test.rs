@@ -606,6 +606,9 @@ fn e2e_full_pipeline_all_languages() {
                 is_async: f.is_async,
                 body_hash: f.body_hash.to_le_bytes().to_vec(),
                 signature_hash: f.signature_hash.to_le_bytes().to_vec(),
+                cyclomatic: 0,
+                cognitive: 0,
+                max_nesting: 0,
             })
             .collect();
         if !func_rows.is_empty() {
@@ -1428,6 +1431,9 @@ fn e2e_reporter_output_validation() {
                     owasp_category: Some("A03:2025".to_string()),
                     suppressed: false,
                     is_new: true,
+                    owners: Vec::new(),
+                    last_author: None,
+                    file_level: false,
                 },
                 drift_analysis::enforcement::rules::Violation {
                     id: "sec-002".to_string(),
@@ -1445,6 +1451,9 @@ fn e2e_reporter_output_validation() {
                     owasp_category: Some("A02:2025".to_string()),
                     suppressed: false,
                     is_new: false,
+                    owners: Vec::new(),
+                    last_author: None,
+                    file_level: false,
                 },
             ],
         ),
@@ -1634,6 +1643,7 @@ fn build_rules_input(
         patterns: pattern_infos,
         source_lines,
         baseline_violation_ids: std::collections::HashSet::new(),
+        sources: Default::default(),
     }
 }
 
@@ -1653,6 +1663,8 @@ fn build_gate_input(files: &[(&str, &str)]) -> GateInput {
         predecessor_results: std::collections::HashMap::new(),
         baseline_violations: std::collections::HashSet::new(),
         feedback_stats: None,
+        contract_diff: None,
+        sources: Default::default(),
     }
 }
 
@@ -1945,12 +1957,15 @@ fn e2e_constraint_system_all_invariant_types() {
         vec![
             drift_analysis::structural::constraints::detector::FunctionInfo {
                 name: "getUser".to_string(), line: 5, is_exported: true,
+                complexity: Default::default(),
             },
             drift_analysis::structural::constraints::detector::FunctionInfo {
                 name: "createUser".to_string(), line: 20, is_exported: true,
+                complexity: Default::default(),
             },
             drift_analysis::structural::constraints::detector::FunctionInfo {
                 name: "deleteUser".to_string(), line: 35, is_exported: true,
+                complexity: Default::default(),
             },
         ],
         vec!["src/services/user.ts".to_string(), "src/db/connection.ts".to_string()],
@@ -1962,9 +1977,11 @@ fn e2e_constraint_system_all_invariant_types() {
         vec![
             drift_analysis::structural::constraints::detector::FunctionInfo {
                 name: "findUser".to_string(), line: 3, is_exported: true,
+                complexity: Default::default(),
             },
             drift_analysis::structural::constraints::detector::FunctionInfo {
                 name: "saveUser".to_string(), line: 15, is_exported: true,
+                complexity: Default::default(),
             },
         ],
         vec!["src/db/connection.ts".to_string()],
@@ -1976,6 +1993,7 @@ fn e2e_constraint_system_all_invariant_types() {
         vec![
             drift_analysis::structural::constraints::detector::FunctionInfo {
                 name: "connect".to_string(), line: 1, is_exported: true,
+                complexity: Default::default(),
             },
         ],
         vec![],
@@ -2397,6 +2415,9 @@ const API_KEY = 'AKIA1234567890ABCDEF';
                 is_async: f.is_async,
                 body_hash: f.body_hash.to_le_bytes().to_vec(),
                 signature_hash: f.signature_hash.to_le_bytes().to_vec(),
+                cyclomatic: 0,
+                cognitive: 0,
+                max_nesting: 0,
             })
         })
         .collect();
@@ -3776,6 +3797,8 @@ fn e2e_gate_predecessor_dag() {
         predecessor_results,
         baseline_violations: std::collections::HashSet::new(),
         feedback_stats: None,
+        contract_diff: None,
+        sources: Default::default(),
     };
 
     let orchestrator = GateOrchestrator::new();
@@ -3813,6 +3836,8 @@ fn e2e_gate_predecessor_dag() {
         predecessor_results: std::collections::HashMap::new(),
         baseline_violations: std::collections::HashSet::new(),
         feedback_stats: None,
+        contract_diff: None,
+        sources: Default::default(),
     };
 
     let stable_results = orchestrator.execute(&stable_input).unwrap();
@@ -4117,6 +4142,9 @@ fn e2e_reporter_idempotency() {
                     owasp_category: Some("A03:2025".to_string()),
                     suppressed: false,
                     is_new: true,
+                    owners: Vec::new(),
+                    last_author: None,
+                    file_level: false,
                 },
             ],
         ),
@@ -4643,6 +4671,7 @@ fn e2e_dead_code_fp_exclusions() {
             language: "typescript".to_string(),
             signature_hash: 0,
             body_hash: 0,
+            complexity: Default::default(),
         });
     }
 
@@ -4709,6 +4738,7 @@ fn e2e_test_topology_coverage() {
         qualified_name: None,
         language: "typescript".to_string(),
         signature_hash: 0, body_hash: 0,
+        complexity: Default::default(),
     });
     let src_b = call_graph.add_function(FunctionNode {
         name: "saveUser".to_string(),
@@ -4718,6 +4748,7 @@ fn e2e_test_topology_coverage() {
         qualified_name: None,
         language: "typescript".to_string(),
         signature_hash: 0, body_hash: 0,
+        complexity: Default::default(),
     });
     let src_c = call_graph.add_function(FunctionNode {
         name: "deleteUser".to_string(),
@@ -4727,6 +4758,7 @@ fn e2e_test_topology_coverage() {
         qualified_name: None,
         language: "typescript".to_string(),
         signature_hash: 0, body_hash: 0,
+        complexity: Default::default(),
     });
     let helper = call_graph.add_function(FunctionNode {
         name: "validateEmail".to_string(),
@@ -4736,6 +4768,7 @@ fn e2e_test_topology_coverage() {
         qualified_name: None,
         language: "typescript".to_string(),
         signature_hash: 0, body_hash: 0,
+        complexity: Default::default(),
     });
 
     // Test functions
@@ -4747,6 +4780,7 @@ fn e2e_test_topology_coverage() {
         qualified_name: None,
         language: "typescript".to_string(),
         signature_hash: 0, body_hash: 0,
+        complexity: Default::default(),
     });
     let test_b = call_graph.add_function(FunctionNode {
         name: "test_saveUser".to_string(),
@@ -4756,6 +4790,7 @@ fn e2e_test_topology_coverage() {
         qualified_name: None,
         language: "typescript".to_string(),
         signature_hash: 0, body_hash: 0,
+        complexity: Default::default(),
     });
 
     // Edges: test_a → src_a, test_b → src_b → helper
@@ -5268,17 +5303,17 @@ fn e2e_constraint_synthesis() {
         synth.add_file(
             &format!("src/module{}.ts", i),
             vec![
-                FunctionInfo { name: format!("getUserById{}", i), line: 1, is_exported: true },
-                FunctionInfo { name: format!("saveRecord{}", i), line: 10, is_exported: true },
+                FunctionInfo { name: format!("getUserById{}", i), line: 1, is_exported: true, complexity: Default::default() },
+                FunctionInfo { name: format!("saveRecord{}", i), line: 10, is_exported: true, complexity: Default::default() },
             ],
         );
     }
     // Add 2 files with snake_case (minority)
     synth.add_file("src/legacy1.py", vec![
-        FunctionInfo { name: "get_user_by_id".to_string(), line: 1, is_exported: true },
+        FunctionInfo { name: "get_user_by_id".to_string(), line: 1, is_exported: true, complexity: Default::default() },
     ]);
     synth.add_file("src/legacy2.py", vec![
-        FunctionInfo { name: "save_record".to_string(), line: 1, is_exported: true },
+        FunctionInfo { name: "save_record".to_string(), line: 1, is_exported: true, complexity: Default::default() },
     ]);
 
     let constraints = synth.synthesize_naming_conventions();
@@ -5313,8 +5348,8 @@ fn e2e_constraint_synthesis() {
         synth2.add_file(
             &format!("src/mod{}.py", i),
             vec![
-                FunctionInfo { name: format!("get_user_{}", i), line: 1, is_exported: true },
-                FunctionInfo { name: format!("save_record_{}", i), line: 10, is_exported: true },
+                FunctionInfo { name: format!("get_user_{}", i), line: 1, is_exported: true, complexity: Default::default() },
+                FunctionInfo { name: format!("save_record_{}", i), line: 10, is_exported: true, complexity: Default::default() },
             ],
         );
     }
@@ -5327,11 +5362,11 @@ fn e2e_constraint_synthesis() {
     for i in 0..5 {
         synth3.add_file(
             &format!("src/a{}.ts", i),
-            vec![FunctionInfo { name: format!("getUser{}", i), line: 1, is_exported: true }],
+            vec![FunctionInfo { name: format!("getUser{}", i), line: 1, is_exported: true, complexity: Default::default() }],
         );
         synth3.add_file(
             &format!("src/b{}.py", i),
-            vec![FunctionInfo { name: format!("get_user_{}", i), line: 1, is_exported: true }],
+            vec![FunctionInfo { name: format!("get_user_{}", i), line: 1, is_exported: true, complexity: Default::default() }],
         );
     }
     let constraints3 = synth3.synthesize_naming_conventions();
@@ -6550,6 +6585,7 @@ fn e2e_call_graph_incremental_update() {
             is_exported: exported, is_async: false, is_generator: false, is_abstract: false,
             range, decorators: vec![], doc_comment: None,
             body_hash: body, signature_hash: sig,
+            complexity: Default::default(),
         }
     };
 
@@ -6769,6 +6805,7 @@ fn e2e_rules_evaluator_severity_dedup_suppression() {
             },
         ],
         source_lines,
+        sources: Default::default(),
     };
 
     let violations = evaluator.evaluate(&input);
@@ -6920,6 +6957,8 @@ fn e2e_policy_engine_all_modes() {
         required_gates: vec![],
         progressive: false,
         ramp_up_days: 0,
+        diff_scope: None,
+        gate_thresholds: std::collections::HashMap::new(),
     };
     weighted_policy.weights.insert("pattern-compliance".to_string(), 0.3);
     weighted_policy.weights.insert("constraint-verification".to_string(), 0.2);
@@ -6943,6 +6982,8 @@ fn e2e_policy_engine_all_modes() {
         required_gates: vec![],
         progressive: false,
         ramp_up_days: 0,
+        diff_scope: None,
+        gate_thresholds: std::collections::HashMap::new(),
     };
     let threshold_engine = PolicyEngine::new(threshold_policy);
     let threshold_result = threshold_engine.evaluate(&gate_results);
@@ -6960,6 +7001,8 @@ fn e2e_policy_engine_all_modes() {
         required_gates: vec![GateId::Regression], // Regression is required but failed
         progressive: false,
         ramp_up_days: 0,
+        diff_scope: None,
+        gate_thresholds: std::collections::HashMap::new(),
     };
     let required_engine = PolicyEngine::new(required_policy);
     let required_result = required_engine.evaluate(&gate_results);
@@ -7016,6 +7059,9 @@ fn e2e_reporter_all_formats() {
             owasp_category: Some("A07:2021".to_string()),
             suppressed: false,
             is_new: true,
+            owners: Vec::new(),
+            last_author: None,
+            file_level: false,
         },
         Violation {
             id: "naming-001".to_string(),
@@ -7033,6 +7079,9 @@ fn e2e_reporter_all_formats() {
             owasp_category: None,
             suppressed: false,
             is_new: false,
+            owners: Vec::new(),
+            last_author: None,
+            file_level: false,
         },
     ];
 
@@ -7057,7 +7106,7 @@ fn e2e_reporter_all_formats() {
     ];
 
     let formats = available_formats();
-    assert_eq!(formats.len(), 8, "Should have 8 reporter formats");
+    assert_eq!(formats.len(), 10, "Should have 10 reporter formats");
 
     eprintln!("[Reporters] Testing {} formats:", formats.len());
     for format in formats {
@@ -7449,6 +7498,7 @@ fn e2e_di_framework_detection() {
                     },
                 ],
                 body_hash: 100, signature_hash: 200,
+                complexity: Default::default(),
             },
         ],
         ..ParseResult::default()
@@ -7485,6 +7535,7 @@ fn e2e_di_framework_detection() {
                     },
                 ],
                 body_hash: 300, signature_hash: 400,
+                complexity: Default::default(),
             },
         ],
         ..ParseResult::default()
@@ -7505,6 +7556,7 @@ fn e2e_di_framework_detection() {
                 range: Range::default(), doc_comment: None,
                 decorators: vec![],
                 body_hash: 500, signature_hash: 600,
+                complexity: Default::default(),
             },
         ],
         ..ParseResult::default()
@@ -8030,6 +8082,7 @@ fn e2e_dead_code_detection() {
         is_entry_point: true,
         signature_hash: 1,
         body_hash: 1,
+        complexity: Default::default(),
     });
 
     // Called by main
@@ -8043,6 +8096,7 @@ fn e2e_dead_code_detection() {
         is_entry_point: false,
         signature_hash: 2,
         body_hash: 2,
+        complexity: Default::default(),
     });
     graph.add_edge(main_idx, handler_idx, CallEdge {
         resolution: Resolution::SameFile,
@@ -8061,6 +8115,7 @@ fn e2e_dead_code_detection() {
         is_entry_point: false,
         signature_hash: 3,
         body_hash: 3,
+        complexity: Default::default(),
     });
 
     // Event handler: excluded (onMessage pattern)
@@ -8074,6 +8129,7 @@ fn e2e_dead_code_detection() {
         is_entry_point: false,
         signature_hash: 4,
         body_hash: 4,
+        complexity: Default::default(),
     });
 
     // Exported function: excluded
@@ -8087,6 +8143,7 @@ fn e2e_dead_code_detection() {
         is_entry_point: false,
         signature_hash: 5,
         body_hash: 5,
+        complexity: Default::default(),
     });
 
     // Test utility: excluded (test file)
@@ -8100,6 +8157,7 @@ fn e2e_dead_code_detection() {
         is_entry_point: false,
         signature_hash: 6,
         body_hash: 6,
+        complexity: Default::default(),
     });
 
     // Detect dead code (no callers)
@@ -8177,18 +8235,21 @@ fn e2e_test_topology() {
         qualified_name: None, language: "TypeScript".to_string(),
         line: 10, end_line: 30, is_exported: true, is_entry_point: false,
         signature_hash: 1, body_hash: 1,
+        complexity: Default::default(),
     });
     let src_b = graph.add_function(FunctionNode {
         name: "validateInput".to_string(), file: "src/validation.ts".to_string(),
         qualified_name: None, language: "TypeScript".to_string(),
         line: 5, end_line: 20, is_exported: true, is_entry_point: false,
         signature_hash: 2, body_hash: 2,
+        complexity: Default::default(),
     });
     let src_c = graph.add_function(FunctionNode {
         name: "sendEmail".to_string(), file: "src/email.ts".to_string(),
         qualified_name: None, language: "TypeScript".to_string(),
         line: 1, end_line: 15, is_exported: true, is_entry_point: false,
         signature_hash: 3, body_hash: 3,
+        complexity: Default::default(),
     });
 
     // Test functions
@@ -8197,12 +8258,14 @@ fn e2e_test_topology() {
         qualified_name: None, language: "TypeScript".to_string(),
         line: 1, end_line: 20, is_exported: false, is_entry_point: false,
         signature_hash: 10, body_hash: 10,
+        complexity: Default::default(),
     });
     let test_b = graph.add_function(FunctionNode {
         name: "test_validation".to_string(), file: "tests/validation.test.ts".to_string(),
         qualified_name: None, language: "TypeScript".to_string(),
         line: 1, end_line: 15, is_exported: false, is_entry_point: false,
         signature_hash: 11, body_hash: 11,
+        complexity: Default::default(),
     });
 
     // test_processOrder → processOrder → validateInput
@@ -8251,6 +8314,7 @@ fn e2e_test_topology() {
                 is_exported: false, is_async: false, is_generator: false, is_abstract: false,
                 range: Range::default(), doc_comment: None,
                 decorators: vec![], body_hash: 10, signature_hash: 10,
+                complexity: Default::default(),
             },
         ],
         call_sites: vec![], // No assertions → AssertionFree
@@ -8345,6 +8409,10 @@ fn e2e_boundary_detector() {
                         is_static: false,
                         is_readonly: false,
                         visibility: Visibility::Public,
+                        line: 0,
+                        decorators: Vec::new(),
+                        tag: None,
+                        initializer: None,
                     },
                     PropertyInfo {
                         name: "email".to_string(),
@@ -8352,6 +8420,10 @@ fn e2e_boundary_detector() {
                         is_static: false,
                         is_readonly: false,
                         visibility: Visibility::Public,
+                        line: 0,
+                        decorators: Vec::new(),
+                        tag: None,
+                        initializer: None,
                     },
                     PropertyInfo {
                         name: "password".to_string(),
@@ -8359,6 +8431,10 @@ fn e2e_boundary_detector() {
                         is_static: false,
                         is_readonly: false,
                         visibility: Visibility::Private,
+                        line: 0,
+                        decorators: Vec::new(),
+                        tag: None,
+                        initializer: None,
                     },
                     PropertyInfo {
                         name: "ssn".to_string(),
@@ -8366,6 +8442,10 @@ fn e2e_boundary_detector() {
                         is_static: false,
                         is_readonly: false,
                         visibility: Visibility::Private,
+                        line: 0,
+                        decorators: Vec::new(),
+                        tag: None,
+                        initializer: None,
                     },
                     PropertyInfo {
                         name: "creditCardNumber".to_string(),
@@ -8373,6 +8453,10 @@ fn e2e_boundary_detector() {
                         is_static: false,
                         is_readonly: false,
                         visibility: Visibility::Private,
+                        line: 0,
+                        decorators: Vec::new(),
+                        tag: None,
+                        initializer: None,
                     },
                 ],
                 range: Range::default(),
@@ -8462,12 +8546,14 @@ fn e2e_cross_service_reachability() {
         qualified_name: None, language: "TypeScript".to_string(),
         line: 1, end_line: 20, is_exported: true, is_entry_point: true,
         signature_hash: 1, body_hash: 1,
+        complexity: Default::default(),
     });
     let auth_validate = graph.add_function(FunctionNode {
         name: "validateToken".to_string(), file: "services/auth/validate.ts".to_string(),
         qualified_name: None, language: "TypeScript".to_string(),
         line: 1, end_line: 15, is_exported: true, is_entry_point: false,
         signature_hash: 2, body_hash: 2,
+        complexity: Default::default(),
     });
 
     // Service B: billing
@@ -8476,12 +8562,14 @@ fn e2e_cross_service_reachability() {
         qualified_name: None, language: "TypeScript".to_string(),
         line: 1, end_line: 30, is_exported: true, is_entry_point: false,
         signature_hash: 3, body_hash: 3,
+        complexity: Default::default(),
     });
     let billing_invoice = graph.add_function(FunctionNode {
         name: "createInvoice".to_string(), file: "services/billing/invoice.ts".to_string(),
         qualified_name: None, language: "TypeScript".to_string(),
         line: 1, end_line: 25, is_exported: true, is_entry_point: false,
         signature_hash: 4, body_hash: 4,
+        complexity: Default::default(),
     });
 
     // Service C: notification
@@ -8490,6 +8578,7 @@ fn e2e_cross_service_reachability() {
         qualified_name: None, language: "TypeScript".to_string(),
         line: 1, end_line: 10, is_exported: true, is_entry_point: false,
         signature_hash: 5, body_hash: 5,
+        complexity: Default::default(),
     });
 
     // Edges: auth → billing → notification (cross-service)
@@ -8557,24 +8646,28 @@ fn e2e_field_flow_tracking() {
         qualified_name: None, language: "TypeScript".to_string(),
         line: 1, end_line: 10, is_exported: true, is_entry_point: true,
         signature_hash: 1, body_hash: 1,
+        complexity: Default::default(),
     });
     let transform_user = graph.add_function(FunctionNode {
         name: "transformUserData".to_string(), file: "src/transform.ts".to_string(),
         qualified_name: None, language: "TypeScript".to_string(),
         line: 1, end_line: 15, is_exported: false, is_entry_point: false,
         signature_hash: 2, body_hash: 2,
+        complexity: Default::default(),
     });
     let save_user = graph.add_function(FunctionNode {
         name: "saveUser".to_string(), file: "src/db.ts".to_string(),
         qualified_name: None, language: "TypeScript".to_string(),
         line: 1, end_line: 20, is_exported: false, is_entry_point: false,
         signature_hash: 3, body_hash: 3,
+        complexity: Default::default(),
     });
     let log_user = graph.add_function(FunctionNode {
         name: "logAccess".to_string(), file: "src/logging.ts".to_string(),
         qualified_name: None, language: "TypeScript".to_string(),
         line: 1, end_line: 8, is_exported: false, is_entry_point: false,
         signature_hash: 4, body_hash: 4,
+        complexity: Default::default(),
     });
 
     graph.add_edge(get_user, transform_user, CallEdge {
@@ -8644,12 +8737,14 @@ fn e2e_sensitivity_classification() {
         qualified_name: None, language: "TypeScript".to_string(),
         line: 1, end_line: 20, is_exported: true, is_entry_point: true,
         signature_hash: 1, body_hash: 1,
+        complexity: Default::default(),
     });
     let sql_query = graph.add_function(FunctionNode {
         name: "executeQuery".to_string(), file: "src/db.ts".to_string(),
         qualified_name: None, language: "TypeScript".to_string(),
         line: 1, end_line: 10, is_exported: false, is_entry_point: false,
         signature_hash: 2, body_hash: 2,
+        complexity: Default::default(),
     });
     graph.add_edge(handler, sql_query, CallEdge {
         resolution: Resolution::ImportBased, confidence: 0.9, call_site_line: 5,
@@ -8665,6 +8760,7 @@ fn e2e_sensitivity_classification() {
         qualified_name: None, language: "TypeScript".to_string(),
         line: 1, end_line: 10, is_exported: false, is_entry_point: false,
         signature_hash: 3, body_hash: 3,
+        complexity: Default::default(),
     });
     let high = classify_sensitivity(&graph, handler, &[file_writer]);
     eprintln!("[Sensitivity] handler → writeFile: {:?}", high);
@@ -8676,6 +8772,7 @@ fn e2e_sensitivity_classification() {
         qualified_name: None, language: "TypeScript".to_string(),
         line: 1, end_line: 15, is_exported: true, is_entry_point: false,
         signature_hash: 4, body_hash: 4,
+        complexity: Default::default(),
     });
     let medium = classify_sensitivity(&graph, admin_fn, &[sql_query]);
     eprintln!("[Sensitivity] admin → executeQuery: {:?}", medium);
@@ -8687,12 +8784,14 @@ fn e2e_sensitivity_classification() {
         qualified_name: None, language: "TypeScript".to_string(),
         line: 1, end_line: 10, is_exported: false, is_entry_point: false,
         signature_hash: 5, body_hash: 5,
+        complexity: Default::default(),
     });
     let internal_b = graph.add_function(FunctionNode {
         name: "formatOutput".to_string(), file: "src/format.ts".to_string(),
         qualified_name: None, language: "TypeScript".to_string(),
         line: 1, end_line: 10, is_exported: false, is_entry_point: false,
         signature_hash: 6, body_hash: 6,
+        complexity: Default::default(),
     });
     let low = classify_sensitivity(&graph, internal_a, &[internal_b]);
     eprintln!("[Sensitivity] internal → internal: {:?}", low);
@@ -8724,6 +8823,7 @@ fn e2e_blast_radius_risk_scoring() {
         qualified_name: None, language: "TypeScript".to_string(),
         line: 1, end_line: 10, is_exported: true, is_entry_point: false,
         signature_hash: 1, body_hash: 1,
+        complexity: Default::default(),
     });
 
     let mut callers = Vec::new();
@@ -8733,6 +8833,7 @@ fn e2e_blast_radius_risk_scoring() {
             qualified_name: None, language: "TypeScript".to_string(),
             line: 1, end_line: 15, is_exported: false, is_entry_point: i == 0,
             signature_hash: 100 + i as u64, body_hash: 100 + i as u64,
+            complexity: Default::default(),
         });
         graph.add_edge(caller, shared_util, CallEdge {
             resolution: Resolution::ImportBased, confidence: 0.9, call_site_line: 5,
@@ -8805,6 +8906,7 @@ fn e2e_minimum_test_set() {
             qualified_name: None, language: "TypeScript".to_string(),
             line: 1, end_line: 10, is_exported: true, is_entry_point: false,
             signature_hash: i as u64, body_hash: i as u64,
+            complexity: Default::default(),
         })
     }).collect();
 
@@ -8814,6 +8916,7 @@ fn e2e_minimum_test_set() {
         qualified_name: None, language: "TypeScript".to_string(),
         line: 1, end_line: 30, is_exported: false, is_entry_point: false,
         signature_hash: 100, body_hash: 100,
+        complexity: Default::default(),
     });
     for i in 0..3 {
         graph.add_edge(test_a, src_fns[i], CallEdge {
@@ -8827,6 +8930,7 @@ fn e2e_minimum_test_set() {
         qualified_name: None, language: "TypeScript".to_string(),
         line: 1, end_line: 20, is_exported: false, is_entry_point: false,
         signature_hash: 101, body_hash: 101,
+        complexity: Default::default(),
     });
     graph.add_edge(test_b, src_fns[2], CallEdge {
         resolution: Resolution::ImportBased, confidence: 0.9, call_site_line: 1,
@@ -8841,6 +8945,7 @@ fn e2e_minimum_test_set() {
         qualified_name: None, language: "TypeScript".to_string(),
         line: 1, end_line: 10, is_exported: false, is_entry_point: false,
         signature_hash: 102, body_hash: 102,
+        complexity: Default::default(),
     });
     graph.add_edge(test_c, src_fns[4], CallEdge {
         resolution: Resolution::ImportBased, confidence: 0.9, call_site_line: 1,
@@ -8852,6 +8957,7 @@ fn e2e_minimum_test_set() {
         qualified_name: None, language: "TypeScript".to_string(),
         line: 1, end_line: 5, is_exported: false, is_entry_point: false,
         signature_hash: 103, body_hash: 103,
+        complexity: Default::default(),
     });
     graph.add_edge(_test_d, src_fns[0], CallEdge {
         resolution: Resolution::ImportBased, confidence: 0.9, call_site_line: 1,
@@ -8983,24 +9089,28 @@ fn e2e_path_finding() {
         qualified_name: None, language: "TypeScript".to_string(),
         line: 1, end_line: 10, is_exported: true, is_entry_point: true,
         signature_hash: 1, body_hash: 1,
+        complexity: Default::default(),
     });
     let node_b = graph.add_function(FunctionNode {
         name: "fastPath".to_string(), file: "src/fast.ts".to_string(),
         qualified_name: None, language: "TypeScript".to_string(),
         line: 1, end_line: 10, is_exported: false, is_entry_point: false,
         signature_hash: 2, body_hash: 2,
+        complexity: Default::default(),
     });
     let node_c = graph.add_function(FunctionNode {
         name: "slowPath".to_string(), file: "src/slow.ts".to_string(),
         qualified_name: None, language: "TypeScript".to_string(),
         line: 1, end_line: 10, is_exported: false, is_entry_point: false,
         signature_hash: 3, body_hash: 3,
+        complexity: Default::default(),
     });
     let node_d = graph.add_function(FunctionNode {
         name: "target".to_string(), file: "src/target.ts".to_string(),
         qualified_name: None, language: "TypeScript".to_string(),
         line: 1, end_line: 10, is_exported: false, is_entry_point: false,
         signature_hash: 4, body_hash: 4,
+        complexity: Default::default(),
     });
 
     // A → B (high confidence = low cost)
@@ -9047,6 +9157,7 @@ fn e2e_path_finding() {
         qualified_name: None, language: "TypeScript".to_string(),
         line: 1, end_line: 5, is_exported: false, is_entry_point: false,
         signature_hash: 99, body_hash: 99,
+        complexity: Default::default(),
     });
     let no_path = shortest_path(&graph, node_a, isolated);
     assert!(no_path.is_none(), "Should return None for disconnected nodes");
@@ -9082,6 +9193,7 @@ fn e2e_bfs_reachability() {
             qualified_name: None, language: "TypeScript".to_string(),
             line: 1, end_line: 10, is_exported: i == 0, is_entry_point: i == 0,
             signature_hash: i as u64, body_hash: i as u64,
+            complexity: Default::default(),
         })
     }).collect();
 
@@ -9308,6 +9420,7 @@ fn e2e_quality_scorer() {
         qualified_name: None, language: "TypeScript".to_string(),
         line: 1, end_line: 20, is_exported: true, is_entry_point: false,
         signature_hash: 1, body_hash: 1,
+        complexity: Default::default(),
     });
 
     // Test function
@@ -9316,6 +9429,7 @@ fn e2e_quality_scorer() {
         qualified_name: None, language: "TypeScript".to_string(),
         line: 1, end_line: 15, is_exported: false, is_entry_point: false,
         signature_hash: 10, body_hash: 10,
+        complexity: Default::default(),
     });
     graph.add_edge(test_fn, src_fn, CallEdge {
         resolution: Resolution::ImportBased, confidence: 0.9, call_site_line: 3,
@@ -9335,6 +9449,7 @@ fn e2e_quality_scorer() {
                 is_exported: false, is_async: false, is_generator: false, is_abstract: false,
                 range: Range::default(), doc_comment: None,
                 decorators: vec![], body_hash: 10, signature_hash: 10,
+                complexity: Default::default(),
             },
         ],
         call_sites: vec![
@@ -9370,6 +9485,7 @@ fn e2e_quality_scorer() {
                 is_exported: true, is_async: false, is_generator: false, is_abstract: false,
                 range: Range::default(), doc_comment: None,
                 decorators: vec![], body_hash: 1, signature_hash: 1,
+                complexity: Default::default(),
             },
         ],
         ..ParseResult::default()
@@ -10793,6 +10909,7 @@ fn e2e_reachability_auto_mode() {
             qualified_name: None, language: "TypeScript".to_string(),
             line: 1, end_line: 10, is_exported: i == 0, is_entry_point: i == 0,
             signature_hash: i as u64, body_hash: i as u64,
+            complexity: Default::default(),
         })
     }).collect();
 
@@ -11541,6 +11658,7 @@ fn e2e_n_plus_one_detection() {
             doc_comment: None,
             body_hash: 0,
             signature_hash: 0,
+            complexity: Default::default(),
         }],
         call_sites: vec![
             CallSite {
@@ -11597,6 +11715,7 @@ fn e2e_n_plus_one_detection() {
             doc_comment: None,
             body_hash: 0,
             signature_hash: 0,
+            complexity: Default::default(),
         }],
         call_sites: vec![
             CallSite {
@@ -12244,8 +12363,8 @@ fn e2e_taint_dynamic_dispatch_and_frameworks() {
     };
     use drift_analysis::graph::taint::framework_specs::{TaintFramework, apply_framework_specs};
 
-    // ---- 12 Framework Specs ----
-    assert_eq!(TaintFramework::all().len(), 12, "Should support 12 frameworks");
+    // ---- 19 Framework Specs ----
+    assert_eq!(TaintFramework::all().len(), 19, "Should support 19 frameworks");
     for fw in TaintFramework::all() {
         assert!(!fw.name().is_empty());
     }
@@ -12639,6 +12758,7 @@ fn e2e_error_handling_antipatterns() {
             decorators: vec![],
             doc_comment: None,
             body_hash: 0, signature_hash: 0,
+            complexity: Default::default(),
         }],
         call_sites: vec![
             CallSite {
@@ -12680,6 +12800,7 @@ fn e2e_error_handling_antipatterns() {
             decorators: vec![],
             doc_comment: None,
             body_hash: 0, signature_hash: 0,
+            complexity: Default::default(),
         }],
         call_sites: vec![
             CallSite {
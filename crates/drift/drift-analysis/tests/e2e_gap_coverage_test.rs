@@ -364,6 +364,9 @@ fn concurrency_batch_writer_multi_thread_writes() {
                         is_async: false,
                         body_hash: vec![],
                         signature_hash: vec![],
+                        cyclomatic: 0,
+                        cognitive: 0,
+                        max_nesting: 0,
                     },
                 ])).unwrap();
             }
@@ -604,6 +607,7 @@ fn edge_case_rules_extreme_confidence() {
         ],
         source_lines: std::collections::HashMap::new(),
         baseline_violation_ids: std::collections::HashSet::new(),
+        sources: Default::default(),
     };
     let violations = evaluator.evaluate(&input);
     eprintln!("[EdgeCase] Extreme confidence: {} violations from 0.0 and 1.0 confidence", violations.len());
@@ -636,6 +640,9 @@ fn storage_roundtrip_violation_field_fidelity() {
         owasp_category: Some("A03:2021".to_string()),
         suppressed: false,
         is_new: true,
+        owners: None,
+        last_author: None,
+        quick_fix_id: None, quick_fix_edits: None,
     };
 
     enforcement::insert_violation(&conn, &original).unwrap();
@@ -685,6 +692,9 @@ fn storage_roundtrip_suppressed_violation() {
         owasp_category: None,
         suppressed: true,
         is_new: false,
+        owners: None,
+        last_author: None,
+        quick_fix_id: None, quick_fix_edits: None,
     };
 
     enforcement::insert_violation(&conn, &v).unwrap();
@@ -850,6 +860,9 @@ fn storage_roundtrip_functions_via_batch_writer() {
             is_async: true,
             body_hash: vec![0xDE, 0xAD, 0xBE, 0xEF],
             signature_hash: vec![0xCA, 0xFE, 0xBA, 0xBE],
+            cyclomatic: 0,
+            cognitive: 0,
+            max_nesting: 0,
         },
     ])).unwrap();
     writer.flush().unwrap();
@@ -895,6 +908,9 @@ fn storage_roundtrip_multiple_violations_ordering() {
             owasp_category: None,
             suppressed: false,
             is_new: false,
+            owners: None,
+            last_author: None,
+            quick_fix_id: None, quick_fix_edits: None,
         };
         enforcement::insert_violation(&conn, &v).unwrap();
     }
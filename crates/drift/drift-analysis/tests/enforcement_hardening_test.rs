@@ -330,7 +330,10 @@ fn eft_gate_12_regression_fails_on_new_errors() {
                 cwe_id: None,
                 owasp_category: None,
                 suppressed: false,
-                is_new: true, // This is a NEW error
+                is_new: true, // This is a NEW error,
+                owners: Vec::new(),
+                last_author: None,
+                file_level: false,
             }],
             warnings: vec![],
             execution_time_ms: 0,
@@ -381,7 +384,10 @@ fn eft_gate_13_regression_passes_existing_only() {
                 cwe_id: None,
                 owasp_category: None,
                 suppressed: false,
-                is_new: false, // NOT new
+                is_new: false, // NOT new,
+                owners: Vec::new(),
+                last_author: None,
+                file_level: false,
             }],
             warnings: vec![],
             execution_time_ms: 0,
@@ -443,6 +449,7 @@ fn eft_gate_14_orchestrator_dependency_order() {
             overall_coverage: 85.0,
             threshold: 80.0,
             uncovered_files: vec![],
+            measured_coverage: None,
         })
         .previous_health_score(80.0)
         .current_health_score(82.0)
@@ -611,6 +618,7 @@ fn eft_gate_18_full_pipeline_real_scores() {
             overall_coverage: 65.0,
             threshold: 80.0,
             uncovered_files: vec!["src/uncovered.ts".to_string()],
+            measured_coverage: None,
         })
         .previous_health_score(80.0)
         .current_health_score(75.0)
@@ -679,6 +687,7 @@ fn eft_rule_01_is_new_from_baseline() {
         }],
         source_lines: HashMap::new(),
         baseline_violation_ids: baseline,
+        sources: Default::default(),
     };
 
     let violations = evaluator.evaluate(&input);
@@ -714,6 +723,7 @@ fn eft_rule_02_end_line_end_column() {
         }],
         source_lines: HashMap::new(),
         baseline_violation_ids: HashSet::new(),
+        sources: Default::default(),
     };
 
     let violations = evaluator.evaluate(&input);
@@ -749,6 +759,7 @@ fn eft_rule_03_fp_rate_downgrades_severity() {
         }],
         source_lines: HashMap::new(),
         baseline_violation_ids: HashSet::new(),
+        sources: Default::default(),
     };
 
     let violations = evaluator.evaluate(&input);
@@ -785,6 +796,7 @@ fn eft_rule_04_low_fp_rate_preserves_severity() {
         }],
         source_lines: HashMap::new(),
         baseline_violation_ids: HashSet::new(),
+        sources: Default::default(),
     };
 
     let violations = evaluator.evaluate(&input);
@@ -1023,11 +1035,16 @@ fn make_reporter_test_results() -> Vec<GateResult> {
                     strategy: QuickFixStrategy::UseParameterizedQuery,
                     description: "Use parameterized query".to_string(),
                     replacement: None,
+                    fix_id: String::new(),
+                    edits: Vec::new(),
                 }),
                 cwe_id: Some(89),
                 owasp_category: Some("A03:2021-Injection".to_string()),
                 suppressed: false,
                 is_new: true,
+                owners: Vec::new(),
+                last_author: None,
+                file_level: false,
             },
             Violation {
                 id: "v2".to_string(),
@@ -1045,6 +1062,9 @@ fn make_reporter_test_results() -> Vec<GateResult> {
                 owasp_category: None,
                 suppressed: false,
                 is_new: false,
+                owners: Vec::new(),
+                last_author: None,
+                file_level: false,
             },
             Violation {
                 id: "v3".to_string(),
@@ -1062,6 +1082,9 @@ fn make_reporter_test_results() -> Vec<GateResult> {
                 owasp_category: None,
                 suppressed: true, // suppressed — should be excluded from most outputs
                 is_new: false,
+                owners: Vec::new(),
+                last_author: None,
+                file_level: false,
             },
         ],
         warnings: vec!["Health score dropped 5 points".to_string()],
@@ -1094,7 +1117,8 @@ fn eft_rule_14_empty_baseline_means_all_existing() {
             owasp_categories: vec![],
         }],
         source_lines: HashMap::new(),
-        baseline_violation_ids: HashSet::new(), // Empty baseline
+        baseline_violation_ids: HashSet::new(), // Empty baseline,
+        sources: Default::default(),
     };
 
     let violations = evaluator.evaluate(&input);
@@ -1392,13 +1416,13 @@ fn eft_rpt_15_html_new_badge() {
     assert!(output.contains(">NEW</span>"), "HTML should show NEW badge text");
 }
 
-/// EFT-RPT-16: create_reporter returns all 8 formats.
+/// EFT-RPT-16: create_reporter returns all 10 formats.
 #[test]
 fn eft_rpt_16_all_formats_available() {
     use drift_analysis::enforcement::reporters::{create_reporter, available_formats};
 
     let formats = available_formats();
-    assert_eq!(formats.len(), 8, "Should have 8 reporter formats");
+    assert_eq!(formats.len(), 10, "Should have 10 reporter formats");
 
     for format in formats {
         let reporter = create_reporter(format);
@@ -1844,6 +1868,9 @@ fn eft_napi_01_violation_row_new_fields_round_trip() {
         owasp_category: Some("A03:2021-Injection".to_string()),
         suppressed: false,
         is_new: true,
+        owners: None,
+        last_author: None,
+        quick_fix_id: None, quick_fix_edits: None,
     };
     insert_violation(&conn, &v).unwrap();
 
@@ -1882,6 +1909,9 @@ fn eft_napi_02_violation_row_null_new_fields() {
         owasp_category: None,
         suppressed: false,
         is_new: false,
+        owners: None,
+        last_author: None,
+        quick_fix_id: None, quick_fix_edits: None,
     };
     insert_violation(&conn, &v).unwrap();
 
@@ -1982,6 +2012,9 @@ fn eft_napi_05_query_by_file_returns_new_fields() {
         owasp_category: None,
         suppressed: false,
         is_new: true,
+        owners: None,
+        last_author: None,
+        quick_fix_id: None, quick_fix_edits: None,
     }).unwrap();
 
     insert_violation(&conn, &ViolationRow {
@@ -2001,6 +2034,9 @@ fn eft_napi_05_query_by_file_returns_new_fields() {
         owasp_category: None,
         suppressed: false,
         is_new: false,
+        owners: None,
+        last_author: None,
+        quick_fix_id: None, quick_fix_edits: None,
     }).unwrap();
 
     let rows = query_violations_by_file(&conn, "src/target.ts").unwrap();
@@ -2145,6 +2181,9 @@ fn eft_napi_10_reporter_generates_from_gate_results() {
         owasp_category: Some("A03:2021-Injection".to_string()),
         suppressed: false,
         is_new: true,
+        owners: Vec::new(),
+        last_author: None,
+        file_level: false,
     }];
 
     let gate_results = vec![GateResult {
@@ -2225,6 +2264,9 @@ fn eft_napi_12_upsert_preserves_new_fields() {
         owasp_category: None,
         suppressed: false,
         is_new: true,
+        owners: None,
+        last_author: None,
+        quick_fix_id: None, quick_fix_edits: None,
     }).unwrap();
 
     // Upsert with different values for new fields
@@ -2245,6 +2287,9 @@ fn eft_napi_12_upsert_preserves_new_fields() {
         owasp_category: None,
         suppressed: false,
         is_new: false,
+        owners: None,
+        last_author: None,
+        quick_fix_id: None, quick_fix_edits: None,
     }).unwrap();
 
     let rows = query_all_violations(&conn).unwrap();
@@ -2314,6 +2359,9 @@ fn eft_napi_14_batch_mixed_new_fields() {
             owasp_category: None,
             suppressed: false,
             is_new: i % 5 == 0,
+            owners: None,
+            last_author: None,
+            quick_fix_id: None, quick_fix_edits: None,
         }).unwrap();
     }
 
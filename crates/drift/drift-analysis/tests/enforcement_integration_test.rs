@@ -131,6 +131,7 @@ fn test_full_enforcement_round_trip() {
     let input = RulesInput {
         patterns: make_realistic_patterns(),
         source_lines: HashMap::new(), baseline_violation_ids: std::collections::HashSet::new(),
+        sources: Default::default(),
     };
     let violations = evaluator.evaluate(&input);
     assert!(!violations.is_empty(), "Step 1: Should detect violations");
@@ -156,6 +157,7 @@ fn test_full_enforcement_round_trip() {
             overall_coverage: 82.0,
             threshold: 80.0,
             uncovered_files: vec![],
+            measured_coverage: None,
         }),
         error_gaps: vec![],
         previous_health_score: Some(80.0),
@@ -236,6 +238,9 @@ fn test_enforcement_data_persistence() {
         owasp_category: Some("A03:2021-Injection".to_string()),
         suppressed: false,
         is_new: false,
+        owners: None,
+        last_author: None,
+        quick_fix_id: None, quick_fix_edits: None,
     };
     insert_violation(&conn, &v).unwrap();
 
@@ -338,6 +343,9 @@ fn test_napi_type_contracts() {
         owasp_category: Some("A03:2021".to_string()),
         suppressed: false,
         is_new: true,
+        owners: Vec::new(),
+        last_author: None,
+        file_level: false,
     };
     let json = serde_json::to_string(&v).unwrap();
     assert!(json.contains("\"severity\":\"error\""));
@@ -397,6 +405,9 @@ fn test_materialized_views_refresh() {
             owasp_category: Some("A03:2021".to_string()),
             suppressed: false,
             is_new: false,
+            owners: None,
+            last_author: None,
+            quick_fix_id: None, quick_fix_edits: None,
         },
     )
     .unwrap();
@@ -420,6 +431,9 @@ fn test_materialized_views_refresh() {
             owasp_category: None,
             suppressed: false,
             is_new: false,
+            owners: None,
+            last_author: None,
+            quick_fix_id: None, quick_fix_edits: None,
         },
     )
     .unwrap();
@@ -463,6 +477,9 @@ fn test_sarif_upload_format() {
             owasp_category: Some("A03:2021-Injection".to_string()),
             suppressed: false,
             is_new: true,
+            owners: Vec::new(),
+            last_author: None,
+            file_level: false,
         }],
         warnings: vec![],
         execution_time_ms: 10,
@@ -559,6 +576,7 @@ fn test_enforcement_idempotent() {
     let input = RulesInput {
         patterns: make_realistic_patterns(),
         source_lines: HashMap::new(), baseline_violation_ids: std::collections::HashSet::new(),
+        sources: Default::default(),
     };
 
     // Run twice
@@ -1,12 +1,14 @@
 #![allow(dead_code, unused_imports, clippy::field_reassign_with_default)]
-//! Engine tests — T2-UAE-01 through T2-UAE-15.
+//! Engine tests — T2-UAE-01 through T2-UAE-21.
 //!
 //! Tests for the Unified Analysis Engine: 4-phase pipeline, GAST normalization,
-//! visitor pattern, string extraction, regex engine, resolution index, TOML patterns.
+//! visitor pattern, string extraction, embedded SQL, regex engine, resolution index,
+//! TOML patterns.
 
 use std::path::Path;
 use std::time::Instant;
 
+use drift_analysis::engine::embedded_sql::{self, SqlOperation, SqlStatement};
 use drift_analysis::engine::gast::base_normalizer::GASTNormalizer;
 use drift_analysis::engine::gast::normalizers::python::PythonNormalizer;
 use drift_analysis::engine::gast::normalizers::typescript::TypeScriptNormalizer;
@@ -15,8 +17,8 @@ use drift_analysis::engine::pipeline::AnalysisPipeline;
 use drift_analysis::engine::regex_engine::RegexEngine;
 use drift_analysis::engine::resolution::{ResolutionIndex, ResolutionStrategy};
 use drift_analysis::engine::string_extraction;
-use drift_analysis::engine::toml_patterns::TomlPatternLoader;
-use drift_analysis::engine::types::{PatternCategory, PatternMatch};
+use drift_analysis::engine::toml_patterns::{TomlPatternLoader, TomlQueryHandler};
+use drift_analysis::engine::types::{DetectionMethod, PatternCategory, PatternMatch};
 use drift_analysis::engine::visitor::{
     DetectionContext, DetectionEngine, DetectorHandler, VisitorRegistry,
 };
@@ -827,3 +829,288 @@ fn t2_uae_15_content_hash_skip() {
         "string extraction should be deterministic"
     );
 }
+
+// ---- T2-UAE-16: TOML tree-sitter queries with predicates run in the visitor pass ----
+
+#[test]
+fn t2_uae_16_toml_query_predicates() {
+    let toml_str = r#"
+[[patterns]]
+id = "exec-template"
+name = "exec with template string"
+category = "security"
+languages = ["typescript"]
+query = """
+(call_expression
+  function: (identifier) @_fn
+  arguments: (arguments . (template_string) @match)
+  (#eq? @_fn "exec"))
+"""
+confidence = 0.9
+cwe_ids = [78]
+owasp = "A03:2021"
+
+[[patterns]]
+id = "raw-query"
+name = "raw database query"
+category = "data_access"
+languages = ["typescript"]
+query = """
+(call_expression
+  function: (member_expression property: (property_identifier) @method)
+  (#match? @method "^(query|raw)$"))
+"""
+"#;
+    let queries = TomlPatternLoader::load_from_str(toml_str).unwrap();
+    assert_eq!(queries.len(), 2);
+    assert!(queries[0].regex.is_none());
+    assert_eq!(queries[0].ast_queries.len(), 1);
+
+    let source = r#"exec(`rm -rf ${dir}`);
+exec("ls");
+// exec(`echo ${x}`)
+const s = "exec(`echo ${x}`)";
+other(`echo ${y}`);
+db.query(sql);
+db.raw(sql);
+db.find(id);
+"#;
+    let (pr, bytes, tree) = parse_typescript(source);
+    let mut registry = VisitorRegistry::new();
+    registry.register(Box::new(TomlQueryHandler::new(queries)));
+    let mut engine = DetectionEngine::new(registry);
+    let ctx = DetectionContext::from_parse_result(&pr, &bytes);
+    let matches = engine.run(&tree, &bytes, &ctx);
+
+    let exec: Vec<_> = matches.iter().filter(|m| m.pattern_id == "exec-template").collect();
+    assert_eq!(exec.len(), 1, "comments, strings and other callees must not match: {exec:?}");
+    assert_eq!(exec[0].line, 0);
+    assert_eq!(exec[0].column, 5, "location comes from the @match capture");
+    assert_eq!(exec[0].matched_text, "`rm -rf ${dir}`");
+    assert_eq!(exec[0].cwe_ids.as_slice(), &[78]);
+    assert_eq!(exec[0].owasp.as_deref(), Some("A03:2021"));
+    assert_eq!(exec[0].detection_method, DetectionMethod::TomlPattern);
+
+    let raw: Vec<&str> = matches.iter()
+        .filter(|m| m.pattern_id == "raw-query")
+        .map(|m| m.matched_text.as_str())
+        .collect();
+    assert_eq!(raw, vec!["query", "raw"], "without @match the first capture is reported");
+
+    // Handlers are reset between files.
+    let again = engine.run(&tree, &bytes, &ctx);
+    assert_eq!(again.len(), matches.len());
+}
+
+// ---- T2-UAE-17: TOML queries compile per language and are skipped for others ----
+
+#[test]
+fn t2_uae_17_toml_query_per_language() {
+    let toml_str = r#"
+[[patterns]]
+id = "eval-call"
+name = "eval call"
+category = "security"
+languages = ["typescript", "python"]
+query = "(call function: (identifier) @match (#eq? @match \"eval\"))"
+"#;
+    // `call` is a Python node; TypeScript calls are `call_expression`.
+    let err = TomlPatternLoader::load_from_str(toml_str).unwrap_err();
+    assert!(err.to_string().contains("TypeScript"), "error should name the language: {err}");
+
+    let toml_str = r#"
+[[patterns]]
+id = "eval-call"
+name = "eval call"
+category = "security"
+languages = ["python"]
+query = "(call function: (identifier) @match (#eq? @match \"eval\"))"
+"#;
+    let queries = TomlPatternLoader::load_from_str(toml_str).unwrap();
+    let handler = TomlQueryHandler::new(queries);
+    assert_eq!(handler.languages(), &[Language::Python]);
+
+    let (pr, bytes, tree) = parse_python("eval(data)\nprint(eval)\n");
+    let mut registry = VisitorRegistry::new();
+    registry.register(Box::new(handler));
+    let mut engine = DetectionEngine::new(registry);
+    let ctx = DetectionContext::from_parse_result(&pr, &bytes);
+    let matches = engine.run(&tree, &bytes, &ctx);
+    assert_eq!(matches.len(), 1);
+    assert_eq!(matches[0].pattern_id, "eval-call");
+}
+
+// ---- T2-UAE-18: Invalid TOML query definitions are rejected ----
+
+#[test]
+fn t2_uae_18_toml_query_invalid() {
+    let no_languages = r#"
+[[patterns]]
+id = "q"
+name = "q"
+category = "security"
+query = "(identifier) @match"
+"#;
+    assert!(TomlPatternLoader::load_from_str(no_languages).is_err());
+
+    let unknown_language = r#"
+[[patterns]]
+id = "q"
+name = "q"
+category = "security"
+languages = ["cobol"]
+query = "(identifier) @match"
+"#;
+    assert!(TomlPatternLoader::load_from_str(unknown_language).is_err());
+
+    let bad_syntax = r#"
+[[patterns]]
+id = "q"
+name = "q"
+category = "security"
+languages = ["javascript"]
+query = "(call_expression"
+"#;
+    assert!(TomlPatternLoader::load_from_str(bad_syntax).is_err());
+
+    let empty = r#"
+[[patterns]]
+id = "q"
+name = "q"
+category = "security"
+"#;
+    assert!(TomlPatternLoader::load_from_str(empty).is_err(), "needs a pattern or a query");
+}
+
+// ---- T2-UAE-19: Embedded SQL in template strings and concatenations ----
+
+fn embedded_sql_in(file: &str, source: &str) -> Vec<SqlStatement> {
+    let (pr, tree) = ParserManager::new()
+        .parse_returning_tree(source.as_bytes(), Path::new(file))
+        .unwrap();
+    embedded_sql::extract_embedded_sql(&tree, source.as_bytes(), file, pr.language)
+}
+
+#[test]
+fn t2_uae_19_embedded_sql_typescript() {
+    let source = r#"
+async function getUser(db, req) {
+  const byId = await db.query(`SELECT email, ssn FROM users WHERE id = ${req.params.id}`);
+  const sorted = await db.query("SELECT u.name FROM users u JOIN orders o ON o.user_id = u.id ORDER BY " + req.query.sort);
+  await db.query("UPDATE users SET password_hash = $1 WHERE id = $2", [hash, id]);
+  const label = "Select a user from the list";
+}
+"#;
+    let statements = embedded_sql_in("repo.ts", source);
+    assert_eq!(statements.len(), 3, "got {statements:#?}");
+
+    let by_id = &statements[0];
+    assert_eq!(by_id.operation, SqlOperation::Read);
+    assert_eq!(by_id.line, 2);
+    assert_eq!(by_id.tables, vec!["users"]);
+    assert_eq!(by_id.columns, vec!["email", "ssn", "id"]);
+    assert_eq!(by_id.interpolations.len(), 1);
+    assert_eq!(by_id.interpolations[0].expression, "req.params.id");
+    assert_eq!(by_id.interpolations[0].clause.as_deref(), Some("WHERE"));
+
+    let sorted = &statements[1];
+    assert_eq!(sorted.tables, vec!["users", "orders"]);
+    assert!(sorted.columns.contains(&"user_id".to_string()));
+    assert_eq!(sorted.interpolations[0].expression, "req.query.sort");
+    assert_eq!(sorted.interpolations[0].clause.as_deref(), Some("ORDER BY"));
+
+    let update = &statements[2];
+    assert_eq!(update.operation, SqlOperation::Write);
+    assert_eq!(update.verb, "UPDATE");
+    assert_eq!(update.parameter_count, 2);
+    assert!(!update.is_dynamic());
+    assert_eq!(update.columns, vec!["password_hash", "id"]);
+}
+
+// ---- T2-UAE-20: Embedded SQL across languages and format calls ----
+
+#[test]
+fn t2_uae_20_embedded_sql_languages() {
+    let python = r#"
+def find(cursor, name, order):
+    cursor.execute(f"SELECT id FROM accounts WHERE name = '{name}'")
+    cursor.execute("SELECT id FROM accounts WHERE name = %s", (name,))
+    cursor.execute("SELECT id FROM accounts ORDER BY %s" % order)
+    cursor.execute("DELETE FROM sessions WHERE token = '{}'".format(name))
+"#;
+    let statements = embedded_sql_in("repo.py", python);
+    assert_eq!(statements.len(), 4, "got {statements:#?}");
+    assert_eq!(statements[0].interpolations[0].expression, "name");
+    assert!(!statements[1].is_dynamic());
+    assert_eq!(statements[1].parameter_count, 1);
+    assert_eq!(statements[2].interpolations[0].expression, "order");
+    assert_eq!(statements[3].operation, SqlOperation::Write);
+    assert_eq!(statements[3].tables, vec!["sessions"]);
+    assert_eq!(statements[3].interpolations[0].expression, "name");
+
+    let go = r#"package repo
+
+func Find(db *sql.DB, id string) {
+	db.Query(fmt.Sprintf("SELECT email FROM users WHERE id = %s", id))
+	db.Query("SELECT email FROM users WHERE id = ?", id)
+}
+"#;
+    let statements = embedded_sql_in("repo.go", go);
+    assert_eq!(statements.len(), 2, "got {statements:#?}");
+    assert_eq!(statements[0].interpolations[0].expression, "id");
+    assert_eq!(statements[1].parameter_count, 1);
+
+    let java = r#"class Repo {
+    void find(Statement st, String id) {
+        st.executeQuery("SELECT email FROM users WHERE id = " + id + " AND active = true");
+    }
+}
+"#;
+    let statements = embedded_sql_in("Repo.java", java);
+    assert_eq!(statements.len(), 1, "got {statements:#?}");
+    assert_eq!(statements[0].columns, vec!["email", "id", "active"]);
+    assert_eq!(statements[0].interpolations[0].expression, "id");
+
+    let php = r#"<?php
+function find($pdo, $id) {
+    $pdo->query("SELECT email FROM users WHERE id = $id");
+    $pdo->query('SELECT email FROM users WHERE id = ' . $id);
+}
+"#;
+    let statements = embedded_sql_in("repo.php", php);
+    assert_eq!(statements.len(), 2, "got {statements:#?}");
+    assert!(statements.iter().all(|s| s.interpolations.len() == 1 && s.tables == vec!["users"]));
+}
+
+// ---- T2-UAE-21: SQL statement model — DDL, CTEs, insert column lists ----
+
+#[test]
+fn t2_uae_21_sql_statement_model() {
+    let insert = embedded_sql::parse_sql("INSERT INTO users (email, password_hash) VALUES (?, ?) RETURNING id").unwrap();
+    assert_eq!(insert.operation, SqlOperation::Write);
+    assert_eq!(insert.tables, vec!["users"]);
+    assert_eq!(insert.columns, vec!["email", "password_hash", "id"]);
+    assert_eq!(insert.parameter_count, 2);
+
+    let ddl = embedded_sql::parse_sql(
+        "CREATE TABLE IF NOT EXISTS patients (id INTEGER PRIMARY KEY, diagnosis TEXT NOT NULL, FOREIGN KEY (id) REFERENCES people(id))",
+    )
+    .unwrap();
+    assert_eq!(ddl.operation, SqlOperation::Ddl);
+    assert_eq!(ddl.tables, vec!["patients"]);
+    assert_eq!(ddl.columns, vec!["id", "diagnosis"]);
+
+    let cte = embedded_sql::parse_sql(
+        "WITH recent AS (SELECT user_id FROM orders WHERE created_at > now() - interval '1 day') \
+         SELECT u.email FROM users u JOIN recent r ON r.user_id = u.id",
+    )
+    .unwrap();
+    assert_eq!(cte.operation, SqlOperation::Read);
+    assert_eq!(cte.tables, vec!["orders", "users"]);
+    assert!(cte.columns.contains(&"email".to_string()));
+    assert!(!cte.columns.contains(&"recent".to_string()));
+
+    assert!(embedded_sql::parse_sql("Select the rows you want from the table").is_none());
+    assert!(embedded_sql::parse_sql("update available").is_none());
+    assert_eq!(embedded_sql::parse_sql("truncate audit_log").unwrap().tables, vec!["audit_log"]);
+}
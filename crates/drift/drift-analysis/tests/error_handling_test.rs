@@ -20,6 +20,7 @@ fn make_node(file: &str, name: &str, exported: bool) -> FunctionNode {
         is_exported: exported,
         signature_hash: 0,
         body_hash: 0,
+        complexity: Default::default(),
     }
 }
 
@@ -48,6 +49,7 @@ fn make_parse_result(
         error_count: 0,
         error_ranges: Vec::new(),
         has_errors: false,
+        type_bindings: Vec::new(),
     }
 }
 
@@ -72,6 +74,7 @@ fn make_function(name: &str, line: u32, end_line: u32, is_async: bool) -> Functi
         doc_comment: None,
         body_hash: 0,
         signature_hash: 0,
+        complexity: Default::default(),
     }
 }
 
@@ -895,6 +895,7 @@ deviation_threshold = 0.15
             doc_comment: None,
             body_hash: 0,
             signature_hash: 0,
+            complexity: Default::default(),
         }
     }
 
@@ -1065,6 +1066,7 @@ deviation_threshold = 0.15
             doc_comment: None,
             body_hash: 0,
             signature_hash: 0,
+            complexity: Default::default(),
         }
     }
 
@@ -1228,6 +1230,7 @@ type_annotations = ["\\bany\\b"]
             is_exported: false, is_async: false, is_generator: false, is_abstract: false,
             range: Range { start: Position { line: 1, column: 0 }, end: Position { line: 1, column: 34 } },
             decorators: vec![], doc_comment: None, body_hash: 0, signature_hash: 0,
+            complexity: Default::default(),
         }],
         ..Default::default()
     };
@@ -1284,6 +1287,7 @@ type_annotations = ["Request|Response"]
             is_exported: false, is_async: false, is_generator: false, is_abstract: false,
             range: Range { start: Position { line: 2, column: 0 }, end: Position { line: 2, column: 20 } },
             decorators: vec![], doc_comment: None, body_hash: 0, signature_hash: 0,
+            complexity: Default::default(),
         }],
         ..Default::default()
     };
@@ -1319,6 +1323,7 @@ type_annotations = ["Request|Response"]
             is_exported: false, is_async: false, is_generator: false, is_abstract: false,
             range: Range { start: Position { line: 2, column: 0 }, end: Position { line: 2, column: 32 } },
             decorators: vec![], doc_comment: None, body_hash: 0, signature_hash: 0,
+            complexity: Default::default(),
         }],
         ..Default::default()
     };
@@ -2354,6 +2359,7 @@ signal = "frequency"
             doc_comment: None,
             body_hash: 0,
             signature_hash: 0,
+            complexity: Default::default(),
         }
     }
 
@@ -2449,6 +2455,7 @@ signal = "frequency"
             doc_comment: None,
             body_hash: 0,
             signature_hash: 0,
+            complexity: Default::default(),
         }
     }
 
@@ -2552,6 +2559,7 @@ signal = "presence"
             doc_comment: None,
             body_hash: 0,
             signature_hash: 0,
+            complexity: Default::default(),
         }
     }
 
@@ -2646,6 +2654,7 @@ signal = "co_occurrence"
             doc_comment: None,
             body_hash: 0,
             signature_hash: 0,
+            complexity: Default::default(),
         }
     }
 
@@ -2731,6 +2740,7 @@ signal = "nonexistent_signal_type"
             doc_comment: None,
             body_hash: 0,
             signature_hash: 0,
+            complexity: Default::default(),
         }
     }
 
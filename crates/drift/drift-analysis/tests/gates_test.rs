@@ -1,9 +1,12 @@
 //! Phase 6 tests: Quality Gates — DAG Orchestration & Progressive Enforcement
-//! T6-GAT-01 through T6-GAT-08
+//! T6-GAT-01 through T6-GAT-16
 
 use drift_analysis::enforcement::gates::*;
 use drift_analysis::enforcement::rules::*;
 
+mod common;
+use common::commit;
+
 fn make_gate_input() -> GateInput {
     GateInput {
         files: vec!["src/main.ts".to_string()],
@@ -40,6 +43,7 @@ fn make_gate_input() -> GateInput {
             overall_coverage: 85.0,
             threshold: 80.0,
             uncovered_files: vec![],
+            measured_coverage: None,
         }),
         error_gaps: vec![],
         previous_health_score: Some(80.0),
@@ -47,6 +51,8 @@ fn make_gate_input() -> GateInput {
         predecessor_results: std::collections::HashMap::new(),
         baseline_violations: std::collections::HashSet::new(),
         feedback_stats: None,
+        contract_diff: None,
+        sources: Default::default(),
     }
 }
 
@@ -284,3 +290,363 @@ fn test_regression_gate_health_drop() {
     assert!(result.summary.contains("Critical") || result.summary.contains("critical"),
         "Should indicate critical regression");
 }
+
+fn legacy_pattern_input(outlier_lines: &[u32]) -> GateInput {
+    GateInput {
+        files: vec!["src/legacy.ts".to_string()],
+        all_files: vec!["src/legacy.ts".to_string()],
+        patterns: vec![PatternInfo {
+            pattern_id: "error-handling".to_string(),
+            category: "errors".to_string(),
+            confidence: 0.95,
+            locations: vec![PatternLocation {
+                file: "src/legacy.ts".to_string(),
+                line: 1,
+                column: None,
+            }],
+            outliers: outlier_lines
+                .iter()
+                .map(|&line| OutlierLocation {
+                    file: "src/legacy.ts".to_string(),
+                    line,
+                    column: None,
+                    end_line: None,
+                    end_column: None,
+                    deviation_score: 3.0,
+                    message: "Missing try/catch".to_string(),
+                })
+                .collect(),
+            cwe_ids: vec![],
+            owasp_categories: vec![],
+        }],
+        ..Default::default()
+    }
+}
+
+fn legacy_context(source: &str, functions: &[(&str, u32, u32)]) -> drift_analysis::enforcement::baseline::FingerprintContext {
+    use drift_analysis::enforcement::baseline::{FingerprintContext, FunctionSpan};
+    let mut context = FingerprintContext::new();
+    context.add_source("src/legacy.ts", source);
+    for &(name, start_line, end_line) in functions {
+        context.add_function("src/legacy.ts", FunctionSpan { name: name.to_string(), start_line, end_line });
+    }
+    context
+}
+
+const LEGACY_SOURCE: &str = "function load() {\n  fetch(url);\n}\nfunction save() {\n  fetch(url);\n  fetch(url);\n}\n";
+
+/// T6-GAT-09: Baseline fingerprints ignore line shifts but not code changes.
+#[test]
+fn test_baseline_fingerprint_stability() {
+    use drift_analysis::enforcement::baseline::fingerprint_violations;
+
+    let violation = |line: u32| Violation {
+        id: format!("v-{line}"),
+        file: "src/legacy.ts".to_string(),
+        line,
+        column: None,
+        end_line: None,
+        end_column: None,
+        severity: Severity::Error,
+        pattern_id: "error-handling".to_string(),
+        rule_id: "pattern-compliance/error-handling".to_string(),
+        message: "Missing try/catch".to_string(),
+        quick_fix: None,
+        cwe_id: None,
+        owasp_category: None,
+        suppressed: false,
+        is_new: false,
+        owners: Vec::new(),
+        last_author: None,
+        file_level: false,
+    };
+    // Rows are 0-based: the fetch calls sit on rows 1, 4 and 5.
+    let functions = [("load", 0, 2), ("save", 3, 6)];
+    let context = legacy_context(LEGACY_SOURCE, &functions);
+    let (v1, v4, v5) = (violation(1), violation(4), violation(5));
+    let fps = fingerprint_violations(&[&v1, &v4, &v5], &context);
+    assert_eq!(fps[0].function.as_deref(), Some("load"));
+    assert_eq!(fps[1].function.as_deref(), Some("save"));
+    // Same snippet in different functions, and twice in one function, stay distinct.
+    assert_ne!(fps[0].fingerprint, fps[1].fingerprint);
+    assert_ne!(fps[1].fingerprint, fps[2].fingerprint);
+
+    // Insert a header and re-indent: lines move, fingerprints don't.
+    let shifted_source = format!("// header\n\n{}", LEGACY_SOURCE.replace("  fetch", "    fetch"));
+    let shifted_context = legacy_context(&shifted_source, &[("load", 2, 4), ("save", 5, 8)]);
+    let (s3, s6, s7) = (violation(3), violation(6), violation(7));
+    // Input order doesn't matter either.
+    let shifted = fingerprint_violations(&[&s7, &s3, &s6], &shifted_context);
+    assert_eq!(shifted[1].fingerprint, fps[0].fingerprint);
+    assert_eq!(shifted[2].fingerprint, fps[1].fingerprint);
+    assert_eq!(shifted[0].fingerprint, fps[2].fingerprint);
+
+    // Changing the offending code changes the fingerprint.
+    let edited_context = legacy_context(&LEGACY_SOURCE.replacen("fetch(url)", "fetch(other)", 1), &functions);
+    let edited = fingerprint_violations(&[&v1], &edited_context);
+    assert_ne!(edited[0].fingerprint, fps[0].fingerprint);
+}
+
+/// T6-GAT-10: With a baseline, gates fail only on violations it doesn't list.
+#[test]
+fn test_baseline_gates_fail_only_on_new_violations() {
+    use drift_analysis::enforcement::baseline::{BaselineFilter, ViolationBaseline};
+
+    let context = legacy_context(LEGACY_SOURCE, &[("load", 0, 2), ("save", 3, 6)]);
+    let legacy = legacy_pattern_input(&[1, 4]);
+
+    let results = GateOrchestrator::new().execute(&legacy).unwrap();
+    let compliance = results.iter().find(|r| r.gate_id == GateId::PatternCompliance).unwrap();
+    assert!(!compliance.passed, "legacy violations fail the gate without a baseline");
+
+    let baseline = ViolationBaseline::from_results(&results, &context);
+    assert_eq!(baseline.len(), 2);
+
+    let orchestrator = GateOrchestrator::new().with_baseline(BaselineFilter::new(&baseline, context.clone()));
+    let results = orchestrator.execute(&legacy).unwrap();
+    let compliance = results.iter().find(|r| r.gate_id == GateId::PatternCompliance).unwrap();
+    assert!(compliance.passed);
+    assert_eq!(compliance.status, GateStatus::Passed);
+    assert_eq!(compliance.violations.len(), 2, "baselined violations are still reported");
+    assert!(compliance.violations.iter().all(|v| !v.is_new));
+    assert!(compliance.summary.contains("2 baselined, 0 new"));
+    assert_eq!(compliance.details["baseline"]["baselined"], 2);
+
+    // A new violation fails the gate again, and only it is marked new.
+    let results = orchestrator.execute(&legacy_pattern_input(&[1, 4, 5])).unwrap();
+    let compliance = results.iter().find(|r| r.gate_id == GateId::PatternCompliance).unwrap();
+    assert!(!compliance.passed);
+    let new: Vec<u32> = compliance.violations.iter().filter(|v| v.is_new).map(|v| v.line).collect();
+    assert_eq!(new, vec![5]);
+}
+
+/// T6-GAT-11: Baseline files round-trip, and pruning drops fixed entries only.
+#[test]
+fn test_baseline_file_save_load_prune() {
+    use drift_analysis::enforcement::baseline::{ViolationBaseline, BASELINE_FILE};
+
+    let context = legacy_context(LEGACY_SOURCE, &[("load", 0, 2), ("save", 3, 6)]);
+    let results = GateOrchestrator::new().execute(&legacy_pattern_input(&[1, 4])).unwrap();
+    let baseline = ViolationBaseline::from_results(&results, &context);
+
+    let dir = tempfile::tempdir().unwrap();
+    assert!(ViolationBaseline::load_from_root(dir.path()).unwrap().is_none());
+    baseline.save(&dir.path().join(BASELINE_FILE)).unwrap();
+    let mut loaded = ViolationBaseline::load_from_root(dir.path()).unwrap().unwrap();
+    assert_eq!(loaded, baseline);
+    assert_eq!(loaded.violations[0].function.as_deref(), Some("load"));
+
+    // Row 1 gets fixed, row 5 is new: prune drops the fixed entry and adds nothing.
+    let current = GateOrchestrator::new().execute(&legacy_pattern_input(&[4, 5])).unwrap();
+    let diff = loaded.diff(&current, &context);
+    assert_eq!(diff.baselined, 1);
+    assert_eq!(diff.new.len(), 1);
+    assert_eq!(diff.fixed.len(), 1);
+    let removed = loaded.prune(&current, &context);
+    assert_eq!(removed.len(), 1);
+    assert_eq!(removed[0].line, 1);
+    assert_eq!(loaded.len(), 1);
+    assert!(loaded.contains(&baseline.violations[1].fingerprint));
+
+    // Files from a newer drift are rejected rather than misread.
+    let path = dir.path().join("future.json");
+    std::fs::write(&path, r#"{"version": 99, "violations": []}"#).unwrap();
+    assert!(ViolationBaseline::load(&path).is_err());
+}
+
+/// T6-GAT-12: Changed lines come from the merge base to head, or to the working tree.
+#[test]
+fn test_diff_scope_changed_lines_from_git() {
+    let dir = tempfile::tempdir().unwrap();
+    let repo = git2::Repository::init(dir.path()).unwrap();
+    std::fs::create_dir(dir.path().join("src")).unwrap();
+    std::fs::write(dir.path().join("src/legacy.ts"), LEGACY_SOURCE).unwrap();
+    let base = commit(&repo, "drift", "initial");
+
+    // Uncommitted edit to line 6 plus an untracked file.
+    std::fs::write(dir.path().join("src/legacy.ts"), LEGACY_SOURCE.replace("  fetch(url);\n  fetch(url);", "  fetch(url);\n  fetch(other);")).unwrap();
+    std::fs::write(dir.path().join("src/new.ts"), "export const x = 1;\n").unwrap();
+    let changed = ChangedLines::from_git(dir.path(), &base, None).unwrap();
+    assert_eq!(changed.files(), vec!["src/legacy.ts", "src/new.ts"]);
+    assert!(changed.intersects("src/legacy.ts", 6, 6));
+    assert!(!changed.intersects("src/legacy.ts", 1, 5));
+    assert!(changed.intersects("src/new.ts", 1, 1));
+
+    // The same edit, committed, seen through a base..head range.
+    commit(&repo, "drift", "edit");
+    let changed = ChangedLines::from_git(dir.path(), &base, Some("HEAD")).unwrap();
+    assert!(changed.intersects("src/legacy.ts", 6, 6));
+    assert!(!changed.intersects("src/legacy.ts", 2, 2));
+
+    assert!(ChangedLines::from_git(dir.path(), "no-such-revision", None).is_err());
+}
+
+/// T6-GAT-13: New-code scope drops violations outside changed lines and re-grades.
+#[test]
+fn test_diff_scope_new_code_only() {
+    // Outliers on the three fetch calls; rows are 0-based, hunks 1-based.
+    let input = legacy_pattern_input(&[1, 4, 5]);
+    let compliance = |orchestrator: GateOrchestrator| {
+        let results = orchestrator.execute(&input).unwrap();
+        results.into_iter().find(|r| r.gate_id == GateId::PatternCompliance).unwrap()
+    };
+
+    // An untouched function's violations don't fail the change.
+    let mut changed = ChangedLines::new();
+    changed.add_range("src/legacy.ts", 3, 4);
+    let result = compliance(GateOrchestrator::new().with_diff_scope(DiffScopeFilter::new(DiffScope::NewCode, changed)));
+    assert!(result.passed);
+    assert_eq!(result.status, GateStatus::Passed);
+    assert!(result.violations.is_empty());
+    assert!(result.summary.contains("0 of 3 violations in new_code scope"));
+    assert_eq!(result.details["diff_scope"]["out_of_scope"], 3);
+
+    // A violation on a changed line still fails it.
+    let mut changed = ChangedLines::new();
+    changed.add_range("src/legacy.ts", 6, 6);
+    let result = compliance(GateOrchestrator::new().with_diff_scope(DiffScopeFilter::new(DiffScope::NewCode, changed)));
+    assert!(!result.passed);
+    let lines: Vec<u32> = result.violations.iter().map(|v| v.line).collect();
+    assert_eq!(lines, vec![5]);
+}
+
+/// T6-GAT-14: Touched-function scope keeps violations in functions whose blast radius reaches a change.
+#[test]
+fn test_diff_scope_touched_functions() {
+    use drift_analysis::call_graph::types::{CallEdge, CallGraph, FunctionNode, Resolution};
+
+    // save() calls load(); call graph lines are 0-based.
+    let node = |name: &str, line: u32, end_line: u32| FunctionNode {
+        file: "src/legacy.ts".to_string(),
+        name: name.to_string(),
+        qualified_name: None,
+        language: "typescript".to_string(),
+        line,
+        end_line,
+        is_entry_point: false,
+        is_exported: false,
+        signature_hash: 0,
+        body_hash: 0,
+        complexity: Default::default(),
+    };
+    let mut graph = CallGraph::new();
+    let load = graph.add_function(node("load", 0, 2));
+    let save = graph.add_function(node("save", 3, 6));
+    graph.add_edge(save, load, CallEdge { resolution: Resolution::SameFile, confidence: 0.95, call_site_line: 4 });
+
+    let mut changed = ChangedLines::new();
+    changed.add_range("src/legacy.ts", 5, 5);
+    let input = legacy_pattern_input(&[1, 4]);
+    let lines = |filter: DiffScopeFilter| -> Vec<u32> {
+        let results = GateOrchestrator::new().with_diff_scope(filter).execute(&input).unwrap();
+        let compliance = results.into_iter().find(|r| r.gate_id == GateId::PatternCompliance).unwrap();
+        compliance.violations.iter().map(|v| v.line).collect()
+    };
+
+    assert_eq!(lines(DiffScopeFilter::new(DiffScope::NewCode, changed.clone()).with_call_graph(&graph)), vec![4]);
+    let touched = DiffScopeFilter::new(DiffScope::TouchedFunctions, changed).with_call_graph(&graph);
+    assert_eq!(lines(touched), vec![1, 4]);
+    assert_eq!(DiffScope::from_name("touched_functions"), Some(DiffScope::TouchedFunctions));
+}
+
+/// T6-GAT-15: Violations at parsed rows line up with the 1-based hunks of a
+/// real edit; file-level violations follow their file.
+#[test]
+fn test_diff_scope_parsed_violation_lines() {
+    use drift_analysis::parsers::manager::ParserManager;
+
+    let dir = tempfile::tempdir().unwrap();
+    let repo = git2::Repository::init(dir.path()).unwrap();
+    std::fs::create_dir(dir.path().join("src")).unwrap();
+    std::fs::write(dir.path().join("src/legacy.ts"), LEGACY_SOURCE).unwrap();
+    let base = commit(&repo, "drift", "initial");
+    // Edit the last fetch of save(), line 6.
+    let edited = LEGACY_SOURCE.replace("  fetch(url);\n  fetch(url);", "  fetch(url);\n  fetch(other);");
+    std::fs::write(dir.path().join("src/legacy.ts"), &edited).unwrap();
+    let changed = ChangedLines::from_git(dir.path(), &base, None).unwrap();
+
+    let pr = ParserManager::new().parse(edited.as_bytes(), std::path::Path::new("src/legacy.ts")).unwrap();
+    let fetches: Vec<u32> = pr.call_sites.iter().filter(|c| c.callee_name == "fetch").map(|c| c.line).collect();
+    assert_eq!(fetches, vec![1, 4, 5]);
+    let violation = |line: u32, file_level: bool| Violation {
+        id: format!("v-{line}"),
+        file: "src/legacy.ts".to_string(),
+        line,
+        column: None,
+        end_line: None,
+        end_column: None,
+        severity: Severity::Error,
+        pattern_id: "error-handling".to_string(),
+        rule_id: "pattern-compliance/error-handling".to_string(),
+        message: "Missing try/catch".to_string(),
+        quick_fix: None,
+        cwe_id: None,
+        owasp_category: None,
+        suppressed: false,
+        is_new: false,
+        owners: Vec::new(),
+        last_author: None,
+        file_level,
+    };
+
+    let filter = DiffScopeFilter::new(DiffScope::NewCode, changed);
+    let kept: Vec<u32> = fetches.iter().filter(|&&row| filter.in_scope(&violation(row, false))).copied().collect();
+    assert_eq!(kept, vec![5]);
+    // Row 0 is the first line, not "no line".
+    assert!(!filter.in_scope(&violation(0, false)));
+    assert!(filter.in_scope(&violation(0, true)));
+    let mut other = violation(0, true);
+    other.file = "src/other.ts".to_string();
+    assert!(!filter.in_scope(&other));
+}
+
+/// T6-GAT-16: Fingerprints read violation rows from a real parse as 0-based,
+/// finding the function and source line the parser reported.
+#[test]
+fn test_baseline_fingerprint_parsed_rows() {
+    use drift_analysis::enforcement::baseline::{fingerprint_violations, FingerprintContext};
+    use drift_analysis::parsers::manager::ParserManager;
+
+    let pr = ParserManager::new().parse(LEGACY_SOURCE.as_bytes(), std::path::Path::new("src/legacy.ts")).unwrap();
+    let mut context = FingerprintContext::new();
+    context.add_parse_result(&pr);
+    context.add_source(&pr.file, LEGACY_SOURCE);
+
+    let rows: Vec<u32> = pr.call_sites.iter().filter(|c| c.callee_name == "fetch").map(|c| c.line).collect();
+    assert_eq!(rows, vec![1, 4, 5]);
+    let functions: Vec<Option<&str>> = rows.iter().map(|&row| context.enclosing_function(&pr.file, row)).collect();
+    assert_eq!(functions, vec![Some("load"), Some("save"), Some("save")]);
+    for &row in &rows {
+        assert_eq!(context.snippet(&pr.file, row, None).as_deref(), Some("fetch(url);"));
+    }
+    // The declaration row belongs to its function; the first row has a snippet.
+    let save = pr.functions.iter().find(|f| f.name == "save").unwrap();
+    assert_eq!(context.enclosing_function(&pr.file, save.line), Some("save"));
+    assert_eq!(context.snippet(&pr.file, 0, None).as_deref(), Some("function load() {"));
+    assert_eq!(context.snippet(&pr.file, 7, None), None);
+
+    let violation = |line: u32| Violation {
+        id: format!("v-{line}"),
+        file: pr.file.clone(),
+        line,
+        column: None,
+        end_line: None,
+        end_column: None,
+        severity: Severity::Error,
+        pattern_id: "error-handling".to_string(),
+        rule_id: "pattern-compliance/error-handling".to_string(),
+        message: "Missing try/catch".to_string(),
+        quick_fix: None,
+        cwe_id: None,
+        owasp_category: None,
+        suppressed: false,
+        is_new: false,
+        owners: Vec::new(),
+        last_author: None,
+        file_level: false,
+    };
+    let violations: Vec<Violation> = rows.iter().map(|&row| violation(row)).collect();
+    let fps = fingerprint_violations(&violations.iter().collect::<Vec<_>>(), &context);
+    let names: Vec<Option<&str>> = fps.iter().map(|f| f.function.as_deref()).collect();
+    assert_eq!(names, vec![Some("load"), Some("save"), Some("save")]);
+}
@@ -25,6 +25,7 @@ fn make_node(file: &str, name: &str, exported: bool) -> FunctionNode {
         is_exported: exported,
         signature_hash: 0,
         body_hash: 0,
+        complexity: Default::default(),
     }
 }
 
@@ -65,6 +66,7 @@ fn make_function(name: &str, line: u32, end_line: u32) -> FunctionInfo {
         doc_comment: None,
         body_hash: 0,
         signature_hash: 0,
+        complexity: Default::default(),
     }
 }
 
@@ -88,6 +90,7 @@ fn test_all_systems_performance_10k() {
             is_exported: i % 10 == 0,
             signature_hash: 0,
             body_hash: 0,
+            complexity: Default::default(),
         });
         g.add_edge(prev, node, make_edge());
         // Add some cross-edges for realism
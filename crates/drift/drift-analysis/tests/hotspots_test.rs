@@ -0,0 +1,206 @@
+//! Complexity hotspot tests (T5-HOT-01 through T5-HOT-05).
+
+use std::path::Path;
+
+use drift_analysis::parsers::manager::ParserManager;
+use drift_analysis::parsers::types::ComplexityMetrics;
+use drift_analysis::structural::hotspots::*;
+
+mod common;
+use common::{commit_at, write_file};
+
+fn measured(file: &str, name: &str, line: u32, cyclomatic: u32, cognitive: u32) -> FunctionComplexity {
+    FunctionComplexity {
+        file: file.into(),
+        name: name.into(),
+        line,
+        complexity: ComplexityMetrics { cyclomatic, cognitive, max_nesting: 1, parameter_count: 0 },
+    }
+}
+
+/// T5-HOT-01: Files are ranked by summed cognitive complexity, with the
+/// function driving each file's score.
+#[test]
+fn test_hotspots_rank_files() {
+    let report = compute_hotspots(&[
+        measured("src/a.ts", "small", 1, 2, 1),
+        measured("src/b.ts", "parse", 3, 12, 20),
+        measured("src/b.ts", "emit", 40, 4, 3),
+        measured("src/c.ts", "render", 1, 6, 8),
+        // Unmeasured functions are ignored.
+        FunctionComplexity { file: "src/d.ts".into(), name: "stub".into(), line: 1, complexity: ComplexityMetrics::default() },
+    ]);
+
+    assert_eq!(report.function_count, 4);
+    let files: Vec<&str> = report.files.iter().map(|h| h.file.as_str()).collect();
+    assert_eq!(files, vec!["src/b.ts", "src/c.ts", "src/a.ts"]);
+
+    let b = &report.files[0];
+    assert_eq!((b.function_count, b.total_cyclomatic, b.max_cyclomatic), (2, 16, 12));
+    assert_eq!((b.total_cognitive, b.max_cognitive), (23, 20));
+    assert_eq!((b.hottest_function.as_str(), b.hottest_line), ("parse", 3));
+    assert_eq!(report.top(1).len(), 1);
+}
+
+/// T5-HOT-02: The report covers functions and class methods from parsing.
+#[test]
+fn test_hotspots_from_parse_results() {
+    let parser = ParserManager::new();
+    let pr = parser.parse(br#"
+export function route(req) {
+  if (req.a && req.b) { return 1; }
+  return 0;
+}
+export class Service {
+  handle(x) {
+    for (const y of x) {
+      if (y) { continue; }
+    }
+  }
+}
+"#, Path::new("src/app.ts")).unwrap();
+
+    let functions = functions_from_parse_results(std::slice::from_ref(&pr));
+    let mut names: Vec<&str> = functions.iter().map(|f| f.name.as_str()).collect();
+    names.sort();
+    assert_eq!(names, vec!["handle", "route"]);
+
+    let report = compute_hotspots(&functions);
+    assert_eq!(report.files.len(), 1);
+    let app = &report.files[0];
+    // route: if + && = 2; handle: for + nested if = 3.
+    assert_eq!(app.total_cognitive, 5);
+    assert_eq!(app.hottest_function, "handle");
+}
+
+const DAY: i64 = 86_400;
+
+fn span(file: &str, name: &str, line: u32, end_line: u32) -> FunctionSpan {
+    FunctionSpan { file: file.into(), name: name.into(), line, end_line }
+}
+
+/// T5-HOT-03: Churn is counted per file and attributed to functions through
+/// line shifts and renames; deleted files are not reported.
+#[test]
+fn test_churn_from_git_history() {
+    let dir = tempfile::tempdir().unwrap();
+    let repo = git2::Repository::init(dir.path()).unwrap();
+    let root = dir.path();
+    let t0 = 1_700_000_000;
+
+    write_file(root, "src/app.ts", "export function a() {\n  return 1;\n}\nexport function b() {\n  return 2;\n}\n");
+    write_file(root, "src/util.ts", "export const x = 1;\n");
+    write_file(root, "src/old.ts", "export const y = 2;\n");
+    commit_at(&repo, "Alice", t0, "initial");
+    // Shifts both functions down two lines and edits b.
+    write_file(root, "src/app.ts", "import x from 'x';\n\nexport function a() {\n  return 1;\n}\nexport function b() {\n  return 3;\n}\n");
+    std::fs::remove_file(root.join("src/old.ts")).unwrap();
+    commit_at(&repo, "Bob", t0 + 10 * DAY, "edit b");
+    std::fs::rename(root.join("src/app.ts"), root.join("src/main.ts")).unwrap();
+    commit_at(&repo, "Alice", t0 + 20 * DAY, "rename");
+    write_file(root, "src/main.ts", "import x from 'x';\n\nexport function a() {\n  return 1;\n}\nexport function b() {\n  return 4;\n}\n");
+    commit_at(&repo, "Alice", t0 + 30 * DAY, "edit b again");
+
+    let spans = [span("src/main.ts", "a", 3, 5), span("src/main.ts", "b", 6, 8)];
+    let report = ChurnAnalyzer::new().analyze(root, &spans).unwrap();
+    assert_eq!(report.commits_analyzed, 4);
+    assert!(report.head_commit.is_some());
+
+    let files: Vec<&str> = report.files.iter().map(|f| f.file.as_str()).collect();
+    assert_eq!(files, vec!["src/main.ts", "src/util.ts"]);
+    let main = &report.files[0];
+    assert_eq!(main.commits, 4);
+    assert_eq!((main.lines_added, main.lines_removed), (10, 2));
+    assert_eq!(main.authors, 2);
+    assert_eq!(main.last_changed, t0 + 30 * DAY);
+
+    let function = |name: &str| report.functions.iter().find(|f| f.name == name).unwrap();
+    assert_eq!(function("b").commits, 3);
+    assert_eq!(function("a").commits, 1);
+    // The latest commit carries full weight; a 30-day-old one is decayed.
+    let expected_b = 1.0 + 0.5f64.powf(20.0 / 90.0) + 0.5f64.powf(30.0 / 90.0);
+    assert!((function("b").recency_weighted - expected_b).abs() < 1e-9);
+
+    let frequencies = report.change_frequencies();
+    assert_eq!(frequencies.get("src/main.ts", "b"), 1.0);
+    assert!(frequencies.get("src/main.ts", "a") < 1.0);
+    // Attributed files score untouched functions as 0; others use the file.
+    assert_eq!(frequencies.get("src/main.ts", "c"), 0.0);
+    let util = frequencies.get("src/util.ts", "anything");
+    assert!(util > 0.0 && util < 1.0);
+
+    let latest = ChurnAnalyzer::new().with_max_commits(1).analyze(root, &spans).unwrap();
+    assert_eq!(latest.commits_analyzed, 1);
+    assert_eq!(latest.files.len(), 1);
+    assert_eq!(latest.functions.len(), 1);
+}
+
+/// T5-HOT-05: Spans built from parse results attribute edits to a
+/// function's first and last lines.
+#[test]
+fn test_churn_spans_from_parse_results() {
+    let dir = tempfile::tempdir().unwrap();
+    let repo = git2::Repository::init(dir.path()).unwrap();
+    let root = dir.path();
+    let t0 = 1_700_000_000;
+    let source = |a_end: &str, b_end: &str| {
+        format!("export function a() {{\n  return 1;\n}}{a_end}\nexport class Box {{\n  b() {{\n    return 2;\n  }}{b_end}\n}}\n")
+    };
+
+    write_file(root, "src/app.ts", &source("", ""));
+    commit_at(&repo, "Alice", t0, "initial");
+    write_file(root, "src/app.ts", &source(" // a", ""));
+    commit_at(&repo, "Alice", t0 + DAY, "edit the end of a");
+    write_file(root, "src/app.ts", &source(" // a", " // b"));
+    commit_at(&repo, "Alice", t0 + 2 * DAY, "edit the end of b");
+
+    let pr = ParserManager::new().parse(source(" // a", " // b").as_bytes(), Path::new("src/app.ts")).unwrap();
+    let spans = spans_from_parse_results(std::slice::from_ref(&pr));
+    let mut lines: Vec<(&str, u32, u32)> = spans.iter().map(|s| (s.name.as_str(), s.line, s.end_line)).collect();
+    lines.sort();
+    // The TypeScript parser also lists methods among the functions.
+    assert_eq!(lines, vec![("Box.b", 5, 7), ("a", 1, 3), ("b", 5, 7)]);
+
+    let report = ChurnAnalyzer::new().analyze(root, &spans).unwrap();
+    let commits = |name: &str| report.functions.iter().find(|f| f.name == name).map_or(0, |f| f.commits);
+    assert_eq!(commits("a"), 2);
+    assert_eq!(commits("Box.b"), 2);
+}
+
+/// T5-HOT-04: Risk hotspots need both churn and complexity; coupling
+/// amplifies the score and trends compare with the previous run.
+#[test]
+fn test_rank_risk_hotspots() {
+    let complexity = compute_hotspots(&[
+        measured("src/a.ts", "parse", 1, 10, 20),
+        measured("src/b.ts", "emit", 1, 10, 20),
+        measured("src/c.ts", "render", 1, 4, 5),
+        measured("src/d.ts", "stable", 1, 10, 40),
+    ]);
+    let file = |file: &str, recency_weighted: f64| FileChurn {
+        file: file.into(),
+        commits: 3,
+        lines_added: 10,
+        lines_removed: 2,
+        authors: 2,
+        last_changed: 0,
+        recency_weighted,
+    };
+    let churn = ChurnReport {
+        files: vec![file("src/a.ts", 4.0), file("src/b.ts", 4.0), file("src/c.ts", 2.0), file("src/e.ts", 8.0)],
+        ..ChurnReport::default()
+    };
+    let coupling = [("src/a.ts".to_string(), 4)].into_iter().collect();
+    let previous = [("src/a.ts".to_string(), 0.1), ("src/b.ts".to_string(), 0.25)].into_iter().collect();
+
+    let hotspots = rank_risk_hotspots(&complexity, &churn, &coupling, &previous);
+    let files: Vec<&str> = hotspots.iter().map(|h| h.file.as_str()).collect();
+    // d has no churn and e has no measured complexity.
+    assert_eq!(files, vec!["src/a.ts", "src/b.ts", "src/c.ts"]);
+    // a and b differ only in coupling.
+    assert!((hotspots[0].score - 0.5).abs() < 1e-9);
+    assert!((hotspots[1].score - 0.25).abs() < 1e-9);
+    assert_eq!(hotspots[0].trend, HotspotTrend::Rising);
+    assert_eq!(hotspots[1].trend, HotspotTrend::Stable);
+    assert_eq!(hotspots[2].trend, HotspotTrend::New);
+}
@@ -1,5 +1,5 @@
 #![allow(unused_variables)]
-//! T4-IMP-01 through T4-IMP-05: Impact analysis tests.
+//! T4-IMP-01 through T4-IMP-07: Impact analysis tests.
 
 use drift_analysis::call_graph::types::{CallEdge, CallGraph, FunctionNode, Resolution};
 use drift_analysis::graph::impact::*;
@@ -16,6 +16,7 @@ fn make_node(file: &str, name: &str, exported: bool) -> FunctionNode {
         is_exported: exported,
         signature_hash: 0,
         body_hash: 0,
+        complexity: Default::default(),
     }
 }
 
@@ -259,3 +260,70 @@ fn test_compute_all_blast_radii() {
     let c_radius = radii.iter().find(|r| r.function_id == c).unwrap();
     assert_eq!(c_radius.caller_count, 2);
 }
+
+// T4-IMP-06: Measured complexity drives the risk score's complexity factor
+#[test]
+fn test_blast_radius_uses_measured_complexity() {
+    use drift_analysis::parsers::types::ComplexityMetrics;
+
+    let mut g = CallGraph::new();
+    let mut simple = make_node("a.ts", "simple", true);
+    simple.complexity = ComplexityMetrics { cyclomatic: 1, cognitive: 0, max_nesting: 0, parameter_count: 1 };
+    let mut tangled = make_node("b.ts", "tangled", true);
+    tangled.complexity = ComplexityMetrics { cyclomatic: 18, cognitive: 30, max_nesting: 4, parameter_count: 3 };
+    // Unmeasured: falls back to the 9-line span.
+    let unmeasured = make_node("c.ts", "unmeasured", true);
+    let simple = g.add_function(simple);
+    let tangled = g.add_function(tangled);
+    let unmeasured = g.add_function(unmeasured);
+
+    let complexity = |idx| compute_blast_radius(&g, idx, 10).risk_score.complexity;
+    assert_eq!(complexity(simple), 0.0);
+    assert_eq!(complexity(tangled), 1.0);
+    assert!((complexity(unmeasured) - 0.18).abs() < 1e-6);
+}
+
+// T4-IMP-07: Git churn feeds the change frequency factor
+#[test]
+fn test_blast_radii_with_churn() {
+    use drift_analysis::structural::hotspots::{ChurnReport, FileChurn, FunctionChurn};
+
+    let mut g = CallGraph::new();
+    let hot = g.add_function(make_node("/repo/a.ts", "hot", true));
+    let cold = g.add_function(make_node("/repo/a.ts", "cold", true));
+    let whole_file = g.add_function(make_node("/repo/b.ts", "whole", true));
+    let untracked = g.add_function(make_node("/repo/c.ts", "untracked", true));
+
+    let file = |file: &str, recency_weighted: f64| FileChurn {
+        file: file.into(),
+        commits: 2,
+        lines_added: 5,
+        lines_removed: 1,
+        authors: 1,
+        last_changed: 0,
+        recency_weighted,
+    };
+    let churn = ChurnReport {
+        files: vec![file("a.ts", 4.0), file("b.ts", 2.0)],
+        functions: vec![FunctionChurn {
+            file: "a.ts".into(),
+            name: "hot".into(),
+            line: 1,
+            commits: 2,
+            lines_changed: 6,
+            recency_weighted: 1.5,
+        }],
+        root: "/repo".into(),
+        ..ChurnReport::default()
+    };
+
+    let radii = blast_radius::compute_all_blast_radii_with_churn(&g, &churn);
+    let frequency = |idx| radii.iter().find(|r| r.function_id == idx).unwrap().risk_score.change_frequency;
+    assert_eq!(frequency(hot), 1.0);
+    assert_eq!(frequency(cold), 0.0);
+    assert_eq!(frequency(whole_file), 0.5);
+    assert_eq!(frequency(untracked), 0.0);
+
+    let plain = blast_radius::compute_all_blast_radii(&g);
+    assert!(plain.iter().all(|r| r.risk_score.change_frequency == 0.0));
+}
@@ -133,6 +133,9 @@ function processData_{i}(input: string): string {{
                 is_async: f.is_async,
                 body_hash: f.body_hash.to_le_bytes().to_vec(),
                 signature_hash: f.signature_hash.to_le_bytes().to_vec(),
+                cyclomatic: 0,
+                cognitive: 0,
+                max_nesting: 0,
             })
             .collect();
 
@@ -595,6 +598,9 @@ export function handler_{i}(req: any) {{
                 is_async: f.is_async,
                 body_hash: f.body_hash.to_le_bytes().to_vec(),
                 signature_hash: f.signature_hash.to_le_bytes().to_vec(),
+                cyclomatic: 0,
+                cognitive: 0,
+                max_nesting: 0,
             })
             .collect();
         if !func_rows.is_empty() {
@@ -713,6 +719,9 @@ export function getUser(id: string) {
             is_async: f.is_async,
             body_hash: f.body_hash.to_le_bytes().to_vec(),
             signature_hash: f.signature_hash.to_le_bytes().to_vec(),
+            cyclomatic: 0,
+            cognitive: 0,
+            max_nesting: 0,
         })
         .collect();
 
@@ -1,5 +1,5 @@
 #![allow(unused_imports, unused_variables, clippy::needless_range_loop)]
-//! Language Provider tests — T2-ULP-01 through T2-ULP-03.
+//! Language Provider tests — T2-ULP-01 through T2-ULP-04.
 //!
 //! Tests for the Unified Language Provider: cross-language normalization,
 //! framework matchers, taint sink extraction.
@@ -231,3 +231,20 @@ fn t2_ulp_03_taint_sink_extraction() {
         "should have SQL or Command execution category"
     );
 }
+
+// ---- T2-ULP-04: C/C++ sinks taint the argument each attack goes through ----
+
+#[test]
+fn t2_ulp_04_c_cpp_sink_arguments() {
+    for lang in [Language::C, Language::Cpp] {
+        let sinks = extract_sinks(lang);
+        let sink = |name: &str| sinks.iter().find(|s| s.name == name).unwrap();
+        // File writes carry the written content: fwrite(ptr, size, nmemb, stream).
+        assert_eq!(sink("fwrite").category, SinkCategory::FileWrite);
+        assert_eq!(sink("fwrite").tainted_params, vec![0]);
+        // Format strings: printf(fmt, ...) and sprintf(buf, fmt, ...).
+        assert_eq!(sink("printf").tainted_params, vec![0]);
+        assert_eq!(sink("sprintf").tainted_params, vec![1, 2]);
+        assert_eq!(sink("strcpy").tainted_params, vec![1]);
+    }
+}
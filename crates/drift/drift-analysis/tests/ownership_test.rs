@@ -0,0 +1,228 @@
+//! Code ownership tests (T6-OWN-01 through T6-OWN-05).
+
+use std::path::Path;
+
+use drift_analysis::detectors::registry::create_default_registry;
+use drift_analysis::engine::visitor::DetectionContext;
+use drift_analysis::enforcement::gates::*;
+use drift_analysis::enforcement::ownership::*;
+use drift_analysis::enforcement::rules::*;
+use drift_analysis::parsers::manager::ParserManager;
+
+mod common;
+use common::{commit, write_file};
+
+fn violation(id: &str, file: &str, line: u32, severity: Severity) -> Violation {
+    Violation {
+        id: id.to_string(),
+        file: file.to_string(),
+        line,
+        column: None,
+        end_line: None,
+        end_column: None,
+        severity,
+        pattern_id: "p".to_string(),
+        rule_id: "r".to_string(),
+        message: "m".to_string(),
+        quick_fix: None,
+        cwe_id: None,
+        owasp_category: None,
+        suppressed: false,
+        is_new: false,
+        owners: Vec::new(),
+        last_author: None,
+        file_level: false,
+    }
+}
+
+fn failed(violations: Vec<Violation>) -> GateResult {
+    GateResult::fail(GateId::PatternCompliance, 50.0, "failed".to_string(), violations)
+}
+
+/// T6-OWN-01: GitHub CODEOWNERS follows gitignore matching and the last
+/// matching rule wins.
+#[test]
+fn test_codeowners_github_syntax() {
+    let owners = CodeOwners::parse(
+        r#"
+# Default owners
+*                   @acme/core
+*.ts                @acme/frontend   # inline comment
+/apps/              @acme/apps
+docs/*              docs@acme.com
+/build/logs/
+src/payments/**     @acme/payments @alice
+My\ Folder/         @bob
+"#,
+    );
+    assert_eq!(owners.rule_count(), 7);
+    assert!(owners.errors.is_empty());
+
+    assert_eq!(owners.owners_of("README.md"), vec!["@acme/core"]);
+    // Unanchored patterns match at any depth.
+    assert_eq!(owners.owners_of("lib/deep/util.ts"), vec!["@acme/frontend"]);
+    // A directory owns everything below it; later rules override earlier ones.
+    assert_eq!(owners.owners_of("apps/web/index.ts"), vec!["@acme/apps"]);
+    assert_eq!(owners.owners_of("./apps/web/index.ts"), vec!["@acme/apps"]);
+    // `docs/*` owns the files in docs, not the ones further down.
+    assert_eq!(owners.owners_of("docs/intro.md"), vec!["docs@acme.com"]);
+    assert_eq!(owners.owners_of("docs/api/auth.md"), vec!["@acme/core"]);
+    // A rule without owners clears ownership.
+    assert!(owners.owners_of("build/logs/out.log").is_empty());
+    assert_eq!(owners.owners_of("src/payments/charge/card.ts"), vec!["@acme/payments", "@alice"]);
+    assert_eq!(owners.owners_of("My Folder/notes.txt"), vec!["@bob"]);
+
+    assert!(CodeOwners::parse("# only comments\n").is_empty());
+}
+
+/// T6-OWN-02: GitLab sections are independent; rules without owners take
+/// the section's defaults.
+#[test]
+fn test_codeowners_gitlab_sections() {
+    let owners = CodeOwners::parse(
+        r#"
+*.rb @ruby-team
+
+[Documentation] @docs-team
+docs/
+README.md @alice
+
+^[Database][2] @dba
+*.sql
+db/migrate/ @bob
+"#,
+    );
+    assert!(owners.errors.is_empty());
+    assert_eq!(owners.rule_count(), 5);
+
+    assert_eq!(owners.owners_of("docs/guide.md"), vec!["@docs-team"]);
+    assert_eq!(owners.owners_of("README.md"), vec!["@alice"]);
+    // Owners accumulate across sections.
+    assert_eq!(owners.owners_of("db/migrate/001.rb"), vec!["@ruby-team", "@bob"]);
+    assert_eq!(owners.owners_of("db/schema.sql"), vec!["@dba"]);
+    assert!(owners.owners_of("src/main.go").is_empty());
+
+    // Found in the standard locations, patterns relative to the root.
+    let dir = tempfile::tempdir().unwrap();
+    assert!(CodeOwners::load(dir.path()).unwrap().is_none());
+    std::fs::create_dir_all(dir.path().join(".gitlab")).unwrap();
+    std::fs::write(dir.path().join(".gitlab/CODEOWNERS"), "/src/ @core\n").unwrap();
+    let loaded = CodeOwners::load(dir.path()).unwrap().unwrap();
+    assert!(loaded.path.as_ref().unwrap().ends_with(".gitlab/CODEOWNERS"));
+    let absolute = dir.path().join("src/lib.rs").to_string_lossy().to_string();
+    assert_eq!(loaded.owners_of(&absolute), vec!["@core"]);
+}
+
+/// T6-OWN-03: The resolver attaches CODEOWNERS owners and the last author
+/// of each violating line (0-based) from git blame.
+#[test]
+fn test_attribute_owners_and_blame() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    let repo = git2::Repository::init(root).unwrap();
+    write_file(root, "CODEOWNERS", "/src/ @acme/core\n");
+    write_file(root, "src/app.ts", "const a = 1;\nconst b = 2;\n");
+    commit(&repo, "Alice", "initial");
+    write_file(root, "src/app.ts", "const a = 1;\nconst b = 3;\nconst c = 4;\n");
+    commit(&repo, "Bob", "edit");
+    write_file(root, "src/new.ts", "uncommitted\n");
+
+    let mut resolver = OwnershipResolver::new()
+        .with_codeowners(CodeOwners::load(root).unwrap().unwrap())
+        .with_blame(Blamer::open(root).unwrap());
+    assert!(!resolver.is_empty());
+    let mut results = vec![failed(vec![
+        violation("v1", "src/app.ts", 0, Severity::Error),
+        violation("v2", &root.join("src/app.ts").to_string_lossy(), 2, Severity::Error),
+        violation("v3", "src/new.ts", 0, Severity::Warning),
+        violation("v4", "README.md", 0, Severity::Warning),
+    ])];
+    assert!(!has_ownership(&results));
+    resolver.attribute_results(&mut results);
+
+    let violations = &results[0].violations;
+    assert_eq!(violations[0].owners, vec!["@acme/core"]);
+    assert_eq!(violations[0].last_author.as_deref(), Some("Alice"));
+    assert_eq!(violations[1].owners, vec!["@acme/core"]);
+    assert_eq!(violations[1].last_author.as_deref(), Some("Bob"));
+    // Untracked files have no blame.
+    assert_eq!(violations[2].owners, vec!["@acme/core"]);
+    assert_eq!(violations[2].last_author, None);
+    assert!(violations[3].owners.is_empty());
+    assert!(has_ownership(&results));
+
+    assert!(Blamer::open(tempfile::tempdir().unwrap().path()).is_err());
+}
+
+/// T6-OWN-05: Blame lands on the line a detector reported, not the one
+/// after it.
+#[test]
+fn test_blame_detector_violation() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    let repo = git2::Repository::init(root).unwrap();
+    write_file(root, "src/app.ts", "export function ok() { return 1; }\n");
+    commit(&repo, "Alice", "initial");
+    let source = "export function ok() { return 1; }\nexport function run(x: string) { return eval(x); }\nexport const done = true;\n";
+    write_file(root, "src/app.ts", source);
+    commit(&repo, "Bob", "add run");
+    write_file(root, "src/app.ts", &format!("{source}export const later = true;\n"));
+    commit(&repo, "Carol", "add later");
+
+    let pr = ParserManager::new().parse(source.as_bytes(), Path::new("src/app.ts")).unwrap();
+    let matches = create_default_registry().run_all(&DetectionContext::from_parse_result(&pr, source.as_bytes()));
+    let eval = matches.iter().find(|m| m.pattern_id == "SEC-EVAL-001").unwrap();
+    let pattern = PatternInfo {
+        pattern_id: eval.pattern_id.clone(),
+        category: "security".to_string(),
+        confidence: eval.confidence as f64,
+        locations: vec![],
+        outliers: vec![OutlierLocation {
+            file: eval.file.clone(),
+            line: eval.line,
+            column: Some(eval.column),
+            deviation_score: 3.0,
+            message: eval.matched_text.clone(),
+            ..OutlierLocation::default()
+        }],
+        cwe_ids: eval.cwe_ids.iter().copied().filter(|c| *c != 0).collect(),
+        owasp_categories: vec![],
+    };
+    let violations = RulesEvaluator::new().evaluate(&RulesInput { patterns: vec![pattern], ..RulesInput::default() });
+    let mut results = vec![failed(violations)];
+
+    let mut resolver = OwnershipResolver::new().with_blame(Blamer::open(root).unwrap());
+    resolver.attribute_results(&mut results);
+    let violation = &results[0].violations[0];
+    assert_eq!(violation.file, "src/app.ts");
+    assert_eq!(violation.last_author.as_deref(), Some("Bob"));
+}
+
+/// T6-OWN-04: Violations group by owner, most errors first; shared files
+/// count for every owner and suppressed violations are left out.
+#[test]
+fn test_group_by_owner() {
+    let owned = |id: &str, severity: Severity, owners: &[&str]| {
+        let mut v = violation(id, "src/x.ts", 1, severity);
+        v.owners = owners.iter().map(|o| o.to_string()).collect();
+        v
+    };
+    let mut suppressed = owned("v5", Severity::Error, &["@web"]);
+    suppressed.suppressed = true;
+    let results = vec![failed(vec![
+        owned("v1", Severity::Warning, &["@web"]),
+        owned("v2", Severity::Error, &["@api", "@web"]),
+        owned("v3", Severity::Error, &["@api"]),
+        owned("v4", Severity::Info, &[]),
+        suppressed,
+    ])];
+
+    let groups = group_by_owner(&results);
+    let summaries: Vec<(&str, usize, usize, usize)> = groups
+        .iter()
+        .map(|(s, _)| (s.owner.as_str(), s.errors, s.warnings, s.total))
+        .collect();
+    assert_eq!(summaries, vec![("@api", 2, 0, 2), ("@web", 1, 1, 2), (UNOWNED, 0, 0, 1)]);
+    let web: Vec<&str> = groups[1].1.iter().map(|v| v.id.as_str()).collect();
+    assert_eq!(web, vec!["v1", "v2"]);
+}
@@ -237,6 +237,7 @@ fn make_parse_result(file: &str, functions: Vec<(&str, u32, u32, bool)>, calls:
             doc_comment: None,
             body_hash: 0,
             signature_hash: 0,
+            complexity: Default::default(),
         }).collect(),
         classes: Vec::new(),
         imports: imports.iter().map(|src| ImportInfo {
@@ -266,6 +267,7 @@ fn make_parse_result(file: &str, functions: Vec<(&str, u32, u32, bool)>, calls:
         error_count: 0,
         error_ranges: Vec::new(),
         has_errors: false,
+        type_bindings: Vec::new(),
     }
 }
 
@@ -332,6 +334,7 @@ fn stress_call_graph_100_files_1000_functions() {
                     doc_comment: None,
                     body_hash: 0,
                     signature_hash: 0,
+                    complexity: Default::default(),
                 });
             }
             pr
@@ -362,6 +365,7 @@ fn stress_call_graph_node_dedup() {
         is_exported: true,
         signature_hash: 0,
         body_hash: 0,
+        complexity: Default::default(),
     };
 
     let idx1 = graph.add_function(node.clone());
@@ -385,6 +389,7 @@ fn stress_call_graph_remove_file() {
             is_exported: false,
             signature_hash: 0,
             body_hash: 0,
+            complexity: Default::default(),
         });
     }
     assert_eq!(graph.function_count(), 10);
@@ -396,12 +401,14 @@ fn stress_call_graph_remove_file() {
 
 #[test]
 fn stress_call_graph_resolution_strategies() {
-    // Verify all 6 resolution strategies have correct confidence values
+    // Verify all 8 resolution strategies have correct confidence values
     let strategies = Resolution::all_ordered();
-    assert_eq!(strategies.len(), 6);
+    assert_eq!(strategies.len(), 8);
 
     assert_eq!(Resolution::SameFile.default_confidence(), 0.95);
+    assert_eq!(Resolution::TypedReceiver.default_confidence(), 0.92);
     assert_eq!(Resolution::MethodCall.default_confidence(), 0.90);
+    assert_eq!(Resolution::ManifestImport.default_confidence(), 0.85);
     assert_eq!(Resolution::DiInjection.default_confidence(), 0.80);
     assert_eq!(Resolution::ImportBased.default_confidence(), 0.75);
     assert_eq!(Resolution::ExportBased.default_confidence(), 0.60);
@@ -421,11 +428,13 @@ fn stress_call_graph_edge_management() {
         file: "a.ts".into(), name: "caller".into(), qualified_name: None,
         language: "typescript".into(), line: 1, end_line: 20,
         is_entry_point: false, is_exported: true, signature_hash: 0, body_hash: 0,
+        complexity: Default::default(),
     });
     let n2 = graph.add_function(FunctionNode {
         file: "a.ts".into(), name: "callee".into(), qualified_name: None,
         language: "typescript".into(), line: 25, end_line: 40,
         is_entry_point: false, is_exported: false, signature_hash: 0, body_hash: 0,
+        complexity: Default::default(),
     });
 
     graph.add_edge(n1, n2, CallEdge {
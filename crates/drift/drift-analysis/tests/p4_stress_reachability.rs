@@ -21,6 +21,7 @@ fn node(file: &str, name: &str, exported: bool) -> FunctionNode {
         file: file.to_string(), name: name.to_string(), qualified_name: None,
         language: "typescript".to_string(), line: 1, end_line: 10,
         is_entry_point: false, is_exported: exported, signature_hash: 0, body_hash: 0,
+        complexity: Default::default(),
     }
 }
 
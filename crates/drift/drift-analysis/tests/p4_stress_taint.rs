@@ -32,6 +32,7 @@ fn func(name: &str, line: u32, end_line: u32) -> FunctionInfo {
         visibility: Visibility::Public, is_exported: true, is_async: false,
         is_generator: false, is_abstract: false, range: Range::default(),
         decorators: Vec::new(), doc_comment: None, body_hash: 0, signature_hash: 0,
+        complexity: Default::default(),
     }
 }
 
@@ -58,6 +59,7 @@ fn full_pr(file: &str, functions: Vec<FunctionInfo>, calls: Vec<CallSite>) -> Pa
         numeric_literals: Vec::new(), error_handling: Vec::new(), doc_comments: Vec::new(),
         namespace: None, parse_time_us: 0, error_count: 0, error_ranges: Vec::new(),
         has_errors: false,
+        type_bindings: Vec::new(),
     }
 }
 
@@ -556,6 +558,7 @@ fn stress_inter_single_function_with_source_and_sink() {
         file: "handler.ts".into(), name: "handler".into(), qualified_name: None,
         language: "typescript".into(), line: 1, end_line: 20,
         is_entry_point: true, is_exported: true, signature_hash: 0, body_hash: 0,
+        complexity: Default::default(),
     });
     let p = full_pr("handler.ts",
         vec![func_with_params("handler", 1, 20, &["req"])],
@@ -573,11 +576,13 @@ fn stress_inter_mutual_recursion_terminates() {
         file: "a.ts".into(), name: "funcA".into(), qualified_name: None,
         language: "typescript".into(), line: 1, end_line: 10,
         is_entry_point: true, is_exported: true, signature_hash: 0, body_hash: 0,
+        complexity: Default::default(),
     });
     let b = g.add_function(FunctionNode {
         file: "b.ts".into(), name: "funcB".into(), qualified_name: None,
         language: "typescript".into(), line: 1, end_line: 10,
         is_entry_point: false, is_exported: false, signature_hash: 0, body_hash: 0,
+        complexity: Default::default(),
     });
     g.add_edge(a, b, edge());
     g.add_edge(b, a, edge());
@@ -601,6 +606,7 @@ fn stress_inter_path_too_long_error() {
             file: format!("f{i}.ts"), name: format!("func_{i}"), qualified_name: None,
             language: "typescript".into(), line: 1, end_line: 10,
             is_entry_point: i == 0, is_exported: i == 0, signature_hash: 0, body_hash: 0,
+            complexity: Default::default(),
         }));
     }
     for i in 0..99 { g.add_edge(nodes[i], nodes[i+1], edge()); }
@@ -732,14 +738,14 @@ fn stress_sarif_serializes_to_json() {
 // ═══════════════════════════════════════════════════════════════════════════
 
 #[test]
-fn stress_all_12_frameworks_apply_without_panic() {
+fn stress_all_19_frameworks_apply_without_panic() {
     for fw in TaintFramework::all() {
         let mut reg = TaintRegistry::new();
         apply_framework_specs(&mut reg, *fw);
         assert!(!reg.sources.is_empty() || !reg.sinks.is_empty() || !reg.sanitizers.is_empty(),
             "Framework {:?} should add at least one pattern", fw);
     }
-    assert_eq!(TaintFramework::all().len(), 12);
+    assert_eq!(TaintFramework::all().len(), 19);
 }
 
 #[test]
@@ -23,6 +23,7 @@ fn node(file: &str, name: &str, exported: bool) -> FunctionNode {
         file: file.to_string(), name: name.to_string(), qualified_name: None,
         language: "typescript".to_string(), line: 1, end_line: 10,
         is_entry_point: false, is_exported: exported, signature_hash: 0, body_hash: 0,
+        complexity: Default::default(),
     }
 }
 
@@ -38,6 +39,7 @@ fn func(name: &str, line: u32, end_line: u32) -> FunctionInfo {
         visibility: Visibility::Public, is_exported: true, is_async: false,
         is_generator: false, is_abstract: false, range: Range::default(),
         decorators: Vec::new(), doc_comment: None, body_hash: 0, signature_hash: 0,
+        complexity: Default::default(),
     }
 }
 
@@ -56,6 +58,7 @@ fn full_pr(file: &str, functions: Vec<FunctionInfo>, calls: Vec<CallSite>) -> Pa
         numeric_literals: Vec::new(), error_handling: Vec::new(), doc_comments: Vec::new(),
         namespace: None, parse_time_us: 0, error_count: 0, error_ranges: Vec::new(),
         has_errors: false,
+        type_bindings: Vec::new(),
     }
 }
 
@@ -1,8 +1,9 @@
-//! Parser tests — T1-PRS-01 through T1-PRS-15.
+//! Parser tests — T1-PRS-01 through T1-PRS-31.
 //!
 //! Tests cover: all 10 language parsers, parse cache, error tolerance,
 //! body/signature hashing, macro correctness, edge cases, thread safety,
-//! and Unicode source code.
+//! Unicode source code, complexity metrics, type bindings, class
+//! member decorators, Python base classes and parameter bindings.
 
 use std::path::Path;
 use std::sync::Arc;
@@ -437,6 +438,7 @@ fn t1_prs_14_cache_persistence_round_trip() {
         error_count: 0,
         error_ranges: vec![],
         has_errors: false,
+        type_bindings: Vec::new(),
     };
 
     let json = serde_json::to_string(&original).unwrap();
@@ -466,3 +468,609 @@ fn t1_prs_15_unicode_source_code() {
     let result2 = manager.parse(ts_source.as_bytes(), path2);
     assert!(result2.is_ok(), "TS parser should handle Unicode identifiers");
 }
+
+// ---- T1-PRS-16: C and C++ use their own grammars ----
+
+#[test]
+fn t1_prs_16_c_reference_fixture() {
+    let manager = ParserManager::new();
+    let path = fixture_path("c/reference.c");
+    let source = std::fs::read(&path).unwrap();
+    let pr = manager.parse(&source, &path).unwrap();
+
+    assert_eq!(pr.language, Language::C);
+    assert!(!pr.has_errors, "C fixture should parse without errors");
+
+    let names: Vec<&str> = pr.functions.iter().map(|f| f.name.as_str()).collect();
+    for expected in ["validate_input", "copy_name", "run_command", "sum_items", "main"] {
+        assert!(names.contains(&expected), "missing C function {expected}: {names:?}");
+    }
+    let validate = pr.functions.iter().find(|f| f.name == "validate_input").unwrap();
+    assert!(!validate.is_exported, "static functions are file-local");
+    assert_eq!(validate.parameters.len(), 1);
+    let main = pr.functions.iter().find(|f| f.name == "main").unwrap();
+    assert!(main.parameters.is_empty(), "(void) means no parameters");
+
+    assert!(pr.classes.iter().any(|c| c.name == "buffer"));
+
+    let includes: Vec<&str> = pr.imports.iter().map(|i| i.source.as_str()).collect();
+    assert_eq!(includes, vec!["stdio.h", "string.h", "config.h"]);
+
+    let callees: Vec<&str> = pr.call_sites.iter().map(|c| c.callee_name.as_str()).collect();
+    for expected in ["strcpy", "sprintf", "system", "validate_input", "copy_name"] {
+        assert!(callees.contains(&expected), "missing call to {expected}: {callees:?}");
+    }
+}
+
+#[test]
+fn t1_prs_17_cpp_reference_fixture() {
+    let manager = ParserManager::new();
+    let path = fixture_path("cpp/reference.cpp");
+    let source = std::fs::read(&path).unwrap();
+    let pr = manager.parse(&source, &path).unwrap();
+
+    assert_eq!(pr.language, Language::Cpp);
+    assert!(!pr.has_errors, "C++ fixture should parse without errors");
+    assert_eq!(pr.namespace.as_deref(), Some("app::service"));
+
+    let repo = pr.classes.iter().find(|c| c.name == "Repository").unwrap();
+    assert!(repo.is_abstract, "pure virtual method makes the class abstract");
+
+    let service = pr.classes.iter().find(|c| c.name == "UserService").unwrap();
+    assert_eq!(service.extends.as_deref(), Some("Repository"));
+    let method_names: Vec<&str> = service.methods.iter().map(|m| m.name.as_str()).collect();
+    for expected in ["UserService", "find", "save", "lookup"] {
+        assert!(method_names.contains(&expected), "missing method {expected}: {method_names:?}");
+    }
+    let lookup = service.methods.iter().find(|m| m.name == "lookup").unwrap();
+    assert!(!lookup.is_exported, "private: section should not be exported");
+    assert_eq!(lookup.qualified_name.as_deref(), Some("UserService.lookup"));
+
+    assert!(pr.classes.iter().any(|c| c.name == "Point"));
+    let clamp = pr.functions.iter().find(|f| f.name == "clamp").unwrap();
+    assert_eq!(clamp.generic_params.len(), 1);
+
+    let includes: Vec<&str> = pr.imports.iter().map(|i| i.source.as_str()).collect();
+    assert!(includes.contains(&"service/repository.hpp"));
+
+    let push = pr.call_sites.iter().find(|c| c.callee_name == "push_back").unwrap();
+    assert_eq!(push.receiver.as_deref(), Some("names_"));
+
+    assert!(pr.error_handling.iter().any(|e| e.caught_type.as_deref() == Some("std::bad_alloc")));
+}
+
+#[test]
+fn t1_prs_18_cpp_out_of_line_methods() {
+    let manager = ParserManager::new();
+    let source = b"namespace net {\nvoid Server::stop() { close(fd_); }\nstatic void helper() {}\n}\n";
+    let pr = manager
+        .parse_with_language(source, Path::new("server.cc"), Language::Cpp)
+        .unwrap();
+
+    let stop = pr.functions.iter().find(|f| f.name == "stop").unwrap();
+    assert_eq!(stop.qualified_name.as_deref(), Some("Server.stop"));
+    let helper = pr.functions.iter().find(|f| f.name == "helper").unwrap();
+    assert!(!helper.is_exported);
+    assert!(pr.call_sites.iter().any(|c| c.callee_name == "close"));
+}
+
+#[test]
+fn t1_prs_19_c_cpp_queries_compile() {
+    use drift_analysis::parsers::queries;
+
+    for lang in [Language::C, Language::Cpp] {
+        let ts_lang = lang.ts_language();
+        tree_sitter::Query::new(&ts_lang, queries::structure_query_for(lang))
+            .unwrap_or_else(|e| panic!("{lang:?} structure query: {e}"));
+        tree_sitter::Query::new(&ts_lang, queries::calls_query_for(lang))
+            .unwrap_or_else(|e| panic!("{lang:?} calls query: {e}"));
+    }
+}
+
+#[test]
+fn t1_prs_20_cpp_gast_normalization() {
+    use drift_analysis::engine::gast::normalizers::normalizer_for;
+    use drift_analysis::engine::gast::types::GASTNode;
+
+    let source = b"int add(int a, int b) { return a + b; }\nclass Box : public Base { };\n";
+    let mut parser = tree_sitter::Parser::new();
+    parser.set_language(&Language::Cpp.ts_language()).unwrap();
+    let tree = parser.parse(source, None).unwrap();
+
+    let gast = normalizer_for(Language::Cpp).normalize(&tree, source);
+    let GASTNode::Program { body } = gast else { panic!("expected Program") };
+    match &body[0] {
+        GASTNode::Function { name, params, .. } => {
+            assert_eq!(name, "add");
+            assert_eq!(params.len(), 2);
+        }
+        other => panic!("expected Function, got {other:?}"),
+    }
+    assert!(body.iter().any(|n| matches!(n,
+        GASTNode::Class { name, bases, .. } if name == "Box" && bases == &["Base".to_string()])));
+}
+
+#[test]
+fn t1_prs_21_swift_reference_fixture() {
+    use drift_analysis::parsers::types::{ClassKind, ErrorHandlingKind};
+
+    let manager = ParserManager::new();
+    let path = fixture_path("swift/reference.swift");
+    let source = std::fs::read(&path).unwrap();
+    let pr = manager.parse(&source, &path).unwrap();
+
+    assert_eq!(pr.language, Language::Swift);
+    assert!(!pr.has_errors, "Swift fixture should parse without errors");
+
+    let repo = pr.classes.iter().find(|c| c.name == "Repository").unwrap();
+    assert_eq!(repo.class_kind, ClassKind::Interface);
+    let user = pr.classes.iter().find(|c| c.name == "User").unwrap();
+    assert_eq!(user.class_kind, ClassKind::Struct);
+    assert_eq!(user.implements.as_slice(), &["Codable".to_string()]);
+    assert!(pr.classes.iter().any(|c| c.name == "LoadError" && c.class_kind == ClassKind::Enum));
+
+    // The extension's `count` is merged into the class declared in the same file.
+    let service = pr.classes.iter().find(|c| c.name == "UserService").unwrap();
+    assert_eq!(pr.classes.iter().filter(|c| c.name == "UserService").count(), 1);
+    assert_eq!(service.extends.as_deref(), Some("Repository"));
+    let method_names: Vec<&str> = service.methods.iter().map(|m| m.name.as_str()).collect();
+    assert_eq!(method_names, vec!["init", "find", "save", "evict", "count"]);
+    let evict = service.methods.iter().find(|m| m.name == "evict").unwrap();
+    assert!(!evict.is_exported, "private methods should not be exported");
+
+    // Closures: the bound name when assigned, `<anonymous>` when passed inline.
+    assert!(pr.functions.iter().any(|f| f.name == "normalize" && f.parameters.len() == 1));
+    assert!(pr.functions.iter().any(|f| f.name == "<anonymous>"));
+
+    let imports: Vec<&str> = pr.imports.iter().map(|i| i.source.as_str()).collect();
+    assert_eq!(imports, vec!["Foundation", "SQLite3"]);
+
+    let exec = pr.call_sites.iter().find(|c| c.callee_name == "sqlite3_exec").unwrap();
+    assert_eq!(exec.argument_count, 5);
+    let decode = pr.call_sites.iter().find(|c| c.callee_name == "decode").unwrap();
+    assert_eq!(decode.receiver.as_deref(), Some("JSONDecoder()"));
+    let for_each = pr.call_sites.iter().find(|c| c.callee_name == "forEach").unwrap();
+    assert_eq!(for_each.argument_count, 1, "trailing closure counts as an argument");
+    assert!(!pr.call_sites.iter().any(|c| c.callee_name == "cache"), "subscripts are not calls");
+
+    assert!(pr.error_handling.iter().any(|e| e.kind == ErrorHandlingKind::TryCatch
+        && e.caught_type.as_deref() == Some("DecodingError")));
+    assert!(pr.error_handling.iter().any(|e| e.kind == ErrorHandlingKind::Throw));
+}
+
+#[test]
+fn t1_prs_22_swift_extension_of_external_type() {
+    let manager = ParserManager::new();
+    let source = b"extension String: Identifiable {\n    @inlinable public var id: String { self }\n    func shout() -> String { uppercased() }\n}\n";
+    let pr = manager
+        .parse_with_language(source, Path::new("String+Id.swift"), Language::Swift)
+        .unwrap();
+
+    let ext = pr.classes.iter().find(|c| c.name == "String").unwrap();
+    assert!(ext.extends.is_none(), "extensions cannot introduce a superclass");
+    assert_eq!(ext.implements.as_slice(), &["Identifiable".to_string()]);
+    let shout = ext.methods.iter().find(|m| m.name == "shout").unwrap();
+    assert_eq!(shout.qualified_name.as_deref(), Some("String.shout"));
+    assert_eq!(shout.return_type.as_deref(), Some("String"));
+}
+
+#[test]
+fn t1_prs_23_scala_reference_fixture() {
+    use drift_analysis::parsers::types::{ClassKind, ErrorHandlingKind};
+
+    let manager = ParserManager::new();
+    let path = fixture_path("scala/reference.scala");
+    let source = std::fs::read(&path).unwrap();
+    let pr = manager.parse(&source, &path).unwrap();
+
+    assert_eq!(pr.language, Language::Scala);
+    assert!(!pr.has_errors, "Scala fixture should parse without errors");
+    assert_eq!(pr.namespace.as_deref(), Some("app.service"));
+
+    let user = pr.classes.iter().find(|c| c.name == "User").unwrap();
+    assert_eq!(user.class_kind, ClassKind::Record, "case classes map to records");
+    let props: Vec<&str> = user.properties.iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(props, vec!["id", "name"]);
+
+    let repo = pr.classes.iter().find(|c| c.name == "Repository").unwrap();
+    assert_eq!(repo.class_kind, ClassKind::Trait);
+    assert!(repo.is_abstract);
+
+    let service = pr.classes.iter()
+        .find(|c| c.name == "UserService" && c.extends.is_some())
+        .unwrap();
+    assert_eq!(service.extends.as_deref(), Some("Repository"));
+    assert_eq!(service.implements.as_slice(), &["Logging".to_string()]);
+
+    // Curried and implicit parameter lists are flattened.
+    let apply = pr.functions.iter().find(|f| f.name == "apply").unwrap();
+    let params: Vec<&str> = apply.parameters.iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(params, vec!["conn", "ec"]);
+
+    let rich = pr.classes.iter().find(|c| c.name == "RichUser").unwrap();
+    assert!(rich.methods.iter().any(|m| m.name == "display"));
+
+    assert!(pr.functions.iter().any(|f| f.name == "normalize"));
+
+    let selectors = &pr.imports.iter().find(|i| i.source == "scala.util").unwrap().specifiers;
+    assert!(selectors.iter().any(|s| s.name == "Success" && s.alias.as_deref() == Some("Ok")));
+    assert!(pr.imports.iter().any(|i| i.source == "java.sql.Connection"));
+
+    let query = pr.call_sites.iter().find(|c| c.callee_name == "executeQuery").unwrap();
+    assert_eq!(query.receiver.as_deref(), Some("stmt"));
+
+    assert!(pr.error_handling.iter().any(|e| e.kind == ErrorHandlingKind::TryCatch
+        && e.caught_type.as_deref() == Some("java.io.IOException")));
+}
+
+#[test]
+fn t1_prs_24_swift_scala_queries_compile() {
+    use drift_analysis::parsers::queries;
+
+    for lang in [Language::Swift, Language::Scala] {
+        let ts_lang = lang.ts_language();
+        tree_sitter::Query::new(&ts_lang, queries::structure_query_for(lang))
+            .unwrap_or_else(|e| panic!("{lang:?} structure query: {e}"));
+        tree_sitter::Query::new(&ts_lang, queries::calls_query_for(lang))
+            .unwrap_or_else(|e| panic!("{lang:?} calls query: {e}"));
+    }
+}
+
+#[test]
+fn t1_prs_25_swift_scala_gast_normalization() {
+    use drift_analysis::engine::gast::normalizers::normalizer_for;
+    use drift_analysis::engine::gast::types::GASTNode;
+
+    let cases: [(Language, &[u8]); 2] = [
+        (Language::Swift, b"func add(a: Int, b: Int) -> Int { return a + b }\nclass Box: Base { }\n"),
+        (Language::Scala, b"def add(a: Int)(b: Int): Int = a + b\nclass Box extends Base\n"),
+    ];
+    for (lang, source) in cases {
+        let mut parser = tree_sitter::Parser::new();
+        parser.set_language(&lang.ts_language()).unwrap();
+        let tree = parser.parse(source, None).unwrap();
+
+        let gast = normalizer_for(lang).normalize(&tree, source);
+        let GASTNode::Program { body } = gast else { panic!("{lang:?}: expected Program") };
+        match &body[0] {
+            GASTNode::Function { name, params, return_type, .. } => {
+                assert_eq!(name, "add");
+                assert_eq!(params.len(), 2, "{lang:?} params");
+                assert_eq!(return_type.as_deref(), Some("Int"));
+            }
+            other => panic!("{lang:?}: expected Function, got {other:?}"),
+        }
+        assert!(body.iter().any(|n| matches!(n,
+            GASTNode::Class { name, bases, .. } if name == "Box" && bases == &["Base".to_string()])),
+            "{lang:?}: expected class Box: Base in {body:?}");
+    }
+}
+
+// ---- T1-PRS-26: Cyclomatic and cognitive complexity (SonarSource examples) ----
+
+#[test]
+fn t1_prs_26_complexity_metrics() {
+    use drift_analysis::parsers::types::ComplexityMetrics;
+
+    let manager = ParserManager::new();
+    let source = br#"
+function sumOfPrimes(max: number): number {
+  let total = 0;
+  outer: for (let i = 1; i <= max; ++i) {
+    for (let j = 2; j < i; ++j) {
+      if (i % j === 0) {
+        continue outer;
+      }
+    }
+    total += i;
+  }
+  return total;
+}
+
+function getWords(n: number): string {
+  switch (n) {
+    case 1: return "one";
+    case 2: return "a couple";
+    default: return "lots";
+  }
+}
+
+function classify(x: number, y: number) {
+  if (x > 0 && y > 0) {
+    return 1;
+  } else if (x < 0 || y < 0 || x === y) {
+    return 2;
+  } else {
+    return x ? 3 : 4;
+  }
+}
+
+function empty(a, b, c) {}
+"#;
+    let pr = manager.parse(source, Path::new("metrics.ts")).unwrap();
+    let metrics = |name: &str| pr.functions.iter().find(|f| f.name == name).unwrap().complexity;
+
+    // Labelled continue +1; loops and the if nest (1 + 2 + 3).
+    assert_eq!(
+        metrics("sumOfPrimes"),
+        ComplexityMetrics { cyclomatic: 4, cognitive: 7, max_nesting: 3, parameter_count: 1 },
+    );
+    // Cases add to cyclomatic; the switch is one cognitive increment.
+    assert_eq!(
+        metrics("getWords"),
+        ComplexityMetrics { cyclomatic: 3, cognitive: 1, max_nesting: 1, parameter_count: 1 },
+    );
+    // if, &&, else if, one || run, else, ternary nested in the else.
+    assert_eq!(
+        metrics("classify"),
+        ComplexityMetrics { cyclomatic: 7, cognitive: 7, max_nesting: 1, parameter_count: 2 },
+    );
+    assert_eq!(
+        metrics("empty"),
+        ComplexityMetrics { cyclomatic: 1, cognitive: 0, max_nesting: 0, parameter_count: 3 },
+    );
+}
+
+// ---- T1-PRS-27: Complexity is measured for every language's control flow ----
+
+#[test]
+fn t1_prs_27_complexity_across_languages() {
+    let manager = ParserManager::new();
+    // (file, source, function, [cyclomatic, cognitive, max_nesting])
+    let cases: [(&str, &[u8], &str, [u32; 3]); 11] = [
+        (
+            "process.py",
+            b"def process(items, strict):\n    for item in items:\n        if item and strict:\n            continue\n        elif item is None:\n            break\n        else:\n            pass\n    try:\n        run()\n    except ValueError:\n        pass\n",
+            "process", [6, 7, 2],
+        ),
+        (
+            "route.go",
+            b"package main\n\nfunc route(kind string, n int) int {\n\tswitch kind {\n\tcase \"a\":\n\t\tif n > 0 {\n\t\t\treturn 1\n\t\t}\n\tcase \"b\", \"c\":\n\t\treturn 2\n\tdefault:\n\t}\n\treturn 0\n}\n",
+            "route", [4, 3, 2],
+        ),
+        (
+            "pick.rs",
+            b"fn pick(v: Option<i32>) -> i32 {\n    match v {\n        Some(x) if x > 0 => x,\n        _ => 0,\n    }\n}\n",
+            "pick", [2, 1, 1],
+        ),
+        (
+            "Sign.java",
+            b"class Sign {\n  int sign(int a) {\n    if (a > 0) { return 1; } else if (a < 0) { return -1; } else { return 0; }\n  }\n}\n",
+            "sign", [3, 3, 1],
+        ),
+        (
+            "retry.rb",
+            b"def retry_all(jobs)\n  jobs.each do |job|\n    if job.failed? && job.retries < 3\n      job.retry\n    end\n  end\nend\n",
+            // Ruby blocks are not functions, so they do not nest.
+            "retry_all", [3, 2, 1],
+        ),
+        (
+            "Sign.cs",
+            b"class Sign {\n  int Of(int a) {\n    if (a > 0) { return 1; } else if (a < 0) { return -1; } else { return 0; }\n  }\n}\n",
+            "Of", [3, 3, 1],
+        ),
+        (
+            "sign.php",
+            b"<?php\nfunction sign($a) {\n  if ($a > 0) { return 1; } elseif ($a < 0) { return -1; } else { return 0; }\n}\n",
+            "sign", [3, 3, 1],
+        ),
+        (
+            "Sign.kt",
+            b"fun sign(a: Int): Int {\n  if (a > 0) { return 1 } else if (a < 0) { return -1 } else { return 0 }\n}\n",
+            "sign", [3, 3, 1],
+        ),
+        (
+            "Sign.swift",
+            b"func sign(a: Int) -> Int {\n  if a > 0 { return 1 } else if a < 0 { return -1 } else { return 0 }\n}\n",
+            "sign", [3, 3, 1],
+        ),
+        (
+            "sign.c",
+            b"int sign(int a) {\n  if (a > 0) { return 1; } else if (a < 0) { return -1; } else { return 0; }\n}\n",
+            "sign", [3, 3, 1],
+        ),
+        (
+            "Sign.scala",
+            b"object Sign {\n  def sign(a: Int): Int = if (a > 0) 1 else if (a < 0) -1 else 0\n}\n",
+            "sign", [3, 3, 1],
+        ),
+    ];
+
+    for (file, source, name, expected) in cases {
+        let pr = manager.parse(source, Path::new(file)).unwrap();
+        let func = pr.functions.iter()
+            .chain(pr.classes.iter().flat_map(|c| c.methods.iter()))
+            .find(|f| f.name == name)
+            .unwrap_or_else(|| panic!("{file}: {name} not extracted"));
+        let m = func.complexity;
+        assert_eq!([m.cyclomatic, m.cognitive, m.max_nesting], expected, "{file}: {name}");
+        assert_eq!(m.parameter_count as usize, func.parameters.len(), "{file}");
+    }
+}
+
+// ---- T1-PRS-28: Type bindings and receivers for receiver inference ----
+
+#[test]
+fn t1_prs_28_type_bindings() {
+    use drift_analysis::parsers::types::TypeBindingKind::{self, *};
+
+    let manager = ParserManager::new();
+    // (file, source, [(name, type_name, kind)])
+    type Expected<'a> = &'a [(&'a str, &'a str, TypeBindingKind)];
+    let cases: [(&str, &[u8], Expected); 4] = [
+        (
+            "svc.ts",
+            b"function f(orders: OrderRepo) {\n  const repo = new UserRepo();\n  const typed: UserRepo = make();\n  this.orders = orders;\n}\n",
+            &[("repo", "UserRepo", Constructed), ("this.orders", "orders", Alias)],
+        ),
+        (
+            "Svc.java",
+            b"class Svc {\n  void f() {\n    UserRepo repo = new UserRepo();\n  }\n}\n",
+            &[("repo", "UserRepo", Declared)],
+        ),
+        (
+            "svc.go",
+            b"package svc\n\nfunc (s *Service) F() {\n\tu := NewUserRepo()\n\tr := &OrderRepo{}\n}\n",
+            &[("s", "*Service", Receiver), ("u", "NewUserRepo", Call), ("r", "OrderRepo", Constructed)],
+        ),
+        (
+            "svc.py",
+            b"class Svc:\n    def __init__(self, orders):\n        self.orders = orders\n        self.users = UserRepo()\n",
+            &[("self.orders", "orders", Alias), ("self.users", "UserRepo", Call)],
+        ),
+    ];
+
+    for (file, source, expected) in cases {
+        let pr = manager.parse(source, Path::new(file)).unwrap();
+        for &(name, type_name, kind) in expected {
+            assert!(
+                pr.type_bindings.iter().any(|b| b.name == name && b.type_name == type_name && b.kind == kind),
+                "{file}: missing {name} = {kind:?}({type_name}) in {:?}",
+                pr.type_bindings
+            );
+        }
+    }
+
+    // C# member access and Kotlin navigation calls keep their receivers.
+    let cs = manager
+        .parse(b"class S { void F() { _repo.Save(x); } }\n", Path::new("S.cs"))
+        .unwrap();
+    assert!(cs.call_sites.iter().any(|c| c.callee_name == "Save" && c.receiver.as_deref() == Some("_repo")));
+    let kt = manager
+        .parse(b"class S {\n  fun f() {\n    repo.save(x)\n  }\n}\n", Path::new("S.kt"))
+        .unwrap();
+    assert!(kt.call_sites.iter().any(|c| c.callee_name == "save" && c.receiver.as_deref() == Some("repo")));
+}
+
+// ---- T1-PRS-29: Class and member annotations, attributes and struct fields ----
+
+#[test]
+fn t1_prs_29_member_decorators_and_struct_fields() {
+    let manager = ParserManager::new();
+    let names = |decorators: &[drift_analysis::parsers::types::DecoratorInfo]| -> Vec<String> {
+        decorators.iter().map(|d| d.name.clone()).collect()
+    };
+
+    // Java keeps annotations inside `modifiers`.
+    let java = manager.parse(
+        b"@Entity\n@Table(name = \"users\")\npublic class User {\n  @Id\n  private static Long id;\n  @Column(nullable = false)\n  private String email;\n}\n@FunctionalInterface\ninterface Named { String name(); }\n",
+        Path::new("User.java"),
+    ).unwrap();
+    assert_eq!(names(&java.classes[0].decorators), vec!["Entity", "Table"]);
+    let props: Vec<(&str, bool, u32, Vec<String>)> = java.classes[0].properties.iter()
+        .map(|p| (p.name.as_str(), p.is_static, p.line, names(&p.decorators)))
+        .collect();
+    assert_eq!(props, vec![
+        ("id", true, 3, vec!["Id".to_string()]),
+        ("email", false, 5, vec!["Column".to_string()]),
+    ]);
+    assert_eq!(names(&java.classes[1].decorators), vec!["FunctionalInterface"]);
+    let table = manager.parse(
+        b"class Posts {\n  public final TableField<R, Long> ID = createField(DSL.name(\"id\"), BIGINT.nullable(false));\n}\n",
+        Path::new("Posts.java"),
+    ).unwrap();
+    assert_eq!(
+        table.classes[0].properties[0].initializer.as_deref(),
+        Some("createField(DSL.name(\"id\"), BIGINT.nullable(false))")
+    );
+
+    // C# and PHP attribute lists.
+    let cs = manager.parse(
+        b"[Table(\"users\")]\npublic class User\n{\n    [Key]\n    public int Id { get; set; }\n    public string Email { get; set; }\n}\n",
+        Path::new("User.cs"),
+    ).unwrap();
+    assert_eq!(names(&cs.classes[0].decorators), vec!["Table"]);
+    assert_eq!(names(&cs.classes[0].properties[0].decorators), vec!["Key"]);
+    assert!(cs.classes[0].properties[1].decorators.is_empty());
+
+    let php = manager.parse(
+        b"<?php\n#[ORM\\Entity]\n#[ORM\\Table(name: 'users')]\nclass User {\n    #[ORM\\Column(type: 'string')]\n    private string $email;\n}\n",
+        Path::new("User.php"),
+    ).unwrap();
+    assert_eq!(names(&php.classes[0].decorators), vec!["ORM\\Entity", "ORM\\Table"]);
+    assert_eq!(php.classes[0].properties[0].name, "email");
+    assert_eq!(names(&php.classes[0].properties[0].decorators), vec!["ORM\\Column"]);
+
+    // Rust and Go structs carry their fields; Go embedded fields go by type.
+    let rust = manager.parse(
+        b"#[derive(Queryable, Debug)]\n#[diesel(table_name = users)]\npub struct User {\n    #[diesel(column_name = mail)]\n    pub email: String,\n    id: i32,\n}\n",
+        Path::new("user.rs"),
+    ).unwrap();
+    let user = &rust.classes[0];
+    assert_eq!(names(&user.decorators), vec!["#[derive(Queryable, Debug)]", "#[diesel(table_name = users)]"]);
+    let fields: Vec<(&str, Option<&str>)> = user.properties.iter()
+        .map(|p| (p.name.as_str(), p.type_annotation.as_deref()))
+        .collect();
+    assert_eq!(fields, vec![("email", Some("String")), ("id", Some("i32"))]);
+    assert_eq!(names(&user.properties[0].decorators), vec!["#[diesel(column_name = mail)]"]);
+
+    let go = manager.parse(
+        b"package m\n\ntype User struct {\n\tgorm.Model\n\tEmail string `gorm:\"unique\"`\n\tage, Rank int\n}\n",
+        Path::new("user.go"),
+    ).unwrap();
+    let fields: Vec<(&str, Option<&str>)> = go.classes[0].properties.iter()
+        .map(|p| (p.name.as_str(), p.tag.as_deref()))
+        .collect();
+    assert_eq!(fields, vec![("gorm.Model", None), ("Email", Some("gorm:\"unique\"")), ("age", None), ("Rank", None)]);
+
+    // TypeScript decorators are not picked up twice.
+    let ts = manager.parse(
+        b"@Entity()\nexport class User {\n  @Column()\n  email: string;\n}\n@Injectable()\nclass Svc {}\n",
+        Path::new("user.ts"),
+    ).unwrap();
+    assert_eq!(names(&ts.classes[0].decorators), vec!["Entity()"]);
+    assert_eq!(names(&ts.classes[0].properties[0].decorators), vec!["Column()"]);
+    assert_eq!(names(&ts.classes[1].decorators), vec!["Injectable()"]);
+}
+
+// ---- T1-PRS-30: Python base classes ----
+
+#[test]
+fn t1_prs_30_python_base_classes() {
+    let manager = ParserManager::new();
+    let pr = manager.parse(
+        b"class User(models.Model, metaclass=Meta):\n    pass\n\nclass Repo(Generic[T]):\n    pass\n\nclass Config(metaclass=Meta):\n    pass\n\nclass Plain:\n    pass\n",
+        Path::new("models.py"),
+    ).unwrap();
+    let extends: Vec<(&str, Option<&str>)> = pr.classes.iter()
+        .map(|c| (c.name.as_str(), c.extends.as_deref()))
+        .collect();
+    assert_eq!(extends, vec![
+        ("User", Some("models.Model")),
+        ("Repo", Some("Generic[T]")),
+        // A keyword argument is not a base.
+        ("Config", None),
+        ("Plain", None),
+    ]);
+
+    // Other grammars keep their own superclass extraction.
+    let ts = manager.parse(b"class Admin extends User {}\n", Path::new("admin.ts")).unwrap();
+    assert!(ts.classes[0].extends.as_deref().is_some_and(|e| e.contains("User")));
+}
+
+// ---- T1-PRS-31: Names bound by destructuring parameters ----
+
+#[test]
+fn t1_prs_31_parameter_bindings() {
+    use drift_analysis::parsers::types::ParameterInfo;
+
+    let param = |name: &str| ParameterInfo {
+        name: name.to_string(),
+        type_annotation: None,
+        default_value: None,
+        is_rest: false,
+    };
+    assert_eq!(param("$request").bindings(), vec!["request"]);
+    assert_eq!(param("Query(params): Query<Params>").bindings(), vec!["params"]);
+    assert_eq!(param("{ params }: Props").bindings(), vec!["params"]);
+    // A renamed property binds the new name, not the key.
+    assert_eq!(param("{ params: id, query: { q } }: Props").bindings(), vec!["id", "q"]);
+    // Defaults are values, not bindings.
+    assert_eq!(param("{ page = first, size = limit(10) }").bindings(), vec!["page", "size"]);
+    assert_eq!(param("Json(Payload { name, .. }): Json<Payload>").bindings(), vec!["name"]);
+
+    let ts = ParserManager::new()
+        .parse(b"function show({ params: id }: Props) {}\n", Path::new("page.ts"))
+        .unwrap();
+    assert_eq!(ts.functions[0].parameters[0].bindings(), vec!["id"]);
+}
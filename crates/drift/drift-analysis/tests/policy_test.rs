@@ -1,5 +1,5 @@
 //! Phase 6 tests: Policy Engine — Aggregation Modes
-//! T6-POL-01 through T6-POL-06
+//! T6-POL-01 through T6-POL-12
 
 use drift_analysis::enforcement::gates::*;
 use drift_analysis::enforcement::policy::*;
@@ -72,6 +72,8 @@ fn test_all_must_pass_one_failure() {
         required_gates: vec![],
         progressive: false,
         ramp_up_days: 0,
+        diff_scope: None,
+        gate_thresholds: std::collections::HashMap::new(),
     };
     let engine = PolicyEngine::new(policy);
     let pr = engine.evaluate(&results);
@@ -91,6 +93,8 @@ fn test_any_must_pass_one_success() {
         required_gates: vec![],
         progressive: false,
         ramp_up_days: 0,
+        diff_scope: None,
+        gate_thresholds: std::collections::HashMap::new(),
     };
     let engine = PolicyEngine::new(policy);
     let pr = engine.evaluate(&results);
@@ -116,6 +120,8 @@ fn test_weighted_mode() {
         required_gates: vec![],
         progressive: false,
         ramp_up_days: 0,
+        diff_scope: None,
+        gate_thresholds: std::collections::HashMap::new(),
     };
     let engine = PolicyEngine::new(policy);
     let pr = engine.evaluate(&results);
@@ -142,6 +148,8 @@ fn test_threshold_boundary() {
         required_gates: vec![],
         progressive: false,
         ramp_up_days: 0,
+        diff_scope: None,
+        gate_thresholds: std::collections::HashMap::new(),
     };
     let engine = PolicyEngine::new(policy.clone());
     let pr = engine.evaluate(&results);
@@ -187,3 +195,323 @@ fn test_progressive_ramp_up() {
     });
     assert_eq!(pe3.effective_severity(Severity::Error, false), Severity::Error);
 }
+
+const POLICY_TOML: &str = r#"
+default = "base"
+
+[policies.base]
+extends = "standard"
+threshold = 60
+
+[policies.payments]
+extends = "base"
+aggregation_mode = "all_must_pass"
+gate_thresholds = { pattern-compliance = 90 }
+
+[[overrides]]
+paths = ["tools/**"]
+disabled_detectors = ["legacy-*"]
+severity = { "pattern-compliance/naming-*" = "warning" }
+ramp_start = "2026-01-01"
+ramp_end = "2026-01-29"
+
+[[overrides]]
+paths = ["packages/payments/**"]
+policy = "payments"
+"#;
+
+fn package(name: &str, path: &str) -> drift_core::workspace::monorepo::PackageInfo {
+    drift_core::workspace::monorepo::PackageInfo {
+        name: name.to_string(),
+        path: std::path::PathBuf::from(path),
+        language: Some("typescript".to_string()),
+        framework: None,
+        dependencies: vec![],
+    }
+}
+
+/// T6-POL-07: Policy files resolve inheritance and reject cycles, unknown names and bad dates.
+#[test]
+fn test_policy_file_inheritance() {
+    let file = PolicyFile::parse(POLICY_TOML, POLICY_FILE).unwrap();
+    let set = PolicySet::from_files(&file, &[]).unwrap();
+
+    let base = set.default_policy();
+    assert_eq!(base.policy.name, "base");
+    assert_eq!(base.policy.threshold, 60.0);
+    assert_eq!(base.policy.required_gates, vec![GateId::SecurityBoundaries], "inherited from standard");
+
+    let payments = set.policy_for("packages/payments/src/charge.ts");
+    assert_eq!(payments.policy.name, "payments");
+    assert_eq!(payments.policy.aggregation_mode, AggregationMode::AllMustPass);
+    assert_eq!(payments.policy.threshold, 60.0, "inherited from base");
+    assert_eq!(payments.policy.gate_thresholds["pattern-compliance"], 90.0);
+
+    // Ramp dates set the ramp length and the project age.
+    let tools = set.policy_for("tools/gen/main.ts");
+    assert_eq!(tools.policy.name, "base");
+    assert_eq!(tools.policy.ramp_up_days, 28);
+    let config = tools.progressive_config(parse_date("2026-01-11").unwrap());
+    assert!(config.enabled);
+    assert_eq!(config.project_age_days, 10);
+    assert!(!base.progressive_config(parse_date("2026-01-11").unwrap()).enabled, "no ramp start, no ramp");
+    assert_eq!(parse_date("1970-01-02"), Some(1));
+
+    let cyclic = PolicyFile::parse("default = \"a\"\n[policies.a]\nextends = \"b\"\n[policies.b]\nextends = \"a\"\n", POLICY_FILE).unwrap();
+    let err = PolicySet::from_files(&cyclic, &[]).unwrap_err().to_string();
+    assert!(err.contains("inheritance cycle: a -> b -> a"), "{err}");
+
+    let unknown = PolicyFile::parse("[[overrides]]\npaths = [\"x/**\"]\npolicy = \"nope\"\n", POLICY_FILE).unwrap();
+    assert!(PolicySet::from_files(&unknown, &[]).is_err());
+    assert!(PolicyFile::parse("[policies.a]\nramp_start = \"next week\"\n", POLICY_FILE).is_err());
+}
+
+/// T6-POL-08: Monorepo packages carry their own policy, from the root file or their own.
+#[test]
+fn test_policy_file_monorepo_packages() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(
+        dir.path().join(POLICY_FILE),
+        format!("{POLICY_TOML}\n[packages.web]\npolicy = \"lenient\"\n"),
+    )
+    .unwrap();
+    std::fs::create_dir_all(dir.path().join("packages/billing")).unwrap();
+    std::fs::write(
+        dir.path().join("packages/billing").join(POLICY_FILE),
+        r#"
+default = "billing"
+
+[policies.billing]
+extends = "base"
+threshold = 95
+
+[[overrides]]
+paths = ["legacy/**"]
+severity = { "pattern-compliance/*" = "info" }
+"#,
+    )
+    .unwrap();
+
+    let packages = [package("web", "apps/web"), package("billing", "packages/billing")];
+    let set = PolicySet::load(dir.path(), &packages).unwrap().unwrap();
+    assert_eq!(set.policy_for("apps/web/index.ts").policy.name, "lenient");
+    assert_eq!(set.policy_for("src/main.ts").policy.name, "base");
+
+    // The package file's policy extends the root file's "base".
+    let billing = set.policy_for("packages/billing/src/invoice.ts");
+    assert_eq!(billing.policy.name, "billing");
+    assert_eq!(billing.policy.threshold, 95.0);
+    assert!(billing.severity_overrides.is_empty());
+    let legacy = set.policy_for("packages/billing/legacy/old.ts");
+    assert_eq!(legacy.policy.name, "billing");
+    assert_eq!(legacy.severity_overrides["pattern-compliance/*"], Severity::Info);
+
+    let files: Vec<String> = ["src/main.ts", "apps/web/index.ts", "packages/billing/legacy/old.ts", "src/util.ts"]
+        .iter()
+        .map(|f| f.to_string())
+        .collect();
+    let scopes = set.partition(&files);
+    let labels: Vec<&str> = scopes.iter().map(|s| s.label.as_str()).collect();
+    assert_eq!(
+        labels,
+        vec![
+            "default",
+            "package web",
+            "packages/billing/drift-policy.toml > packages/billing/drift-policy.toml legacy/**",
+        ]
+    );
+    assert_eq!(scopes[0].files, vec!["src/main.ts", "src/util.ts"]);
+
+    // No policy file anywhere: nothing to load.
+    let empty = tempfile::tempdir().unwrap();
+    assert!(PolicySet::load(empty.path(), &packages).unwrap().is_none());
+}
+
+/// T6-POL-09: Each scope runs the gates on its own files and is judged by its own policy.
+#[test]
+fn test_policy_set_scoped_evaluation() {
+    use drift_analysis::enforcement::rules::{OutlierLocation, PatternInfo, PatternLocation};
+
+    let location = |file: &str, line: u32| PatternLocation { file: file.to_string(), line, column: None };
+    let outlier = |file: &str, line: u32| OutlierLocation {
+        file: file.to_string(),
+        line,
+        column: None,
+        end_line: None,
+        end_column: None,
+        deviation_score: 3.0,
+        message: "Deviation".to_string(),
+    };
+    let pattern = |id: &str, locations, outliers| PatternInfo {
+        pattern_id: id.to_string(),
+        category: "naming".to_string(),
+        confidence: 0.95,
+        locations,
+        outliers,
+        cwe_ids: vec![],
+        owasp_categories: vec![],
+    };
+    let input = GateInput {
+        files: vec!["packages/payments/pay.ts".to_string(), "tools/gen.ts".to_string(), "src/app.ts".to_string()],
+        patterns: vec![
+            pattern(
+                "naming-camel",
+                vec![location("packages/payments/pay.ts", 1), location("tools/gen.ts", 1), location("src/app.ts", 1)],
+                vec![outlier("packages/payments/pay.ts", 5), outlier("tools/gen.ts", 5)],
+            ),
+            pattern("legacy-log", vec![location("src/app.ts", 3)], vec![outlier("tools/gen.ts", 9)]),
+        ],
+        ..Default::default()
+    };
+
+    let file = PolicyFile::parse(POLICY_TOML, POLICY_FILE).unwrap();
+    let set = PolicySet::from_files(&file, &[]).unwrap().with_today(parse_date("2026-06-01").unwrap());
+    let report = set.evaluate(&input, |orchestrator, _| orchestrator).unwrap();
+    assert_eq!(report.scopes.len(), 3);
+    let compliance = |label: &str| {
+        let scope = report.scopes.iter().find(|s| s.label == label).unwrap();
+        scope.results.iter().find(|r| r.gate_id == GateId::PatternCompliance).unwrap().clone()
+    };
+
+    // tools/**: the legacy detector is off and naming findings are warnings.
+    let tools = compliance("tools/**");
+    assert!(tools.passed);
+    assert_eq!(tools.status, GateStatus::Warned);
+    assert_eq!(tools.violations.len(), 1);
+    assert_eq!(tools.violations[0].severity, Severity::Warning);
+
+    // packages/payments/**: the same finding fails, and the payments policy fails with it.
+    assert!(!compliance("packages/payments/**").passed);
+    let payments = report.scopes.iter().find(|s| s.label == "packages/payments/**").unwrap();
+    assert_eq!(payments.verdict.policy_name, "payments");
+    assert!(!payments.verdict.overall_passed);
+    assert!(!report.passed());
+
+    assert!(compliance("default").violations.is_empty());
+
+    // Merged per gate for storage: worst status, all violations.
+    let merged = report.merged_results();
+    assert_eq!(merged.len(), GateId::all().len());
+    let gate = merged.iter().find(|r| r.gate_id == GateId::PatternCompliance).unwrap();
+    assert_eq!(gate.status, GateStatus::Failed);
+    assert!(!gate.passed);
+    assert_eq!(gate.violations.len(), 2);
+    assert!(gate.details["scopes"].as_object().unwrap().contains_key("tools/**"));
+}
+
+/// T6-POL-10: Per-gate thresholds fail gates that pass on their own.
+#[test]
+fn test_policy_gate_thresholds() {
+    let results = make_gate_results(6, 0);
+    let mut policy = Policy::strict();
+    assert!(PolicyEngine::new(policy.clone()).evaluate(&results).overall_passed);
+
+    policy.gate_thresholds.insert("pattern-compliance".to_string(), 95.0);
+    let pr = PolicyEngine::new(policy.clone()).evaluate(&results);
+    assert!(!pr.overall_passed, "score 90 is below the pattern-compliance threshold");
+    assert_eq!(pr.gates_passed, 5);
+
+    policy.gate_thresholds.insert("pattern-compliance".to_string(), 85.0);
+    assert!(PolicyEngine::new(policy).evaluate(&results).overall_passed);
+}
+
+/// T6-POL-11: `[owners.*]` entries route policies by CODEOWNERS, between
+/// package entries and path overrides.
+#[test]
+fn test_policy_owner_routing() {
+    use drift_analysis::enforcement::ownership::CodeOwners;
+
+    let file = PolicyFile::parse(
+        r#"
+[owners."@acme/payments"]
+policy = "strict"
+threshold = 90
+
+[[overrides]]
+paths = ["src/payments/legacy/**"]
+threshold = 50
+"#,
+        POLICY_FILE,
+    )
+    .unwrap();
+    let codeowners = CodeOwners::parse("/src/payments/ @acme/payments\n");
+    let set = PolicySet::from_files(&file, &[]).unwrap().with_codeowners(codeowners);
+
+    let charge = set.policy_for("src/payments/charge.ts");
+    assert_eq!(charge.policy.name, "strict");
+    assert_eq!(charge.policy.threshold, 90.0);
+    assert_eq!(set.policy_for("src/payments/legacy/old.ts").policy.threshold, 50.0, "overrides win");
+    assert_eq!(set.policy_for("src/app.ts").policy.name, "standard");
+
+    let files: Vec<String> = ["src/app.ts", "src/payments/charge.ts"].iter().map(|f| f.to_string()).collect();
+    let labels: Vec<String> = set.partition(&files).into_iter().map(|s| s.label).collect();
+    assert_eq!(labels, vec!["default", "owner @acme/payments"]);
+
+    // Loaded sets route by owner once CODEOWNERS is attached.
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join(POLICY_FILE), "[owners.\"@web\"]\nthreshold = 80\n").unwrap();
+    std::fs::write(dir.path().join("CODEOWNERS"), "*.tsx @web\n").unwrap();
+    let loaded = PolicySet::load(dir.path(), &[]).unwrap().unwrap();
+    assert_ne!(loaded.policy_for("ui/button.tsx").policy.threshold, 80.0);
+    let loaded = loaded.with_codeowners(CodeOwners::load(dir.path()).unwrap().unwrap());
+    assert_eq!(loaded.policy_for("ui/button.tsx").policy.threshold, 80.0);
+    assert!(PolicyFile::parse("[owners.x]\nramp_end = \"soon\"\n", POLICY_FILE).is_err());
+}
+
+/// T6-POL-12: A scope fails its policy while every gate passes; the report
+/// fails even though the merged gate results don't.
+#[test]
+fn test_policy_scope_fails_with_passing_gates() {
+    use drift_analysis::enforcement::rules::{OutlierLocation, PatternInfo, PatternLocation};
+
+    let input = GateInput {
+        files: vec!["tools/gen.ts".to_string(), "src/app.ts".to_string()],
+        patterns: vec![PatternInfo {
+            pattern_id: "naming-camel".to_string(),
+            category: "naming".to_string(),
+            confidence: 0.95,
+            locations: ["tools/gen.ts", "src/app.ts"]
+                .iter()
+                .map(|f| PatternLocation { file: f.to_string(), line: 1, column: None })
+                .collect(),
+            outliers: vec![OutlierLocation {
+                file: "tools/gen.ts".to_string(),
+                line: 5,
+                column: None,
+                end_line: None,
+                end_column: None,
+                deviation_score: 3.0,
+                message: "Deviation".to_string(),
+            }],
+            cwe_ids: vec![],
+            owasp_categories: vec![],
+        }],
+        ..Default::default()
+    };
+    let file = PolicyFile::parse(
+        r#"
+default = "base"
+
+[policies.base]
+extends = "standard"
+aggregation_mode = "all_must_pass"
+
+[[overrides]]
+paths = ["tools/**"]
+severity = { "pattern-compliance/*" = "warning" }
+gate_thresholds = { pattern-compliance = 100 }
+"#,
+        POLICY_FILE,
+    )
+    .unwrap();
+    let set = PolicySet::from_files(&file, &[]).unwrap();
+    let report = set.evaluate(&input, |orchestrator, _| orchestrator).unwrap();
+
+    let merged = report.merged_results();
+    assert!(merged.iter().all(|r| r.passed), "warnings don't fail the gate");
+    let tools = report.scopes.iter().find(|s| s.label == "tools/**").unwrap();
+    assert!(!tools.verdict.overall_passed, "the scope's compliance score is below its threshold");
+    assert_eq!(tools.verdict.gates_failed, 1);
+    assert!(report.scopes.iter().find(|s| s.label == "default").unwrap().verdict.overall_passed);
+    assert!(!report.passed());
+}
@@ -26,11 +26,16 @@ fn make_cwe_owasp_violation() -> Violation {
             strategy: QuickFixStrategy::UseParameterizedQuery,
             description: "Use parameterized query instead of string concatenation".to_string(),
             replacement: None,
+            fix_id: String::new(),
+            edits: Vec::new(),
         }),
         cwe_id: Some(89),
         owasp_category: Some("A03:2021-Injection".to_string()),
         suppressed: false,
         is_new: true,
+        owners: Vec::new(),
+        last_author: None,
+        file_level: false,
     }
 }
 
@@ -51,11 +56,16 @@ fn make_warning_violation() -> Violation {
             strategy: QuickFixStrategy::Rename,
             description: "Rename to camelCase".to_string(),
             replacement: Some("myFunction".to_string()),
+            fix_id: String::new(),
+            edits: Vec::new(),
         }),
         cwe_id: None,
         owasp_category: None,
         suppressed: false,
         is_new: false,
+        owners: Vec::new(),
+        last_author: None,
+        file_level: false,
     }
 }
 
@@ -77,6 +87,9 @@ fn make_info_violation() -> Violation {
         owasp_category: None,
         suppressed: false,
         is_new: false,
+        owners: Vec::new(),
+        last_author: None,
+        file_level: false,
     }
 }
 
@@ -98,6 +111,9 @@ fn make_hint_violation() -> Violation {
         owasp_category: None,
         suppressed: false,
         is_new: true,
+        owners: Vec::new(),
+        last_author: None,
+        file_level: false,
     }
 }
 
@@ -329,6 +345,8 @@ fn t10_04_console_report_readability() {
                     strategy: QuickFixStrategy::WrapInTryCatch,
                     description: "Add error handling".to_string(),
                     replacement: None,
+                    fix_id: String::new(),
+                    edits: Vec::new(),
                 })
             } else {
                 None
@@ -337,6 +355,9 @@ fn t10_04_console_report_readability() {
             owasp_category: None,
             suppressed: false,
             is_new: i % 2 == 0,
+            owners: Vec::new(),
+            last_author: None,
+            file_level: false,
         })
         .collect();
 
@@ -356,11 +377,16 @@ fn t10_04_console_report_readability() {
             strategy: QuickFixStrategy::WrapInTryCatch,
             description: "Sanitize user input".to_string(),
             replacement: None,
+            fix_id: String::new(),
+            edits: Vec::new(),
         }),
         cwe_id: None,
         owasp_category: None,
         suppressed: false,
         is_new: true,
+        owners: Vec::new(),
+        last_author: None,
+        file_level: false,
     });
 
     let results = vec![GateResult::fail(
@@ -550,6 +576,9 @@ fn t10_07_gitlab_code_quality_fingerprints() {
             owasp_category: None,
             suppressed: false,
             is_new: false,
+            owners: Vec::new(),
+            last_author: None,
+            file_level: false,
         },
         Violation {
             id: "v2".to_string(),
@@ -567,6 +596,9 @@ fn t10_07_gitlab_code_quality_fingerprints() {
             owasp_category: None,
             suppressed: false,
             is_new: false,
+            owners: Vec::new(),
+            last_author: None,
+            file_level: false,
         },
         Violation {
             id: "v3".to_string(),
@@ -584,6 +616,9 @@ fn t10_07_gitlab_code_quality_fingerprints() {
             owasp_category: None,
             suppressed: false,
             is_new: false,
+            owners: Vec::new(),
+            last_author: None,
+            file_level: false,
         },
         // One with CWE for Security category
         Violation {
@@ -602,6 +637,9 @@ fn t10_07_gitlab_code_quality_fingerprints() {
             owasp_category: None,
             suppressed: false,
             is_new: false,
+            owners: Vec::new(),
+            last_author: None,
+            file_level: false,
         },
     ];
 
@@ -730,19 +768,20 @@ fn t10_08_html_report_generation() {
     );
 }
 
-// ─── T10-09: All 8 Formats via Reporter Factory ───────────────────────
+// ─── T10-09: All 10 Formats via Reporter Factory ───────────────────────
 
-/// T10-09: Call create_reporter(format) for each of the 8 formats.
+/// T10-09: Call create_reporter(format) for each of the 10 formats.
 /// Each must return non-empty string and not error. Reporter name must match format string.
 #[test]
-fn t10_09_all_8_formats_via_factory() {
+fn t10_09_all_10_formats_via_factory() {
     let results = make_mixed_gate_results();
     let all_formats = reporters::available_formats();
 
-    assert_eq!(all_formats.len(), 8, "Must have exactly 8 reporter formats");
+    assert_eq!(all_formats.len(), 10, "Must have exactly 10 reporter formats");
 
     let expected_formats = [
         "sarif", "json", "console", "github", "gitlab", "junit", "html", "sonarqube",
+        "checkstyle", "codeclimate",
     ];
     for fmt in &expected_formats {
         assert!(
@@ -785,7 +824,7 @@ fn t10_09_all_8_formats_via_factory() {
 
         // For JSON-based formats, verify valid JSON
         match *format {
-            "sarif" | "json" | "github" | "gitlab" | "sonarqube" => {
+            "sarif" | "json" | "github" | "gitlab" | "sonarqube" | "codeclimate" => {
                 let parsed: Result<serde_json::Value, _> = serde_json::from_str(&text);
                 assert!(
                     parsed.is_ok(),
@@ -793,10 +832,10 @@ fn t10_09_all_8_formats_via_factory() {
                     parsed.err()
                 );
             }
-            "junit" => {
+            "junit" | "checkstyle" => {
                 assert!(
                     text.starts_with("<?xml"),
-                    "JUnit reporter must produce XML"
+                    "{format} reporter must produce XML"
                 );
             }
             "html" => {
@@ -211,7 +211,7 @@ fn t15_03_fallback_grammar_coverage() {
     let cpp_pr = cpp_result.unwrap();
     assert_eq!(cpp_pr.language, Language::Cpp, "language must be C++");
 
-    // Swift/Scala use Java grammar as fallback
+    // Swift/Scala have dedicated grammars
     let swift_source = "func hello() { print(\"hello\") }";
     let swift_result = manager.parse(swift_source.as_bytes(), Path::new("test.swift"));
     assert!(
@@ -70,6 +70,7 @@ fn make_function(name: &str, line: u32) -> FunctionInfo {
         doc_comment: None,
         body_hash: 0,
         signature_hash: 0,
+        complexity: Default::default(),
     }
 }
 
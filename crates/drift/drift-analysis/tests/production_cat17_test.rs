@@ -523,11 +523,13 @@ fn t17_08_constraint_verification() {
                 name: "getUsers".into(),
                 line: 5,
                 is_exported: true,
+                complexity: Default::default(),
             },
             FunctionInfo {
                 name: "createUser".into(),
                 line: 15,
                 is_exported: true,
+                complexity: Default::default(),
             },
         ],
         vec![],
@@ -539,6 +541,7 @@ fn t17_08_constraint_verification() {
             name: "formatDate".into(),
             line: 1,
             is_exported: false,
+            complexity: Default::default(),
         }],
         vec![],
         10,
@@ -651,31 +654,37 @@ fn t17_10_decomposition_suggestions() {
                 path: "src/auth/login.ts".into(),
                 line_count: 200,
                 language: "typescript".into(),
+                cyclomatic: 0,
             },
             FileEntry {
                 path: "src/auth/session.ts".into(),
                 line_count: 150,
                 language: "typescript".into(),
+                cyclomatic: 0,
             },
             FileEntry {
                 path: "src/users/crud.ts".into(),
                 line_count: 300,
                 language: "typescript".into(),
+                cyclomatic: 0,
             },
             FileEntry {
                 path: "src/users/profile.ts".into(),
                 line_count: 100,
                 language: "typescript".into(),
+                cyclomatic: 0,
             },
             FileEntry {
                 path: "src/billing/payments.ts".into(),
                 line_count: 250,
                 language: "typescript".into(),
+                cyclomatic: 0,
             },
             FileEntry {
                 path: "src/billing/invoices.ts".into(),
                 line_count: 180,
                 language: "typescript".into(),
+                cyclomatic: 0,
             },
         ],
         call_edges: vec![
@@ -61,6 +61,7 @@ fn make_function(name: &str, file: &str, line: u32, end_line: u32) -> FunctionIn
         doc_comment: None,
         body_hash: 0,
         signature_hash: 0,
+        complexity: Default::default(),
     }
 }
 
@@ -216,15 +217,17 @@ fn t18_01_call_graph_all_6_resolution_strategies() {
         "Edge to veryUniqueProcessorXYZ must exist (export or fuzzy)"
     );
 
-    // Verify Resolution enum has all 6 strategies
+    // Verify Resolution enum has all 8 strategies
     let all = Resolution::all_ordered();
-    assert_eq!(all.len(), 6, "Must have exactly 6 resolution strategies");
+    assert_eq!(all.len(), 8, "Must have exactly 8 resolution strategies");
     assert_eq!(all[0], Resolution::SameFile);
-    assert_eq!(all[1], Resolution::MethodCall);
-    assert_eq!(all[2], Resolution::DiInjection);
-    assert_eq!(all[3], Resolution::ImportBased);
-    assert_eq!(all[4], Resolution::ExportBased);
-    assert_eq!(all[5], Resolution::Fuzzy);
+    assert_eq!(all[1], Resolution::TypedReceiver);
+    assert_eq!(all[2], Resolution::MethodCall);
+    assert_eq!(all[3], Resolution::ManifestImport);
+    assert_eq!(all[4], Resolution::DiInjection);
+    assert_eq!(all[5], Resolution::ImportBased);
+    assert_eq!(all[6], Resolution::ExportBased);
+    assert_eq!(all[7], Resolution::Fuzzy);
 
     // Verify confidence ordering
     assert!(Resolution::SameFile.default_confidence() > Resolution::Fuzzy.default_confidence());
@@ -573,6 +576,7 @@ fn t18_08_impact_blast_radius_scoring() {
         is_exported: true,
         signature_hash: 0,
         body_hash: 0,
+        complexity: Default::default(),
     });
 
     // Add 10 callers
@@ -588,6 +592,7 @@ fn t18_08_impact_blast_radius_scoring() {
             is_exported: false,
             signature_hash: 0,
             body_hash: 0,
+            complexity: Default::default(),
         });
         graph.add_edge(
             caller,
@@ -658,6 +663,7 @@ fn t18_09_impact_dead_code_detection() {
         is_exported: false,
         signature_hash: 0,
         body_hash: 0,
+        complexity: Default::default(),
     });
 
     // Route handler: 0 callers but has entry point decorator info
@@ -673,6 +679,7 @@ fn t18_09_impact_dead_code_detection() {
         is_exported: true,
         signature_hash: 0,
         body_hash: 0,
+        complexity: Default::default(),
     });
 
     // Normal called function (has a caller)
@@ -687,6 +694,7 @@ fn t18_09_impact_dead_code_detection() {
         is_exported: false,
         signature_hash: 0,
         body_hash: 0,
+        complexity: Default::default(),
     });
 
     // Create an edge: route_handler → called_func
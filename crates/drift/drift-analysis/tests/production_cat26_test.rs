@@ -172,6 +172,8 @@ fn build_gate_input() -> GateInput {
         predecessor_results: HashMap::new(),
         baseline_violations: std::collections::HashSet::new(),
         feedback_stats: None,
+        contract_diff: None,
+        sources: Default::default(),
     }
 }
 
@@ -195,11 +197,16 @@ fn build_gate_results_with_violations() -> Vec<drift_analysis::enforcement::gate
                 strategy: drift_analysis::enforcement::rules::QuickFixStrategy::Rename,
                 description: "Move to environment variable".to_string(),
                 replacement: Some("process.env.SECRET".to_string()),
+                fix_id: String::new(),
+                edits: Vec::new(),
             }),
             cwe_id: Some(798),
             owasp_category: Some("A07:2021".to_string()),
             suppressed: false,
             is_new: true,
+            owners: Vec::new(),
+            last_author: None,
+            file_level: false,
         },
         Violation {
             id: "singleton-outlier-src/module_3.ts-13".to_string(),
@@ -217,6 +224,9 @@ fn build_gate_results_with_violations() -> Vec<drift_analysis::enforcement::gate
             owasp_category: None,
             suppressed: false,
             is_new: false,
+            owners: Vec::new(),
+            last_author: None,
+            file_level: false,
         },
     ];
 
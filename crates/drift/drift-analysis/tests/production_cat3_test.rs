@@ -216,6 +216,7 @@ fn t3_03_taint_reachability_depth() {
             is_exported: false,
             signature_hash: i as u64,
             body_hash: i as u64,
+            complexity: Default::default(),
         };
         nodes.push(graph.add_function(node));
     }
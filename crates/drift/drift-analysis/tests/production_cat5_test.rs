@@ -84,6 +84,7 @@ fn make_parse_result(file: &str, num_functions: usize) -> ParseResult {
             doc_comment: None,
             body_hash: i as u64,
             signature_hash: i as u64 * 17,
+            complexity: Default::default(),
         });
     }
     pr
@@ -176,6 +177,7 @@ fn t5_01_phase_ordering_invariant() {
             doc_comment: None,
             body_hash: i as u64,
             signature_hash: i as u64 * 31,
+            complexity: Default::default(),
         });
     }
 
@@ -589,6 +589,8 @@ fn t6_13_policy_engine_4_modes() {
         required_gates: vec![],
         progressive: false,
         ramp_up_days: 0,
+        diff_scope: None,
+        gate_thresholds: HashMap::new(),
     };
     let r = PolicyEngine::new(strict).evaluate(&results);
     assert!(!r.overall_passed, "AllMustPass: 1 fail → overall fail");
@@ -603,6 +605,8 @@ fn t6_13_policy_engine_4_modes() {
         required_gates: vec![],
         progressive: false,
         ramp_up_days: 0,
+        diff_scope: None,
+        gate_thresholds: HashMap::new(),
     };
     let r = PolicyEngine::new(lenient).evaluate(&results);
     assert!(r.overall_passed, "AnyMustPass: at least 1 pass → overall pass");
@@ -625,6 +629,8 @@ fn t6_13_policy_engine_4_modes() {
         required_gates: vec![],
         progressive: false,
         ramp_up_days: 0,
+        diff_scope: None,
+        gate_thresholds: HashMap::new(),
     };
     let r = PolicyEngine::new(threshold_policy).evaluate(&results);
     assert!(!r.overall_passed, "Threshold: avg ~75.8 < 80 → fail, score={}", r.overall_score);
@@ -698,6 +704,7 @@ fn t6_14_fp_rate_auto_disable() {
         }],
         source_lines: HashMap::new(),
         baseline_violation_ids: HashSet::new(),
+        sources: Default::default(),
     };
     let violations = evaluator.evaluate(&input);
     assert!(!violations.is_empty());
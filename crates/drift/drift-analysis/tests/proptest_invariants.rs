@@ -182,6 +182,7 @@ fn random_import_graph(
         modules,
         abstract_counts,
         total_type_counts,
+        file_modules: Default::default(),
     }
 }
 
@@ -20,6 +20,7 @@ fn make_node(file: &str, name: &str, exported: bool) -> FunctionNode {
         is_exported: exported,
         signature_hash: 0,
         body_hash: 0,
+        complexity: Default::default(),
     }
 }
 
@@ -238,6 +239,7 @@ fn test_large_graph_bfs_performance() {
             is_exported: false,
             signature_hash: 0,
             body_hash: 0,
+            complexity: Default::default(),
         });
         g.add_edge(prev, node, CallEdge {
             resolution: Resolution::ImportBased,
@@ -1,8 +1,8 @@
 #![allow(clippy::len_zero)]
-//! Phase 8 reporter tests — T8-RPT-01 through T8-RPT-07.
+//! Phase 8 reporter tests — T8-RPT-01 through T8-RPT-11.
 //!
-//! Tests all 8 reporter formats: SARIF, JSON, console, GitHub Code Quality,
-//! GitLab Code Quality, JUnit XML, HTML, SonarQube.
+//! Tests all 10 reporter formats: SARIF, JSON, console, GitHub Code Quality,
+//! GitLab Code Quality, JUnit XML, HTML, SonarQube, Checkstyle, Code Climate.
 
 use drift_analysis::enforcement::gates::{GateId, GateResult};
 use drift_analysis::enforcement::reporters::*;
@@ -26,11 +26,16 @@ fn test_violations() -> Vec<Violation> {
                 strategy: QuickFixStrategy::WrapInTryCatch,
                 description: "Wrap in try-catch block".to_string(),
                 replacement: None,
+                fix_id: String::new(),
+                edits: Vec::new(),
             }),
             cwe_id: Some(755),
             owasp_category: Some("A09:2021".to_string()),
             suppressed: false,
             is_new: true,
+            owners: Vec::new(),
+            last_author: None,
+            file_level: false,
         },
         Violation {
             id: "security-boundary-src/db.ts-10".to_string(),
@@ -48,6 +53,9 @@ fn test_violations() -> Vec<Violation> {
             owasp_category: Some("A03:2021".to_string()),
             suppressed: false,
             is_new: false,
+            owners: Vec::new(),
+            last_author: None,
+            file_level: false,
         },
         Violation {
             id: "info-hint-src/utils.ts-5".to_string(),
@@ -64,11 +72,16 @@ fn test_violations() -> Vec<Violation> {
                 strategy: QuickFixStrategy::Rename,
                 description: "Rename to camelCase".to_string(),
                 replacement: Some("myFunction".to_string()),
+                fix_id: String::new(),
+                edits: Vec::new(),
             }),
             cwe_id: None,
             owasp_category: None,
             suppressed: false,
             is_new: false,
+            owners: Vec::new(),
+            last_author: None,
+            file_level: false,
         },
     ]
 }
@@ -97,7 +110,7 @@ fn empty_gate_results() -> Vec<GateResult> {
     )]
 }
 
-// T8-RPT-01: Test all 8 reporter formats produce valid output
+// T8-RPT-01: Test all 10 reporter formats produce valid output
 #[test]
 fn t8_rpt_01_all_reporters_produce_valid_output() {
     let results = test_gate_results();
@@ -151,6 +164,18 @@ fn t8_rpt_01_all_reporters_produce_valid_output() {
     let output = sonarqube.generate(&results).unwrap();
     let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
     assert!(!parsed["issues"].as_array().unwrap().is_empty());
+
+    // Checkstyle XML
+    let checkstyle = checkstyle::CheckstyleReporter::new();
+    let output = checkstyle.generate(&results).unwrap();
+    assert!(output.starts_with("<?xml"));
+    assert!(output.contains("</checkstyle>"));
+
+    // Code Climate
+    let codeclimate = codeclimate::CodeClimateReporter::new();
+    let output = codeclimate.generate(&results).unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert!(!parsed.as_array().unwrap().is_empty());
 }
 
 // T8-RPT-02: Validate GitHub Code Quality format
@@ -269,6 +294,9 @@ fn t8_rpt_06_unicode_handling() {
         owasp_category: None,
         suppressed: false,
         is_new: false,
+        owners: Vec::new(),
+        last_author: None,
+        file_level: false,
     }];
 
     let results = vec![GateResult::fail(
@@ -337,6 +365,9 @@ fn t8_rpt_07_large_violation_set() {
             },
             suppressed: false,
             is_new: i % 2 == 0,
+            owners: Vec::new(),
+            last_author: None,
+            file_level: false,
         })
         .collect();
 
@@ -376,6 +407,170 @@ fn t8_rpt_07_large_violation_set() {
     }
 }
 
+// T8-RPT-08: ReporterRegistry accepts downstream reporters by name
+#[test]
+fn t8_rpt_08_reporter_registry() {
+    struct CountReporter;
+    impl Reporter for CountReporter {
+        fn name(&self) -> &'static str {
+            "count"
+        }
+        fn generate(&self, results: &[GateResult]) -> Result<String, String> {
+            Ok(results.iter().map(|r| r.violations.len()).sum::<usize>().to_string())
+        }
+    }
+
+    let mut registry = ReporterRegistry::with_builtins();
+    for format in available_formats() {
+        assert!(registry.contains(format), "built-in '{format}' missing");
+    }
+    assert!(!registry.register("count", || Box::new(CountReporter)));
+    assert_eq!(registry.create("count").unwrap().generate(&test_gate_results()).unwrap(), "3");
+    assert_eq!(registry.formats().len(), 11);
+
+    // Re-registering replaces the format.
+    assert!(registry.register("json", || Box::new(CountReporter)));
+    assert_eq!(registry.create("json").unwrap().name(), "count");
+    assert!(registry.create("nonexistent").is_none());
+    assert!(ReporterRegistry::new().formats().is_empty());
+}
+
+// T8-RPT-09: Template reporter renders the GateResult slice; templates load from a directory
+#[test]
+fn t8_rpt_09_template_reporter() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(
+        dir.path().join("review-bot.xml.j2"),
+        r#"<review>{% for v in violations %}
+<file name="{{ v.file }}"><error line="{{ v.line }}" severity="{{ v.severity }}" message="{{ v.message }}" source="{{ v.gate_id }}"/></file>{% endfor %}
+</review>"#,
+    )
+    .unwrap();
+    std::fs::write(
+        dir.path().join("dashboard.txt.tera"),
+        "{% for r in results %}{{ r.gate_id }}={{ r.score }} {% endfor %}total={{ total_violations }} passed={{ overall_passed }}",
+    )
+    .unwrap();
+    std::fs::write(dir.path().join("broken.j2"), "{% for x in %}").unwrap();
+    std::fs::write(dir.path().join("README.md"), "not a template").unwrap();
+
+    let mut registry = ReporterRegistry::with_builtins();
+    let registered = registry.register_templates_from_dir(dir.path());
+    assert_eq!(registered, vec!["dashboard", "review-bot"]);
+
+    let results = test_gate_results();
+    let dashboard = registry.create("dashboard").unwrap().generate(&results).unwrap();
+    assert_eq!(dashboard, "pattern-compliance=65.0 security-boundaries=95.0 total=3 passed=False");
+
+    let review_bot = registry.create("review-bot").unwrap();
+    assert_eq!(review_bot.name(), "review-bot");
+    let xml = review_bot.generate(&results).unwrap();
+    assert!(xml.contains(r#"<error line="42" severity="error""#), "{xml}");
+    assert!(xml.contains(r#"source="pattern-compliance""#));
+    assert_eq!(xml.matches("<error ").count(), 3);
+
+    // .xml templates escape markup in values.
+    let mut unsafe_results = results.clone();
+    unsafe_results[0].violations[0].message = "a < b & \"c\"".to_string();
+    let xml = review_bot.generate(&unsafe_results).unwrap();
+    assert!(xml.contains("a &lt; b &amp; &quot;c&quot;"));
+
+    // So do .xml.tera and .html.tmpl templates; a plain .tera one renders raw.
+    for (name, escaped) in [("report.xml.tera", true), ("report.html.tmpl", true), ("report.txt.tera", false)] {
+        let reporter = TemplateReporter::from_source(name, "{% for v in violations %}{{ v.message }}{% endfor %}").unwrap();
+        let output = reporter.generate(&unsafe_results).unwrap();
+        assert_eq!(output.contains("a &lt; b &amp;"), escaped, "{name}: {output}");
+        assert_eq!(output.contains("a < b &"), !escaped, "{name}: {output}");
+    }
+
+    assert!(TemplateReporter::from_source("bad.txt", "{{ unclosed").is_err());
+}
+
+// T8-RPT-10: Checkstyle XML — per-file grouping, severity mapping, rule ids, CWE tags
+#[test]
+fn t8_rpt_10_checkstyle_format() {
+    let reporter = checkstyle::CheckstyleReporter::new();
+    let output = reporter.generate(&test_gate_results()).unwrap();
+
+    assert!(output.contains(r#"<checkstyle version="8.0">"#));
+    assert_eq!(output.matches("<file name=").count(), 3);
+    // Files are sorted by path.
+    let auth = output.find(r#"<file name="src/auth.ts">"#).unwrap();
+    let db = output.find(r#"<file name="src/db.ts">"#).unwrap();
+    let utils = output.find(r#"<file name="src/utils.ts">"#).unwrap();
+    assert!(auth < db && db < utils);
+
+    assert!(output.contains(
+        r#"<error line="42" column="5" severity="error" message="Inconsistent error handling: missing try-catch [CWE-755, A09:2021]" source="drift.pattern-compliance.pattern-consistency" />"#
+    ));
+    // No column → no column attribute; Info → info.
+    assert!(output.contains(r#"<error line="10" severity="warning""#));
+    assert!(output.contains(r#"severity="info" message="Consider using camelCase for function names" source="drift.pattern-compliance.naming-convention""#));
+
+    // Markup in messages is escaped.
+    let mut results = test_gate_results();
+    results[0].violations[0].message = "a < b && \"c\"".to_string();
+    let output = reporter.generate(&results).unwrap();
+    assert!(output.contains("a &lt; b &amp;&amp; &quot;c&quot;"));
+
+    // Passing gates still produce a well-formed document.
+    let empty = reporter.generate(&empty_gate_results()).unwrap();
+    assert!(empty.contains("<checkstyle version=\"8.0\">\n</checkstyle>"));
+}
+
+// T8-RPT-11: Code Climate — engine spec fields and line-independent fingerprints
+#[test]
+fn t8_rpt_11_codeclimate_format() {
+    let reporter = codeclimate::CodeClimateReporter::new();
+    let output = reporter.generate(&test_gate_results()).unwrap();
+    let issues: Vec<serde_json::Value> = serde_json::from_str(&output).unwrap();
+    assert_eq!(issues.len(), 3);
+
+    let auth = issues.iter().find(|i| i["location"]["path"] == "src/auth.ts").unwrap();
+    assert_eq!(auth["type"], "issue");
+    assert_eq!(auth["engine_name"], "drift");
+    assert_eq!(auth["check_name"], "pattern-consistency");
+    assert_eq!(auth["severity"], "critical");
+    assert_eq!(auth["description"], "Inconsistent error handling: missing try-catch (CWE-755)");
+    assert_eq!(auth["location"]["positions"]["begin"]["line"], 42);
+    assert_eq!(auth["location"]["positions"]["begin"]["column"], 5);
+    assert_eq!(auth["location"]["positions"]["end"]["column"], 30);
+    assert!(auth["content"]["body"].as_str().unwrap().contains("CWE-755"));
+    assert!(auth["categories"].as_array().unwrap().contains(&serde_json::json!("Security")));
+    assert!(auth["remediation_points"].as_u64().unwrap() > 0);
+
+    // Without a column, the issue falls back to a line range.
+    let db = issues.iter().find(|i| i["location"]["path"] == "src/db.ts").unwrap();
+    assert_eq!(db["location"]["lines"]["begin"], 10);
+    assert_eq!(db["severity"], "major");
+
+    // Fingerprints survive line shifts and gate reordering...
+    let fingerprints = |results: &[GateResult]| -> Vec<String> {
+        let out = reporter.generate(results).unwrap();
+        let issues: Vec<serde_json::Value> = serde_json::from_str(&out).unwrap();
+        let mut fps: Vec<String> = issues.iter().map(|i| i["fingerprint"].as_str().unwrap().to_string()).collect();
+        fps.sort();
+        fps
+    };
+    let original = fingerprints(&test_gate_results());
+    let mut shifted = test_gate_results();
+    for v in &mut shifted[0].violations {
+        v.line += 7;
+    }
+    shifted.reverse();
+    assert_eq!(fingerprints(&shifted), original);
+
+    // ...while identical findings in one file stay distinct.
+    let mut duplicated = test_gate_results();
+    let mut copy = duplicated[0].violations[0].clone();
+    copy.line = 99;
+    duplicated[0].violations.push(copy);
+    let fps = fingerprints(&duplicated);
+    assert_eq!(fps.len(), 4);
+    let unique: std::collections::HashSet<_> = fps.iter().collect();
+    assert_eq!(unique.len(), 4);
+}
+
 // Test create_reporter factory
 #[test]
 fn test_create_reporter_factory() {
@@ -395,7 +590,7 @@ fn test_create_reporter_factory() {
 #[test]
 fn test_available_formats() {
     let formats = available_formats();
-    assert_eq!(formats.len(), 8);
+    assert_eq!(formats.len(), 10);
     assert!(formats.contains(&"sarif"));
     assert!(formats.contains(&"json"));
     assert!(formats.contains(&"console"));
@@ -404,6 +599,8 @@ fn test_available_formats() {
     assert!(formats.contains(&"junit"));
     assert!(formats.contains(&"html"));
     assert!(formats.contains(&"sonarqube"));
+    assert!(formats.contains(&"checkstyle"));
+    assert!(formats.contains(&"codeclimate"));
 }
 
 // Test GitLab fingerprint stability
@@ -439,6 +636,9 @@ fn test_suppressed_violations_excluded() {
             owasp_category: None,
             suppressed: true,
             is_new: false,
+            owners: Vec::new(),
+            last_author: None,
+            file_level: false,
         },
     ];
 
@@ -488,6 +688,9 @@ fn test_sonarqube_issue_types() {
             owasp_category: None,
             suppressed: false,
             is_new: false,
+            owners: Vec::new(),
+            last_author: None,
+            file_level: false,
         },
         Violation {
             id: "bug".to_string(),
@@ -505,6 +708,9 @@ fn test_sonarqube_issue_types() {
             owasp_category: None,
             suppressed: false,
             is_new: false,
+            owners: Vec::new(),
+            last_author: None,
+            file_level: false,
         },
         Violation {
             id: "smell".to_string(),
@@ -522,6 +728,9 @@ fn test_sonarqube_issue_types() {
             owasp_category: None,
             suppressed: false,
             is_new: false,
+            owners: Vec::new(),
+            last_author: None,
+            file_level: false,
         },
     ];
 
@@ -1,5 +1,5 @@
 //! Phase 6 tests: Reporters — Schema Validation & Format Correctness
-//! T6-RPT-01 through T6-RPT-07
+//! T6-RPT-01 through T6-RPT-08
 
 use drift_analysis::enforcement::gates::*;
 use drift_analysis::enforcement::reporters::*;
@@ -32,11 +32,16 @@ fn make_test_results() -> Vec<GateResult> {
                         strategy: QuickFixStrategy::WrapInTryCatch,
                         description: "Use parameterized query".to_string(),
                         replacement: None,
+                        fix_id: String::new(),
+                        edits: Vec::new(),
                     }),
                     cwe_id: Some(89),
                     owasp_category: Some("A03:2021-Injection".to_string()),
                     suppressed: false,
                     is_new: true,
+                    owners: Vec::new(),
+                    last_author: None,
+                    file_level: false,
                 },
                 Violation {
                     id: "v2".to_string(),
@@ -54,6 +59,9 @@ fn make_test_results() -> Vec<GateResult> {
                     owasp_category: None,
                     suppressed: false,
                     is_new: false,
+                    owners: Vec::new(),
+                    last_author: None,
+                    file_level: false,
                 },
             ],
             warnings: vec![],
@@ -168,6 +176,9 @@ fn test_sarif_10k_violations_performance() {
             owasp_category: None,
             suppressed: false,
             is_new: false,
+            owners: Vec::new(),
+            last_author: None,
+            file_level: false,
         })
         .collect();
 
@@ -270,3 +281,40 @@ fn test_sarif_cwe_owasp_references() {
     let relationships = sql_rule.unwrap()["relationships"].as_array().unwrap();
     assert!(relationships.iter().any(|r| r["target"]["id"].as_str().unwrap().contains("CWE-89")));
 }
+
+/// T6-RPT-08: Reporters carry owners and last authors, and summarize by owner.
+#[test]
+fn test_reporters_ownership() {
+    use drift_analysis::enforcement::reporters::html::HtmlReporter;
+
+    // Without ownership, no owner section.
+    let plain = ConsoleReporter::new(false).generate(&make_test_results()).unwrap();
+    assert!(!plain.contains("By owner"));
+
+    let mut results = make_test_results();
+    results[0].violations[0].owners = vec!["@acme/security".to_string()];
+    results[0].violations[0].last_author = Some("Alice".to_string());
+
+    let console = ConsoleReporter::new(false).generate(&results).unwrap();
+    assert!(console.contains("Owners: @acme/security"));
+    assert!(console.contains("Last changed by: Alice"));
+    assert!(console.contains("By owner"));
+    assert!(console.contains("@acme/security: 1 errors, 0 warnings, 1 total"));
+    assert!(console.contains("(unowned): 0 errors, 1 warnings, 1 total"));
+
+    let json: serde_json::Value = serde_json::from_str(&JsonReporter.generate(&results).unwrap()).unwrap();
+    let v1 = &json["gates"][0]["violations"][0];
+    assert_eq!(v1["owners"], serde_json::json!(["@acme/security"]));
+    assert_eq!(v1["last_author"], "Alice");
+    assert_eq!(json["by_owner"][0]["owner"], "@acme/security");
+    assert_eq!(json["by_owner"][1]["owner"], "(unowned)");
+
+    let sarif: serde_json::Value = serde_json::from_str(&SarifReporter::new().generate(&results).unwrap()).unwrap();
+    let properties = &sarif["runs"][0]["results"][0]["properties"];
+    assert_eq!(properties["owners"], serde_json::json!(["@acme/security"]));
+    assert_eq!(properties["lastAuthor"], "Alice");
+
+    let html = HtmlReporter::new().generate(&results).unwrap();
+    assert!(html.contains("<span class=\"tag tag-owner\">@acme/security</span>"));
+    assert!(html.contains("<h2>By owner</h2>"));
+}
@@ -1,5 +1,5 @@
 //! Phase 6 tests: Rules Engine — Violation Mapping & Suppression
-//! T6-RUL-01 through T6-RUL-06
+//! T6-RUL-01 through T6-RUL-12
 
 use drift_analysis::enforcement::rules::*;
 use std::collections::HashMap;
@@ -40,6 +40,7 @@ fn test_rules_engine_maps_patterns_to_violations() {
         ],
         source_lines: HashMap::new(),
         baseline_violation_ids: std::collections::HashSet::new(),
+        sources: Default::default(),
     };
 
     let violations = evaluator.evaluate(&input);
@@ -106,6 +107,7 @@ fn test_inline_suppression() {
         }],
         source_lines,
         baseline_violation_ids: std::collections::HashSet::new(),
+        sources: Default::default(),
     };
 
     let violations = evaluator.evaluate(&input);
@@ -237,6 +239,7 @@ fn test_violation_deduplication() {
         ],
         source_lines: HashMap::new(),
         baseline_violation_ids: std::collections::HashSet::new(),
+        sources: Default::default(),
     };
 
     let violations = evaluator.evaluate(&input);
@@ -254,6 +257,7 @@ fn test_severity_assignment_by_cwe() {
         patterns: vec![make_pattern("sql-inj", "security", 0.95, vec![89])],
         source_lines: HashMap::new(),
         baseline_violation_ids: std::collections::HashSet::new(),
+        sources: Default::default(),
     };
     let violations = evaluator.evaluate(&input);
     assert!(violations.iter().all(|v| v.severity == Severity::Error));
@@ -263,6 +267,7 @@ fn test_severity_assignment_by_cwe() {
         patterns: vec![make_pattern("camelCase", "naming", 0.8, vec![])],
         source_lines: HashMap::new(),
         baseline_violation_ids: std::collections::HashSet::new(),
+        sources: Default::default(),
     };
     let violations2 = evaluator.evaluate(&input2);
     assert!(violations2.iter().all(|v| v.severity == Severity::Info || v.severity == Severity::Warning));
@@ -272,7 +277,259 @@ fn test_severity_assignment_by_cwe() {
         patterns: vec![make_pattern("jsdoc", "documentation", 0.7, vec![])],
         source_lines: HashMap::new(),
         baseline_violation_ids: std::collections::HashSet::new(),
+        sources: Default::default(),
     };
     let violations3 = evaluator.evaluate(&input3);
     assert!(violations3.iter().all(|v| v.severity == Severity::Info));
 }
+
+fn fix_input(id: &str, category: &str, file: &str, line: u32, column: Option<u32>) -> (PatternInfo, OutlierLocation) {
+    let pattern = PatternInfo {
+        pattern_id: id.to_string(),
+        category: category.to_string(),
+        confidence: 0.9,
+        locations: vec![],
+        outliers: vec![],
+        cwe_ids: vec![],
+        owasp_categories: vec![],
+    };
+    let outlier = OutlierLocation {
+        file: file.to_string(),
+        line,
+        column,
+        end_line: None,
+        end_column: None,
+        deviation_score: 2.0,
+        message: "test".to_string(),
+    };
+    (pattern, outlier)
+}
+
+fn apply(source: &str, fix: &QuickFix) -> String {
+    let edits: Vec<&TextEdit> = fix.edits.iter().collect();
+    FixApplier::apply_edits(source, &edits).expect("edits should apply")
+}
+
+/// T6-RUL-07: Rename edits the binding's references in its scope but not
+/// strings, comments, properties or shadowing bindings.
+#[test]
+fn test_quick_fix_rename_edits() {
+    let source = "function get_user(user_id) {\n  // get_user is cached\n  return \"get_user\" + user_id;\n}\nget_user(1);\n";
+    let (pattern, outlier) = fix_input("naming-camelCase", "naming", "src/users.js", 0, Some(9));
+    let fix = QuickFixGenerator::new().suggest_with_source(&pattern, &outlier, source).unwrap();
+
+    assert_eq!(fix.strategy, QuickFixStrategy::Rename);
+    assert_eq!(fix.edits.len(), 2);
+    assert_eq!(
+        apply(source, &fix),
+        "function getUser(user_id) {\n  // get_user is cached\n  return \"get_user\" + user_id;\n}\ngetUser(1);\n"
+    );
+
+    // Same source → same fix ID.
+    let again = QuickFixGenerator::new().suggest_with_source(&pattern, &outlier, source).unwrap();
+    assert_eq!(fix.fix_id, again.fix_id);
+    assert!(fix.fix_id.starts_with("rename-"));
+
+    let scoped = "function load(user_id) {\n  return fetch(user_id);\n}\nfunction save(user_id) {\n  api.user_id = user_id;\n}\n";
+    let (pattern, outlier) = fix_input("naming-camelCase", "naming", "src/users.js", 0, Some(14));
+    let fix = QuickFixGenerator::new().suggest_with_source(&pattern, &outlier, scoped).unwrap();
+    assert_eq!(
+        apply(scoped, &fix),
+        "function load(userId) {\n  return fetch(userId);\n}\nfunction save(user_id) {\n  api.user_id = user_id;\n}\n"
+    );
+
+    // Exported, imported and class-member names are used from other files.
+    for source in [
+        "export function get_user() {}\nget_user();\n",
+        "import { get_user } from \"./users\";\nget_user();\n",
+        "class Users {\n  get_user() {}\n}\n",
+    ] {
+        let line = source.lines().position(|l| l.contains("get_user")).unwrap() as u32;
+        let column = source.lines().nth(line as usize).unwrap().find("get_user").unwrap() as u32;
+        let (pattern, outlier) = fix_input("naming-camelCase", "naming", "src/users.js", line, Some(column));
+        let fix = QuickFixGenerator::new().suggest_with_source(&pattern, &outlier, source).unwrap();
+        assert!(!fix.is_applicable(), "{source}");
+    }
+}
+
+/// T6-RUL-08: AddImport inserts after the last import and skips modules already imported.
+#[test]
+fn test_quick_fix_add_import_edits() {
+    let source = "import os\nimport sys\n\nprint(Path(\".\"))\n";
+    let (pattern, outlier) = fix_input("import/pathlib", "import", "app.py", 3, Some(6));
+    let fix = QuickFixGenerator::new().suggest_with_source(&pattern, &outlier, source).unwrap();
+    assert_eq!(
+        apply(source, &fix),
+        "import os\nimport sys\nfrom pathlib import Path\n\nprint(Path(\".\"))\n"
+    );
+
+    let imported = "from pathlib import Path\n\nprint(Path(\".\"))\n";
+    let (pattern, outlier) = fix_input("import/pathlib", "import", "app.py", 2, Some(6));
+    let fix = QuickFixGenerator::new().suggest_with_source(&pattern, &outlier, imported).unwrap();
+    assert!(!fix.is_applicable(), "already imported: no edits");
+
+    let go = "package main\n\nfunc main() {\n\tfmt.Println(1)\n}\n";
+    let (pattern, outlier) = fix_input("import/fmt", "import", "main.go", 3, None);
+    let fix = QuickFixGenerator::new().suggest_with_source(&pattern, &outlier, go).unwrap();
+    assert_eq!(apply(go, &fix), "package main\n\nimport \"fmt\"\n\nfunc main() {\n\tfmt.Println(1)\n}\n");
+}
+
+/// T6-RUL-09: WrapInTryCatch wraps the enclosing statement with a rethrowing handler.
+#[test]
+fn test_quick_fix_wrap_in_try_edits() {
+    let source = "async function load() {\n  await fetchData(url);\n  return 1;\n}\n";
+    let (pattern, outlier) = fix_input("async-errors", "error_handling", "src/load.ts", 1, None);
+    let fix = QuickFixGenerator::new().suggest_with_source(&pattern, &outlier, source).unwrap();
+    assert_eq!(
+        apply(source, &fix),
+        "async function load() {\n  try {\n    await fetchData(url);\n  } catch (error) {\n    throw error;\n  }\n  return 1;\n}\n"
+    );
+
+    let python = "def load():\n    data = fetch(url)\n    return data\n";
+    let (pattern, outlier) = fix_input("errors", "error_handling", "load.py", 1, None);
+    let fix = QuickFixGenerator::new().suggest_with_source(&pattern, &outlier, python).unwrap();
+    assert_eq!(
+        apply(python, &fix),
+        "def load():\n    try:\n        data = fetch(url)\n    except Exception:\n        raise\n    return data\n"
+    );
+
+    // Block-scoped declarations would be hidden from the code after them.
+    let decl = "function f() {\n  const data = parse(input);\n  return data;\n}\n";
+    let (pattern, outlier) = fix_input("errors", "error_handling", "src/f.js", 1, None);
+    let fix = QuickFixGenerator::new().suggest_with_source(&pattern, &outlier, decl).unwrap();
+    assert!(!fix.is_applicable());
+}
+
+/// T6-RUL-10: UseParameterizedQuery turns concatenation and interpolation into bound parameters.
+#[test]
+fn test_quick_fix_parameterized_query_edits() {
+    let js = "const rows = db.query(\"SELECT * FROM users WHERE id = \" + id + \" AND org = \" + org);\n";
+    let (pattern, outlier) = fix_input("sql-injection", "security", "src/db.js", 0, None);
+    let fix = QuickFixGenerator::new().suggest_with_source(&pattern, &outlier, js).unwrap();
+    assert_eq!(
+        apply(js, &fix),
+        "const rows = db.query(\"SELECT * FROM users WHERE id = ? AND org = ?\", [id, org]);\n"
+    );
+
+    let template = "db.query(`SELECT * FROM users WHERE name = ${name}`);\n";
+    let (pattern, outlier) = fix_input("sql-injection", "security", "src/db.ts", 0, None);
+    let fix = QuickFixGenerator::new().suggest_with_source(&pattern, &outlier, template).unwrap();
+    assert_eq!(apply(template, &fix), "db.query(`SELECT * FROM users WHERE name = ?`, [name]);\n");
+
+    let python = "cursor.execute(f\"SELECT * FROM users WHERE id = {user_id} AND pct > 50%\")\n";
+    let (pattern, outlier) = fix_input("sql-injection", "security", "repo.py", 0, None);
+    let fix = QuickFixGenerator::new().suggest_with_source(&pattern, &outlier, python).unwrap();
+    assert_eq!(
+        apply(python, &fix),
+        "cursor.execute(\"SELECT * FROM users WHERE id = %s AND pct > 50%%\", (user_id,))\n"
+    );
+
+    // The placeholder replaces the quoted value, quotes included.
+    let quoted = "db.query(\"SELECT * FROM users WHERE name = '\" + name + \"' AND id = \" + id);\n";
+    let (pattern, outlier) = fix_input("sql-injection", "security", "src/db.js", 0, None);
+    let fix = QuickFixGenerator::new().suggest_with_source(&pattern, &outlier, quoted).unwrap();
+    assert_eq!(
+        apply(quoted, &fix),
+        "db.query(\"SELECT * FROM users WHERE name = ? AND id = ?\", [name, id]);\n"
+    );
+    let quoted = "cursor.execute(f\"SELECT * FROM users WHERE name = '{name}'\")\n";
+    let (pattern, outlier) = fix_input("sql-injection", "security", "repo.py", 0, None);
+    let fix = QuickFixGenerator::new().suggest_with_source(&pattern, &outlier, quoted).unwrap();
+    assert_eq!(apply(quoted, &fix), "cursor.execute(\"SELECT * FROM users WHERE name = %s\", (name,))\n");
+
+    // A constant query has nothing to parameterize.
+    let constant = "db.query(\"SELECT 1\");\n";
+    let (pattern, outlier) = fix_input("sql-injection", "security", "src/db.js", 0, None);
+    let fix = QuickFixGenerator::new().suggest_with_source(&pattern, &outlier, constant).unwrap();
+    assert!(!fix.is_applicable());
+}
+
+/// T6-RUL-11: The evaluator computes edits from raw sources; SARIF carries them as artifactChanges.
+#[test]
+fn test_quick_fix_edits_in_sarif() {
+    use drift_analysis::enforcement::gates::{GateId, GateResult};
+    use drift_analysis::enforcement::reporters::sarif::SarifReporter;
+    use drift_analysis::enforcement::reporters::Reporter;
+
+    let (mut pattern, outlier) = fix_input("naming-camelCase", "naming", "src/a.js", 0, Some(6));
+    pattern.outliers.push(outlier);
+    let source = "const my_value = 1;\r\nuse(my_value);\r\n";
+    let mut input = RulesInput { patterns: vec![pattern], ..Default::default() };
+    input.source_lines.insert("src/a.js".to_string(), source.lines().map(String::from).collect());
+
+    // Lines alone can't place byte offsets: the fix stays advisory.
+    let violations = RulesEvaluator::new().evaluate(&input);
+    assert!(!violations[0].quick_fix.as_ref().unwrap().is_applicable());
+
+    input.sources.insert("src/a.js".to_string(), source.to_string());
+    let violations = RulesEvaluator::new().evaluate(&input);
+    let fix = violations[0].quick_fix.as_ref().unwrap();
+    assert_eq!(fix.edits.len(), 2);
+    // Second line starts after "const my_value = 1;\r\n".
+    assert_eq!(fix.edits[1].start_byte, 25);
+    assert_eq!(apply(source, fix), "const myValue = 1;\r\nuse(myValue);\r\n");
+
+    let gate = GateResult::fail(GateId::PatternCompliance, 50.0, "1 violation".to_string(), violations.clone());
+    let sarif: serde_json::Value = serde_json::from_str(&SarifReporter::new().generate(&[gate]).unwrap()).unwrap();
+    let sarif_fix = &sarif["runs"][0]["results"][0]["fixes"][0];
+    assert_eq!(sarif_fix["properties"]["fixId"], fix.fix_id.as_str());
+    let changes = sarif_fix["artifactChanges"].as_array().unwrap();
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0]["artifactLocation"]["uri"], "src/a.js");
+    let replacements = changes[0]["replacements"].as_array().unwrap();
+    assert_eq!(replacements.len(), 2);
+    assert_eq!(replacements[0]["deletedRegion"]["byteOffset"], 6);
+    assert_eq!(replacements[0]["deletedRegion"]["byteLength"], 8);
+    assert_eq!(replacements[0]["insertedContent"]["text"], "myValue");
+}
+
+/// T6-RUL-12: FixApplier applies non-overlapping fixes and reports conflicts.
+#[test]
+fn test_fix_applier_conflicts() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("a.js"), "let one = 1;\nlet two = 2;\n").unwrap();
+
+    let fix = |id: &str, start: usize, end: usize, text: &str, original: &str| QuickFix {
+        strategy: QuickFixStrategy::Rename,
+        description: String::new(),
+        replacement: None,
+        fix_id: id.to_string(),
+        edits: vec![TextEdit {
+            file: "a.js".to_string(),
+            start_byte: start,
+            end_byte: end,
+            replacement: text.to_string(),
+            original: original.to_string(),
+        }],
+    };
+    let fixes = vec![
+        fix("rename-one", 4, 7, "uno", "one"),
+        fix("rename-one-again", 5, 7, "NE", "ne"),
+        fix("rename-two", 17, 20, "dos", "two"),
+        // Identical edit to an accepted fix: not a conflict.
+        fix("rename-two-dup", 17, 20, "dos", "two"),
+        QuickFix { edits: vec![], ..fix("advisory", 0, 0, "", "") },
+    ];
+
+    let result = FixApplier::new(dir.path()).apply(&fixes).unwrap();
+    assert_eq!(result.applied, vec!["rename-one", "rename-two", "rename-two-dup"]);
+    assert_eq!(result.files_changed, vec!["a.js"]);
+    assert_eq!(result.conflicts.len(), 2);
+    assert_eq!(result.conflicts[0].fix_id, "rename-one-again");
+    assert_eq!(result.conflicts[0].conflicts_with.as_deref(), Some("rename-one"));
+    assert_eq!(result.conflicts[1].fix_id, "advisory");
+    assert_eq!(std::fs::read_to_string(dir.path().join("a.js")).unwrap(), "let uno = 1;\nlet dos = 2;\n");
+
+    // Re-applying against the changed file: the range no longer fits.
+    std::fs::write(dir.path().join("a.js"), "x").unwrap();
+    let result = FixApplier::new(dir.path()).apply(&fixes[..1]).unwrap();
+    assert!(result.applied.is_empty());
+    assert!(result.files_changed.is_empty());
+    assert_eq!(result.conflicts[0].reason, "edits no longer match the file contents");
+
+    // Same length, different text: the range is in bounds but holds something else.
+    std::fs::write(dir.path().join("a.js"), "let ONE = 1;\nlet two = 2;\n").unwrap();
+    let result = FixApplier::new(dir.path()).apply(&fixes[..1]).unwrap();
+    assert!(result.applied.is_empty());
+    assert_eq!(std::fs::read_to_string(dir.path().join("a.js")).unwrap(), "let ONE = 1;\nlet two = 2;\n");
+}
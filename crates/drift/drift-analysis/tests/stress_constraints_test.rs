@@ -44,9 +44,9 @@ fn detector_with_files() -> InvariantDetector {
     d.add_file(
         "src/auth/login.ts",
         vec![
-            FunctionInfo { name: "validateInput".into(), line: 5, is_exported: true },
-            FunctionInfo { name: "authenticate".into(), line: 15, is_exported: true },
-            FunctionInfo { name: "createSession".into(), line: 30, is_exported: false },
+            FunctionInfo { name: "validateInput".into(), line: 5, is_exported: true, complexity: Default::default() },
+            FunctionInfo { name: "authenticate".into(), line: 15, is_exported: true, complexity: Default::default() },
+            FunctionInfo { name: "createSession".into(), line: 30, is_exported: false, complexity: Default::default() },
         ],
         vec!["src/db/users.ts".to_string()],
         100,
@@ -54,8 +54,8 @@ fn detector_with_files() -> InvariantDetector {
     d.add_file(
         "src/db/users.ts",
         vec![
-            FunctionInfo { name: "findUser".into(), line: 1, is_exported: true },
-            FunctionInfo { name: "saveUser".into(), line: 20, is_exported: true },
+            FunctionInfo { name: "findUser".into(), line: 1, is_exported: true, complexity: Default::default() },
+            FunctionInfo { name: "saveUser".into(), line: 20, is_exported: true, complexity: Default::default() },
         ],
         vec![],
         50,
@@ -63,7 +63,7 @@ fn detector_with_files() -> InvariantDetector {
     d.add_file(
         "src/ui/dashboard.ts",
         vec![
-            FunctionInfo { name: "renderDashboard".into(), line: 1, is_exported: true },
+            FunctionInfo { name: "renderDashboard".into(), line: 1, is_exported: true, complexity: Default::default() },
         ],
         vec!["src/db/users.ts".to_string()],
         200,
@@ -311,13 +311,13 @@ fn stress_complexity_limit_within() {
     assert!(r.passed);
 }
 
-// ─── DataFlow (deferred) ────────────────────────────────────────────
+// ─── DataFlow (no call graph) ───────────────────────────────────────
 
 #[test]
-fn stress_dataflow_always_passes() {
+fn stress_dataflow_without_call_graph_passes() {
     let d = detector_with_files();
-    let r = d.verify(&constraint("c1", InvariantType::DataFlow, "anything"));
-    assert!(r.passed, "DataFlow is deferred to Phase 6 → should always pass");
+    let r = d.verify(&constraint("c1", InvariantType::DataFlow, "src/** !-> db.*"));
+    assert!(r.passed, "DataFlow needs a call graph → passes vacuously without one");
 }
 
 // ─── Disabled constraint stress ─────────────────────────────────────
@@ -415,12 +415,12 @@ fn stress_synthesizer_mixed_conventions() {
     let mut s = ConstraintSynthesizer::new();
     // Mix of camelCase and snake_case — should not synthesize a strong convention
     let camel_fns = vec![
-        FunctionInfo { name: "getUserById".into(), line: 1, is_exported: true },
-        FunctionInfo { name: "createOrder".into(), line: 5, is_exported: true },
+        FunctionInfo { name: "getUserById".into(), line: 1, is_exported: true, complexity: Default::default() },
+        FunctionInfo { name: "createOrder".into(), line: 5, is_exported: true, complexity: Default::default() },
     ];
     let snake_fns = vec![
-        FunctionInfo { name: "get_user_by_id".into(), line: 1, is_exported: true },
-        FunctionInfo { name: "create_order".into(), line: 5, is_exported: true },
+        FunctionInfo { name: "get_user_by_id".into(), line: 1, is_exported: true, complexity: Default::default() },
+        FunctionInfo { name: "create_order".into(), line: 5, is_exported: true, complexity: Default::default() },
     ];
     for i in 0..10 {
         s.add_file(&format!("src/camel{}.ts", i), camel_fns.clone());
@@ -436,9 +436,9 @@ fn stress_synthesizer_mixed_conventions() {
 fn stress_synthesizer_strong_convention() {
     let mut s = ConstraintSynthesizer::new();
     let camel_fns = vec![
-        FunctionInfo { name: "getUserById".into(), line: 1, is_exported: true },
-        FunctionInfo { name: "createOrder".into(), line: 5, is_exported: true },
-        FunctionInfo { name: "validateInput".into(), line: 10, is_exported: true },
+        FunctionInfo { name: "getUserById".into(), line: 1, is_exported: true, complexity: Default::default() },
+        FunctionInfo { name: "createOrder".into(), line: 5, is_exported: true, complexity: Default::default() },
+        FunctionInfo { name: "validateInput".into(), line: 10, is_exported: true, complexity: Default::default() },
     ];
     for i in 0..30 {
         s.add_file(&format!("src/module{}/handler.ts", i), camel_fns.clone());
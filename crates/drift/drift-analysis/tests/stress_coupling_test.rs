@@ -18,6 +18,7 @@ fn empty_graph() -> ImportGraph {
         modules: vec![],
         abstract_counts: FxHashMap::default(),
         total_type_counts: FxHashMap::default(),
+        file_modules: FxHashMap::default(),
     }
 }
 
@@ -34,6 +35,7 @@ fn simple_graph(modules: &[&str], edges: &[(&str, &[&str])]) -> ImportGraph {
         modules: modules.iter().map(|m| m.to_string()).collect(),
         abstract_counts: FxHashMap::default(),
         total_type_counts: FxHashMap::default(),
+        file_modules: FxHashMap::default(),
     }
 }
 
@@ -17,6 +17,7 @@ fn input(files: &[&str], edges: &[(&str, &str, &str)]) -> DecompositionInput {
                 path: f.to_string(),
                 line_count: 100,
                 language: "typescript".into(),
+                cyclomatic: 0,
             })
             .collect(),
         call_edges: edges
@@ -41,6 +42,7 @@ fn input_with_data(
                 path: f.to_string(),
                 line_count: 100,
                 language: "typescript".into(),
+                cyclomatic: 0,
             })
             .collect(),
         call_edges: edges
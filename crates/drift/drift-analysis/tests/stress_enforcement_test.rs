@@ -53,6 +53,7 @@ fn stress_unicode_everywhere() {
             owasp_categories: vec![],
         }],
         source_lines: HashMap::new(), baseline_violation_ids: std::collections::HashSet::new(),
+        sources: Default::default(),
     };
 
     let violations = evaluator.evaluate(&input);
@@ -137,6 +138,7 @@ fn stress_path_traversal_filenames() {
             owasp_categories: vec![],
         }],
         source_lines: HashMap::new(), baseline_violation_ids: std::collections::HashSet::new(),
+        sources: Default::default(),
     };
 
     let violations = evaluator.evaluate(&input);
@@ -174,6 +176,7 @@ fn stress_huge_strings() {
             owasp_categories: vec![],
         }],
         source_lines: HashMap::new(), baseline_violation_ids: std::collections::HashSet::new(),
+        sources: Default::default(),
     };
 
     let violations = evaluator.evaluate(&input);
@@ -342,6 +345,8 @@ fn stress_policy_empty_results() {
             required_gates: vec![],
             progressive: false,
             ramp_up_days: 0,
+            diff_scope: None,
+            gate_thresholds: HashMap::new(),
         };
         let engine = PolicyEngine::new(policy);
         let result = engine.evaluate(&[]);
@@ -410,6 +415,7 @@ fn stress_rules_to_gates_to_reporters_data_flow() {
             owasp_categories: vec!["A03:2021-Injection".to_string()],
         }],
         source_lines: HashMap::new(), baseline_violation_ids: std::collections::HashSet::new(),
+        sources: Default::default(),
     };
     let violations = evaluator.evaluate(&input);
     assert_eq!(violations.len(), 1);
@@ -495,6 +501,8 @@ fn stress_gates_to_policy_score_integrity() {
         required_gates: vec![],
         progressive: false,
         ramp_up_days: 0,
+        diff_scope: None,
+        gate_thresholds: HashMap::new(),
     };
     let engine = PolicyEngine::new(policy);
     let pr = engine.evaluate(&results);
@@ -519,6 +527,8 @@ fn stress_gates_to_policy_score_integrity() {
         required_gates: vec![],
         progressive: false,
         ramp_up_days: 0,
+        diff_scope: None,
+        gate_thresholds: HashMap::new(),
     };
     let engine2 = PolicyEngine::new(policy2);
     let pr2 = engine2.evaluate(&results);
@@ -544,6 +554,8 @@ fn stress_required_gates_override() {
         required_gates: vec![GateId::SecurityBoundaries],
         progressive: false,
         ramp_up_days: 0,
+        diff_scope: None,
+        gate_thresholds: HashMap::new(),
     };
     let engine = PolicyEngine::new(policy);
     let pr = engine.evaluate(&results);
@@ -684,11 +696,16 @@ fn stress_serde_round_trip_all_types() {
             strategy: QuickFixStrategy::WrapInTryCatch,
             description: "Use parameterized query".to_string(),
             replacement: Some("db.query($1, [input])".to_string()),
+            fix_id: String::new(),
+            edits: Vec::new(),
         }),
         cwe_id: Some(89),
         owasp_category: Some("A03:2021".to_string()),
         suppressed: false,
         is_new: true,
+        owners: Vec::new(),
+        last_author: None,
+        file_level: false,
     };
     let json = serde_json::to_string(&v).unwrap();
     let v2: Violation = serde_json::from_str(&json).unwrap();
@@ -829,6 +846,9 @@ fn stress_suppressed_violations_excluded_from_sarif() {
                 owasp_category: None,
                 suppressed: false,
                 is_new: false,
+                owners: Vec::new(),
+                last_author: None,
+                file_level: false,
             },
             Violation {
                 id: "v-suppressed".to_string(),
@@ -846,6 +866,9 @@ fn stress_suppressed_violations_excluded_from_sarif() {
                 owasp_category: None,
                 suppressed: true,
                 is_new: false,
+                owners: Vec::new(),
+                last_author: None,
+                file_level: false,
             },
         ],
         warnings: vec![],
@@ -1052,6 +1075,9 @@ fn stress_storage_full_round_trip() {
                 },
                 suppressed: i % 20 == 0, // 5% suppressed
                 is_new: false,
+                owners: None,
+                last_author: None,
+                quick_fix_id: None, quick_fix_edits: None,
             },
         )
         .unwrap();
@@ -1184,6 +1210,9 @@ fn stress_storage_upsert_no_duplicates() {
         owasp_category: None,
         suppressed: false,
         is_new: false,
+        owners: None,
+        last_author: None,
+        quick_fix_id: None, quick_fix_edits: None,
     };
 
     // Insert twice with same ID
@@ -1357,6 +1386,7 @@ fn stress_50k_violations_full_pipeline() {
             owasp_categories: vec![],
         }],
         source_lines: HashMap::new(), baseline_violation_ids: std::collections::HashSet::new(),
+        sources: Default::default(),
     };
 
     let start = std::time::Instant::now();
@@ -88,7 +88,7 @@ fn language_from_extension_none_and_unknown() {
 
 #[test]
 fn language_ts_language_new_variants_dont_panic() {
-    // The new languages use fallback grammars (C# for C/C++, Java for Swift/Scala).
+    // Each new variant is backed by its own tree-sitter grammar.
     // This test ensures ts_language() doesn't panic at runtime.
     let new_langs = [Language::Cpp, Language::C, Language::Swift, Language::Scala];
     for lang in &new_langs {
@@ -257,8 +257,8 @@ use drift_analysis::structural::decomposition::decomposer::{DecompositionInput,
 fn decomposition_empty_call_edges_and_data_access_doesnt_panic() {
     let input = DecompositionInput {
         files: vec![
-            FileEntry { path: "src/a.ts".to_string(), line_count: 100, language: "TypeScript".to_string() },
-            FileEntry { path: "src/b.ts".to_string(), line_count: 200, language: "TypeScript".to_string() },
+            FileEntry { path: "src/a.ts".to_string(), line_count: 100, language: "TypeScript".to_string(), cyclomatic: 0 },
+            FileEntry { path: "src/b.ts".to_string(), line_count: 200, language: "TypeScript".to_string(), cyclomatic: 0 },
         ],
         call_edges: vec![],
         data_access: vec![],
@@ -273,9 +273,9 @@ fn decomposition_empty_call_edges_and_data_access_doesnt_panic() {
 fn decomposition_with_real_call_edges_produces_modules() {
     let input = DecompositionInput {
         files: vec![
-            FileEntry { path: "src/api/handler.ts".to_string(), line_count: 200, language: "TypeScript".to_string() },
-            FileEntry { path: "src/api/service.ts".to_string(), line_count: 300, language: "TypeScript".to_string() },
-            FileEntry { path: "src/db/repo.ts".to_string(), line_count: 150, language: "TypeScript".to_string() },
+            FileEntry { path: "src/api/handler.ts".to_string(), line_count: 200, language: "TypeScript".to_string(), cyclomatic: 0 },
+            FileEntry { path: "src/api/service.ts".to_string(), line_count: 300, language: "TypeScript".to_string(), cyclomatic: 0 },
+            FileEntry { path: "src/db/repo.ts".to_string(), line_count: 150, language: "TypeScript".to_string(), cyclomatic: 0 },
         ],
         call_edges: vec![
             ("src/api/handler.ts".to_string(), "src/api/service.ts".to_string(), "getUser".to_string()),
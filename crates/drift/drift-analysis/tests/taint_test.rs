@@ -1,13 +1,18 @@
-//! T4-TNT-01 through T4-TNT-12: Taint analysis tests.
+//! T4-TNT-01 through T4-TNT-26: Taint analysis tests.
 
-use drift_analysis::graph::taint::intraprocedural::analyze_intraprocedural;
-use drift_analysis::graph::taint::interprocedural::analyze_interprocedural;
+use drift_analysis::graph::taint::intraprocedural::{analyze_intraprocedural, analyze_intraprocedural_with_source};
+use drift_analysis::graph::taint::interprocedural::{analyze_interprocedural, analyze_interprocedural_incremental};
 use drift_analysis::graph::taint::propagation::PropagationContext;
 use drift_analysis::graph::taint::registry::TaintRegistry;
 use drift_analysis::graph::taint::sarif::generate_sarif;
+use drift_analysis::graph::taint::summaries::SummaryCache;
 use drift_analysis::graph::taint::types::*;
 
+use drift_analysis::frameworks::registry::FrameworkPackRegistry;
+use drift_analysis::graph::taint::framework_specs::TaintFramework;
+
 use drift_analysis::call_graph::types::{CallEdge, CallGraph, FunctionNode, Resolution};
+use drift_analysis::parsers::manager::ParserManager;
 use drift_analysis::parsers::types::*;
 use drift_analysis::scanner::language_detect::Language;
 
@@ -33,6 +38,7 @@ fn make_parse_result(file: &str, functions: Vec<FunctionInfo>, call_sites: Vec<C
         error_count: 0,
         error_ranges: Vec::new(),
         has_errors: false,
+        type_bindings: Vec::new(),
     }
 }
 
@@ -62,6 +68,7 @@ fn make_function(name: &str, line: u32, end_line: u32, params: Vec<&str>) -> Fun
         doc_comment: None,
         body_hash: 0,
         signature_hash: 0,
+        complexity: Default::default(),
     }
 }
 
@@ -77,6 +84,47 @@ fn make_call(callee: &str, receiver: Option<&str>, line: u32) -> CallSite {
     }
 }
 
+/// Parse `source` and run the syntax-tree dataflow analysis on it.
+fn analyze_source(file: &str, source: &str) -> Vec<TaintFlow> {
+    let pr = ParserManager::new().parse(source.as_bytes(), std::path::Path::new(file)).unwrap();
+    analyze_intraprocedural_with_source(&pr, source, &TaintRegistry::with_defaults())
+}
+
+/// Flows into the sink on 0-based `line`.
+fn flows_at(flows: &[TaintFlow], line: u32) -> Vec<&TaintFlow> {
+    flows.iter().filter(|f| f.sink.line == line).collect()
+}
+
+/// Frameworks whose taint specs the built-in packs switch on for `imports`.
+fn detect_taint_frameworks(imports: &[String]) -> Vec<TaintFramework> {
+    FrameworkPackRegistry::with_builtins().detect_taint_frameworks(&[], imports)
+}
+
+/// Analyzes a file under `test-fixtures/taint/frameworks` with the specs of
+/// the frameworks detected from its imports, as the pipeline does.
+fn analyze_fixture(name: &str) -> (Vec<TaintFramework>, Vec<TaintFlow>) {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../../../test-fixtures/taint/frameworks")
+        .join(name);
+    let source = std::fs::read_to_string(&path).unwrap();
+    let pr = ParserManager::new().parse(source.as_bytes(), &path).unwrap();
+    let imports: Vec<String> = pr.imports.iter().map(|i| i.source.clone()).collect();
+    let frameworks = detect_taint_frameworks(&imports);
+    let flows = analyze_intraprocedural_with_source(&pr, &source, &TaintRegistry::with_frameworks(&frameworks));
+    (frameworks, flows)
+}
+
+/// (source line, sink line) of every vulnerable flow, 0-based.
+fn vulnerable_paths(flows: &[TaintFlow]) -> Vec<(u32, u32)> {
+    let mut paths: Vec<(u32, u32)> = flows.iter()
+        .filter(|f| f.is_vulnerability())
+        .map(|f| (f.source.line, f.sink.line))
+        .collect();
+    paths.sort_unstable();
+    paths.dedup();
+    paths
+}
+
 // T4-TNT-01: Taint traces source→sink with sanitizer tracking
 #[test]
 fn test_taint_source_to_sink_with_sanitizer() {
@@ -319,6 +367,7 @@ fn test_recursive_function_no_infinite_loop() {
         is_exported: true,
         signature_hash: 0,
         body_hash: 0,
+        complexity: Default::default(),
     });
     // Self-edge (recursive call)
     g.add_edge(a, a, CallEdge {
@@ -360,6 +409,7 @@ fn test_long_taint_path() {
             is_exported: i == 0,
             signature_hash: 0,
             body_hash: 0,
+            complexity: Default::default(),
         });
         nodes.push(node);
     }
@@ -422,6 +472,7 @@ fn test_path_too_long_error() {
             is_exported: i == 0,
             signature_hash: 0,
             body_hash: 0,
+            complexity: Default::default(),
         });
         nodes.push(node);
     }
@@ -504,12 +555,12 @@ fn test_all_sink_types_have_cwe() {
     assert_eq!(SinkType::all_builtin().len(), 17);
 }
 
-// Coverage boost: Exercise all 12 framework specs
+// Coverage boost: Exercise all 19 framework specs
 #[test]
-fn test_framework_specs_all_12() {
+fn test_framework_specs_all_19() {
     use drift_analysis::graph::taint::framework_specs::{TaintFramework, apply_framework_specs};
 
-    assert_eq!(TaintFramework::all().len(), 12);
+    assert_eq!(TaintFramework::all().len(), 19);
 
     for fw in TaintFramework::all() {
         let mut registry = TaintRegistry::new();
@@ -528,7 +579,13 @@ fn test_framework_specs_all_12() {
                 || registry.match_source("ctx.query").is_some()
                 || registry.match_source("@Body").is_some()
                 || registry.match_source("c.Query").is_some()
-                || registry.match_source("web::Query").is_some(),
+                || registry.match_source("web::Query").is_some()
+                || registry.match_source("fastapi.Query").is_some()
+                || registry.match_source("searchParams").is_some()
+                || registry.match_source("axum::extract::Query").is_some()
+                || registry.match_source("c.QueryParam").is_some()
+                || registry.match_source("request.query").is_some()
+                || registry.match_source("c.req.query").is_some(),
             "Framework {} should register at least one source",
             fw_name
         );
@@ -569,3 +626,349 @@ fn test_taint_label_operations() {
     assert_eq!(label.id, 42);
     assert_eq!(label.origin, SourceType::UserInput);
 }
+
+// T4-TNT-13: Reassignment replaces taint — a sanitized or constant
+// reassignment clears it, later re-tainting restores it
+#[test]
+fn test_dataflow_reassignment() {
+    let flows = analyze_source("handler.js", r#"
+function handler(req, res) {
+  let q = req.query.name;
+  q = escape(q);
+  res.send(q);
+  q = req.query.raw;
+  res.send(q);
+  q = "constant";
+  res.send(q);
+}
+"#);
+    let sanitized = flows_at(&flows, 4);
+    assert_eq!(sanitized.len(), 1);
+    assert!(sanitized[0].is_sanitized, "escape() dominates the first sink");
+    assert_eq!(sanitized[0].source.expression, "req.query.name");
+
+    let retainted = flows_at(&flows, 6);
+    assert_eq!(retainted.len(), 1);
+    assert!(retainted[0].is_vulnerability(), "reassignment from a source re-taints q");
+    assert_eq!(retainted[0].source.expression, "req.query.raw");
+
+    assert!(flows_at(&flows, 8).is_empty(), "constant reassignment kills taint");
+}
+
+// T4-TNT-14: Branch-local sanitization only protects the branch; a
+// sanitizer on every arm or a validation guard protects the join
+#[test]
+fn test_dataflow_branch_sanitization() {
+    let flows = analyze_source("handler.js", r#"
+function handler(req, res) {
+  let q = req.query.name;
+  if (req.query.trusted) {
+    q = escape(q);
+  }
+  res.send(q);
+  let r = req.query.other;
+  if (flag) { r = escape(r); } else { r = escapeHtml(r); }
+  res.send(r);
+  const id = req.body.id;
+  if (!validate(id)) {
+    return;
+  }
+  db.query(id);
+}
+"#);
+    assert!(flows_at(&flows, 6)[0].is_vulnerability(), "fall-through path skips escape()");
+    assert!(flows_at(&flows, 9)[0].is_sanitized, "both arms sanitize");
+    let guarded = flows_at(&flows, 14);
+    assert!(guarded[0].is_sanitized, "validate() guard dominates the sink");
+    assert_eq!(guarded[0].sanitizers_applied[0].expression, "validate");
+
+    let py = analyze_source("views.py", r#"
+def view(request):
+    q = request.args.get("q")
+    if request.args.get("safe"):
+        q = shlex.quote(q)
+    os.system(q)
+"#);
+    assert_eq!(py.len(), 1);
+    assert!(py[0].is_vulnerability(), "Python branch-local quote() does not cover the join");
+}
+
+// T4-TNT-15: Aliases and fields are tracked separately
+#[test]
+fn test_dataflow_aliasing_and_fields() {
+    let flows = analyze_source("handler.js", r#"
+function handler(req, res) {
+  let q = req.query;
+  const copy = q;
+  q = escape(q);
+  res.send(q);
+  res.send(copy);
+  const ctx = {};
+  ctx.safe = "ok";
+  ctx.user = req.body.user;
+  db.query(ctx.safe);
+  db.query(ctx.user);
+}
+"#);
+    assert!(flows_at(&flows, 5)[0].is_sanitized, "q is sanitized after reassignment");
+    let alias = flows_at(&flows, 6);
+    assert!(alias[0].is_vulnerability(), "the alias taken before escape() stays tainted");
+    assert!(alias[0].path.iter().any(|hop| hop.description.contains("`copy`")));
+    assert!(flows_at(&flows, 10).is_empty(), "clean field is not tainted by its sibling");
+    assert_eq!(flows_at(&flows, 11)[0].source.expression, "req.body.user");
+}
+
+// T4-TNT-16: is_sanitized_for is per variable
+#[test]
+fn test_sanitized_for_is_per_variable() {
+    let mut ctx = PropagationContext::new();
+    ctx.taint_variable("a", SourceType::UserInput);
+    ctx.taint_variable("b", SourceType::UserInput);
+    ctx.sanitize("a", SanitizerType::SqlParameterize, &[SinkType::SqlQuery]);
+    assert!(ctx.is_sanitized_for("a", &SinkType::SqlQuery));
+    assert!(!ctx.is_sanitized_for("b", &SinkType::SqlQuery), "sanitizing a must not clear b");
+    assert!(!ctx.is_sanitized_for("a", &SinkType::OsCommand));
+
+    ctx.propagate("a", "c");
+    assert!(ctx.is_sanitized_for("c", &SinkType::SqlQuery), "copies keep sanitization");
+    ctx.merge(&["a", "b"], "joined");
+    assert!(!ctx.is_sanitized_for("joined", &SinkType::SqlQuery), "join with unsanitized b");
+
+    ctx.taint_variable("a", SourceType::UserInput);
+    assert!(!ctx.is_sanitized_for("a", &SinkType::SqlQuery), "re-tainting resets sanitization");
+}
+
+// T4-TNT-17: Incremental summaries — a second run reuses everything, and a
+// changed callee recomputes only itself and its transitive callers
+#[test]
+fn test_incremental_summaries_dirty_cone() {
+    let registry = TaintRegistry::with_defaults();
+    let build = |db_body_hash: u64, with_unrelated: bool| {
+        let mut g = CallGraph::new();
+        let mut names = vec![("handler.ts", "handle"), ("service.ts", "load"), ("db.ts", "run")];
+        if with_unrelated {
+            names.push(("util.ts", "format"));
+        }
+        let nodes: Vec<_> = names
+            .iter()
+            .map(|(file, name)| {
+                g.add_function(FunctionNode {
+                    file: file.to_string(),
+                    name: name.to_string(),
+                    qualified_name: None,
+                    language: "typescript".to_string(),
+                    line: 1,
+                    end_line: 10,
+                    is_entry_point: *name == "handle",
+                    is_exported: true,
+                    signature_hash: 0,
+                    body_hash: if *name == "run" { db_body_hash } else { 0 },
+                    complexity: Default::default(),
+                })
+            })
+            .collect();
+        for pair in nodes[..3].windows(2) {
+            g.add_edge(pair[0], pair[1], CallEdge {
+                resolution: Resolution::ImportBased,
+                confidence: 0.75,
+                call_site_line: 5,
+            });
+        }
+        g
+    };
+    let prs = vec![
+        make_parse_result("handler.ts", vec![make_function("handle", 1, 10, vec!["req"])], vec![
+            make_call("query", Some("req"), 3),
+            make_call("load", None, 5),
+        ]),
+        make_parse_result("service.ts", vec![make_function("load", 1, 10, vec![])], vec![
+            make_call("run", None, 5),
+        ]),
+        make_parse_result("db.ts", vec![make_function("run", 1, 10, vec![])], vec![
+            make_call("query", Some("db"), 5),
+        ]),
+        make_parse_result("util.ts", vec![make_function("format", 1, 10, vec![])], vec![]),
+    ];
+
+    let g = build(1, true);
+    let full = analyze_interprocedural(&g, &prs, &registry, None).unwrap();
+    assert!(!full.is_empty(), "handler → db flow expected");
+
+    let mut cache = SummaryCache::new();
+    let (first, stats) = analyze_interprocedural_incremental(&g, &prs, &registry, None, &mut cache).unwrap();
+    assert_eq!(first.len(), full.len());
+    assert_eq!((stats.reused, stats.recomputed), (0, 4));
+    assert_eq!(cache.updated().len(), 4);
+    cache.mark_persisted();
+
+    let (second, stats) = analyze_interprocedural_incremental(&g, &prs, &registry, None, &mut cache).unwrap();
+    assert_eq!((stats.reused, stats.recomputed), (4, 0));
+    assert!(cache.updated().is_empty());
+    assert_eq!(second.len(), full.len());
+    assert_eq!(second[0].sink.file, full[0].sink.file);
+
+    // db.ts::run changed: it and its callers are dirty, util.ts is not
+    let g = build(2, true);
+    let (third, stats) = analyze_interprocedural_incremental(&g, &prs, &registry, None, &mut cache).unwrap();
+    assert_eq!((stats.reused, stats.recomputed), (1, 3));
+    let updated: Vec<&str> = cache.updated().iter().map(|e| e.function_id.as_str()).collect();
+    assert_eq!(updated, vec!["db.ts::run", "handler.ts::handle", "service.ts::load"]);
+    assert_eq!(third.len(), full.len());
+    cache.mark_persisted();
+
+    // Functions that leave the call graph are dropped from the cache
+    let g = build(2, false);
+    let (_, stats) = analyze_interprocedural_incremental(&g, &prs, &registry, None, &mut cache).unwrap();
+    assert_eq!((stats.reused, stats.removed), (3, 1));
+    assert_eq!(cache.removed(), ["util.ts::format".to_string()]);
+    assert_eq!(cache.len(), 3);
+}
+
+// T4-TNT-18: Bind values are not query text — a parameterized query with
+// tainted parameters is clean, interpolating them into the SQL is not
+#[test]
+fn test_dataflow_sql_bind_parameters() {
+    let flows = analyze_source("handler.js", r#"
+function handler(req, res) {
+  const id = req.params.id;
+  db.query("SELECT * FROM users WHERE id = $1", [id]);
+  db.query(`SELECT * FROM users WHERE id = ${id}`);
+  db.query("SELECT * FROM users WHERE id = " + id, []);
+}
+"#);
+    assert!(flows_at(&flows, 3).is_empty(), "tainted bind value is not an injection");
+    assert!(flows_at(&flows, 4)[0].is_vulnerability(), "template interpolation reaches the query");
+    assert!(flows_at(&flows, 5)[0].is_vulnerability(), "concatenation reaches the query");
+
+    let py = analyze_source("views.py", r#"
+def view(request, cursor):
+    name = request.args.get("name")
+    cursor.execute("SELECT id FROM accounts WHERE name = %s", (name,))
+    cursor.execute("SELECT id FROM accounts WHERE name = '%s'" % name)
+"#);
+    assert!(flows_at(&py, 3).is_empty(), "DB-API parameters are bound by the driver");
+    assert_eq!(flows_at(&py, 4).len(), 1);
+
+    // The query is the argument that builds SQL, wherever it sits; a query
+    // held in a variable falls back to the usual position.
+    let placed = analyze_source("repo.js", r#"
+function find(req, res) {
+  const id = req.params.id;
+  db.query(id, "SELECT * FROM users");
+  db.query({ timeout: 5 }, "SELECT * FROM users WHERE id = " + id);
+  const sql = "DELETE FROM users WHERE id = " + id;
+  db.query(sql);
+}
+"#);
+    assert!(flows_at(&placed, 3).is_empty(), "constant query text is not injectable");
+    assert!(flows_at(&placed, 4)[0].is_vulnerability(), "interpolated query in a later argument");
+    assert!(flows_at(&placed, 6)[0].is_vulnerability(), "query built in a variable");
+}
+
+// T4-TNT-19: FastAPI — Query()/Header() markers and pydantic bodies are
+// sources; a Depends() dependency is tainted only if it reads request input
+#[test]
+fn test_framework_fixture_fastapi() {
+    let (frameworks, flows) = analyze_fixture("fastapi_app.py");
+    assert!(frameworks.contains(&TaintFramework::FastApi));
+    assert_eq!(vulnerable_paths(&flows), vec![(20, 21), (25, 26), (30, 31)]);
+    assert!(flows_at(&flows, 21)[0].sink.sink_type == SinkType::HtmlOutput);
+    assert!(flows_at(&flows, 31)[0].sink.sink_type == SinkType::HttpRedirect);
+}
+
+// T4-TNT-20: Next.js — NextRequest, page searchParams and server-action FormData
+#[test]
+fn test_framework_fixture_nextjs() {
+    let (frameworks, flows) = analyze_fixture("nextjs_route.ts");
+    assert!(frameworks.contains(&TaintFramework::NextJs));
+    assert_eq!(vulnerable_paths(&flows), vec![(7, 9), (14, 15), (18, 19)]);
+}
+
+// T4-TNT-21: Axum — extractor parameters are sources, Html/Redirect are sinks
+#[test]
+fn test_framework_fixture_axum() {
+    let (frameworks, flows) = analyze_fixture("axum_handlers.rs");
+    assert!(frameworks.contains(&TaintFramework::Axum));
+    assert_eq!(vulnerable_paths(&flows), vec![(7, 9), (12, 13)]);
+    assert!(flows_at(&flows, 13)[0].sink.sink_type == SinkType::HttpRedirect);
+}
+
+// T4-TNT-22: Echo — echo.Context accessors
+#[test]
+fn test_framework_fixture_echo() {
+    let (frameworks, flows) = analyze_fixture("echo_handlers.go");
+    assert!(frameworks.contains(&TaintFramework::Echo));
+    assert_eq!(vulnerable_paths(&flows), vec![(12, 13), (17, 17)]);
+}
+
+// T4-TNT-23: Fiber — fiber.Ctx accessors
+#[test]
+fn test_framework_fixture_fiber() {
+    let (frameworks, flows) = analyze_fixture("fiber_handlers.go");
+    assert!(frameworks.contains(&TaintFramework::Fiber));
+    assert_eq!(vulnerable_paths(&flows), vec![(8, 9), (13, 13)]);
+    assert!(flows_at(&flows, 9)[0].sink.sink_type == SinkType::FileRead);
+}
+
+// T4-TNT-24: Symfony — Request bags, Response objects, htmlspecialchars
+#[test]
+fn test_framework_fixture_symfony() {
+    let (frameworks, flows) = analyze_fixture("SymfonyController.php");
+    assert!(frameworks.contains(&TaintFramework::Symfony));
+    assert_eq!(vulnerable_paths(&flows), vec![(14, 15), (26, 26)]);
+    let escaped = flows_at(&flows, 21);
+    assert!(!escaped.is_empty() && escaped.iter().all(|f| f.is_sanitized), "htmlspecialchars sanitizes the response body");
+}
+
+// T4-TNT-25: Hono — c.req accessors and Context responses
+#[test]
+fn test_framework_fixture_hono() {
+    let (frameworks, flows) = analyze_fixture("hono_app.ts");
+    assert!(frameworks.contains(&TaintFramework::Hono));
+    assert_eq!(vulnerable_paths(&flows), vec![(6, 7), (11, 11)]);
+}
+
+// T4-TNT-26: Framework specs are activated only by a matching import
+#[test]
+fn test_framework_detection_from_imports() {
+    let detected = detect_taint_frameworks(&[
+        "fastapi.responses".to_string(),
+        "github.com/labstack/echo/v4".to_string(),
+        "axum::extract".to_string(),
+        "hono".to_string(),
+    ]);
+    for fw in [TaintFramework::FastApi, TaintFramework::Echo, TaintFramework::Axum, TaintFramework::Hono] {
+        assert!(detected.contains(&fw), "{} should be detected", fw.name());
+    }
+    assert!(!detected.contains(&TaintFramework::Fiber));
+    assert!(detect_taint_frameworks(&["honours".to_string(), "fastapi_utils".to_string()]).is_empty());
+
+    // Pack detection still matches import substrings; taint specs don't,
+    // including those that rely on their pack's signals.
+    let imports = vec!["express-validator".to_string(), "koala".to_string()];
+    let mut registry = FrameworkPackRegistry::with_builtins();
+    assert!(registry.evaluate_signals(&[], &imports).contains(&"express".to_string()));
+    assert!(registry.detect_taint_frameworks(&[], &imports).is_empty());
+    assert_eq!(detect_taint_frameworks(&["express".to_string()]), vec![TaintFramework::Express]);
+
+    // Packs name the specs they switch on; unknown names are skipped.
+    let pack = FrameworkPackRegistry::load_single(r#"
+[framework]
+name = "web"
+languages = ["go"]
+[[framework.taint]]
+framework = "gin"
+[[framework.taint]]
+framework = "martini"
+"#).unwrap();
+    let specs: Vec<TaintFramework> = pack.taint_specs.iter().map(|t| t.framework).collect();
+    assert_eq!(specs, vec![TaintFramework::Gin]);
+
+    // Without the Axum spec, extractor parameters are not sources.
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../../../test-fixtures/taint/frameworks/axum_handlers.rs");
+    let source = std::fs::read_to_string(&path).unwrap();
+    let pr = ParserManager::new().parse(source.as_bytes(), &path).unwrap();
+    let flows = analyze_intraprocedural_with_source(&pr, &source, &TaintRegistry::with_defaults());
+    assert!(flows_at(&flows, 9).is_empty());
+}
@@ -1,5 +1,5 @@
 #![allow(clippy::field_reassign_with_default)]
-//! T4-TST-01 through T4-TST-04: Test topology tests.
+//! T4-TST-01 through T4-TST-07: Test topology tests.
 
 use drift_analysis::call_graph::types::{CallEdge, CallGraph, FunctionNode, Resolution};
 use drift_analysis::graph::test_topology::*;
@@ -20,6 +20,7 @@ fn make_node(file: &str, name: &str, exported: bool) -> FunctionNode {
         is_exported: exported,
         signature_hash: 0,
         body_hash: 0,
+        complexity: Default::default(),
     }
 }
 
@@ -51,6 +52,7 @@ fn make_parse_result(file: &str, functions: Vec<FunctionInfo>, call_sites: Vec<C
         error_count: 0,
         error_ranges: Vec::new(),
         has_errors: false,
+        type_bindings: Vec::new(),
     }
 }
 
@@ -75,6 +77,7 @@ fn make_function(name: &str, line: u32, end_line: u32) -> FunctionInfo {
         doc_comment: None,
         body_hash: 0,
         signature_hash: 0,
+        complexity: Default::default(),
     }
 }
 
@@ -508,3 +511,165 @@ fn test_reachability_types() {
     assert_eq!(ReachabilityEngine::SqliteCte.name(), "sqlite_cte");
     assert_eq!(format!("{}", ReachabilityEngine::Petgraph), "petgraph");
 }
+
+// T4-TST-05: Coverage report import — LCOV, Cobertura, JaCoCo, Go coverprofile
+#[test]
+fn test_coverage_report_import() {
+    use std::path::Path;
+
+    let lcov = "TN:\nSF:src/auth.ts\nDA:2,3\nDA:3,0\nend_of_record\nSF:src/db.ts\nDA:5,0\nend_of_record\n";
+    assert_eq!(CoverageFormat::detect(Path::new("lcov.info"), lcov), Some(CoverageFormat::Lcov));
+    let report = parse_coverage_report(lcov, CoverageFormat::Lcov).unwrap();
+    let auth = report.lines_for("src/auth.ts").unwrap();
+    assert_eq!(auth.get(&2), Some(&3));
+    assert_eq!(auth.get(&3), Some(&0));
+    assert!(parse_coverage_report("DA:1,1\n", CoverageFormat::Lcov).is_err(), "DA outside SF");
+
+    let cobertura = r#"<?xml version="1.0"?>
+<coverage line-rate="0.5">
+  <sources><source>/home/ci/project</source></sources>
+  <packages><package name="app"><classes>
+    <class name="auth" filename="app/auth.py">
+      <methods><method name="login"><lines><line number="4" hits="2"/></lines></method></methods>
+      <lines><line number="4" hits="1"/><line number="5" hits="0"/></lines>
+    </class>
+  </classes></package></packages>
+</coverage>"#;
+    assert_eq!(CoverageFormat::detect(Path::new("coverage.xml"), cobertura), Some(CoverageFormat::Cobertura));
+    let report = parse_coverage_report(cobertura, CoverageFormat::Cobertura).unwrap();
+    let auth = report.lines_for("app/auth.py").unwrap();
+    assert_eq!(auth.get(&4), Some(&2), "highest hit count wins");
+    assert_eq!(auth.get(&5), Some(&0));
+
+    // Relative file names resolve against the <sources> roots.
+    let rooted = r#"<?xml version="1.0"?>
+<coverage>
+  <sources><source>/home/ci/project/src</source><source>/home/ci/project/gen/</source></sources>
+  <packages><package name="app"><classes>
+    <class name="auth" filename="app/auth.py"><lines><line number="7" hits="3"/></lines></class>
+    <class name="abs" filename="/opt/vendor/lib.py"><lines><line number="1" hits="1"/></lines></class>
+  </classes></package></packages>
+</coverage>"#;
+    let report = parse_coverage_report(rooted, CoverageFormat::Cobertura).unwrap();
+    assert_eq!(report.lines_for("src/app/auth.py").unwrap().get(&7), Some(&3));
+    assert_eq!(report.lines_for("gen/app/auth.py").unwrap().get(&7), Some(&3));
+    assert!(report.lines_for("lib/app/auth.py").is_none(), "outside every source root");
+    assert!(report.lines_for("vendor/lib.py").is_some(), "absolute file names are kept");
+
+    let jacoco = r#"<?xml version="1.0"?>
+<!DOCTYPE report PUBLIC "-//JACOCO//DTD Report 1.1//EN" "report.dtd">
+<report name="demo">
+  <package name="com/example">
+    <class name="com/example/Auth" sourcefilename="Auth.java"/>
+    <sourcefile name="Auth.java">
+      <line nr="10" mi="0" ci="4" mb="0" cb="0"/>
+      <line nr="11" mi="3" ci="0" mb="0" cb="0"/>
+    </sourcefile>
+  </package>
+</report>"#;
+    assert_eq!(CoverageFormat::detect(Path::new("jacoco.xml"), jacoco), Some(CoverageFormat::JaCoCo));
+    let report = parse_coverage_report(jacoco, CoverageFormat::JaCoCo).unwrap();
+    // Project paths match on whole trailing segments.
+    let auth = report.lines_for("src/main/java/com/example/Auth.java").unwrap();
+    assert_eq!(auth.get(&10), Some(&4));
+    assert_eq!(auth.get(&11), Some(&0));
+    assert!(report.lines_for("src/main/java/com/other/Auth.java").is_none());
+
+    let go = "mode: count\ngithub.com/acme/svc/auth/auth.go:3.20,5.2 2 7\ngithub.com/acme/svc/auth/auth.go:8.10,9.2 1 0\n";
+    assert_eq!(CoverageFormat::detect(Path::new("coverage.out"), go), Some(CoverageFormat::GoCoverprofile));
+    let report = parse_coverage_report(go, CoverageFormat::GoCoverprofile).unwrap();
+    let auth = report.lines_for("auth/auth.go").unwrap();
+    assert_eq!(auth.get(&4), Some(&7));
+    assert_eq!(auth.get(&9), Some(&0));
+
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::create_dir_all(dir.path().join("coverage")).unwrap();
+    std::fs::write(dir.path().join("coverage/lcov.info"), lcov).unwrap();
+    let found = coverage_import::discover_coverage_reports(dir.path());
+    assert_eq!(found.len(), 1);
+    assert!(load_coverage_report(&found[0]).is_ok());
+    std::fs::write(dir.path().join("coverage.xml"), "not a report").unwrap();
+    assert!(load_coverage_report(&dir.path().join("coverage.xml")).is_err());
+}
+
+// T4-TST-06: Measured coverage merges with the inferred mapping and wins where present
+#[test]
+fn test_measured_coverage_merge() {
+    let mut g = CallGraph::new();
+    let authenticate = g.add_function(make_node("src/auth.ts", "authenticate", true));
+    let query = g.add_function(make_node("src/db.ts", "query", false));
+    let format = g.add_function(make_node("src/utils.ts", "format", false));
+    let untested = g.add_function(make_node("src/other.ts", "untested", false));
+    let test_1 = g.add_function(make_node("tests/auth.test.ts", "test_authenticate", false));
+    g.add_edge(test_1, authenticate, make_edge());
+    g.add_edge(test_1, query, make_edge());
+    g.add_edge(test_1, format, make_edge());
+
+    let inferred = compute_coverage(&g);
+    assert_eq!(inferred.covered_source_functions(), 3);
+    assert_eq!(inferred.evidence.get(&format), Some(&CoverageEvidence::Inferred));
+
+    // authenticate ran; query never ran despite the call edge; other.ts ran
+    // without any test reaching it through the graph; utils.ts isn't in the report.
+    let lcov = "SF:src/auth.ts\nDA:3,4\nDA:4,0\nend_of_record\n\
+                SF:src/db.ts\nDA:3,0\nend_of_record\n\
+                SF:/ci/checkout/src/other.ts\nDA:5,1\nend_of_record\n";
+    let report = parse_coverage_report(lcov, CoverageFormat::Lcov).unwrap();
+    let coverage = compute_coverage_with_report(&g, &report);
+
+    assert!(coverage.has_measured_data());
+    assert_eq!(coverage.evidence.get(&authenticate), Some(&CoverageEvidence::MeasuredAndInferred));
+    assert_eq!(coverage.measured.get(&authenticate), Some(&MeasuredCoverage { lines_total: 2, lines_hit: 1 }));
+    assert_eq!(coverage.evidence.get(&query), Some(&CoverageEvidence::Measured));
+    assert!(!coverage.is_covered(query));
+    assert_eq!(coverage.evidence.get(&format), Some(&CoverageEvidence::Inferred));
+    assert!(coverage.is_covered(format));
+    assert_eq!(coverage.evidence.get(&untested), Some(&CoverageEvidence::Measured));
+    assert!(coverage.is_covered(untested));
+    assert_eq!(coverage.covered_source_functions(), 3);
+
+    // The minimum set only needs to cover functions the tests really reach.
+    let min_set = compute_minimum_test_set(&coverage);
+    assert_eq!(min_set.total_functions, 2);
+    assert_eq!(min_set.covered_functions, 2);
+
+    let scored = compute_quality_score_with_coverage(&g, &[], &coverage);
+    assert!((scored.coverage_breadth - 0.75).abs() < 1e-6);
+}
+
+// T4-TST-07: The TestCoverage gate prefers measured coverage
+#[test]
+fn test_coverage_gate_prefers_measured() {
+    use drift_analysis::enforcement::gates::*;
+
+    let mut g = CallGraph::new();
+    let a = g.add_function(make_node("src/a.ts", "a", true));
+    let b = g.add_function(make_node("src/b.ts", "b", true));
+    let t = g.add_function(make_node("tests/a.test.ts", "test_a", false));
+    g.add_edge(t, a, make_edge());
+    g.add_edge(t, b, make_edge());
+
+    // Inferred: both covered. Measured: b never ran.
+    let report = parse_coverage_report("SF:src/a.ts\nDA:2,1\nend_of_record\nSF:src/b.ts\nDA:2,0\nend_of_record\n", CoverageFormat::Lcov).unwrap();
+    let coverage = compute_coverage_with_report(&g, &report);
+
+    let input = GateInputBuilder::new()
+        .test_coverage(TestCoverageInput::from_coverage_mapping(&g, &coverage, 80.0).unwrap())
+        .build();
+    let tc = input.test_coverage.as_ref().unwrap();
+    assert_eq!(tc.overall_coverage, 100.0);
+    assert_eq!(tc.measured_coverage, Some(50.0));
+    assert_eq!(tc.uncovered_files, vec!["src/b.ts".to_string()]);
+
+    let result = test_coverage::TestCoverageGate.evaluate(&input);
+    assert!(!result.passed, "measured 50% is below the 80% threshold");
+    assert_eq!(result.score, 50.0);
+    assert!(result.summary.contains("measured"));
+
+    // Without a report the inference decides.
+    let inferred = GateInputBuilder::new()
+        .test_coverage(TestCoverageInput::from_coverage_mapping(&g, &compute_coverage(&g), 80.0).unwrap())
+        .build();
+    assert!(inferred.test_coverage.as_ref().unwrap().measured_coverage.is_none());
+    assert!(test_coverage::TestCoverageGate.evaluate(&inferred).passed);
+}
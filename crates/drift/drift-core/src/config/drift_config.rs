@@ -119,6 +119,14 @@ impl DriftConfig {
                 });
             }
         }
+        if let Some(ref scope) = config.quality_gates.diff_scope {
+            if scope != "new_code" && scope != "touched_functions" {
+                return Err(ConfigError::ValidationFailed {
+                    field: "quality_gates.diff_scope".to_string(),
+                    message: "must be \"new_code\" or \"touched_functions\"".to_string(),
+                });
+            }
+        }
         if let Some(ref max_file_size) = config.scan.max_file_size {
             if *max_file_size == 0 {
                 return Err(ConfigError::ValidationFailed {
@@ -241,6 +249,21 @@ impl DriftConfig {
         if other.quality_gates.ramp_up_period.is_some() {
             base.quality_gates.ramp_up_period = other.quality_gates.ramp_up_period;
         }
+        if other.quality_gates.diff_scope.is_some() {
+            base.quality_gates.diff_scope = other.quality_gates.diff_scope.clone();
+        }
+        if other.quality_gates.diff_base.is_some() {
+            base.quality_gates.diff_base = other.quality_gates.diff_base.clone();
+        }
+        if other.quality_gates.diff_head.is_some() {
+            base.quality_gates.diff_head = other.quality_gates.diff_head.clone();
+        }
+        if other.quality_gates.blame_owners.is_some() {
+            base.quality_gates.blame_owners = other.quality_gates.blame_owners;
+        }
+        if other.quality_gates.min_coverage.is_some() {
+            base.quality_gates.min_coverage = other.quality_gates.min_coverage;
+        }
 
         // MCP
         if other.mcp.cache_ttl_seconds.is_some() {
@@ -333,6 +356,20 @@ impl DriftConfig {
                 config.quality_gates.min_score = Some(v);
             }
         }
+        if let Ok(val) = std::env::var("DRIFT_GATE_DIFF_SCOPE") {
+            config.quality_gates.diff_scope = Some(val);
+        }
+        if let Ok(val) = std::env::var("DRIFT_GATE_DIFF_BASE") {
+            config.quality_gates.diff_base = Some(val);
+        }
+        if let Ok(val) = std::env::var("DRIFT_GATE_DIFF_HEAD") {
+            config.quality_gates.diff_head = Some(val);
+        }
+        if let Ok(val) = std::env::var("DRIFT_GATE_BLAME_OWNERS") {
+            if let Ok(v) = val.parse::<bool>() {
+                config.quality_gates.blame_owners = Some(v);
+            }
+        }
         if let Ok(val) = std::env::var("DRIFT_MCP_MAX_RESPONSE_TOKENS") {
             if let Ok(v) = val.parse::<u32>() {
                 config.mcp.max_response_tokens = Some(v);
@@ -20,6 +20,17 @@ pub struct GateConfig {
     pub progressive_enforcement: Option<bool>,
     /// Ramp-up period in days for progressive enforcement.
     pub ramp_up_period: Option<u32>,
+    /// Diff scope: "new_code" | "touched_functions". Requires `diff_base`.
+    pub diff_scope: Option<String>,
+    /// Base revision of the change range to gate (e.g. "origin/main").
+    pub diff_base: Option<String>,
+    /// Head revision of the change range. Default: the working tree.
+    pub diff_head: Option<String>,
+    /// Attach the last author of each violating line via git blame.
+    /// Default: false.
+    pub blame_owners: Option<bool>,
+    /// Minimum test coverage percentage for the test-coverage gate. Default: 80.
+    pub min_coverage: Option<f64>,
 }
 
 impl GateConfig {
@@ -32,4 +43,16 @@ impl GateConfig {
     pub fn effective_min_score(&self) -> u32 {
         self.min_score.unwrap_or(70)
     }
+
+    /// Returns the effective minimum test coverage, defaulting to 80.
+    pub fn effective_min_coverage(&self) -> f64 {
+        self.min_coverage.unwrap_or(80.0)
+    }
+
+    /// Returns the effective diff scope, defaulting to "new_code" once a
+    /// base revision is set.
+    pub fn effective_diff_scope(&self) -> Option<&str> {
+        self.diff_base.as_ref()?;
+        Some(self.diff_scope.as_deref().unwrap_or("new_code"))
+    }
 }
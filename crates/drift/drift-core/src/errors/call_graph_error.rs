@@ -16,6 +16,9 @@ pub enum CallGraphError {
 
     #[error("CTE fallback failed: {message}")]
     CteFallbackFailed { message: String },
+
+    #[error("Coverage import failed for {path}: {message}")]
+    CoverageImportFailed { path: String, message: String },
 }
 
 impl DriftErrorCode for CallGraphError {
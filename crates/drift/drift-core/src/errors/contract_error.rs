@@ -0,0 +1,19 @@
+//! Contract tracking errors.
+
+use super::error_code::{self, DriftErrorCode};
+
+/// Errors that can occur while extracting or comparing API contracts.
+#[derive(Debug, thiserror::Error)]
+pub enum ContractError {
+    #[error("Cannot resolve revision '{revision}': {message}")]
+    RevisionNotFound { revision: String, message: String },
+
+    #[error("Git error: {0}")]
+    Git(String),
+}
+
+impl DriftErrorCode for ContractError {
+    fn error_code(&self) -> &'static str {
+        error_code::CONTRACT_ERROR
+    }
+}
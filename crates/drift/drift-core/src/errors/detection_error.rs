@@ -16,6 +16,9 @@ pub enum DetectionError {
 
     #[error("Detection timeout after {timeout_ms}ms")]
     Timeout { timeout_ms: u64 },
+
+    #[error("Git error: {0}")]
+    Git(String),
 }
 
 impl DriftErrorCode for DetectionError {
@@ -33,5 +33,6 @@ pub const DISK_FULL: &str = "DISK_FULL";
 pub const MIGRATION_FAILED: &str = "MIGRATION_FAILED";
 pub const TAINT_ERROR: &str = "TAINT_ERROR";
 pub const CONSTRAINT_ERROR: &str = "CONSTRAINT_ERROR";
+pub const CONTRACT_ERROR: &str = "CONTRACT_ERROR";
 pub const BOUNDARY_ERROR: &str = "BOUNDARY_ERROR";
 pub const PIPELINE_ERROR: &str = "PIPELINE_ERROR";
@@ -13,6 +13,15 @@ pub enum GateError {
 
     #[error("Policy violation: {0}")]
     PolicyViolation(String),
+
+    #[error("Baseline file {path}: {message}")]
+    BaselineFailed { path: String, message: String },
+
+    #[error("Policy file {path}: {message}")]
+    PolicyFileInvalid { path: String, message: String },
+
+    #[error("Code ownership {path}: {message}")]
+    OwnershipFailed { path: String, message: String },
 }
 
 impl DriftErrorCode for GateError {
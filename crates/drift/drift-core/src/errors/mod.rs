@@ -5,6 +5,7 @@ pub mod boundary_error;
 pub mod call_graph_error;
 pub mod config_error;
 pub mod constraint_error;
+pub mod contract_error;
 pub mod context_error;
 pub mod detection_error;
 pub mod error_code;
@@ -20,6 +21,7 @@ pub use boundary_error::BoundaryError;
 pub use call_graph_error::CallGraphError;
 pub use config_error::ConfigError;
 pub use constraint_error::ConstraintError;
+pub use contract_error::ContractError;
 pub use context_error::ContextError;
 pub use detection_error::DetectionError;
 pub use error_code::DriftErrorCode;
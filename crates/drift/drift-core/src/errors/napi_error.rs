@@ -3,7 +3,7 @@
 
 use super::error_code::DriftErrorCode;
 use super::{
-    BoundaryError, CallGraphError, ConfigError, ConstraintError, DetectionError,
+    BoundaryError, CallGraphError, ConfigError, ConstraintError, ContractError, DetectionError,
     GateError, ParseError, PipelineError, ScanError, StorageError, TaintError,
 };
 
@@ -76,6 +76,12 @@ impl From<ConstraintError> for NapiError {
     }
 }
 
+impl From<ContractError> for NapiError {
+    fn from(e: ContractError) -> Self {
+        Self::new(e.error_code(), e.to_string())
+    }
+}
+
 impl From<BoundaryError> for NapiError {
     fn from(e: BoundaryError) -> Self {
         Self::new(e.error_code(), e.to_string())
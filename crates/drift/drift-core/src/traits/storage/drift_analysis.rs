@@ -24,6 +24,9 @@ pub struct FunctionRow {
     pub is_async: bool,
     pub body_hash: Option<Vec<u8>>,
     pub signature_hash: Option<Vec<u8>>,
+    pub cyclomatic: i64,
+    pub cognitive: i64,
+    pub max_nesting: i64,
 }
 
 /// A detection record.
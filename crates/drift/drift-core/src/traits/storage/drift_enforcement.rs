@@ -26,6 +26,13 @@ pub struct ViolationRow {
     pub owasp_category: Option<String>,
     pub suppressed: bool,
     pub is_new: bool,
+    /// JSON array of CODEOWNERS owners.
+    pub owners: Option<String>,
+    pub last_author: Option<String>,
+    /// Stable id of the quick fix.
+    pub quick_fix_id: Option<String>,
+    /// JSON array of the quick fix's text edits.
+    pub quick_fix_edits: Option<String>,
 }
 
 #[derive(Debug, Clone)]
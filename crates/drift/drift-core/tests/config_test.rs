@@ -81,6 +81,7 @@ fn test_load_missing_files_fallback() {
     assert_eq!(config.scan.effective_max_file_size(), 1_048_576);
     assert_eq!(config.analysis.effective_min_occurrences(), 3);
     assert_eq!(config.quality_gates.effective_min_score(), 70);
+    assert_eq!(config.quality_gates.effective_min_coverage(), 80.0);
     assert_eq!(config.mcp.effective_max_response_tokens(), 8000);
 }
 
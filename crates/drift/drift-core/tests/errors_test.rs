@@ -239,6 +239,7 @@ fn test_napi_error_from_all_types() {
     let _: NapiError = PipelineError::Cancelled.into();
     let _: NapiError = TaintError::InvalidSource("src".into()).into();
     let _: NapiError = ConstraintError::InvalidInvariant("inv".into()).into();
+    let _: NapiError = ContractError::Git("bad object".into()).into();
     let _: NapiError = BoundaryError::UnknownOrm("orm".into()).into();
     let _: NapiError = GateError::EvaluationFailed("fail".into()).into();
     let _: NapiError = ConfigError::FileNotFound {
@@ -326,7 +326,7 @@ fn stress_error_codes_are_unique_constants() {
         UNSUPPORTED_LANGUAGE, DETECTION_ERROR, CALL_GRAPH_ERROR,
         CONFIG_ERROR, LICENSE_ERROR, GATE_FAILED, STORAGE_ERROR,
         DISK_FULL, MIGRATION_FAILED, TAINT_ERROR, CONSTRAINT_ERROR,
-        BOUNDARY_ERROR, PIPELINE_ERROR,
+        BOUNDARY_ERROR, PIPELINE_ERROR, CONTRACT_ERROR,
     ];
     // All codes are non-empty
     for code in &codes {
@@ -337,7 +337,7 @@ fn stress_error_codes_are_unique_constants() {
     for code in &codes {
         assert!(seen.insert(*code), "duplicate error code: {code}");
     }
-    assert_eq!(codes.len(), 18, "expected 18 error code constants");
+    assert_eq!(codes.len(), 19, "expected 19 error code constants");
 }
 
 #[test]
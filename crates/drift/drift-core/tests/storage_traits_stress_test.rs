@@ -623,6 +623,9 @@ fn row_types_with_empty_strings() {
         is_async: false,
         body_hash: None,
         signature_hash: None,
+        cyclomatic: 0,
+        cognitive: 0,
+        max_nesting: 0,
     };
     assert_eq!(func.name, "");
 }
@@ -646,6 +649,9 @@ fn row_types_with_unicode() {
         owasp_category: None,
         suppressed: false,
         is_new: true,
+        owners: None,
+        last_author: None,
+        quick_fix_id: None, quick_fix_edits: None,
     };
     assert_eq!(violation.id, "违规_1");
     assert_eq!(violation.rule_id, "rule_🔥");
@@ -667,6 +673,9 @@ fn row_type_boundary_integer_values() {
         is_async: true,
         body_hash: Some(vec![255; 32]),
         signature_hash: Some(vec![0; 32]),
+        cyclomatic: 0,
+        cognitive: 0,
+        max_nesting: 0,
     };
     assert_eq!(func.id, i64::MAX);
     assert_eq!(func.line, i64::MAX);
@@ -939,6 +948,8 @@ fn all_enforcement_row_types_constructible() {
         quick_fix_description: Some("Use X instead".into()),
         cwe_id: Some(79), owasp_category: Some("A03".into()),
         suppressed: false, is_new: true,
+        owners: None, last_author: None,
+        quick_fix_id: None, quick_fix_edits: None,
     };
     let _g = GateResultRow {
         gate_id: "g1".into(), status: "passed".into(), passed: true, score: 0.95,
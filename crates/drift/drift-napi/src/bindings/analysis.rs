@@ -133,6 +133,21 @@ fn storage_err(e: impl std::fmt::Display) -> napi::Error {
     napi::Error::from_reason(format!("[{}] {e}", error_codes::STORAGE_ERROR))
 }
 
+/// Fingerprint context for baseline matching from parsed files and their sources.
+pub(crate) fn baseline_context<'a>(
+    parse_results: impl IntoIterator<Item = &'a drift_analysis::parsers::ParseResult>,
+    file_contents: &std::collections::HashMap<String, String>,
+) -> drift_analysis::enforcement::baseline::FingerprintContext {
+    let mut context = drift_analysis::enforcement::baseline::FingerprintContext::new();
+    for pr in parse_results {
+        context.add_parse_result(pr);
+        if let Some(source) = file_contents.get(&pr.file) {
+            context.add_source(&pr.file, source);
+        }
+    }
+    context
+}
+
 /// Run the analysis pipeline on the project.
 ///
 /// Orchestrates in phases:
@@ -159,16 +174,26 @@ pub async fn drift_analyze(max_phase: Option<u32>) -> napi::Result<Vec<JsAnalysi
 
     // Step 2: Parse each file and run detection
     let parser_manager = drift_analysis::parsers::ParserManager::new();
-    let detection_engine = drift_analysis::engine::DetectionEngine::new(
-        drift_analysis::engine::VisitorRegistry::new(),
-    );
+    // Custom TOML patterns from .drift/patterns/ run as visitor handlers.
+    let mut visitor_registry = drift_analysis::engine::VisitorRegistry::new();
+    if let Some(patterns_dir) = rt.project_root.as_ref().map(|p| p.join(".drift").join("patterns")) {
+        if patterns_dir.is_dir() {
+            let custom = drift_analysis::engine::TomlPatternLoader::load_from_dir(&patterns_dir);
+            let handler = drift_analysis::engine::TomlQueryHandler::new(custom);
+            drift_log!("[drift-analyze] custom query patterns loaded: {}", handler.query_count());
+            if handler.query_count() > 0 {
+                visitor_registry.register(Box::new(handler));
+            }
+        }
+    }
+    let detection_engine = drift_analysis::engine::DetectionEngine::new(visitor_registry);
     let mut analysis_pipeline = drift_analysis::engine::AnalysisPipeline::with_engine(
         detection_engine,
     );
 
     // Step 2a: Load framework packs (built-in + custom from .drift/frameworks/)
     let fw_load_timer = std::time::Instant::now();
-    let framework_registry = {
+    let mut framework_registry = {
         let custom_dir = rt.project_root.as_ref()
             .map(|p| p.join(".drift").join("frameworks"));
         match custom_dir {
@@ -178,7 +203,7 @@ pub async fn drift_analyze(max_phase: Option<u32>) -> napi::Result<Vec<JsAnalysi
             _ => drift_analysis::frameworks::registry::FrameworkPackRegistry::with_builtins(),
         }
     };
-    let framework_packs = framework_registry.into_packs();
+    let framework_packs = framework_registry.packs().to_vec();
     let framework_packs_for_learner = framework_packs.clone();
     let mut framework_matcher = drift_analysis::frameworks::FrameworkMatcher::new(framework_packs);
     let mut framework_learner = drift_analysis::frameworks::FrameworkLearner::new(framework_packs_for_learner);
@@ -194,11 +219,18 @@ pub async fn drift_analyze(max_phase: Option<u32>) -> napi::Result<Vec<JsAnalysi
     let mut detection_rows: Vec<drift_storage::batch::commands::DetectionRow> = Vec::new();
     let mut function_rows: Vec<drift_storage::batch::commands::FunctionRow> = Vec::new();
     let mut all_parse_results: Vec<drift_analysis::parsers::ParseResult> = Vec::new();
+    let mut all_sql_statements: Vec<drift_analysis::engine::embedded_sql::SqlStatement> = Vec::new();
     // File content cache — read once in Phase 1, reused in Phase 3+ sub-steps.
     // Eliminates ~15,000 redundant disk reads (9 sub-steps × 1700 files).
     let mut file_contents: std::collections::HashMap<String, String> = std::collections::HashMap::new();
 
     let project_root = rt.project_root.as_deref();
+    // Call graphs resolve imports through the project's manifests when the root is known.
+    let module_resolver = project_root.map(drift_analysis::call_graph::ModuleResolver::from_root);
+    let call_graph_builder = || match &module_resolver {
+        Some(resolver) => drift_analysis::call_graph::CallGraphBuilder::new().with_module_resolver(resolver.clone()),
+        None => drift_analysis::call_graph::CallGraphBuilder::new(),
+    };
 
     for file_meta in &files {
         let file_path = if let Some(root) = project_root {
@@ -278,6 +310,7 @@ pub async fn drift_analyze(max_phase: Option<u32>) -> napi::Result<Vec<JsAnalysi
 
         // Collect matches for pattern intelligence
         all_matches.extend(result.matches.iter().cloned());
+        all_sql_statements.extend(result.sql_statements.iter().cloned());
 
         // Convert to detection rows for batch persistence
         for m in &result.matches {
@@ -314,6 +347,9 @@ pub async fn drift_analyze(max_phase: Option<u32>) -> napi::Result<Vec<JsAnalysi
                 is_async: func.is_async,
                 body_hash: func.body_hash.to_le_bytes().to_vec(),
                 signature_hash: func.signature_hash.to_le_bytes().to_vec(),
+                cyclomatic: func.complexity.cyclomatic as i64,
+                cognitive: func.complexity.cognitive as i64,
+                max_nesting: func.complexity.max_nesting as i64,
             });
         }
 
@@ -500,6 +536,19 @@ pub async fn drift_analyze(max_phase: Option<u32>) -> napi::Result<Vec<JsAnalysi
                 });
             }
 
+            // Sensitive columns read or written by raw SQL
+            for access in boundary_detector.link_sql_statements(&all_sql_statements, &boundary_result) {
+                boundary_rows.push(drift_storage::batch::commands::BoundaryRow {
+                    file: access.file,
+                    framework: drift_analysis::engine::embedded_sql::RAW_SQL_FRAMEWORK.to_string(),
+                    model_name: access.model_name.unwrap_or_else(|| access.table.clone()),
+                    table_name: Some(access.table),
+                    field_name: Some(access.column),
+                    sensitivity: Some(access.sensitivity.name().to_string()),
+                    confidence: access.confidence as f64,
+                });
+            }
+
             if !boundary_rows.is_empty() {
                 rt.storage.send_batch(
                     drift_storage::batch::commands::BatchCommand::InsertBoundaries(boundary_rows),
@@ -525,7 +574,7 @@ pub async fn drift_analyze(max_phase: Option<u32>) -> napi::Result<Vec<JsAnalysi
         }
 
         // Call graph building → persist call edges
-        let cg_builder = drift_analysis::call_graph::CallGraphBuilder::new();
+        let cg_builder = call_graph_builder();
         if let Ok((call_graph, _stats)) = cg_builder.build(&all_parse_results) {
             use petgraph::visit::{EdgeRef, IntoEdgeReferences};
             let call_edge_rows: Vec<drift_storage::batch::commands::CallEdgeRow> = call_graph
@@ -558,6 +607,9 @@ pub async fn drift_analyze(max_phase: Option<u32>) -> napi::Result<Vec<JsAnalysi
     }
 
     let phase_timer = std::time::Instant::now();
+    // Outlier locations per pattern, for pattern compliance and its quick fixes.
+    let mut pattern_outliers: std::collections::HashMap<String, Vec<drift_analysis::enforcement::rules::types::OutlierLocation>> =
+        std::collections::HashMap::new();
     // Step 4: Run pattern intelligence pipeline (with feedback store for closed-loop)
     if !all_matches.is_empty() {
         let feedback_store = crate::feedback_store::DbFeedbackStore::new(rt.clone());
@@ -604,6 +656,19 @@ pub async fn drift_analyze(max_phase: Option<u32>) -> napi::Result<Vec<JsAnalysi
                 .collect();
 
             for o in outliers {
+                if let Some(m) = pattern_matches.get(o.index).filter(|_| o.is_outlier) {
+                    pattern_outliers.entry(pid.clone()).or_default().push(
+                        drift_analysis::enforcement::rules::types::OutlierLocation {
+                            file: m.file.clone(),
+                            line: m.line,
+                            column: Some(m.column),
+                            end_line: None,
+                            end_column: None,
+                            deviation_score: o.deviation_score.value(),
+                            message: format!("Deviates from pattern '{pid}' (method: {})", o.method.name()),
+                        },
+                    );
+                }
                 // Use the outlier index to look up the source detection, fallback to first match
                 let source = pattern_matches.get(o.index).or_else(|| pattern_matches.first());
                 let (file, line) = match source {
@@ -742,6 +807,53 @@ pub async fn drift_analyze(max_phase: Option<u32>) -> napi::Result<Vec<JsAnalysi
             ).map_err(storage_err)?;
         }
 
+        // Logical coupling from co-change history → change_coupling
+        if let Some(root) = rt.project_root.as_deref() {
+            match drift_analysis::structural::coupling::ChangeCouplingAnalyzer::new()
+                .with_max_commits(HISTORY_MAX_COMMITS)
+                .analyze(root)
+            {
+                Ok(logical) => {
+                    let packages = match drift_core::workspace::detect_workspace(root) {
+                        Ok(drift_core::workspace::WorkspaceLayout::Monorepo { packages, .. }) => packages,
+                        _ => Vec::new(),
+                    };
+                    let module_pairs = drift_analysis::structural::coupling::module_change_coupling(
+                        &logical, &import_graph, &cycles, &packages,
+                    );
+                    drift_log!(
+                        "[drift-analyze] change coupling: {} file pairs, {} module pairs ({} hidden)",
+                        logical.pairs.len(),
+                        module_pairs.len(),
+                        module_pairs.iter().filter(|m| m.is_hidden()).count()
+                    );
+                    let rows: Vec<drift_storage::queries::structural::ChangeCouplingRow> = module_pairs
+                        .into_iter()
+                        .map(|m| drift_storage::queries::structural::ChangeCouplingRow {
+                            hidden: m.is_hidden(),
+                            module_a: m.module_a,
+                            module_b: m.module_b,
+                            co_changes: m.co_changes,
+                            confidence: m.confidence,
+                            file_a: m.file_a,
+                            file_b: m.file_b,
+                            file_pairs: m.file_pairs,
+                            import_edge: m.import_edge,
+                            in_cycle: m.in_cycle,
+                            package_a: m.package_a,
+                            package_b: m.package_b,
+                        })
+                        .collect();
+                    if let Err(e) = rt.storage.with_writer(|conn| {
+                        drift_storage::queries::structural::replace_change_coupling(conn, &rows)
+                    }) {
+                        drift_log!("[drift-analyze] warning: change coupling not stored: {}", e);
+                    }
+                }
+                Err(e) => drift_log!("[drift-analyze] change coupling skipped: {}", e),
+            }
+        }
+
         drift_log!("[drift-analyze] 5a (coupling): {:?}", step_timer.elapsed());
         let step_timer = std::time::Instant::now();
 
@@ -928,6 +1040,12 @@ pub async fn drift_analyze(max_phase: Option<u32>) -> napi::Result<Vec<JsAnalysi
             ).map_err(storage_err)?;
         }
 
+        // Secrets anywhere in git history → historical_secrets, resuming
+        // from where the previous analysis stopped.
+        if let Some(root) = rt.project_root.as_deref() {
+            persist_secret_history(&rt, root);
+        }
+
         drift_log!("[drift-analyze] 5e (secrets): {:?}", step_timer.elapsed());
         let step_timer = std::time::Instant::now();
 
@@ -973,6 +1091,7 @@ pub async fn drift_analyze(max_phase: Option<u32>) -> napi::Result<Vec<JsAnalysi
                         name: f.name.clone(),
                         line: f.line,
                         is_exported: f.is_exported,
+                        complexity: f.complexity,
                     }
                 }).collect();
                 let imports: Vec<String> = pr.imports.iter().map(|i| i.source.clone()).collect();
@@ -982,6 +1101,22 @@ pub async fn drift_analyze(max_phase: Option<u32>) -> napi::Result<Vec<JsAnalysi
                 inv_detector.add_file(&pr.file, funcs, imports, line_count);
             }
 
+            // DataFlow constraints are checked against the call graph
+            if constraint_rows.iter().any(|cr| cr.invariant_type == "DataFlow") {
+                let cg_builder = call_graph_builder();
+                if let Ok((call_graph, _stats)) = cg_builder.build(&all_parse_results) {
+                    let sensitive_fields = drift_analysis::boundaries::BoundaryDetector::new()
+                        .detect(&all_parse_results)
+                        .map(|result| result.sensitive_fields)
+                        .unwrap_or_default();
+                    inv_detector.set_data_flow(
+                        drift_analysis::structural::constraints::DataFlowContext::new(call_graph)
+                            .with_parse_results(&prod_pr_owned)
+                            .with_sensitive_fields(sensitive_fields),
+                    );
+                }
+            }
+
             // Build store + verifier, run, persist results
             let mut store = drift_analysis::structural::constraints::store::ConstraintStore::new();
             for cr in &constraint_rows {
@@ -1068,6 +1203,31 @@ pub async fn drift_analyze(max_phase: Option<u32>) -> napi::Result<Vec<JsAnalysi
                 });
             }
         }
+        // One row per table touched by an embedded SQL statement, attributed
+        // to the innermost function around it (0 at module level). Functions
+        // were flushed in phase 1.
+        for stmt in &all_sql_statements {
+            if stmt.tables.is_empty() {
+                continue;
+            }
+            let function_id = rt.storage
+                .with_reader(|conn| {
+                    drift_storage::queries::functions::get_enclosing_function_id(conn, &stmt.file, stmt.line as i64)
+                })
+                .ok()
+                .flatten()
+                .unwrap_or(0);
+            for table in &stmt.tables {
+                da_rows.push(drift_storage::batch::commands::DataAccessInsertRow {
+                    function_id,
+                    table_name: table.clone(),
+                    operation: stmt.operation.name().to_string(),
+                    framework: Some(drift_analysis::engine::embedded_sql::RAW_SQL_FRAMEWORK.to_string()),
+                    line: stmt.line as i64,
+                    confidence: 0.85,
+                });
+            }
+        }
         if !da_rows.is_empty() {
             rt.storage.send_batch(
                 drift_storage::batch::commands::BatchCommand::InsertDataAccess(da_rows),
@@ -1126,6 +1286,10 @@ pub async fn drift_analyze(max_phase: Option<u32>) -> napi::Result<Vec<JsAnalysi
                         path: pr.file.clone(),
                         line_count,
                         language: format!("{:?}", pr.language).to_lowercase(),
+                        cyclomatic: drift_analysis::structural::hotspots::functions_from_parse_results(std::slice::from_ref(pr))
+                            .iter()
+                            .map(|f| f.complexity.cyclomatic as u64)
+                            .sum(),
                     }
                 }).collect();
 
@@ -1308,6 +1472,8 @@ pub async fn drift_analyze(max_phase: Option<u32>) -> napi::Result<Vec<JsAnalysi
     }
 
     // Step 6: Graph intelligence — taint, error handling, impact, test topology
+    // Test topology also feeds the TestCoverage gate in Step 7.
+    let mut test_coverage = None;
     if !all_parse_results.is_empty() {
         // Clear graph intelligence tables before re-populating.
         // These tables are fully recomputed from the current parse results each
@@ -1323,25 +1489,60 @@ pub async fn drift_analyze(max_phase: Option<u32>) -> napi::Result<Vec<JsAnalysi
             ).map_err(|e| drift_core::errors::StorageError::SqliteError { message: e.to_string() })
         });
         // Re-build call graph (or reuse from Step 3b if we stored it)
-        let cg_builder = drift_analysis::call_graph::CallGraphBuilder::new();
+        let cg_builder = call_graph_builder();
         let call_graph_result = cg_builder.build(&all_parse_results);
 
         if let Ok((ref call_graph, ref _cg_stats)) = call_graph_result {
             // 6a: Taint analysis → taint_flows table
-            let taint_registry = drift_analysis::graph::taint::TaintRegistry::with_defaults();
+            // Framework specs are switched on by the packs the project's imports detect.
+            let import_sources: Vec<String> = prod_parse_results
+                .iter()
+                .flat_map(|pr| pr.imports.iter().map(|i| i.source.clone()))
+                .collect::<std::collections::BTreeSet<_>>()
+                .into_iter()
+                .collect();
+            let source_files: Vec<String> = prod_parse_results.iter().map(|pr| pr.file.clone()).collect();
+            let taint_registry = drift_analysis::graph::taint::TaintRegistry::with_frameworks(
+                &framework_registry.detect_taint_frameworks(&source_files, &import_sources),
+            );
 
             // Phase 1: intraprocedural (per-file)
             // Uses prod_parse_results to avoid false taint flows from test mocks.
+            // Def-use dataflow over the syntax tree when the source is in memory.
             let mut all_taint_flows = Vec::new();
             for pr in &prod_parse_results {
-                let intra_flows = drift_analysis::graph::taint::analyze_intraprocedural(pr, &taint_registry);
+                let intra_flows = match file_contents.get(&pr.file) {
+                    Some(source) => drift_analysis::graph::taint::analyze_intraprocedural_with_source(pr, source, &taint_registry),
+                    None => drift_analysis::graph::taint::analyze_intraprocedural(pr, &taint_registry),
+                };
                 all_taint_flows.extend(intra_flows);
             }
             // Phase 2: interprocedural (cross-function via call graph)
-            if let Ok(inter_flows) = drift_analysis::graph::taint::analyze_interprocedural(
-                call_graph, &prod_pr_owned, &taint_registry, None,
+            // Function summaries persist in taint_summaries; only functions
+            // whose content or callees changed, and their callers, are redone.
+            let mut summary_cache = drift_analysis::graph::taint::SummaryCache::from_entries(
+                rt.storage
+                    .with_reader(drift_storage::queries::graph::get_taint_summaries)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter_map(|row| serde_json::from_str(&row.summary).ok()),
+            );
+            if let Ok((inter_flows, _summary_stats)) = drift_analysis::graph::taint::analyze_interprocedural_incremental(
+                call_graph, &prod_pr_owned, &taint_registry, None, &mut summary_cache,
             ) {
                 all_taint_flows.extend(inter_flows);
+                let summary_rows: Vec<drift_storage::queries::graph::TaintSummaryRow> = summary_cache
+                    .updated()
+                    .into_iter()
+                    .map(|entry| drift_storage::queries::graph::TaintSummaryRow {
+                        function_id: entry.function_id.clone(),
+                        content_hash: entry.content_hash.to_le_bytes().to_vec(),
+                        summary: serde_json::to_string(entry).unwrap_or_default(),
+                    })
+                    .collect();
+                let _ = rt.storage.with_writer(|conn| {
+                    drift_storage::queries::graph::replace_taint_summaries(conn, &summary_rows, summary_cache.removed())
+                });
             }
 
             let taint_rows: Vec<drift_storage::batch::commands::TaintFlowInsertRow> = all_taint_flows
@@ -1399,8 +1600,33 @@ pub async fn drift_analyze(max_phase: Option<u32>) -> napi::Result<Vec<JsAnalysi
                 ).map_err(storage_err)?;
             }
 
-            // 6c: Impact analysis → impact_scores table
-            let blast_radii = drift_analysis::graph::impact::blast_radius::compute_all_blast_radii(call_graph);
+            // 6c: Churn from git history → file_churn, function_churn, hotspot_history
+            let churn = rt.project_root.as_deref().and_then(|root| {
+                let spans = drift_analysis::structural::hotspots::spans_from_parse_results(&all_parse_results);
+                match drift_analysis::structural::hotspots::ChurnAnalyzer::new()
+                    .with_max_commits(HISTORY_MAX_COMMITS)
+                    .analyze(root, &spans)
+                {
+                    Ok(churn) => Some(churn),
+                    Err(e) => {
+                        drift_log!("[drift-analyze] churn skipped: {}", e);
+                        None
+                    }
+                }
+            });
+            if let Some(churn) = &churn {
+                drift_log!(
+                    "[drift-analyze] churn: {} commits, {} files, {} functions",
+                    churn.commits_analyzed, churn.files.len(), churn.functions.len()
+                );
+                persist_churn(&rt, call_graph, churn, &prod_pr_owned);
+            }
+
+            // Impact analysis → impact_scores table
+            let blast_radii = match &churn {
+                Some(churn) => drift_analysis::graph::impact::blast_radius::compute_all_blast_radii_with_churn(call_graph, churn),
+                None => drift_analysis::graph::impact::blast_radius::compute_all_blast_radii(call_graph),
+            };
             let dead_code = drift_analysis::graph::impact::dead_code::detect_dead_code(call_graph);
 
             let mut impact_rows: Vec<drift_storage::batch::commands::ImpactScoreInsertRow> = Vec::new();
@@ -1445,9 +1671,30 @@ pub async fn drift_analyze(max_phase: Option<u32>) -> napi::Result<Vec<JsAnalysi
             }
 
             // 6d: Test topology → test_quality table
-            let quality_score = drift_analysis::graph::test_topology::quality_scorer::compute_quality_score(
-                call_graph, &all_parse_results,
+            // Coverage reports at well-known locations override the call-graph inference.
+            let mut coverage_report = drift_analysis::graph::test_topology::CoverageReport::default();
+            if let Some(root) = rt.project_root.as_deref() {
+                for path in drift_analysis::graph::test_topology::coverage_import::discover_coverage_reports(root) {
+                    match drift_analysis::graph::test_topology::load_coverage_report(&path) {
+                        Ok(report) => coverage_report.merge(report),
+                        Err(e) => drift_log!("[drift-analyze] warning: {}", e),
+                    }
+                }
+            }
+            drift_log!("[drift-analyze] coverage reports imported: {} files", coverage_report.len());
+            let coverage = drift_analysis::graph::test_topology::compute_coverage_with_report(
+                call_graph, &coverage_report,
+            );
+            let quality_score = drift_analysis::graph::test_topology::compute_quality_score_with_coverage(
+                call_graph, &all_parse_results, &coverage,
             );
+            // Call-graph inference alone is too rough to gate on: the gate
+            // only runs once a coverage report was imported.
+            if coverage.has_measured_data() {
+                test_coverage = drift_analysis::enforcement::gates::TestCoverageInput::from_coverage_mapping(
+                    call_graph, &coverage, rt.config.quality_gates.effective_min_coverage(),
+                );
+            }
             let smells = drift_analysis::graph::test_topology::smells::detect_all_smells(
                 &all_parse_results, call_graph,
             );
@@ -1533,7 +1780,11 @@ pub async fn drift_analyze(max_phase: Option<u32>) -> napi::Result<Vec<JsAnalysi
 
     // Step 7: Enforcement — run quality gates, persist violations + gate results
     if !all_parse_results.is_empty() {
-        use drift_analysis::enforcement::gates::{GateOrchestrator, GateInputBuilder};
+        use drift_analysis::enforcement::gates::{
+            ChangedLines, ContractCompatibilityGate, DiffScope, DiffScopeFilter, GateInputBuilder,
+            GateOrchestrator,
+        };
+        use drift_analysis::enforcement::policy::{PolicySet, ScopedPolicy};
         use drift_analysis::enforcement::rules::types::PatternInfo as RulesPatternInfo;
 
         // Build GateInput from upstream analysis results
@@ -1546,10 +1797,13 @@ pub async fn drift_analyze(max_phase: Option<u32>) -> napi::Result<Vec<JsAnalysi
         for m in &prod_matches {
             let entry = pattern_map.entry(m.pattern_id.clone()).or_insert_with(|| RulesPatternInfo {
                 pattern_id: m.pattern_id.clone(),
-                category: format!("{:?}", m.category),
+                category: m.category.name().to_string(),
                 confidence: m.confidence as f64,
                 locations: Vec::new(),
-                outliers: Vec::new(),
+                outliers: pattern_outliers
+                    .get(&m.pattern_id)
+                    .map(|o| o.iter().filter(|o| !is_test_or_fixture_file(&o.file)).cloned().collect())
+                    .unwrap_or_default(),
                 cwe_ids: m.cwe_ids.to_vec(),
                 owasp_categories: m.owasp.as_ref().map(|o| vec![o.clone()]).unwrap_or_default(),
             });
@@ -1561,13 +1815,174 @@ pub async fn drift_analyze(max_phase: Option<u32>) -> napi::Result<Vec<JsAnalysi
         }
         let patterns: Vec<RulesPatternInfo> = pattern_map.into_values().collect();
 
-        let gate_input = GateInputBuilder::new()
+        // Raw sources of production files, so quick fixes carry edits.
+        let sources: std::collections::HashMap<String, String> = file_list
+            .iter()
+            .filter_map(|f| file_contents.get(f).map(|c| (f.clone(), c.clone())))
+            .collect();
+        let mut gate_builder = GateInputBuilder::new()
             .files(file_list)
             .patterns(patterns)
-            .build();
+            .sources(sources);
+        if let Some(coverage) = test_coverage {
+            gate_builder = gate_builder.test_coverage(coverage);
+        }
+        let mut gate_input = gate_builder.build();
+
+        // Committed drift-baseline.json: gates fail only on violations it doesn't list.
+        let baseline = rt.project_root.as_deref().map(
+            drift_analysis::enforcement::baseline::ViolationBaseline::load_from_root,
+        );
+        let baseline_filter = match baseline {
+            Some(Ok(Some(baseline))) => {
+                let context = baseline_context(prod_parse_results.iter().copied(), &file_contents);
+                drift_log!("[drift-analyze] baseline: {} accepted violations", baseline.len());
+                Some(drift_analysis::enforcement::baseline::BaselineFilter::new(&baseline, context))
+            }
+            Some(Err(e)) => {
+                eprintln!("[drift-analyze] warning: {e}");
+                None
+            }
+            _ => None,
+        };
+
+        // quality_gates.diff_base: report only violations in the change range.
+        let gates_config = &rt.config.quality_gates;
+        let diff_scope = DiffScope::from_name(gates_config.effective_diff_scope().unwrap_or("new_code"))
+            .unwrap_or(DiffScope::NewCode);
+        let changed_lines = match (rt.project_root.as_deref(), gates_config.diff_base.as_deref()) {
+            (Some(root), Some(base)) => {
+                match ChangedLines::from_git(root, base, gates_config.diff_head.as_deref()) {
+                    Ok(changed) => {
+                        drift_log!(
+                            "[drift-analyze] diff scope: {} changed files since {base}",
+                            changed.files().len()
+                        );
+                        Some(changed)
+                    }
+                    Err(e) => {
+                        eprintln!("[drift-analyze] warning: {e}");
+                        None
+                    }
+                }
+            }
+            _ => None,
+        };
+
+        // The same range gates contract changes: breaking ones must be announced.
+        if let (Some(root), Some(base)) = (rt.project_root.as_deref(), gates_config.diff_base.as_deref()) {
+            use drift_analysis::structural::contracts::revisions::ContractDiff;
+            match ContractDiff::from_git(root, base, gates_config.diff_head.as_deref()) {
+                Ok(diff) => {
+                    drift_log!(
+                        "[drift-analyze] contract diff: {} changed endpoints, {} unannounced breaking",
+                        diff.endpoints.len(),
+                        diff.unannounced().count()
+                    );
+                    gate_input.contract_diff = Some(std::sync::Arc::new(diff));
+                }
+                Err(e) => eprintln!("[drift-analyze] warning: {e}"),
+            }
+        }
+
+        // CODEOWNERS routes owner-scoped policies and attributes violations.
+        let codeowners = rt.project_root.as_deref().and_then(|root| {
+            match drift_analysis::enforcement::ownership::CodeOwners::load(root) {
+                Ok(Some(codeowners)) => {
+                    for error in &codeowners.errors {
+                        eprintln!("[drift-analyze] warning: CODEOWNERS {error}");
+                    }
+                    drift_log!("[drift-analyze] ownership: {} CODEOWNERS rules", codeowners.rule_count());
+                    Some(codeowners)
+                }
+                Ok(None) => None,
+                Err(e) => {
+                    eprintln!("[drift-analyze] warning: {e}");
+                    None
+                }
+            }
+        });
+
+        // drift-policy.toml at the root or in monorepo packages: per-path policies.
+        let policy_set = rt.project_root.as_deref().and_then(|root| {
+            let packages = match drift_core::workspace::detect_workspace(root) {
+                Ok(drift_core::workspace::WorkspaceLayout::Monorepo { packages, .. }) => packages,
+                _ => Vec::new(),
+            };
+            let policy_set = PolicySet::load(root, &packages).unwrap_or_else(|e| {
+                eprintln!("[drift-analyze] warning: {e}");
+                None
+            })?;
+            Some(match &codeowners {
+                Some(codeowners) => policy_set.with_codeowners(codeowners.clone()),
+                None => policy_set,
+            })
+        });
+
+        // Policies may pick the touched-functions scope, which needs the call graph.
+        let call_graph = if changed_lines.is_some()
+            && (diff_scope == DiffScope::TouchedFunctions || policy_set.is_some())
+        {
+            call_graph_builder()
+                .build(&prod_pr_owned)
+                .ok()
+                .map(|(graph, _)| graph)
+        } else {
+            None
+        };
+        let configure = |mut orchestrator: GateOrchestrator, scoped: &ScopedPolicy| {
+            if let Some(ref changed) = changed_lines {
+                let scope = scoped.policy.diff_scope.unwrap_or(diff_scope);
+                let mut filter = DiffScopeFilter::new(scope, changed.clone());
+                if let Some(ref graph) = call_graph {
+                    filter = filter.with_call_graph(graph);
+                }
+                orchestrator = orchestrator.with_diff_scope(filter);
+            }
+            if let Some(ref filter) = baseline_filter {
+                orchestrator = orchestrator.with_baseline(filter.clone());
+            }
+            if gate_input.contract_diff.is_some() {
+                orchestrator = orchestrator.with_gate(Box::new(ContractCompatibilityGate));
+            }
+            orchestrator
+        };
+
+        // With policies, each scope's verdict is kept next to the merged gate
+        // results: a scope can fail its policy while every gate passes.
+        let evaluation = match policy_set {
+            Some(ref policy_set) => policy_set.evaluate(&gate_input, configure).map(|report| {
+                for scope in &report.scopes {
+                    drift_log!(
+                        "[drift-analyze] policy {} ({} files): {}",
+                        scope.label, scope.files.len(), scope.verdict.details
+                    );
+                }
+                (report.merged_results(), report.scopes)
+            }),
+            None => configure(GateOrchestrator::new(), &ScopedPolicy::default())
+                .execute(&gate_input)
+                .map(|results| (results, Vec::new())),
+        };
+        if let Ok((mut gate_results, policy_scopes)) = evaluation {
+            // CODEOWNERS (and, if enabled, git blame): who owns each violation.
+            if let Some(root) = rt.project_root.as_deref() {
+                use drift_analysis::enforcement::ownership::{Blamer, OwnershipResolver};
+                let mut resolver = OwnershipResolver::new();
+                if let Some(codeowners) = codeowners {
+                    resolver = resolver.with_codeowners(codeowners);
+                }
+                if gates_config.blame_owners == Some(true) {
+                    match Blamer::open(root) {
+                        Ok(blamer) => resolver = resolver.with_blame(blamer),
+                        Err(e) => eprintln!("[drift-analyze] warning: {e}"),
+                    }
+                }
+                if !resolver.is_empty() {
+                    resolver.attribute_results(&mut gate_results);
+                }
+            }
 
-        let orchestrator = GateOrchestrator::new();
-        if let Ok(gate_results) = orchestrator.execute(&gate_input) {
             // Collect all violations from all gates
             let mut violation_rows: Vec<drift_storage::batch::commands::ViolationInsertRow> = Vec::new();
             let mut gate_result_rows: Vec<drift_storage::batch::commands::GateResultInsertRow> = Vec::new();
@@ -1606,6 +2021,12 @@ pub async fn drift_analyze(max_phase: Option<u32>) -> napi::Result<Vec<JsAnalysi
                         owasp_category: v.owasp_category.clone(),
                         suppressed: v.suppressed,
                         is_new: v.is_new,
+                        owners: (!v.owners.is_empty()).then(|| serde_json::to_string(&v.owners).unwrap_or_default()),
+                        last_author: v.last_author.clone(),
+                        quick_fix_id: v.quick_fix.as_ref().map(|qf| qf.fix_id.clone()),
+                        quick_fix_edits: v.quick_fix.as_ref()
+                            .filter(|qf| qf.is_applicable())
+                            .and_then(|qf| serde_json::to_string(&qf.edits).ok()),
                     });
                 }
             }
@@ -1621,6 +2042,36 @@ pub async fn drift_analyze(max_phase: Option<u32>) -> napi::Result<Vec<JsAnalysi
                 ).map_err(storage_err)?;
             }
 
+            // Policy verdicts go in after this run's gate results, so
+            // drift_check can tell them from a later run without policies.
+            if !policy_scopes.is_empty() {
+                rt.storage.flush_batch_sync().map_err(storage_err)?;
+                rt.storage.with_writer(|conn| {
+                    for scope in &policy_scopes {
+                        let verdict = &scope.verdict;
+                        drift_storage::queries::enforcement::insert_policy_result(
+                            conn,
+                            &drift_storage::queries::enforcement::PolicyResultRow {
+                                id: 0,
+                                policy_name: verdict.policy_name.clone(),
+                                aggregation_mode: serde_json::json!(verdict.aggregation_mode)
+                                    .as_str()
+                                    .unwrap_or_default()
+                                    .to_string(),
+                                overall_passed: verdict.overall_passed,
+                                overall_score: verdict.overall_score,
+                                gate_count: verdict.gate_count as i64,
+                                gates_passed: verdict.gates_passed as i64,
+                                gates_failed: verdict.gates_failed as i64,
+                                details: Some(format!("{}: {}", scope.label, verdict.details)),
+                                run_at: 0,
+                            },
+                        )?;
+                    }
+                    Ok(())
+                }).map_err(storage_err)?;
+            }
+
             // BW-EVT-05: Fire on_gate_evaluated for each gate result
             {
                 use drift_core::events::types::GateEvaluatedEvent;
@@ -1782,6 +2233,181 @@ pub async fn drift_analyze(max_phase: Option<u32>) -> napi::Result<Vec<JsAnalysi
     Ok(all_results)
 }
 
+/// Commits read for churn and change coupling; older history adds little.
+/// Also the commits one secret history scan reads, which resumes next time.
+const HISTORY_MAX_COMMITS: usize = 2000;
+
+/// Resume the git history secret scan from its stored state and store the
+/// result. Failures are logged; the current-tree scan already ran.
+fn persist_secret_history(rt: &crate::runtime::DriftRuntime, root: &std::path::Path) {
+    use drift_analysis::structural::constants::types::{HistoricalSecret, Secret, SecretHistory, SecretSeverity};
+    use drift_storage::queries::structural as queries;
+
+    let previous = rt
+        .storage
+        .with_reader(|conn| Ok((queries::get_secret_history_state(conn)?, queries::get_historical_secrets(conn)?)));
+    let previous = match previous {
+        Ok((Some(state), rows)) => SecretHistory {
+            last_commit: state.last_commit,
+            scanned_tips: serde_json::from_str(&state.scanned_tips).unwrap_or_default(),
+            commits_scanned: state.commits_scanned as usize,
+            secrets: rows
+                .into_iter()
+                .map(|r| HistoricalSecret {
+                    secret: Secret {
+                        pattern_name: r.pattern_name,
+                        redacted_value: r.redacted_value,
+                        file: r.file,
+                        line: r.line,
+                        severity: SecretSeverity::parse_str(&r.severity).unwrap_or(SecretSeverity::Medium),
+                        entropy: r.entropy,
+                        confidence: r.confidence,
+                        cwe_ids: serde_json::from_str(&r.cwe_ids).unwrap_or_default(),
+                    },
+                    fingerprint: r.fingerprint,
+                    first_commit: r.first_commit,
+                    author: r.author,
+                    timestamp: r.timestamp,
+                    occurrences: r.occurrences,
+                    files: serde_json::from_str(&r.files).unwrap_or_default(),
+                    present_at_head: r.present_at_head,
+                })
+                .collect(),
+        },
+        Ok((None, _)) => SecretHistory::default(),
+        Err(e) => {
+            drift_log!("[drift-analyze] warning: secret history state not read, rescanning: {}", e);
+            SecretHistory::default()
+        }
+    };
+
+    let history = match drift_analysis::structural::constants::history::SecretHistoryScanner::new()
+        .with_max_commits(HISTORY_MAX_COMMITS)
+        .resume(root, previous)
+    {
+        Ok(history) => history,
+        Err(e) => {
+            drift_log!("[drift-analyze] secret history skipped: {}", e);
+            return;
+        }
+    };
+    drift_log!(
+        "[drift-analyze] secret history: {} commits scanned, {} secrets ({} at HEAD)",
+        history.commits_scanned,
+        history.secrets.len(),
+        history.secrets.iter().filter(|s| s.present_at_head).count()
+    );
+
+    let state = queries::SecretHistoryStateRow {
+        last_commit: history.last_commit.clone(),
+        scanned_tips: serde_json::to_string(&history.scanned_tips).unwrap_or_default(),
+        commits_scanned: history.commits_scanned as u32,
+    };
+    let rows: Vec<queries::HistoricalSecretRow> = history
+        .secrets
+        .into_iter()
+        .map(|h| queries::HistoricalSecretRow {
+            fingerprint: h.fingerprint,
+            pattern_name: h.secret.pattern_name,
+            redacted_value: h.secret.redacted_value,
+            file: h.secret.file,
+            line: h.secret.line,
+            severity: h.secret.severity.name().to_string(),
+            entropy: h.secret.entropy,
+            confidence: h.secret.confidence,
+            cwe_ids: serde_json::to_string(&h.secret.cwe_ids).unwrap_or_default(),
+            first_commit: h.first_commit,
+            author: h.author,
+            timestamp: h.timestamp,
+            occurrences: h.occurrences,
+            files: serde_json::to_string(&h.files).unwrap_or_default(),
+            present_at_head: h.present_at_head,
+        })
+        .collect();
+    if let Err(e) = rt.storage.with_writer(|conn| queries::replace_secret_history(conn, &state, &rows)) {
+        drift_log!("[drift-analyze] warning: secret history not stored: {}", e);
+    }
+}
+
+/// Store churn, and rank and record risk hotspots with their trends against
+/// the previous analysis. Failures are logged; churn is advisory.
+fn persist_churn(
+    rt: &crate::runtime::DriftRuntime,
+    call_graph: &drift_analysis::call_graph::CallGraph,
+    churn: &drift_analysis::structural::hotspots::ChurnReport,
+    prod_parse_results: &[drift_analysis::parsers::ParseResult],
+) {
+    use drift_analysis::structural::hotspots;
+    use drift_storage::queries::structural as queries;
+
+    // Function rows are keyed like impact_scores, by call graph node.
+    let frequencies = churn.change_frequencies();
+    let by_function: std::collections::HashMap<(&str, &str), &hotspots::FunctionChurn> = churn
+        .functions
+        .iter()
+        .map(|f| ((f.file.as_str(), f.name.as_str()), f))
+        .collect();
+    let function_rows: Vec<queries::FunctionChurnRow> = call_graph
+        .graph
+        .node_weights()
+        .filter_map(|node| {
+            let f = by_function.get(&(churn.relative(&node.file), node.name.as_str()))?;
+            Some(queries::FunctionChurnRow {
+                function_id: format!("{}::{}", node.file, node.name),
+                file: f.file.clone(),
+                commits: f.commits,
+                lines_changed: f.lines_changed,
+                recency_weighted: f.recency_weighted,
+                change_frequency: frequencies.get(&node.file, &node.name) as f64,
+            })
+        })
+        .collect();
+    let file_rows: Vec<queries::FileChurnRow> = churn
+        .files
+        .iter()
+        .map(|f| queries::FileChurnRow {
+            file: f.file.clone(),
+            commits: f.commits,
+            lines_added: f.lines_added,
+            lines_removed: f.lines_removed,
+            authors: f.authors,
+            last_changed: f.last_changed,
+            recency_weighted: f.recency_weighted,
+        })
+        .collect();
+    if let Err(e) = rt.storage.with_writer(|conn| queries::replace_churn(conn, &file_rows, &function_rows)) {
+        drift_log!("[drift-analyze] warning: churn not stored: {}", e);
+    }
+
+    let previous: drift_core::types::collections::FxHashMap<String, f64> = rt
+        .storage
+        .with_reader(|conn| queries::get_latest_hotspots(conn, u32::MAX))
+        .unwrap_or_default()
+        .into_iter()
+        .map(|row| (row.file, row.score))
+        .collect();
+    let complexity = hotspots::compute_hotspots(&hotspots::functions_from_parse_results(prod_parse_results));
+    let ranked = hotspots::rank_risk_hotspots(&complexity, churn, &hotspots::file_coupling(call_graph), &previous);
+    let history_rows: Vec<queries::HotspotHistoryRow> = ranked
+        .iter()
+        .map(|h| queries::HotspotHistoryRow {
+            file: h.file.clone(),
+            score: h.score,
+            churn: h.churn,
+            complexity: h.complexity,
+            coupling: h.coupling,
+            commits: h.commits,
+            authors: h.authors,
+            trend: h.trend.name().to_string(),
+            head_commit: churn.head_commit.clone(),
+            recorded_at: 0,
+        })
+        .collect();
+    if let Err(e) = rt.storage.with_writer(|conn| queries::insert_hotspot_snapshot(conn, &history_rows)) {
+        drift_log!("[drift-analyze] warning: hotspots not stored: {}", e);
+    }
+}
+
 /// BW-EVT-08: Run the bridge grounding loop on all bridge memories.
 /// Called automatically after drift_analyze() completes.
 fn run_bridge_grounding_loop(
@@ -1,7 +1,8 @@
 //! NAPI bindings for enforcement systems (Phase 6).
 //!
 //! Exposes drift_check(), drift_audit(), drift_violations(), drift_gates(),
-//! drift_approve_pattern(), drift_pattern_status().
+//! drift_baseline(), drift_apply_fixes(), drift_approve_pattern(),
+//! drift_pattern_status().
 
 #[allow(unused_imports)]
 use napi::bindgen_prelude::*;
@@ -32,6 +33,14 @@ pub struct JsViolation {
     pub owasp_category: Option<String>,
     pub suppressed: bool,
     pub is_new: bool,
+    /// CODEOWNERS owners of the file.
+    pub owners: Vec<String>,
+    /// Author of the last change to the line, from git blame.
+    pub last_author: Option<String>,
+    /// Stable id of the quick fix, for `drift_apply_fixes`.
+    pub quick_fix_id: Option<String>,
+    /// Whether the quick fix carries edits that can be applied automatically.
+    pub quick_fix_applicable: bool,
 }
 
 // ─── Gate Result Types ───────────────────────────────────────────────
@@ -51,12 +60,28 @@ pub struct JsGateResult {
     pub error: Option<String>,
 }
 
+/// One policy scope's verdict from the latest analysis run.
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsPolicyVerdict {
+    pub policy_name: String,
+    pub aggregation_mode: String,
+    pub passed: bool,
+    pub score: f64,
+    pub gates_passed: u32,
+    pub gates_failed: u32,
+    /// The scope label followed by the verdict, e.g. "tools/**: Policy ...".
+    pub details: Option<String>,
+}
+
 #[napi(object)]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct JsCheckResult {
     pub overall_passed: bool,
     pub total_violations: u32,
     pub gates: Vec<JsGateResult>,
+    /// Per-scope policy verdicts; empty when no drift-policy.toml applies.
+    pub policies: Vec<JsPolicyVerdict>,
     pub sarif: Option<String>,
 }
 
@@ -116,7 +141,22 @@ pub fn drift_check(_root: String) -> napi::Result<JsCheckResult> {
     let active_gates: Vec<&JsGateResult> = js_gates.iter()
         .filter(|g| g.status != "skipped" && g.status != "no_data")
         .collect();
-    let overall_passed = active_gates.is_empty() || active_gates.iter().all(|g| g.passed);
+    let policies = rt.storage.with_reader(|conn| {
+        drift_storage::queries::enforcement::query_latest_policy_results(conn)
+    }).map_err(|e| napi::Error::from_reason(format!("[{}] {e}", error_codes::STORAGE_ERROR)))?;
+    let js_policies: Vec<JsPolicyVerdict> = policies.into_iter().map(|p| JsPolicyVerdict {
+        policy_name: p.policy_name,
+        aggregation_mode: p.aggregation_mode,
+        passed: p.overall_passed,
+        score: p.overall_score,
+        gates_passed: p.gates_passed as u32,
+        gates_failed: p.gates_failed as u32,
+        details: p.details,
+    }).collect();
+
+    // A scope can fail its policy while every gate passes.
+    let overall_passed = (active_gates.is_empty() || active_gates.iter().all(|g| g.passed))
+        && js_policies.iter().all(|p| p.passed);
 
     // PH2-04: Generate SARIF inline
     let sarif = drift_analysis::enforcement::reporters::create_reporter("sarif")
@@ -129,6 +169,7 @@ pub fn drift_check(_root: String) -> napi::Result<JsCheckResult> {
         overall_passed,
         total_violations: violations.len() as u32,
         gates: js_gates,
+        policies: js_policies,
         sarif,
     })
 }
@@ -355,12 +396,18 @@ pub fn drift_violations(_root: String) -> napi::Result<Vec<JsViolation>> {
         owasp_category: v.owasp_category,
         suppressed: v.suppressed,
         is_new: v.is_new,
+        owners: parse_owners(v.owners.as_deref()),
+        last_author: v.last_author,
+        quick_fix_applicable: v.quick_fix_edits.is_some(),
+        quick_fix_id: v.quick_fix_id,
     }).collect())
 }
 
 /// Generate a report in the specified format from stored violations and gate results.
 ///
-/// Supported formats: "sarif", "json", "html", "junit", "sonarqube", "console", "github", "gitlab"
+/// Supported formats: "sarif", "json", "html", "junit", "sonarqube", "console", "github", "gitlab",
+/// "checkstyle", "codeclimate", plus any template in `.drift/reporters/`
+/// (e.g. `dashboard.html.j2` → "dashboard").
 #[napi]
 pub fn drift_report(format: String) -> napi::Result<String> {
     let rt = runtime::get()?;
@@ -376,17 +423,83 @@ pub fn drift_report(format: String) -> napi::Result<String> {
     // Convert storage rows to enforcement gate results
     let gate_results = storage_to_gate_results(&violations, &gates);
 
+    // Built-in formats plus template formats from .drift/reporters/
+    let mut registry = drift_analysis::enforcement::reporters::ReporterRegistry::with_builtins();
+    if let Some(dir) = rt.project_root.as_ref().map(|p| p.join(".drift").join("reporters")) {
+        registry.register_templates_from_dir(&dir);
+    }
+
     // Create reporter and generate output
-    let reporter = drift_analysis::enforcement::reporters::create_reporter(&format)
+    let reporter = registry.create(&format)
         .ok_or_else(|| napi::Error::from_reason(format!(
-            "[{}] Unknown report format: '{}'. Supported: sarif, json, html, junit, sonarqube, console, github, gitlab",
-            error_codes::INVALID_ARGUMENT, format
+            "[{}] Unknown report format: '{}'. Supported: {}",
+            error_codes::INVALID_ARGUMENT, format, registry.formats().join(", ")
         )))?;
 
     reporter.generate(&gate_results)
         .map_err(|e| napi::Error::from_reason(format!("[{}] Report generation failed: {e}", error_codes::INTERNAL_ERROR)))
 }
 
+/// Convert a stored violation row into an enforcement `Violation`.
+fn storage_to_violation(
+    v: &drift_storage::queries::enforcement::ViolationRow,
+) -> drift_analysis::enforcement::rules::types::Violation {
+    use drift_analysis::enforcement::rules::types::{Severity, Violation};
+
+    Violation {
+        id: v.id.clone(),
+        file: v.file.clone(),
+        line: v.line,
+        column: v.column,
+        end_line: v.end_line,
+        end_column: v.end_column,
+        severity: match v.severity.as_str() {
+            "critical" | "error" => Severity::Error,
+            "high" | "warning" => Severity::Warning,
+            "medium" | "info" => Severity::Info,
+            _ => Severity::Hint,
+        },
+        pattern_id: v.pattern_id.clone(),
+        rule_id: v.rule_id.clone(),
+        message: v.message.clone(),
+        cwe_id: v.cwe_id,
+        owasp_category: v.owasp_category.clone(),
+        suppressed: v.suppressed,
+        is_new: v.is_new,
+        quick_fix: v.quick_fix_strategy.as_ref().and_then(|s| {
+            use drift_analysis::enforcement::rules::types::QuickFixStrategy;
+            let strategy = match s.as_str() {
+                "add_import" => QuickFixStrategy::AddImport,
+                "rename" => QuickFixStrategy::Rename,
+                "extract_function" => QuickFixStrategy::ExtractFunction,
+                "wrap_in_try_catch" => QuickFixStrategy::WrapInTryCatch,
+                "add_type_annotation" => QuickFixStrategy::AddTypeAnnotation,
+                "add_test" => QuickFixStrategy::AddTest,
+                "add_documentation" => QuickFixStrategy::AddDocumentation,
+                "use_parameterized_query" => QuickFixStrategy::UseParameterizedQuery,
+                _ => return None,
+            };
+            Some(drift_analysis::enforcement::rules::types::QuickFix {
+                strategy,
+                description: v.quick_fix_description.clone().unwrap_or_default(),
+                replacement: None,
+                fix_id: v.quick_fix_id.clone().unwrap_or_default(),
+                edits: v.quick_fix_edits.as_deref()
+                    .and_then(|json| serde_json::from_str(json).ok())
+                    .unwrap_or_default(),
+            })
+        }),
+        owners: parse_owners(v.owners.as_deref()),
+        last_author: v.last_author.clone(),
+        file_level: false,
+    }
+}
+
+/// Owners stored as a JSON array.
+fn parse_owners(json: Option<&str>) -> Vec<String> {
+    json.and_then(|j| serde_json::from_str(j).ok()).unwrap_or_default()
+}
+
 /// Convert storage rows into enforcement GateResult structs for reporters.
 fn storage_to_gate_results(
     violations: &[drift_storage::queries::enforcement::ViolationRow],
@@ -395,48 +508,7 @@ fn storage_to_gate_results(
     use drift_analysis::enforcement::gates::{GateId, GateResult, GateStatus};
     use drift_analysis::enforcement::rules::types::{Severity, Violation};
 
-    let mut all_violations: Vec<Violation> = violations.iter().map(|v| {
-        Violation {
-            id: v.id.clone(),
-            file: v.file.clone(),
-            line: v.line,
-            column: v.column,
-            end_line: v.end_line,
-            end_column: v.end_column,
-            severity: match v.severity.as_str() {
-                "critical" | "error" => Severity::Error,
-                "high" | "warning" => Severity::Warning,
-                "medium" | "info" => Severity::Info,
-                _ => Severity::Hint,
-            },
-            pattern_id: v.pattern_id.clone(),
-            rule_id: v.rule_id.clone(),
-            message: v.message.clone(),
-            cwe_id: v.cwe_id,
-            owasp_category: v.owasp_category.clone(),
-            suppressed: v.suppressed,
-            is_new: v.is_new,
-            quick_fix: v.quick_fix_strategy.as_ref().and_then(|s| {
-                use drift_analysis::enforcement::rules::types::QuickFixStrategy;
-                let strategy = match s.as_str() {
-                    "add_import" => QuickFixStrategy::AddImport,
-                    "rename" => QuickFixStrategy::Rename,
-                    "extract_function" => QuickFixStrategy::ExtractFunction,
-                    "wrap_in_try_catch" => QuickFixStrategy::WrapInTryCatch,
-                    "add_type_annotation" => QuickFixStrategy::AddTypeAnnotation,
-                    "add_test" => QuickFixStrategy::AddTest,
-                    "add_documentation" => QuickFixStrategy::AddDocumentation,
-                    "use_parameterized_query" => QuickFixStrategy::UseParameterizedQuery,
-                    _ => return None,
-                };
-                Some(drift_analysis::enforcement::rules::types::QuickFix {
-                    strategy,
-                    description: v.quick_fix_description.clone().unwrap_or_default(),
-                    replacement: None,
-                })
-            }),
-        }
-    }).collect();
+    let mut all_violations: Vec<Violation> = violations.iter().map(storage_to_violation).collect();
 
     if gates.is_empty() {
         // No gate results stored — create a single synthetic gate
@@ -467,6 +539,7 @@ fn storage_to_gate_results(
             "test-coverage" => GateId::TestCoverage,
             "error-handling" => GateId::ErrorHandling,
             "regression" => GateId::Regression,
+            "contract-compatibility" => GateId::ContractCompatibility,
             _ => GateId::PatternCompliance,
         };
         let status = match g.status.as_str() {
@@ -521,6 +594,170 @@ pub fn drift_gates(_root: String) -> napi::Result<Vec<JsGateResult>> {
     }).collect())
 }
 
+// ─── Baseline ───────────────────────────────────────────────────────
+
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsBaselineResult {
+    pub path: String,
+    pub total: u32,
+    pub added: u32,
+    pub removed: u32,
+}
+
+/// Regenerate or prune `drift-baseline.json` from the stored violations.
+///
+/// `action`: "regenerate" snapshots every current violation; "prune" only
+/// drops entries whose violations are gone, so new violations stay new.
+#[napi]
+pub fn drift_baseline(action: String) -> napi::Result<JsBaselineResult> {
+    use drift_analysis::enforcement::baseline::{FingerprintContext, FunctionSpan, ViolationBaseline, BASELINE_FILE};
+    use drift_analysis::enforcement::gates::{GateId, GateResult};
+
+    let rt = runtime::get()?;
+    let root = rt.project_root.as_deref().ok_or_else(|| {
+        napi::Error::from_reason(format!("[{}] No project root configured", error_codes::CONFIG_ERROR))
+    })?;
+    let path = root.join(BASELINE_FILE);
+
+    let rows = rt.storage.with_reader(|conn| {
+        drift_storage::queries::enforcement::query_all_violations(conn)
+    }).map_err(|e| napi::Error::from_reason(format!("[{}] {e}", error_codes::STORAGE_ERROR)))?;
+    let mut seen = std::collections::HashSet::new();
+    let violations: Vec<_> = rows.iter()
+        .filter(|v| seen.insert((v.id.as_str(), v.rule_id.as_str())))
+        .map(storage_to_violation)
+        .collect();
+
+    // Fingerprints need each file's source and function extents.
+    let mut context = FingerprintContext::new();
+    let files: std::collections::BTreeSet<&str> = violations.iter().map(|v| v.file.as_str()).collect();
+    for file in files {
+        if let Ok(source) = std::fs::read_to_string(root.join(file)) {
+            context.add_source(file, &source);
+        }
+        let functions = rt.storage.with_reader(|conn| {
+            drift_storage::queries::functions::get_functions_by_file(conn, file)
+        }).unwrap_or_default();
+        for f in functions {
+            context.add_function(file, FunctionSpan {
+                name: f.qualified_name.unwrap_or(f.name),
+                start_line: f.line as u32,
+                end_line: f.end_line.max(f.line) as u32,
+            });
+        }
+    }
+
+    let existing = ViolationBaseline::load_from_root(root)
+        .map_err(|e| napi::Error::from_reason(format!("[{}] {e}", error_codes::CONFIG_ERROR)))?
+        .unwrap_or_default();
+    let results = vec![GateResult::fail(GateId::PatternCompliance, 0.0, String::new(), violations)];
+
+    let (baseline, added, removed) = match action.as_str() {
+        "regenerate" => {
+            let baseline = ViolationBaseline::from_results(&results, &context);
+            let diff = existing.diff(&results, &context);
+            (baseline, diff.new.len(), diff.fixed.len())
+        }
+        "prune" => {
+            let mut baseline = existing;
+            let removed = baseline.prune(&results, &context).len();
+            (baseline, 0, removed)
+        }
+        other => {
+            return Err(napi::Error::from_reason(format!(
+                "[{}] Unknown baseline action '{other}'. Expected 'regenerate' or 'prune'",
+                error_codes::INVALID_ARGUMENT
+            )));
+        }
+    };
+
+    baseline.save(&path)
+        .map_err(|e| napi::Error::from_reason(format!("[{}] {e}", error_codes::INTERNAL_ERROR)))?;
+
+    Ok(JsBaselineResult {
+        path: path.display().to_string(),
+        total: baseline.len() as u32,
+        added: added as u32,
+        removed: removed as u32,
+    })
+}
+
+// ─── Quick-Fix Types ────────────────────────────────────────────────
+
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsFixConflict {
+    pub fix_id: String,
+    pub conflicts_with: Option<String>,
+    pub file: String,
+    pub reason: String,
+}
+
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsFixApplication {
+    pub applied: Vec<String>,
+    pub conflicts: Vec<JsFixConflict>,
+    pub files_changed: Vec<String>,
+}
+
+/// Apply stored quick fixes to the working tree.
+///
+/// `fix_ids` picks fixes in priority order; when empty, every stored fix
+/// with edits is applied. Overlapping fixes, stale fixes and unknown ids
+/// are reported as conflicts.
+#[napi]
+pub fn drift_apply_fixes(fix_ids: Vec<String>) -> napi::Result<JsFixApplication> {
+    use drift_analysis::enforcement::rules::{FixApplier, FixConflict};
+
+    let rt = runtime::get()?;
+    let root = rt.project_root.as_deref().ok_or_else(|| {
+        napi::Error::from_reason(format!("[{}] No project root configured", error_codes::CONFIG_ERROR))
+    })?;
+
+    let rows = rt.storage.with_reader(|conn| {
+        drift_storage::queries::enforcement::query_all_violations(conn)
+    }).map_err(|e| napi::Error::from_reason(format!("[{}] {e}", error_codes::STORAGE_ERROR)))?;
+    let mut stored = std::collections::BTreeMap::new();
+    for fix in rows.iter().filter_map(|v| storage_to_violation(v).quick_fix) {
+        if fix.is_applicable() {
+            stored.entry(fix.fix_id.clone()).or_insert(fix);
+        }
+    }
+
+    let mut unknown = Vec::new();
+    let fixes: Vec<_> = if fix_ids.is_empty() {
+        stored.into_values().collect()
+    } else {
+        fix_ids.into_iter().filter_map(|id| match stored.get(&id) {
+            Some(fix) => Some(fix.clone()),
+            None => {
+                unknown.push(FixConflict {
+                    fix_id: id,
+                    conflicts_with: None,
+                    file: String::new(),
+                    reason: "no stored fix with edits has this id".to_string(),
+                });
+                None
+            }
+        }).collect()
+    };
+
+    let application = FixApplier::new(root).apply(&fixes)
+        .map_err(|e| napi::Error::from_reason(format!("[{}] {e}", error_codes::INTERNAL_ERROR)))?;
+    Ok(JsFixApplication {
+        applied: application.applied,
+        conflicts: unknown.into_iter().chain(application.conflicts).map(|c| JsFixConflict {
+            fix_id: c.fix_id,
+            conflicts_with: c.conflicts_with,
+            file: c.file,
+            reason: c.reason,
+        }).collect(),
+        files_changed: application.files_changed,
+    })
+}
+
 // ─── Pattern Status Types ───────────────────────────────────────────
 
 #[napi(object)]
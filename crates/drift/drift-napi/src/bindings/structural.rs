@@ -1,7 +1,8 @@
 //! NAPI bindings for all 9 structural intelligence systems (Phase 5).
 //!
 //! Exposes coupling, constraints, contracts, constants, wrappers, DNA,
-//! OWASP/CWE, crypto, and decomposition analysis to TypeScript/JavaScript.
+//! OWASP/CWE, crypto, decomposition analysis and complexity and risk hotspots to
+//! TypeScript/JavaScript.
 
 #[allow(unused_imports)]
 use napi::bindgen_prelude::*;
@@ -76,12 +77,35 @@ pub struct JsCycleInfo {
     pub break_suggestion_count: u32,
 }
 
+/// Modules that change together in git history.
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsChangeCoupling {
+    pub module_a: String,
+    pub module_b: String,
+    pub co_changes: u32,
+    pub confidence: f64,
+    /// The most strongly coupled file pair.
+    pub file_a: String,
+    pub file_b: String,
+    pub file_pairs: u32,
+    pub import_edge: bool,
+    pub in_cycle: bool,
+    /// Workspace packages of `file_a` and `file_b`.
+    pub package_a: Option<String>,
+    pub package_b: Option<String>,
+    /// The modules are in different packages and no import edge or
+    /// dependency cycle connects them.
+    pub hidden: bool,
+}
+
 #[napi(object)]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct JsCouplingResult {
     pub metrics: Vec<JsCouplingMetrics>,
     pub cycles: Vec<JsCycleInfo>,
     pub module_count: u32,
+    pub change_coupling: Vec<JsChangeCoupling>,
 }
 
 #[napi]
@@ -117,7 +141,27 @@ pub fn drift_coupling_analysis(_root: String) -> napi::Result<JsCouplingResult>
 
     let module_count = metrics.len() as u32;
 
-    Ok(JsCouplingResult { metrics, cycles: js_cycles, module_count })
+    let change_coupling = rt.storage.with_reader(|conn| {
+        drift_storage::queries::structural::get_change_coupling(conn, false)
+    }).map_err(storage_err)?
+        .into_iter()
+        .map(|r| JsChangeCoupling {
+            module_a: r.module_a,
+            module_b: r.module_b,
+            co_changes: r.co_changes,
+            confidence: r.confidence,
+            file_a: r.file_a,
+            file_b: r.file_b,
+            file_pairs: r.file_pairs,
+            import_edge: r.import_edge,
+            in_cycle: r.in_cycle,
+            package_a: r.package_a,
+            package_b: r.package_b,
+            hidden: r.hidden,
+        })
+        .collect();
+
+    Ok(JsCouplingResult { metrics, cycles: js_cycles, module_count, change_coupling })
 }
 
 // ─── Constraint System ───────────────────────────────────────────────
@@ -394,6 +438,24 @@ pub struct JsSecret {
     pub confidence: f64,
 }
 
+/// A secret found anywhere in git history, with the commit that introduced it.
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsHistoricalSecret {
+    pub pattern_name: String,
+    pub redacted_value: String,
+    pub file: String,
+    pub line: u32,
+    pub severity: String,
+    pub first_commit: String,
+    pub author: String,
+    pub timestamp: i64,
+    pub occurrences: u32,
+    pub files: Vec<String>,
+    /// Still in the tree at HEAD; otherwise only rewriting history removes it.
+    pub present_at_head: bool,
+}
+
 #[napi(object)]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct JsMagicNumber {
@@ -408,6 +470,7 @@ pub struct JsMagicNumber {
 pub struct JsConstantsResult {
     pub constant_count: u32,
     pub secrets: Vec<JsSecret>,
+    pub historical_secrets: Vec<JsHistoricalSecret>,
     pub magic_numbers: Vec<JsMagicNumber>,
     pub missing_env_vars: Vec<String>,
     pub dead_constant_count: u32,
@@ -433,6 +496,24 @@ pub fn drift_constants_analysis(_root: String) -> napi::Result<JsConstantsResult
         confidence: s.confidence,
     }).collect();
 
+    let historical = rt.storage.with_reader(|conn| {
+        drift_storage::queries::structural::get_historical_secrets(conn)
+    }).map_err(storage_err)?;
+
+    let historical_secrets: Vec<JsHistoricalSecret> = historical.into_iter().map(|h| JsHistoricalSecret {
+        pattern_name: h.pattern_name,
+        redacted_value: h.redacted_value,
+        file: h.file,
+        line: h.line,
+        severity: h.severity,
+        first_commit: h.first_commit,
+        author: h.author,
+        timestamp: h.timestamp,
+        occurrences: h.occurrences,
+        files: serde_json::from_str(&h.files).unwrap_or_default(),
+        present_at_head: h.present_at_head,
+    }).collect();
+
     let magic_numbers = rt.storage.with_reader(|conn| {
         drift_storage::queries::constants::query_magic_numbers(conn)
     }).map_err(storage_err)?;
@@ -457,6 +538,7 @@ pub fn drift_constants_analysis(_root: String) -> napi::Result<JsConstantsResult
     Ok(JsConstantsResult {
         constant_count,
         secrets: js_secrets,
+        historical_secrets,
         magic_numbers: js_magic,
         missing_env_vars,
         dead_constant_count: unused.len() as u32,
@@ -1024,3 +1106,126 @@ pub fn drift_decomposition(_root: String) -> napi::Result<JsDecompositionResult>
 
     Ok(JsDecompositionResult { modules, module_count, total_files: file_count, avg_cohesion, avg_coupling })
 }
+
+// ─── Complexity Hotspots ─────────────────────────────────────────────
+
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsFileHotspot {
+    pub file: String,
+    pub function_count: u32,
+    pub total_cyclomatic: u32,
+    pub max_cyclomatic: u32,
+    pub total_cognitive: u32,
+    pub max_cognitive: u32,
+    pub max_nesting: u32,
+    pub hottest_function: String,
+    pub hottest_line: u32,
+    pub score: f64,
+}
+
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsHotspotResult {
+    pub files: Vec<JsFileHotspot>,
+    pub file_count: u32,
+    pub function_count: u32,
+}
+
+#[napi]
+pub fn drift_complexity_hotspots(_root: String, limit: Option<u32>) -> napi::Result<JsHotspotResult> {
+    use drift_analysis::structural::hotspots::{compute_hotspots, FunctionComplexity};
+    use drift_analysis::parsers::types::ComplexityMetrics;
+
+    let rt = runtime::get()?;
+
+    let rows = rt.storage.with_reader(|conn| {
+        drift_storage::queries::functions::get_measured_functions(conn)
+    }).map_err(storage_err)?;
+
+    let functions: Vec<FunctionComplexity> = rows.into_iter()
+        .filter(|r| !is_test_or_fixture_file(&r.file))
+        .map(|r| FunctionComplexity {
+            file: r.file,
+            name: r.name,
+            line: r.line as u32,
+            complexity: ComplexityMetrics {
+                cyclomatic: r.cyclomatic as u32,
+                cognitive: r.cognitive as u32,
+                max_nesting: r.max_nesting as u32,
+                parameter_count: r.parameter_count as u32,
+            },
+        })
+        .collect();
+
+    let report = compute_hotspots(&functions);
+    let file_count = report.files.len() as u32;
+    let limit = limit.map(|l| l as usize).unwrap_or(50);
+
+    let files = report.top(limit).iter().map(|h| JsFileHotspot {
+        file: h.file.clone(),
+        function_count: h.function_count,
+        total_cyclomatic: h.total_cyclomatic,
+        max_cyclomatic: h.max_cyclomatic,
+        total_cognitive: h.total_cognitive,
+        max_cognitive: h.max_cognitive,
+        max_nesting: h.max_nesting,
+        hottest_function: h.hottest_function.clone(),
+        hottest_line: h.hottest_line,
+        score: h.score,
+    }).collect();
+
+    Ok(JsHotspotResult { files, file_count, function_count: report.function_count })
+}
+
+// ─── Risk Hotspots ───────────────────────────────────────────────────
+
+#[napi(object)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsRiskHotspot {
+    pub file: String,
+    /// churn × complexity × (1 + coupling).
+    pub score: f64,
+    pub churn: f64,
+    pub complexity: f64,
+    pub coupling: f64,
+    pub commits: u32,
+    pub authors: u32,
+    /// "new", "rising", "stable" or "falling" since the previous analysis.
+    pub trend: String,
+    pub recorded_at: i64,
+}
+
+fn risk_hotspot_from_row(row: drift_storage::queries::structural::HotspotHistoryRow) -> JsRiskHotspot {
+    JsRiskHotspot {
+        file: row.file,
+        score: row.score,
+        churn: row.churn,
+        complexity: row.complexity,
+        coupling: row.coupling,
+        commits: row.commits,
+        authors: row.authors,
+        trend: row.trend,
+        recorded_at: row.recorded_at,
+    }
+}
+
+/// Files ranked by churn × complexity × coupling, from the last analysis.
+#[napi]
+pub fn drift_risk_hotspots(limit: Option<u32>) -> napi::Result<Vec<JsRiskHotspot>> {
+    let rt = runtime::get()?;
+    let rows = rt.storage.with_reader(|conn| {
+        drift_storage::queries::structural::get_latest_hotspots(conn, limit.unwrap_or(50))
+    }).map_err(storage_err)?;
+    Ok(rows.into_iter().map(risk_hotspot_from_row).collect())
+}
+
+/// A file's risk hotspot scores across analyses, newest first.
+#[napi]
+pub fn drift_hotspot_trend(file: String, limit: Option<u32>) -> napi::Result<Vec<JsRiskHotspot>> {
+    let rt = runtime::get()?;
+    let rows = rt.storage.with_reader(|conn| {
+        drift_storage::queries::structural::get_hotspot_trend(conn, &file, limit.unwrap_or(20))
+    }).map_err(storage_err)?;
+    Ok(rows.into_iter().map(risk_hotspot_from_row).collect())
+}
@@ -42,6 +42,7 @@ pub const DETECTION_ERROR: &str = "DETECTION_ERROR";
 pub const BOUNDARY_ERROR: &str = "BOUNDARY_ERROR";
 pub const TAINT_ERROR: &str = "TAINT_ERROR";
 pub const CONSTRAINT_ERROR: &str = "CONSTRAINT_ERROR";
+pub const CONTRACT_ERROR: &str = "CONTRACT_ERROR";
 
 // Query
 pub const NOT_FOUND: &str = "NOT_FOUND";
@@ -110,6 +110,7 @@ fn t1_02_buffer_transfer_stress() {
             doc_comment: Some(format!("Function {i} documentation")),
             body_hash: i as u64,
             signature_hash: i as u64 * 31,
+            complexity: Default::default(),
         });
     }
 
@@ -78,6 +78,8 @@ fn t20_01_mcp_entry_point_contracts() {
         rule_id: "r-test".into(), message: "test violation".into(),
         quick_fix_strategy: None, quick_fix_description: None,
         cwe_id: None, owasp_category: None, suppressed: false, is_new: false,
+        owners: None, last_author: None,
+        quick_fix_id: None, quick_fix_edits: None,
     }).unwrap();
 
     // 1. drift_scan underlying: file metadata query
@@ -398,6 +400,8 @@ fn t20_06_analyze_populates_db() {
         quick_fix_description: Some("Add explicit type".into()),
         cwe_id: Some(79), owasp_category: Some("A03".into()),
         suppressed: false, is_new: true,
+        owners: None, last_author: None,
+        quick_fix_id: None, quick_fix_edits: None,
     }).unwrap();
 
     // After analysis: drift status must show non-zero
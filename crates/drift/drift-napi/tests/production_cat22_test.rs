@@ -71,6 +71,7 @@ fn make_function(name: &str, line: u32, end_line: u32, is_exported: bool) -> Fun
         doc_comment: None,
         body_hash: 0,
         signature_hash: 0,
+        complexity: Default::default(),
     }
 }
 
@@ -193,6 +193,9 @@ fn run_analysis_pipeline(
                 is_async: func.is_async,
                 body_hash: func.body_hash.to_le_bytes().to_vec(),
                 signature_hash: func.signature_hash.to_le_bytes().to_vec(),
+                cyclomatic: 0,
+                cognitive: 0,
+                max_nesting: 0,
             });
         }
     }
@@ -119,6 +119,9 @@ pub struct FunctionRow {
     pub is_async: bool,
     pub body_hash: Vec<u8>,
     pub signature_hash: Vec<u8>,
+    pub cyclomatic: i64,
+    pub cognitive: i64,
+    pub max_nesting: i64,
 }
 
 /// A row for the call_edges table.
@@ -320,6 +323,13 @@ pub struct ViolationInsertRow {
     pub owasp_category: Option<String>,
     pub suppressed: bool,
     pub is_new: bool,
+    /// JSON array of CODEOWNERS owners.
+    pub owners: Option<String>,
+    pub last_author: Option<String>,
+    /// Stable id of the quick fix.
+    pub quick_fix_id: Option<String>,
+    /// JSON array of the quick fix's text edits.
+    pub quick_fix_edits: Option<String>,
 }
 
 /// A row for the gate_results table (batch insert).
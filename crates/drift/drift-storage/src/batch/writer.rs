@@ -446,8 +446,8 @@ fn insert_functions(
             "INSERT OR REPLACE INTO functions
              (file, name, qualified_name, language, line, end_line,
               parameter_count, return_type, is_exported, is_async,
-              body_hash, signature_hash)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)",
+              body_hash, signature_hash, cyclomatic, cognitive, max_nesting)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15)",
         )
         .map_err(|e| StorageError::SqliteError {
             message: e.to_string(),
@@ -467,6 +467,9 @@ fn insert_functions(
             row.is_async,
             row.body_hash,
             row.signature_hash,
+            row.cyclomatic,
+            row.cognitive,
+            row.max_nesting,
         ])
         .map_err(|e| StorageError::SqliteError {
             message: e.to_string(),
@@ -840,8 +843,9 @@ fn insert_violations(
             "INSERT OR REPLACE INTO violations
              (id, file, line, column_num, end_line, end_column, severity,
               pattern_id, rule_id, message, quick_fix_strategy, quick_fix_description,
-              cwe_id, owasp_category, suppressed, is_new)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16)",
+              cwe_id, owasp_category, suppressed, is_new, owners, last_author,
+              quick_fix_id, quick_fix_edits)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20)",
         )
         .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
 
@@ -852,6 +856,8 @@ fn insert_violations(
             row.pattern_id, row.rule_id, row.message,
             row.quick_fix_strategy, row.quick_fix_description,
             row.cwe_id, row.owasp_category, row.suppressed as i32, row.is_new as i32,
+            row.owners, row.last_author,
+            row.quick_fix_id, row.quick_fix_edits,
         ])
         .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
     }
@@ -183,6 +183,9 @@ impl From<queries::functions::FunctionRecord> for FunctionRow {
             is_async: r.is_async,
             body_hash: r.body_hash,
             signature_hash: r.signature_hash,
+            cyclomatic: r.cyclomatic,
+            cognitive: r.cognitive,
+            max_nesting: r.max_nesting,
         }
     }
 }
@@ -1531,6 +1534,8 @@ fn to_storage_violation(v: &ViolationRow) -> queries::enforcement::ViolationRow
         quick_fix_strategy: v.quick_fix_strategy.clone(), quick_fix_description: v.quick_fix_description.clone(),
         cwe_id: v.cwe_id, owasp_category: v.owasp_category.clone(),
         suppressed: v.suppressed, is_new: v.is_new,
+        owners: v.owners.clone(), last_author: v.last_author.clone(),
+        quick_fix_id: v.quick_fix_id.clone(), quick_fix_edits: v.quick_fix_edits.clone(),
     }
 }
 
@@ -1542,6 +1547,8 @@ fn from_storage_violation(r: queries::enforcement::ViolationRow) -> ViolationRow
         quick_fix_strategy: r.quick_fix_strategy, quick_fix_description: r.quick_fix_description,
         cwe_id: r.cwe_id, owasp_category: r.owasp_category,
         suppressed: r.suppressed, is_new: r.is_new,
+        owners: r.owners, last_author: r.last_author,
+        quick_fix_id: r.quick_fix_id, quick_fix_edits: r.quick_fix_edits,
     }
 }
 
@@ -9,6 +9,13 @@ pub mod v006_enforcement;
 pub mod v007_advanced;
 pub mod v008_enforcement_fixes;
 pub mod v009_pattern_status;
+pub mod v010_taint_summaries;
+pub mod v011_function_complexity;
+pub mod v012_churn;
+pub mod v013_change_coupling;
+pub mod v014_violation_owners;
+pub mod v015_secret_history;
+pub mod v016_quick_fix_edits;
 
 use drift_core::errors::StorageError;
 use rusqlite::Connection;
@@ -32,6 +39,13 @@ pub fn run_migrations(conn: &Connection) -> Result<(), StorageError> {
         (v007_advanced::MIGRATION_SQL, 7),
         (v008_enforcement_fixes::MIGRATION_SQL, 8),
         (v009_pattern_status::MIGRATION_SQL, 9),
+        (v010_taint_summaries::MIGRATION_SQL, 10),
+        (v011_function_complexity::MIGRATION_SQL, 11),
+        (v012_churn::MIGRATION_SQL, 12),
+        (v013_change_coupling::MIGRATION_SQL, 13),
+        (v014_violation_owners::MIGRATION_SQL, 14),
+        (v015_secret_history::MIGRATION_SQL, 15),
+        (v016_quick_fix_edits::MIGRATION_SQL, 16),
     ];
 
     for (sql, version) in migrations {
@@ -0,0 +1,16 @@
+//! V010 migration: Persisted taint function summaries.
+//!
+//! Adds taint_summaries table so interprocedural taint analysis can reuse
+//! per-function summaries across scans, keyed by function id and content hash.
+
+pub const MIGRATION_SQL: &str = r#"
+-- Interprocedural taint summaries
+-- One row per call graph function; summary holds the serialized summary,
+-- callees and the flows that start at the function.
+CREATE TABLE IF NOT EXISTS taint_summaries (
+    function_id TEXT PRIMARY KEY,
+    content_hash BLOB NOT NULL,
+    summary TEXT NOT NULL,
+    updated_at INTEGER NOT NULL DEFAULT (unixepoch())
+) STRICT;
+"#;
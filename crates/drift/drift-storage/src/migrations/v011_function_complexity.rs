@@ -0,0 +1,11 @@
+//! V011 migration: Function complexity.
+//!
+//! Adds cyclomatic, cognitive and max_nesting columns to functions. 0 means
+//! the function was not measured.
+
+pub const MIGRATION_SQL: &str = r#"
+ALTER TABLE functions ADD COLUMN cyclomatic INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE functions ADD COLUMN cognitive INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE functions ADD COLUMN max_nesting INTEGER NOT NULL DEFAULT 0;
+CREATE INDEX IF NOT EXISTS idx_functions_cyclomatic ON functions(cyclomatic);
+"#;
@@ -0,0 +1,49 @@
+//! V012 migration: Git churn and hotspot history.
+//!
+//! Adds file_churn and function_churn (current churn from git history) and
+//! hotspot_history, one row per file per analysis, so hotspot scores can be
+//! tracked over time.
+
+pub const MIGRATION_SQL: &str = r#"
+-- Churn per file, relative to the repository root
+CREATE TABLE IF NOT EXISTS file_churn (
+    file TEXT PRIMARY KEY,
+    commits INTEGER NOT NULL,
+    lines_added INTEGER NOT NULL,
+    lines_removed INTEGER NOT NULL,
+    authors INTEGER NOT NULL,
+    last_changed INTEGER NOT NULL,
+    recency_weighted REAL NOT NULL,
+    updated_at INTEGER NOT NULL DEFAULT (unixepoch())
+) STRICT;
+
+-- Churn per call graph function; change_frequency is normalized 0.0-1.0
+CREATE TABLE IF NOT EXISTS function_churn (
+    function_id TEXT PRIMARY KEY,
+    file TEXT NOT NULL,
+    commits INTEGER NOT NULL,
+    lines_changed INTEGER NOT NULL,
+    recency_weighted REAL NOT NULL,
+    change_frequency REAL NOT NULL,
+    updated_at INTEGER NOT NULL DEFAULT (unixepoch())
+) STRICT;
+
+CREATE INDEX IF NOT EXISTS idx_function_churn_file ON function_churn(file);
+
+-- Risk hotspot scores (churn x complexity x coupling) per analysis
+CREATE TABLE IF NOT EXISTS hotspot_history (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    file TEXT NOT NULL,
+    score REAL NOT NULL,
+    churn REAL NOT NULL,
+    complexity REAL NOT NULL,
+    coupling REAL NOT NULL,
+    commits INTEGER NOT NULL,
+    authors INTEGER NOT NULL,
+    trend TEXT NOT NULL,
+    head_commit TEXT,
+    recorded_at INTEGER NOT NULL DEFAULT (unixepoch())
+) STRICT;
+
+CREATE INDEX IF NOT EXISTS idx_hotspot_history_file ON hotspot_history(file, recorded_at);
+"#;
@@ -0,0 +1,25 @@
+//! V013 migration: Logical (change) coupling.
+//!
+//! Adds change_coupling, module pairs that change together in git history,
+//! alongside whether an import edge or dependency cycle connects them and
+//! which workspace package each side is in.
+
+pub const MIGRATION_SQL: &str = r#"
+-- Co-changing module pairs (module_a < module_b), replaced on each analysis
+CREATE TABLE IF NOT EXISTS change_coupling (
+    module_a TEXT NOT NULL,
+    module_b TEXT NOT NULL,
+    co_changes INTEGER NOT NULL,
+    confidence REAL NOT NULL,
+    file_a TEXT NOT NULL,
+    file_b TEXT NOT NULL,
+    file_pairs INTEGER NOT NULL,
+    import_edge INTEGER NOT NULL DEFAULT 0,
+    in_cycle INTEGER NOT NULL DEFAULT 0,
+    package_a TEXT,
+    package_b TEXT,
+    hidden INTEGER NOT NULL DEFAULT 0,
+    updated_at INTEGER NOT NULL DEFAULT (unixepoch()),
+    PRIMARY KEY (module_a, module_b)
+) STRICT;
+"#;
@@ -0,0 +1,9 @@
+//! V014 migration: Violation ownership.
+//!
+//! Adds owners (JSON array of CODEOWNERS owners) and last_author (from git
+//! blame) to violations.
+
+pub const MIGRATION_SQL: &str = r#"
+ALTER TABLE violations ADD COLUMN owners TEXT;
+ALTER TABLE violations ADD COLUMN last_author TEXT;
+"#;
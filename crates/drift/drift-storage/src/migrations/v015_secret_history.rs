@@ -0,0 +1,34 @@
+//! V015 migration: Secret history.
+//!
+//! Adds historical_secrets, secrets found in git history de-duplicated by
+//! fingerprint, and secret_history_state, where the scan resumes from.
+
+pub const MIGRATION_SQL: &str = r#"
+-- Secrets added anywhere in git history, one row per fingerprint
+CREATE TABLE IF NOT EXISTS historical_secrets (
+    fingerprint TEXT PRIMARY KEY,
+    pattern_name TEXT NOT NULL,
+    redacted_value TEXT NOT NULL,
+    file TEXT NOT NULL,
+    line INTEGER NOT NULL,
+    severity TEXT NOT NULL,
+    entropy REAL NOT NULL,
+    confidence REAL NOT NULL,
+    cwe_ids TEXT NOT NULL DEFAULT '[]',
+    first_commit TEXT NOT NULL,
+    author TEXT NOT NULL,
+    timestamp INTEGER NOT NULL,
+    occurrences INTEGER NOT NULL DEFAULT 1,
+    files TEXT NOT NULL DEFAULT '[]',
+    present_at_head INTEGER NOT NULL DEFAULT 0
+) STRICT;
+
+-- Resume point of the history scan (single row)
+CREATE TABLE IF NOT EXISTS secret_history_state (
+    id INTEGER PRIMARY KEY CHECK (id = 1),
+    last_commit TEXT,
+    scanned_tips TEXT NOT NULL DEFAULT '[]',
+    commits_scanned INTEGER NOT NULL DEFAULT 0,
+    updated_at INTEGER NOT NULL DEFAULT (unixepoch())
+) STRICT;
+"#;
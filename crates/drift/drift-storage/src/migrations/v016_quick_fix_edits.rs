@@ -0,0 +1,9 @@
+//! V016 migration: Quick-fix edits.
+//!
+//! Adds quick_fix_id and quick_fix_edits (JSON array of text edits) to
+//! violations, so applicable fixes survive a round trip through storage.
+
+pub const MIGRATION_SQL: &str = r#"
+ALTER TABLE violations ADD COLUMN quick_fix_id TEXT;
+ALTER TABLE violations ADD COLUMN quick_fix_edits TEXT;
+"#;
@@ -25,6 +25,13 @@ pub struct ViolationRow {
     pub owasp_category: Option<String>,
     pub suppressed: bool,
     pub is_new: bool,
+    /// JSON array of CODEOWNERS owners.
+    pub owners: Option<String>,
+    pub last_author: Option<String>,
+    /// Stable id of the quick fix.
+    pub quick_fix_id: Option<String>,
+    /// JSON array of the quick fix's text edits.
+    pub quick_fix_edits: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -82,9 +89,9 @@ pub fn insert_violation(
     v: &ViolationRow,
 ) -> Result<(), StorageError> {
     conn.execute(
-        "INSERT OR REPLACE INTO violations (id, file, line, column_num, end_line, end_column, severity, pattern_id, rule_id, message, quick_fix_strategy, quick_fix_description, cwe_id, owasp_category, suppressed, is_new)
-         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16)",
-        params![v.id, v.file, v.line, v.column, v.end_line, v.end_column, v.severity, v.pattern_id, v.rule_id, v.message, v.quick_fix_strategy, v.quick_fix_description, v.cwe_id, v.owasp_category, v.suppressed as i32, v.is_new as i32],
+        "INSERT OR REPLACE INTO violations (id, file, line, column_num, end_line, end_column, severity, pattern_id, rule_id, message, quick_fix_strategy, quick_fix_description, cwe_id, owasp_category, suppressed, is_new, owners, last_author, quick_fix_id, quick_fix_edits)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20)",
+        params![v.id, v.file, v.line, v.column, v.end_line, v.end_column, v.severity, v.pattern_id, v.rule_id, v.message, v.quick_fix_strategy, v.quick_fix_description, v.cwe_id, v.owasp_category, v.suppressed as i32, v.is_new as i32, v.owners, v.last_author, v.quick_fix_id, v.quick_fix_edits],
     ).map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
     Ok(())
 }
@@ -95,7 +102,7 @@ pub fn query_violations_by_file(
 ) -> Result<Vec<ViolationRow>, StorageError> {
     let mut stmt = conn
         .prepare_cached(
-            "SELECT id, file, line, column_num, end_line, end_column, severity, pattern_id, rule_id, message, quick_fix_strategy, quick_fix_description, cwe_id, owasp_category, suppressed, is_new
+            "SELECT id, file, line, column_num, end_line, end_column, severity, pattern_id, rule_id, message, quick_fix_strategy, quick_fix_description, cwe_id, owasp_category, suppressed, is_new, owners, last_author, quick_fix_id, quick_fix_edits
              FROM violations WHERE file = ?1 ORDER BY line",
         )
         .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
@@ -119,6 +126,10 @@ pub fn query_violations_by_file(
                 owasp_category: row.get(13)?,
                 suppressed: row.get::<_, i32>(14)? != 0,
                 is_new: row.get::<_, i32>(15).unwrap_or(0) != 0,
+                owners: row.get(16)?,
+                last_author: row.get(17)?,
+                quick_fix_id: row.get(18)?,
+                quick_fix_edits: row.get(19)?,
             })
         })
         .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
@@ -130,7 +141,7 @@ pub fn query_violations_by_file(
 pub fn query_all_violations(conn: &Connection) -> Result<Vec<ViolationRow>, StorageError> {
     let mut stmt = conn
         .prepare_cached(
-            "SELECT id, file, line, column_num, end_line, end_column, severity, pattern_id, rule_id, message, quick_fix_strategy, quick_fix_description, cwe_id, owasp_category, suppressed, is_new
+            "SELECT id, file, line, column_num, end_line, end_column, severity, pattern_id, rule_id, message, quick_fix_strategy, quick_fix_description, cwe_id, owasp_category, suppressed, is_new, owners, last_author, quick_fix_id, quick_fix_edits
              FROM violations ORDER BY file, line",
         )
         .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
@@ -154,6 +165,10 @@ pub fn query_all_violations(conn: &Connection) -> Result<Vec<ViolationRow>, Stor
                 owasp_category: row.get(13)?,
                 suppressed: row.get::<_, i32>(14)? != 0,
                 is_new: row.get::<_, i32>(15).unwrap_or(0) != 0,
+                owners: row.get(16)?,
+                last_author: row.get(17)?,
+                quick_fix_id: row.get(18)?,
+                quick_fix_edits: row.get(19)?,
             })
         })
         .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
@@ -580,6 +595,41 @@ pub fn query_recent_policy_results(
         .map_err(|e| StorageError::SqliteError { message: e.to_string() })
 }
 
+/// Policy verdicts of the latest analysis run: the newest batch of policy
+/// results, unless gate results were written after it by a run without
+/// policies.
+pub fn query_latest_policy_results(conn: &Connection) -> Result<Vec<PolicyResultRow>, StorageError> {
+    let mut stmt = conn
+        .prepare_cached(
+            "SELECT id, policy_name, aggregation_mode, overall_passed, overall_score, gate_count, gates_passed, gates_failed, details, run_at
+             FROM policy_results
+             WHERE run_at = (SELECT MAX(run_at) FROM policy_results)
+               AND run_at >= (SELECT COALESCE(MAX(run_at), 0) FROM gate_results)
+             ORDER BY id",
+        )
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+
+    let rows = stmt
+        .query_map([], |row| {
+            Ok(PolicyResultRow {
+                id: row.get(0)?,
+                policy_name: row.get(1)?,
+                aggregation_mode: row.get(2)?,
+                overall_passed: row.get::<_, i32>(3)? != 0,
+                overall_score: row.get(4)?,
+                gate_count: row.get(5)?,
+                gates_passed: row.get(6)?,
+                gates_failed: row.get(7)?,
+                details: row.get(8)?,
+                run_at: row.get(9)?,
+            })
+        })
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+
+    rows.collect::<Result<Vec<_>, _>>()
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })
+}
+
 // ─── Degradation Alerts ─────────────────────────────────────────────
 
 #[derive(Debug, Clone)]
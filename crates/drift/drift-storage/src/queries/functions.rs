@@ -1,7 +1,7 @@
 //! functions table queries.
 
 use drift_core::errors::StorageError;
-use rusqlite::{params, Connection};
+use rusqlite::{params, Connection, OptionalExtension};
 
 /// A function record from the database.
 #[derive(Debug, Clone)]
@@ -19,6 +19,30 @@ pub struct FunctionRecord {
     pub is_async: bool,
     pub body_hash: Option<Vec<u8>>,
     pub signature_hash: Option<Vec<u8>>,
+    pub cyclomatic: i64,
+    pub cognitive: i64,
+    pub max_nesting: i64,
+}
+
+fn function_from_row(row: &rusqlite::Row) -> rusqlite::Result<FunctionRecord> {
+    Ok(FunctionRecord {
+        id: row.get(0)?,
+        file: row.get(1)?,
+        name: row.get(2)?,
+        qualified_name: row.get(3)?,
+        language: row.get(4)?,
+        line: row.get(5)?,
+        end_line: row.get(6)?,
+        parameter_count: row.get(7)?,
+        return_type: row.get(8)?,
+        is_exported: row.get(9)?,
+        is_async: row.get(10)?,
+        body_hash: row.get(11)?,
+        signature_hash: row.get(12)?,
+        cyclomatic: row.get(13)?,
+        cognitive: row.get(14)?,
+        max_nesting: row.get(15)?,
+    })
 }
 
 /// Get all functions for a given file.
@@ -30,7 +54,7 @@ pub fn get_functions_by_file(
         .prepare_cached(
             "SELECT id, file, name, qualified_name, language, line, end_line,
                     parameter_count, return_type, is_exported, is_async,
-                    body_hash, signature_hash
+                    body_hash, signature_hash, cyclomatic, cognitive, max_nesting
              FROM functions WHERE file = ?1 ORDER BY line",
         )
         .map_err(|e| StorageError::SqliteError {
@@ -38,23 +62,7 @@ pub fn get_functions_by_file(
         })?;
 
     let rows = stmt
-        .query_map(params![file], |row| {
-            Ok(FunctionRecord {
-                id: row.get(0)?,
-                file: row.get(1)?,
-                name: row.get(2)?,
-                qualified_name: row.get(3)?,
-                language: row.get(4)?,
-                line: row.get(5)?,
-                end_line: row.get(6)?,
-                parameter_count: row.get(7)?,
-                return_type: row.get(8)?,
-                is_exported: row.get(9)?,
-                is_async: row.get(10)?,
-                body_hash: row.get(11)?,
-                signature_hash: row.get(12)?,
-            })
-        })
+        .query_map(params![file], function_from_row)
         .map_err(|e| StorageError::SqliteError {
             message: e.to_string(),
         })?;
@@ -77,7 +85,7 @@ pub fn get_function_by_qualified_name(
         .prepare_cached(
             "SELECT id, file, name, qualified_name, language, line, end_line,
                     parameter_count, return_type, is_exported, is_async,
-                    body_hash, signature_hash
+                    body_hash, signature_hash, cyclomatic, cognitive, max_nesting
              FROM functions WHERE qualified_name = ?1",
         )
         .map_err(|e| StorageError::SqliteError {
@@ -85,23 +93,7 @@ pub fn get_function_by_qualified_name(
         })?;
 
     let mut rows = stmt
-        .query_map(params![qualified_name], |row| {
-            Ok(FunctionRecord {
-                id: row.get(0)?,
-                file: row.get(1)?,
-                name: row.get(2)?,
-                qualified_name: row.get(3)?,
-                language: row.get(4)?,
-                line: row.get(5)?,
-                end_line: row.get(6)?,
-                parameter_count: row.get(7)?,
-                return_type: row.get(8)?,
-                is_exported: row.get(9)?,
-                is_async: row.get(10)?,
-                body_hash: row.get(11)?,
-                signature_hash: row.get(12)?,
-            })
-        })
+        .query_map(params![qualified_name], function_from_row)
         .map_err(|e| StorageError::SqliteError {
             message: e.to_string(),
         })?;
@@ -115,6 +107,59 @@ pub fn get_function_by_qualified_name(
     }
 }
 
+/// Get every function with measured complexity, most complex first.
+pub fn get_measured_functions(conn: &Connection) -> Result<Vec<FunctionRecord>, StorageError> {
+    let mut stmt = conn
+        .prepare_cached(
+            "SELECT id, file, name, qualified_name, language, line, end_line,
+                    parameter_count, return_type, is_exported, is_async,
+                    body_hash, signature_hash, cyclomatic, cognitive, max_nesting
+             FROM functions WHERE cyclomatic > 0
+             ORDER BY cognitive DESC, cyclomatic DESC, file, line",
+        )
+        .map_err(|e| StorageError::SqliteError {
+            message: e.to_string(),
+        })?;
+
+    let rows = stmt
+        .query_map([], function_from_row)
+        .map_err(|e| StorageError::SqliteError {
+            message: e.to_string(),
+        })?;
+
+    let mut result = Vec::new();
+    for row in rows {
+        result.push(row.map_err(|e| StorageError::SqliteError {
+            message: e.to_string(),
+        })?);
+    }
+    Ok(result)
+}
+
+/// Id of the innermost function in `file` whose span contains `line`.
+pub fn get_enclosing_function_id(
+    conn: &Connection,
+    file: &str,
+    line: i64,
+) -> Result<Option<i64>, StorageError> {
+    let mut stmt = conn
+        .prepare_cached(
+            "SELECT id FROM functions
+             WHERE file = ?1 AND line <= ?2 AND end_line >= ?2
+             ORDER BY end_line - line, line DESC
+             LIMIT 1",
+        )
+        .map_err(|e| StorageError::SqliteError {
+            message: e.to_string(),
+        })?;
+
+    stmt.query_row(params![file, line], |row| row.get(0))
+        .optional()
+        .map_err(|e| StorageError::SqliteError {
+            message: e.to_string(),
+        })
+}
+
 /// Delete all functions for a given file (used when file is re-parsed).
 pub fn delete_functions_by_file(
     conn: &Connection,
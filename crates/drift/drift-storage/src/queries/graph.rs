@@ -156,6 +156,73 @@ pub fn get_taint_flows_by_cwe(
         .map_err(|e| StorageError::SqliteError { message: e.to_string() })
 }
 
+// --- Taint Summaries ---
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaintSummaryRow {
+    pub function_id: String,
+    pub content_hash: Vec<u8>,
+    pub summary: String, // JSON
+}
+
+pub fn get_taint_summaries(conn: &Connection) -> Result<Vec<TaintSummaryRow>, StorageError> {
+    let mut stmt = conn
+        .prepare_cached("SELECT function_id, content_hash, summary FROM taint_summaries")
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+
+    let rows = stmt
+        .query_map([], |row| {
+            Ok(TaintSummaryRow {
+                function_id: row.get(0)?,
+                content_hash: row.get(1)?,
+                summary: row.get(2)?,
+            })
+        })
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+
+    rows.collect::<Result<Vec<_>, _>>()
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })
+}
+
+/// Upsert `rows` and delete `removed` function ids in one transaction.
+pub fn replace_taint_summaries(
+    conn: &Connection,
+    rows: &[TaintSummaryRow],
+    removed: &[String],
+) -> Result<(), StorageError> {
+    let tx = conn
+        .unchecked_transaction()
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+    {
+        let mut delete = tx
+            .prepare_cached("DELETE FROM taint_summaries WHERE function_id = ?1")
+            .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+        for function_id in removed {
+            delete
+                .execute(params![function_id])
+                .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+        }
+        let mut upsert = tx
+            .prepare_cached(
+                "INSERT OR REPLACE INTO taint_summaries (function_id, content_hash, summary, updated_at)
+                 VALUES (?1, ?2, ?3, unixepoch())",
+            )
+            .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+        for row in rows {
+            upsert
+                .execute(params![row.function_id, row.content_hash, row.summary])
+                .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+        }
+    }
+    tx.commit()
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })
+}
+
+pub fn count_taint_summaries(conn: &Connection) -> Result<i64, StorageError> {
+    conn.query_row("SELECT COUNT(*) FROM taint_summaries", [], |row| row.get(0))
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })
+}
+
 // --- Error Gaps ---
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -1,4 +1,5 @@
-//! Queries for all 9 structural intelligence systems (Phase 5).
+//! Queries for all 9 structural intelligence systems (Phase 5), plus change
+//! coupling, secret history, churn and hotspot history.
 
 use drift_core::errors::StorageError;
 use rusqlite::{params, Connection};
@@ -876,6 +877,238 @@ pub fn query_coupling_cycles(conn: &Connection) -> Result<Vec<CouplingCycleRow>,
         .map_err(|e| StorageError::SqliteError { message: e.to_string() })
 }
 
+// ─── Secret History ─────────────────────────────────────────────────
+
+/// Where the git history secret scan resumes from.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SecretHistoryStateRow {
+    pub last_commit: Option<String>,
+    pub scanned_tips: String, // JSON array
+    pub commits_scanned: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoricalSecretRow {
+    pub fingerprint: String,
+    pub pattern_name: String,
+    pub redacted_value: String,
+    pub file: String,
+    pub line: u32,
+    pub severity: String,
+    pub entropy: f64,
+    pub confidence: f64,
+    pub cwe_ids: String, // JSON array
+    pub first_commit: String,
+    pub author: String,
+    pub timestamp: i64,
+    pub occurrences: u32,
+    pub files: String, // JSON array
+    pub present_at_head: bool,
+}
+
+/// Replace the stored history scan, secrets and resume state, with the latest.
+pub fn replace_secret_history(
+    conn: &Connection,
+    state: &SecretHistoryStateRow,
+    secrets: &[HistoricalSecretRow],
+) -> Result<(), StorageError> {
+    let tx = conn
+        .unchecked_transaction()
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+    tx.execute("DELETE FROM historical_secrets", [])
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+    {
+        let mut insert = tx
+            .prepare_cached(
+                "INSERT OR REPLACE INTO historical_secrets
+                 (fingerprint, pattern_name, redacted_value, file, line, severity, entropy, confidence, cwe_ids,
+                  first_commit, author, timestamp, occurrences, files, present_at_head)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15)",
+            )
+            .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+        for row in secrets {
+            insert
+                .execute(params![
+                    row.fingerprint,
+                    row.pattern_name,
+                    row.redacted_value,
+                    row.file,
+                    row.line,
+                    row.severity,
+                    row.entropy,
+                    row.confidence,
+                    row.cwe_ids,
+                    row.first_commit,
+                    row.author,
+                    row.timestamp,
+                    row.occurrences,
+                    row.files,
+                    row.present_at_head as i32
+                ])
+                .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+        }
+    }
+    tx.execute(
+        "INSERT OR REPLACE INTO secret_history_state (id, last_commit, scanned_tips, commits_scanned, updated_at)
+         VALUES (1, ?1, ?2, ?3, unixepoch())",
+        params![state.last_commit, state.scanned_tips, state.commits_scanned],
+    )
+    .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+    tx.commit()
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })
+}
+
+/// The resume state of the last history scan, if one has run.
+pub fn get_secret_history_state(conn: &Connection) -> Result<Option<SecretHistoryStateRow>, StorageError> {
+    let mut stmt = conn
+        .prepare_cached("SELECT last_commit, scanned_tips, commits_scanned FROM secret_history_state WHERE id = 1")
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+
+    let mut rows = stmt
+        .query_map([], |row| {
+            Ok(SecretHistoryStateRow {
+                last_commit: row.get(0)?,
+                scanned_tips: row.get(1)?,
+                commits_scanned: row.get(2)?,
+            })
+        })
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+
+    rows.next()
+        .transpose()
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })
+}
+
+/// Secrets found in git history, those still at HEAD first, then oldest first.
+pub fn get_historical_secrets(conn: &Connection) -> Result<Vec<HistoricalSecretRow>, StorageError> {
+    let mut stmt = conn
+        .prepare_cached(
+            "SELECT fingerprint, pattern_name, redacted_value, file, line, severity, entropy, confidence, cwe_ids,
+                    first_commit, author, timestamp, occurrences, files, present_at_head
+             FROM historical_secrets
+             ORDER BY present_at_head DESC, timestamp, fingerprint",
+        )
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+
+    let rows = stmt
+        .query_map([], |row| {
+            Ok(HistoricalSecretRow {
+                fingerprint: row.get(0)?,
+                pattern_name: row.get(1)?,
+                redacted_value: row.get(2)?,
+                file: row.get(3)?,
+                line: row.get(4)?,
+                severity: row.get(5)?,
+                entropy: row.get(6)?,
+                confidence: row.get(7)?,
+                cwe_ids: row.get(8)?,
+                first_commit: row.get(9)?,
+                author: row.get(10)?,
+                timestamp: row.get(11)?,
+                occurrences: row.get(12)?,
+                files: row.get(13)?,
+                present_at_head: row.get::<_, i32>(14)? != 0,
+            })
+        })
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+
+    rows.collect::<Result<Vec<_>, _>>()
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })
+}
+
+// ─── Change Coupling ────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChangeCouplingRow {
+    pub module_a: String,
+    pub module_b: String,
+    pub co_changes: u32,
+    pub confidence: f64,
+    pub file_a: String,
+    pub file_b: String,
+    pub file_pairs: u32,
+    pub import_edge: bool,
+    pub in_cycle: bool,
+    pub package_a: Option<String>,
+    pub package_b: Option<String>,
+    /// Cross-package co-change with no import edge or shared cycle.
+    pub hidden: bool,
+}
+
+/// Replace all change coupling rows with the latest analysis.
+pub fn replace_change_coupling(conn: &Connection, rows: &[ChangeCouplingRow]) -> Result<(), StorageError> {
+    let tx = conn
+        .unchecked_transaction()
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+    tx.execute("DELETE FROM change_coupling", [])
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+    {
+        let mut insert = tx
+            .prepare_cached(
+                "INSERT OR REPLACE INTO change_coupling
+                 (module_a, module_b, co_changes, confidence, file_a, file_b, file_pairs, import_edge, in_cycle,
+                  package_a, package_b, hidden)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)",
+            )
+            .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+        for row in rows {
+            insert
+                .execute(params![
+                    row.module_a,
+                    row.module_b,
+                    row.co_changes,
+                    row.confidence,
+                    row.file_a,
+                    row.file_b,
+                    row.file_pairs,
+                    row.import_edge as i32,
+                    row.in_cycle as i32,
+                    row.package_a,
+                    row.package_b,
+                    row.hidden as i32
+                ])
+                .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+        }
+    }
+    tx.commit()
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })
+}
+
+/// Change coupling, strongest first. `hidden_only` keeps hidden
+/// cross-package dependencies.
+pub fn get_change_coupling(conn: &Connection, hidden_only: bool) -> Result<Vec<ChangeCouplingRow>, StorageError> {
+    let mut stmt = conn
+        .prepare_cached(
+            "SELECT module_a, module_b, co_changes, confidence, file_a, file_b, file_pairs, import_edge, in_cycle,
+                    package_a, package_b, hidden
+             FROM change_coupling WHERE ?1 = 0 OR hidden = 1
+             ORDER BY co_changes DESC, confidence DESC, module_a, module_b",
+        )
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+
+    let rows = stmt
+        .query_map(params![hidden_only as i32], |row| {
+            Ok(ChangeCouplingRow {
+                module_a: row.get(0)?,
+                module_b: row.get(1)?,
+                co_changes: row.get(2)?,
+                confidence: row.get(3)?,
+                file_a: row.get(4)?,
+                file_b: row.get(5)?,
+                file_pairs: row.get(6)?,
+                import_edge: row.get::<_, i32>(7)? != 0,
+                in_cycle: row.get::<_, i32>(8)? != 0,
+                package_a: row.get(9)?,
+                package_b: row.get(10)?,
+                hidden: row.get::<_, i32>(11)? != 0,
+            })
+        })
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+
+    rows.collect::<Result<Vec<_>, _>>()
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })
+}
+
 // ─── Constraint Verifications ───────────────────────────────────────
 
 #[derive(Debug, Clone)]
@@ -1019,3 +1252,233 @@ pub fn query_contract_mismatches_by_type(
     rows.collect::<Result<Vec<_>, _>>()
         .map_err(|e| StorageError::SqliteError { message: e.to_string() })
 }
+
+// ─── Churn ──────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileChurnRow {
+    pub file: String,
+    pub commits: u32,
+    pub lines_added: u32,
+    pub lines_removed: u32,
+    pub authors: u32,
+    pub last_changed: i64,
+    pub recency_weighted: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FunctionChurnRow {
+    pub function_id: String,
+    pub file: String,
+    pub commits: u32,
+    pub lines_changed: u32,
+    pub recency_weighted: f64,
+    pub change_frequency: f64,
+}
+
+/// Replace all churn rows with the latest analysis in one transaction.
+pub fn replace_churn(
+    conn: &Connection,
+    files: &[FileChurnRow],
+    functions: &[FunctionChurnRow],
+) -> Result<(), StorageError> {
+    let tx = conn
+        .unchecked_transaction()
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+    tx.execute_batch("DELETE FROM file_churn; DELETE FROM function_churn;")
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+    {
+        let mut insert_file = tx
+            .prepare_cached(
+                "INSERT INTO file_churn (file, commits, lines_added, lines_removed, authors, last_changed, recency_weighted)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            )
+            .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+        for row in files {
+            insert_file
+                .execute(params![
+                    row.file,
+                    row.commits,
+                    row.lines_added,
+                    row.lines_removed,
+                    row.authors,
+                    row.last_changed,
+                    row.recency_weighted
+                ])
+                .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+        }
+        let mut insert_function = tx
+            .prepare_cached(
+                "INSERT OR REPLACE INTO function_churn (function_id, file, commits, lines_changed, recency_weighted, change_frequency)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            )
+            .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+        for row in functions {
+            insert_function
+                .execute(params![
+                    row.function_id,
+                    row.file,
+                    row.commits,
+                    row.lines_changed,
+                    row.recency_weighted,
+                    row.change_frequency
+                ])
+                .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+        }
+    }
+    tx.commit()
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })
+}
+
+/// All file churn, most recency-weighted churn first.
+pub fn get_file_churn(conn: &Connection) -> Result<Vec<FileChurnRow>, StorageError> {
+    let mut stmt = conn
+        .prepare_cached(
+            "SELECT file, commits, lines_added, lines_removed, authors, last_changed, recency_weighted
+             FROM file_churn ORDER BY recency_weighted DESC, file",
+        )
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+
+    let rows = stmt
+        .query_map([], |row| {
+            Ok(FileChurnRow {
+                file: row.get(0)?,
+                commits: row.get(1)?,
+                lines_added: row.get(2)?,
+                lines_removed: row.get(3)?,
+                authors: row.get(4)?,
+                last_changed: row.get(5)?,
+                recency_weighted: row.get(6)?,
+            })
+        })
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+
+    rows.collect::<Result<Vec<_>, _>>()
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })
+}
+
+pub fn get_function_churn(conn: &Connection, function_id: &str) -> Result<Option<FunctionChurnRow>, StorageError> {
+    let mut stmt = conn
+        .prepare_cached(
+            "SELECT function_id, file, commits, lines_changed, recency_weighted, change_frequency
+             FROM function_churn WHERE function_id = ?1",
+        )
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+
+    stmt.query_row(params![function_id], |row| {
+        Ok(FunctionChurnRow {
+            function_id: row.get(0)?,
+            file: row.get(1)?,
+            commits: row.get(2)?,
+            lines_changed: row.get(3)?,
+            recency_weighted: row.get(4)?,
+            change_frequency: row.get(5)?,
+        })
+    })
+    .optional()
+    .map_err(|e| StorageError::SqliteError { message: e.to_string() })
+}
+
+// ─── Hotspot History ────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HotspotHistoryRow {
+    pub file: String,
+    pub score: f64,
+    pub churn: f64,
+    pub complexity: f64,
+    pub coupling: f64,
+    pub commits: u32,
+    pub authors: u32,
+    pub trend: String,
+    pub head_commit: Option<String>,
+    /// Set by the database on insert.
+    pub recorded_at: i64,
+}
+
+/// Record one analysis's hotspot scores.
+pub fn insert_hotspot_snapshot(conn: &Connection, rows: &[HotspotHistoryRow]) -> Result<(), StorageError> {
+    let tx = conn
+        .unchecked_transaction()
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+    {
+        let mut insert = tx
+            .prepare_cached(
+                "INSERT INTO hotspot_history (file, score, churn, complexity, coupling, commits, authors, trend, head_commit)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+            )
+            .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+        for row in rows {
+            insert
+                .execute(params![
+                    row.file,
+                    row.score,
+                    row.churn,
+                    row.complexity,
+                    row.coupling,
+                    row.commits,
+                    row.authors,
+                    row.trend,
+                    row.head_commit
+                ])
+                .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+        }
+    }
+    tx.commit()
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })
+}
+
+fn hotspot_history_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<HotspotHistoryRow> {
+    Ok(HotspotHistoryRow {
+        file: row.get(0)?,
+        score: row.get(1)?,
+        churn: row.get(2)?,
+        complexity: row.get(3)?,
+        coupling: row.get(4)?,
+        commits: row.get(5)?,
+        authors: row.get(6)?,
+        trend: row.get(7)?,
+        head_commit: row.get(8)?,
+        recorded_at: row.get(9)?,
+    })
+}
+
+/// Each file's most recent hotspot score, highest first. Gates and context
+/// generation use this to prioritize risky code.
+pub fn get_latest_hotspots(conn: &Connection, limit: u32) -> Result<Vec<HotspotHistoryRow>, StorageError> {
+    let mut stmt = conn
+        .prepare_cached(
+            "SELECT file, score, churn, complexity, coupling, commits, authors, trend, head_commit, recorded_at
+             FROM hotspot_history
+             WHERE id IN (SELECT MAX(id) FROM hotspot_history GROUP BY file)
+             ORDER BY score DESC, file
+             LIMIT ?1",
+        )
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+
+    let rows = stmt
+        .query_map(params![limit], hotspot_history_row)
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+
+    rows.collect::<Result<Vec<_>, _>>()
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })
+}
+
+/// A file's hotspot scores over time, newest first.
+pub fn get_hotspot_trend(conn: &Connection, file: &str, limit: u32) -> Result<Vec<HotspotHistoryRow>, StorageError> {
+    let mut stmt = conn
+        .prepare_cached(
+            "SELECT file, score, churn, complexity, coupling, commits, authors, trend, head_commit, recorded_at
+             FROM hotspot_history WHERE file = ?1
+             ORDER BY id DESC
+             LIMIT ?2",
+        )
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+
+    let rows = stmt
+        .query_map(params![file, limit], hotspot_history_row)
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })?;
+
+    rows.collect::<Result<Vec<_>, _>>()
+        .map_err(|e| StorageError::SqliteError { message: e.to_string() })
+}
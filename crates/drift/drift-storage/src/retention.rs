@@ -124,6 +124,7 @@ fn apply_retention_inner(
     cleanup_by_time(conn, "scan_history", "started_at", medium_cutoff, report)?;
     cleanup_by_time(conn, "audit_snapshots", "created_at", medium_cutoff, report)?;
     cleanup_by_time(conn, "health_trends", "recorded_at", medium_cutoff, report)?;
+    cleanup_by_time(conn, "hotspot_history", "recorded_at", medium_cutoff, report)?;
     cleanup_by_time(conn, "feedback", "created_at", medium_cutoff, report)?;
     cleanup_by_time(conn, "constraint_verifications", "verified_at", medium_cutoff, report)?;
     cleanup_by_time(conn, "contract_mismatches", "created_at", medium_cutoff, report)?;
@@ -219,6 +220,7 @@ mod tests {
              CREATE TABLE policy_results (id INTEGER PRIMARY KEY, run_at INTEGER DEFAULT 0);
              CREATE TABLE audit_snapshots (id INTEGER PRIMARY KEY, created_at INTEGER DEFAULT 0);
              CREATE TABLE health_trends (id INTEGER PRIMARY KEY, recorded_at INTEGER DEFAULT 0);
+             CREATE TABLE hotspot_history (id INTEGER PRIMARY KEY, recorded_at INTEGER DEFAULT 0);
              CREATE TABLE constraint_verifications (id INTEGER PRIMARY KEY, verified_at INTEGER DEFAULT 0);
              CREATE TABLE contract_mismatches (id INTEGER PRIMARY KEY, created_at INTEGER DEFAULT 0);
              CREATE TABLE dna_mutations (id TEXT PRIMARY KEY, detected_at INTEGER DEFAULT 0);
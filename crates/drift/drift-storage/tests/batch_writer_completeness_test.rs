@@ -68,6 +68,9 @@ fn batch_insert_data_access() {
             is_async: true,
             body_hash: vec![1, 2, 3],
             signature_hash: vec![4, 5, 6],
+            cyclomatic: 0,
+            cognitive: 0,
+            max_nesting: 0,
         }]))
         .unwrap();
     writer.flush().unwrap();
@@ -316,6 +319,9 @@ fn batch_insert_violations() {
             owasp_category: Some("A03:2021".to_string()),
             suppressed: false,
             is_new: true,
+            owners: None,
+            last_author: None,
+            quick_fix_id: None, quick_fix_edits: None,
         }]))
         .unwrap();
     let stats = writer.shutdown().unwrap();
@@ -773,7 +779,7 @@ fn batch_mixed_all_new_variants() {
         CouplingMetricInsertRow { module: "m".into(), ce: 1, ca: 1, instability: 0.5, abstractness: 0.5, distance: 0.0, zone: "ms".into() },
     ])).unwrap();
     writer.send(BatchCommand::InsertViolations(vec![
-        ViolationInsertRow { id: "v1".into(), file: "f".into(), line: 1, column_num: None, end_line: None, end_column: None, severity: "warning".into(), pattern_id: "p".into(), rule_id: "r".into(), message: "m".into(), quick_fix_strategy: None, quick_fix_description: None, cwe_id: None, owasp_category: None, suppressed: false, is_new: false },
+        ViolationInsertRow { id: "v1".into(), file: "f".into(), line: 1, column_num: None, end_line: None, end_column: None, severity: "warning".into(), pattern_id: "p".into(), rule_id: "r".into(), message: "m".into(), quick_fix_strategy: None, quick_fix_description: None, cwe_id: None, owasp_category: None, suppressed: false, is_new: false, owners: None, last_author: None, quick_fix_id: None, quick_fix_edits: None },
     ])).unwrap();
     writer.send(BatchCommand::InsertDegradationAlerts(vec![
         DegradationAlertInsertRow { alert_type: "t".into(), severity: "info".into(), message: "m".into(), current_value: 1.0, previous_value: 0.5, delta: 0.5 },
@@ -27,6 +27,8 @@ fn sql_injection_in_violation_id() {
         rule_id: "r".into(), message: "m".into(),
         quick_fix_strategy: None, quick_fix_description: None,
         cwe_id: None, owasp_category: None, suppressed: false, is_new: false,
+        owners: None, last_author: None,
+        quick_fix_id: None, quick_fix_edits: None,
     }).unwrap();
 
     // Table still exists and violation was inserted
@@ -46,6 +48,8 @@ fn sql_injection_in_file_path() {
         rule_id: "r".into(), message: "m".into(),
         quick_fix_strategy: None, quick_fix_description: None,
         cwe_id: None, owasp_category: None, suppressed: false, is_new: false,
+        owners: None, last_author: None,
+        quick_fix_id: None, quick_fix_edits: None,
     }).unwrap();
 
     let results = enforcement::query_violations_by_file(&conn, &malicious_file).unwrap();
@@ -93,6 +97,8 @@ fn unicode_in_violation_message() {
         rule_id: "r".into(), message: unicode_msg.clone(),
         quick_fix_strategy: None, quick_fix_description: None,
         cwe_id: None, owasp_category: None, suppressed: false, is_new: false,
+        owners: None, last_author: None,
+        quick_fix_id: None, quick_fix_edits: None,
     }).unwrap();
 
     let violations = enforcement::query_all_violations(&conn).unwrap();
@@ -146,6 +152,8 @@ fn empty_string_violation_fields() {
         quick_fix_strategy: Some("".into()),
         quick_fix_description: Some("".into()),
         cwe_id: None, owasp_category: None, suppressed: false, is_new: false,
+        owners: None, last_author: None,
+        quick_fix_id: None, quick_fix_edits: None,
     }).unwrap();
 
     let violations = enforcement::query_all_violations(&conn).unwrap();
@@ -181,6 +189,8 @@ fn huge_violation_message() {
         rule_id: "r".into(), message: huge_msg.clone(),
         quick_fix_strategy: None, quick_fix_description: None,
         cwe_id: None, owasp_category: None, suppressed: false, is_new: false,
+        owners: None, last_author: None,
+        quick_fix_id: None, quick_fix_edits: None,
     }).unwrap();
 
     let violations = enforcement::query_all_violations(&conn).unwrap();
@@ -217,6 +227,8 @@ fn max_integer_values() {
         quick_fix_strategy: None, quick_fix_description: None,
         cwe_id: Some(u32::MAX), owasp_category: None,
         suppressed: false, is_new: false,
+        owners: None, last_author: None,
+        quick_fix_id: None, quick_fix_edits: None,
     }).unwrap();
 
     let violations = enforcement::query_all_violations(&conn).unwrap();
@@ -272,6 +284,8 @@ fn special_characters_in_file_paths() {
             rule_id: "r".into(), message: "m".into(),
             quick_fix_strategy: None, quick_fix_description: None,
             cwe_id: None, owasp_category: None, suppressed: false, is_new: false,
+            owners: None, last_author: None,
+            quick_fix_id: None, quick_fix_edits: None,
         }).unwrap();
     }
 
@@ -90,6 +90,9 @@ fn violations_survive_restart() {
             owasp_category: None,
             suppressed: false,
             is_new: true,
+            owners: None,
+            last_author: None,
+            quick_fix_id: None, quick_fix_edits: None,
         }).unwrap();
     }
 
@@ -256,6 +259,8 @@ fn integrity_check_passes_after_heavy_writes() {
             quick_fix_strategy: None, quick_fix_description: None,
             cwe_id: None, owasp_category: None,
             suppressed: false, is_new: false,
+            owners: None, last_author: None,
+            quick_fix_id: None, quick_fix_edits: None,
         }).unwrap();
     }
 
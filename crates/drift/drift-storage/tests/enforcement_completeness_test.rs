@@ -1,6 +1,6 @@
 //! Tests for the untested enforcement query functions:
 //! audit_snapshots, health_trends, feedback_by_pattern, feedback_adjustments,
-//! policy_results, degradation_alerts_by_type, violations_by_file, get_violation_pattern_id.
+//! policy_results, latest policy verdicts, degradation_alerts_by_type, violations_by_file, get_violation_pattern_id.
 
 use drift_storage::migrations::run_migrations;
 use drift_storage::queries::enforcement::*;
@@ -98,6 +98,8 @@ fn feedback_by_pattern_roundtrip() {
         rule_id: "r1".into(), message: "m".into(),
         quick_fix_strategy: None, quick_fix_description: None,
         cwe_id: None, owasp_category: None, suppressed: false, is_new: false,
+        owners: None, last_author: None,
+        quick_fix_id: None, quick_fix_edits: None,
     }).unwrap();
 
     insert_feedback(&conn, &FeedbackRow {
@@ -127,6 +129,8 @@ fn feedback_adjustments_bayesian_deltas() {
         rule_id: "r".into(), message: "m".into(),
         quick_fix_strategy: None, quick_fix_description: None,
         cwe_id: None, owasp_category: None, suppressed: false, is_new: false,
+        owners: None, last_author: None,
+        quick_fix_id: None, quick_fix_edits: None,
     }).unwrap();
 
     // fix → (1.0, 0.0)
@@ -178,6 +182,50 @@ fn policy_result_insert_and_query() {
     assert_eq!(results[0].gates_failed, 2);
 }
 
+#[test]
+fn latest_policy_results_follow_gate_runs() {
+    let conn = setup_db();
+    let policy = |name: &str, passed: bool| PolicyResultRow {
+        id: 0, policy_name: name.into(), aggregation_mode: "all_must_pass".into(),
+        overall_passed: passed, overall_score: 90.0,
+        gate_count: 6, gates_passed: 6, gates_failed: 0,
+        details: None, run_at: 0,
+    };
+    let gate = GateResultRow {
+        gate_id: "pattern-compliance".into(), status: "passed".into(), passed: true, score: 90.0,
+        summary: "ok".into(), violation_count: 0, warning_count: 0, execution_time_ms: 1,
+        details: None, error: None, run_at: 0,
+    };
+    let stamp = |table: &str, run_at: i64| {
+        conn.execute(&format!("UPDATE {table} SET run_at = ?1 WHERE run_at > ?1"), [run_at]).unwrap();
+    };
+
+    // First run: two scopes, one failing.
+    insert_gate_result(&conn, &gate).unwrap();
+    insert_policy_result(&conn, &policy("standard", true)).unwrap();
+    insert_policy_result(&conn, &policy("payments", false)).unwrap();
+    stamp("gate_results", 100);
+    stamp("policy_results", 100);
+    let latest = query_latest_policy_results(&conn).unwrap();
+    assert_eq!(latest.len(), 2);
+    assert_eq!(latest[1].policy_name, "payments");
+    assert!(!latest[1].overall_passed);
+
+    // Second run with a single scope replaces the first.
+    insert_gate_result(&conn, &gate).unwrap();
+    insert_policy_result(&conn, &policy("standard", true)).unwrap();
+    stamp("gate_results", 200);
+    stamp("policy_results", 200);
+    let latest = query_latest_policy_results(&conn).unwrap();
+    assert_eq!(latest.len(), 1);
+    assert!(latest[0].overall_passed);
+
+    // A later run without policies leaves no verdicts behind.
+    insert_gate_result(&conn, &gate).unwrap();
+    stamp("gate_results", 300);
+    assert!(query_latest_policy_results(&conn).unwrap().is_empty());
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // DEGRADATION ALERTS BY TYPE
 // ═══════════════════════════════════════════════════════════════════════════
@@ -215,6 +263,8 @@ fn violations_by_file() {
         rule_id: "r".into(), message: "m".into(),
         quick_fix_strategy: None, quick_fix_description: None,
         cwe_id: Some(95), owasp_category: None, suppressed: false, is_new: true,
+        owners: None, last_author: None,
+        quick_fix_id: None, quick_fix_edits: None,
     }).unwrap();
     insert_violation(&conn, &ViolationRow {
         id: "vf-2".into(), file: "src/db.ts".into(), line: 5,
@@ -223,6 +273,8 @@ fn violations_by_file() {
         rule_id: "r".into(), message: "m".into(),
         quick_fix_strategy: None, quick_fix_description: None,
         cwe_id: Some(89), owasp_category: None, suppressed: false, is_new: false,
+        owners: None, last_author: None,
+        quick_fix_id: None, quick_fix_edits: None,
     }).unwrap();
 
     let auth = query_violations_by_file(&conn, "src/auth.ts").unwrap();
@@ -231,6 +283,50 @@ fn violations_by_file() {
     assert!(auth[0].is_new);
 }
 
+#[test]
+fn violation_owners_roundtrip() {
+    let conn = setup_db();
+    insert_violation(&conn, &ViolationRow {
+        id: "vo-1".into(), file: "src/pay.ts".into(), line: 3,
+        column: None, end_line: None, end_column: None,
+        severity: "error".into(), pattern_id: "p".into(),
+        rule_id: "r".into(), message: "m".into(),
+        quick_fix_strategy: None, quick_fix_description: None,
+        cwe_id: None, owasp_category: None, suppressed: false, is_new: false,
+        owners: Some(r#"["@acme/payments","@alice"]"#.into()), last_author: Some("Alice".into()),
+        quick_fix_id: None, quick_fix_edits: None,
+    }).unwrap();
+
+    let all = query_all_violations(&conn).unwrap();
+    assert_eq!(all[0].owners.as_deref(), Some(r#"["@acme/payments","@alice"]"#));
+    assert_eq!(all[0].last_author.as_deref(), Some("Alice"));
+    let by_file = query_violations_by_file(&conn, "src/pay.ts").unwrap();
+    assert_eq!(by_file[0].last_author.as_deref(), Some("Alice"));
+}
+
+#[test]
+fn violation_quick_fix_roundtrip() {
+    let conn = setup_db();
+    let edits = r#"[{"file":"src/db.ts","start_byte":10,"end_byte":20,"replacement":"?"}]"#;
+    insert_violation(&conn, &ViolationRow {
+        id: "vq-1".into(), file: "src/db.ts".into(), line: 4,
+        column: None, end_line: None, end_column: None,
+        severity: "error".into(), pattern_id: "p".into(),
+        rule_id: "r".into(), message: "m".into(),
+        quick_fix_strategy: Some("use_parameterized_query".into()),
+        quick_fix_description: Some("Use a parameterized query".into()),
+        cwe_id: Some(89), owasp_category: None, suppressed: false, is_new: false,
+        owners: None, last_author: None,
+        quick_fix_id: Some("fix-1".into()), quick_fix_edits: Some(edits.into()),
+    }).unwrap();
+
+    let all = query_all_violations(&conn).unwrap();
+    assert_eq!(all[0].quick_fix_id.as_deref(), Some("fix-1"));
+    assert_eq!(all[0].quick_fix_edits.as_deref(), Some(edits));
+    let by_file = query_violations_by_file(&conn, "src/db.ts").unwrap();
+    assert_eq!(by_file[0].quick_fix_edits.as_deref(), Some(edits));
+}
+
 #[test]
 fn get_violation_pattern_id_found() {
     let conn = setup_db();
@@ -241,6 +337,8 @@ fn get_violation_pattern_id_found() {
         rule_id: "r".into(), message: "m".into(),
         quick_fix_strategy: None, quick_fix_description: None,
         cwe_id: None, owasp_category: None, suppressed: false, is_new: false,
+        owners: None, last_author: None,
+        quick_fix_id: None, quick_fix_edits: None,
     }).unwrap();
 
     let pid = get_violation_pattern_id(&conn, "vp-1").unwrap();
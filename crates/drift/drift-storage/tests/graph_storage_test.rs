@@ -73,6 +73,34 @@ fn test_taint_flow_persistence() {
     assert_eq!(cwe_flows.len(), 1);
 }
 
+#[test]
+fn test_taint_summary_persistence() {
+    let conn = setup_db();
+
+    let row = TaintSummaryRow {
+        function_id: "handler.ts::getUser".to_string(),
+        content_hash: 42u64.to_le_bytes().to_vec(),
+        summary: r#"{"function_id":"handler.ts::getUser"}"#.to_string(),
+    };
+    let other = TaintSummaryRow {
+        function_id: "db.ts::query".to_string(),
+        ..row.clone()
+    };
+    replace_taint_summaries(&conn, &[row.clone(), other], &[]).unwrap();
+    assert_eq!(count_taint_summaries(&conn).unwrap(), 2);
+
+    // Upsert replaces, removed ids are deleted
+    let updated = TaintSummaryRow {
+        content_hash: 7u64.to_le_bytes().to_vec(),
+        ..row
+    };
+    replace_taint_summaries(&conn, &[updated], &["db.ts::query".to_string()]).unwrap();
+    let rows = get_taint_summaries(&conn).unwrap();
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].function_id, "handler.ts::getUser");
+    assert_eq!(rows[0].content_hash, 7u64.to_le_bytes().to_vec());
+}
+
 #[test]
 fn test_error_gap_persistence() {
     let conn = setup_db();
@@ -161,7 +189,7 @@ fn test_test_quality_persistence() {
     assert!(result.smells.as_ref().unwrap().contains("eager_test"));
 }
 
-// T4-INT-02: All 6 tables exist and have correct schema
+// T4-INT-02: All 7 tables exist and have correct schema
 #[test]
 fn test_all_tables_exist() {
     let conn = setup_db();
@@ -180,4 +208,5 @@ fn test_all_tables_exist() {
     assert!(tables.contains(&"impact_scores".to_string()), "Missing impact_scores table");
     assert!(tables.contains(&"test_coverage".to_string()), "Missing test_coverage table");
     assert!(tables.contains(&"test_quality".to_string()), "Missing test_quality table");
+    assert!(tables.contains(&"taint_summaries".to_string()), "Missing taint_summaries table");
 }
@@ -46,6 +46,8 @@ fn status_view_with_data() {
         rule_id: "r".into(), message: "m".into(),
         quick_fix_strategy: None, quick_fix_description: None,
         cwe_id: None, owasp_category: None, suppressed: false, is_new: false,
+        owners: None, last_author: None,
+        quick_fix_id: None, quick_fix_edits: None,
     }).unwrap();
     // Insert suppressed violation (should NOT count)
     insert_violation(&conn, &ViolationRow {
@@ -55,6 +57,8 @@ fn status_view_with_data() {
         rule_id: "r".into(), message: "m".into(),
         quick_fix_strategy: None, quick_fix_description: None,
         cwe_id: None, owasp_category: None, suppressed: true, is_new: false,
+        owners: None, last_author: None,
+        quick_fix_id: None, quick_fix_edits: None,
     }).unwrap();
 
     // Insert gate results
@@ -102,6 +106,8 @@ fn security_view_counts_by_severity() {
         rule_id: "r".into(), message: "m".into(),
         quick_fix_strategy: None, quick_fix_description: None,
         cwe_id: Some(89), owasp_category: None, suppressed: false, is_new: false,
+        owners: None, last_author: None,
+        quick_fix_id: None, quick_fix_edits: None,
     }).unwrap();
 
     // High: warning + cwe_id + not suppressed
@@ -112,6 +118,8 @@ fn security_view_counts_by_severity() {
         rule_id: "r".into(), message: "m".into(),
         quick_fix_strategy: None, quick_fix_description: None,
         cwe_id: Some(79), owasp_category: None, suppressed: false, is_new: false,
+        owners: None, last_author: None,
+        quick_fix_id: None, quick_fix_edits: None,
     }).unwrap();
 
     // Suppressed security (should NOT count)
@@ -122,6 +130,8 @@ fn security_view_counts_by_severity() {
         rule_id: "r".into(), message: "m".into(),
         quick_fix_strategy: None, quick_fix_description: None,
         cwe_id: Some(95), owasp_category: None, suppressed: true, is_new: false,
+        owners: None, last_author: None,
+        quick_fix_id: None, quick_fix_edits: None,
     }).unwrap();
 
     // Non-security (no cwe_id, should NOT count)
@@ -132,6 +142,8 @@ fn security_view_counts_by_severity() {
         rule_id: "r".into(), message: "m".into(),
         quick_fix_strategy: None, quick_fix_description: None,
         cwe_id: None, owasp_category: None, suppressed: false, is_new: false,
+        owners: None, last_author: None,
+        quick_fix_id: None, quick_fix_edits: None,
     }).unwrap();
 
     let s = security::refresh_security(&conn).unwrap();
@@ -13,9 +13,9 @@ fn t1_str_03_migration_v001_schema() {
     apply_pragmas(&conn).unwrap();
     migrations::run_migrations(&conn).unwrap();
 
-    // Verify user_version matches latest migration (v001 through v009)
+    // Verify user_version matches latest migration (v001 through v016)
     let version = migrations::current_version(&conn).unwrap();
-    assert_eq!(version, 9, "schema version should match latest migration");
+    assert_eq!(version, 16, "schema version should match latest migration");
 
     // Verify file_metadata table exists with correct columns
     let columns = get_table_columns(&conn, "file_metadata");
@@ -44,6 +44,9 @@ fn t1_str_03_migration_v001_schema() {
     assert!(columns.contains(&"is_exported".to_string()));
     assert!(columns.contains(&"body_hash".to_string()));
     assert!(columns.contains(&"signature_hash".to_string()));
+    assert!(columns.contains(&"cyclomatic".to_string()));
+    assert!(columns.contains(&"cognitive".to_string()));
+    assert!(columns.contains(&"max_nesting".to_string()));
 
     // Verify scan_history table
     let columns = get_table_columns(&conn, "scan_history");
@@ -122,7 +125,7 @@ fn t1_str_08_migration_idempotent() {
     migrations::run_migrations(&conn).unwrap();
 
     let version = migrations::current_version(&conn).unwrap();
-    assert_eq!(version, 9, "version should still match latest after double migration");
+    assert_eq!(version, 16, "version should still match latest after double migration");
 }
 
 // ---- Helpers ----
@@ -56,6 +56,9 @@ fn stress_enforcement_full_pipeline() {
                 owasp_category: if i % 3 == 0 { Some("A01:2021".to_string()) } else { None },
                 suppressed: i % 7 == 0,
                 is_new: false,
+                owners: None,
+                last_author: None,
+                quick_fix_id: None, quick_fix_edits: None,
             })?;
         }
         Ok(())
@@ -782,6 +785,9 @@ fn stress_concurrent_reads_and_writes() {
                 owasp_category: None,
                 suppressed: false,
                 is_new: false,
+                owners: None,
+                last_author: None,
+                quick_fix_id: None, quick_fix_edits: None,
             })?;
         }
         Ok(())
@@ -832,6 +838,9 @@ fn stress_concurrent_reads_and_writes() {
                     owasp_category: None,
                     suppressed: false,
                     is_new: false,
+                    owners: None,
+                    last_author: None,
+                    quick_fix_id: None, quick_fix_edits: None,
                 })
             }).unwrap();
         }
@@ -875,6 +884,9 @@ fn stress_edge_cases() {
             owasp_category: Some("A03:2021-注入".to_string()),
             suppressed: false,
             is_new: false,
+            owners: None,
+            last_author: None,
+            quick_fix_id: None, quick_fix_edits: None,
         })
     }).unwrap();
 
@@ -926,6 +938,9 @@ fn stress_edge_cases() {
             owasp_category: None,
             suppressed: false,
             is_new: false,
+            owners: None,
+            last_author: None,
+            quick_fix_id: None, quick_fix_edits: None,
         })
     }).unwrap();
 
@@ -954,6 +969,9 @@ fn stress_edge_cases() {
             owasp_category: None,
             suppressed: false,
             is_new: false,
+            owners: None,
+            last_author: None,
+            quick_fix_id: None, quick_fix_edits: None,
         })
     }).unwrap();
 
@@ -1072,6 +1090,9 @@ fn stress_wal_checkpoint() {
                 owasp_category: None,
                 suppressed: false,
                 is_new: false,
+                owners: None,
+                last_author: None,
+                quick_fix_id: None, quick_fix_edits: None,
             })?;
         }
         Ok(())
@@ -1103,6 +1124,9 @@ fn stress_wal_checkpoint() {
             owasp_category: None,
             suppressed: false,
             is_new: false,
+            owners: None,
+            last_author: None,
+            quick_fix_id: None, quick_fix_edits: None,
         })
     }).unwrap();
 
@@ -208,6 +208,9 @@ fn stress_batch_writer_functions_500_rows() {
             is_async: i % 3 == 0,
             body_hash: vec![i as u8; 8],
             signature_hash: vec![(i + 1) as u8; 8],
+            cyclomatic: 0,
+            cognitive: 0,
+            max_nesting: 0,
         })
         .collect();
 
@@ -420,6 +423,9 @@ fn stress_batch_writer_mixed_commands_rapid() {
             is_async: false,
             body_hash: vec![0u8; 8],
             signature_hash: vec![0u8; 8],
+            cyclomatic: 0,
+            cognitive: 0,
+            max_nesting: 0,
         }])).unwrap();
     }
 
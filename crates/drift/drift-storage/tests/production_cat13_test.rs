@@ -402,6 +402,7 @@ fn t13_04_tier_assignment_coverage() {
         "dna_mutations",
         "coupling_cycles",
         "decomposition_decisions",
+        "hotspot_history",
     ]
     .into_iter()
     .collect();
@@ -434,6 +435,12 @@ fn t13_04_tier_assignment_coverage() {
         "contracts",
         "dna_genes",
         "pattern_status",
+        "taint_summaries",
+        "file_churn",
+        "function_churn",
+        "change_coupling",
+        "historical_secrets",
+        "secret_history_state",
     ]
     .into_iter()
     .collect();
@@ -476,8 +483,8 @@ fn t13_04_tier_assignment_coverage() {
     // ── Verify expected table count ──
     assert_eq!(
         all_tables.len(),
-        46,
-        "Expected 46 tables after all migrations, got {}. Tables: {:?}",
+        53,
+        "Expected 53 tables after all migrations, got {}. Tables: {:?}",
         all_tables.len(),
         all_tables
     );
@@ -76,6 +76,9 @@ fn t7_01_all_33_data_commands_round_trip() {
             is_async: false,
             body_hash: vec![10, 20],
             signature_hash: vec![30, 40],
+            cyclomatic: 0,
+            cognitive: 0,
+            max_nesting: 0,
         }]))
         .unwrap();
 
@@ -319,6 +322,9 @@ fn t7_01_all_33_data_commands_round_trip() {
             owasp_category: Some("A03:2021".into()),
             suppressed: false,
             is_new: true,
+            owners: None,
+            last_author: None,
+            quick_fix_id: None, quick_fix_edits: None,
         }]))
         .unwrap();
 
@@ -689,6 +695,9 @@ fn t7_03_mixed_batch_transaction() {
                 is_async: false,
                 body_hash: vec![i as u8],
                 signature_hash: vec![i as u8],
+                cyclomatic: 0,
+                cognitive: 0,
+                max_nesting: 0,
             }]))
             .unwrap();
     }
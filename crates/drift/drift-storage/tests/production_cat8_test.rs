@@ -46,7 +46,7 @@ fn t8_01_fresh_db_all_45_tables_created() {
 
     let tables = get_table_names(&conn);
 
-    // All 46 expected tables from v001–v009 (+ v006 PART2)
+    // All 53 expected tables from v001–v015 (+ v006 PART2)
     let expected_tables = [
         // v001
         "file_metadata",
@@ -103,12 +103,23 @@ fn t8_01_fresh_db_all_45_tables_created() {
         "migration_corrections",
         // v009
         "pattern_status",
+        // v010
+        "taint_summaries",
+        // v012
+        "file_churn",
+        "function_churn",
+        "hotspot_history",
+        // v013
+        "change_coupling",
+        // v015
+        "historical_secrets",
+        "secret_history_state",
     ];
 
     assert_eq!(
         expected_tables.len(),
-        46,
-        "sanity: expected_tables array must have 46 entries"
+        53,
+        "sanity: expected_tables array must have 53 entries"
     );
 
     for table_name in &expected_tables {
@@ -121,26 +132,30 @@ fn t8_01_fresh_db_all_45_tables_created() {
     // Verify total table count matches
     assert_eq!(
         tables.len(),
-        46,
-        "expected 46 tables, got {}: {:?}",
+        53,
+        "expected 53 tables, got {}: {:?}",
         tables.len(),
         tables
     );
 
     // Verify total column count across all tables matches DD-15 audit
-    // v001-v007: 398 columns + v008 scan_root: 1 column + v009 pattern_status: 7 columns = 406
+    // v001-v007: 398 columns + v008 scan_root: 1 column + v009 pattern_status: 7 columns
+    // + v010 taint_summaries: 4 columns + v011 function complexity: 3 columns
+    // + v012 churn and hotspot history: 26 columns + v013 change_coupling: 13 columns
+    // + v014 violation owners: 2 columns + v015 secret history: 20 columns = 474
+    // + v016 quick-fix edits: 2 columns = 476
     let total_columns: usize = expected_tables
         .iter()
         .map(|t| get_column_count(&conn, t))
         .sum();
     assert_eq!(
-        total_columns, 406,
-        "total column count across 46 tables must be 406 (DD-15 audit + v008 + v009)"
+        total_columns, 476,
+        "total column count across 53 tables must be 476 (DD-15 audit + v008 + v009 + v010 + v011 + v012 + v013 + v014 + v015 + v016)"
     );
 
     // Verify schema version
     let version = migrations::current_version(&conn).unwrap();
-    assert_eq!(version, 9);
+    assert_eq!(version, 16);
 }
 
 // ---- T8-02: Idempotent Re-Open ----
@@ -160,10 +175,10 @@ fn t8_02_idempotent_reopen() {
         let db = DatabaseManager::open(&db_path).unwrap();
         db.with_writer(|conn| {
             let version = migrations::current_version(conn).unwrap();
-            assert_eq!(version, 9, "version must remain 9 after re-open");
+            assert_eq!(version, 16, "version must remain 16 after re-open");
 
             let tables = get_table_names(conn);
-            assert_eq!(tables.len(), 46, "all 46 tables must still exist after re-open");
+            assert_eq!(tables.len(), 53, "all 53 tables must still exist after re-open");
             Ok(())
         })
         .unwrap();
@@ -174,7 +189,7 @@ fn t8_02_idempotent_reopen() {
         let db = DatabaseManager::open(&db_path).unwrap();
         db.with_writer(|conn| {
             let version = migrations::current_version(conn).unwrap();
-            assert_eq!(version, 9);
+            assert_eq!(version, 16);
             Ok(())
         })
         .unwrap();
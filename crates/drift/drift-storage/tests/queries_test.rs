@@ -174,6 +174,54 @@ fn t1_str_queries_functions_crud() {
     assert_eq!(functions::count_functions(&conn).unwrap(), 0);
 }
 
+// ---- T1-STR-05 (queries): Function complexity ----
+
+#[test]
+fn t1_str_queries_measured_functions() {
+    let conn = test_connection();
+
+    conn.execute_batch(
+        "INSERT INTO functions (file, name, language, line, end_line, cyclomatic, cognitive, max_nesting)
+         VALUES ('a.ts', 'simple', 'TypeScript', 1, 3, 1, 0, 0),
+                ('a.ts', 'tangled', 'TypeScript', 5, 40, 9, 14, 3),
+                ('b.ts', 'unmeasured', 'TypeScript', 1, 2, 0, 0, 0);",
+    )
+    .unwrap();
+
+    // Unmeasured rows are skipped; most cognitively complex first.
+    let measured = functions::get_measured_functions(&conn).unwrap();
+    let names: Vec<&str> = measured.iter().map(|f| f.name.as_str()).collect();
+    assert_eq!(names, vec!["tangled", "simple"]);
+    assert_eq!((measured[0].cyclomatic, measured[0].cognitive, measured[0].max_nesting), (9, 14, 3));
+}
+
+// ---- T1-STR-05 (queries): Enclosing function lookup ----
+
+#[test]
+fn t1_str_queries_enclosing_function() {
+    let conn = test_connection();
+
+    conn.execute_batch(
+        "INSERT INTO functions (file, name, language, line, end_line)
+         VALUES ('a.ts', 'outer', 'TypeScript', 2, 30),
+                ('a.ts', 'inner', 'TypeScript', 10, 15),
+                ('b.ts', 'other', 'TypeScript', 0, 100);",
+    )
+    .unwrap();
+    let id_of = |name: &str| -> i64 {
+        conn.query_row("SELECT id FROM functions WHERE name = ?1", [name], |r| r.get(0))
+            .unwrap()
+    };
+
+    // Innermost span wins; boundaries are inclusive.
+    assert_eq!(functions::get_enclosing_function_id(&conn, "a.ts", 12).unwrap(), Some(id_of("inner")));
+    assert_eq!(functions::get_enclosing_function_id(&conn, "a.ts", 15).unwrap(), Some(id_of("inner")));
+    assert_eq!(functions::get_enclosing_function_id(&conn, "a.ts", 20).unwrap(), Some(id_of("outer")));
+    // Module-level lines have no enclosing function.
+    assert_eq!(functions::get_enclosing_function_id(&conn, "a.ts", 40).unwrap(), None);
+    assert_eq!(functions::get_enclosing_function_id(&conn, "c.ts", 12).unwrap(), None);
+}
+
 // ---- Helpers ----
 
 /// Simple keyset pagination over file_metadata ordered by path.
@@ -99,6 +99,8 @@ fn short_tier_violations_cleaned() {
         rule_id: "r".into(), message: "old".into(),
         quick_fix_strategy: None, quick_fix_description: None,
         cwe_id: None, owasp_category: None, suppressed: false, is_new: false,
+        owners: None, last_author: None,
+        quick_fix_id: None, quick_fix_edits: None,
     }).unwrap();
     // Backdate it
     conn.execute(
@@ -114,6 +116,8 @@ fn short_tier_violations_cleaned() {
         rule_id: "r".into(), message: "new".into(),
         quick_fix_strategy: None, quick_fix_description: None,
         cwe_id: None, owasp_category: None, suppressed: false, is_new: false,
+        owners: None, last_author: None,
+        quick_fix_id: None, quick_fix_edits: None,
     }).unwrap();
 
     let report = apply_retention(&conn, &RetentionPolicy { short_days: 30, medium_days: 90, long_days: 365 }).unwrap();
@@ -192,6 +196,8 @@ fn retention_atomicity_all_or_nothing() {
         rule_id: "r".into(), message: "m".into(),
         quick_fix_strategy: None, quick_fix_description: None,
         cwe_id: None, owasp_category: None, suppressed: false, is_new: false,
+        owners: None, last_author: None,
+        quick_fix_id: None, quick_fix_edits: None,
     }).unwrap();
     conn.execute("UPDATE violations SET created_at = ?1 WHERE id = 'v1'", params![now - 60 * 86400]).unwrap();
 
@@ -223,6 +229,8 @@ fn retention_report_tracks_per_table() {
             rule_id: "r".into(), message: "m".into(),
             quick_fix_strategy: None, quick_fix_description: None,
             cwe_id: None, owasp_category: None, suppressed: false, is_new: false,
+            owners: None, last_author: None,
+            quick_fix_id: None, quick_fix_edits: None,
         }).unwrap();
     }
     conn.execute("UPDATE violations SET created_at = ?1", params![now - 60 * 86400]).unwrap();
@@ -1,4 +1,4 @@
-//! Roundtrip tests for all 13 entity types in structural.rs (37 pub fns).
+//! Roundtrip tests for all 18 entity types in structural.rs (48 pub fns).
 //! Every write→read path is verified to catch column-mapping bugs.
 
 use drift_storage::migrations::run_migrations;
@@ -566,3 +566,201 @@ fn coupling_cycle_roundtrip() {
     assert_eq!(cycles.len(), 2);
     assert!(cycles[0].members.contains('x') || cycles[1].members.contains('x'));
 }
+
+// ═══════════════════════════════════════════════════════════════════════════
+// CHANGE COUPLING (2 fns)
+// ═══════════════════════════════════════════════════════════════════════════
+
+fn change_coupling(module_b: &str, co_changes: u32, import_edge: bool) -> ChangeCouplingRow {
+    ChangeCouplingRow {
+        module_a: "api".to_string(),
+        module_b: module_b.to_string(),
+        co_changes,
+        confidence: 0.8,
+        file_a: "api/routes.ts".to_string(),
+        file_b: format!("{module_b}/index.ts"),
+        file_pairs: 2,
+        import_edge,
+        in_cycle: import_edge,
+        package_a: Some("server".to_string()),
+        package_b: (!import_edge).then(|| "web".to_string()),
+        hidden: !import_edge,
+    }
+}
+
+#[test]
+fn change_coupling_roundtrip() {
+    let conn = setup_db();
+    replace_change_coupling(&conn, &[change_coupling("db", 4, true), change_coupling("ui", 7, false)]).unwrap();
+
+    let all = get_change_coupling(&conn, false).unwrap();
+    assert_eq!(all.len(), 2);
+    assert_eq!(all[0].module_b, "ui");
+    assert_eq!(all[0].co_changes, 7);
+    assert!((all[0].confidence - 0.8).abs() < 0.001);
+    assert_eq!(all[0].file_b, "ui/index.ts");
+    assert_eq!(all[0].file_pairs, 2);
+    assert!(!all[0].import_edge && !all[0].in_cycle);
+    assert!(all[1].import_edge && all[1].in_cycle);
+
+    let hidden = get_change_coupling(&conn, true).unwrap();
+    assert_eq!(hidden.len(), 1);
+    assert_eq!(hidden[0].module_b, "ui");
+    assert_eq!((hidden[0].package_a.as_deref(), hidden[0].package_b.as_deref()), (Some("server"), Some("web")));
+
+    // A later analysis replaces everything.
+    replace_change_coupling(&conn, &[change_coupling("db", 5, true)]).unwrap();
+    let all = get_change_coupling(&conn, false).unwrap();
+    assert_eq!(all.len(), 1);
+    assert_eq!(all[0].co_changes, 5);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SECRET HISTORY (3 fns)
+// ═══════════════════════════════════════════════════════════════════════════
+
+fn historical_secret(fingerprint: &str, timestamp: i64, present_at_head: bool) -> HistoricalSecretRow {
+    HistoricalSecretRow {
+        fingerprint: fingerprint.to_string(),
+        pattern_name: "github_pat".to_string(),
+        redacted_value: "ghp_****".to_string(),
+        file: "src/config.ts".to_string(),
+        line: 3,
+        severity: "critical".to_string(),
+        entropy: 4.5,
+        confidence: 0.95,
+        cwe_ids: "[798]".to_string(),
+        first_commit: "abc123".to_string(),
+        author: "Alice <alice@example.com>".to_string(),
+        timestamp,
+        occurrences: 2,
+        files: r#"["src/config.ts","deploy.sh"]"#.to_string(),
+        present_at_head,
+    }
+}
+
+#[test]
+fn secret_history_roundtrip() {
+    let conn = setup_db();
+    assert!(get_secret_history_state(&conn).unwrap().is_none());
+
+    let state = SecretHistoryStateRow {
+        last_commit: Some("def456".to_string()),
+        scanned_tips: r#"["def456","0a1b2c"]"#.to_string(),
+        commits_scanned: 12,
+    };
+    replace_secret_history(&conn, &state, &[historical_secret("f1", 100, false), historical_secret("f2", 200, true)]).unwrap();
+
+    let loaded = get_secret_history_state(&conn).unwrap().unwrap();
+    assert_eq!(loaded.last_commit.as_deref(), Some("def456"));
+    assert_eq!(loaded.scanned_tips, r#"["def456","0a1b2c"]"#);
+    assert_eq!(loaded.commits_scanned, 12);
+
+    let secrets = get_historical_secrets(&conn).unwrap();
+    assert_eq!(secrets.len(), 2);
+    // Still present at HEAD first.
+    assert_eq!(secrets[0].fingerprint, "f2");
+    assert!(secrets[0].present_at_head);
+    assert_eq!(secrets[1].author, "Alice <alice@example.com>");
+    assert_eq!(secrets[1].files, r#"["src/config.ts","deploy.sh"]"#);
+    assert_eq!((secrets[1].line, secrets[1].occurrences, secrets[1].timestamp), (3, 2, 100));
+    assert!((secrets[1].entropy - 4.5).abs() < 0.001);
+
+    // A later scan replaces both.
+    let state = SecretHistoryStateRow { commits_scanned: 13, ..state };
+    replace_secret_history(&conn, &state, &[historical_secret("f1", 100, true)]).unwrap();
+    assert_eq!(get_secret_history_state(&conn).unwrap().unwrap().commits_scanned, 13);
+    assert_eq!(get_historical_secrets(&conn).unwrap().len(), 1);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// CHURN (3 fns)
+// ═══════════════════════════════════════════════════════════════════════════
+
+fn function_churn(function_id: &str, change_frequency: f64) -> FunctionChurnRow {
+    FunctionChurnRow {
+        function_id: function_id.to_string(),
+        file: "src/a.ts".to_string(),
+        commits: 3,
+        lines_changed: 12,
+        recency_weighted: 2.5,
+        change_frequency,
+    }
+}
+
+#[test]
+fn churn_roundtrip() {
+    let conn = setup_db();
+    let file = FileChurnRow {
+        file: "src/a.ts".to_string(),
+        commits: 5,
+        lines_added: 40,
+        lines_removed: 9,
+        authors: 2,
+        last_changed: 1_700_000_000,
+        recency_weighted: 3.25,
+    };
+    replace_churn(&conn, &[file], &[function_churn("src/a.ts::parse", 1.0)]).unwrap();
+
+    let files = get_file_churn(&conn).unwrap();
+    assert_eq!(files.len(), 1);
+    assert_eq!(files[0].file, "src/a.ts");
+    assert_eq!((files[0].commits, files[0].lines_added, files[0].lines_removed), (5, 40, 9));
+    assert_eq!(files[0].authors, 2);
+    assert_eq!(files[0].last_changed, 1_700_000_000);
+    assert!((files[0].recency_weighted - 3.25).abs() < 0.001);
+
+    let parse = get_function_churn(&conn, "src/a.ts::parse").unwrap().unwrap();
+    assert_eq!(parse.file, "src/a.ts");
+    assert_eq!((parse.commits, parse.lines_changed), (3, 12));
+    assert!((parse.change_frequency - 1.0).abs() < 0.001);
+
+    // A later analysis replaces everything.
+    replace_churn(&conn, &[], &[function_churn("src/a.ts::emit", 0.5)]).unwrap();
+    assert!(get_file_churn(&conn).unwrap().is_empty());
+    assert!(get_function_churn(&conn, "src/a.ts::parse").unwrap().is_none());
+    assert!(get_function_churn(&conn, "src/a.ts::emit").unwrap().is_some());
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// HOTSPOT HISTORY (3 fns)
+// ═══════════════════════════════════════════════════════════════════════════
+
+fn hotspot(file: &str, score: f64, trend: &str) -> HotspotHistoryRow {
+    HotspotHistoryRow {
+        file: file.to_string(),
+        score,
+        churn: 0.8,
+        complexity: 0.5,
+        coupling: 0.25,
+        commits: 7,
+        authors: 3,
+        trend: trend.to_string(),
+        head_commit: Some("abc123".to_string()),
+        recorded_at: 0,
+    }
+}
+
+#[test]
+fn hotspot_history_roundtrip() {
+    let conn = setup_db();
+    insert_hotspot_snapshot(&conn, &[hotspot("src/a.ts", 0.4, "new"), hotspot("src/b.ts", 0.6, "new")]).unwrap();
+    insert_hotspot_snapshot(&conn, &[hotspot("src/a.ts", 0.9, "rising")]).unwrap();
+
+    // Latest snapshot per file, highest score first.
+    let latest = get_latest_hotspots(&conn, 10).unwrap();
+    assert_eq!(latest.len(), 2);
+    assert_eq!(latest[0].file, "src/a.ts");
+    assert!((latest[0].score - 0.9).abs() < 0.001);
+    assert_eq!(latest[0].trend, "rising");
+    assert_eq!((latest[0].commits, latest[0].authors), (7, 3));
+    assert!((latest[0].coupling - 0.25).abs() < 0.001);
+    assert_eq!(latest[0].head_commit.as_deref(), Some("abc123"));
+    assert!(latest[0].recorded_at > 0);
+    assert_eq!(get_latest_hotspots(&conn, 1).unwrap().len(), 1);
+
+    // Trend history, newest first.
+    let trend = get_hotspot_trend(&conn, "src/a.ts", 10).unwrap();
+    let scores: Vec<f64> = trend.iter().map(|r| r.score).collect();
+    assert_eq!(scores, vec![0.9, 0.4]);
+}
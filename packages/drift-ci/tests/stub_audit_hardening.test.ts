@@ -176,6 +176,7 @@ describe('PH6-01: Enforcement pass uses real gate results', () => {
             overallPassed: false,
             totalViolations: 5,
             gates: [],
+            policies: [],
             sarif: null,
           };
         },
@@ -198,6 +199,7 @@ describe('PH6-01: Enforcement pass uses real gate results', () => {
             overallPassed: true,
             totalViolations: 0,
             gates: [],
+            policies: [],
             sarif: null,
           };
         },
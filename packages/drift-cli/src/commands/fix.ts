@@ -1,5 +1,8 @@
 /**
  * drift fix — mark a violation as fixed (positive Bayesian signal).
+ *
+ * With --apply, the violation's stored quick fix is written to the working
+ * tree first; the violation is only marked fixed when the edit applied.
  */
 
 import type { Command } from 'commander';
@@ -12,9 +15,26 @@ export function registerFixCommand(program: Command): void {
     .description('Mark a violation as fixed. Reports confidence adjustment.')
     .option('-f, --format <format>', 'Output format: table, json', 'table')
     .option('-q, --quiet', 'Suppress all output except errors')
-    .action(async (violationId: string, opts: { format: OutputFormat; quiet?: boolean }) => {
+    .option('--apply', 'Apply the violation\'s quick fix to the working tree first')
+    .action(async (violationId: string, opts: { format: OutputFormat; quiet?: boolean; apply?: boolean }) => {
       const napi = loadNapi();
       try {
+        if (opts.apply) {
+          const violation = napi.driftViolations(process.cwd()).find((v) => v.id === violationId);
+          if (!violation?.quickFixId || !violation.quickFixApplicable) {
+            process.stderr.write(`Error: violation ${violationId} has no applicable quick fix\n`);
+            process.exitCode = 1;
+            return;
+          }
+          const application = napi.driftApplyFixes([violation.quickFixId]);
+          if (!opts.quiet) {
+            process.stdout.write(formatOutput(application, opts.format));
+          }
+          if (application.applied.length === 0) {
+            process.exitCode = 1;
+            return;
+          }
+        }
         const result = napi.driftFixViolation(violationId);
         if (!opts.quiet) {
           process.stdout.write(formatOutput(result, opts.format));
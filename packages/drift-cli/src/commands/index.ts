@@ -1,5 +1,5 @@
 /**
- * Command registration — registers all 31 CLI commands.
+ * Command registration — registers all 32 CLI commands.
  */
 
 import type { Command } from 'commander';
@@ -20,6 +20,7 @@ import { registerAnalyzeCommand } from './analyze.js';
 import { registerReportCommand } from './report.js';
 import { registerGcCommand } from './gc.js';
 import { registerSecurityCommand } from './security.js';
+import { registerSecretsCommand } from './secrets.js';
 import { registerContractsCommand } from './contracts.js';
 import { registerCouplingCommand } from './coupling.js';
 import { registerDnaCommand } from './dna.js';
@@ -49,6 +50,7 @@ export function registerAllCommands(program: Command): void {
   registerPatternsCommand(program);
   registerViolationsCommand(program);
   registerSecurityCommand(program);
+  registerSecretsCommand(program);
   registerContractsCommand(program);
   registerCouplingCommand(program);
   registerDnaCommand(program);
@@ -0,0 +1,32 @@
+/**
+ * drift secrets — hardcoded secrets in the tree and anywhere in git history.
+ */
+
+import type { Command } from 'commander';
+import { loadNapi } from '../napi.js';
+import { formatOutput, type OutputFormat } from '../output/index.js';
+
+export function registerSecretsCommand(program: Command): void {
+  program
+    .command('secrets [path]')
+    .description('List hardcoded secrets, including ones committed earlier in git history')
+    .option('-f, --format <format>', 'Output format: table, json, sarif', 'table')
+    .option('--head-only', 'Only report historical secrets still present at HEAD')
+    .option('-q, --quiet', 'Suppress all output except errors')
+    .action(async (path: string | undefined, opts: { format: OutputFormat; headOnly?: boolean; quiet?: boolean }) => {
+      const napi = loadNapi();
+      try {
+        const result = napi.driftConstantsAnalysis(path ?? process.cwd());
+        const historicalSecrets = opts.headOnly
+          ? result.historicalSecrets.filter((s) => s.presentAtHead)
+          : result.historicalSecrets;
+        if (!opts.quiet) {
+          process.stdout.write(formatOutput({ secrets: result.secrets, historicalSecrets }, opts.format));
+        }
+        process.exitCode = result.secrets.length > 0 || historicalSecrets.length > 0 ? 1 : 0;
+      } catch (err) {
+        process.stderr.write(`Error: ${err instanceof Error ? err.message : err}\n`);
+        process.exitCode = 2;
+      }
+    });
+}
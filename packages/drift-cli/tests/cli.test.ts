@@ -27,7 +27,7 @@ describe('CLI', () => {
     expect(program).toBeDefined();
     expect(program.name()).toBe('drift');
 
-    // Verify all 25 commands are registered
+    // Verify all 32 commands are registered
     const commands = program.commands.map((c) => c.name());
     expect(commands).toContain('scan');
     expect(commands).toContain('analyze');
@@ -37,6 +37,7 @@ describe('CLI', () => {
     expect(commands).toContain('patterns');
     expect(commands).toContain('violations');
     expect(commands).toContain('security');
+    expect(commands).toContain('secrets');
     expect(commands).toContain('contracts');
     expect(commands).toContain('coupling');
     expect(commands).toContain('dna');
@@ -56,7 +57,7 @@ describe('CLI', () => {
     expect(commands).toContain('gc');
     expect(commands).toContain('setup');
     expect(commands).toContain('doctor');
-    expect(commands).toHaveLength(31);
+    expect(commands).toHaveLength(32);
   });
 
   // T8-CLI-03: Test all output formats produce valid output
@@ -115,7 +116,7 @@ describe('CLI', () => {
   it('T8-CLI-10: program handles unknown commands', () => {
     const program = createProgram();
     // Commander handles unknown commands with help text
-    expect(program.commands.length).toBe(31);
+    expect(program.commands.length).toBe(32);
   });
 });
 
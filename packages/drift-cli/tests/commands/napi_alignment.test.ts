@@ -1,5 +1,5 @@
 /**
- * Phase D Tests — CLI NAPI Alignment (TH-CLI-01 through TH-CLI-20)
+ * Phase D Tests — CLI NAPI Alignment (TH-CLI-01 through TH-CLI-21)
  *
  * Verifies all CLI commands call the correct NAPI contract methods.
  */
@@ -88,6 +88,21 @@ describe('CLI NAPI Alignment — Commands', () => {
     expect(napi.driftFixViolation).toHaveBeenCalledWith('v-123');
   });
 
+  // TH-CLI-21: drift fix --apply applies the violation's quick fix first
+  it('TH-CLI-21: fix --apply calls driftApplyFixes with the stored fix id', async () => {
+    const { registerFixCommand } = await import('../../src/commands/fix.js');
+    const { Command } = await import('commander');
+    napi.driftViolations.mockReturnValue([
+      { id: 'v-123', quickFixId: 'fix-abc', quickFixApplicable: true },
+    ]);
+    napi.driftApplyFixes.mockReturnValue({ applied: ['fix-abc'], conflicts: [], filesChanged: ['a.ts'] });
+    const program = new Command();
+    registerFixCommand(program);
+    await program.parseAsync(['node', 'drift', 'fix', 'v-123', '--apply', '--quiet']);
+    expect(napi.driftApplyFixes).toHaveBeenCalledWith(['fix-abc']);
+    expect(napi.driftFixViolation).toHaveBeenCalledWith('v-123');
+  });
+
   // TH-CLI-09: drift explain calls driftContext with 3 args
   it('TH-CLI-09: explain calls driftContext with 3 args', () => {
     napi.driftContext('understand_code', 'deep', '{}');
@@ -17,9 +17,9 @@ describe('CLI E2E Pipeline', () => {
   });
 
   // T9-CLI-01: Full pipeline creates a working program with all commands
-  it('T9-CLI-01: createProgram registers all 31 commands', () => {
+  it('T9-CLI-01: createProgram registers all 32 commands', () => {
     const program = createProgram();
-    expect(program.commands.length).toBe(31);
+    expect(program.commands.length).toBe(32);
 
     const names = program.commands.map((c) => c.name());
     // Core pipeline
@@ -33,6 +33,7 @@ describe('CLI E2E Pipeline', () => {
     expect(names).toContain('patterns');
     expect(names).toContain('violations');
     expect(names).toContain('security');
+    expect(names).toContain('secrets');
     expect(names).toContain('contracts');
     expect(names).toContain('coupling');
     expect(names).toContain('dna');
@@ -5,14 +5,14 @@
  * `crates/drift/drift-napi/src/bindings/*.rs`. Function names and parameter
  * types MUST match Rust exactly. When Rust disagrees with TypeScript, Rust wins.
  *
- * 66 methods total, grouped by Rust binding module:
+ * 67 methods total, grouped by Rust binding module:
  * - Lifecycle (4): lifecycle.rs
  * - Scanner (3): scanner.rs
  * - Analysis (4): analysis.rs
  * - Patterns (4): patterns.rs
  * - Graph (5): graph.rs
  * - Structural (9): structural.rs
- * - Enforcement (7): enforcement.rs
+ * - Enforcement (8): enforcement.rs
  * - Feedback (3): feedback.rs
  * - Advanced (4): advanced.rs
  * - Bridge (21): bridge.rs
@@ -55,6 +55,7 @@ import type {
   JsCheckResult,
   JsAuditResult,
   JsViolation,
+  JsFixApplication,
   JsGateResult,
   JsFeedbackInput,
   JsFeedbackResult,
@@ -210,7 +211,7 @@ export interface DriftNapi {
   // Rust: drift_decomposition(root: String)
   driftDecomposition(root: string): JsDecompositionResult;
 
-  // ─── Enforcement (7) — enforcement.rs ────────────────────────────────
+  // ─── Enforcement (8) — enforcement.rs ────────────────────────────────
   // Rust: drift_check(_root: String)
   driftCheck(root: string): JsCheckResult;
 
@@ -223,6 +224,9 @@ export interface DriftNapi {
   // Rust: drift_gates(_root: String) -> Vec<JsGateResult>
   driftGates(root: string): JsGateResult[];
 
+  // Rust: drift_apply_fixes(fix_ids: Vec<String>) -> JsFixApplication
+  driftApplyFixes(fixIds: string[]): JsFixApplication;
+
   // Rust: drift_report(format: String) -> String
   driftReport(format: string): string;
 
@@ -353,7 +357,7 @@ export interface DriftNapi {
 }
 
 /** Total number of methods in the DriftNapi interface. */
-export const DRIFT_NAPI_METHOD_COUNT = 66;
+export const DRIFT_NAPI_METHOD_COUNT = 67;
 
 /** All method names in the DriftNapi interface, for runtime validation. */
 export const DRIFT_NAPI_METHOD_NAMES: ReadonlyArray<keyof DriftNapi> = [
@@ -392,11 +396,12 @@ export const DRIFT_NAPI_METHOD_NAMES: ReadonlyArray<keyof DriftNapi> = [
   'driftOwaspAnalysis',
   'driftCryptoAnalysis',
   'driftDecomposition',
-  // Enforcement (7)
+  // Enforcement (8)
   'driftCheck',
   'driftAudit',
   'driftViolations',
   'driftGates',
+  'driftApplyFixes',
   'driftReport',
   'driftApprovePattern',
   'driftPatternStatus',
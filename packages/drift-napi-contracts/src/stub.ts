@@ -43,6 +43,7 @@ import type {
   JsCheckResult,
   JsAuditResult,
   JsViolation,
+  JsFixApplication,
   JsGateResult,
   JsFeedbackInput,
   JsFeedbackResult,
@@ -247,7 +248,7 @@ export function createStubNapi(): DriftNapi {
 
     // ─── Structural (9) ──────────────────────────────────────────────
     driftCouplingAnalysis(_root: string): JsCouplingResult {
-      return { metrics: [], cycles: [], moduleCount: 0 };
+      return { metrics: [], cycles: [], moduleCount: 0, changeCoupling: [] };
     },
 
     driftConstraintVerification(_root: string): JsConstraintResult {
@@ -272,6 +273,7 @@ export function createStubNapi(): DriftNapi {
       return {
         constantCount: 0,
         secrets: [],
+        historicalSecrets: [],
         magicNumbers: [],
         missingEnvVars: [],
         deadConstantCount: 0,
@@ -350,6 +352,7 @@ export function createStubNapi(): DriftNapi {
         overallPassed: true,
         totalViolations: 0,
         gates: [],
+        policies: [],
         sarif: null,
       };
     },
@@ -380,6 +383,10 @@ export function createStubNapi(): DriftNapi {
       return [];
     },
 
+    driftApplyFixes(_fixIds: string[]): JsFixApplication {
+      return { applied: [], conflicts: [], filesChanged: [] };
+    },
+
     driftReport(_format: string): string {
       return '';
     },
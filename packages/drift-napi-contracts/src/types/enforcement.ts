@@ -23,6 +23,29 @@ export interface JsViolation {
   owaspCategory: string | null;
   suppressed: boolean;
   isNew: boolean;
+  /** CODEOWNERS owners of the file. */
+  owners: string[];
+  /** Author of the last change to the line, from git blame. */
+  lastAuthor: string | null;
+  /** Stable id of the quick fix, for `driftApplyFixes`. */
+  quickFixId: string | null;
+  /** Whether the quick fix carries edits that can be applied automatically. */
+  quickFixApplicable: boolean;
+}
+
+/** Aligned to Rust JsFixConflict (#[napi(object)]). */
+export interface JsFixConflict {
+  fixId: string;
+  conflictsWith: string | null;
+  file: string;
+  reason: string;
+}
+
+/** Aligned to Rust JsFixApplication (#[napi(object)]). */
+export interface JsFixApplication {
+  applied: string[];
+  conflicts: JsFixConflict[];
+  filesChanged: string[];
 }
 
 // ─── Gate Result Types ───────────────────────────────────────────────
@@ -43,11 +66,25 @@ export interface JsGateResult {
 
 // ─── Check Result ────────────────────────────────────────────────────
 
+/** Aligned to Rust JsPolicyVerdict (#[napi(object)]). */
+export interface JsPolicyVerdict {
+  policyName: string;
+  aggregationMode: string;
+  passed: boolean;
+  score: number;
+  gatesPassed: number;
+  gatesFailed: number;
+  /** The scope label followed by the verdict, e.g. "tools/**: Policy ...". */
+  details: string | null;
+}
+
 /** Aligned to Rust JsCheckResult (#[napi(object)]). */
 export interface JsCheckResult {
   overallPassed: boolean;
   totalViolations: number;
   gates: JsGateResult[];
+  /** Per-scope policy verdicts; empty when no drift-policy.toml applies. */
+  policies: JsPolicyVerdict[];
   sarif: string | null;
 }
 
@@ -38,6 +38,7 @@ export type {
 export type {
   JsCouplingMetrics,
   JsCycleInfo,
+  JsChangeCoupling,
   JsCouplingResult,
   JsConstraintViolation,
   JsConstraintResult,
@@ -45,6 +46,7 @@ export type {
   JsContractMismatch,
   JsContractResult,
   JsSecret,
+  JsHistoricalSecret,
   JsMagicNumber,
   JsConstantsResult,
   JsWrapper,
@@ -65,7 +67,10 @@ export type {
 } from './structural.js';
 export type {
   JsViolation,
+  JsFixConflict,
+  JsFixApplication,
   JsGateResult,
+  JsPolicyVerdict,
   JsCheckResult,
   JsHealthBreakdown,
   JsAuditResult,
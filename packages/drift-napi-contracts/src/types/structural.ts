@@ -22,11 +22,30 @@ export interface JsCycleInfo {
   breakSuggestionCount: number;
 }
 
+/** Aligned to Rust JsChangeCoupling (#[napi(object)]). */
+export interface JsChangeCoupling {
+  moduleA: string;
+  moduleB: string;
+  coChanges: number;
+  confidence: number;
+  fileA: string;
+  fileB: string;
+  filePairs: number;
+  importEdge: boolean;
+  inCycle: boolean;
+  /** Workspace packages of fileA and fileB. */
+  packageA: string | null;
+  packageB: string | null;
+  /** Different packages, and no import edge or dependency cycle connects them. */
+  hidden: boolean;
+}
+
 /** Aligned to Rust JsCouplingResult (#[napi(object)]). */
 export interface JsCouplingResult {
   metrics: JsCouplingMetrics[];
   cycles: JsCycleInfo[];
   moduleCount: number;
+  changeCoupling: JsChangeCoupling[];
 }
 
 // ─── Constraint System ───────────────────────────────────────────────
@@ -86,6 +105,22 @@ export interface JsSecret {
   confidence: number;
 }
 
+/** Aligned to Rust JsHistoricalSecret (#[napi(object)]). */
+export interface JsHistoricalSecret {
+  patternName: string;
+  redactedValue: string;
+  file: string;
+  line: number;
+  severity: string;
+  firstCommit: string;
+  author: string;
+  timestamp: number;
+  occurrences: number;
+  files: string[];
+  /** Still in the tree at HEAD; otherwise only rewriting history removes it. */
+  presentAtHead: boolean;
+}
+
 /** Aligned to Rust JsMagicNumber (#[napi(object)]). */
 export interface JsMagicNumber {
   value: string;
@@ -98,6 +133,7 @@ export interface JsMagicNumber {
 export interface JsConstantsResult {
   constantCount: number;
   secrets: JsSecret[];
+  historicalSecrets: JsHistoricalSecret[];
   magicNumbers: JsMagicNumber[];
   missingEnvVars: string[];
   deadConstantCount: number;
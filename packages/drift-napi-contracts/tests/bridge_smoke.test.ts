@@ -262,12 +262,12 @@ describe('Bridge Smoke — Return Shape Completeness', () => {
 });
 
 describe('Bridge Contract Alignment Tests', () => {
-  // BT-NAPI-11: DriftNapi interface has exactly 66 methods
-  it('BT-NAPI-11: DriftNapi has exactly 66 methods — 43 drift + 21 bridge + 2 cloud', () => {
-    expect(DRIFT_NAPI_METHOD_COUNT).toBe(66);
-    expect(DRIFT_NAPI_METHOD_NAMES.length).toBe(66);
+  // BT-NAPI-11: DriftNapi interface has exactly 67 methods
+  it('BT-NAPI-11: DriftNapi has exactly 67 methods — 44 drift + 21 bridge + 2 cloud', () => {
+    expect(DRIFT_NAPI_METHOD_COUNT).toBe(67);
+    expect(DRIFT_NAPI_METHOD_NAMES.length).toBe(67);
     const unique = new Set(DRIFT_NAPI_METHOD_NAMES);
-    expect(unique.size).toBe(66);
+    expect(unique.size).toBe(67);
   });
 
   // BT-NAPI-12: Every bridge method has a corresponding stub entry
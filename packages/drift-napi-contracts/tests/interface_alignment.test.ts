@@ -39,6 +39,7 @@ describe('Interface Alignment — Contract Integrity', () => {
       'driftOwaspAnalysis', 'driftCryptoAnalysis', 'driftDecomposition',
       // enforcement.rs
       'driftCheck', 'driftAudit', 'driftViolations', 'driftGates',
+      'driftApplyFixes',
       'driftReport', 'driftGC',
       'driftApprovePattern', 'driftPatternStatus',
       // feedback.rs
@@ -70,14 +71,14 @@ describe('Interface Alignment — Contract Integrity', () => {
     }
   });
 
-  // TH-NAPI-03: DriftNapi has exactly 66 functions (43 drift + 21 bridge + 2 cloud)
-  it('TH-NAPI-03: DriftNapi has exactly 66 functions — prevents accidental add/remove', () => {
-    expect(DRIFT_NAPI_METHOD_COUNT).toBe(66);
-    expect(DRIFT_NAPI_METHOD_NAMES.length).toBe(66);
+  // TH-NAPI-03: DriftNapi has exactly 67 functions (44 drift + 21 bridge + 2 cloud)
+  it('TH-NAPI-03: DriftNapi has exactly 67 functions — prevents accidental add/remove', () => {
+    expect(DRIFT_NAPI_METHOD_COUNT).toBe(67);
+    expect(DRIFT_NAPI_METHOD_NAMES.length).toBe(67);
 
     // Also verify no duplicates
     const unique = new Set(DRIFT_NAPI_METHOD_NAMES);
-    expect(unique.size).toBe(66);
+    expect(unique.size).toBe(67);
   });
 
   // TH-NAPI-04: No function uses `any` type
@@ -132,6 +132,7 @@ describe('Stub — Full Method Coverage', () => {
     const r = stub.driftConstantsAnalysis('/src');
     expect(r.constantCount).toBe(0);
     expect(r.secrets).toEqual([]);
+    expect(r.historicalSecrets).toEqual([]);
     expect(r.magicNumbers).toEqual([]);
     expect(r.missingEnvVars).toEqual([]);
     expect(r.deadConstantCount).toBe(0);
@@ -196,6 +197,7 @@ describe('Stub — Full Method Coverage', () => {
     expect(r.overallPassed).toBe(true);
     expect(r.totalViolations).toBe(0);
     expect(r.gates).toEqual([]);
+    expect(r.policies).toEqual([]);
     expect(r.sarif).toBeNull();
   });
 
@@ -219,6 +221,13 @@ describe('Stub — Full Method Coverage', () => {
     expect(r).toEqual([]);
   });
 
+  it('drift_apply_fixes returns empty application', () => {
+    const r = stub.driftApplyFixes([]);
+    expect(r.applied).toEqual([]);
+    expect(r.conflicts).toEqual([]);
+    expect(r.filesChanged).toEqual([]);
+  });
+
   // Feedback — all 3
   it('drift_dismiss_violation returns success', () => {
     const r = stub.driftDismissViolation({ violationId: 'v1', action: 'dismiss' });
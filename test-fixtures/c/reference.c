@@ -0,0 +1,47 @@
+// EXPECT: function_count=5 class_count=1 import_count=3
+
+#include <stdio.h>
+#include <string.h>
+#include "config.h"
+
+/* Pattern: snake_case naming convention */
+
+struct buffer {
+    char *data;
+    size_t len;
+};
+
+static int validate_input(const char *input) {
+    if (input == NULL || strlen(input) == 0) {
+        return 0;
+    }
+    return 1;
+}
+
+int copy_name(char *dest, const char *src) {
+    // Taint sink: unbounded copy
+    strcpy(dest, src);
+    return validate_input(dest);
+}
+
+void run_command(const char *cmd) {
+    char line[256];
+    sprintf(line, "sh -c %s", cmd);
+    system(line);
+}
+
+int sum_items(const int *items, int count) {
+    int total = 0;
+    for (int i = 0; i < count; i++) {
+        total += items[i];
+    }
+    return total;
+}
+
+int main(void) {
+    char name[64];
+    copy_name(name, "drift");
+    printf("%d\n", sum_items((int[]){1, 2, 3}, 3));
+    run_command("ls");
+    return 0;
+}
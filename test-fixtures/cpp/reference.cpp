@@ -0,0 +1,57 @@
+// EXPECT: function_count=7 class_count=3 import_count=3
+
+#include <string>
+#include <vector>
+#include "service/repository.hpp"
+
+namespace app {
+namespace service {
+
+/// Pattern: PascalCase classes, camelCase methods
+class Repository {
+public:
+    virtual ~Repository() = default;
+    virtual std::string find(int id) = 0;
+};
+
+class UserService : public Repository {
+public:
+    explicit UserService(int capacity) : capacity_(capacity) {}
+
+    std::string find(int id) override {
+        return lookup(id);
+    }
+
+    void save(const std::string& name) {
+        try {
+            names_.push_back(name);
+        } catch (const std::bad_alloc& e) {
+            throw std::runtime_error("out of memory");
+        }
+    }
+
+private:
+    std::string lookup(int id) {
+        return names_.at(id);
+    }
+
+    int capacity_;
+    std::vector<std::string> names_;
+};
+
+struct Point {
+    int x;
+    int y;
+};
+
+int distance(const Point& a, const Point& b) {
+    return (a.x - b.x) + (a.y - b.y);
+}
+
+template <typename T>
+T clamp(T value, T lo, T hi) {
+    return value < lo ? lo : (value > hi ? hi : value);
+}
+
+} // namespace service
+} // namespace app
@@ -0,0 +1,56 @@
+// EXPECT: function_count=11 class_count=5 import_count=2
+
+package app.service
+
+import scala.util.{Try, Success => Ok}
+import java.sql.Connection
+
+/** Pattern: PascalCase types, camelCase methods */
+case class User(id: Int, name: String)
+
+trait Repository {
+  def find(id: Int): Option[User]
+  def save(user: User): Unit
+}
+
+class UserService(conn: Connection)(implicit ec: ExecutionContext) extends Repository with Logging {
+  private var cache: Map[Int, User] = Map.empty
+
+  def find(id: Int): Option[User] = cache.get(id)
+
+  def save(user: User): Unit = {
+    val stmt = conn.createStatement()
+    stmt.executeQuery("SELECT * FROM users WHERE id = " + user.id)
+    cache = cache + (user.id -> user)
+  }
+
+  private def evict(ids: Seq[Int]): Unit = {
+    ids.foreach(id => cache = cache - id)
+  }
+}
+
+object UserService {
+  val DefaultLimit = 100
+
+  def apply(conn: Connection)(implicit ec: ExecutionContext): UserService = new UserService(conn)
+
+  implicit class RichUser(val user: User) {
+    def display: String = s"${user.name} (${user.id})"
+  }
+}
+
+def loadUsers(path: String): List[User] = {
+  try {
+    val lines = scala.io.Source.fromFile(path).getLines().toList
+    lines.map(parseUser)
+  } catch {
+    case e: java.io.IOException => throw new IllegalStateException(e)
+  }
+}
+
+def parseUser(line: String): User = {
+  val parts = line.split(",")
+  User(parts(0).toInt, parts(1))
+}
+
+val normalize = (name: String) => name.trim.toLowerCase
@@ -0,0 +1,70 @@
+// EXPECT: function_count=10 class_count=4 import_count=2
+
+import Foundation
+import SQLite3
+
+/// Pattern: PascalCase types, camelCase methods
+protocol Repository {
+    func find(id: Int) -> User?
+    func save(_ user: User) throws
+}
+
+struct User: Codable {
+    let id: Int
+    var name: String
+}
+
+enum LoadError: Error {
+    case notFound
+    case invalid(String)
+}
+
+final class UserService: Repository {
+    private var cache: [Int: User] = [:]
+    let db: OpaquePointer?
+
+    init(db: OpaquePointer?) {
+        self.db = db
+    }
+
+    func find(id: Int) -> User? {
+        return cache[id]
+    }
+
+    func save(_ user: User) throws {
+        guard !user.name.isEmpty else {
+            throw LoadError.invalid(user.name)
+        }
+        let query = "INSERT INTO users VALUES (\(user.id), '\(user.name)')"
+        sqlite3_exec(db, query, nil, nil, nil)
+        cache[user.id] = user
+    }
+
+    private func evict(ids: [Int]) {
+        ids.forEach { id in
+            cache.removeValue(forKey: id)
+        }
+    }
+}
+
+extension UserService {
+    func count() -> Int {
+        return cache.count
+    }
+}
+
+func loadUsers(from path: String) -> [User] {
+    do {
+        let data = try Data(contentsOf: URL(fileURLWithPath: path))
+        return try JSONDecoder().decode([User].self, from: data)
+    } catch let error as DecodingError {
+        print("decode failed: \(error)")
+        return []
+    } catch {
+        return []
+    }
+}
+
+let normalize = { (name: String) -> String in
+    name.trimmingCharacters(in: .whitespaces).lowercased()
+}
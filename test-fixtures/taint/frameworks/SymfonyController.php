@@ -0,0 +1,29 @@
+<?php
+// Known taint paths (Symfony):
+//   $request->query → new Response (XSS)
+//   $request->headers → new RedirectResponse (open redirect)
+namespace App\Controller;
+
+use Symfony\Component\HttpFoundation\Request;
+use Symfony\Component\HttpFoundation\Response;
+use Symfony\Component\HttpFoundation\RedirectResponse;
+
+class SearchController
+{
+    public function search(Request $request): Response
+    {
+        $q = $request->query->get('q'); // SOURCE
+        return new Response('<h1>' . $q . '</h1>'); // SINK: XSS
+    }
+
+    public function escapedSearch(Request $request): Response
+    {
+        $q = htmlspecialchars($request->query->get('q')); // SOURCE, sanitized
+        return new Response('<h1>' . $q . '</h1>');
+    }
+
+    public function back(Request $request): RedirectResponse
+    {
+        return new RedirectResponse($request->headers->get('referer')); // SOURCE + SINK: open redirect
+    }
+}
@@ -0,0 +1,23 @@
+// Known taint paths (Axum):
+//   Query<T> extractor → Html (XSS)
+//   Path<T> extractor → Redirect::to (open redirect)
+use axum::extract::{Path, Query, State};
+use axum::response::{Html, Redirect};
+use axum::Json;
+
+async fn search(Query(params): Query<SearchParams>) -> Html<String> {  // SOURCE
+    let heading = format!("<h1>{}</h1>", params.q);
+    Html(heading)  // SINK: XSS
+}
+
+async fn go(Path(target): Path<String>) -> Redirect {  // SOURCE
+    Redirect::to(&target)  // SINK: open redirect
+}
+
+async fn create(State(state): State<AppState>, Json(user): Json<NewUser>) -> Json<User> {
+    Json(state.users.insert(user))
+}
+
+async fn health(State(state): State<AppState>) -> Html<String> {
+    Html(state.banner.clone())
+}
@@ -0,0 +1,23 @@
+// Known taint paths (Echo):
+//   c.QueryParam → c.HTML (XSS)
+//   c.FormValue → c.Redirect (open redirect)
+package handlers
+
+import (
+	"net/http"
+
+	"github.com/labstack/echo/v4"
+)
+
+func Search(c echo.Context) error {
+	q := c.QueryParam("q") // SOURCE
+	return c.HTML(http.StatusOK, "<h1>"+q+"</h1>") // SINK: XSS
+}
+
+func Back(c echo.Context) error {
+	return c.Redirect(http.StatusFound, c.FormValue("next")) // SOURCE + SINK: open redirect
+}
+
+func Health(c echo.Context) error {
+	return c.HTML(http.StatusOK, "<p>ok</p>")
+}
@@ -0,0 +1,37 @@
+# Known taint paths (FastAPI):
+#   Query() parameter → HTMLResponse (XSS)
+#   pydantic request body → cursor.execute (SQL injection)
+#   Header() read by a Depends() dependency → RedirectResponse (open redirect)
+from fastapi import FastAPI, Query, Header, Depends
+from fastapi.responses import HTMLResponse, RedirectResponse
+from pydantic import BaseModel
+
+app = FastAPI()
+
+
+class Comment(BaseModel):
+    body: str
+
+
+def current_referrer(referer: str = Header(None)):
+    return referer
+
+
+@app.get("/search")
+def search(q: str = Query(None)):  # SOURCE
+    return HTMLResponse(f"<h1>{q}</h1>")  # SINK: XSS
+
+
+@app.post("/comments")
+def create_comment(comment: Comment, cursor=Depends(get_cursor)):  # SOURCE
+    cursor.execute("INSERT INTO comments (body) VALUES ('%s')" % comment.body)  # SINK: SQL injection
+
+
+@app.get("/back")
+def back(target: str = Depends(current_referrer)):  # SOURCE
+    return RedirectResponse(target)  # SINK: open redirect
+
+
+@app.get("/count")
+def count(limit: int = 10):
+    return HTMLResponse(str(limit))
@@ -0,0 +1,19 @@
+// Known taint paths (Fiber):
+//   c.Params → c.SendFile (path traversal)
+//   c.Query → c.Redirect (open redirect)
+package handlers
+
+import "github.com/gofiber/fiber/v2"
+
+func Download(c *fiber.Ctx) error {
+	name := c.Params("name") // SOURCE
+	return c.SendFile("/srv/files/" + name) // SINK: path traversal
+}
+
+func Back(c *fiber.Ctx) error {
+	return c.Redirect(c.Query("next")) // SOURCE + SINK: open redirect
+}
+
+func Logo(c *fiber.Ctx) error {
+	return c.SendFile("/srv/static/logo.png")
+}
@@ -0,0 +1,22 @@
+// Known taint paths (Hono):
+//   c.req.query → c.html (XSS)
+//   c.req.param → c.redirect (open redirect)
+import { Hono, Context } from 'hono';
+
+export function search(c: Context) {
+  const q = c.req.query('q');  // SOURCE
+  return c.html(`<h1>${q}</h1>`);  // SINK: XSS
+}
+
+export function go(c: Context) {
+  return c.redirect(c.req.param('target'));  // SOURCE + SINK: open redirect
+}
+
+export function home(c: Context) {
+  return c.html('<h1>Welcome</h1>');
+}
+
+const app = new Hono();
+app.get('/search', search);
+app.get('/go/:target', go);
+app.get('/', home);
@@ -0,0 +1,25 @@
+// Known taint paths (Next.js):
+//   NextRequest search params → NextResponse.redirect (open redirect)
+//   server action FormData → db.query (SQL injection)
+//   page searchParams → redirect (open redirect)
+import { NextRequest, NextResponse } from 'next/server';
+import { redirect } from 'next/navigation';
+
+export async function GET(request: NextRequest) {  // SOURCE
+  const next = request.nextUrl.searchParams.get('next');
+  return NextResponse.redirect(next);  // SINK: open redirect
+}
+
+export async function subscribe(formData: FormData) {
+  'use server';
+  const email = formData.get('email');  // SOURCE
+  await db.query(`INSERT INTO subscribers (email) VALUES ('${email}')`);  // SINK: SQL injection
+}
+
+export default function Page({ searchParams }: { searchParams: { ref: string } }) {  // SOURCE
+  redirect(searchParams.ref);  // SINK: open redirect
+}
+
+export async function POST(request: NextRequest) {
+  return NextResponse.redirect('/thanks');
+}